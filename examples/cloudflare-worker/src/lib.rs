@@ -1,7 +1,8 @@
 //! Cloudflare Worker example using deribit-http with WASM
 //!
 //! This example demonstrates how to use the deribit-http crate in a
-//! Cloudflare Worker environment to fetch public market data.
+//! Cloudflare Worker environment to fetch public market data and, with API
+//! credentials, private account data.
 //!
 //! ## Running locally
 //!
@@ -9,17 +10,53 @@
 //! npx wrangler dev
 //! ```
 //!
+//! ## Private endpoints
+//!
+//! Store the credentials as Worker secrets (or in `.dev.vars` for
+//! `wrangler dev`); the client authenticates and refreshes its token itself:
+//!
+//! ```bash
+//! npx wrangler secret put DERIBIT_CLIENT_ID
+//! npx wrangler secret put DERIBIT_CLIENT_SECRET
+//! ```
+//!
 //! ## Building
 //!
 //! ```bash
 //! npx wrangler build
 //! ```
 
-use deribit_http::prelude::{DeribitHttpClient, HttpConfig, setup_logger};
+use deribit_http::prelude::{DeribitHttpClient, setup_logger};
+use std::cell::OnceCell;
 use worker::*;
 
+thread_local! {
+    // One client per isolate: requests reuse its connection pool and access
+    // token instead of paying for a new TLS handshake and login each time.
+    static CLIENT: OnceCell<DeribitHttpClient> = const { OnceCell::new() };
+}
+
+/// The isolate's client, authenticated if the credential secrets are set
+fn client(env: &Env) -> Result<DeribitHttpClient> {
+    if let Some(client) = CLIENT.with(|client| client.get().cloned()) {
+        return Ok(client);
+    }
+
+    let mut builder = DeribitHttpClient::builder();
+    if let (Ok(client_id), Ok(client_secret)) = (
+        env.secret("DERIBIT_CLIENT_ID"),
+        env.secret("DERIBIT_CLIENT_SECRET"),
+    ) {
+        builder = builder.credentials(client_id.to_string(), client_secret.to_string());
+    }
+    let client = builder
+        .build()
+        .map_err(|e| Error::RustError(e.to_string()))?;
+    Ok(CLIENT.with(|cell| cell.get_or_init(|| client).clone()))
+}
+
 #[event(fetch)]
-async fn fetch(req: Request, _env: Env, _ctx: Context) -> Result<Response> {
+async fn fetch(req: Request, env: Env, _ctx: Context) -> Result<Response> {
     // Set up logging for WASM (routes to console.log)
     setup_logger();
 
@@ -28,8 +65,9 @@ async fn fetch(req: Request, _env: Env, _ctx: Context) -> Result<Response> {
 
     match path {
         "/" => handle_root().await,
-        "/currencies" => handle_currencies().await,
-        "/ticker" => handle_ticker(&url).await,
+        "/currencies" => handle_currencies(client(&env)?).await,
+        "/ticker" => handle_ticker(client(&env)?, &url).await,
+        "/account" => handle_account(client(&env)?, &url).await,
         _ => Response::error("Not Found", 404),
     }
 }
@@ -47,6 +85,7 @@ async fn handle_root() -> Result<Response> {
     <ul>
         <li><a href="/currencies">/currencies</a> - List available currencies</li>
         <li><a href="/ticker?instrument=BTC-PERPETUAL">/ticker?instrument=BTC-PERPETUAL</a> - Get ticker for an instrument</li>
+        <li><a href="/account?currency=BTC">/account?currency=BTC</a> - Get the account summary (needs credentials)</li>
     </ul>
 </body>
 </html>
@@ -54,11 +93,7 @@ async fn handle_root() -> Result<Response> {
     Response::from_html(html)
 }
 
-async fn handle_currencies() -> Result<Response> {
-    // Create client with default testnet configuration
-    let config = HttpConfig::default();
-    let client = DeribitHttpClient::with_config(config);
-
+async fn handle_currencies(client: DeribitHttpClient) -> Result<Response> {
     match client.get_currencies().await {
         Ok(currencies) => {
             let json = serde_json::to_string_pretty(&currencies)
@@ -69,16 +104,8 @@ async fn handle_currencies() -> Result<Response> {
     }
 }
 
-async fn handle_ticker(url: &Url) -> Result<Response> {
-    let instrument = url
-        .query_pairs()
-        .find(|(k, _)| k == "instrument")
-        .map(|(_, v)| v.to_string())
-        .unwrap_or_else(|| "BTC-PERPETUAL".to_string());
-
-    // Create client with default testnet configuration
-    let config = HttpConfig::default();
-    let client = DeribitHttpClient::with_config(config);
+async fn handle_ticker(client: DeribitHttpClient, url: &Url) -> Result<Response> {
+    let instrument = query_param(url, "instrument").unwrap_or_else(|| "BTC-PERPETUAL".to_string());
 
     match client.get_ticker(&instrument).await {
         Ok(ticker) => {
@@ -90,6 +117,25 @@ async fn handle_ticker(url: &Url) -> Result<Response> {
     }
 }
 
+async fn handle_account(client: DeribitHttpClient, url: &Url) -> Result<Response> {
+    let currency = query_param(url, "currency").unwrap_or_else(|| "BTC".to_string());
+
+    match client.get_account_summary(&currency, None).await {
+        Ok(summary) => {
+            let json = serde_json::to_string_pretty(&summary)
+                .map_err(|e| Error::RustError(e.to_string()))?;
+            Response::ok(json).map(|r| r.with_headers(json_headers()))
+        }
+        Err(e) => Response::error(format!("Failed to fetch account summary: {}", e), 500),
+    }
+}
+
+fn query_param(url: &Url, name: &str) -> Option<String> {
+    url.query_pairs()
+        .find(|(k, _)| k == name)
+        .map(|(_, v)| v.to_string())
+}
+
 fn json_headers() -> Headers {
     let mut headers = Headers::new();
     headers.set("Content-Type", "application/json").ok();
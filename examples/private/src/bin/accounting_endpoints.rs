@@ -12,6 +12,7 @@
 //!
 //! Then run: cargo run --bin accounting_endpoints
 
+use deribit_http::model::instrument::InstrumentKind;
 use deribit_http::prelude::setup_logger;
 use deribit_http::{DeribitHttpClient, HttpError};
 use tracing::{error, info, warn};
@@ -30,7 +31,7 @@ async fn main() -> Result<(), HttpError> {
     info!("📊 1. GET ALL POSITIONS");
     info!("-----------------------");
 
-    let all_positions = match client.get_positions(None, None, None).await {
+    let all_positions = match client.get_positions(None::<&str>, None, None).await {
         Ok(positions) => {
             info!("✅ Retrieved all positions successfully");
             info!("📈 Total positions found: {}", positions.len());
@@ -107,7 +108,10 @@ async fn main() -> Result<(), HttpError> {
     info!("🔮 3. GET FUTURE POSITIONS");
     info!("---------------------------");
 
-    let _future_positions = match client.get_positions(None, Some("future"), None).await {
+    let _future_positions = match client
+        .get_positions(None::<&str>, Some(InstrumentKind::Future), None)
+        .await
+    {
         Ok(positions) => {
             info!("✅ Retrieved future positions successfully");
             info!("🔮 Future positions found: {}", positions.len());
@@ -34,9 +34,9 @@ async fn main() -> Result<(), HttpError> {
 
     // Get credentials from environment variables
     let client_id = env::var("DERIBIT_CLIENT_ID")
-        .map_err(|_| HttpError::ConfigError("DERIBIT_CLIENT_ID not set".to_string()))?;
+        .map_err(|_| HttpErrorKind::ConfigError("DERIBIT_CLIENT_ID not set".to_string()))?;
     let _client_secret = env::var("DERIBIT_CLIENT_SECRET")
-        .map_err(|_| HttpError::ConfigError("DERIBIT_CLIENT_SECRET not set".to_string()))?;
+        .map_err(|_| HttpErrorKind::ConfigError("DERIBIT_CLIENT_SECRET not set".to_string()))?;
     let testnet = env::var("DERIBIT_TESTNET")
         .unwrap_or_else(|_| "true".to_string())
         .parse::<bool>()
@@ -59,29 +59,16 @@ async fn main() -> Result<(), HttpError> {
     info!("📝 1. CREATE TEST ORDER");
     info!("-----------------------");
 
-    let buy_request = OrderRequest {
-        order_id: None,
-        instrument_name: "BTC-PERPETUAL".to_string(),
-        amount: Some(10.0),
-        contracts: None,
-        type_: Some(OrderType::Limit),
-        label: Some("test_cancel_order".to_string()),
-        price: Some(20000.0), // Very low price to avoid execution
-        time_in_force: Some(TimeInForce::GoodTilCancelled),
-        display_amount: None,
-        post_only: Some(true),
-        reject_post_only: None,
-        reduce_only: Some(false),
-        trigger_price: None,
-        trigger_offset: None,
-        trigger: None,
-        advanced: None,
-        mmp: None,
-        valid_until: None,
-        linked_order_type: None,
-        trigger_fill_condition: None,
-        otoco_config: None,
-    };
+    let buy_request = OrderRequest::builder()
+        .instrument_name("BTC-PERPETUAL")
+        .amount(10.0)
+        .order_type(OrderType::Limit)
+        .label("test_cancel_order")
+        .price(20000.0) // Very low price to avoid execution
+        .time_in_force(TimeInForce::GoodTilCancelled)
+        .post_only(true)
+        .reduce_only(false)
+        .build()?;
 
     let order_id = match client.buy_order(buy_request).await {
         Ok(order_response) => {
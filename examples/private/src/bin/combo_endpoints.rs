@@ -99,19 +99,10 @@ async fn main() -> Result<(), HttpError> {
     info!("   use deribit_http::model::combo::*;");
     info!("   ");
     info!("   // Calendar spread example:");
-    info!("   let legs = vec![");
-    info!("       ComboLeg {{");
-    info!("           instrument_name: \"BTC-25DEC25\".to_string(),");
-    info!("           direction: \"buy\".to_string(),");
-    info!("           amount: 1.0,");
-    info!("       }},");
-    info!("       ComboLeg {{");
-    info!("           instrument_name: \"BTC-28MAR26\".to_string(),");
-    info!("           direction: \"sell\".to_string(),");
-    info!("           amount: 1.0,");
-    info!("       }},");
-    info!("   ];");
-    info!("   let combo = client.create_combo(&legs).await?;");
+    info!("   let request = CreateComboRequest::default()");
+    info!("       .buy(\"BTC-25DEC25\", Some(1.0))");
+    info!("       .sell(\"BTC-28MAR26\", Some(1.0));");
+    info!("   let combo = client.create_combo(&request).await?;");
     println!();
 
     // =================================================================
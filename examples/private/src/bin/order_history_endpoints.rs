@@ -19,17 +19,11 @@
 
 use deribit_http::model::trigger::Trigger;
 use deribit_http::prelude::*;
+use futures_util::StreamExt;
+use std::pin::pin;
 use tokio::time::{Duration, sleep};
 use tracing::{error, info, warn};
 
-// Función auxiliar para redondear precios según el tick size
-fn round_to_tick_size(price: f64, tick_size: f64) -> f64 {
-    if tick_size <= 0.0 {
-        return price;
-    }
-    (price / tick_size).round() * tick_size
-}
-
 #[tokio::main]
 async fn main() -> Result<(), HttpError> {
     // Initialize logging
@@ -111,6 +105,7 @@ async fn main() -> Result<(), HttpError> {
             // Crear un instrumento mínimo con tick size por defecto
             Instrument {
                 tick_size: Some(0.5),
+                tick_size_steps: None,
                 instrument_name: "BTC-PERPETUAL".to_string(),
                 instrument_id: None,
                 kind: Some(InstrumentKind::Future),
@@ -151,6 +146,7 @@ async fn main() -> Result<(), HttpError> {
             info!("💡 Usando tick size por defecto de 0.05");
             Instrument {
                 tick_size: Some(0.05),
+                tick_size_steps: None,
                 instrument_name: "ETH-PERPETUAL".to_string(),
                 instrument_id: None,
                 kind: Some(InstrumentKind::Future),
@@ -179,9 +175,11 @@ async fn main() -> Result<(), HttpError> {
     // Redondear los precios de mercado según el tick size
     let btc_tick_size = btc_instrument.tick_size.unwrap_or(0.5);
     let eth_tick_size = eth_instrument.tick_size.unwrap_or(0.05);
+    let round_btc = |price: f64| btc_instrument.round_to_tick(price).unwrap_or(price);
+    let round_eth = |price: f64| eth_instrument.round_to_tick(price).unwrap_or(price);
 
-    let rounded_btc_price = round_to_tick_size(btc_mark_price, btc_tick_size);
-    let rounded_eth_price = round_to_tick_size(eth_mark_price, eth_tick_size);
+    let rounded_btc_price = round_btc(btc_mark_price);
+    let rounded_eth_price = round_eth(eth_mark_price);
 
     info!(
         "💰 Precio de BTC redondeado al tick size: {} -> {}",
@@ -210,7 +208,7 @@ async fn main() -> Result<(), HttpError> {
         (
             "BTC-PERPETUAL",
             "history_test_btc_1",
-            round_to_tick_size(btc_mark_price * 0.95, btc_tick_size),
+            round_btc(btc_mark_price * 0.95),
             10.0,
             "buy",
             OrderType::Limit,
@@ -218,7 +216,7 @@ async fn main() -> Result<(), HttpError> {
         (
             "BTC-PERPETUAL",
             "history_test_btc_2",
-            round_to_tick_size(btc_mark_price * 1.05, btc_tick_size),
+            round_btc(btc_mark_price * 1.05),
             10.0,
             "sell",
             OrderType::Limit,
@@ -226,7 +224,7 @@ async fn main() -> Result<(), HttpError> {
         (
             "ETH-PERPETUAL",
             "history_test_eth_1",
-            round_to_tick_size(eth_mark_price * 0.95, eth_tick_size),
+            round_eth(eth_mark_price * 0.95),
             100.0,
             "buy",
             OrderType::Limit,
@@ -234,7 +232,7 @@ async fn main() -> Result<(), HttpError> {
         (
             "ETH-PERPETUAL",
             "history_test_eth_2",
-            round_to_tick_size(eth_mark_price * 1.05, eth_tick_size),
+            round_eth(eth_mark_price * 1.05),
             100.0,
             "sell",
             OrderType::Limit,
@@ -243,7 +241,7 @@ async fn main() -> Result<(), HttpError> {
         (
             "BTC-PERPETUAL",
             "stop_history_btc_1",
-            round_to_tick_size(btc_mark_price * 0.90, btc_tick_size),
+            round_btc(btc_mark_price * 0.90),
             10.0,
             "buy",
             OrderType::StopLimit,
@@ -251,7 +249,7 @@ async fn main() -> Result<(), HttpError> {
         (
             "BTC-PERPETUAL",
             "stop_history_btc_2",
-            round_to_tick_size(btc_mark_price * 1.10, btc_tick_size),
+            round_btc(btc_mark_price * 1.10),
             10.0,
             "sell",
             OrderType::StopLimit,
@@ -259,7 +257,7 @@ async fn main() -> Result<(), HttpError> {
         (
             "ETH-PERPETUAL",
             "stop_history_eth_1",
-            round_to_tick_size(eth_mark_price * 0.90, eth_tick_size),
+            round_eth(eth_mark_price * 0.90),
             150.0,
             "buy",
             OrderType::StopLimit,
@@ -267,7 +265,7 @@ async fn main() -> Result<(), HttpError> {
         (
             "ETH-PERPETUAL",
             "stop_history_eth_2",
-            round_to_tick_size(eth_mark_price * 1.10, eth_tick_size),
+            round_eth(eth_mark_price * 1.10),
             150.0,
             "sell",
             OrderType::StopLimit,
@@ -289,9 +287,9 @@ async fn main() -> Result<(), HttpError> {
             let (trigger_price_val, trigger_val) = if order_type == OrderType::StopLimit {
                 // Para stop buy: trigger price por encima del precio actual
                 let trigger_price = if instrument.contains("BTC") {
-                    round_to_tick_size(btc_mark_price * 1.05, btc_tick_size)
+                    round_btc(btc_mark_price * 1.05)
                 } else {
-                    round_to_tick_size(eth_mark_price * 1.05, eth_tick_size)
+                    round_eth(eth_mark_price * 1.05)
                 };
                 (Some(trigger_price), Some(Trigger::LastPrice))
             } else {
@@ -346,9 +344,9 @@ async fn main() -> Result<(), HttpError> {
             let (trigger_price_val, trigger_val) = if order_type == OrderType::StopLimit {
                 // Para stop sell: trigger price por debajo del precio actual
                 let trigger_price = if instrument.contains("BTC") {
-                    round_to_tick_size(btc_mark_price * 0.95, btc_tick_size)
+                    round_btc(btc_mark_price * 0.95)
                 } else {
-                    round_to_tick_size(eth_mark_price * 0.95, eth_tick_size)
+                    round_eth(eth_mark_price * 0.95)
                 };
                 (Some(trigger_price), Some(Trigger::LastPrice))
             } else {
@@ -463,7 +461,7 @@ async fn main() -> Result<(), HttpError> {
 
     // Test BTC order history
     match client
-        .get_order_history("BTC", Some("future"), Some(20), Some(0))
+        .get_order_history("BTC", Some(InstrumentKind::Future), Some(20), Some(0))
         .await
     {
         Ok(orders) => {
@@ -474,12 +472,11 @@ async fn main() -> Result<(), HttpError> {
                 info!("📝 Recent BTC order history:");
                 for order in orders.iter().take(5) {
                     // Show first 5
-                    let creation_time = chrono::DateTime::from_timestamp(
-                        (order.creation_timestamp / 1000) as i64,
-                        0,
-                    )
-                    .map(|dt| dt.format("%Y-%m-%d %H:%M:%S UTC").to_string())
-                    .unwrap_or_else(|| "Unknown".to_string());
+                    let creation_time = order
+                        .creation_timestamp
+                        .to_datetime()
+                        .map(|dt| dt.format("%Y-%m-%d %H:%M:%S UTC").to_string())
+                        .unwrap_or_else(|| "Unknown".to_string());
 
                     info!(
                         "   - Order {}: {} {} @ ${:.2} [{}]",
@@ -514,7 +511,7 @@ async fn main() -> Result<(), HttpError> {
 
     // Test ETH order history
     match client
-        .get_order_history("ETH", Some("future"), Some(20), Some(0))
+        .get_order_history("ETH", Some(InstrumentKind::Future), Some(20), Some(0))
         .await
     {
         Ok(orders) => {
@@ -525,12 +522,11 @@ async fn main() -> Result<(), HttpError> {
                 info!("📝 Recent ETH order history:");
                 for order in orders.iter().take(3) {
                     // Show first 3
-                    let creation_time = chrono::DateTime::from_timestamp(
-                        (order.creation_timestamp / 1000) as i64,
-                        0,
-                    )
-                    .map(|dt| dt.format("%Y-%m-%d %H:%M:%S UTC").to_string())
-                    .unwrap_or_else(|| "Unknown".to_string());
+                    let creation_time = order
+                        .creation_timestamp
+                        .to_datetime()
+                        .map(|dt| dt.format("%Y-%m-%d %H:%M:%S UTC").to_string())
+                        .unwrap_or_else(|| "Unknown".to_string());
 
                     info!(
                         "   - Order {}: {} {} @ ${:.2} [{}]",
@@ -561,103 +557,55 @@ async fn main() -> Result<(), HttpError> {
     info!("🛑 2. GET STOP ORDER HISTORY");
     info!("-----------------------------");
 
-    // Test BTC stop order history
-    match client
-        .get_order_history("BTC", Some("future"), Some(15), Some(0))
-        .await
-    {
-        Ok(orders) => {
-            info!("✅ Retrieved BTC stop order history successfully");
-            info!("📊 BTC stop orders count: {}", orders.len());
-
-            if !orders.is_empty() {
-                info!("📝 Recent BTC stop order history:");
-                for order in orders.iter().take(3) {
-                    // Show first 3
-                    let creation_time = chrono::DateTime::from_timestamp(
-                        (order.creation_timestamp / 1000) as i64,
-                        0,
-                    )
-                    .map(|dt| dt.format("%Y-%m-%d %H:%M:%S UTC").to_string())
-                    .unwrap_or_else(|| "Unknown".to_string());
-
-                    info!(
-                        "   - Stop Order {}: {} {} @ ${:.2} [{}]",
-                        order.order_id,
-                        order.direction,
-                        order.instrument_name,
-                        order.price,
-                        order.order_state
-                    );
-                    info!(
-                        "     Created: {}, Type: {}, Label: {}",
-                        creation_time, order.order_type, order.label
-                    );
-                    info!(
-                        "     Amount: {:.6}, Filled: {:.6}, Post Only: {}",
-                        order.amount,
-                        order.filled_amount.unwrap_or(0.0),
-                        order.post_only
-                    );
-                }
-            } else {
+    for currency in ["BTC", "ETH"] {
+        match client
+            .get_stop_order_history(currency, None, Some(15), None)
+            .await
+        {
+            Ok(history) => {
+                info!("✅ Retrieved {} stop order history successfully", currency);
                 info!(
-                    "ℹ️  No BTC stop order history found (this is normal if no stop orders were placed)"
+                    "📊 {} stop order entries: {}",
+                    currency,
+                    history.entries.len()
                 );
-            }
-        }
-        Err(e) => {
-            warn!("⚠️  Failed to get BTC stop order history: {}", e);
-        }
-    }
-
-    // Test ETH stop order history
-    match client
-        .get_order_history("ETH", Some("future"), Some(15), Some(0))
-        .await
-    {
-        Ok(orders) => {
-            info!("✅ Retrieved ETH stop order history successfully");
-            info!("📊 ETH stop orders count: {}", orders.len());
 
-            if !orders.is_empty() {
-                info!("📝 Recent ETH stop order history:");
-                for order in orders.iter().take(3) {
-                    // Show first 3
-                    let creation_time = chrono::DateTime::from_timestamp(
-                        (order.creation_timestamp / 1000) as i64,
-                        0,
-                    )
-                    .map(|dt| dt.format("%Y-%m-%d %H:%M:%S UTC").to_string())
-                    .unwrap_or_else(|| "Unknown".to_string());
-
-                    info!(
-                        "   - Stop Order {}: {} {} @ ${:.2} [{}]",
-                        order.order_id,
-                        order.direction,
-                        order.instrument_name,
-                        order.price,
-                        order.order_state
-                    );
-                    info!(
-                        "     Created: {}, Type: {}, Label: {}",
-                        creation_time, order.order_type, order.label
-                    );
+                if !history.entries.is_empty() {
+                    info!("📝 Recent {} stop order history:", currency);
+                    for entry in history.entries.iter().take(3) {
+                        // Show first 3
+                        let event_time = entry
+                            .timestamp
+                            .to_datetime()
+                            .map(|dt| dt.format("%Y-%m-%d %H:%M:%S UTC").to_string())
+                            .unwrap_or_else(|| "Unknown".to_string());
+
+                        info!(
+                            "   - Stop Order {}: {} {} {:.6} [{}]",
+                            entry.stop_id,
+                            entry.direction,
+                            entry.instrument_name,
+                            entry.amount,
+                            entry.order_state
+                        );
+                        info!(
+                            "     Time: {}, Stop Price: {:?}, Trigger: {:?}",
+                            event_time, entry.stop_price, entry.trigger
+                        );
+                    }
+                    if history.has_more() {
+                        info!("   ... more entries available via continuation");
+                    }
+                } else {
                     info!(
-                        "     Amount: {:.6}, Filled: {:.6}, Reduce Only: {}",
-                        order.amount,
-                        order.filled_amount.unwrap_or(0.0),
-                        order.reduce_only
+                        "ℹ️  No {} stop order history found (this is normal if no stop orders were placed)",
+                        currency
                     );
                 }
-            } else {
-                info!(
-                    "ℹ️  No ETH stop order history found (this is normal if no stop orders were placed)"
-                );
             }
-        }
-        Err(e) => {
-            warn!("⚠️  Failed to get ETH stop order history: {}", e);
+            Err(e) => {
+                warn!("⚠️  Failed to get {} stop order history: {}", currency, e);
+            }
         }
     }
     println!();
@@ -711,54 +659,29 @@ async fn main() -> Result<(), HttpError> {
     info!("📄 4. PAGINATION DEMONSTRATION");
     info!("-------------------------------");
 
-    // Demonstrate pagination with BTC orders
-    let page_size = 5;
-    let mut offset = 0;
-    let mut total_fetched = 0;
-
+    // Stream BTC order history; offsets are advanced until a page comes back short
+    let preview = 15;
     info!(
-        "📖 Fetching BTC order history with pagination (page size: {}):",
-        page_size
+        "📖 Streaming BTC future order history (showing up to {}):",
+        preview
     );
 
-    for page in 1..=3 {
-        // Fetch up to 3 pages
-        match client
-            .get_order_history("BTC", Some("future"), Some(page_size), Some(offset))
-            .await
-        {
-            Ok(orders) => {
-                if orders.is_empty() {
-                    info!("   Page {}: No more orders found", page);
-                    break;
-                }
-
-                info!(
-                    "   Page {}: {} orders (offset: {})",
-                    page,
-                    orders.len(),
-                    offset
-                );
-                for (i, order) in orders.iter().enumerate() {
+    let mut orders =
+        pin!(client.stream_order_history_by_currency("BTC", Some(InstrumentKind::Future)));
+    let mut total_fetched = 0;
+    while let Some(order) = orders.next().await {
+        match order {
+            Ok(order) => {
+                total_fetched += 1;
+                if total_fetched <= preview {
                     info!(
                         "     {}. {} [{}] - ${:.2}",
-                        offset + i as u32 + 1,
-                        order.order_id,
-                        order.order_state,
-                        order.price
+                        total_fetched, order.order_id, order.order_state, order.price
                     );
                 }
-
-                total_fetched += orders.len();
-                offset += orders.len() as u32;
-
-                if orders.len() < page_size as usize {
-                    info!("   Last page reached (fewer orders than page size)");
-                    break;
-                }
             }
             Err(e) => {
-                warn!("⚠️  Failed to fetch page {}: {}", page, e);
+                warn!("⚠️  Failed to fetch order history page: {}", e);
                 break;
             }
         }
@@ -819,7 +742,7 @@ async fn main() -> Result<(), HttpError> {
     info!("   ✅ /private/get_stop_order_history - Stop order history by currency");
     info!("🔧 Additional features demonstrated:");
     info!("   📊 Order history filtering by currency and instrument kind");
-    info!("   📄 Pagination with count and offset parameters");
+    info!("   📄 Streaming order history across count/offset pages");
     info!("   🔍 Order state and type analysis");
     info!("   ⏰ Timestamp formatting for readable output");
     info!("🔧 Auxiliary endpoints used:");
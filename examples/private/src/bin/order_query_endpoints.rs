@@ -70,29 +70,16 @@ async fn main() -> Result<(), HttpError> {
 
     for (instrument, label, price, amount, side) in test_orders {
         if side == "buy" {
-            let buy_request = OrderRequest {
-                order_id: None,
-                instrument_name: instrument.to_string(),
-                amount: Some(amount),
-                contracts: None,
-                type_: Some(OrderType::Limit),
-                label: Some(label.to_string()),
-                price: Some(price),
-                time_in_force: Some(TimeInForce::GoodTilCancelled),
-                display_amount: None,
-                post_only: Some(true), // Avoid immediate execution
-                reject_post_only: None,
-                reduce_only: Some(false),
-                trigger_price: None,
-                trigger_offset: None,
-                trigger: None,
-                advanced: None,
-                mmp: None,
-                valid_until: None,
-                linked_order_type: None,
-                trigger_fill_condition: None,
-                otoco_config: None,
-            };
+            let buy_request = OrderRequest::builder()
+                .instrument_name(instrument)
+                .amount(amount)
+                .order_type(OrderType::Limit)
+                .label(label)
+                .price(price)
+                .time_in_force(TimeInForce::GoodTilCancelled)
+                .post_only(true) // Avoid immediate execution
+                .reduce_only(false)
+                .build()?;
 
             match client.buy_order(buy_request).await {
                 Ok(order_response) => {
@@ -107,29 +94,16 @@ async fn main() -> Result<(), HttpError> {
                 }
             }
         } else {
-            let sell_request = OrderRequest {
-                order_id: None,
-                instrument_name: instrument.to_string(),
-                amount: Some(amount),
-                contracts: None,
-                type_: Some(OrderType::Limit),
-                label: Some(label.to_string()),
-                price: Some(price),
-                time_in_force: Some(TimeInForce::GoodTilCancelled),
-                display_amount: None,
-                post_only: Some(true), // Avoid immediate execution
-                reject_post_only: None,
-                reduce_only: Some(false),
-                trigger_price: None,
-                trigger_offset: None,
-                trigger: None,
-                advanced: None,
-                mmp: None,
-                valid_until: None,
-                linked_order_type: None,
-                trigger_fill_condition: None,
-                otoco_config: None,
-            };
+            let sell_request = OrderRequest::builder()
+                .instrument_name(instrument)
+                .amount(amount)
+                .order_type(OrderType::Limit)
+                .label(label)
+                .price(price)
+                .time_in_force(TimeInForce::GoodTilCancelled)
+                .post_only(true) // Avoid immediate execution
+                .reduce_only(false)
+                .build()?;
 
             match client.sell_order(sell_request).await {
                 Ok(order_response) => {
@@ -161,7 +135,10 @@ async fn main() -> Result<(), HttpError> {
     info!("📋 1. GET OPEN ORDERS (ALL)");
     info!("----------------------------");
 
-    match client.get_open_orders(Some("future"), None).await {
+    match client
+        .get_open_orders(Some(InstrumentKind::Future), None)
+        .await
+    {
         Ok(orders) => {
             info!("✅ Retrieved all open orders successfully");
             info!("📊 Total open orders: {}", orders.len());
@@ -198,7 +175,7 @@ async fn main() -> Result<(), HttpError> {
 
     // Test BTC currency
     match client
-        .get_open_orders_by_currency("BTC", Some("future"), None)
+        .get_open_orders_by_currency("BTC", Some(InstrumentKind::Future), None)
         .await
     {
         Ok(orders) => {
@@ -220,7 +197,7 @@ async fn main() -> Result<(), HttpError> {
 
     // Test ETH currency
     match client
-        .get_open_orders_by_currency("ETH", Some("future"), None)
+        .get_open_orders_by_currency("ETH", Some(InstrumentKind::Future), None)
         .await
     {
         Ok(orders) => {
@@ -328,7 +305,7 @@ async fn main() -> Result<(), HttpError> {
 
     // Test BTC history
     match client
-        .get_order_history_by_currency("BTC", Some("future"), Some(10), Some(0))
+        .get_order_history_by_currency("BTC", Some(InstrumentKind::Future), Some(10), Some(0))
         .await
     {
         Ok(orders) => {
@@ -354,7 +331,7 @@ async fn main() -> Result<(), HttpError> {
 
     // Test ETH history
     match client
-        .get_order_history_by_currency("ETH", Some("future"), Some(10), Some(0))
+        .get_order_history_by_currency("ETH", Some(InstrumentKind::Future), Some(10), Some(0))
         .await
     {
         Ok(orders) => {
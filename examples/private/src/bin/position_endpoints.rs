@@ -13,6 +13,7 @@
 //!
 //! Then run: cargo run --bin position_endpoints
 
+use deribit_http::model::instrument::InstrumentKind;
 use deribit_http::prelude::setup_logger;
 use deribit_http::{DeribitHttpClient, HttpError};
 use tracing::{error, info, warn};
@@ -34,7 +35,7 @@ async fn main() -> Result<(), HttpError> {
     info!("📊 1. GET ALL POSITIONS");
     info!("------------------------");
 
-    let positions = match client.get_positions(None, None, None).await {
+    let positions = match client.get_positions(None::<&str>, None, None).await {
         Ok(pos) => {
             info!("✅ Retrieved all positions");
             info!("   📈 Total positions: {}", pos.len());
@@ -102,7 +103,10 @@ async fn main() -> Result<(), HttpError> {
     info!("📊 3. GET OPTION POSITIONS");
     info!("--------------------------");
 
-    match client.get_positions(None, Some("option"), None).await {
+    match client
+        .get_positions(None::<&str>, Some(InstrumentKind::Option), None)
+        .await
+    {
         Ok(option_positions) => {
             info!("✅ Retrieved option positions");
             info!("   📈 Option positions: {}", option_positions.len());
@@ -125,19 +129,17 @@ async fn main() -> Result<(), HttpError> {
 
     if let Some(first_pos) = positions.first() {
         match client.get_position(&first_pos.instrument_name).await {
-            Ok(pos_list) => {
-                info!("✅ Retrieved specific position(s)");
-                for position in pos_list.iter() {
-                    info!("   📈 Instrument: {}", position.instrument_name);
-                    info!("   💰 Size: {}", position.size);
-                    info!("   📊 Direction: {:?}", position.direction);
-                    info!("   💵 Average Price: ${:.2}", position.average_price);
-                    if let Some(initial_margin) = position.initial_margin {
-                        info!("   🔒 Initial Margin: ${:.2}", initial_margin);
-                    }
-                    if let Some(maint_margin) = position.maintenance_margin {
-                        info!("   🔒 Maintenance Margin: ${:.2}", maint_margin);
-                    }
+            Ok(position) => {
+                info!("✅ Retrieved specific position");
+                info!("   📈 Instrument: {}", position.instrument_name);
+                info!("   💰 Size: {}", position.size);
+                info!("   📊 Direction: {:?}", position.direction);
+                info!("   💵 Average Price: ${:.2}", position.average_price);
+                if let Some(initial_margin) = position.initial_margin {
+                    info!("   🔒 Initial Margin: ${:.2}", initial_margin);
+                }
+                if let Some(maint_margin) = position.maintenance_margin {
+                    info!("   🔒 Maintenance Margin: ${:.2}", maint_margin);
                 }
             }
             Err(e) => {
@@ -147,7 +149,7 @@ async fn main() -> Result<(), HttpError> {
     } else {
         info!("   No positions available to query");
         info!("   Example usage:");
-        info!("   let positions = client.get_position(\"BTC-PERPETUAL\").await?;");
+        info!("   let position = client.get_position(\"BTC-PERPETUAL\").await?;");
     }
     println!();
 
@@ -163,17 +165,11 @@ async fn main() -> Result<(), HttpError> {
     info!("   use deribit_http::model::request::position::*;");
     info!("   ");
     info!("   let trades = vec![");
-    info!("       MovePositionTrade::new(");
-    info!("           \"BTC-PERPETUAL\".to_string(),");
-    info!("           1000.0,  // Amount to move");
-    info!("       ),");
+    info!("       MovePositionTrade::new(\"BTC-PERPETUAL\", 1000.0), // Amount to move");
     info!("   ];");
-    info!("   let request = MovePositionsRequest::new(");
-    info!("       \"BTC\".to_string(),");
-    info!("       12345,  // Destination subaccount ID");
-    info!("       trades,");
-    info!("   );");
-    info!("   let result = client.move_positions(&request).await?;");
+    info!("   let results = client");
+    info!("       .move_positions(\"BTC\", source_uid, target_uid, &trades)");
+    info!("       .await?;");
     println!();
 
     // =================================================================
@@ -34,29 +34,16 @@ async fn main() -> Result<(), HttpError> {
     info!("💰 1. PLACE BUY ORDER");
     info!("---------------------");
 
-    let buy_request = OrderRequest {
-        order_id: None,
-        instrument_name: "BTC-PERPETUAL".to_string(),
-        amount: Some(10.0), // 10 USD worth of BTC
-        contracts: None,
-        type_: Some(OrderType::Limit),
-        label: Some("example_buy_order".to_string()),
-        price: Some(30000.0), // Low price to avoid execution
-        time_in_force: Some(TimeInForce::GoodTilCancelled),
-        display_amount: None,
-        post_only: Some(true), // Ensure we don't execute immediately
-        reject_post_only: None,
-        reduce_only: Some(false),
-        trigger_price: None,
-        trigger_offset: None,
-        trigger: None,
-        advanced: None,
-        mmp: None,
-        valid_until: None,
-        linked_order_type: None,
-        trigger_fill_condition: None,
-        otoco_config: None,
-    };
+    let buy_request = OrderRequest::builder()
+        .instrument_name("BTC-PERPETUAL")
+        .amount(10.0) // 10 USD worth of BTC
+        .order_type(OrderType::Limit)
+        .label("example_buy_order")
+        .price(30000.0) // Low price to avoid execution
+        .time_in_force(TimeInForce::GoodTilCancelled)
+        .post_only(true) // Ensure we don't execute immediately
+        .reduce_only(false)
+        .build()?;
 
     let buy_order_id = match client.buy_order(buy_request).await {
         Ok(order_response) => {
@@ -81,29 +68,16 @@ async fn main() -> Result<(), HttpError> {
     info!("💸 2. PLACE SELL ORDER");
     info!("----------------------");
 
-    let sell_request = OrderRequest {
-        order_id: None,
-        instrument_name: "BTC-PERPETUAL".to_string(),
-        amount: Some(10.0), // 10 USD worth of BTC
-        contracts: None,
-        type_: Some(OrderType::Limit),
-        label: Some("example_sell_order".to_string()),
-        price: Some(150000.0), // High price to avoid execution
-        time_in_force: Some(TimeInForce::GoodTilCancelled),
-        display_amount: None,
-        post_only: Some(true), // Ensure we don't execute immediately
-        reject_post_only: None,
-        reduce_only: Some(false),
-        trigger_price: None,
-        trigger_offset: None,
-        trigger: None,
-        advanced: None,
-        mmp: None,
-        valid_until: None,
-        linked_order_type: None,
-        trigger_fill_condition: None,
-        otoco_config: None,
-    };
+    let sell_request = OrderRequest::builder()
+        .instrument_name("BTC-PERPETUAL")
+        .amount(10.0) // 10 USD worth of BTC
+        .order_type(OrderType::Limit)
+        .label("example_sell_order")
+        .price(150000.0) // High price to avoid execution
+        .time_in_force(TimeInForce::GoodTilCancelled)
+        .post_only(true) // Ensure we don't execute immediately
+        .reduce_only(false)
+        .build()?;
 
     let sell_order_id = match client.sell_order(sell_request).await {
         Ok(order_response) => {
@@ -128,29 +102,17 @@ async fn main() -> Result<(), HttpError> {
     info!("✏️  3. EDIT ORDER BY ID");
     info!("-----------------------");
 
-    let edit_request = OrderRequest {
-        order_id: Some(buy_order_id.clone()),
-        instrument_name: "BTC-PERPETUAL".to_string(),
-        amount: Some(20.0), // Change amount from 10 to 20 USD
-        contracts: None,
-        type_: Some(OrderType::Limit),
-        label: Some("example_buy_order".to_string()),
-        price: Some(25000.0), // Change price from 30000 to 25000
-        time_in_force: Some(TimeInForce::GoodTilCancelled),
-        display_amount: None,
-        post_only: Some(true),
-        reject_post_only: None,
-        reduce_only: Some(false),
-        trigger_price: None,
-        trigger_offset: None,
-        trigger: None,
-        advanced: None,
-        mmp: None,
-        valid_until: None,
-        linked_order_type: None,
-        trigger_fill_condition: None,
-        otoco_config: None,
-    };
+    let edit_request = OrderRequest::builder()
+        .order_id(buy_order_id.clone())
+        .instrument_name("BTC-PERPETUAL")
+        .amount(20.0) // Change amount from 10 to 20 USD
+        .order_type(OrderType::Limit)
+        .label("example_buy_order")
+        .price(25000.0) // Change price from 30000 to 25000
+        .time_in_force(TimeInForce::GoodTilCancelled)
+        .post_only(true)
+        .reduce_only(false)
+        .build()?;
 
     match client.edit_order(edit_request).await {
         Ok(order_response) => {
@@ -137,29 +137,16 @@ async fn main() -> Result<(), HttpError> {
 
     for (instrument, label, price, amount, side) in test_orders {
         if side == "buy" {
-            let buy_request = OrderRequest {
-                order_id: None,
-                instrument_name: instrument.to_string(),
-                amount: Some(amount),
-                contracts: None,
-                type_: Some(OrderType::Limit),
-                label: Some(label.to_string()),
-                price: Some(price),
-                time_in_force: Some(TimeInForce::GoodTilCancelled),
-                display_amount: None,
-                post_only: Some(false), // Allow execution to potentially generate trades
-                reject_post_only: None,
-                reduce_only: Some(false),
-                trigger_price: None,
-                trigger_offset: None,
-                trigger: None,
-                advanced: None,
-                mmp: None,
-                valid_until: None,
-                linked_order_type: None,
-                trigger_fill_condition: None,
-                otoco_config: None,
-            };
+            let buy_request = OrderRequest::builder()
+                .instrument_name(instrument)
+                .amount(amount)
+                .order_type(OrderType::Limit)
+                .label(label)
+                .price(price)
+                .time_in_force(TimeInForce::GoodTilCancelled)
+                .post_only(false) // Allow execution to potentially generate trades
+                .reduce_only(false)
+                .build()?;
 
             match client.buy_order(buy_request).await {
                 Ok(order_response) => {
@@ -182,29 +169,16 @@ async fn main() -> Result<(), HttpError> {
                 }
             }
         } else {
-            let sell_request = OrderRequest {
-                order_id: None,
-                instrument_name: instrument.to_string(),
-                amount: Some(amount),
-                contracts: None,
-                type_: Some(OrderType::Limit),
-                label: Some(label.to_string()),
-                price: Some(price),
-                time_in_force: Some(TimeInForce::GoodTilCancelled),
-                display_amount: None,
-                post_only: Some(false), // Allow execution to potentially generate trades
-                reject_post_only: None,
-                reduce_only: Some(false),
-                trigger_price: None,
-                trigger_offset: None,
-                trigger: None,
-                advanced: None,
-                mmp: None,
-                valid_until: None,
-                linked_order_type: None,
-                trigger_fill_condition: None,
-                otoco_config: None,
-            };
+            let sell_request = OrderRequest::builder()
+                .instrument_name(instrument)
+                .amount(amount)
+                .order_type(OrderType::Limit)
+                .label(label)
+                .price(price)
+                .time_in_force(TimeInForce::GoodTilCancelled)
+                .post_only(false) // Allow execution to potentially generate trades
+                .reduce_only(false)
+                .build()?;
 
             match client.sell_order(sell_request).await {
                 Ok(order_response) => {
@@ -353,8 +327,8 @@ async fn main() -> Result<(), HttpError> {
         start_id: None,
         end_id: None,
         count: Some(20),
-        start_timestamp: Some(one_day_ago),
-        end_timestamp: Some(current_timestamp),
+        start_timestamp: Some(TimestampMs::from_millis(one_day_ago)),
+        end_timestamp: Some(TimestampMs::from_millis(current_timestamp)),
         sorting: Some(SortDirection::Desc),
         historical: None,
         subaccount_id: None,
@@ -372,10 +346,11 @@ async fn main() -> Result<(), HttpError> {
                 info!("📝 BTC trades in time range:");
                 for trade in trades.trades.iter().take(2) {
                     // Show first 2
-                    let trade_time =
-                        chrono::DateTime::from_timestamp((trade.timestamp / 1000) as i64, 0)
-                            .map(|dt| dt.format("%Y-%m-%d %H:%M:%S UTC").to_string())
-                            .unwrap_or_else(|| "Unknown".to_string());
+                    let trade_time = trade
+                        .timestamp
+                        .to_datetime()
+                        .map(|dt| dt.format("%Y-%m-%d %H:%M:%S UTC").to_string())
+                        .unwrap_or_else(|| "Unknown".to_string());
 
                     info!(
                         "   - {} @ ${:.2} on {} ({} {})",
@@ -406,8 +381,8 @@ async fn main() -> Result<(), HttpError> {
         start_id: None,
         end_id: None,
         count: Some(15),
-        start_timestamp: Some(one_hour_ago),
-        end_timestamp: Some(current_timestamp),
+        start_timestamp: Some(TimestampMs::from_millis(one_hour_ago)),
+        end_timestamp: Some(TimestampMs::from_millis(current_timestamp)),
         sorting: Some(SortDirection::Desc),
         historical: None,
         subaccount_id: None,
@@ -425,10 +400,11 @@ async fn main() -> Result<(), HttpError> {
                 info!("📝 ETH trades in time range:");
                 for trade in trades.trades.iter().take(2) {
                     // Show first 2
-                    let trade_time =
-                        chrono::DateTime::from_timestamp((trade.timestamp / 1000) as i64, 0)
-                            .map(|dt| dt.format("%Y-%m-%d %H:%M:%S UTC").to_string())
-                            .unwrap_or_else(|| "Unknown".to_string());
+                    let trade_time = trade
+                        .timestamp
+                        .to_datetime()
+                        .map(|dt| dt.format("%Y-%m-%d %H:%M:%S UTC").to_string())
+                        .unwrap_or_else(|| "Unknown".to_string());
 
                     info!(
                         "   - {} @ ${:.2} on {} ({} {})",
@@ -467,7 +443,7 @@ async fn main() -> Result<(), HttpError> {
             None,
             Some(10),
             Some(true),
-            Some("desc"),
+            Some(SortDirection::Desc),
         )
         .await
     {
@@ -506,7 +482,7 @@ async fn main() -> Result<(), HttpError> {
             None,
             Some(10),
             Some(true),
-            Some("desc"),
+            Some(SortDirection::Desc),
         )
         .await
     {
@@ -548,11 +524,13 @@ async fn main() -> Result<(), HttpError> {
     match client
         .get_user_trades_by_instrument_and_time(
             "BTC-PERPETUAL",
-            one_day_ago,
-            current_timestamp,
+            (
+                TimestampMs::from_millis(one_day_ago),
+                TimestampMs::from_millis(current_timestamp),
+            ),
             Some(15),
             Some(true),
-            Some("desc"),
+            Some(SortDirection::Desc),
         )
         .await
     {
@@ -568,10 +546,11 @@ async fn main() -> Result<(), HttpError> {
                 info!("📝 BTC-PERPETUAL trades in time range:");
                 for trade in response.trades.iter().take(2) {
                     // Show first 2
-                    let trade_time =
-                        chrono::DateTime::from_timestamp((trade.timestamp / 1000) as i64, 0)
-                            .map(|dt| dt.format("%Y-%m-%d %H:%M:%S UTC").to_string())
-                            .unwrap_or_else(|| "Unknown".to_string());
+                    let trade_time = trade
+                        .timestamp
+                        .to_datetime()
+                        .map(|dt| dt.format("%Y-%m-%d %H:%M:%S UTC").to_string())
+                        .unwrap_or_else(|| "Unknown".to_string());
 
                     info!(
                         "   - {} @ ${:.2} on {}",
@@ -595,11 +574,13 @@ async fn main() -> Result<(), HttpError> {
     match client
         .get_user_trades_by_instrument_and_time(
             "ETH-PERPETUAL",
-            one_hour_ago,
-            current_timestamp,
+            (
+                TimestampMs::from_millis(one_hour_ago),
+                TimestampMs::from_millis(current_timestamp),
+            ),
             Some(15),
             Some(true),
-            Some("desc"),
+            Some(SortDirection::Desc),
         )
         .await
     {
@@ -615,10 +596,11 @@ async fn main() -> Result<(), HttpError> {
                 info!("📝 ETH-PERPETUAL trades in time range:");
                 for trade in response.trades.iter().take(2) {
                     // Show first 2
-                    let trade_time =
-                        chrono::DateTime::from_timestamp((trade.timestamp / 1000) as i64, 0)
-                            .map(|dt| dt.format("%Y-%m-%d %H:%M:%S UTC").to_string())
-                            .unwrap_or_else(|| "Unknown".to_string());
+                    let trade_time = trade
+                        .timestamp
+                        .to_datetime()
+                        .map(|dt| dt.format("%Y-%m-%d %H:%M:%S UTC").to_string())
+                        .unwrap_or_else(|| "Unknown".to_string());
 
                     info!(
                         "   - {} @ ${:.2} on {}",
@@ -649,7 +631,7 @@ async fn main() -> Result<(), HttpError> {
     for (order_id, label) in created_order_ids.iter().take(3) {
         // Test first 3 orders
         match client
-            .get_user_trades_by_order(order_id, Some("desc"), true)
+            .get_user_trades_by_order(order_id, Some(SortDirection::Desc), true)
             .await
         {
             Ok(user_trades) => {
@@ -37,7 +37,10 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     info!("📋 1. FETCHING ALL BTC OPTIONS WITH TICKER DATA");
     info!("-----------------------------------------------");
 
-    let all_btc_options = match client.get_options("BTC", target_expiry).await {
+    let all_btc_options = match client
+        .get_options("BTC", target_expiry, OptionFilter::default())
+        .await
+    {
         Ok(options) => {
             info!(
                 "✅ Successfully fetched {} BTC options with ticker data",
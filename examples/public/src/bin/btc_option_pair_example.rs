@@ -34,7 +34,9 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     info!("📋 1. FETCHING BTC OPTION PAIRS FOR {}", target_expiry);
     info!("-----------------------------------------------");
     // Fetch option pairs for the target expiry
-    let option_pairs = client.get_options_pair("BTC", target_expiry).await?;
+    let option_pairs = client
+        .get_options_pair("BTC", target_expiry, OptionFilter::default())
+        .await?;
     info!(
         "✅ Successfully fetched {} option pairs",
         option_pairs.len()
@@ -93,7 +93,10 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     info!("--------------------");
 
     // Test with BTC futures
-    match client.get_expirations("BTC", "future", None).await {
+    match client
+        .get_expirations("BTC", InstrumentKind::Future, None)
+        .await
+    {
         Ok(expirations) => {
             info!("✅ Expirations for BTC futures retrieved successfully");
 
@@ -131,7 +134,10 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     }
 
     // Test with ETH options
-    match client.get_expirations("ETH", "option", None).await {
+    match client
+        .get_expirations("ETH", InstrumentKind::Option, None)
+        .await
+    {
         Ok(expirations) => {
             info!("✅ Expirations for ETH options retrieved successfully");
 
@@ -173,7 +179,10 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     }
 
     // Test with any currency and any kind to see all available expirations
-    match client.get_expirations("any", "any", None).await {
+    match client
+        .get_expirations("any", InstrumentKind::Any, None)
+        .await
+    {
         Ok(expirations) => {
             info!("✅ All expirations retrieved successfully");
 
@@ -7,6 +7,7 @@
 //! Usage: cargo run --bin funding_rate_endpoints
 
 use deribit_http::prelude::*;
+use std::time::Duration;
 use tracing::{error, info, warn};
 
 #[tokio::main]
@@ -22,8 +23,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let client = DeribitHttpClient::new();
 
     // Define time range for testing (last 24 hours from a known historical point)
-    let end_timestamp = 1569974400000u64; // Known historical timestamp
-    let start_timestamp = end_timestamp - (24 * 60 * 60 * 1000); // 24 hours before
+    let end_timestamp = TimestampMs::from_millis(1569974400000); // Known historical timestamp
+    let start_timestamp = end_timestamp.saturating_sub(Duration::from_secs(24 * 60 * 60)); // 24 hours before
 
     info!("⏰ Time range for testing:");
     info!(
@@ -41,7 +42,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Test with BTC-PERPETUAL
     match client
-        .get_funding_rate_history("BTC-PERPETUAL", start_timestamp, end_timestamp)
+        .get_funding_rate_history("BTC-PERPETUAL", (start_timestamp, end_timestamp))
         .await
     {
         Ok(funding_history) => {
@@ -80,7 +81,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Also test with ETH-PERPETUAL
     match client
-        .get_funding_rate_history("ETH-PERPETUAL", start_timestamp, end_timestamp)
+        .get_funding_rate_history("ETH-PERPETUAL", (start_timestamp, end_timestamp))
         .await
     {
         Ok(funding_history) => {
@@ -114,7 +115,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Test with BTC-PERPETUAL for the same time period
     match client
-        .get_funding_rate_value("BTC-PERPETUAL", start_timestamp, end_timestamp)
+        .get_funding_rate_value("BTC-PERPETUAL", (start_timestamp, end_timestamp))
         .await
     {
         Ok(funding_rate_value) => {
@@ -140,10 +141,10 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     }
 
     // Test with a shorter time period (last 8 hours from the reference point)
-    let short_start_timestamp = end_timestamp - (8 * 60 * 60 * 1000); // 8 hours before
+    let short_start_timestamp = end_timestamp.saturating_sub(Duration::from_secs(8 * 60 * 60)); // 8 hours before
 
     match client
-        .get_funding_rate_value("BTC-PERPETUAL", short_start_timestamp, end_timestamp)
+        .get_funding_rate_value("BTC-PERPETUAL", (short_start_timestamp, end_timestamp))
         .await
     {
         Ok(funding_rate_value) => {
@@ -162,7 +163,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Also test with ETH-PERPETUAL
     match client
-        .get_funding_rate_value("ETH-PERPETUAL", start_timestamp, end_timestamp)
+        .get_funding_rate_value("ETH-PERPETUAL", (start_timestamp, end_timestamp))
         .await
     {
         Ok(funding_rate_value) => {
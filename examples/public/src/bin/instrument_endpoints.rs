@@ -214,7 +214,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Test with BTC futures only
     match client
-        .get_instruments("BTC", Some("future"), Some(false))
+        .get_instruments("BTC", Some(InstrumentKind::Future), Some(false))
         .await
     {
         Ok(instruments) => {
@@ -246,7 +246,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Test with BTC options only
     match client
-        .get_instruments("BTC", Some("option"), Some(false))
+        .get_instruments("BTC", Some(InstrumentKind::Option), Some(false))
         .await
     {
         Ok(instruments) => {
@@ -338,7 +338,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Test with expired instruments
     match client
-        .get_instruments("BTC", Some("future"), Some(true))
+        .get_instruments("BTC", Some(InstrumentKind::Future), Some(true))
         .await
     {
         Ok(instruments) => {
@@ -110,7 +110,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     info!("----------------------------------");
 
     match client
-        .get_book_summary_by_currency("BTC", Some("future"))
+        .get_book_summary_by_currency(Currency::Btc, Some(InstrumentKind::Future))
         .await
     {
         Ok(book_summaries) => {
@@ -153,7 +153,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Test with available BTC futures (dynamically discovered)
     match client
-        .get_instruments("BTC", Some("future"), Some(false))
+        .get_instruments("BTC", Some(InstrumentKind::Future), Some(false))
         .await
     {
         Ok(instruments) => {
@@ -204,7 +204,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Test with available spot instruments (dynamically discovered)
     match client
-        .get_instruments("BTC", Some("spot"), Some(false))
+        .get_instruments("BTC", Some(InstrumentKind::Spot), Some(false))
         .await
     {
         Ok(instruments) => {
@@ -249,7 +249,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Test with available BTC options (dynamically discovered)
     match client
-        .get_instruments("BTC", Some("option"), Some(false))
+        .get_instruments("BTC", Some(InstrumentKind::Option), Some(false))
         .await
     {
         Ok(instruments) => {
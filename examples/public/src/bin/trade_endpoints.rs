@@ -30,7 +30,13 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Test with BTC trades (all instruments)
     match client
-        .get_last_trades_by_currency("BTC", None, Some(10), Some(false), Some("default"))
+        .get_last_trades_by_currency(
+            "BTC",
+            None,
+            Some(10),
+            Some(false),
+            Some(SortDirection::Default),
+        )
         .await
     {
         Ok(trades_response) => {
@@ -79,7 +85,13 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Test with BTC futures only
     match client
-        .get_last_trades_by_currency("BTC", Some("future"), Some(5), Some(false), Some("default"))
+        .get_last_trades_by_currency(
+            "BTC",
+            Some(InstrumentKind::Future),
+            Some(5),
+            Some(false),
+            Some(SortDirection::Default),
+        )
         .await
     {
         Ok(trades_response) => {
@@ -112,7 +124,13 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Test with ETH trades
     match client
-        .get_last_trades_by_currency("ETH", None, Some(3), Some(false), Some("default"))
+        .get_last_trades_by_currency(
+            "ETH",
+            None,
+            Some(3),
+            Some(false),
+            Some(SortDirection::Default),
+        )
         .await
     {
         Ok(trades_response) => {
@@ -143,11 +161,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     info!("------------------------------------------");
 
     // Get current timestamp and set a time range (last hour)
-    let end_timestamp = std::time::SystemTime::now()
-        .duration_since(std::time::UNIX_EPOCH)
-        .unwrap()
-        .as_millis() as u64;
-    let start_timestamp = end_timestamp - (60 * 60 * 1000); // 1 hour ago
+    let end_timestamp = TimestampMs::now();
+    let start_timestamp = end_timestamp.saturating_sub(std::time::Duration::from_secs(60 * 60)); // 1 hour ago
 
     info!(
         "🕐 Time range: {} to {} (last hour)",
@@ -157,12 +172,11 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     match client
         .get_last_trades_by_currency_and_time(
             "BTC",
-            start_timestamp,
-            end_timestamp,
+            (start_timestamp, end_timestamp),
             None,
             Some(10),
             Some(false),
-            Some("default"),
+            Some(SortDirection::Default),
         )
         .await
     {
@@ -205,12 +219,11 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     match client
         .get_last_trades_by_currency_and_time(
             "ETH",
-            start_timestamp,
-            end_timestamp,
-            Some("future"),
+            (start_timestamp, end_timestamp),
+            Some(InstrumentKind::Future),
             Some(5),
             Some(false),
-            Some("default"),
+            Some(SortDirection::Default),
         )
         .await
     {
@@ -358,11 +371,10 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     match client
         .get_last_trades_by_instrument_and_time(
             "BTC-PERPETUAL",
-            start_timestamp,
-            end_timestamp,
+            (start_timestamp, end_timestamp),
             Some(10),
             Some(false),
-            Some("default"),
+            Some(SortDirection::Default),
         )
         .await
     {
@@ -411,11 +423,10 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     match client
         .get_last_trades_by_instrument_and_time(
             "ETH-PERPETUAL",
-            start_timestamp,
-            end_timestamp,
+            (start_timestamp, end_timestamp),
             Some(5),
             Some(false),
-            Some("default"),
+            Some(SortDirection::Default),
         )
         .await
     {
@@ -440,7 +451,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     }
 
     // Test with wider time range (last 24 hours)
-    let start_timestamp_24h = end_timestamp - (24 * 60 * 60 * 1000); // 24 hours ago
+    let start_timestamp_24h =
+        end_timestamp.saturating_sub(std::time::Duration::from_secs(24 * 60 * 60)); // 24 hours ago
     info!(
         "🕐 Extended time range: {} to {} (last 24 hours)",
         start_timestamp_24h, end_timestamp
@@ -449,11 +461,10 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     match client
         .get_last_trades_by_instrument_and_time(
             "BTC-PERPETUAL",
-            start_timestamp_24h,
-            end_timestamp,
+            (start_timestamp_24h, end_timestamp),
             Some(5),
             Some(false),
-            Some("default"),
+            Some(SortDirection::Default),
         )
         .await
     {
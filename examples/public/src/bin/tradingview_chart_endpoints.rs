@@ -6,6 +6,7 @@
 //! Usage: cargo run --bin tradingview_chart_endpoints
 
 use deribit_http::prelude::*;
+use std::time::Duration;
 use tracing::{info, warn};
 
 #[tokio::main]
@@ -23,11 +24,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     info!("--------------------------------");
 
     // Set up time range (last 24 hours)
-    let end_timestamp = std::time::SystemTime::now()
-        .duration_since(std::time::UNIX_EPOCH)
-        .unwrap()
-        .as_millis() as u64;
-    let start_timestamp = end_timestamp - (24 * 60 * 60 * 1000); // 24 hours ago
+    let end_timestamp = TimestampMs::now();
+    let start_timestamp = end_timestamp.saturating_sub(Duration::from_secs(24 * 60 * 60)); // 24 hours ago
 
     info!(
         "🕐 Time range: {} to {} (last 24 hours)",
@@ -36,7 +34,11 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Test with BTC-PERPETUAL and 1-hour resolution
     match client
-        .get_tradingview_chart_data("BTC-PERPETUAL", start_timestamp, end_timestamp, "60")
+        .get_tradingview_chart_data(
+            "BTC-PERPETUAL",
+            (start_timestamp, end_timestamp),
+            Resolution::Hour1,
+        )
         .await
     {
         Ok(chart_data) => {
@@ -128,7 +130,11 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Test with BTC-PERPETUAL and 15-minute resolution for more granular data
     match client
-        .get_tradingview_chart_data("BTC-PERPETUAL", start_timestamp, end_timestamp, "15")
+        .get_tradingview_chart_data(
+            "BTC-PERPETUAL",
+            (start_timestamp, end_timestamp),
+            Resolution::Minute15,
+        )
         .await
     {
         Ok(chart_data) => {
@@ -167,7 +173,11 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Test with ETH-PERPETUAL and 30-minute resolution
     match client
-        .get_tradingview_chart_data("ETH-PERPETUAL", start_timestamp, end_timestamp, "30")
+        .get_tradingview_chart_data(
+            "ETH-PERPETUAL",
+            (start_timestamp, end_timestamp),
+            Resolution::Minute30,
+        )
         .await
     {
         Ok(chart_data) => {
@@ -201,14 +211,18 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     }
 
     // Test with shorter time range (last 4 hours) and higher resolution (5 minutes)
-    let short_start_timestamp = end_timestamp - (4 * 60 * 60 * 1000); // 4 hours ago
+    let short_start_timestamp = end_timestamp.saturating_sub(Duration::from_secs(4 * 60 * 60)); // 4 hours ago
     info!(
         "🕐 Short time range: {} to {} (last 4 hours)",
         short_start_timestamp, end_timestamp
     );
 
     match client
-        .get_tradingview_chart_data("BTC-PERPETUAL", short_start_timestamp, end_timestamp, "5")
+        .get_tradingview_chart_data(
+            "BTC-PERPETUAL",
+            (short_start_timestamp, end_timestamp),
+            Resolution::Minute5,
+        )
         .await
     {
         Ok(chart_data) => {
@@ -248,17 +262,17 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Test with different resolutions to demonstrate various timeframes
     let resolutions = vec![
-        ("1", "1 minute"),
-        ("3", "3 minutes"),
-        ("10", "10 minutes"),
-        ("60", "1 hour"),
+        (Resolution::Minute1, "1 minute"),
+        (Resolution::Minute3, "3 minutes"),
+        (Resolution::Minute10, "10 minutes"),
+        (Resolution::Hour1, "1 hour"),
     ];
-    let test_start = end_timestamp - (2 * 60 * 60 * 1000); // 2 hours ago
+    let test_start = end_timestamp.saturating_sub(Duration::from_secs(2 * 60 * 60)); // 2 hours ago
 
     info!("🔄 Testing different chart resolutions:");
     for (resolution, description) in &resolutions {
         match client
-            .get_tradingview_chart_data("BTC-PERPETUAL", test_start, end_timestamp, resolution)
+            .get_tradingview_chart_data("BTC-PERPETUAL", (test_start, end_timestamp), *resolution)
             .await
         {
             Ok(chart_data) => {
@@ -277,7 +291,11 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Test with a BTC future (if available)
     match client
-        .get_tradingview_chart_data("BTC-29MAR24", start_timestamp, end_timestamp, "60")
+        .get_tradingview_chart_data(
+            "BTC-29MAR24",
+            (start_timestamp, end_timestamp),
+            Resolution::Hour1,
+        )
         .await
     {
         Ok(chart_data) => {
@@ -297,7 +315,11 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Test with invalid instrument to demonstrate error handling
     match client
-        .get_tradingview_chart_data("INVALID-INSTRUMENT", start_timestamp, end_timestamp, "60")
+        .get_tradingview_chart_data(
+            "INVALID-INSTRUMENT",
+            (start_timestamp, end_timestamp),
+            Resolution::Hour1,
+        )
         .await
     {
         Ok(chart_data) => {
@@ -315,30 +337,6 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             info!("💡 This demonstrates proper error handling for invalid instrument names");
         }
     }
-
-    // Test with invalid resolution
-    match client
-        .get_tradingview_chart_data("BTC-PERPETUAL", start_timestamp, end_timestamp, "7")
-        .await
-    {
-        Ok(chart_data) => {
-            if chart_data.status == "ok" {
-                info!(
-                    "✅ Unusual resolution accepted: {} candles",
-                    chart_data.ticks.len()
-                );
-            } else {
-                info!(
-                    "⚠️ Unusual resolution returned status: {}",
-                    chart_data.status
-                );
-            }
-        }
-        Err(e) => {
-            info!("✅ Expected error for invalid resolution: {}", e);
-            info!("💡 Valid resolutions: 1, 3, 5, 10, 15, 30, 60, 120, 180, 360 minutes");
-        }
-    }
     println!();
 
     // =================================================================
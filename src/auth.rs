@@ -4,24 +4,36 @@
 //! for the Deribit REST API. It handles token management, refresh,
 //! and secure credential storage.
 
-use crate::config::HttpConfig;
-use crate::error::HttpError;
+use crate::config::{ApiCredentials, HttpConfig};
+use crate::constants::endpoints::{
+    ACCEPT_BLOCK_RFQ, ADD_BLOCK_RFQ_QUOTE, APPROVE_BLOCK_TRADE, AUTH, BUY, CLOSE_POSITION,
+    CREATE_BLOCK_RFQ, CREATE_COMBO, EDIT, EDIT_BLOCK_RFQ_QUOTE, EDIT_BY_LABEL, EXCHANGE_TOKEN,
+    EXECUTE_BLOCK_TRADE, FORK_TOKEN, INVALIDATE_BLOCK_TRADE_SIGNATURE, LOGOUT, MOVE_POSITIONS,
+    REJECT_BLOCK_TRADE, RESET_MMP, SELL, SEND_RFQ, SET_MMP_CONFIG, VERIFY_BLOCK_TRADE,
+};
+use crate::error::{HttpError, HttpErrorKind};
+use crate::logger::{REDACTED, impl_redacted_display, redact, redacted_body};
 use crate::model::types::AuthToken;
+use crate::rate_limit::api_path;
+use crate::sync_compat::Mutex;
 use crate::time_compat::{SystemTime, UNIX_EPOCH};
+use crate::transport;
 use base64::Engine;
 use hmac::{Hmac, Mac};
 use pretty_simple_display::{DebugPretty, DisplaySimple};
 use reqwest::Client;
 use serde::{Deserialize, Serialize};
-use sha2::Sha256;
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::sync::PoisonError;
 use std::time::Duration;
-use tracing::{debug, error};
+use tracing::{debug, error, warn};
 use urlencoding;
 
 type HmacSha256 = Hmac<Sha256>;
 
 /// OAuth2 authentication request
-#[derive(DebugPretty, DisplaySimple, Clone, Serialize, Deserialize)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct AuthRequest {
     /// Grant type (always "client_credentials" for Deribit)
     pub grant_type: String,
@@ -34,7 +46,7 @@ pub struct AuthRequest {
 }
 
 /// API key authentication parameters
-#[derive(DebugPretty, DisplaySimple, Clone, Serialize, Deserialize)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct ApiKeyAuth {
     /// API key
     pub key: String,
@@ -46,13 +58,291 @@ pub struct ApiKeyAuth {
     pub nonce: String,
 }
 
+/// `public/exchange_token` request: switch a session to another subject
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExchangeTokenRequest {
+    /// Refresh token of the current session
+    pub refresh_token: String,
+    /// User id of the subaccount (or main account) to switch to
+    pub subject_id: u64,
+    /// Optional scope for the new token
+    pub scope: Option<String>,
+}
+
+impl ExchangeTokenRequest {
+    /// Create a request for `subject_id` with the current session's scope
+    pub fn new(refresh_token: impl Into<String>, subject_id: u64) -> Self {
+        Self {
+            refresh_token: refresh_token.into(),
+            subject_id,
+            scope: None,
+        }
+    }
+
+    /// Request a different scope for the new token
+    pub fn with_scope(mut self, scope: impl Into<String>) -> Self {
+        self.scope = Some(scope.into());
+        self
+    }
+
+    fn query(&self) -> String {
+        let mut query = format!(
+            "refresh_token={}&subject_id={}",
+            urlencoding::encode(&self.refresh_token),
+            self.subject_id
+        );
+        if let Some(scope) = &self.scope {
+            query.push_str(&format!("&scope={}", urlencoding::encode(scope)));
+        }
+        query
+    }
+}
+
+/// `public/fork_token` request: create a new named session
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ForkTokenRequest {
+    /// Refresh token of the current session
+    pub refresh_token: String,
+    /// Name of the new session
+    pub session_name: String,
+    /// Optional scope for the new token
+    pub scope: Option<String>,
+}
+
+impl ForkTokenRequest {
+    /// Create a request for a session named `session_name`
+    pub fn new(refresh_token: impl Into<String>, session_name: impl Into<String>) -> Self {
+        Self {
+            refresh_token: refresh_token.into(),
+            session_name: session_name.into(),
+            scope: None,
+        }
+    }
+
+    /// Request a different scope for the new token
+    pub fn with_scope(mut self, scope: impl Into<String>) -> Self {
+        self.scope = Some(scope.into());
+        self
+    }
+
+    fn query(&self) -> String {
+        let mut query = format!(
+            "refresh_token={}&session_name={}",
+            urlencoding::encode(&self.refresh_token),
+            urlencoding::encode(&self.session_name)
+        );
+        if let Some(scope) = &self.scope {
+            query.push_str(&format!("&scope={}", urlencoding::encode(scope)));
+        }
+        query
+    }
+}
+
+impl_redacted_display!(
+    AuthRequest,
+    ApiKeyAuth,
+    ExchangeTokenRequest,
+    ForkTokenRequest
+);
+
+/// Permission scopes reduced between two consecutive token grants
+///
+/// Produced when a new token (after authentication, refresh, exchange or fork)
+/// grants a lower access level than the previous one for any permission scope,
+/// e.g. `trade:read_write` becoming `trade:read` or disappearing entirely.
+#[derive(DebugPretty, DisplaySimple, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScopeDowngrade {
+    /// Scope string of the previous grant
+    pub previous_scope: String,
+    /// Scope string of the new grant
+    pub current_scope: String,
+    /// Permission scopes of the previous grant that were reduced or revoked
+    pub reduced: Vec<String>,
+}
+
+impl ScopeDowngrade {
+    /// Compare two scope strings and return the downgrade, if any.
+    ///
+    /// Only permission scopes of the form `name:none`, `name:read` or
+    /// `name:read_write` are compared; informational entries such as
+    /// `mainaccount`, `expires:...` or `session:...` are ignored.
+    pub fn detect(previous: &str, current: &str) -> Option<Self> {
+        let current_levels = permission_levels(current);
+        let mut reduced: Vec<String> = permission_levels(previous)
+            .into_iter()
+            .filter(|(name, level)| current_levels.get(name).copied().unwrap_or(0) < *level)
+            .map(|(name, level)| format!("{}:{}", name, permission_level_name(level)))
+            .collect();
+
+        if reduced.is_empty() {
+            return None;
+        }
+        reduced.sort();
+
+        Some(Self {
+            previous_scope: previous.to_string(),
+            current_scope: current.to_string(),
+            reduced,
+        })
+    }
+
+    /// Check whether the given permission (e.g. `"trade"`) was reduced
+    pub fn affects(&self, permission: &str) -> bool {
+        self.reduced
+            .iter()
+            .any(|scope| scope.split_once(':').map(|(name, _)| name) == Some(permission))
+    }
+}
+
+/// Endpoints that place, amend or close orders, gated by the `trade` scope
+const TRADE_WRITE_ENDPOINTS: &[&str] = &[
+    BUY,
+    SELL,
+    EDIT,
+    EDIT_BY_LABEL,
+    CLOSE_POSITION,
+    CREATE_COMBO,
+    MOVE_POSITIONS,
+    SET_MMP_CONFIG,
+    RESET_MMP,
+    SEND_RFQ,
+];
+
+/// Endpoints that create or settle block trades, gated by the `block_trade` scope
+const BLOCK_TRADE_WRITE_ENDPOINTS: &[&str] = &[
+    APPROVE_BLOCK_TRADE,
+    EXECUTE_BLOCK_TRADE,
+    INVALIDATE_BLOCK_TRADE_SIGNATURE,
+    REJECT_BLOCK_TRADE,
+    VERIFY_BLOCK_TRADE,
+];
+
+/// Endpoints that open or quote Block RFQs, gated by the `block_rfq` scope
+const BLOCK_RFQ_WRITE_ENDPOINTS: &[&str] = &[
+    CREATE_BLOCK_RFQ,
+    ACCEPT_BLOCK_RFQ,
+    ADD_BLOCK_RFQ_QUOTE,
+    EDIT_BLOCK_RFQ_QUOTE,
+];
+
+/// Permission scope a private request writes through, if any
+///
+/// Reads (`get_*`, `list_*`, simulations), cancels and logout return `None`,
+/// so a pending [`ScopeDowngrade`] never blocks them. Withdrawals, transfers
+/// and address book changes map to `wallet`; the remaining account
+/// management endpoints map to `account`.
+pub(crate) fn write_permission(url: &str) -> Option<&'static str> {
+    let path = api_path(url);
+    let method = path.strip_prefix("/private/")?;
+    if TRADE_WRITE_ENDPOINTS.contains(&path) {
+        Some("trade")
+    } else if BLOCK_TRADE_WRITE_ENDPOINTS.contains(&path) {
+        Some("block_trade")
+    } else if BLOCK_RFQ_WRITE_ENDPOINTS.contains(&path) {
+        Some("block_rfq")
+    } else if path == LOGOUT
+        || ["get_", "list_", "cancel"]
+            .iter()
+            .any(|prefix| method.starts_with(prefix))
+        || method.contains("simulate")
+    {
+        None
+    } else if method.starts_with("withdraw")
+        || method.starts_with("submit_transfer")
+        || method.contains("address")
+        || method == "set_clearance_originator"
+    {
+        Some("wallet")
+    } else {
+        Some("account")
+    }
+}
+
+/// Parse the permission scopes of a scope string into access levels
+/// (0 = none, 1 = read, 2 = read_write)
+fn permission_levels(scope: &str) -> HashMap<String, u8> {
+    scope
+        .split_whitespace()
+        .filter_map(|entry| {
+            let (name, level) = entry.split_once(':')?;
+            let level = match level {
+                "none" => 0,
+                "read" => 1,
+                "read_write" => 2,
+                _ => return None,
+            };
+            Some((name.to_string(), level))
+        })
+        .collect()
+}
+
+fn permission_level_name(level: u8) -> &'static str {
+    match level {
+        2 => "read_write",
+        1 => "read",
+        _ => "none",
+    }
+}
+
+/// Token state guarded by the [`AuthManager`]
+#[derive(Debug, Default)]
+struct TokenState {
+    token: Option<AuthToken>,
+    expires_at: Option<SystemTime>,
+    refresh_at: Option<SystemTime>,
+    refreshing: bool,
+    scope_downgrade: Option<ScopeDowngrade>,
+    /// Digest of the credentials the session was authenticated with
+    credentials_digest: Option<[u8; 32]>,
+}
+
+/// Clears `refreshing` once a renewal ends, also when the caller waiting for
+/// it is dropped mid-flight
+struct Refreshing<'a>(&'a AuthManager);
+
+impl Drop for Refreshing<'_> {
+    fn drop(&mut self) {
+        self.0.state().refreshing = false;
+    }
+}
+
+/// How usable the current token is
+enum TokenFreshness {
+    /// Valid and not yet due for refresh; carries the authorization header
+    Fresh(String),
+    /// Still valid, but past the proactive refresh point
+    RefreshDue(String),
+    /// Missing or within [`TOKEN_EXPIRY_MARGIN`] of expiring
+    Expired,
+}
+
+/// SHA-256 of the client id and secret, to notice rotation without keeping
+/// another copy of the secret
+fn credentials_digest(credentials: &ApiCredentials) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    for part in [&credentials.client_id, &credentials.client_secret] {
+        hasher.update(part.as_deref().unwrap_or_default().as_bytes());
+        hasher.update([0]);
+    }
+    hasher.finalize().into()
+}
+
+/// Tokens expiring within this margin are treated as expired
+const TOKEN_EXPIRY_MARGIN: Duration = Duration::from_secs(60);
+
 /// Authentication manager for HTTP client
-#[derive(Debug, Clone)]
+///
+/// Shared by every clone of a [`DeribitHttpClient`](crate::DeribitHttpClient).
+/// At most one token request is in flight at a time: concurrent callers that
+/// find the token expired wait for that request instead of starting their
+/// own. Once four fifths of a token's lifetime has passed, the next caller
+/// refreshes it ahead of expiry while the others keep using the current one.
+#[derive(Debug)]
 pub struct AuthManager {
     client: Client,
     config: HttpConfig,
-    token: Option<AuthToken>,
-    token_expires_at: Option<SystemTime>,
+    state: std::sync::Mutex<TokenState>,
+    refresh: Mutex<()>,
 }
 
 impl AuthManager {
@@ -61,93 +351,164 @@ impl AuthManager {
         Self {
             client,
             config,
-            token: None,
-            token_expires_at: None,
+            state: std::sync::Mutex::new(TokenState::default()),
+            refresh: Mutex::new(()),
         }
     }
 
+    fn state(&self) -> std::sync::MutexGuard<'_, TokenState> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
     /// Authenticate using OAuth2 client credentials
-    pub async fn authenticate_oauth2(&mut self) -> Result<AuthToken, HttpError> {
-        let credentials = match self.config.credentials.clone() {
-            Some(creds) => match creds.is_valid() {
-                true => creds,
-                false => {
-                    return Err(HttpError::AuthenticationFailed(
-                        "Invalid credentials for OAuth2".to_string(),
-                    ));
-                }
-            },
-            None => {
-                return Err(HttpError::AuthenticationFailed(
-                    "No credentials configured".to_string(),
-                ));
-            }
-        };
+    ///
+    /// Takes the credentials from the configured
+    /// [`CredentialsProvider`](crate::config::CredentialsProvider), or the
+    /// static ones.
+    pub async fn authenticate_oauth2(&self) -> Result<AuthToken, HttpError> {
+        let credentials = self.current_credentials().await?;
+        self.authenticate_with(credentials).await
+    }
+
+    /// Credentials from the configured provider, else the static ones
+    async fn current_credentials(&self) -> Result<ApiCredentials, HttpError> {
+        match (&self.config.credentials_provider, &self.config.credentials) {
+            (Some(provider), _) => provider.credentials().await,
+            (None, Some(credentials)) => Ok(credentials.clone()),
+            (None, None) => Err(HttpErrorKind::AuthenticationFailed(
+                "No credentials configured".to_string(),
+            )
+            .into()),
+        }
+    }
+
+    async fn authenticate_with(&self, credentials: ApiCredentials) -> Result<AuthToken, HttpError> {
+        if !credentials.is_valid() {
+            return Err(HttpErrorKind::AuthenticationFailed(
+                "Invalid credentials for OAuth2".to_string(),
+            )
+            .into());
+        }
         let (client_id, client_secret) = credentials.get_client_credentials()?;
-        // Build URL with query parameters as per Deribit API documentation
-        let url = format!(
-            "{}/public/auth?grant_type=client_credentials&client_id={}&client_secret={}",
-            self.config.base_url,
+        // Build query parameters as per Deribit API documentation
+        let query = format!(
+            "grant_type=client_credentials&client_id={}&client_secret={}",
             urlencoding::encode(client_id.as_str()),
             urlencoding::encode(client_secret.as_str())
         );
 
-        // Debug: log the URL being used
-        debug!("Authentication URL: {}", url);
+        let token = self
+            .request_token(AUTH, &query, "OAuth2 authentication")
+            .await?;
+        self.update_token(token.clone());
+        self.state().credentials_digest = Some(credentials_digest(&credentials));
+        Ok(token)
+    }
+
+    /// Renew the session with the `refresh_token` grant
+    pub async fn refresh_oauth2(&self, refresh_token: &str) -> Result<AuthToken, HttpError> {
+        let query = format!(
+            "grant_type=refresh_token&refresh_token={}",
+            urlencoding::encode(refresh_token)
+        );
+
+        let token = self
+            .request_token(AUTH, &query, "OAuth2 authentication")
+            .await?;
+        self.update_token(token.clone());
+        Ok(token)
+    }
 
-        let response = self
+    /// Switch to another subject with `public/exchange_token`
+    ///
+    /// The returned token replaces the current one, so subsequent private
+    /// requests act on behalf of `request.subject_id`. Its scope becomes the
+    /// one later renewals are compared with for downgrades.
+    pub async fn exchange_token(
+        &self,
+        request: &ExchangeTokenRequest,
+    ) -> Result<AuthToken, HttpError> {
+        let token = self
+            .request_token(EXCHANGE_TOKEN, &request.query(), "Token exchange")
+            .await?;
+        self.replace_token(token.clone());
+        Ok(token)
+    }
+
+    /// Create a named session with `public/fork_token`
+    ///
+    /// The returned token replaces the current one, and its scope becomes the
+    /// one later renewals are compared with for downgrades.
+    pub async fn fork_token(&self, request: &ForkTokenRequest) -> Result<AuthToken, HttpError> {
+        let token = self
+            .request_token(FORK_TOKEN, &request.query(), "Token fork")
+            .await?;
+        self.replace_token(token.clone());
+        Ok(token)
+    }
+
+    /// Call a token-granting endpoint and parse the granted token
+    async fn request_token(
+        &self,
+        endpoint: &str,
+        query: &str,
+        action: &str,
+    ) -> Result<AuthToken, HttpError> {
+        let url = format!("{}{}?{}", self.config.base_url, endpoint, query);
+        // Errors quote the URL, whose query holds the secret or refresh token,
+        // and end up in the logs
+        let scrubbed = |e: &dyn std::fmt::Display| e.to_string().replace(query, REDACTED);
+
+        let request = self
             .client
             .get(&url)
             .header("Content-Type", "application/json")
-            .send()
+            .build()
+            .map_err(|e| HttpErrorKind::NetworkError(scrubbed(&e)))?;
+        let response = transport::send(&self.config, &self.client, request)
             .await
-            .map_err(|e| HttpError::NetworkError(e.to_string()))?;
+            .map_err(|e| HttpErrorKind::NetworkError(scrubbed(&e)))?;
 
         if !response.status().is_success() {
             let error_text = response
                 .text()
                 .await
                 .unwrap_or_else(|_| "Unknown error".to_string());
-            return Err(HttpError::AuthenticationFailed(format!(
-                "OAuth2 authentication failed: {}",
-                error_text
-            )));
+            return Err(HttpErrorKind::AuthenticationFailed(format!(
+                "{} failed: {}",
+                action,
+                redacted_body(&error_text)
+            ))
+            .into());
         }
 
         // Parse the JSON-RPC response directly
-        let json_response: serde_json::Value = response
+        let mut json_response: serde_json::Value = response
             .json()
             .await
-            .map_err(|e| HttpError::InvalidResponse(e.to_string()))?;
+            .map_err(|e| HttpErrorKind::InvalidResponse(scrubbed(&e)))?;
 
         // Check for JSON-RPC error
-        if let Some(error) = json_response.get("error") {
-            let _code = error.get("code").and_then(|c| c.as_i64()).unwrap_or(-1);
-            let _message = error
-                .get("message")
-                .and_then(|m| m.as_str())
-                .unwrap_or("Unknown error");
-            return Err(HttpError::AuthenticationFailed(format!(
-                "OAuth2 authentication failed: {}",
-                json_response
-            )));
+        if json_response.get("error").is_some() {
+            redact(&mut json_response);
+            return Err(HttpErrorKind::AuthenticationFailed(format!(
+                "{} failed: {}",
+                action, json_response
+            ))
+            .into());
         }
 
         // Extract the result and parse as AuthToken
         let result = json_response
             .get("result")
-            .ok_or_else(|| HttpError::InvalidResponse("No result in response".to_string()))?;
-
-        let token: AuthToken = serde_json::from_value(result.clone())
-            .map_err(|e| HttpError::InvalidResponse(format!("Failed to parse token: {}", e)))?;
-
-        // Calculate token expiration time
-        let expires_at = SystemTime::now() + Duration::from_secs(token.expires_in);
-
-        self.token = Some(token.clone());
-        self.token_expires_at = Some(expires_at);
-
-        Ok(token)
+            .ok_or_else(|| HttpErrorKind::InvalidResponse("No result in response".to_string()))?;
+
+        serde_json::from_value(result.clone()).map_err(|e| {
+            HttpError::from(HttpErrorKind::InvalidResponse(format!(
+                "Failed to parse token: {}",
+                e
+            )))
+        })
     }
 
     /// Generate API key signature for request
@@ -169,8 +530,9 @@ impl AuthManager {
             body
         );
 
-        let mut mac = HmacSha256::new_from_slice(api_secret.as_bytes())
-            .map_err(|e| HttpError::AuthenticationFailed(format!("Invalid API secret: {}", e)))?;
+        let mut mac = HmacSha256::new_from_slice(api_secret.as_bytes()).map_err(|e| {
+            HttpErrorKind::AuthenticationFailed(format!("Invalid API secret: {}", e))
+        })?;
 
         mac.update(data.as_bytes());
         let result = mac.finalize();
@@ -178,65 +540,120 @@ impl AuthManager {
         Ok(base64::engine::general_purpose::STANDARD.encode(result.into_bytes()))
     }
 
-    /// Get current authentication token
-    pub fn get_token(&self) -> Option<&AuthToken> {
-        if !self.is_token_expired() {
-            self.token.as_ref()
-        } else {
-            None
+    /// Get current authentication token, unless it is expired
+    pub fn get_token(&self) -> Option<AuthToken> {
+        match self.freshness() {
+            TokenFreshness::Expired => None,
+            _ => self.state().token.clone(),
         }
     }
 
-    /// Check if token is expired or about to expire
-    fn is_token_expired(&self) -> bool {
-        match self.token_expires_at {
-            Some(expires_at) => {
-                // Consider token expired if it expires within the next 60 seconds
-                let buffer = Duration::from_secs(60);
-                SystemTime::now() + buffer >= expires_at
-            }
-            None => true,
+    /// Classify the current token
+    fn freshness(&self) -> TokenFreshness {
+        let state = self.state();
+        let (Some(token), Some(expires_at)) = (state.token.as_ref(), state.expires_at) else {
+            return TokenFreshness::Expired;
+        };
+        let now = SystemTime::now();
+        if now + TOKEN_EXPIRY_MARGIN >= expires_at {
+            return TokenFreshness::Expired;
+        }
+        let header = format!("{} {}", token.token_type, token.access_token);
+        match state.refresh_at {
+            Some(refresh_at) if now >= refresh_at => TokenFreshness::RefreshDue(header),
+            _ => TokenFreshness::Fresh(header),
         }
     }
 
+    /// Get authorization header value
     ///
-    /// Checks whether the token is valid.
-    ///
-    /// The function determines the validity of a token based on two conditions:
-    /// 1. The token must exist (i.e., it is `Some`).
-    /// 2. The token must not be expired, as determined by the `is_token_expired` function.
-    ///
-    /// # Returns
-    /// * `true` - if the token exists and is not expired.
-    /// * `false` - if the token does not exist or is expired.
-    ///
-    fn is_token_valid(&self) -> bool {
-        match self.token {
-            Some(_) => !self.is_token_expired(),
-            None => false,
+    /// Authenticates when there is no valid token, or refreshes ahead of expiry.
+    /// Returns `None` if no token could be obtained.
+    pub async fn get_authorization_header(&self) -> Option<String> {
+        match self.freshness() {
+            TokenFreshness::Fresh(header) => Some(header),
+            TokenFreshness::RefreshDue(header) => {
+                if std::mem::replace(&mut self.state().refreshing, true) {
+                    // Another caller is already refreshing; the current token is still good
+                    return Some(header);
+                }
+                let _refreshing = Refreshing(self);
+                let _in_flight = self.refresh.lock().await;
+                match self.renew().await {
+                    Ok(token) => Some(format!("{} {}", token.token_type, token.access_token)),
+                    Err(e) => {
+                        warn!("Proactive token refresh failed: {}", e);
+                        Some(header)
+                    }
+                }
+            }
+            TokenFreshness::Expired => {
+                let _in_flight = self.refresh.lock().await;
+                // The token may have been renewed while waiting for the in-flight request
+                if let TokenFreshness::Fresh(header) | TokenFreshness::RefreshDue(header) =
+                    self.freshness()
+                {
+                    return Some(header);
+                }
+                self.state().refreshing = true;
+                let _refreshing = Refreshing(self);
+                match self.renew().await {
+                    Ok(token) => Some(format!("{} {}", token.token_type, token.access_token)),
+                    Err(e) => {
+                        error!("Failed to authenticate: {}", e);
+                        None
+                    }
+                }
+            }
         }
     }
 
-    /// Get authorization header value
-    pub async fn get_authorization_header(&mut self) -> Option<String> {
-        match self.is_token_valid() {
-            true => {
-                let token = self.token.as_ref().unwrap();
-                Some(format!("{} {}", token.token_type, token.access_token))
+    /// Obtain a new token, preferring the refresh token over client credentials
+    ///
+    /// Asks for the current credentials first: if they changed since the last
+    /// authentication, the session is replaced by one for the new credentials
+    /// instead of being refreshed. Must be called with the refresh lock held
+    /// and `refreshing` set, under a [`Refreshing`] guard.
+    async fn renew(&self) -> Result<AuthToken, HttpError> {
+        let refresh_token = self
+            .state()
+            .token
+            .as_ref()
+            .and_then(|token| token.refresh_token.clone());
+        let credentials = self.current_credentials().await;
+        let rotated = match &credentials {
+            Ok(credentials) => self
+                .state()
+                .credentials_digest
+                .is_some_and(|digest| digest != credentials_digest(credentials)),
+            Err(e) => {
+                if refresh_token.is_some() {
+                    warn!("Cannot get credentials, refreshing the session: {}", e);
+                }
+                false
             }
-            false => match self.config.credentials.as_ref() {
-                Some(credentials) => match credentials.is_valid() {
-                    true => match self.authenticate_oauth2().await {
-                        Ok(token) => Some(format!("{} {}", token.token_type, token.access_token)),
-                        Err(e) => {
-                            error!("Failed to authenticate: {}", e);
-                            None
+        };
+
+        match (refresh_token, credentials) {
+            (Some(refresh_token), credentials) if !rotated => {
+                match self.refresh_oauth2(&refresh_token).await {
+                    Ok(token) => Ok(token),
+                    Err(e) => match credentials {
+                        Ok(credentials) if credentials.is_valid() => {
+                            debug!("Refresh token rejected, re-authenticating: {}", e);
+                            self.authenticate_with(credentials).await
                         }
+                        _ => Err(e),
                     },
-                    false => None,
-                },
-                None => None,
-            },
+                }
+            }
+            (_, Ok(credentials)) => {
+                if rotated {
+                    debug!("Credentials rotated, re-authenticating");
+                }
+                self.authenticate_with(credentials).await
+            }
+            (_, Err(e)) => Err(e),
         }
     }
 
@@ -267,33 +684,65 @@ impl AuthManager {
 
     /// Updates the authentication token and its expiration time for the current instance.
     ///
-    /// This method updates the internal state of the object by setting a new authentication token
-    /// and calculating its expiration time based on the current system time and the token's
-    /// `expires_in` duration.
-    ///
-    /// # Arguments
-    ///
-    /// * `token` - An instance of `AuthToken` containing the new authentication token and its
-    ///   metadata (e.g., expiration duration).
-    ///
-    /// # Effects
-    ///
-    /// * The `self.token` field is set to the provided `token`.
-    /// * The `self.token_expires_at` field is set to the current system time plus the `expires_in`
-    ///   duration from the provided `token`.
-    ///
-    /// # Note
-    ///
-    /// Ensure that the provided `AuthToken` is valid and its `expires_in` duration is correctly
-    /// defined in seconds, as it will determine the computed expiration time.
+    /// The token expires `expires_in` seconds from now and becomes due for
+    /// proactive refresh after four fifths of that lifetime. A pending scope
+    /// downgrade is recorded if the new token grants less than the current one.
+    pub fn update_token(&self, token: AuthToken) {
+        let mut state = self.state();
+        Self::track_scope(&mut state, &token);
+        Self::store_token(&mut state, token);
+    }
+
+    /// Replace the token with one deliberately obtained for another subject or
+    /// session, making its scope the baseline for later renewals
     ///
-    /// # Panics
+    /// Unlike [`update_token`](Self::update_token), a narrower scope is not a
+    /// downgrade: it is what the caller asked for.
+    fn replace_token(&self, token: AuthToken) {
+        Self::store_token(&mut self.state(), token);
+    }
+
+    fn store_token(state: &mut TokenState, token: AuthToken) {
+        let now = SystemTime::now();
+        let lifetime = Duration::from_secs(token.expires_in);
+        state.expires_at = Some(now + lifetime);
+        state.refresh_at = Some(now + lifetime * 4 / 5);
+        state.token = Some(token);
+    }
+
+    /// Forget the current token
     ///
-    /// This function does not explicitly panic, but unexpected behavior could occur if the
-    /// system time manipulation or `Duration` calculations fail (e.g., overflow).
-    pub fn update_token(&mut self, token: AuthToken) {
-        self.token_expires_at = Some(SystemTime::now() + Duration::from_secs(token.expires_in));
-        self.token = Some(token);
+    /// The next call to [`get_authorization_header`](Self::get_authorization_header)
+    /// authenticates from scratch. A pending scope downgrade is kept.
+    pub fn clear_token(&self) {
+        let mut state = self.state();
+        state.token = None;
+        state.expires_at = None;
+        state.refresh_at = None;
+    }
+
+    /// Record a scope downgrade if the new token grants less than the current one
+    fn track_scope(state: &mut TokenState, token: &AuthToken) {
+        let Some(previous) = state.token.as_ref() else {
+            return;
+        };
+        if let Some(downgrade) = ScopeDowngrade::detect(&previous.scope, &token.scope) {
+            warn!(
+                reduced = ?downgrade.reduced,
+                "Token scope downgraded after re-authentication"
+            );
+            state.scope_downgrade = Some(downgrade);
+        }
+    }
+
+    /// Get the pending scope downgrade, if one was detected and not yet acknowledged
+    pub fn scope_downgrade(&self) -> Option<ScopeDowngrade> {
+        self.state().scope_downgrade.clone()
+    }
+
+    /// Acknowledge and clear the pending scope downgrade, returning it
+    pub fn acknowledge_scope_downgrade(&self) -> Option<ScopeDowngrade> {
+        self.state().scope_downgrade.take()
     }
 }
 
@@ -324,6 +773,55 @@ mod tests {
         assert_ne!(nonce1, nonce2);
     }
 
+    #[test]
+    fn test_scope_downgrade_detected() {
+        let downgrade = ScopeDowngrade::detect(
+            "account:read_write trade:read_write wallet:read mainaccount",
+            "account:read_write trade:read mainaccount",
+        )
+        .expect("downgrade expected");
+
+        assert_eq!(downgrade.reduced, vec!["trade:read_write", "wallet:read"]);
+        assert!(downgrade.affects("trade"));
+        assert!(downgrade.affects("wallet"));
+        assert!(!downgrade.affects("account"));
+    }
+
+    #[test]
+    fn test_scope_downgrade_ignores_upgrades_and_metadata() {
+        assert!(
+            ScopeDowngrade::detect(
+                "trade:read expires:1000 session:a",
+                "trade:read_write expires:2000 session:b"
+            )
+            .is_none()
+        );
+        assert!(ScopeDowngrade::detect("trade:read_write", "trade:read_write").is_none());
+    }
+
+    #[test]
+    fn test_write_permission_skips_reads_and_cancels() {
+        assert_eq!(
+            write_permission("https://x/api/v2/private/buy?a=1"),
+            Some("trade")
+        );
+        assert_eq!(write_permission("/private/close_position"), Some("trade"));
+        assert_eq!(write_permission("/private/withdraw"), Some("wallet"));
+        assert_eq!(
+            write_permission("/private/create_subaccount"),
+            Some("account")
+        );
+        assert_eq!(
+            write_permission("/private/execute_block_trade"),
+            Some("block_trade")
+        );
+        assert_eq!(write_permission("/private/cancel_all"), None);
+        assert_eq!(write_permission("/private/cancel_block_rfq"), None);
+        assert_eq!(write_permission("/private/get_positions"), None);
+        assert_eq!(write_permission("/private/simulate_portfolio"), None);
+        assert_eq!(write_permission("/public/get_time"), None);
+    }
+
     #[test]
     fn test_timestamp_generation() {
         let timestamp1 = AuthManager::get_timestamp();
@@ -332,4 +830,146 @@ mod tests {
 
         assert!(timestamp2 > timestamp1);
     }
+
+    #[test]
+    fn test_auth_request_debug_redacts_the_secret() {
+        let request = ForkTokenRequest::new("refresh_secret", "bot");
+        assert!(!format!("{:?}", request).contains("refresh_secret"));
+        assert!(request.to_string().contains("bot"));
+    }
+
+    /// Transport failing with the request URL in its message, like reqwest
+    struct Unreachable;
+
+    impl transport::HttpTransport for Unreachable {
+        fn send(
+            &self,
+            request: reqwest::Request,
+        ) -> crate::interceptor::HookFuture<'_, Result<reqwest::Response, transport::TransportError>>
+        {
+            let message = format!("error sending request for url ({})", request.url());
+            Box::pin(async move { Err(transport::TransportError::Connect(message)) })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_authentication_errors_do_not_leak_the_secret() {
+        let config = HttpConfig::testnet()
+            .with_oauth2("client".to_string(), "s3cr3t".to_string())
+            .with_transport(Unreachable);
+        let manager = AuthManager::new(Client::new(), config);
+
+        let error = manager.authenticate_oauth2().await.unwrap_err().to_string();
+        assert!(error.contains("public/auth"));
+        assert!(error.contains(REDACTED));
+        assert!(!error.contains("s3cr3t"));
+        assert!(!format!("{:?}", manager).contains("s3cr3t"));
+    }
+
+    fn token(access_token: &str, refresh_token: &str) -> AuthToken {
+        AuthToken {
+            access_token: access_token.to_string(),
+            token_type: "bearer".to_string(),
+            expires_in: 900,
+            refresh_token: Some(refresh_token.to_string()),
+            scope: "trade:read_write".to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_token_is_refreshed_ahead_of_expiry() {
+        let mut server = mockito::Server::new_async().await;
+        let refresh_mock = server
+            .mock("GET", "/api/v2/public/auth")
+            .match_query(mockito::Matcher::AllOf(vec![
+                mockito::Matcher::UrlEncoded("grant_type".into(), "refresh_token".into()),
+                mockito::Matcher::UrlEncoded("refresh_token".into(), "r1".into()),
+            ]))
+            .with_status(200)
+            .with_body(
+                r#"{"jsonrpc": "2.0", "id": 1, "result": {"access_token": "t2", "expires_in": 900,
+                "refresh_token": "r2", "scope": "trade:read_write", "token_type": "bearer"}}"#,
+            )
+            .expect(1)
+            .create_async()
+            .await;
+        let config = HttpConfig {
+            base_url: url::Url::parse(&format!("{}/api/v2", server.url())).unwrap(),
+            ..HttpConfig::testnet()
+        };
+        let manager = AuthManager::new(Client::new(), config);
+
+        manager.update_token(token("t1", "r1"));
+        assert_eq!(
+            manager.get_authorization_header().await.as_deref(),
+            Some("bearer t1")
+        );
+
+        manager.state().refresh_at = Some(SystemTime::now());
+        assert_eq!(
+            manager.get_authorization_header().await.as_deref(),
+            Some("bearer t2")
+        );
+        assert_eq!(
+            manager.get_authorization_header().await.as_deref(),
+            Some("bearer t2")
+        );
+        refresh_mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_failed_proactive_refresh_keeps_current_token() {
+        let mut server = mockito::Server::new_async().await;
+        let _refresh_mock = server
+            .mock("GET", "/api/v2/public/auth")
+            .match_query(mockito::Matcher::Any)
+            .with_status(400)
+            .with_body("invalid_token")
+            .create_async()
+            .await;
+        let config = HttpConfig {
+            base_url: url::Url::parse(&format!("{}/api/v2", server.url())).unwrap(),
+            ..HttpConfig::testnet()
+        };
+        let manager = AuthManager::new(Client::new(), config);
+
+        manager.update_token(token("t1", "r1"));
+        manager.state().refresh_at = Some(SystemTime::now());
+
+        assert_eq!(
+            manager.get_authorization_header().await.as_deref(),
+            Some("bearer t1")
+        );
+        assert!(!manager.state().refreshing);
+    }
+
+    #[tokio::test]
+    async fn test_dropped_proactive_refresh_clears_refreshing() {
+        let mut server = mockito::Server::new_async().await;
+        let _refresh_mock = server
+            .mock("GET", "/api/v2/public/auth")
+            .match_query(mockito::Matcher::Any)
+            .with_body_from_request(|_| {
+                std::thread::sleep(Duration::from_millis(200));
+                b"{}".to_vec()
+            })
+            .create_async()
+            .await;
+        let config = HttpConfig {
+            base_url: url::Url::parse(&format!("{}/api/v2", server.url())).unwrap(),
+            ..HttpConfig::testnet()
+        };
+        let manager = AuthManager::new(Client::new(), config);
+
+        manager.update_token(token("t1", "r1"));
+        manager.state().refresh_at = Some(SystemTime::now());
+
+        let refresh = manager.get_authorization_header();
+        assert!(
+            tokio::time::timeout(Duration::from_millis(50), refresh)
+                .await
+                .is_err()
+        );
+        assert!(!manager.state().refreshing);
+    }
 }
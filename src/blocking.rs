@@ -0,0 +1,1002 @@
+//! Blocking client for code that doesn't run an async runtime
+//!
+//! [`DeribitHttpBlockingClient`] wraps a [`DeribitHttpClient`] together with a
+//! small tokio runtime of its own, and exposes every endpoint as a plain
+//! function that returns once the request completes. It suits scripts,
+//! notebooks run through `evcxr`, and synchronous codebases.
+//!
+//! Don't call it from inside an async runtime: blocking a runtime thread on
+//! another runtime panics. Async code should use [`DeribitHttpClient`].
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! use deribit_http::blocking::DeribitHttpBlockingClient;
+//!
+//! # fn main() -> Result<(), deribit_http::HttpError> {
+//! let client = DeribitHttpBlockingClient::new();
+//! let ticker = client.get_ticker("BTC-PERPETUAL")?;
+//! println!("BTC-PERPETUAL mark price: {}", ticker.mark_price);
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::DeribitHttpClient;
+use crate::auth::ScopeDowngrade;
+use crate::config::HttpConfig;
+use crate::endpoints::{AllBookSummaries, FlattenReport};
+use crate::error::HttpError;
+use crate::health::Health;
+use crate::model::request::trade::TradesRequest;
+use crate::model::types::AuthToken;
+use crate::model::*;
+use crate::rate_limit::RateLimitCategory;
+use chrono::NaiveDate;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use std::collections::HashMap;
+use std::future::Future;
+use std::ops::RangeBounds;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::runtime::{Builder, Runtime};
+
+/// Synchronous Deribit HTTP client
+///
+/// Has the same endpoints as [`DeribitHttpClient`], each blocking the calling
+/// thread until the response arrives. Clones share the runtime, connection
+/// pool, token and rate limiter.
+#[derive(Clone)]
+pub struct DeribitHttpBlockingClient {
+    inner: DeribitHttpClient,
+    runtime: Arc<Runtime>,
+}
+
+impl std::fmt::Debug for DeribitHttpBlockingClient {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DeribitHttpBlockingClient")
+            .field("inner", &self.inner)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Default for DeribitHttpBlockingClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<DeribitHttpClient> for DeribitHttpBlockingClient {
+    fn from(client: DeribitHttpClient) -> Self {
+        Self::from_async(client)
+    }
+}
+
+impl DeribitHttpBlockingClient {
+    /// Create a client with the configuration from the environment
+    ///
+    /// See [`DeribitHttpClient::new`].
+    pub fn new() -> Self {
+        Self::from_async(DeribitHttpClient::new())
+    }
+
+    /// Create a client with custom configuration
+    ///
+    /// # Panics
+    ///
+    /// Panics under the same conditions as [`DeribitHttpClient::with_config`].
+    pub fn with_config(config: HttpConfig) -> Self {
+        Self::from_async(DeribitHttpClient::with_config(config))
+    }
+
+    /// Wrap an async client, such as one made with [`DeribitHttpClient::builder`]
+    ///
+    /// # Panics
+    ///
+    /// Panics if the runtime's worker thread cannot be started.
+    pub fn from_async(client: DeribitHttpClient) -> Self {
+        let runtime = Builder::new_multi_thread()
+            .worker_threads(1)
+            .thread_name("deribit-http-blocking")
+            .enable_all()
+            .build()
+            .expect("Failed to start the blocking client runtime");
+        Self {
+            inner: client,
+            runtime: Arc::new(runtime),
+        }
+    }
+
+    /// The async client requests go through
+    pub fn async_client(&self) -> &DeribitHttpClient {
+        &self.inner
+    }
+
+    /// Get the configuration
+    pub fn config(&self) -> &HttpConfig {
+        self.inner.config()
+    }
+
+    /// Get the base URL
+    pub fn base_url(&self) -> &str {
+        self.inner.base_url()
+    }
+
+    /// Run `future` to completion on the client's runtime
+    ///
+    /// Reaches anything this type doesn't wrap, such as pagination streams or
+    /// [`SubaccountClient`](crate::SubaccountClient) calls.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use deribit_http::blocking::DeribitHttpBlockingClient;
+    ///
+    /// # fn main() -> Result<(), deribit_http::HttpError> {
+    /// let client = DeribitHttpBlockingClient::new();
+    /// let sub = client.async_client().for_subaccount(12345);
+    /// let summary = client.block_on(sub.get_account_summary("BTC", None))?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn block_on<F: Future>(&self, future: F) -> F::Output {
+        self.runtime.block_on(future)
+    }
+
+    /// Blocking [`DeribitHttpClient::call`]
+    pub fn call<T>(&self, method: &str, params: impl Serialize) -> Result<T, HttpError>
+    where
+        T: DeserializeOwned,
+    {
+        self.block_on(self.inner.call(method, params))
+    }
+
+    /// [`DeribitHttpClient::scope_downgrade`]
+    pub fn scope_downgrade(&self) -> Option<ScopeDowngrade> {
+        self.inner.scope_downgrade()
+    }
+
+    /// [`DeribitHttpClient::acknowledge_scope_downgrade`]
+    pub fn acknowledge_scope_downgrade(&self) -> Option<ScopeDowngrade> {
+        self.inner.acknowledge_scope_downgrade()
+    }
+}
+
+/// Generate blocking wrappers that forward to the async client's methods
+macro_rules! blocking_methods {
+    ($(fn $name:ident(&self $(, $arg:ident: $ty:ty)* $(,)?) -> $ret:ty;)*) => {
+        impl DeribitHttpBlockingClient {
+            $(
+                #[doc = concat!("Blocking [`DeribitHttpClient::", stringify!($name), "`]")]
+                #[allow(clippy::too_many_arguments)]
+                pub fn $name(&self $(, $arg: $ty)*) -> $ret {
+                    self.block_on(self.inner.$name($($arg),*))
+                }
+            )*
+        }
+    };
+}
+
+blocking_methods! {
+    // Client
+    fn call_raw(
+        &self,
+        method: &str,
+        params: impl Serialize,
+    ) -> Result<serde_json::Value, HttpError>;
+    fn warm_up(&self) -> Result<(), HttpError>;
+    fn check_health(&self) -> Health;
+    fn exchange_token(
+        &self,
+        refresh_token: &str,
+        subject_id: u64,
+        scope: Option<&str>,
+    ) -> Result<AuthToken, HttpError>;
+    fn fork_token(
+        &self,
+        refresh_token: &str,
+        session_name: &str,
+        scope: Option<&str>,
+    ) -> Result<AuthToken, HttpError>;
+    fn logout(&self, invalidate_token: bool) -> Result<(), HttpError>;
+    fn remaining_credits(&self, category: RateLimitCategory) -> u64;
+    fn credit_refill_rate(&self, category: RateLimitCategory) -> u64;
+    fn refresh_instruments(&self, currency: impl AsRef<str>) -> Result<usize, HttpError>;
+
+    // Public endpoints
+    fn get_currencies(&self) -> Result<Vec<CurrencyStruct>, HttpError>;
+    fn get_index(&self, currency: impl AsRef<str>) -> Result<IndexData, HttpError>;
+    fn get_index_price(&self, index_name: &str) -> Result<IndexPriceData, HttpError>;
+    fn get_index_price_names(&self) -> Result<Vec<String>, HttpError>;
+    fn get_index_chart_data(
+        &self,
+        index_name: &str,
+        range: &str,
+    ) -> Result<Vec<IndexChartDataPoint>, HttpError>;
+    fn get_book_summary_by_currency(
+        &self,
+        currency: impl AsRef<str>,
+        kind: Option<InstrumentKind>,
+    ) -> Result<Vec<BookSummary>, HttpError>;
+    fn get_all_book_summaries(
+        &self,
+        kind: Option<InstrumentKind>,
+    ) -> Result<AllBookSummaries, HttpError>;
+    fn get_instrument(&self, instrument_name: &str) -> Result<Instrument, HttpError>;
+    fn cached_instrument(&self, instrument_name: &str) -> Result<Instrument, HttpError>;
+    fn cached_tick_size(&self, instrument_name: &str) -> Result<f64, HttpError>;
+    fn cached_contract_size(&self, instrument_name: &str) -> Result<f64, HttpError>;
+    fn cached_min_trade_amount(&self, instrument_name: &str) -> Result<f64, HttpError>;
+    fn get_book_summary_by_instrument(
+        &self,
+        instrument_name: &str,
+    ) -> Result<BookSummary, HttpError>;
+    fn get_contract_size(&self, instrument_name: &str) -> Result<f64, HttpError>;
+    fn get_server_time(&self) -> Result<u64, HttpError>;
+    fn test_connection(&self) -> Result<String, HttpError>;
+    fn get_status(&self) -> Result<StatusResponse, HttpError>;
+    fn get_apr_history(
+        &self,
+        currency: impl AsRef<str>,
+        limit: Option<u32>,
+        before: Option<i32>,
+    ) -> Result<AprHistoryResponse, HttpError>;
+    fn get_apr_history_range(
+        &self,
+        currency: impl AsRef<str>,
+        from_day: i32,
+        to_day: i32,
+    ) -> Result<Vec<AprDataPoint>, HttpError>;
+    fn get_ticker(&self, instrument_name: &str) -> Result<TickerData, HttpError>;
+    fn get_order_book(
+        &self,
+        instrument_name: &str,
+        depth: Option<u32>,
+    ) -> Result<OrderBook, HttpError>;
+    fn get_options(
+        &self,
+        currency: impl AsRef<str>,
+        expiry: &str,
+        filter: OptionFilter,
+    ) -> Result<Vec<OptionInstrument>, HttpError>;
+    fn get_options_pair(
+        &self,
+        currency: impl AsRef<str>,
+        expiry: &str,
+        filter: OptionFilter,
+    ) -> Result<HashMap<u64, OptionInstrumentPair>, HttpError>;
+    fn get_option_chain(
+        &self,
+        currency: impl AsRef<str>,
+        expiry: &str,
+        filter: OptionFilter,
+    ) -> Result<OptionChain, HttpError>;
+    fn get_instruments(
+        &self,
+        currency: impl AsRef<str>,
+        kind: Option<InstrumentKind>,
+        expired: Option<bool>,
+    ) -> Result<Vec<Instrument>, HttpError>;
+    fn get_last_trades(
+        &self,
+        instrument_name: &str,
+        count: Option<u32>,
+        include_old: Option<bool>,
+    ) -> Result<Vec<Trade>, HttpError>;
+    fn get_historical_volatility(
+        &self,
+        currency: impl AsRef<str>,
+    ) -> Result<Vec<[f64; 2]>, HttpError>;
+    fn get_mark_price_history(
+        &self,
+        instrument_name: &str,
+        range: impl Into<TimeRange>,
+    ) -> Result<Vec<MarkPriceHistoryPoint>, HttpError>;
+    fn get_supported_index_names(
+        &self,
+        index_type: Option<&str>,
+    ) -> Result<Vec<String>, HttpError>;
+    fn get_supported_index_names_extended(
+        &self,
+        index_type: Option<&str>,
+    ) -> Result<Vec<IndexNameInfo>, HttpError>;
+    fn get_trade_volumes(&self, extended: bool) -> Result<Vec<TradeVolume>, HttpError>;
+    fn get_volatility_index_data(
+        &self,
+        currency: impl AsRef<str>,
+        range: impl Into<TimeRange>,
+        resolution: VolatilityIndexResolution,
+    ) -> Result<VolatilityIndexData, HttpError>;
+    fn get_all_volatility_index_data(
+        &self,
+        currency: impl AsRef<str>,
+        range: impl Into<TimeRange>,
+        resolution: VolatilityIndexResolution,
+    ) -> Result<Vec<VolatilityIndexCandle>, HttpError>;
+    fn get_funding_chart_data(
+        &self,
+        instrument_name: &str,
+        length: FundingChartLength,
+    ) -> Result<FundingChartData, HttpError>;
+    fn get_tradingview_chart_data(
+        &self,
+        instrument_name: &str,
+        range: impl Into<TimeRange>,
+        resolution: Resolution,
+    ) -> Result<TradingViewChartData, HttpError>;
+    fn download_candles(
+        &self,
+        instrument_name: &str,
+        range: impl Into<TimeRange>,
+        resolution: Resolution,
+    ) -> Result<TradingViewChartData, HttpError>;
+    fn get_delivery_prices(
+        &self,
+        index_name: &str,
+        count: Option<u32>,
+        offset: Option<u32>,
+    ) -> Result<DeliveryPricesResponse, HttpError>;
+    fn get_all_delivery_prices(
+        &self,
+        index_name: &str,
+        range: impl RangeBounds<NaiveDate>,
+    ) -> Result<Vec<DeliveryPriceData>, HttpError>;
+    fn get_expirations(
+        &self,
+        currency: impl AsRef<str>,
+        kind: InstrumentKind,
+        currency_pair: Option<&str>,
+    ) -> Result<ExpirationsResponse, HttpError>;
+    fn get_funding_rate_history(
+        &self,
+        instrument_name: &str,
+        range: impl Into<TimeRange>,
+    ) -> Result<Vec<FundingRateData>, HttpError>;
+    fn get_funding_rate_value(
+        &self,
+        instrument_name: &str,
+        range: impl Into<TimeRange>,
+    ) -> Result<f64, HttpError>;
+    fn get_last_settlements_by_currency(
+        &self,
+        currency: impl AsRef<str>,
+        settlement_type: Option<&str>,
+        count: Option<u32>,
+        continuation: Option<&str>,
+        search_start_timestamp: Option<TimestampMs>,
+    ) -> Result<SettlementsResponse, HttpError>;
+    fn get_last_settlements_by_instrument(
+        &self,
+        instrument_name: &str,
+        settlement_type: Option<&str>,
+        count: Option<u32>,
+        continuation: Option<&str>,
+        search_start_timestamp: Option<TimestampMs>,
+    ) -> Result<SettlementsResponse, HttpError>;
+    fn get_last_trades_by_currency(
+        &self,
+        currency: impl AsRef<str>,
+        kind: Option<InstrumentKind>,
+        count: Option<u32>,
+        include_old: Option<bool>,
+        sorting: Option<SortDirection>,
+    ) -> Result<LastTradesResponse, HttpError>;
+    fn get_last_trades_by_currency_and_time(
+        &self,
+        currency: impl AsRef<str>,
+        range: impl Into<TimeRange>,
+        kind: Option<InstrumentKind>,
+        count: Option<u32>,
+        include_old: Option<bool>,
+        sorting: Option<SortDirection>,
+    ) -> Result<LastTradesResponse, HttpError>;
+    fn get_last_trades_by_instrument_and_time(
+        &self,
+        instrument_name: &str,
+        range: impl Into<TimeRange>,
+        count: Option<u32>,
+        include_old: Option<bool>,
+        sorting: Option<SortDirection>,
+    ) -> Result<LastTradesResponse, HttpError>;
+    fn get_order_book_by_instrument_id(
+        &self,
+        instrument_id: u32,
+        depth: Option<u32>,
+    ) -> Result<OrderBook, HttpError>;
+    fn get_announcements(
+        &self,
+        count: Option<u32>,
+        start_timestamp: Option<TimestampMs>,
+    ) -> Result<Vec<crate::model::Announcement>, HttpError>;
+    fn get_combo_details(&self, combo_id: &str) -> Result<crate::model::Combo, HttpError>;
+    fn get_combo_ids(
+        &self,
+        currency: impl AsRef<str>,
+        state: Option<&str>,
+    ) -> Result<Vec<String>, HttpError>;
+    fn get_combos(
+        &self,
+        currency: impl AsRef<str>,
+    ) -> Result<Vec<crate::model::Combo>, HttpError>;
+    fn get_block_rfq_trades(
+        &self,
+        currency: Option<impl AsRef<str>>,
+        count: Option<u32>,
+        continuation: Option<&str>,
+    ) -> Result<crate::model::response::BlockRfqTradesResponse, HttpError>;
+    fn get_rfqs(
+        &self,
+        currency: impl AsRef<str>,
+        kind: Option<InstrumentKind>,
+    ) -> Result<Vec<crate::model::Rfq>, HttpError>;
+
+    // Private endpoints
+    fn get_subaccounts(
+        &self,
+        with_portfolio: Option<bool>,
+    ) -> Result<Vec<Subaccount>, HttpError>;
+    fn get_subaccounts_details(
+        &self,
+        currency: impl AsRef<str>,
+        with_open_orders: Option<bool>,
+    ) -> Result<Vec<SubaccountDetails>, HttpError>;
+    fn create_subaccount(&self) -> Result<Subaccount, HttpError>;
+    fn remove_subaccount(&self, subaccount_id: u64) -> Result<String, HttpError>;
+    fn change_subaccount_name(&self, sid: u64, name: &str) -> Result<String, HttpError>;
+    fn toggle_subaccount_login(&self, sid: u64, state: &str) -> Result<String, HttpError>;
+    fn set_email_for_subaccount(&self, sid: u64, email: &str) -> Result<String, HttpError>;
+    fn set_password_for_subaccount(
+        &self,
+        sid: u64,
+        password: &str,
+    ) -> Result<String, HttpError>;
+    fn toggle_notifications_from_subaccount(
+        &self,
+        sid: u64,
+        state: bool,
+    ) -> Result<String, HttpError>;
+    fn get_transaction_log(
+        &self,
+        request: TransactionLogRequest,
+    ) -> Result<TransactionLogResponse, HttpError>;
+    fn get_transaction_log_all(
+        &self,
+        request: TransactionLogRequest,
+        max_entries: Option<usize>,
+    ) -> Result<Vec<TransactionLogEntry>, HttpError>;
+    fn get_deposits(
+        &self,
+        currency: impl AsRef<str>,
+        count: Option<u32>,
+        offset: Option<u32>,
+    ) -> Result<DepositsResponse, HttpError>;
+    fn get_withdrawals(
+        &self,
+        currency: impl AsRef<str>,
+        count: Option<u32>,
+        offset: Option<u32>,
+    ) -> Result<WithdrawalsResponse, HttpError>;
+    fn submit_transfer_to_subaccount(
+        &self,
+        currency: impl AsRef<str>,
+        amount: f64,
+        destination: u64,
+    ) -> Result<TransferResultResponse, HttpError>;
+    fn submit_transfer_to_user(
+        &self,
+        currency: impl AsRef<str>,
+        amount: f64,
+        destination: &str,
+    ) -> Result<TransferResultResponse, HttpError>;
+    fn get_transfers(
+        &self,
+        currency: impl AsRef<str>,
+        count: Option<u32>,
+        offset: Option<u32>,
+    ) -> Result<TransfersResponse, HttpError>;
+    fn cancel_transfer_by_id(
+        &self,
+        currency: impl AsRef<str>,
+        id: i64,
+    ) -> Result<InternalTransfer, HttpError>;
+    fn submit_transfer_between_subaccounts(
+        &self,
+        currency: impl AsRef<str>,
+        amount: f64,
+        destination: i64,
+        source: Option<i64>,
+    ) -> Result<InternalTransfer, HttpError>;
+    fn buy_order(&self, request: OrderRequest) -> Result<OrderResponse, HttpError>;
+    fn sell_order(&self, request: OrderRequest) -> Result<OrderResponse, HttpError>;
+    fn submit_orders(
+        &self,
+        orders: Vec<(OrderSide, OrderRequest)>,
+    ) -> Vec<Result<OrderResponse, HttpError>>;
+    fn cancel_order(&self, order_id: &str) -> Result<OrderInfoResponse, HttpError>;
+    fn cancel_all(&self) -> Result<u32, HttpError>;
+    fn cancel_all_by_currency(&self, currency: impl AsRef<str>) -> Result<u32, HttpError>;
+    fn cancel_all_by_currency_pair(&self, currency_pair: &str) -> Result<u32, HttpError>;
+    fn cancel_all_by_instrument(&self, instrument_name: &str) -> Result<u32, HttpError>;
+    fn cancel_all_by_kind_or_type(
+        &self,
+        kind: Option<InstrumentKind>,
+        order_type: Option<&str>,
+    ) -> Result<u32, HttpError>;
+    fn cancel_by_label(&self, label: &str) -> Result<u32, HttpError>;
+    fn get_account_summary(
+        &self,
+        currency: impl AsRef<str>,
+        extended: Option<bool>,
+    ) -> Result<AccountSummaryResponse, HttpError>;
+    fn get_account_summary_for_subaccount(
+        &self,
+        currency: impl AsRef<str>,
+        subaccount_id: u64,
+        extended: Option<bool>,
+    ) -> Result<AccountSummaryResponse, HttpError>;
+    fn get_account_summaries(
+        &self,
+        subaccount_id: Option<i64>,
+        extended: Option<bool>,
+    ) -> Result<AccountSummariesResponse, HttpError>;
+    fn get_portfolio_overview(&self) -> Result<PortfolioOverview, HttpError>;
+    fn get_exposure(&self, currency: impl AsRef<str>) -> Result<Exposure, HttpError>;
+    fn get_funding_pnl(
+        &self,
+        currency: impl AsRef<str>,
+        range: impl Into<TimeRange>,
+    ) -> Result<FundingReport, HttpError>;
+    fn get_positions(
+        &self,
+        currency: Option<impl AsRef<str>>,
+        kind: Option<InstrumentKind>,
+        subaccount_id: Option<i32>,
+    ) -> Result<Vec<Position>, HttpError>;
+    fn get_position(&self, instrument_name: &str) -> Result<Position, HttpError>;
+    fn edit_order(&self, request: OrderRequest) -> Result<OrderResponse, HttpError>;
+    fn edit_order_by_label(&self, request: OrderRequest) -> Result<OrderResponse, HttpError>;
+    fn close_position(
+        &self,
+        instrument_name: &str,
+        order_type: OrderType,
+        price: Option<f64>,
+    ) -> Result<OrderResponse, HttpError>;
+    fn close_position_market(&self, instrument_name: &str) -> Result<OrderResponse, HttpError>;
+    fn get_margins(
+        &self,
+        instrument_name: &str,
+        amount: f64,
+        price: f64,
+    ) -> Result<MarginsResponse, HttpError>;
+    fn get_order_margin_by_ids(&self, ids: &[&str]) -> Result<Vec<OrderMargin>, HttpError>;
+    fn get_order_state_by_label(
+        &self,
+        currency: impl AsRef<str>,
+        label: &str,
+    ) -> Result<Vec<OrderInfoResponse>, HttpError>;
+    fn get_settlement_history_by_currency(
+        &self,
+        currency: impl AsRef<str>,
+        settlement_type: Option<&str>,
+        count: Option<u32>,
+        continuation: Option<&str>,
+        search_start_timestamp: Option<TimestampMs>,
+    ) -> Result<SettlementsResponse, HttpError>;
+    fn get_settlement_history_by_instrument(
+        &self,
+        instrument_name: &str,
+        settlement_type: Option<&str>,
+        count: Option<u32>,
+        continuation: Option<&str>,
+        search_start_timestamp: Option<TimestampMs>,
+    ) -> Result<SettlementsResponse, HttpError>;
+    fn get_trigger_order_history(
+        &self,
+        currency: impl AsRef<str>,
+        instrument_name: Option<&str>,
+        count: Option<u32>,
+        continuation: Option<&str>,
+    ) -> Result<TriggerOrderHistoryResponse, HttpError>;
+    fn get_stop_order_history(
+        &self,
+        currency: impl AsRef<str>,
+        instrument_name: Option<&str>,
+        count: Option<u32>,
+        continuation: Option<&str>,
+    ) -> Result<StopOrderHistoryResponse, HttpError>;
+    fn move_positions(
+        &self,
+        currency: impl AsRef<str>,
+        source_uid: i64,
+        target_uid: i64,
+        trades: &[MovePositionTrade],
+    ) -> Result<Vec<MovePositionResult>, HttpError>;
+    fn get_mmp_config(
+        &self,
+        index_name: Option<&str>,
+        mmp_group: Option<&str>,
+        block_rfq: Option<bool>,
+    ) -> Result<Vec<MmpConfig>, HttpError>;
+    fn get_mmp_status(
+        &self,
+        index_name: Option<&str>,
+        mmp_group: Option<&str>,
+        block_rfq: Option<bool>,
+    ) -> Result<Vec<MmpStatus>, HttpError>;
+    fn set_mmp_config(&self, request: SetMmpConfigRequest) -> Result<MmpConfig, HttpError>;
+    fn reset_mmp(
+        &self,
+        index_name: &str,
+        mmp_group: Option<&str>,
+        block_rfq: Option<bool>,
+    ) -> Result<String, HttpError>;
+    fn mass_quote(&self, _quotes: MassQuoteRequest) -> Result<MassQuoteResponse, HttpError>;
+    fn get_user_trades_by_instrument(
+        &self,
+        instrument_name: &str,
+        start_seq: Option<u64>,
+        end_seq: Option<u64>,
+        count: Option<u32>,
+        include_old: Option<bool>,
+        sorting: Option<SortDirection>,
+    ) -> Result<UserTradeWithPaginationResponse, HttpError>;
+    fn cancel_quotes(&self, cancel_type: Option<&str>) -> Result<u32, HttpError>;
+    fn get_open_orders(
+        &self,
+        kind: Option<InstrumentKind>,
+        order_type: Option<&str>,
+    ) -> Result<Vec<OrderInfoResponse>, HttpError>;
+    fn get_open_orders_by_label(
+        &self,
+        label: &str,
+        currency: impl AsRef<str>,
+    ) -> Result<Vec<OrderInfoResponse>, HttpError>;
+    fn get_order_state(&self, order_id: &str) -> Result<OrderInfoResponse, HttpError>;
+    fn wait_for_order_state(
+        &self,
+        order_id: &str,
+        target_states: &[&str],
+        timeout: Duration,
+        poll_interval: Duration,
+    ) -> Result<OrderInfoResponse, HttpError>;
+    fn emergency_flatten(&self, currencies: &[impl AsRef<str>]) -> FlattenReport;
+    fn wait_for_fill(&self, order_id: &str, timeout: Duration) -> Result<OrderInfoResponse, HttpError>;
+    fn get_open_orders_by_currency(
+        &self,
+        currency: impl AsRef<str>,
+        kind: Option<InstrumentKind>,
+        order_type: Option<&str>,
+    ) -> Result<Vec<OrderInfoResponse>, HttpError>;
+    fn get_open_orders_by_instrument(
+        &self,
+        instrument_name: &str,
+        order_type: Option<&str>,
+    ) -> Result<Vec<OrderInfoResponse>, HttpError>;
+    fn get_order_history(
+        &self,
+        currency: impl AsRef<str>,
+        kind: Option<InstrumentKind>,
+        count: Option<u32>,
+        offset: Option<u32>,
+    ) -> Result<Vec<OrderInfoResponse>, HttpError>;
+    fn get_order_history_by_currency(
+        &self,
+        currency: impl AsRef<str>,
+        kind: Option<InstrumentKind>,
+        count: Option<u32>,
+        offset: Option<u32>,
+    ) -> Result<Vec<OrderInfoResponse>, HttpError>;
+    fn get_order_history_by_instrument(
+        &self,
+        instrument_name: &str,
+        count: Option<u32>,
+        offset: Option<u32>,
+    ) -> Result<Vec<OrderInfoResponse>, HttpError>;
+    fn get_user_trades_by_currency(
+        &self,
+        request: TradesRequest,
+    ) -> Result<UserTradeWithPaginationResponse, HttpError>;
+    fn get_user_trades_by_currency_and_time(
+        &self,
+        request: TradesRequest,
+    ) -> Result<UserTradeWithPaginationResponse, HttpError>;
+    fn get_user_trades_by_instrument_and_time(
+        &self,
+        instrument_name: &str,
+        range: impl Into<TimeRange>,
+        count: Option<u32>,
+        include_old: Option<bool>,
+        sorting: Option<SortDirection>,
+    ) -> Result<UserTradeWithPaginationResponse, HttpError>;
+    fn get_user_trades_by_order(
+        &self,
+        order_id: &str,
+        sorting: Option<SortDirection>,
+        historical: bool,
+    ) -> Result<Vec<UserTradeResponseByOrder>, HttpError>;
+    fn create_api_key(&self, request: CreateApiKeyRequest) -> Result<ApiKeyInfo, HttpError>;
+    fn edit_api_key(&self, request: EditApiKeyRequest) -> Result<ApiKeyInfo, HttpError>;
+    fn disable_api_key(&self, id: u64) -> Result<ApiKeyInfo, HttpError>;
+    fn enable_api_key(&self, id: u64) -> Result<ApiKeyInfo, HttpError>;
+    fn list_api_keys(&self) -> Result<Vec<ApiKeyInfo>, HttpError>;
+    fn remove_api_key(&self, id: u64) -> Result<String, HttpError>;
+    fn reset_api_key(&self, id: u64) -> Result<ApiKeyInfo, HttpError>;
+    fn change_api_key_name(&self, id: u64, name: &str) -> Result<ApiKeyInfo, HttpError>;
+    fn change_scope_in_api_key(
+        &self,
+        id: u64,
+        max_scope: &str,
+    ) -> Result<ApiKeyInfo, HttpError>;
+    fn save_address_beneficiary(
+        &self,
+        request: &crate::model::SaveAddressBeneficiaryRequest,
+    ) -> Result<crate::model::AddressBeneficiary, HttpError>;
+    fn delete_address_beneficiary(
+        &self,
+        currency: impl AsRef<str>,
+        address: &str,
+        tag: Option<&str>,
+    ) -> Result<String, HttpError>;
+    fn get_address_beneficiary(
+        &self,
+        currency: impl AsRef<str>,
+        address: &str,
+        tag: Option<&str>,
+    ) -> Result<crate::model::AddressBeneficiary, HttpError>;
+    fn list_address_beneficiaries(
+        &self,
+        request: Option<&crate::model::ListAddressBeneficiariesRequest>,
+    ) -> Result<crate::model::ListAddressBeneficiariesResponse, HttpError>;
+    fn set_clearance_originator(
+        &self,
+        deposit_id: &crate::model::DepositId,
+        originator: &crate::model::Originator,
+    ) -> Result<crate::model::ClearanceDepositResult, HttpError>;
+    fn get_access_log(
+        &self,
+        count: Option<u32>,
+        offset: Option<u32>,
+    ) -> Result<crate::model::AccessLogResponse, HttpError>;
+    fn get_user_locks(&self) -> Result<Vec<crate::model::UserLock>, HttpError>;
+    fn list_custody_accounts(
+        &self,
+        currency: impl AsRef<str>,
+    ) -> Result<Vec<crate::model::CustodyAccount>, HttpError>;
+    fn get_portfolio_margins(
+        &self,
+        currency: impl AsRef<str>,
+        simulated_positions: Option<std::collections::HashMap<String, f64>>,
+    ) -> Result<crate::model::PortfolioMarginsResponse, HttpError>;
+    fn simulate_portfolio(
+        &self,
+        request: crate::model::SimulatePortfolioRequest,
+    ) -> Result<crate::model::SimulatePortfolioResponse, HttpError>;
+    fn pme_simulate(
+        &self,
+        currency: impl AsRef<str>,
+    ) -> Result<crate::model::PmeSimulateResponse, HttpError>;
+    fn change_margin_model(
+        &self,
+        margin_model: crate::model::MarginModel,
+        user_id: Option<u64>,
+        dry_run: Option<bool>,
+    ) -> Result<crate::model::ChangeMarginModelResponse, HttpError>;
+    fn set_self_trading_config(
+        &self,
+        mode: crate::model::SelfTradingMode,
+        extended_to_subaccounts: bool,
+        block_rfq_self_match_prevention: Option<bool>,
+    ) -> Result<bool, HttpError>;
+    fn get_cancel_on_disconnect(
+        &self,
+        scope: Option<crate::model::CancelOnDisconnectScope>,
+    ) -> Result<crate::model::CancelOnDisconnect, HttpError>;
+    fn enable_cancel_on_disconnect(
+        &self,
+        scope: Option<crate::model::CancelOnDisconnectScope>,
+    ) -> Result<bool, HttpError>;
+    fn disable_cancel_on_disconnect(
+        &self,
+        scope: Option<crate::model::CancelOnDisconnectScope>,
+    ) -> Result<bool, HttpError>;
+    fn set_disabled_trading_products(
+        &self,
+        trading_products: &[crate::model::TradingProduct],
+        user_id: u64,
+    ) -> Result<bool, HttpError>;
+    fn get_new_announcements(&self) -> Result<Vec<crate::model::Announcement>, HttpError>;
+    fn set_announcement_as_read(&self, announcement_id: u64) -> Result<bool, HttpError>;
+    fn enable_affiliate_program(&self) -> Result<bool, HttpError>;
+    fn get_affiliate_program_info(
+        &self,
+    ) -> Result<crate::model::AffiliateProgramInfo, HttpError>;
+    fn set_email_language(
+        &self,
+        language: crate::model::EmailLanguage,
+    ) -> Result<bool, HttpError>;
+    fn get_email_language(&self) -> Result<String, HttpError>;
+    fn withdraw(
+        &self,
+        currency: impl AsRef<str>,
+        address: &str,
+        amount: f64,
+        priority: Option<crate::model::wallet::WithdrawalPriorityLevel>,
+    ) -> Result<crate::model::Withdrawal, HttpError>;
+    fn cancel_withdrawal(
+        &self,
+        currency: impl AsRef<str>,
+        id: u64,
+    ) -> Result<crate::model::Withdrawal, HttpError>;
+    fn create_deposit_address(
+        &self,
+        currency: impl AsRef<str>,
+    ) -> Result<crate::model::wallet::DepositAddress, HttpError>;
+    fn get_current_deposit_address(
+        &self,
+        currency: impl AsRef<str>,
+    ) -> Result<crate::model::wallet::DepositAddress, HttpError>;
+    fn add_to_address_book(
+        &self,
+        currency: impl AsRef<str>,
+        address_type: crate::model::wallet::AddressBookType,
+        address: &str,
+        label: Option<&str>,
+        tag: Option<&str>,
+    ) -> Result<crate::model::wallet::AddressBookEntry, HttpError>;
+    fn remove_from_address_book(
+        &self,
+        currency: impl AsRef<str>,
+        address_type: crate::model::wallet::AddressBookType,
+        address: &str,
+    ) -> Result<bool, HttpError>;
+    fn update_in_address_book(
+        &self,
+        request: &crate::model::request::wallet::UpdateInAddressBookRequest,
+    ) -> Result<bool, HttpError>;
+    fn get_address_book(
+        &self,
+        currency: impl AsRef<str>,
+        address_type: crate::model::wallet::AddressBookType,
+    ) -> Result<Vec<crate::model::wallet::AddressBookEntry>, HttpError>;
+    fn approve_block_trade(
+        &self,
+        timestamp: TimestampMs,
+        nonce: &str,
+        role: crate::model::block_trade::BlockTradeRole,
+    ) -> Result<bool, HttpError>;
+    fn execute_block_trade(
+        &self,
+        request: &crate::model::block_trade::ExecuteBlockTradeRequest,
+    ) -> Result<crate::model::block_trade::BlockTradeResult, HttpError>;
+    fn get_block_trade(
+        &self,
+        id: &str,
+    ) -> Result<crate::model::block_trade::BlockTrade, HttpError>;
+    fn get_block_trade_requests(
+        &self,
+        broker_code: Option<&str>,
+    ) -> Result<Vec<crate::model::block_trade::BlockTradeRequest>, HttpError>;
+    fn get_block_trades(
+        &self,
+        request: &crate::model::block_trade::GetBlockTradesRequest,
+    ) -> Result<Vec<crate::model::block_trade::BlockTrade>, HttpError>;
+    fn get_broker_trade_requests(
+        &self,
+    ) -> Result<Vec<crate::model::block_trade::BlockTradeRequest>, HttpError>;
+    fn get_broker_trades(
+        &self,
+        request: &crate::model::block_trade::GetBlockTradesRequest,
+    ) -> Result<Vec<crate::model::block_trade::BlockTrade>, HttpError>;
+    fn invalidate_block_trade_signature(&self, signature: &str) -> Result<bool, HttpError>;
+    fn reject_block_trade(
+        &self,
+        timestamp: TimestampMs,
+        nonce: &str,
+        role: crate::model::block_trade::BlockTradeRole,
+    ) -> Result<bool, HttpError>;
+    fn simulate_block_trade(
+        &self,
+        request: &crate::model::block_trade::SimulateBlockTradeRequest,
+    ) -> Result<bool, HttpError>;
+    fn verify_block_trade(
+        &self,
+        request: &crate::model::block_trade::VerifyBlockTradeRequest,
+    ) -> Result<crate::model::block_trade::BlockTradeSignature, HttpError>;
+    fn create_combo(
+        &self,
+        request: &crate::model::CreateComboRequest,
+    ) -> Result<crate::model::Combo, HttpError>;
+    fn get_leg_prices(
+        &self,
+        legs: &[crate::model::LegInput],
+        price: f64,
+    ) -> Result<crate::model::LegPricesResponse, HttpError>;
+    fn create_block_rfq(
+        &self,
+        legs: &[crate::model::response::BlockRfqLeg],
+        hedge: Option<&crate::model::response::BlockRfqHedge>,
+        label: Option<&str>,
+        makers: Option<&[&str]>,
+        non_anonymous: Option<bool>,
+        trade_allocations: Option<&[crate::model::response::BlockRfqTradeAllocation]>,
+    ) -> Result<crate::model::response::BlockRfq, HttpError>;
+    fn cancel_block_rfq(
+        &self,
+        block_rfq_id: i64,
+    ) -> Result<crate::model::response::BlockRfq, HttpError>;
+    fn accept_block_rfq(
+        &self,
+        block_rfq_id: i64,
+        legs: &[crate::model::response::BlockRfqLeg],
+        price: f64,
+        direction: crate::model::types::Direction,
+        amount: f64,
+        time_in_force: Option<crate::model::response::BlockRfqTimeInForce>,
+        hedge: Option<&crate::model::response::BlockRfqHedge>,
+    ) -> Result<crate::model::response::AcceptBlockRfqResponse, HttpError>;
+    fn get_block_rfqs(
+        &self,
+        count: Option<u32>,
+        state: Option<crate::model::response::BlockRfqState>,
+        role: Option<crate::model::response::BlockRfqRole>,
+        continuation: Option<&str>,
+        block_rfq_id: Option<i64>,
+        currency: Option<impl AsRef<str>>,
+    ) -> Result<crate::model::response::BlockRfqsResponse, HttpError>;
+    fn get_block_rfq_quotes(
+        &self,
+        block_rfq_id: Option<i64>,
+        label: Option<&str>,
+        block_rfq_quote_id: Option<i64>,
+    ) -> Result<Vec<crate::model::response::BlockRfqQuote>, HttpError>;
+    fn add_block_rfq_quote(
+        &self,
+        block_rfq_id: i64,
+        amount: f64,
+        direction: crate::model::types::Direction,
+        legs: &[crate::model::response::BlockRfqLeg],
+        label: Option<&str>,
+        hedge: Option<&crate::model::response::BlockRfqHedge>,
+        execution_instruction: Option<crate::model::response::ExecutionInstruction>,
+        expires_at: Option<i64>,
+    ) -> Result<crate::model::response::BlockRfqQuote, HttpError>;
+    fn edit_block_rfq_quote(
+        &self,
+        block_rfq_quote_id: Option<i64>,
+        block_rfq_id: Option<i64>,
+        label: Option<&str>,
+        amount: Option<f64>,
+        legs: Option<&[crate::model::response::BlockRfqLeg]>,
+        hedge: Option<&crate::model::response::BlockRfqHedge>,
+        execution_instruction: Option<crate::model::response::ExecutionInstruction>,
+        expires_at: Option<i64>,
+    ) -> Result<crate::model::response::BlockRfqQuote, HttpError>;
+    fn cancel_block_rfq_quote(
+        &self,
+        block_rfq_quote_id: Option<i64>,
+        block_rfq_id: Option<i64>,
+        label: Option<&str>,
+    ) -> Result<crate::model::response::BlockRfqQuote, HttpError>;
+    fn cancel_all_block_rfq_quotes(
+        &self,
+    ) -> Result<Vec<crate::model::response::BlockRfqQuote>, HttpError>;
+    fn send_rfq(
+        &self,
+        instrument_name: &str,
+        amount: Option<f64>,
+        side: Option<crate::model::RfqSide>,
+    ) -> Result<bool, HttpError>;
+    fn cancel_rfq(&self, instrument_name: &str) -> Result<bool, HttpError>;
+
+    // Option strategies
+    fn resolve_strategy(
+        &self,
+        strategy: &OptionStrategy,
+    ) -> Result<Vec<StrategyLeg>, HttpError>;
+    fn create_strategy_combo(&self, strategy: &OptionStrategy) -> Result<Combo, HttpError>;
+    fn place_strategy_orders(
+        &self,
+        strategy: &OptionStrategy,
+        label: &str,
+    ) -> Result<Vec<OrderResponse>, HttpError>;
+}
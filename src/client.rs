@@ -1,27 +1,97 @@
 //! HTTP client implementation for Deribit REST API
 
-use crate::auth::AuthManager;
-use crate::config::HttpConfig;
-use crate::error::HttpError;
-use crate::model::response::api_response::ApiResponse;
-use crate::model::types::AuthToken;
-use crate::rate_limit::{RateLimiter, categorize_endpoint};
-use crate::sync_compat::Mutex;
+use crate::auth::{
+    AuthManager, ExchangeTokenRequest, ForkTokenRequest, ScopeDowngrade, write_permission,
+};
+#[cfg(not(target_arch = "wasm32"))]
+use crate::coalesce::{InFlight, RequestKey};
+use crate::config::{CredentialsProvider, HttpConfig, PoolConfig, ProxyConfig, RequestOptions};
+use crate::connection::CircuitBreaker;
+use crate::constants::endpoints::{GET_SERVER_TIME, LOGOUT};
+use crate::constants::{PRODUCTION_BASE_URL, TESTNET_BASE_URL};
+use crate::error::{ErrorContext, HttpError, HttpErrorKind};
+use crate::health::Health;
+use crate::instrument_cache::InstrumentCache;
+use crate::interceptor::{Interceptors, RequestInterceptor};
+use crate::logger::{redacted_body, redacted_params, redacted_query};
+use crate::message::HttpResponseHandler;
+use crate::model::request::api_request::JsonRpcRequest;
+use crate::model::response::api_response::{ApiResponse, ResponseMeta};
+use crate::model::types::{AuthToken, RequestParams};
+#[cfg(not(target_arch = "wasm32"))]
+use crate::paper::PaperExchange;
+use crate::rate_limit::{
+    CreditLimits, RateLimitCategory, RateLimiter, categorize_endpoint, request_priority,
+};
+use crate::retry::{RetryMetadata, backoff, cool_down, retry_reason, throttled};
+use crate::sleep_compat::sleep;
+use crate::time_compat::Instant;
+#[cfg(not(target_arch = "wasm32"))]
+use crate::transport::HttpBackend;
+use crate::transport::{self, HttpTransport, TransportError};
 use reqwest::Client;
+use serde::Serialize;
 use serde::de::DeserializeOwned;
-use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::Duration;
+use tracing::Instrument;
+use url::Url;
 
 /// HTTP client for Deribit REST API
+///
+/// Each client owns exactly one `reqwest::Client`, built on construction and
+/// shared with its authentication manager. The client is a handle: cloning
+/// it bumps a reference count, and clones share the connection pool, access
+/// token, rate-limit pools and instrument cache. Build one client and move a
+/// clone into each task, e.g. one per instrument, rather than constructing a
+/// new one or borrowing it. Connections and TLS sessions are established
+/// lazily on first use; call [`warm_up`](Self::warm_up) to pay that cost up
+/// front.
 #[derive(Debug, Clone)]
 pub struct DeribitHttpClient {
+    /// Connection, credentials and limits shared by every clone
+    shared: Arc<Shared>,
+    /// Hooks run around every request
+    interceptors: Interceptors,
+    /// Where [`with_raw`](Self::with_raw) and [`with_meta`](Self::with_meta)
+    /// collect what the call's responses carried
+    capture: Option<Arc<Mutex<ResponseCapture>>>,
+    /// Per-call overrides of the configured timeout and retries
+    options: RequestOptions,
+    /// Whether requests skip the circuit breaker and the scope downgrade gate,
+    /// set only for the kill switch
+    emergency: bool,
+    /// Simulated account answering private order endpoints
+    #[cfg(not(target_arch = "wasm32"))]
+    pub(crate) paper: Option<Arc<PaperExchange>>,
+    /// Simulated server behaviour for tests
+    #[cfg(feature = "testing")]
+    pub(crate) simulation: crate::testing::Simulation,
+}
+
+/// State behind a [`DeribitHttpClient`] that its clones share
+#[derive(Debug, Clone)]
+struct Shared {
     /// HTTP client instance
     client: Client,
     /// Configuration
-    config: Arc<HttpConfig>,
+    config: HttpConfig,
     /// Rate limiter
     rate_limiter: RateLimiter,
     /// Authentication manager
-    auth_manager: Arc<Mutex<AuthManager>>,
+    auth_manager: Arc<AuthManager>,
+    /// Next JSON-RPC request id
+    request_id: Arc<AtomicU64>,
+    /// Instrument metadata shared by order helpers
+    instrument_cache: InstrumentCache,
+    /// Breaker failing requests fast during outages, if enabled
+    circuit_breaker: Option<CircuitBreaker>,
+    /// Result of the last health check
+    health: Arc<Mutex<Option<Health>>>,
+    /// Public requests in flight, joined by identical ones
+    #[cfg(not(target_arch = "wasm32"))]
+    in_flight: Arc<InFlight>,
 }
 
 impl DeribitHttpClient {
@@ -32,55 +102,153 @@ impl DeribitHttpClient {
     }
 
     /// Create a new HTTP client with custom configuration
+    ///
+    /// # Panics
+    ///
+    /// Panics if the HTTP client cannot be created, e.g. because of an invalid
+    /// user agent or default header; [`builder`](Self::builder) reports these
+    /// as errors instead.
     pub fn with_config(config: HttpConfig) -> Self {
-        let builder = Client::builder();
-
-        #[cfg(not(target_arch = "wasm32"))]
-        let builder = builder
-            .timeout(config.timeout)
-            .user_agent(&config.user_agent);
+        let client = http_client_for(&config).unwrap_or_else(|e| panic!("{}", e));
+        Self::with_http_client(config, client)
+    }
 
-        let client = builder.build().expect("Failed to create HTTP client");
+    /// Configure a client in one chain
+    ///
+    /// The builder starts from testnet with the built-in defaults and no
+    /// credentials; unlike [`new`](Self::new) it reads nothing from the
+    /// environment unless asked to with
+    /// [`from_env`](DeribitHttpClientBuilder::from_env).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use deribit_http::DeribitHttpClient;
+    /// use std::time::Duration;
+    ///
+    /// let client = DeribitHttpClient::builder()
+    ///     .production()
+    ///     .credentials("client_id", "client_secret")
+    ///     .timeout(Duration::from_secs(5))
+    ///     .max_retries(1)
+    ///     .build()
+    ///     .unwrap();
+    /// assert_eq!(client.base_url(), "https://www.deribit.com/api/v2");
+    /// assert!(client.config().has_credentials());
+    /// ```
+    #[must_use]
+    pub fn builder() -> DeribitHttpClientBuilder {
+        DeribitHttpClientBuilder::default()
+    }
 
+    /// Create a new HTTP client on top of an existing `reqwest::Client`
+    ///
+    /// Lets several clients, e.g. one per account, share a single connection
+    /// pool. The timeout, user agent, pool settings and proxy from `config`
+    /// are not applied; configure them on `client` instead. A transport set in `config` still
+    /// takes over sending, see [`HttpConfig::with_transport`].
+    pub fn with_http_client(config: HttpConfig, client: Client) -> Self {
         let auth_manager = AuthManager::new(client.clone(), config.clone());
 
         Self {
-            client,
-            config: Arc::new(config),
-            rate_limiter: RateLimiter::new(),
-            auth_manager: Arc::new(Mutex::new(auth_manager)),
+            shared: Arc::new(Shared {
+                client,
+                config,
+                rate_limiter: RateLimiter::new(),
+                auth_manager: Arc::new(auth_manager),
+                request_id: Arc::new(AtomicU64::new(1)),
+                instrument_cache: InstrumentCache::new(),
+                circuit_breaker: None,
+                health: Arc::default(),
+                #[cfg(not(target_arch = "wasm32"))]
+                in_flight: Arc::default(),
+            }),
+            interceptors: Interceptors::default(),
+            capture: None,
+            options: RequestOptions::default(),
+            emergency: false,
+            #[cfg(not(target_arch = "wasm32"))]
+            paper: None,
+            #[cfg(feature = "testing")]
+            simulation: Default::default(),
         }
     }
 
     /// Get the configuration
     pub fn config(&self) -> &HttpConfig {
-        &self.config
+        &self.shared.config
     }
 
     /// Get the base URL
     pub fn base_url(&self) -> &str {
-        self.config.base_url.as_str()
+        self.shared.config.base_url.as_str()
     }
 
     /// Get the HTTP client
     pub fn http_client(&self) -> &Client {
-        &self.client
+        &self.shared.client
+    }
+
+    /// Get the instrument metadata cache
+    pub fn instrument_cache(&self) -> &InstrumentCache {
+        &self.shared.instrument_cache
     }
 
     /// Make a rate-limited HTTP request
+    ///
+    /// On native targets, concurrent requests for the same URL share one HTTP
+    /// request: those made while it is in flight, through a client with the
+    /// same request options and interceptors, receive a copy of its response
+    /// instead of being sent.
     pub async fn make_request(&self, url: &str) -> Result<reqwest::Response, HttpError> {
-        // Determine rate limit category from URL
-        let category = categorize_endpoint(url);
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let key = RequestKey {
+                url: url.to_string(),
+                options: self.options,
+                interceptors: self.interceptors.id(),
+            };
+            let client = self.clone();
+            let owned_url = url.to_string();
+            self.shared
+                .in_flight
+                .coalesce(key, async move { client.send_request(&owned_url).await })
+                .await
+        }
+        #[cfg(target_arch = "wasm32")]
+        self.send_request(url).await
+    }
 
+    /// Make a rate-limited HTTP request, without coalescing
+    async fn send_request(&self, url: &str) -> Result<reqwest::Response, HttpError> {
         // Wait for rate limit permission
-        self.rate_limiter.wait_for_permission(category).await;
+        self.wait_for_rate_limit(url).await;
+
+        #[cfg(feature = "testing")]
+        if let Some(response) = self.simulated_response(url) {
+            return Ok(response);
+        }
 
         // Make the request
-        self.client
-            .get(url)
-            .send()
+        self.send_with_retry(url, || self.shared.client.get(url))
             .await
-            .map_err(|e| HttpError::NetworkError(e.to_string()))
+    }
+
+    /// Send one rate-limited GET, without retries or coalescing, and time its
+    /// round trip
+    ///
+    /// The time excludes the wait for rate limit permission.
+    pub(crate) async fn timed_request(
+        &self,
+        url: &str,
+    ) -> Result<(reqwest::Response, Duration), HttpError> {
+        self.wait_for_rate_limit(url).await;
+        let single = self.with_options(self.options.with_max_retries(0));
+        let started = Instant::now();
+        let response = single
+            .send_with_retry(url, || self.shared.client.get(url))
+            .await?;
+        Ok((response, started.elapsed()))
     }
 
     /// Make an authenticated HTTP GET request for private endpoints
@@ -88,35 +256,30 @@ impl DeribitHttpClient {
         &self,
         url: &str,
     ) -> Result<reqwest::Response, HttpError> {
-        // Determine rate limit category from URL
-        let category = categorize_endpoint(url);
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Some(response) = self.paper_response(url, None).await {
+            return response;
+        }
 
         // Wait for rate limit permission
-        self.rate_limiter.wait_for_permission(category).await;
+        self.wait_for_rate_limit(url).await;
 
         // Get authorization header
-        let auth_header = {
-            let mut auth_manager = self.auth_manager.lock().await;
-            auth_manager
-                .get_authorization_header()
-                .await
-                .ok_or_else(|| {
-                    HttpError::AuthenticationFailed(
-                        "No valid authentication token available.".to_string(),
-                    )
-                })?
-        };
+        let auth_header = self.authorization_header(url).await?;
 
-        // Debug: log the authorization header being used
-        tracing::debug!("Using authorization header: {}", auth_header);
+        #[cfg(feature = "testing")]
+        if let Some(response) = self.simulated_response(url) {
+            return Ok(response);
+        }
 
         // Make the authenticated request
-        self.client
-            .get(url)
-            .header("Authorization", auth_header)
-            .send()
-            .await
-            .map_err(|e| HttpError::NetworkError(e.to_string()))
+        self.send_with_retry(url, || {
+            self.shared
+                .client
+                .get(url)
+                .header("Authorization", auth_header.as_str())
+        })
+        .await
     }
 
     /// Make an authenticated HTTP POST request for private endpoints
@@ -125,41 +288,371 @@ impl DeribitHttpClient {
         url: &str,
         body: &T,
     ) -> Result<reqwest::Response, HttpError> {
-        // Determine rate limit category from URL
-        let category = categorize_endpoint(url);
+        #[cfg(not(target_arch = "wasm32"))]
+        if self.is_paper_trading() {
+            let params = serde_json::to_value(body)
+                .ok()
+                .and_then(|mut body| body.get_mut("params").map(serde_json::Value::take));
+            if let Some(response) = self.paper_response(url, params).await {
+                return response;
+            }
+        }
 
         // Wait for rate limit permission
-        self.rate_limiter.wait_for_permission(category).await;
+        self.wait_for_rate_limit(url).await;
 
         // Get authorization header
-        let auth_header = {
-            let mut auth_manager = self.auth_manager.lock().await;
-            auth_manager
-                .get_authorization_header()
-                .await
-                .ok_or_else(|| {
-                    HttpError::AuthenticationFailed(
-                        "No valid authentication token available.".to_string(),
-                    )
-                })?
-        };
+        let auth_header = self.authorization_header(url).await?;
 
-        // Debug: log the authorization header being used
-        tracing::debug!("Using authorization header: {}", auth_header);
+        #[cfg(feature = "testing")]
+        if let Some(response) = self.simulated_response(url) {
+            return Ok(response);
+        }
 
         // Make the authenticated POST request
-        self.client
-            .post(url)
-            .header("Authorization", auth_header)
-            .json(body)
-            .send()
+        self.send_with_retry(url, || {
+            self.shared
+                .client
+                .post(url)
+                .header("Authorization", auth_header.as_str())
+                .json(body)
+        })
+        .await
+    }
+
+    /// Make an authenticated JSON-RPC 2.0 POST request for private endpoints
+    ///
+    /// Sends `params` in the request body as `{"jsonrpc", "id", "method", "params"}`
+    /// to the endpoint's URL, so order details never appear in the query string.
+    pub async fn make_authenticated_rpc_request(
+        &self,
+        endpoint: &str,
+        params: &RequestParams,
+    ) -> Result<reqwest::Response, HttpError> {
+        let url = format!("{}{}", self.base_url(), endpoint);
+        let id = self.next_request_id();
+        let request = JsonRpcRequest::new(id, endpoint, params.to_json());
+        self.make_authenticated_post_request(&url, &request)
             .await
-            .map_err(|e| HttpError::NetworkError(e.to_string()))
+            .map_err(|e| {
+                e.with_context(ErrorContext::for_endpoint(endpoint).with_request_id(Some(id)))
+            })
+    }
+
+    /// Make a rate-limited, unauthenticated JSON-RPC 2.0 POST request
+    async fn make_rpc_request(
+        &self,
+        url: &str,
+        request: &JsonRpcRequest,
+    ) -> Result<reqwest::Response, HttpError> {
+        self.wait_for_rate_limit(url).await;
+
+        #[cfg(feature = "testing")]
+        if let Some(response) = self.simulated_response(url) {
+            return Ok(response);
+        }
+
+        self.send_with_retry(url, || self.shared.client.post(url).json(request))
+            .await
+    }
+
+    /// Wait until the rate limiter admits a request to `url`
+    ///
+    /// The request draws from its endpoint's credit pool and waits behind
+    /// requests of a higher [`RequestPriority`](crate::rate_limit::RequestPriority).
+    async fn wait_for_rate_limit(&self, url: &str) {
+        self.shared
+            .rate_limiter
+            .wait_for_permission_with_priority(categorize_endpoint(url), request_priority(url))
+            .await;
+    }
+
+    /// Next JSON-RPC request id
+    fn next_request_id(&self) -> u64 {
+        self.shared.request_id.fetch_add(1, Ordering::Relaxed)
+    }
+
+    /// Send a request, retrying transient failures per the [`retry`](crate::retry) policy
+    async fn send_with_retry<F>(&self, url: &str, build: F) -> Result<reqwest::Response, HttpError>
+    where
+        F: Fn() -> reqwest::RequestBuilder,
+    {
+        let idempotent = url.contains("/public/");
+        let category = categorize_endpoint(url);
+        let started = Instant::now();
+        let mut metadata = RetryMetadata::default();
+        let max_retries = self
+            .options
+            .max_retries
+            .unwrap_or(self.shared.config.max_retries);
+
+        loop {
+            metadata.attempts += 1;
+            let permit = match &self.shared.circuit_breaker {
+                Some(breaker) if !self.emergency => Some(breaker.try_acquire()?),
+                _ => None,
+            };
+            let (outcome, throttled) = throttled(self.send_intercepted(build()).await?).await;
+            if let Some(permit) = permit {
+                match &outcome {
+                    Ok(response) if !response.status().is_server_error() => permit.success(),
+                    _ => permit.failure(),
+                }
+            }
+
+            let rate_limited = cool_down(&outcome, throttled, metadata.attempts);
+            if let Some(delay) = rate_limited {
+                tracing::debug!(
+                    delay_ms = delay.as_millis() as u64,
+                    "Rate limited, cooling down"
+                );
+                self.shared.rate_limiter.cool_down(category, delay).await;
+            }
+
+            if let Some(reason) = retry_reason(&outcome, throttled, idempotent)
+                && metadata.attempts <= max_retries
+            {
+                tracing::debug!(attempt = metadata.attempts, reason = %reason, "Retrying request");
+                metadata.errors.push(reason);
+                match rate_limited {
+                    Some(_) => self.wait_for_rate_limit(url).await,
+                    None => sleep(backoff(metadata.attempts)).await,
+                }
+                continue;
+            }
+
+            metadata.elapsed = started.elapsed();
+            if let Ok(response) = &outcome {
+                tracing::Span::current().record("status", response.status().as_u16());
+            }
+            let succeeded = matches!(&outcome, Ok(response) if response.status().is_success());
+            metadata.trace(url, succeeded);
+            return outcome
+                .map_err(|e| HttpError::from(HttpErrorKind::NetworkError(e.to_string())));
+        }
+    }
+
+    /// Send one attempt through the registered interceptors
+    ///
+    /// The outer error is an interceptor veto, which is never retried; the
+    /// inner result is the transport outcome.
+    async fn send_intercepted(
+        &self,
+        builder: reqwest::RequestBuilder,
+    ) -> Result<Result<reqwest::Response, TransportError>, HttpError> {
+        let mut request = match builder.build() {
+            Ok(request) => request,
+            Err(e) => return Ok(Err(e.into())),
+        };
+        if let Some(timeout) = self.options.timeout {
+            *request.timeout_mut() = Some(timeout);
+        }
+        self.interceptors.before_request(&mut request).await?;
+
+        let outcome = transport::send(&self.shared.config, &self.shared.client, request).await;
+        if let Ok(response) = &outcome {
+            self.interceptors.after_response(response).await;
+        }
+        Ok(outcome)
+    }
+
+    /// Resolve the authorization header for a private request to `url`.
+    ///
+    /// Authenticates or refreshes the token when needed and refuses to proceed
+    /// while a downgrade of the scope the endpoint writes through is pending
+    /// acknowledgement.
+    async fn authorization_header(&self, url: &str) -> Result<String, HttpError> {
+        let header = self
+            .shared
+            .auth_manager
+            .get_authorization_header()
+            .await
+            .ok_or_else(|| {
+                HttpErrorKind::AuthenticationFailed(
+                    "No valid authentication token available.".to_string(),
+                )
+            })?;
+
+        if !self.emergency
+            && let Some(downgrade) = self.shared.auth_manager.scope_downgrade()
+            && write_permission(url).is_some_and(|permission| downgrade.affects(permission))
+        {
+            return Err(HttpErrorKind::ScopeDowngraded(downgrade).into());
+        }
+
+        Ok(header)
+    }
+
+    /// Get the pending token scope downgrade, if any
+    ///
+    /// While a downgrade is pending, private requests that write through a
+    /// reduced scope (order entry for `trade`, withdrawals for `wallet`, ...)
+    /// fail with `HttpErrorKind::ScopeDowngraded` so automated order flow halts
+    /// immediately. Reads and cancels still go through.
+    /// Reading it needs no executor.
+    pub fn scope_downgrade(&self) -> Option<ScopeDowngrade> {
+        self.shared.auth_manager.scope_downgrade()
+    }
+
+    /// Acknowledge a pending scope downgrade and resume private requests
+    pub fn acknowledge_scope_downgrade(&self) -> Option<ScopeDowngrade> {
+        self.shared.auth_manager.acknowledge_scope_downgrade()
+    }
+
+    /// Open a connection to the API ahead of the first real request
+    ///
+    /// Performs the DNS lookup, TCP connect and TLS handshake by calling
+    /// `public/get_time`, leaving the connection in the pool so latency-sensitive
+    /// requests that follow don't pay for it.
+    ///
+    /// # Errors
+    ///
+    /// Returns `HttpError` if the API cannot be reached.
+    pub async fn warm_up(&self) -> Result<(), HttpError> {
+        self.public_get::<u64>(GET_SERVER_TIME, "").await?;
+        Ok(())
+    }
+
+    /// Register a hook run around every request this client sends
+    ///
+    /// Interceptors run in registration order; clones made afterwards share
+    /// them. See [`RequestInterceptor`].
+    pub fn with_interceptor(mut self, interceptor: impl RequestInterceptor + 'static) -> Self {
+        self.interceptors = self.interceptors.with(Arc::new(interceptor));
+        self
+    }
+
+    /// Copy of this client applying `options` to every request it sends
+    ///
+    /// The copy shares the connection pool, rate limiter, session and
+    /// interceptors, so it costs no more than a clone.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use deribit_http::DeribitHttpClient;
+    /// use deribit_http::config::RequestOptions;
+    /// use std::time::Duration;
+    ///
+    /// let client = DeribitHttpClient::builder().build().unwrap();
+    /// let quoting = client.with_options(
+    ///     RequestOptions::new()
+    ///         .with_timeout(Duration::from_millis(500))
+    ///         .with_max_retries(0),
+    /// );
+    /// let history =
+    ///     client.with_options(RequestOptions::new().with_timeout(Duration::from_secs(120)));
+    /// assert_eq!(quoting.options().max_retries, Some(0));
+    /// assert_eq!(history.options().timeout, Some(Duration::from_secs(120)));
+    /// ```
+    pub fn with_options(&self, options: RequestOptions) -> Self {
+        let mut client = self.clone();
+        client.options = options;
+        client
+    }
+
+    /// Overrides applied to this client's requests
+    pub fn options(&self) -> &RequestOptions {
+        &self.options
+    }
+
+    /// Clone whose requests skip the circuit breaker and the scope downgrade
+    /// gate, so [`emergency_flatten`](Self::emergency_flatten) still reaches
+    /// the exchange when they would hold back ordinary requests
+    pub(crate) fn emergency(&self) -> Self {
+        let mut client = self.clone();
+        client.emergency = true;
+        client
     }
 
     /// Get rate limiter for advanced usage
     pub fn rate_limiter(&self) -> &RateLimiter {
-        &self.rate_limiter
+        &self.shared.rate_limiter
+    }
+
+    /// Use custom credit pools, e.g. for an account above the default tier
+    ///
+    /// Replaces the client's rate limiter; clones made afterwards share the
+    /// new pools.
+    pub fn with_credit_limits(
+        mut self,
+        non_matching_engine: CreditLimits,
+        matching_engine: CreditLimits,
+    ) -> Self {
+        Arc::make_mut(&mut self.shared).rate_limiter =
+            RateLimiter::with_limits(non_matching_engine, matching_engine);
+        self
+    }
+
+    /// Draw from the credit pools of `rate_limiter`, e.g. one shared with
+    /// other clients of the same account
+    ///
+    /// Replaces the client's rate limiter; clones made afterwards share it.
+    /// See [`DeribitHttpClientBuilder::rate_limiter`].
+    pub fn with_rate_limiter(mut self, rate_limiter: RateLimiter) -> Self {
+        Arc::make_mut(&mut self.shared).rate_limiter = rate_limiter;
+        self
+    }
+
+    /// Fail requests fast with `HttpErrorKind::CircuitOpen` while `breaker` is open
+    ///
+    /// Clones made afterwards share the breaker. See
+    /// [`CircuitBreaker`] for how it opens and closes.
+    pub fn with_circuit_breaker(mut self, breaker: CircuitBreaker) -> Self {
+        Arc::make_mut(&mut self.shared).circuit_breaker = Some(breaker);
+        self
+    }
+
+    /// Circuit breaker guarding this client's requests, if enabled
+    pub fn circuit_breaker(&self) -> Option<&CircuitBreaker> {
+        self.shared.circuit_breaker.as_ref()
+    }
+
+    /// Result of the last health check, `None` before the first one
+    ///
+    /// Updated by [`check_health`](Self::check_health) and, on native
+    /// targets, a running [`spawn_health_monitor`](Self::spawn_health_monitor).
+    pub fn health(&self) -> Option<Health> {
+        self.shared
+            .health
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    /// Keep `health` as the result of the last health check
+    pub(crate) fn record_health(&self, health: Health) {
+        *self
+            .shared
+            .health
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner) = Some(health);
+    }
+
+    /// Use `cache` for instrument metadata, e.g. one with a custom time to
+    /// live or shared with other clients
+    ///
+    /// Clones made afterwards share the new cache.
+    pub fn with_instrument_cache(mut self, cache: InstrumentCache) -> Self {
+        Arc::make_mut(&mut self.shared).instrument_cache = cache;
+        self
+    }
+
+    /// Credits currently available to this client in a pool
+    ///
+    /// Bots can use this together with [`credit_refill_rate`](Self::credit_refill_rate)
+    /// to pace themselves; requests that cannot be covered wait for the pool to refill.
+    pub async fn remaining_credits(&self, category: RateLimitCategory) -> u64 {
+        self.shared.rate_limiter.remaining_credits(category).await
+    }
+
+    /// Credits restored per second in a pool
+    pub async fn credit_refill_rate(&self, category: RateLimitCategory) -> u64 {
+        self.shared
+            .rate_limiter
+            .limits(category)
+            .await
+            .refill_per_sec
     }
 
     /// Generic helper for public GET endpoints.
@@ -186,39 +679,72 @@ impl DeribitHttpClient {
     {
         let url = format!("{}{}{}", self.base_url(), endpoint, query);
 
-        let response = self.make_request(&url).await?;
-
-        if !response.status().is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-            return Err(HttpError::RequestFailed(error_text));
-        }
-
-        let api_response: ApiResponse<T> = response
-            .json()
-            .await
-            .map_err(|e| HttpError::InvalidResponse(e.to_string()))?;
+        self.traced(endpoint, || redacted_query(query), async {
+            let response = self.make_request(&url).await?;
+            self.extract_result(response, endpoint).await
+        })
+        .await
+    }
 
-        if let Some(error) = api_response.error {
-            return Err(HttpError::RequestFailed(format!(
-                "API error: {} - {}",
-                error.code, error.message
-            )));
-        }
+    /// Generic helper for private endpoints.
+    ///
+    /// Performs a rate-limited, authenticated JSON-RPC POST request to a private
+    /// endpoint, parses the API response, and extracts the result. Handles all
+    /// standard error cases: authentication errors, network errors, HTTP errors,
+    /// API errors, and missing results.
+    ///
+    /// # Arguments
+    ///
+    /// * `endpoint` - The API endpoint path (e.g., "/private/get_account_summary")
+    /// * `params` - The method parameters
+    ///
+    /// # Type Parameters
+    ///
+    /// * `T` - The expected result type, must implement `DeserializeOwned`
+    ///
+    /// # Errors
+    ///
+    /// Returns `HttpError` if the request fails at any stage.
+    pub async fn private_post<T>(
+        &self,
+        endpoint: &str,
+        params: RequestParams,
+    ) -> Result<T, HttpError>
+    where
+        T: DeserializeOwned,
+    {
+        self.traced(endpoint, || redacted_params(params.to_json()), async {
+            let response = self
+                .make_authenticated_rpc_request(endpoint, &params)
+                .await?;
+            self.extract_result(response, endpoint).await
+        })
+        .await
+    }
 
-        api_response
-            .result
-            .ok_or_else(|| HttpError::InvalidResponse("No result in response".to_string()))
+    /// Like [`private_post`](Self::private_post), for endpoints whose result may be absent
+    pub(crate) async fn private_post_optional<T>(
+        &self,
+        endpoint: &str,
+        params: RequestParams,
+    ) -> Result<Option<T>, HttpError>
+    where
+        T: DeserializeOwned,
+    {
+        self.traced(endpoint, || redacted_params(params.to_json()), async {
+            let response = self
+                .make_authenticated_rpc_request(endpoint, &params)
+                .await?;
+            self.extract_optional_result(response, endpoint).await
+        })
+        .await
     }
 
     /// Generic helper for private GET endpoints.
     ///
-    /// Performs a rate-limited, authenticated GET request to a private endpoint,
-    /// parses the API response, and extracts the result. Handles all standard
-    /// error cases: authentication errors, network errors, HTTP errors, API errors,
-    /// and missing results.
+    /// Like [`private_post`](Self::private_post), but sends the parameters in the
+    /// query string. The crate's own endpoints use `private_post`; this remains
+    /// for callers that need GET semantics.
     ///
     /// # Arguments
     ///
@@ -238,178 +764,804 @@ impl DeribitHttpClient {
     {
         let url = format!("{}{}{}", self.base_url(), endpoint, query);
 
-        let response = self.make_authenticated_request(&url).await?;
+        self.traced(endpoint, || redacted_query(query), async {
+            let response = self.make_authenticated_request(&url).await?;
+            self.extract_result(response, endpoint).await
+        })
+        .await
+    }
 
-        if !response.status().is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-            return Err(HttpError::RequestFailed(error_text));
+    /// Call any Deribit API method and deserialize its result
+    ///
+    /// Passthrough for methods the crate does not wrap yet. `method` is the
+    /// JSON-RPC method name, e.g. `"private/get_positions"` or
+    /// `"public/get_index_price"`; a leading `/` is ignored. `public/` methods
+    /// are sent without authentication, everything else with the client's
+    /// token. `params` must serialize to a JSON object, or to `null`/`()` for
+    /// methods without parameters.
+    ///
+    /// # Errors
+    ///
+    /// Returns `HttpErrorKind::ConfigError` if `params` is not an object, and the
+    /// usual transport, HTTP and API errors otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use deribit_http::DeribitHttpClient;
+    /// use deribit_http::model::Position;
+    /// use serde_json::json;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = DeribitHttpClient::new();
+    /// let positions: Vec<Position> = client
+    ///     .call("private/get_positions", json!({ "currency": "BTC" }))
+    ///     .await?;
+    /// let time = client.call_raw("public/get_time", ()).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn call<T>(&self, method: &str, params: impl Serialize) -> Result<T, HttpError>
+    where
+        T: DeserializeOwned,
+    {
+        self.send_call(method, params, |response, endpoint| async move {
+            self.extract_result(response, &endpoint).await
+        })
+        .await
+    }
+
+    /// Call any Deribit API method and return its raw `result`
+    ///
+    /// Like [`call`](Self::call), but returns the `result` member as a
+    /// `serde_json::Value`, or `Value::Null` when the response has none.
+    pub async fn call_raw(
+        &self,
+        method: &str,
+        params: impl Serialize,
+    ) -> Result<serde_json::Value, HttpError> {
+        self.send_call(method, params, |response, endpoint| async move {
+            let result = self.extract_optional_result(response, &endpoint).await?;
+            Ok(result.unwrap_or_default())
+        })
+        .await
+    }
+
+    /// Run a call and keep the response body it was parsed from
+    ///
+    /// `call` receives a copy of this client that records the untouched body of
+    /// every response it reads, so any typed endpoint can be logged or forwarded
+    /// as Deribit sent it, including when its model fails to deserialize. When
+    /// `call` makes several requests, the last body is kept.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use deribit_http::DeribitHttpClient;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = DeribitHttpClient::new();
+    /// let ticker = client
+    ///     .with_raw(|client| async move { client.get_ticker("BTC-PERPETUAL").await })
+    ///     .await;
+    /// if let Some(raw) = &ticker.raw {
+    ///     println!("{raw}");
+    /// }
+    /// let (ticker, _raw) = ticker.into_parts()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn with_raw<T, F, Fut>(&self, call: F) -> WithRaw<T>
+    where
+        F: FnOnce(DeribitHttpClient) -> Fut,
+        Fut: Future<Output = Result<T, HttpError>>,
+    {
+        let (result, capture) = self.capturing(call).await;
+        WithRaw {
+            result,
+            raw: capture.body,
         }
+    }
 
-        let body = response.text().await.map_err(|e| {
-            HttpError::InvalidResponse(format!("Failed to read response body: {}", e))
-        })?;
+    /// Run a call and return its result with the JSON-RPC envelope metadata
+    ///
+    /// `call` receives a copy of this client that keeps the `usIn`, `usOut`,
+    /// `usDiff` and `testnet` members of the responses it parses, so server
+    /// latency and environment can be monitored per request. When `call` makes
+    /// several requests, the last response's metadata is kept; a call that
+    /// sends none gets the default (empty) metadata.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use deribit_http::DeribitHttpClient;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = DeribitHttpClient::new();
+    /// let ticker = client
+    ///     .with_meta(|client| async move { client.get_ticker("BTC-PERPETUAL").await })
+    ///     .await?;
+    /// println!(
+    ///     "{} in {:?} on testnet: {:?}",
+    ///     ticker.result.instrument_name,
+    ///     ticker.meta.server_time(),
+    ///     ticker.meta.testnet
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn with_meta<T, F, Fut>(&self, call: F) -> Result<WithMeta<T>, HttpError>
+    where
+        F: FnOnce(DeribitHttpClient) -> Fut,
+        Fut: Future<Output = Result<T, HttpError>>,
+    {
+        let (result, capture) = self.capturing(call).await;
+        Ok(WithMeta {
+            result: result?,
+            meta: capture.meta.unwrap_or_default(),
+        })
+    }
 
-        let api_response: ApiResponse<T> = serde_json::from_str(&body).map_err(|e| {
-            tracing::error!(
-                error = %e,
-                endpoint = %endpoint,
-                body_preview = %&body[..body.len().min(1000)],
-                "Failed to deserialize private API response"
-            );
-            HttpError::InvalidResponse(format!(
-                "error decoding response body: {} - Raw (first 500 chars): {}",
-                e,
-                &body[..body.len().min(500)]
-            ))
-        })?;
+    /// Run `call` on a copy of this client that records its responses
+    async fn capturing<T, F, Fut>(&self, call: F) -> (Result<T, HttpError>, ResponseCapture)
+    where
+        F: FnOnce(DeribitHttpClient) -> Fut,
+        Fut: Future<Output = Result<T, HttpError>>,
+    {
+        let capture = Arc::new(Mutex::new(ResponseCapture::default()));
+        let mut client = self.clone();
+        client.capture = Some(capture.clone());
 
-        if let Some(error) = api_response.error {
-            return Err(HttpError::RequestFailed(format!(
-                "API error: {} - {}",
-                error.code, error.message
-            )));
+        let result = call(client).await;
+        let capture = capture
+            .lock()
+            .map(|mut capture| std::mem::take(&mut *capture))
+            .unwrap_or_default();
+        (result, capture)
+    }
+
+    /// Keep `body` for [`with_raw`](Self::with_raw)
+    fn record_raw_body(&self, body: &str) {
+        if let Some(capture) = &self.capture
+            && let Ok(mut capture) = capture.lock()
+        {
+            capture.body = Some(body.to_string());
         }
+    }
 
-        api_response
-            .result
-            .ok_or_else(|| HttpError::InvalidResponse("No result in response".to_string()))
+    /// Keep `meta` for [`with_meta`](Self::with_meta)
+    fn record_meta(&self, meta: ResponseMeta) {
+        if let Some(capture) = &self.capture
+            && let Ok(mut capture) = capture.lock()
+        {
+            capture.meta = Some(meta);
+        }
     }
 
-    /// Exchange refresh token for a new access token with different subject_id
-    pub async fn exchange_token(
+    /// Send a [`call`](Self::call) inside its request span
+    ///
+    /// `handle` turns the response into the call's result.
+    async fn send_call<T, F, Fut>(
         &self,
-        refresh_token: &str,
-        subject_id: u64,
-        scope: Option<&str>,
-    ) -> Result<AuthToken, HttpError> {
-        let mut url = format!(
-            "{}/public/exchange_token?refresh_token={}&subject_id={}",
-            self.config.base_url,
-            urlencoding::encode(refresh_token),
-            subject_id
+        method: &str,
+        params: impl Serialize,
+        handle: F,
+    ) -> Result<T, HttpError>
+    where
+        F: FnOnce(reqwest::Response, String) -> Fut,
+        Fut: Future<Output = Result<T, HttpError>>,
+    {
+        let params = match serde_json::to_value(params) {
+            Ok(serde_json::Value::Null) => serde_json::Value::Object(Default::default()),
+            Ok(params @ serde_json::Value::Object(_)) => params,
+            Ok(other) => {
+                return Err(HttpErrorKind::ConfigError(format!(
+                    "Parameters for {} must be a JSON object, got {}",
+                    method, other
+                ))
+                .into());
+            }
+            Err(e) => {
+                return Err(HttpErrorKind::ConfigError(format!(
+                    "Invalid parameters for {}: {}",
+                    method, e
+                ))
+                .into());
+            }
+        };
+
+        let endpoint = format!("/{}", method.trim_start_matches('/'));
+        let url = format!("{}{}", self.base_url(), endpoint);
+        self.traced(&endpoint, || redacted_params(params.clone()), async {
+            let id = self.next_request_id();
+            let request = JsonRpcRequest::new(id, &endpoint, params.clone());
+            let response = if endpoint.starts_with("/public/") {
+                self.make_rpc_request(&url, &request).await
+            } else {
+                self.make_authenticated_post_request(&url, &request).await
+            };
+            let response = response.map_err(|e| {
+                e.with_context(ErrorContext::for_endpoint(&endpoint).with_request_id(Some(id)))
+            })?;
+            handle(response, endpoint.clone()).await
+        })
+        .await
+    }
+
+    /// Run an endpoint call inside a `deribit_http::request` span
+    ///
+    /// The span carries the JSON-RPC method, the redacted parameters, the
+    /// final HTTP status and the elapsed time, so every event logged while
+    /// the call runs (retries included) is attributed to it. `params` is only
+    /// evaluated when the span is enabled. Errors leave with `endpoint` in
+    /// their [`ErrorContext`].
+    pub(crate) async fn traced<T>(
+        &self,
+        endpoint: &str,
+        params: impl FnOnce() -> String,
+        call: impl Future<Output = Result<T, HttpError>>,
+    ) -> Result<T, HttpError> {
+        let call = async {
+            call.await
+                .map_err(|e| e.with_context(ErrorContext::for_endpoint(endpoint)))
+        };
+        let span = tracing::info_span!(
+            target: "deribit_http::request",
+            "deribit_request",
+            method = endpoint.trim_start_matches('/'),
+            params = tracing::field::Empty,
+            status = tracing::field::Empty,
+            elapsed_ms = tracing::field::Empty,
+            error = tracing::field::Empty,
         );
+        if span.is_disabled() {
+            return call.await;
+        }
+        span.record("params", params());
 
-        if let Some(scope) = scope {
-            url.push_str(&format!("&scope={}", urlencoding::encode(scope)));
+        let started = Instant::now();
+        let result = call.instrument(span.clone()).await;
+        span.record("elapsed_ms", started.elapsed().as_millis() as u64);
+        if let Err(error) = &result {
+            span.record("error", tracing::field::display(error));
         }
+        result
+    }
 
-        let response = self
-            .client
-            .get(&url)
-            .header("Content-Type", "application/json")
-            .send()
-            .await
-            .map_err(|e| HttpError::NetworkError(e.to_string()))?;
+    /// Check the status of a JSON-RPC response and extract its result
+    pub(crate) async fn extract_result<T>(
+        &self,
+        response: reqwest::Response,
+        endpoint: &str,
+    ) -> Result<T, HttpError>
+    where
+        T: DeserializeOwned,
+    {
+        let status = response.status().as_u16();
+        self.extract_optional_result(response, endpoint)
+            .await?
+            .ok_or_else(|| {
+                HttpError::from(HttpErrorKind::InvalidResponse(
+                    "No result in response".to_string(),
+                ))
+                .with_context(ErrorContext::for_endpoint(endpoint).with_status(status))
+            })
+    }
 
+    /// Check the status of a JSON-RPC response and extract its result, if any
+    async fn extract_optional_result<T>(
+        &self,
+        response: reqwest::Response,
+        endpoint: &str,
+    ) -> Result<Option<T>, HttpError>
+    where
+        T: DeserializeOwned,
+    {
+        let status = response.status().as_u16();
         if !response.status().is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-            return Err(HttpError::AuthenticationFailed(format!(
-                "Token exchange failed: {}",
-                error_text
-            )));
+            return Err(self.error_from_response(response).await);
         }
 
-        // Parse the JSON-RPC response directly
-        let json_response: serde_json::Value = response
-            .json()
+        let api_response: ApiResponse<T> = self.parse_response(response, endpoint).await?;
+
+        if let Some(error) = api_response.error {
+            let context = ErrorContext::for_endpoint(endpoint)
+                .with_status(status)
+                .with_request_id(api_response.id);
+            return Err(HttpError::from(error).with_context(context));
+        }
+
+        Ok(api_response.result)
+    }
+
+    /// Convert a non-success HTTP response into an error.
+    ///
+    /// See [`HttpResponseHandler::error_for_status`]. The error carries the
+    /// HTTP status and, when the body has one, the JSON-RPC `id` in its
+    /// [`ErrorContext`].
+    pub async fn error_from_response(&self, response: reqwest::Response) -> HttpError {
+        let status = response.status().as_u16();
+        let retry_after = response
+            .headers()
+            .get(reqwest::header::RETRY_AFTER)
+            .and_then(|value| value.to_str().ok())
+            .and_then(HttpResponseHandler::parse_retry_after);
+        let body = response
+            .text()
             .await
-            .map_err(|e| HttpError::InvalidResponse(e.to_string()))?;
-
-        // Check for JSON-RPC error
-        if let Some(_error) = json_response.get("error") {
-            return Err(HttpError::AuthenticationFailed(format!(
-                "Token exchange failed: {}",
-                json_response
-            )));
+            .unwrap_or_else(|_| "Unknown error".to_string());
+        self.record_raw_body(&body);
+        let request_id = response_id(body.as_bytes());
+        HttpResponseHandler::new()
+            .error_for_status(status, retry_after, &body)
+            .with_context(
+                ErrorContext::default()
+                    .with_status(status)
+                    .with_request_id(request_id),
+            )
+    }
+
+    /// Read and parse a JSON-RPC response body.
+    ///
+    /// With [`HttpConfig::debug_raw_responses`] enabled the body is read as text,
+    /// logged at debug level and quoted in parse errors. Otherwise it is parsed
+    /// straight from the received bytes and errors carry only the decode error.
+    /// Inside [`with_raw`](Self::with_raw) the body is also kept for the caller.
+    ///
+    /// # Arguments
+    ///
+    /// * `response` - The successful HTTP response
+    /// * `endpoint` - The API endpoint path, for logging
+    ///
+    /// # Errors
+    ///
+    /// Returns `HttpErrorKind::InvalidResponse` if the body cannot be read or parsed,
+    /// with the endpoint, HTTP status and JSON-RPC `id` in its [`ErrorContext`].
+    pub async fn parse_response<T>(
+        &self,
+        response: reqwest::Response,
+        endpoint: &str,
+    ) -> Result<ApiResponse<T>, HttpError>
+    where
+        T: DeserializeOwned,
+    {
+        let context = ErrorContext::for_endpoint(endpoint).with_status(response.status().as_u16());
+        let api_response: ApiResponse<T> = self
+            .decode_response(response, endpoint)
+            .await
+            .map_err(|e| e.with_context(context))?;
+        if self.capture.is_some() {
+            self.record_meta(api_response.meta());
         }
+        Ok(api_response)
+    }
 
-        // Extract the result and parse as AuthToken
-        let result = json_response
-            .get("result")
-            .ok_or_else(|| HttpError::InvalidResponse("No result in response".to_string()))?;
+    /// Read and deserialize a response body, see [`parse_response`](Self::parse_response)
+    async fn decode_response<T>(
+        &self,
+        response: reqwest::Response,
+        endpoint: &str,
+    ) -> Result<ApiResponse<T>, HttpError>
+    where
+        T: DeserializeOwned,
+    {
+        if !self.shared.config.debug_raw_responses {
+            let body = response.bytes().await.map_err(|e| {
+                HttpErrorKind::InvalidResponse(format!("Failed to read response body: {}", e))
+            })?;
+            if self.capture.is_some() {
+                self.record_raw_body(&String::from_utf8_lossy(&body));
+            }
+            return serde_json::from_slice(&body).map_err(|e| {
+                tracing::error!(error = %e, endpoint = %endpoint, "Failed to deserialize API response");
+                HttpError::from(HttpErrorKind::InvalidResponse(format!("error decoding response body: {}", e)))
+                    .with_context(ErrorContext::default().with_request_id(response_id(&body)))
+            });
+        }
+
+        let body = response.text().await.map_err(|e| {
+            HttpErrorKind::InvalidResponse(format!("Failed to read response body: {}", e))
+        })?;
+        self.record_raw_body(&body);
+        tracing::debug!(endpoint = %endpoint, body = %redacted_body(&body), "Raw API response");
+
+        serde_json::from_str(&body).map_err(|e| {
+            tracing::error!(
+                error = %e,
+                endpoint = %endpoint,
+                body_preview = %preview(&redacted_body(&body), 1000),
+                "Failed to deserialize API response"
+            );
+            HttpError::from(HttpErrorKind::InvalidResponse(format!(
+                "error decoding response body: {} - Raw (first 500 chars): {}",
+                e,
+                preview(&body, 500)
+            )))
+            .with_context(ErrorContext::default().with_request_id(response_id(body.as_bytes())))
+        })
+    }
 
-        let token: AuthToken = serde_json::from_value(result.clone())
-            .map_err(|e| HttpError::InvalidResponse(format!("Failed to parse token: {}", e)))?;
+    /// Exchange refresh token for a new access token with different subject_id
+    ///
+    /// The new token replaces the client's current one. See
+    /// [`AuthManager::exchange_token`].
+    pub async fn exchange_token(
+        &self,
+        refresh_token: &str,
+        subject_id: u64,
+        scope: Option<&str>,
+    ) -> Result<AuthToken, HttpError> {
+        let mut request = ExchangeTokenRequest::new(refresh_token, subject_id);
+        request.scope = scope.map(str::to_string);
+        self.shared.auth_manager.exchange_token(&request).await
+    }
 
-        // Update the stored token
-        self.auth_manager.lock().await.update_token(token.clone());
+    /// Log out of the current session
+    ///
+    /// Calls `private/logout` and forgets the client's token, so the next
+    /// private request authenticates again. With `invalidate_token`, Deribit
+    /// also revokes the session's access and refresh tokens. The local token is
+    /// cleared even if the server rejects the call; Deribit documents
+    /// `private/logout` as WebSocket-only, in which case the error is returned
+    /// and the tokens stay valid server-side until they expire.
+    pub async fn logout(&self, invalidate_token: bool) -> Result<(), HttpError> {
+        let params = RequestParams::new().add("invalidate_token", invalidate_token);
+        self.traced(LOGOUT, || redacted_params(params.to_json()), async {
+            let outcome = self.make_authenticated_rpc_request(LOGOUT, &params).await;
+            self.shared.auth_manager.clear_token();
 
-        Ok(token)
+            let response = outcome?;
+            self.extract_optional_result::<serde_json::Value>(response, LOGOUT)
+                .await?;
+            Ok(())
+        })
+        .await
     }
 
     /// Fork a token to create a new session with the same permissions
+    ///
+    /// The new token replaces the client's current one. See
+    /// [`AuthManager::fork_token`].
     pub async fn fork_token(
         &self,
         refresh_token: &str,
         session_name: &str,
         scope: Option<&str>,
     ) -> Result<AuthToken, HttpError> {
-        let mut url = format!(
-            "{}/public/fork_token?refresh_token={}&session_name={}",
-            self.config.base_url,
-            urlencoding::encode(refresh_token),
-            urlencoding::encode(session_name)
-        );
+        let mut request = ForkTokenRequest::new(refresh_token, session_name);
+        request.scope = scope.map(str::to_string);
+        self.shared.auth_manager.fork_token(&request).await
+    }
+}
 
-        if let Some(scope) = scope {
-            url.push_str(&format!("&scope={}", urlencoding::encode(scope)));
-        }
+/// Result of a [`DeribitHttpClient::with_raw`] call with its response body
+#[derive(Debug)]
+pub struct WithRaw<T> {
+    /// Deserialized result, or the error the call failed with
+    pub result: Result<T, HttpError>,
+    /// Untouched body of the last response read, if any
+    pub raw: Option<String>,
+}
 
-        let response = self
-            .client
-            .get(&url)
-            .header("Content-Type", "application/json")
-            .send()
-            .await
-            .map_err(|e| HttpError::NetworkError(e.to_string()))?;
+impl<T> WithRaw<T> {
+    /// Splits into the value and the raw body, failing as the call did
+    pub fn into_parts(self) -> Result<(T, Option<String>), HttpError> {
+        self.result.map(|value| (value, self.raw))
+    }
+}
 
-        if !response.status().is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-            return Err(HttpError::AuthenticationFailed(format!(
-                "Token fork failed: {}",
-                error_text
-            )));
+/// Result of a [`DeribitHttpClient::with_meta`] call with its envelope metadata
+#[derive(Debug, Clone)]
+pub struct WithMeta<T> {
+    /// Deserialized result
+    pub result: T,
+    /// JSON-RPC envelope metadata of the last response
+    pub meta: ResponseMeta,
+}
+
+/// What a capturing client recorded from its responses
+#[derive(Debug, Default)]
+struct ResponseCapture {
+    body: Option<String>,
+    meta: Option<ResponseMeta>,
+}
+
+/// Leading `max_len` bytes of `body`, cut at a character boundary
+fn preview(body: &str, max_len: usize) -> &str {
+    let mut end = body.len().min(max_len);
+    while !body.is_char_boundary(end) {
+        end -= 1;
+    }
+    &body[..end]
+}
+
+/// JSON-RPC `id` of a response body, if it is JSON and has one
+fn response_id(body: &[u8]) -> Option<u64> {
+    serde_json::from_slice::<serde_json::Value>(body)
+        .ok()?
+        .get("id")?
+        .as_u64()
+}
+
+impl Default for DeribitHttpClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// `reqwest::Client` applying the timeout, user agent, pool settings and
+/// proxy of `config`
+///
+/// Also checks the default headers, which are added to each request when it
+/// is sent, so invalid ones fail here rather than on every request.
+fn http_client_for(config: &HttpConfig) -> Result<Client, HttpError> {
+    config.default_header_map()?;
+    let failed = |e: reqwest::Error| {
+        HttpError::from(HttpErrorKind::ConfigError(format!(
+            "Failed to create HTTP client: {}",
+            e
+        )))
+    };
+    let builder = Client::builder();
+
+    #[cfg(not(target_arch = "wasm32"))]
+    let builder = {
+        let mut builder = builder
+            .timeout(config.timeout)
+            .user_agent(&config.user_agent);
+        let pool = &config.pool;
+        if let Some(max) = pool.max_idle_per_host {
+            builder = builder.pool_max_idle_per_host(max);
+        }
+        if let Some(timeout) = pool.idle_timeout {
+            builder = builder.pool_idle_timeout(timeout);
+        }
+        if let Some(interval) = pool.tcp_keepalive {
+            builder = builder.tcp_keepalive(interval);
         }
+        if let Some(timeout) = pool.connect_timeout {
+            builder = builder.connect_timeout(timeout);
+        }
+        // The configured proxy replaces reqwest's own reading of the proxy
+        // environment variables
+        match &config.proxy {
+            Some(proxy) => builder.proxy(proxy.to_reqwest().map_err(failed)?),
+            None => builder.no_proxy(),
+        }
+    };
 
-        // Parse the JSON-RPC response directly
-        let json_response: serde_json::Value = response
-            .json()
-            .await
-            .map_err(|e| HttpError::InvalidResponse(e.to_string()))?;
-
-        // Check for JSON-RPC error
-        if let Some(_error) = json_response.get("error") {
-            return Err(HttpError::AuthenticationFailed(format!(
-                "Token fork failed: {}",
-                json_response
-            )));
+    builder.build().map_err(failed)
+}
+
+/// Builder for [`DeribitHttpClient`], created with [`DeribitHttpClient::builder`]
+///
+/// Each setter replaces the value set before it, except
+/// [`interceptor`](Self::interceptor), which adds to the list.
+#[derive(Debug)]
+pub struct DeribitHttpClientBuilder {
+    config: HttpConfig,
+    http_client: Option<Client>,
+    rate_limiter: Option<RateLimiter>,
+    instrument_cache: Option<InstrumentCache>,
+    circuit_breaker: Option<CircuitBreaker>,
+    interceptors: Interceptors,
+}
+
+impl Default for DeribitHttpClientBuilder {
+    fn default() -> Self {
+        Self {
+            config: HttpConfig::with_defaults(
+                Url::parse(TESTNET_BASE_URL).expect("Invalid testnet URL"),
+                true,
+            ),
+            http_client: None,
+            rate_limiter: None,
+            instrument_cache: None,
+            circuit_breaker: None,
+            interceptors: Interceptors::default(),
         }
+    }
+}
 
-        // Extract the result and parse as AuthToken
-        let result = json_response
-            .get("result")
-            .ok_or_else(|| HttpError::InvalidResponse("No result in response".to_string()))?;
+impl DeribitHttpClientBuilder {
+    /// Use Deribit testnet, the default
+    pub fn testnet(mut self) -> Self {
+        self.config.base_url = Url::parse(TESTNET_BASE_URL).expect("Invalid testnet URL");
+        self.config.testnet = true;
+        self
+    }
 
-        let token: AuthToken = serde_json::from_value(result.clone())
-            .map_err(|e| HttpError::InvalidResponse(format!("Failed to parse token: {}", e)))?;
+    /// Use Deribit production
+    pub fn production(mut self) -> Self {
+        self.config.base_url = Url::parse(PRODUCTION_BASE_URL).expect("Invalid production URL");
+        self.config.testnet = false;
+        self
+    }
 
-        self.auth_manager.lock().await.update_token(token.clone());
+    /// Send requests to `base_url`, e.g. a proxy or a mock server
+    ///
+    /// The URL includes the API version path, as in [`TESTNET_BASE_URL`].
+    pub fn base_url(mut self, base_url: Url) -> Self {
+        self.config.base_url = base_url;
+        self
+    }
 
-        Ok(token)
+    /// Start from `config` instead of the defaults
+    ///
+    /// Replaces everything set on the configuration so far, so call it first.
+    pub fn config(mut self, config: HttpConfig) -> Self {
+        self.config = config;
+        self
     }
-}
 
-impl Default for DeribitHttpClient {
-    fn default() -> Self {
-        Self::new()
+    /// Start from the configuration in the environment, as [`DeribitHttpClient::new`] does
+    ///
+    /// Reads `.env` and the `DERIBIT_*` variables described in
+    /// [`HttpConfig`]. Replaces everything set on the configuration so far,
+    /// so call it first.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn from_env(self) -> Self {
+        self.config(HttpConfig::default())
+    }
+
+    /// Authenticate with OAuth2 client credentials
+    pub fn credentials(
+        mut self,
+        client_id: impl Into<String>,
+        client_secret: impl Into<String>,
+    ) -> Self {
+        self.config = self
+            .config
+            .with_oauth2(client_id.into(), client_secret.into());
+        self
+    }
+
+    /// Authenticate with credentials read from `provider` on every renewal
+    pub fn credentials_provider(mut self, provider: impl CredentialsProvider + 'static) -> Self {
+        self.config = self.config.with_credentials_provider(provider);
+        self
+    }
+
+    /// Timeout of each request
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.config.timeout = timeout;
+        self
+    }
+
+    /// Retries of a failed request, see [`retry`](crate::retry) for which are retried
+    pub fn max_retries(mut self, max_retries: u32) -> Self {
+        self.config.max_retries = max_retries;
+        self
+    }
+
+    /// `User-Agent` header of every request
+    pub fn user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        self.config.user_agent = user_agent.into();
+        self
+    }
+
+    /// Tune the connection pool, see [`PoolConfig`]
+    pub fn pool(mut self, pool: PoolConfig) -> Self {
+        self.config.pool = pool;
+        self
+    }
+
+    /// Add `name: value` to every request, see [`HttpConfig::with_header`]
+    pub fn header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.config = self.config.with_header(name, value);
+        self
+    }
+
+    /// Connect through `proxy`
+    pub fn proxy(mut self, proxy: ProxyConfig) -> Self {
+        self.config.proxy = Some(proxy);
+        self
+    }
+
+    /// Share `rate_limiter`, and its credit pools, with other clients
+    ///
+    /// Deribit's credit limits apply to the whole account, so clients of one
+    /// account built with clones of the same limiter keep their combined
+    /// request rate within them: every request, and every 429 cool-down,
+    /// draws from the shared pools.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use deribit_http::DeribitHttpClient;
+    /// use deribit_http::rate_limit::RateLimiter;
+    ///
+    /// let limiter = RateLimiter::new();
+    /// let market_maker = DeribitHttpClient::builder()
+    ///     .rate_limiter(limiter.clone())
+    ///     .build()?;
+    /// let hedger = DeribitHttpClient::builder()
+    ///     .rate_limiter(limiter.clone())
+    ///     .build()?;
+    /// assert!(market_maker.rate_limiter().shares_pools_with(hedger.rate_limiter()));
+    /// # Ok::<(), deribit_http::HttpError>(())
+    /// ```
+    pub fn rate_limiter(mut self, rate_limiter: RateLimiter) -> Self {
+        self.rate_limiter = Some(rate_limiter);
+        self
+    }
+
+    /// Use `cache` for instrument metadata, as [`DeribitHttpClient::with_instrument_cache`] does
+    pub fn instrument_cache(mut self, cache: InstrumentCache) -> Self {
+        self.instrument_cache = Some(cache);
+        self
+    }
+
+    /// Fail requests fast while `breaker` is open, as
+    /// [`DeribitHttpClient::with_circuit_breaker`] does
+    pub fn circuit_breaker(mut self, breaker: CircuitBreaker) -> Self {
+        self.circuit_breaker = Some(breaker);
+        self
+    }
+
+    /// Use custom credit pools, as [`DeribitHttpClient::with_credit_limits`] does
+    pub fn credit_limits(
+        self,
+        non_matching_engine: CreditLimits,
+        matching_engine: CreditLimits,
+    ) -> Self {
+        self.rate_limiter(RateLimiter::with_limits(
+            non_matching_engine,
+            matching_engine,
+        ))
+    }
+
+    /// Add a hook run around every request, after those added before it
+    pub fn interceptor(mut self, interceptor: impl RequestInterceptor + 'static) -> Self {
+        self.interceptors = self.interceptors.with(Arc::new(interceptor));
+        self
+    }
+
+    /// Send requests through `transport`, see [`HttpConfig::with_transport`]
+    pub fn transport(mut self, transport: impl HttpTransport + 'static) -> Self {
+        self.config = self.config.with_transport(transport);
+        self
+    }
+
+    /// Send requests with `backend`, see [`HttpConfig::with_backend`]
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn backend(mut self, backend: impl HttpBackend + 'static) -> Self {
+        self.config = self.config.with_backend(backend);
+        self
+    }
+
+    /// Build on an existing `reqwest::Client`, as [`DeribitHttpClient::with_http_client`] does
+    ///
+    /// The timeout, user agent, pool settings and proxy are then not applied
+    /// to the HTTP client; configure them on `client` instead.
+    pub fn http_client(mut self, client: Client) -> Self {
+        self.http_client = Some(client);
+        self
+    }
+
+    /// Build the client
+    ///
+    /// # Errors
+    ///
+    /// Returns `HttpErrorKind::ConfigError` if the HTTP client cannot be created,
+    /// e.g. because the user agent or a default header is not a valid header
+    /// value.
+    pub fn build(self) -> Result<DeribitHttpClient, HttpError> {
+        let client = match self.http_client {
+            Some(client) => client,
+            None => http_client_for(&self.config)?,
+        };
+        let mut client = DeribitHttpClient::with_http_client(self.config, client);
+        client.interceptors = self.interceptors;
+        if let Some(rate_limiter) = self.rate_limiter {
+            Arc::make_mut(&mut client.shared).rate_limiter = rate_limiter;
+        }
+        if let Some(cache) = self.instrument_cache {
+            Arc::make_mut(&mut client.shared).instrument_cache = cache;
+        }
+        if let Some(breaker) = self.circuit_breaker {
+            Arc::make_mut(&mut client.shared).circuit_breaker = Some(breaker);
+        }
+        Ok(client)
     }
 }
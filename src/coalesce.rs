@@ -0,0 +1,128 @@
+//! Coalescing of identical concurrent public requests
+//!
+//! While a public `GET` for a URL is in flight, further requests for the same
+//! URL do not hit the network: they wait for the first one and receive a copy
+//! of its response, which each caller then parses on its own. The request is
+//! charged to the rate limiter, retried and passed through the interceptors
+//! once. Only requests sent with the same [`RequestOptions`] and interceptors
+//! are joined, so no caller gets a response produced under another's timeout,
+//! retries or hooks.
+
+use crate::config::RequestOptions;
+use crate::error::{HttpError, HttpErrorKind};
+use futures_util::FutureExt;
+use futures_util::future::{BoxFuture, Shared};
+use http::{HeaderMap, StatusCode};
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::{Arc, Mutex, PoisonError, Weak};
+
+/// Response of a coalesced request, shared by every caller waiting on it
+type Pending = Shared<BoxFuture<'static, Result<BufferedResponse, HttpError>>>;
+
+/// What identical requests have in common
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) struct RequestKey {
+    /// Full URL, query included
+    pub(crate) url: String,
+    /// Per-call overrides the request is sent with
+    pub(crate) options: RequestOptions,
+    /// Identity of the interceptor list the request passes through
+    pub(crate) interceptors: usize,
+}
+
+/// Requests in flight, by [`RequestKey`]
+#[derive(Default)]
+pub(crate) struct InFlight {
+    requests: Mutex<HashMap<RequestKey, Pending>>,
+}
+
+impl std::fmt::Debug for InFlight {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let requests = self.requests.lock().unwrap_or_else(PoisonError::into_inner);
+        f.debug_struct("InFlight")
+            .field(
+                "urls",
+                &requests.keys().map(|key| &key.url).collect::<Vec<_>>(),
+            )
+            .finish()
+    }
+}
+
+impl InFlight {
+    /// Send `fetch` for `key`, or join the request already in flight for it
+    pub(crate) async fn coalesce<F>(
+        self: &Arc<Self>,
+        key: RequestKey,
+        fetch: F,
+    ) -> Result<reqwest::Response, HttpError>
+    where
+        F: Future<Output = Result<reqwest::Response, HttpError>> + Send + 'static,
+    {
+        let pending = {
+            let mut requests = self.requests.lock().unwrap_or_else(PoisonError::into_inner);
+            match requests.get(&key) {
+                Some(pending) => pending.clone(),
+                None => {
+                    let pending = Self::buffered(Arc::downgrade(self), key.clone(), fetch);
+                    requests.insert(key, pending.clone());
+                    pending
+                }
+            }
+        };
+        pending.await.map(|response| response.to_response())
+    }
+
+    /// `fetch` read into memory, leaving the in-flight map once done
+    fn buffered<F>(in_flight: Weak<Self>, key: RequestKey, fetch: F) -> Pending
+    where
+        F: Future<Output = Result<reqwest::Response, HttpError>> + Send + 'static,
+    {
+        async move {
+            let result = match fetch.await {
+                Ok(response) => BufferedResponse::read(response).await,
+                Err(e) => Err(e),
+            };
+            if let Some(in_flight) = in_flight.upgrade() {
+                in_flight
+                    .requests
+                    .lock()
+                    .unwrap_or_else(PoisonError::into_inner)
+                    .remove(&key);
+            }
+            result
+        }
+        .boxed()
+        .shared()
+    }
+}
+
+/// Status, headers and body of a response, read so it can be handed out again
+#[derive(Debug, Clone)]
+struct BufferedResponse {
+    status: StatusCode,
+    headers: HeaderMap,
+    body: Arc<[u8]>,
+}
+
+impl BufferedResponse {
+    async fn read(response: reqwest::Response) -> Result<Self, HttpError> {
+        let status = response.status();
+        let headers = response.headers().clone();
+        let body = response.bytes().await.map_err(|e| {
+            HttpErrorKind::InvalidResponse(format!("Failed to read response body: {}", e))
+        })?;
+        Ok(Self {
+            status,
+            headers,
+            body: Arc::from(body.as_ref()),
+        })
+    }
+
+    fn to_response(&self) -> reqwest::Response {
+        let mut response = http::Response::new(self.body.to_vec());
+        *response.status_mut() = self.status;
+        *response.headers_mut() = self.headers.clone();
+        reqwest::Response::from(response)
+    }
+}
@@ -1,16 +1,30 @@
 //! Base configuration for HTTP client
 
 use crate::config::credentials::ApiCredentials;
+use crate::config::provider::CredentialsProvider;
+use crate::config::proxy::ProxyConfig;
 use crate::constants::{DEFAULT_TIMEOUT, MAX_RETRIES, PRODUCTION_BASE_URL, TESTNET_BASE_URL};
-use pretty_simple_display::{DebugPretty, DisplaySimple};
+use crate::error::{HttpError, HttpErrorKind};
+use crate::logger::impl_redacted_display;
+use crate::transport::HttpTransport;
+#[cfg(not(target_arch = "wasm32"))]
+use crate::transport::{BackendTransport, HttpBackend};
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 #[cfg(not(target_arch = "wasm32"))]
 use std::env;
+use std::sync::Arc;
 use std::time::Duration;
 use url::Url;
+use zeroize::Zeroize;
 
 /// Configuration for the HTTP client
-#[derive(DebugPretty, DisplaySimple, Clone, Serialize, Deserialize)]
+///
+/// `Debug` and `Display` print the client secret as `[REDACTED]`. The
+/// credentials are zeroed in memory when the configuration is dropped, and
+/// [`Zeroize::zeroize`] clears them earlier.
+#[derive(Clone, Serialize, Deserialize)]
 pub struct HttpConfig {
     /// Base URL for API requests
     pub base_url: Url,
@@ -24,6 +38,134 @@ pub struct HttpConfig {
     pub testnet: bool,
     /// API credentials
     pub credentials: Option<ApiCredentials>,
+    /// Source of rotatable credentials, consulted on every token renewal and
+    /// preferred over `credentials`. Not serialized
+    #[serde(skip)]
+    pub credentials_provider: Option<Arc<dyn CredentialsProvider>>,
+    /// Keep response bodies as text to log them, redacted, at debug level and
+    /// quote them in parse errors; off by default to avoid the extra copy
+    #[serde(default)]
+    pub debug_raw_responses: bool,
+    /// Round order prices to the instrument's tick size and check amounts
+    /// against its minimum trade amount before sending; off by default
+    #[serde(default)]
+    pub price_normalization: Option<PriceNormalization>,
+    /// Transport that sends the client's requests; `None` sends them with the
+    /// client's own `reqwest::Client`. Not serialized
+    #[serde(skip)]
+    pub transport: Option<Arc<dyn HttpTransport>>,
+    /// Proxy the client connects through; `None` connects directly. Read from
+    /// `HTTPS_PROXY`/`ALL_PROXY` and `NO_PROXY` when loaded from the environment
+    #[serde(default)]
+    pub proxy: Option<ProxyConfig>,
+    /// Headers added to every request, including token requests, unless the
+    /// request sets them itself; e.g. client identification for a gateway
+    #[serde(default)]
+    pub default_headers: BTreeMap<String, String>,
+    /// Connection pool and socket settings of the client's `reqwest::Client`
+    #[serde(default)]
+    pub pool: PoolConfig,
+}
+
+/// Tick-size rounding applied to `buy_order`, `sell_order` and `edit_order`
+///
+/// `price` and `trigger_price` are rounded to the nearest tick of the
+/// instrument. If rounding moves a price by more than `max_relative_change`
+/// (a fraction of the price, e.g. `0.001` for 0.1%), the order is rejected
+/// with `HttpErrorKind::ConfigError` instead of being sent at a different price.
+/// Orders whose `amount` is below the instrument's minimum trade amount are
+/// rejected the same way, without a round trip to the exchange.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct PriceNormalization {
+    /// Largest accepted change from rounding, relative to the original price
+    pub max_relative_change: f64,
+}
+
+/// Overrides of the client-wide request settings for some calls
+///
+/// Applied with [`DeribitHttpClient::with_options`](crate::DeribitHttpClient::with_options),
+/// e.g. a tight timeout without retries for quoting, or a long timeout for
+/// bulk history downloads, on the same connection pool. Unset fields keep the
+/// [`HttpConfig`] values. Token requests made by the authentication manager
+/// are not affected.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct RequestOptions {
+    /// Timeout of each attempt, instead of `HttpConfig::timeout`
+    pub timeout: Option<Duration>,
+    /// Retries of a failed request, instead of `HttpConfig::max_retries`
+    pub max_retries: Option<u32>,
+}
+
+impl RequestOptions {
+    /// Options overriding nothing
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Give up on each attempt after `timeout`
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Retry a failed request at most `max_retries` times; `0` disables retries
+    pub fn with_max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = Some(max_retries);
+        self
+    }
+}
+
+/// Connection pool and socket settings
+///
+/// Unset fields keep `reqwest`'s defaults: unlimited idle connections per host,
+/// closed after 90 seconds idle, TCP keepalive every 15 seconds and no connect
+/// timeout beyond the request timeout. A bot sending sparse but latency-sensitive
+/// requests can keep its connections to Deribit warm with a longer idle timeout,
+/// instead of paying for a new TCP and TLS handshake mid-session.
+///
+/// Applied when the client builds its own `reqwest::Client`; ignored on WASM
+/// and by [`DeribitHttpClient::with_http_client`](crate::DeribitHttpClient::with_http_client).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PoolConfig {
+    /// Idle connections kept per host
+    pub max_idle_per_host: Option<usize>,
+    /// How long an idle connection is kept before it is closed
+    pub idle_timeout: Option<Duration>,
+    /// Interval of TCP keepalive probes on open connections
+    pub tcp_keepalive: Option<Duration>,
+    /// Timeout of the connect phase, TLS handshake included
+    pub connect_timeout: Option<Duration>,
+}
+
+impl PoolConfig {
+    /// Settings keeping every `reqwest` default
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Keep at most `max` idle connections per host
+    pub fn with_max_idle_per_host(mut self, max: usize) -> Self {
+        self.max_idle_per_host = Some(max);
+        self
+    }
+
+    /// Close connections idle for longer than `timeout`
+    pub fn with_idle_timeout(mut self, timeout: Duration) -> Self {
+        self.idle_timeout = Some(timeout);
+        self
+    }
+
+    /// Send TCP keepalive probes every `interval`
+    pub fn with_tcp_keepalive(mut self, interval: Duration) -> Self {
+        self.tcp_keepalive = Some(interval);
+        self
+    }
+
+    /// Give up connecting after `timeout`
+    pub fn with_connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
 }
 
 impl Default for HttpConfig {
@@ -67,6 +209,10 @@ impl HttpConfig {
         let user_agent = env::var("DERIBIT_HTTP_USER_AGENT")
             .unwrap_or_else(|_| format!("deribit-http/{}", env!("CARGO_PKG_VERSION")));
 
+        let debug_raw_responses = env::var("DERIBIT_HTTP_DEBUG_RAW_RESPONSES")
+            .map(|val| val.to_lowercase() == "true")
+            .unwrap_or(false);
+
         Self {
             base_url,
             timeout,
@@ -74,6 +220,13 @@ impl HttpConfig {
             user_agent,
             testnet,
             credentials,
+            credentials_provider: None,
+            debug_raw_responses,
+            price_normalization: None,
+            transport: None,
+            proxy: ProxyConfig::from_env(),
+            default_headers: BTreeMap::new(),
+            pool: PoolConfig::default(),
         }
     }
 
@@ -100,6 +253,12 @@ impl HttpConfig {
 
     #[cfg(target_arch = "wasm32")]
     fn create(base_url: Url, testnet: bool) -> Self {
+        Self::with_defaults(base_url, testnet)
+    }
+
+    /// Configuration for `base_url` with the built-in defaults and no
+    /// credentials, ignoring the environment
+    pub(crate) fn with_defaults(base_url: Url, testnet: bool) -> Self {
         Self {
             base_url,
             timeout: Duration::from_secs(DEFAULT_TIMEOUT),
@@ -107,6 +266,13 @@ impl HttpConfig {
             user_agent: format!("deribit-http/{}", env!("CARGO_PKG_VERSION")),
             testnet,
             credentials: None,
+            credentials_provider: None,
+            debug_raw_responses: false,
+            price_normalization: None,
+            transport: None,
+            proxy: None,
+            default_headers: BTreeMap::new(),
+            pool: PoolConfig::default(),
         }
     }
 
@@ -122,12 +288,89 @@ impl HttpConfig {
         self
     }
 
+    /// Capture raw response bodies for debug logging and error messages
+    pub fn with_debug_raw_responses(mut self, enabled: bool) -> Self {
+        self.debug_raw_responses = enabled;
+        self
+    }
+
+    /// Round order prices to the instrument's tick size
+    ///
+    /// Rejects an order whose price would move by more than
+    /// `max_relative_change` (a fraction of the price). See
+    /// [`PriceNormalization`].
+    pub fn with_price_normalization(mut self, max_relative_change: f64) -> Self {
+        self.price_normalization = Some(PriceNormalization {
+            max_relative_change,
+        });
+        self
+    }
+
+    /// Send requests through `transport` instead of the `reqwest::Client`
+    ///
+    /// Applies to every request of the client, token requests included. See
+    /// [`transport`](crate::transport).
+    pub fn with_transport(mut self, transport: impl HttpTransport + 'static) -> Self {
+        self.transport = Some(Arc::new(transport));
+        self
+    }
+
+    /// Send requests with `backend` instead of `reqwest`
+    ///
+    /// Wraps `backend` in a [`BackendTransport`]. The proxy and pool settings
+    /// are not passed on; configure them on the backend.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn with_backend(self, backend: impl HttpBackend + 'static) -> Self {
+        self.with_transport(BackendTransport::new(backend))
+    }
+
+    /// Connect through `proxy`
+    pub fn with_proxy(mut self, proxy: ProxyConfig) -> Self {
+        self.proxy = Some(proxy);
+        self
+    }
+
     /// Set the user agent string
     pub fn with_user_agent(mut self, user_agent: String) -> Self {
         self.user_agent = user_agent;
         self
     }
 
+    /// Tune the connection pool
+    pub fn with_pool(mut self, pool: PoolConfig) -> Self {
+        self.pool = pool;
+        self
+    }
+
+    /// Add `name: value` to every request, replacing an earlier value of `name`
+    ///
+    /// Names are case-insensitive. An invalid name or value fails client
+    /// construction.
+    pub fn with_header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        let name = name.into().to_ascii_lowercase();
+        self.default_headers.insert(name, value.into());
+        self
+    }
+
+    /// `default_headers` as a header map
+    ///
+    /// # Errors
+    ///
+    /// Returns `HttpErrorKind::ConfigError` naming the first invalid header.
+    pub fn default_header_map(&self) -> Result<HeaderMap, HttpError> {
+        self.default_headers
+            .iter()
+            .map(|(name, value)| {
+                let invalid = |e: &dyn std::fmt::Display| {
+                    HttpErrorKind::ConfigError(format!("Invalid default header {}: {}", name, e))
+                };
+                let name = HeaderName::try_from(name.as_str()).map_err(|e| invalid(&e))?;
+                let value = HeaderValue::try_from(value.as_str()).map_err(|e| invalid(&e))?;
+                Ok((name, value))
+            })
+            .collect()
+    }
+
     /// Set OAuth2 credentials
     pub fn with_oauth2(mut self, client_id: String, client_secret: String) -> Self {
         self.credentials = Some(ApiCredentials {
@@ -137,9 +380,20 @@ impl HttpConfig {
         self
     }
 
-    /// Check if credentials are configured
+    /// Get credentials from `provider` each time a token is obtained or renewed
+    ///
+    /// Takes precedence over static credentials. See [`CredentialsProvider`].
+    pub fn with_credentials_provider(
+        mut self,
+        provider: impl CredentialsProvider + 'static,
+    ) -> Self {
+        self.credentials_provider = Some(Arc::new(provider));
+        self
+    }
+
+    /// Check if credentials or a credentials provider are configured
     pub fn has_credentials(&self) -> bool {
-        self.credentials.is_some()
+        self.credentials.is_some() || self.credentials_provider.is_some()
     }
 
     /// Get the credentials
@@ -147,3 +401,15 @@ impl HttpConfig {
         self.credentials.as_ref()
     }
 }
+
+/// Clears the static credentials; a credentials provider is left in place
+impl Zeroize for HttpConfig {
+    fn zeroize(&mut self) {
+        if let Some(credentials) = self.credentials.as_mut() {
+            credentials.zeroize();
+        }
+        self.credentials = None;
+    }
+}
+
+impl_redacted_display!(HttpConfig);
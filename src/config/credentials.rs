@@ -1,6 +1,7 @@
-use crate::HttpError;
-use pretty_simple_display::{DebugPretty, DisplaySimple};
+use crate::logger::impl_redacted_display;
+use crate::{HttpError, HttpErrorKind};
 use serde::{Deserialize, Serialize};
+use zeroize::Zeroize;
 
 #[cfg(not(target_arch = "wasm32"))]
 use std::env;
@@ -8,7 +9,10 @@ use std::env;
 use tracing::warn;
 
 /// API credentials for authentication
-#[derive(DebugPretty, DisplaySimple, Clone, Serialize, Deserialize)]
+///
+/// `Debug` and `Display` print the client secret as `[REDACTED]`, and the
+/// secret is zeroed in memory when the credentials are dropped.
+#[derive(Clone, Serialize, Deserialize)]
 pub struct ApiCredentials {
     /// Client ID for OAuth2
     pub client_id: Option<String>,
@@ -32,7 +36,7 @@ impl ApiCredentials {
     ///
     /// # Returns
     /// - `Ok(Self)`: If the credentials are properly configured and valid.
-    /// - `Err(HttpError::ConfigError)`: If the credentials are not properly set or invalid, with an appropriate
+    /// - `Err(HttpErrorKind::ConfigError.into())`: If the credentials are not properly set or invalid, with an appropriate
     ///   error message indicating the configuration issue.
     ///
     /// # Note
@@ -49,34 +53,35 @@ impl ApiCredentials {
             warn!(
                 "API credentials are provided in environment variables, only public endpoints will be available."
             );
-            Err(HttpError::ConfigError(
+            Err(HttpErrorKind::ConfigError(
                 "API credentials are not properly set in environment variables".into(),
-            ))
+            )
+            .into())
         }
     }
 
     /// Creates a new instance of the struct (WASM version).
     ///
     /// # Returns
-    /// - `Err(HttpError::ConfigError)`: Always returns an error on WASM since environment variables
-    ///   are not available. Use `with_credentials` to set credentials manually.
+    /// - `Err(HttpErrorKind::ConfigError.into())`: Always returns an error on WASM since environment variables
+    ///   are not available. Use `HttpConfig::with_oauth2` to set credentials manually.
     ///
     #[cfg(target_arch = "wasm32")]
     pub fn new() -> Result<Self, HttpError> {
-        Err(HttpError::ConfigError(
-            "Environment variables are not available in WASM. Use with_credentials() instead."
+        Err(HttpErrorKind::ConfigError(
+            "Environment variables are not available in WASM. Use HttpConfig::with_oauth2() instead."
                 .into(),
-        ))
+        ).into())
     }
 
     /// Retrieves the client credentials (Client ID and Client Secret) required for OAuth2 authentication.
     ///
     /// # Returns
     /// - `Ok((String, String))`: A tuple containing the `client_id` and `client_secret` if they are both set.
-    /// - `Err(HttpError)`: An error of type `HttpError::ConfigError` if either `client_id` or `client_secret` is not set.
+    /// - `Err(HttpError)`: An error of type `HttpErrorKind::ConfigError` if either `client_id` or `client_secret` is not set.
     ///
     /// # Errors
-    /// Returns an `HttpError::ConfigError` with a message indicating that both `Client ID`
+    /// Returns an `HttpErrorKind::ConfigError` with a message indicating that both `Client ID`
     /// and `Client Secret` must be set for OAuth2 authentication when either or both are absent.
     ///
     /// # Note
@@ -89,9 +94,10 @@ impl ApiCredentials {
                 self.client_secret.clone().unwrap(),
             ))
         } else {
-            Err(HttpError::ConfigError(
+            Err(HttpErrorKind::ConfigError(
                 "Client ID and Client Secret must be set for OAuth2 authentication".into(),
-            ))
+            )
+            .into())
         }
     }
 }
@@ -116,3 +122,18 @@ impl Default for ApiCredentials {
         }
     }
 }
+
+impl Zeroize for ApiCredentials {
+    fn zeroize(&mut self) {
+        self.client_id.zeroize();
+        self.client_secret.zeroize();
+    }
+}
+
+impl Drop for ApiCredentials {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl_redacted_display!(ApiCredentials);
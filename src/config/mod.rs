@@ -1,10 +1,30 @@
 //! Configuration module for HTTP client
 
+/// AWS Secrets Manager credentials provider (requires the `aws-secrets-manager` feature)
+#[cfg(feature = "aws-secrets-manager")]
+pub mod aws;
 pub mod base;
 
 /// API credentials for authentication
 pub mod credentials;
 
+/// Pluggable, rotatable sources of API credentials
+pub mod provider;
+
+/// HTTP and SOCKS5 proxy settings
+pub mod proxy;
+
+/// HashiCorp Vault credentials provider (requires the `vault` feature)
+#[cfg(feature = "vault")]
+pub mod vault;
+
+#[cfg(feature = "aws-secrets-manager")]
+pub use aws::AwsSecretsManagerCredentials;
 pub use base::*;
 
 pub use credentials::*;
+
+pub use provider::*;
+pub use proxy::ProxyConfig;
+#[cfg(feature = "vault")]
+pub use vault::VaultCredentials;
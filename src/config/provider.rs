@@ -0,0 +1,166 @@
+use crate::config::credentials::ApiCredentials;
+use crate::error::HttpError;
+use crate::interceptor::HookFuture;
+use std::sync::Arc;
+
+#[cfg(not(target_arch = "wasm32"))]
+use crate::error::HttpErrorKind;
+#[cfg(not(target_arch = "wasm32"))]
+use std::path::PathBuf;
+
+/// Source of the OAuth2 credentials the client authenticates with
+///
+/// The [`AuthManager`](crate::AuthManager) asks the provider for credentials
+/// every time it obtains or renews a token, so a provider that returns new
+/// credentials rotates them without restarting the process. When they change,
+/// the next renewal authenticates with the new credentials instead of using
+/// the refresh token of the old session. Set one with
+/// [`HttpConfig::with_credentials_provider`](crate::HttpConfig::with_credentials_provider);
+/// it takes precedence over the static `HttpConfig::credentials`.
+///
+/// [`ApiCredentials`] is the static provider. [`EnvCredentials`] and
+/// [`FileCredentials`] re-read their source on each call; the `vault` and
+/// `aws-secrets-manager` features add providers for HashiCorp Vault and AWS
+/// Secrets Manager.
+///
+/// # Examples
+///
+/// ```rust
+/// use deribit_http::config::{ApiCredentials, CredentialsProvider};
+/// use deribit_http::interceptor::HookFuture;
+/// use deribit_http::{HttpConfig, HttpError};
+///
+/// struct FromSecretStore;
+///
+/// impl CredentialsProvider for FromSecretStore {
+///     fn credentials(&self) -> HookFuture<'_, Result<ApiCredentials, HttpError>> {
+///         Box::pin(async {
+///             Ok(ApiCredentials {
+///                 client_id: Some("client_id".to_string()),
+///                 client_secret: Some("client_secret".to_string()),
+///             })
+///         })
+///     }
+/// }
+///
+/// let config = HttpConfig::testnet().with_credentials_provider(FromSecretStore);
+/// assert!(config.has_credentials());
+/// ```
+pub trait CredentialsProvider: Send + Sync {
+    /// Current credentials
+    ///
+    /// Errors fail the authentication attempt; a session that can still be
+    /// renewed with its refresh token keeps being renewed.
+    fn credentials(&self) -> HookFuture<'_, Result<ApiCredentials, HttpError>>;
+}
+
+/// Always the same credentials
+impl CredentialsProvider for ApiCredentials {
+    fn credentials(&self) -> HookFuture<'_, Result<ApiCredentials, HttpError>> {
+        Box::pin(async move { Ok(self.clone()) })
+    }
+}
+
+/// Shares one provider, and any cache it keeps, across configurations
+impl<T: CredentialsProvider + ?Sized> CredentialsProvider for Arc<T> {
+    fn credentials(&self) -> HookFuture<'_, Result<ApiCredentials, HttpError>> {
+        self.as_ref().credentials()
+    }
+}
+
+/// Credentials read from environment variables on every call
+///
+/// Unlike [`ApiCredentials::new`], changes to the variables made while the
+/// process runs are picked up on the next renewal. `.env` files are not read.
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EnvCredentials {
+    client_id_var: String,
+    client_secret_var: String,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl EnvCredentials {
+    /// Read `DERIBIT_CLIENT_ID` and `DERIBIT_CLIENT_SECRET`
+    pub fn new() -> Self {
+        Self::with_vars("DERIBIT_CLIENT_ID", "DERIBIT_CLIENT_SECRET")
+    }
+
+    /// Read the given variables instead, e.g. for one of several accounts
+    pub fn with_vars(
+        client_id_var: impl Into<String>,
+        client_secret_var: impl Into<String>,
+    ) -> Self {
+        Self {
+            client_id_var: client_id_var.into(),
+            client_secret_var: client_secret_var.into(),
+        }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl Default for EnvCredentials {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl CredentialsProvider for EnvCredentials {
+    fn credentials(&self) -> HookFuture<'_, Result<ApiCredentials, HttpError>> {
+        let read = |var: &str| {
+            std::env::var(var).map_err(|_| {
+                HttpError::from(HttpErrorKind::ConfigError(format!("{} is not set", var)))
+            })
+        };
+        let credentials = read(&self.client_id_var).and_then(|client_id| {
+            Ok(ApiCredentials {
+                client_id: Some(client_id),
+                client_secret: Some(read(&self.client_secret_var)?),
+            })
+        });
+        Box::pin(async move { credentials })
+    }
+}
+
+/// Credentials read from a JSON file on every call
+///
+/// The file holds `{"client_id": "...", "client_secret": "..."}`, e.g. a
+/// Kubernetes secret mounted as a volume, which is updated in place when the
+/// secret is rotated. The file is small and read synchronously.
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileCredentials {
+    path: PathBuf,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl FileCredentials {
+    /// Read credentials from `path`
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl CredentialsProvider for FileCredentials {
+    fn credentials(&self) -> HookFuture<'_, Result<ApiCredentials, HttpError>> {
+        let path = self.path.display();
+        let credentials = std::fs::read_to_string(&self.path)
+            .map_err(|e| {
+                HttpError::from(HttpErrorKind::ConfigError(format!(
+                    "Cannot read {}: {}",
+                    path, e
+                )))
+            })
+            .and_then(|contents| {
+                serde_json::from_str(&contents).map_err(|e| {
+                    HttpError::from(HttpErrorKind::ConfigError(format!(
+                        "Invalid credentials in {}: {}",
+                        path, e
+                    )))
+                })
+            });
+        Box::pin(async move { credentials })
+    }
+}
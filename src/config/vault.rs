@@ -0,0 +1,135 @@
+use crate::config::credentials::ApiCredentials;
+use crate::config::provider::CredentialsProvider;
+use crate::error::{HttpError, HttpErrorKind};
+use crate::interceptor::HookFuture;
+use crate::logger::REDACTED;
+use reqwest::Client;
+use std::fmt;
+use url::Url;
+
+/// Credentials read from a HashiCorp Vault KV version 2 secret on every call
+///
+/// The secret holds `client_id` and `client_secret` keys. Its latest version
+/// is read, so writing a new version rotates the credentials.
+///
+/// The secret is read through Vault's HTTP API with `reqwest`, so the feature
+/// adds no dependencies.
+///
+/// # Examples
+///
+/// ```rust
+/// use deribit_http::HttpConfig;
+/// use deribit_http::config::VaultCredentials;
+///
+/// let vault = VaultCredentials::new(
+///     "https://vault.internal:8200".parse().unwrap(),
+///     "hvs.token",
+///     "trading/deribit",
+/// )
+/// .with_mount("kv");
+/// let config = HttpConfig::testnet().with_credentials_provider(vault);
+/// ```
+#[derive(Clone)]
+pub struct VaultCredentials {
+    client: Client,
+    address: Url,
+    token: String,
+    mount: String,
+    path: String,
+}
+
+impl VaultCredentials {
+    /// Read the secret at `path` of the `secret` mount of the Vault at `address`
+    pub fn new(address: Url, token: impl Into<String>, path: impl Into<String>) -> Self {
+        Self {
+            client: Client::new(),
+            address,
+            token: token.into(),
+            mount: "secret".to_string(),
+            path: path.into(),
+        }
+    }
+
+    /// Address and token from `VAULT_ADDR` and `VAULT_TOKEN`
+    ///
+    /// # Errors
+    ///
+    /// Returns `HttpErrorKind::ConfigError` if either variable is missing or the
+    /// address is not a URL.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn from_env(path: impl Into<String>) -> Result<Self, HttpError> {
+        let read = |var: &str| {
+            std::env::var(var)
+                .map_err(|_| HttpErrorKind::ConfigError(format!("{} is not set", var)))
+        };
+        let address = read("VAULT_ADDR")?
+            .parse()
+            .map_err(|e| HttpErrorKind::ConfigError(format!("Invalid VAULT_ADDR: {}", e)))?;
+        Ok(Self::new(address, read("VAULT_TOKEN")?, path))
+    }
+
+    /// Read from the KV engine mounted at `mount` instead of `secret`
+    pub fn with_mount(mut self, mount: impl Into<String>) -> Self {
+        self.mount = mount.into();
+        self
+    }
+
+    /// Send the requests with `client`, e.g. one trusting the Vault's CA
+    pub fn with_http_client(mut self, client: Client) -> Self {
+        self.client = client;
+        self
+    }
+
+    async fn read(&self) -> Result<ApiCredentials, HttpError> {
+        let url = format!(
+            "{}/v1/{}/data/{}",
+            self.address.as_str().trim_end_matches('/'),
+            self.mount,
+            self.path
+        );
+        let response = self
+            .client
+            .get(&url)
+            .header("X-Vault-Token", &self.token)
+            .send()
+            .await
+            .map_err(|e| HttpErrorKind::NetworkError(e.to_string()))?;
+        let status = response.status();
+        if !status.is_success() {
+            return Err(HttpErrorKind::RequestFailed(format!(
+                "Vault returned {} for {}/{}",
+                status, self.mount, self.path
+            ))
+            .into());
+        }
+
+        let mut body: serde_json::Value = response
+            .json()
+            .await
+            .map_err(|e| HttpErrorKind::InvalidResponse(e.to_string()))?;
+        let data = body
+            .pointer_mut("/data/data")
+            .map(serde_json::Value::take)
+            .ok_or_else(|| HttpErrorKind::InvalidResponse("No data in Vault secret".to_string()))?;
+        serde_json::from_value(data).map_err(|e| {
+            HttpErrorKind::InvalidResponse(format!("Invalid Vault secret: {}", e)).into()
+        })
+    }
+}
+
+impl CredentialsProvider for VaultCredentials {
+    fn credentials(&self) -> HookFuture<'_, Result<ApiCredentials, HttpError>> {
+        Box::pin(self.read())
+    }
+}
+
+impl fmt::Debug for VaultCredentials {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("VaultCredentials")
+            .field("address", &self.address.as_str())
+            .field("token", &REDACTED)
+            .field("mount", &self.mount)
+            .field("path", &self.path)
+            .finish()
+    }
+}
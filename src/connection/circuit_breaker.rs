@@ -0,0 +1,326 @@
+//! Circuit breaker for persistent upstream failures
+//!
+//! The breaker watches the outcome of the last requests a client sent. Once
+//! the share of failures, connection errors and 5xx responses, reaches the
+//! configured threshold, it opens: requests fail immediately with
+//! [`HttpErrorKind::CircuitOpen`] instead of reaching Deribit. After the open
+//! period it lets probe requests through one at a time (half-open); enough
+//! successful probes close it again, a failed one reopens it.
+//!
+//! Every attempt counts, retries included, so a retry loop stops as soon as
+//! the breaker opens. Token requests are not guarded.
+
+use crate::error::{HttpError, HttpErrorKind};
+use crate::time_compat::Instant;
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::Duration;
+
+/// Thresholds and timings of a [`CircuitBreaker`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CircuitBreakerConfig {
+    /// Share of failed requests in the window, from 0 to 1, that opens the circuit
+    pub failure_rate_threshold: f64,
+    /// Number of most recent outcomes the failure rate is computed over
+    pub window_size: usize,
+    /// Outcomes needed in the window before the failure rate is evaluated
+    pub minimum_requests: usize,
+    /// How long the circuit stays open before probing the API
+    pub open_duration: Duration,
+    /// Successful probes needed to close a half-open circuit
+    pub half_open_probes: u32,
+}
+
+impl Default for CircuitBreakerConfig {
+    /// Opens at 50% failures over the last 20 requests, once 10 were seen,
+    /// and probes again after 30 seconds
+    fn default() -> Self {
+        Self {
+            failure_rate_threshold: 0.5,
+            window_size: 20,
+            minimum_requests: 10,
+            open_duration: Duration::from_secs(30),
+            half_open_probes: 1,
+        }
+    }
+}
+
+impl CircuitBreakerConfig {
+    /// The default thresholds
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Open at `rate` failures, e.g. `0.5` for half of the requests
+    pub fn with_failure_rate_threshold(mut self, rate: f64) -> Self {
+        self.failure_rate_threshold = rate;
+        self
+    }
+
+    /// Compute the failure rate over the last `size` outcomes
+    pub fn with_window_size(mut self, size: usize) -> Self {
+        self.window_size = size;
+        self
+    }
+
+    /// Evaluate the failure rate once `requests` outcomes were seen
+    pub fn with_minimum_requests(mut self, requests: usize) -> Self {
+        self.minimum_requests = requests;
+        self
+    }
+
+    /// Keep the circuit open for `duration` before probing
+    pub fn with_open_duration(mut self, duration: Duration) -> Self {
+        self.open_duration = duration;
+        self
+    }
+
+    /// Close a half-open circuit after `probes` successful requests
+    pub fn with_half_open_probes(mut self, probes: u32) -> Self {
+        self.half_open_probes = probes;
+        self
+    }
+}
+
+/// State of a [`CircuitBreaker`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CircuitState {
+    /// Requests are sent and their outcomes recorded
+    Closed,
+    /// Requests fail fast with [`HttpErrorKind::CircuitOpen`]
+    Open,
+    /// Probe requests are sent one at a time to test the API
+    HalfOpen,
+}
+
+/// Error-rate circuit breaker shared by the clients it is given to
+///
+/// Cloning a breaker shares its state, so one breaker can guard every client
+/// talking to the same Deribit environment.
+///
+/// # Examples
+///
+/// ```rust
+/// use deribit_http::DeribitHttpClient;
+/// use deribit_http::connection::{CircuitBreaker, CircuitBreakerConfig, CircuitState};
+/// use std::time::Duration;
+///
+/// let breaker = CircuitBreaker::new(
+///     CircuitBreakerConfig::new()
+///         .with_failure_rate_threshold(0.5)
+///         .with_open_duration(Duration::from_secs(10)),
+/// );
+/// let client = DeribitHttpClient::builder()
+///     .circuit_breaker(breaker.clone())
+///     .build()?;
+/// assert_eq!(breaker.state(), CircuitState::Closed);
+/// # Ok::<(), deribit_http::HttpError>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct CircuitBreaker {
+    config: CircuitBreakerConfig,
+    inner: Arc<Mutex<Circuit>>,
+}
+
+/// Mutable state behind a [`CircuitBreaker`]
+#[derive(Debug)]
+struct Circuit {
+    /// Outcomes in the window while closed, `true` for a failure
+    outcomes: VecDeque<bool>,
+    /// When the circuit opened, while open or half-open
+    opened_at: Option<Instant>,
+    /// Successful probes since the circuit turned half-open
+    probe_successes: u32,
+    /// Whether a probe is in flight
+    probing: bool,
+}
+
+impl CircuitBreaker {
+    /// A closed breaker with `config`
+    pub fn new(config: CircuitBreakerConfig) -> Self {
+        Self {
+            config,
+            inner: Arc::new(Mutex::new(Circuit {
+                outcomes: VecDeque::with_capacity(config.window_size),
+                opened_at: None,
+                probe_successes: 0,
+                probing: false,
+            })),
+        }
+    }
+
+    /// Thresholds and timings of this breaker
+    pub fn config(&self) -> &CircuitBreakerConfig {
+        &self.config
+    }
+
+    /// Current state
+    pub fn state(&self) -> CircuitState {
+        let circuit = self.lock();
+        match circuit.opened_at {
+            None => CircuitState::Closed,
+            Some(opened_at) if opened_at.elapsed() < self.config.open_duration => {
+                CircuitState::Open
+            }
+            Some(_) => CircuitState::HalfOpen,
+        }
+    }
+
+    /// Failure rate over the current window, `0.0` while it is empty or the
+    /// circuit is not closed
+    pub fn failure_rate(&self) -> f64 {
+        let circuit = self.lock();
+        if circuit.outcomes.is_empty() {
+            return 0.0;
+        }
+        let failures = circuit.outcomes.iter().filter(|failed| **failed).count();
+        failures as f64 / circuit.outcomes.len() as f64
+    }
+
+    /// Close the circuit and forget the recorded outcomes
+    pub fn reset(&self) {
+        let mut circuit = self.lock();
+        circuit.outcomes.clear();
+        circuit.opened_at = None;
+        circuit.probe_successes = 0;
+        circuit.probing = false;
+    }
+
+    /// Whether `other` is this breaker or a clone of it
+    pub fn shares_state_with(&self, other: &CircuitBreaker) -> bool {
+        Arc::ptr_eq(&self.inner, &other.inner)
+    }
+
+    /// Ask to send a request
+    ///
+    /// # Errors
+    ///
+    /// Returns [`HttpErrorKind::CircuitOpen`] while the circuit is open, or
+    /// half-open with a probe already in flight.
+    pub fn try_acquire(&self) -> Result<CircuitPermit, HttpError> {
+        let mut circuit = self.lock();
+        let Some(opened_at) = circuit.opened_at else {
+            return Ok(self.permit(false));
+        };
+        let open_for = opened_at.elapsed();
+        if open_for < self.config.open_duration {
+            return Err(HttpErrorKind::CircuitOpen {
+                retry_after: self.config.open_duration - open_for,
+            }
+            .into());
+        }
+        if circuit.probing {
+            return Err(HttpErrorKind::CircuitOpen {
+                retry_after: Duration::ZERO,
+            }
+            .into());
+        }
+        circuit.probing = true;
+        Ok(self.permit(true))
+    }
+
+    fn permit(&self, probe: bool) -> CircuitPermit {
+        CircuitPermit {
+            breaker: Some(self.clone()),
+            probe,
+        }
+    }
+
+    fn record(&self, probe: bool, failed: bool) {
+        let mut circuit = self.lock();
+        if probe {
+            circuit.probing = false;
+            if failed {
+                tracing::warn!(target: "deribit_http::circuit", "Probe failed, circuit reopened");
+                circuit.opened_at = Some(Instant::now());
+                circuit.probe_successes = 0;
+            } else {
+                circuit.probe_successes += 1;
+                if circuit.probe_successes >= self.config.half_open_probes {
+                    tracing::info!(target: "deribit_http::circuit", "Circuit closed");
+                    circuit.opened_at = None;
+                    circuit.probe_successes = 0;
+                }
+            }
+            return;
+        }
+        // Outcomes of requests let through before the circuit opened
+        if circuit.opened_at.is_some() {
+            return;
+        }
+
+        if circuit.outcomes.len() >= self.config.window_size.max(1) {
+            circuit.outcomes.pop_front();
+        }
+        circuit.outcomes.push_back(failed);
+
+        let seen = circuit.outcomes.len();
+        let failures = circuit.outcomes.iter().filter(|failed| **failed).count();
+        let rate = failures as f64 / seen as f64;
+        if seen >= self.config.minimum_requests && rate >= self.config.failure_rate_threshold {
+            tracing::warn!(
+                target: "deribit_http::circuit",
+                failure_rate = rate,
+                open_ms = self.config.open_duration.as_millis() as u64,
+                "Circuit opened"
+            );
+            circuit.outcomes.clear();
+            circuit.opened_at = Some(Instant::now());
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Circuit> {
+        self.inner.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl Default for CircuitBreaker {
+    fn default() -> Self {
+        Self::new(CircuitBreakerConfig::default())
+    }
+}
+
+/// Permission to send one request through a [`CircuitBreaker`]
+///
+/// Report the outcome with [`success`](Self::success) or
+/// [`failure`](Self::failure); a permit dropped without either is not
+/// counted, and frees the probe slot of a half-open circuit.
+#[derive(Debug)]
+#[must_use = "report the outcome of the request"]
+pub struct CircuitPermit {
+    breaker: Option<CircuitBreaker>,
+    probe: bool,
+}
+
+impl CircuitPermit {
+    /// Whether this request probes a half-open circuit
+    pub fn is_probe(&self) -> bool {
+        self.probe
+    }
+
+    /// Record that the request succeeded
+    pub fn success(self) {
+        self.finish(false);
+    }
+
+    /// Record that the request failed
+    pub fn failure(self) {
+        self.finish(true);
+    }
+
+    fn finish(mut self, failed: bool) {
+        if let Some(breaker) = self.breaker.take() {
+            breaker.record(self.probe, failed);
+        }
+    }
+}
+
+impl Drop for CircuitPermit {
+    fn drop(&mut self) {
+        if let Some(breaker) = self.breaker.take()
+            && self.probe
+        {
+            breaker.lock().probing = false;
+        }
+    }
+}
@@ -1,9 +1,10 @@
 //! HTTP connection management
 
 use crate::config::HttpConfig;
-use crate::error::HttpError;
+use crate::error::{HttpError, HttpErrorKind};
 use crate::model::request::api_request::HttpRequest;
 use crate::model::response::api_response::HttpResponse;
+use crate::transport;
 use reqwest::Client;
 use std::collections::HashMap;
 
@@ -26,7 +27,7 @@ impl HttpConnection {
 
         let client = builder
             .build()
-            .map_err(|e| HttpError::NetworkError(e.to_string()))?;
+            .map_err(|e| HttpErrorKind::NetworkError(e.to_string()))?;
 
         Ok(Self { client, config })
     }
@@ -39,10 +40,11 @@ impl HttpConnection {
             "PUT" => self.client.put(&request.endpoint),
             "DELETE" => self.client.delete(&request.endpoint),
             _ => {
-                return Err(HttpError::RequestFailed(format!(
+                return Err(HttpErrorKind::RequestFailed(format!(
                     "Unsupported method: {}",
                     request.method
-                )));
+                ))
+                .into());
             }
         };
 
@@ -57,10 +59,12 @@ impl HttpConnection {
         }
 
         // Send request
-        let response = req_builder
-            .send()
+        let request = req_builder
+            .build()
+            .map_err(|e| HttpErrorKind::NetworkError(e.to_string()))?;
+        let response = transport::send(&self.config, &self.client, request)
             .await
-            .map_err(|e| HttpError::NetworkError(e.to_string()))?;
+            .map_err(|e| HttpErrorKind::NetworkError(e.to_string()))?;
 
         // Extract response data
         let status = response.status().as_u16();
@@ -72,7 +76,7 @@ impl HttpConnection {
         let body = response
             .text()
             .await
-            .map_err(|e| HttpError::NetworkError(e.to_string()))?;
+            .map_err(|e| HttpErrorKind::NetworkError(e.to_string()))?;
 
         Ok(HttpResponse {
             status,
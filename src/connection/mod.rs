@@ -1,5 +1,7 @@
 //! Connection module for HTTP client
 
+pub mod circuit_breaker;
 pub mod http_connection;
 
+pub use circuit_breaker::*;
 pub use http_connection::*;
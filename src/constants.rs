@@ -6,17 +6,74 @@ pub const DEFAULT_TIMEOUT: u64 = 30;
 /// Maximum number of retries for failed requests
 pub const MAX_RETRIES: u32 = 3;
 
+/// Delay before the first retry, in milliseconds; doubles on each further retry
+pub const RETRY_BASE_DELAY_MS: u64 = 100;
+
+/// Upper bound on the delay between retries, in milliseconds
+pub const RETRY_MAX_DELAY_MS: u64 = 5_000;
+
 /// Production base URL for Deribit API
 pub const PRODUCTION_BASE_URL: &str = "https://www.deribit.com/api/v2";
 
 /// Testnet base URL for Deribit API
 pub const TESTNET_BASE_URL: &str = "https://test.deribit.com/api/v2";
 
+/// Maximum number of days returned per `get_apr_history` page
+pub const APR_HISTORY_PAGE_LIMIT: u32 = 365;
+
+/// Maximum number of records returned per `get_delivery_prices` page
+pub const DELIVERY_PRICES_PAGE_LIMIT: u32 = 1000;
+
+/// Number of settlement events requested per page when following continuations
+pub const SETTLEMENTS_PAGE_COUNT: u32 = 1000;
+
+/// Candles requested per `get_tradingview_chart_data` call by `download_candles`
+pub const CHART_CANDLES_PER_REQUEST: u64 = 5000;
+
+/// Number of `get_ticker` requests `get_options` keeps in flight at once
+pub const OPTION_TICKER_CONCURRENCY: usize = 16;
+
+/// Currencies valued at one US dollar when they have no `_usd` price index
+pub const USD_STABLECOINS: &[&str] = &["USDC", "USDT"];
+
+/// Order states after which an order can no longer be filled
+pub const FINAL_ORDER_STATES: &[&str] = &["filled", "cancelled", "rejected"];
+
+/// Time between the `get_order_state` polls of `wait_for_fill`
+pub const ORDER_STATE_POLL_INTERVAL: std::time::Duration = std::time::Duration::from_millis(500);
+
+/// Shortest interval between the polls of a `PollingStream` or the checks of a `HealthMonitor`
+pub const MIN_POLL_INTERVAL: std::time::Duration = std::time::Duration::from_millis(100);
+
+/// Health events buffered for each receiver of a `HealthMonitor`
+pub const HEALTH_EVENT_CAPACITY: usize = 16;
+
+/// Number of trigger order events requested per page when following continuations
+pub const TRIGGER_ORDER_HISTORY_PAGE_COUNT: u32 = 1000;
+
+/// Number of internal transfers requested per `get_transfers` page
+pub const TRANSFERS_PAGE_COUNT: u32 = 1000;
+
+/// Number of deposits requested per `get_deposits` page
+pub const DEPOSITS_PAGE_COUNT: u32 = 1000;
+
+/// Number of withdrawals requested per `get_withdrawals` page
+pub const WITHDRAWALS_PAGE_COUNT: u32 = 1000;
+
+/// Number of orders requested per `get_order_history_*` page when streaming
+pub const ORDER_HISTORY_PAGE_COUNT: u32 = 1000;
+
 /// API endpoints
 pub mod endpoints {
     // Authentication endpoints
     /// Public authentication endpoint
     pub const AUTH: &str = "/public/auth";
+    /// Exchange a refresh token for one scoped to another subject (subaccount)
+    pub const EXCHANGE_TOKEN: &str = "/public/exchange_token";
+    /// Create a new named session from a refresh token
+    pub const FORK_TOKEN: &str = "/public/fork_token";
+    /// Close the session, optionally invalidating its tokens
+    pub const LOGOUT: &str = "/private/logout";
 
     // Public market data endpoints
     /// Get ticker information for an instrument
@@ -156,6 +213,8 @@ pub mod endpoints {
     /// Get settlement history by instrument
     pub const GET_SETTLEMENT_HISTORY_BY_INSTRUMENT: &str =
         "/private/get_settlement_history_by_instrument";
+    /// Get stop order history (legacy name of trigger order history)
+    pub const GET_STOP_ORDER_HISTORY: &str = "/private/get_stop_order_history";
     /// Get trigger order history
     pub const GET_TRIGGER_ORDER_HISTORY: &str = "/private/get_trigger_order_history";
 
@@ -182,6 +241,8 @@ pub mod endpoints {
     pub const TOGGLE_SUBACCOUNT_LOGIN: &str = "/private/toggle_subaccount_login";
     /// Set email address for a subaccount
     pub const SET_EMAIL_FOR_SUBACCOUNT: &str = "/private/set_email_for_subaccount";
+    /// Set password for a subaccount
+    pub const SET_PASSWORD_FOR_SUBACCOUNT: &str = "/private/set_password_for_subaccount";
     /// Enable or disable notifications for a subaccount
     pub const TOGGLE_NOTIFICATIONS_FROM_SUBACCOUNT: &str =
         "/private/toggle_notifications_from_subaccount";
@@ -294,12 +355,20 @@ pub mod endpoints {
     pub const LIST_CUSTODY_ACCOUNTS: &str = "/private/list_custody_accounts";
     /// Simulate portfolio margin
     pub const SIMULATE_PORTFOLIO: &str = "/private/simulate_portfolio";
+    /// Get portfolio margin details
+    pub const GET_PORTFOLIO_MARGINS: &str = "/private/get_portfolio_margins";
     /// PME margin simulation
     pub const PME_SIMULATE: &str = "/private/pme/simulate";
     /// Change margin model
     pub const CHANGE_MARGIN_MODEL: &str = "/private/change_margin_model";
     /// Set self-trading configuration
     pub const SET_SELF_TRADING_CONFIG: &str = "/private/set_self_trading_config";
+    /// Get cancel-on-disconnect configuration
+    pub const GET_CANCEL_ON_DISCONNECT: &str = "/private/get_cancel_on_disconnect";
+    /// Enable cancel-on-disconnect
+    pub const ENABLE_CANCEL_ON_DISCONNECT: &str = "/private/enable_cancel_on_disconnect";
+    /// Disable cancel-on-disconnect
+    pub const DISABLE_CANCEL_ON_DISCONNECT: &str = "/private/disable_cancel_on_disconnect";
     /// Set disabled trading products
     pub const SET_DISABLED_TRADING_PRODUCTS: &str = "/private/set_disabled_trading_products";
     /// Get public announcements
@@ -362,6 +431,14 @@ pub mod endpoints {
     pub const CANCEL_BLOCK_RFQ_QUOTE: &str = "/private/cancel_block_rfq_quote";
     /// Cancel all Block RFQ quotes (maker)
     pub const CANCEL_ALL_BLOCK_RFQ_QUOTES: &str = "/private/cancel_all_block_rfq_quotes";
+
+    // RFQ endpoints
+    /// Get active requests for quote (public)
+    pub const GET_RFQS: &str = "/public/get_rfqs";
+    /// Send a request for quote
+    pub const SEND_RFQ: &str = "/private/send_rfq";
+    /// Cancel a request for quote
+    pub const CANCEL_RFQ: &str = "/private/cancel_rfq";
 }
 
 /// HTTP headers
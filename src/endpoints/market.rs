@@ -0,0 +1,88 @@
+//! Market-wide scans across currencies
+//!
+//! [`get_all_book_summaries`] fetches the book summaries of every supported
+//! currency concurrently, for scanners that watch the whole exchange.
+//!
+//! [`get_all_book_summaries`]: DeribitHttpClient::get_all_book_summaries
+
+use crate::DeribitHttpClient;
+use crate::error::HttpError;
+use crate::model::book::BookSummaries;
+use crate::model::instrument::InstrumentKind;
+use futures_util::future::join_all;
+
+/// Outcome of [`DeribitHttpClient::get_all_book_summaries`]
+///
+/// Currencies whose request failed are listed in `errors`; the summaries of
+/// the others are still returned.
+#[derive(Debug)]
+pub struct AllBookSummaries {
+    /// Summaries of every currency that answered, in currency order
+    pub summaries: BookSummaries,
+    /// Currencies whose book summaries could not be fetched, with the error
+    pub errors: Vec<(String, HttpError)>,
+}
+
+impl AllBookSummaries {
+    /// Whether every currency answered
+    pub fn is_complete(&self) -> bool {
+        self.errors.is_empty()
+    }
+}
+
+impl DeribitHttpClient {
+    /// Book summaries of every supported currency, fetched concurrently
+    ///
+    /// Lists the currencies with `get_currencies`, then calls
+    /// `get_book_summary_by_currency` for each of them at once and merges the
+    /// results. A failing currency does not fail the scan; it is reported in
+    /// [`AllBookSummaries::errors`].
+    ///
+    /// # Arguments
+    ///
+    /// * `kind` - Only instruments of this kind (optional)
+    ///
+    /// # Errors
+    ///
+    /// Returns `HttpError` if the currencies cannot be listed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use deribit_http::DeribitHttpClient;
+    /// use deribit_http::model::instrument::InstrumentKind;
+    ///
+    /// # async fn example() -> Result<(), deribit_http::HttpError> {
+    /// let client = DeribitHttpClient::new();
+    /// let mut scan = client.get_all_book_summaries(Some(InstrumentKind::Future)).await?;
+    /// scan.summaries.sort_by_volume();
+    /// for summary in scan.summaries.summaries.iter().take(10) {
+    ///     println!("{}: {:?}", summary.instrument_name, summary.volume_usd);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_all_book_summaries(
+        &self,
+        kind: Option<InstrumentKind>,
+    ) -> Result<AllBookSummaries, HttpError> {
+        let currencies = self.get_currencies().await?;
+        let results = join_all(currencies.into_iter().map(|currency| async move {
+            let summaries = self
+                .get_book_summary_by_currency(&currency.currency, kind)
+                .await;
+            (currency.currency, summaries)
+        }))
+        .await;
+
+        let mut summaries = BookSummaries::new();
+        let mut errors = Vec::new();
+        for (currency, result) in results {
+            match result {
+                Ok(books) => summaries.summaries.extend(books),
+                Err(e) => errors.push((currency, e)),
+            }
+        }
+        Ok(AllBookSummaries { summaries, errors })
+    }
+}
@@ -1,2 +1,13 @@
+pub mod market;
+pub mod portfolio;
 pub mod private;
 pub mod public;
+pub mod risk;
+pub mod strategy;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod streams;
+pub mod subaccount;
+
+pub use market::AllBookSummaries;
+pub use risk::FlattenReport;
+pub use subaccount::SubaccountClient;
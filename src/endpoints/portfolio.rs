@@ -0,0 +1,209 @@
+//! Cross-currency portfolio helpers
+//!
+//! Combine the per-currency account endpoints into one account-wide view
+//! valued in USD ([`get_portfolio_overview`]), and positions with option
+//! tickers into net greeks per underlying ([`get_exposure`]), and the
+//! transaction log with funding rates into funding paid and received per
+//! perpetual ([`get_funding_pnl`]).
+//!
+//! [`get_portfolio_overview`]: DeribitHttpClient::get_portfolio_overview
+//! [`get_exposure`]: DeribitHttpClient::get_exposure
+//! [`get_funding_pnl`]: DeribitHttpClient::get_funding_pnl
+
+use crate::DeribitHttpClient;
+use crate::constants::USD_STABLECOINS;
+use crate::error::HttpError;
+use crate::model::funding::FundingReport;
+use crate::model::other::Greeks;
+use crate::model::portfolio::{CurrencyOverview, Exposure, PortfolioOverview};
+use crate::model::time_range::TimeRange;
+use crate::model::transaction::{TransactionLogEntry, TransactionLogRequest};
+use futures_util::future::{try_join, try_join_all};
+use futures_util::stream::TryStreamExt;
+use std::collections::{HashMap, HashSet};
+
+impl DeribitHttpClient {
+    /// Balances, margins and positions of every currency in the account, valued in USD
+    ///
+    /// Fetches the account summaries and the index price names, then each
+    /// currency's positions and `{currency}_usd` index price concurrently.
+    /// USD stablecoins without an index are valued at one dollar; other
+    /// currencies without an index are left out of the USD totals.
+    ///
+    /// # Errors
+    ///
+    /// Returns `HttpError` if any of the requests fails.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use deribit_http::DeribitHttpClient;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = DeribitHttpClient::new();
+    /// let overview = client.get_portfolio_overview().await?;
+    /// println!(
+    ///     "equity ${:.0}, margin usage {:?}",
+    ///     overview.total_equity_usd(),
+    ///     overview.margin_usage()
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_portfolio_overview(&self) -> Result<PortfolioOverview, HttpError> {
+        let (summaries, index_names) = try_join(
+            self.get_account_summaries(None, None),
+            self.get_index_price_names(),
+        )
+        .await?;
+        let index_names: HashSet<String> = index_names.into_iter().collect();
+
+        let currencies = try_join_all(summaries.summaries.into_iter().map(|summary| {
+            let index_name = format!("{}_usd", summary.currency.to_lowercase());
+            let has_index = index_names.contains(&index_name);
+            async move {
+                let positions = self.get_positions(Some(&summary.currency), None, None);
+                let index_price = async {
+                    if has_index {
+                        Ok(Some(self.get_index_price(&index_name).await?.index_price))
+                    } else if USD_STABLECOINS.contains(&summary.currency.as_str()) {
+                        Ok(Some(1.0))
+                    } else {
+                        Ok(None)
+                    }
+                };
+                let (positions, index_price_usd) = try_join(positions, index_price).await?;
+                Ok::<_, HttpError>(CurrencyOverview {
+                    currency: summary.currency.clone(),
+                    summary,
+                    positions,
+                    index_price_usd,
+                })
+            }
+        }))
+        .await?;
+
+        Ok(PortfolioOverview { currencies })
+    }
+
+    /// Net delta, gamma, vega and theta of a currency's positions per underlying
+    ///
+    /// Uses the greeks Deribit reports on each position. Tickers are fetched,
+    /// concurrently, only for option positions that lack some of them. See
+    /// [`Exposure::from_positions`].
+    ///
+    /// # Arguments
+    ///
+    /// * `currency` - Settlement currency of the positions (BTC, ETH, USDC, ...)
+    ///
+    /// # Errors
+    ///
+    /// Returns `HttpError` if the positions or a ticker cannot be fetched.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use deribit_http::DeribitHttpClient;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = DeribitHttpClient::new();
+    /// let exposure = client.get_exposure("BTC").await?;
+    /// if let Some(btc) = exposure.underlying("BTC") {
+    ///     println!("net delta {:.4}, vega {:.2}", btc.delta, btc.vega);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_exposure(&self, currency: impl AsRef<str>) -> Result<Exposure, HttpError> {
+        let currency = currency.as_ref();
+        let positions = self.get_positions(Some(currency), None, None).await?;
+
+        let missing_greeks = positions.iter().filter(|position| {
+            position.is_option()
+                && (position.delta.is_none()
+                    || position.gamma.is_none()
+                    || position.vega.is_none()
+                    || position.theta.is_none())
+        });
+        let tickers =
+            try_join_all(missing_greeks.map(|position| self.get_ticker(&position.instrument_name)))
+                .await?;
+        let option_greeks: HashMap<String, Greeks> = tickers
+            .into_iter()
+            .filter_map(|ticker| Some((ticker.instrument_name, ticker.greeks?)))
+            .collect();
+
+        Ok(Exposure::from_positions(
+            currency,
+            &positions,
+            &option_greeks,
+        ))
+    }
+
+    /// Funding paid and received per perpetual over `range`
+    ///
+    /// Reads every transaction log entry of `currency` in the window and sums
+    /// their funding per perpetual (see [`FundingReport::from_transactions`]),
+    /// then fetches each of those perpetuals' funding rate history
+    /// concurrently for its mean 8h rate.
+    ///
+    /// # Arguments
+    ///
+    /// * `currency` - Currency of the transaction log (BTC, ETH, USDC, ...)
+    /// * `range` - Time window, e.g. `TimeRange::last_hours(24 * 7)`
+    ///
+    /// # Errors
+    ///
+    /// Returns `HttpErrorKind::ConfigError` if the window is inverted, or
+    /// `HttpError` if a request fails.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use deribit_http::DeribitHttpClient;
+    /// use deribit_http::model::TimestampMs;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = DeribitHttpClient::new();
+    /// let week = (
+    ///     TimestampMs::from_millis(1_700_000_000_000),
+    ///     TimestampMs::from_millis(1_700_604_800_000),
+    /// );
+    /// let report = client
+    ///     .get_funding_pnl("BTC", week)
+    ///     .await?;
+    /// for pnl in &report.instruments {
+    ///     println!("{}: {:+.8} BTC", pnl.instrument_name, pnl.realized);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_funding_pnl(
+        &self,
+        currency: impl AsRef<str>,
+        range: impl Into<TimeRange>,
+    ) -> Result<FundingReport, HttpError> {
+        let currency = currency.as_ref();
+        let (start, end) = range.into().resolve()?;
+        let entries: Vec<TransactionLogEntry> = self
+            .stream_transaction_log(TransactionLogRequest::new(currency, (start, end)))
+            .try_collect()
+            .await?;
+        let mut report = FundingReport::from_transactions(currency, start, end, &entries);
+
+        let rates = try_join_all(
+            report
+                .instruments
+                .iter()
+                .map(|pnl| self.get_funding_rate_history(&pnl.instrument_name, (start, end))),
+        )
+        .await?;
+        for (pnl, rates) in report.instruments.iter_mut().zip(rates) {
+            if !rates.is_empty() {
+                let sum: f64 = rates.iter().map(|rate| rate.interest_8h).sum();
+                pnl.average_rate_8h = Some(sum / rates.len() as f64);
+            }
+        }
+        Ok(report)
+    }
+}
@@ -2,15 +2,22 @@
 
 use crate::DeribitHttpClient;
 use crate::constants::endpoints::*;
-use crate::error::HttpError;
+use crate::constants::{
+    DEPOSITS_PAGE_COUNT, FINAL_ORDER_STATES, ORDER_HISTORY_PAGE_COUNT, ORDER_STATE_POLL_INTERVAL,
+    TRANSFERS_PAGE_COUNT, TRIGGER_ORDER_HISTORY_PAGE_COUNT, WITHDRAWALS_PAGE_COUNT,
+};
+use crate::error::{HttpError, HttpErrorKind};
 use crate::model::account::Subaccount;
 use crate::model::api_key::{ApiKeyInfo, CreateApiKeyRequest, EditApiKeyRequest};
+use crate::model::deposit::Deposit;
+use crate::model::instrument::InstrumentKind;
+use crate::model::order::{OrderSide, OrderType};
+use crate::model::other::SortDirection;
 use crate::model::position::Position;
 use crate::model::request::mass_quote::MassQuoteRequest;
 use crate::model::request::order::OrderRequest;
 use crate::model::request::position::MovePositionTrade;
 use crate::model::request::trade::TradesRequest;
-use crate::model::response::api_response::ApiResponse;
 use crate::model::response::deposit::DepositsResponse;
 use crate::model::response::margin::{MarginsResponse, OrderMargin};
 use crate::model::response::mass_quote::MassQuoteResponse;
@@ -20,15 +27,27 @@ use crate::model::response::other::{
     AccountSummariesResponse, AccountSummaryResponse, SettlementsResponse, TransactionLogResponse,
     TransferResultResponse,
 };
-use crate::model::response::position::MovePositionResult;
+use crate::model::response::position::{MovePositionResult, MovePositionsResponse};
 use crate::model::response::subaccount::SubaccountDetails;
 use crate::model::response::transfer::{InternalTransfer, TransfersResponse};
-use crate::model::response::trigger::TriggerOrderHistoryResponse;
+use crate::model::response::trigger::{
+    StopOrderHistoryResponse, TriggerOrderHistoryEntry, TriggerOrderHistoryResponse,
+};
 use crate::model::response::withdrawal::WithdrawalsResponse;
+use crate::model::time_range::TimeRange;
+use crate::model::timestamp::TimestampMs;
+use crate::model::trade::UserTrade;
+use crate::model::transaction::TransactionLogEntry;
+use crate::model::types::{RequestParams, Withdrawal};
 use crate::model::{
     TransactionLogRequest, UserTradeResponseByOrder, UserTradeWithPaginationResponse,
 };
-use crate::prelude::Trigger;
+use crate::pagination::{Cursor, OffsetPage, paginate, trade_window};
+use crate::sleep_compat::sleep;
+use crate::time_compat::Instant;
+use futures_util::future::join_all;
+use futures_util::stream::{Stream, StreamExt, TryStreamExt};
+use std::time::Duration;
 
 /// Private endpoints implementation
 impl DeribitHttpClient {
@@ -53,63 +72,9 @@ impl DeribitHttpClient {
         &self,
         with_portfolio: Option<bool>,
     ) -> Result<Vec<Subaccount>, HttpError> {
-        let mut query_params = Vec::new();
-
-        if let Some(with_portfolio) = with_portfolio {
-            query_params.push(("with_portfolio".to_string(), with_portfolio.to_string()));
-        }
-
-        let query_string = if query_params.is_empty() {
-            String::new()
-        } else {
-            "?".to_string()
-                + &query_params
-                    .iter()
-                    .map(|(k, v)| format!("{}={}", k, urlencoding::encode(v)))
-                    .collect::<Vec<_>>()
-                    .join("&")
-        };
-
-        let url = format!("{}{}{}", self.base_url(), GET_SUBACCOUNTS, query_string);
-
-        let response = self.make_authenticated_request(&url).await?;
-
-        if !response.status().is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-            return Err(HttpError::RequestFailed(format!(
-                "Get subaccounts failed: {}",
-                error_text
-            )));
-        }
-
-        // Debug: Get raw response text first
-        let response_text = response.text().await.map_err(|e| {
-            HttpError::InvalidResponse(format!("Failed to read response text: {}", e))
-        })?;
-
-        tracing::debug!("Raw API response: {}", response_text);
-
-        let api_response: ApiResponse<Vec<Subaccount>> = serde_json::from_str(&response_text)
-            .map_err(|e| {
-                HttpError::InvalidResponse(format!(
-                    "Failed to parse JSON: {} - Raw response: {}",
-                    e, response_text
-                ))
-            })?;
+        let params = RequestParams::new().add_opt("with_portfolio", with_portfolio);
 
-        if let Some(error) = api_response.error {
-            return Err(HttpError::RequestFailed(format!(
-                "API error: {} - {}",
-                error.code, error.message
-            )));
-        }
-
-        api_response.result.ok_or_else(|| {
-            HttpError::InvalidResponse("No subaccounts data in response".to_string())
-        })
+        self.private_post(GET_SUBACCOUNTS, params).await
     }
 
     /// Get subaccounts details with positions
@@ -133,14 +98,14 @@ impl DeribitHttpClient {
     /// ```
     pub async fn get_subaccounts_details(
         &self,
-        currency: &str,
+        currency: impl AsRef<str>,
         with_open_orders: Option<bool>,
     ) -> Result<Vec<SubaccountDetails>, HttpError> {
-        let mut query = format!("?currency={}", urlencoding::encode(currency));
-        if let Some(with_open_orders) = with_open_orders {
-            query.push_str(&format!("&with_open_orders={}", with_open_orders));
-        }
-        self.private_get(GET_SUBACCOUNTS_DETAILS, &query).await
+        let currency = currency.as_ref();
+        let params = RequestParams::new()
+            .add("currency", currency)
+            .add_opt("with_open_orders", with_open_orders);
+        self.private_post(GET_SUBACCOUNTS_DETAILS, params).await
     }
 
     /// Create a new subaccount
@@ -165,7 +130,8 @@ impl DeribitHttpClient {
     /// // tracing::info!("Created subaccount with ID: {}", subaccount.id);
     /// ```
     pub async fn create_subaccount(&self) -> Result<Subaccount, HttpError> {
-        self.private_get(CREATE_SUBACCOUNT, "").await
+        self.private_post(CREATE_SUBACCOUNT, RequestParams::new())
+            .await
     }
 
     /// Remove an empty subaccount
@@ -194,8 +160,8 @@ impl DeribitHttpClient {
     /// // assert_eq!(result, "ok");
     /// ```
     pub async fn remove_subaccount(&self, subaccount_id: u64) -> Result<String, HttpError> {
-        let query = format!("?subaccount_id={}", subaccount_id);
-        self.private_get(REMOVE_SUBACCOUNT, &query).await
+        let params = RequestParams::new().add("subaccount_id", subaccount_id);
+        self.private_post(REMOVE_SUBACCOUNT, params).await
     }
 
     /// Change the name of a subaccount
@@ -225,8 +191,8 @@ impl DeribitHttpClient {
     /// // assert_eq!(result, "ok");
     /// ```
     pub async fn change_subaccount_name(&self, sid: u64, name: &str) -> Result<String, HttpError> {
-        let query = format!("?sid={}&name={}", sid, urlencoding::encode(name));
-        self.private_get(CHANGE_SUBACCOUNT_NAME, &query).await
+        let params = RequestParams::new().add("sid", sid).add("name", name);
+        self.private_post(CHANGE_SUBACCOUNT_NAME, params).await
     }
 
     /// Enable or disable login for a subaccount
@@ -261,8 +227,8 @@ impl DeribitHttpClient {
         sid: u64,
         state: &str,
     ) -> Result<String, HttpError> {
-        let query = format!("?sid={}&state={}", sid, urlencoding::encode(state));
-        self.private_get(TOGGLE_SUBACCOUNT_LOGIN, &query).await
+        let params = RequestParams::new().add("sid", sid).add("state", state);
+        self.private_post(TOGGLE_SUBACCOUNT_LOGIN, params).await
     }
 
     /// Set email address for a subaccount
@@ -297,8 +263,46 @@ impl DeribitHttpClient {
         sid: u64,
         email: &str,
     ) -> Result<String, HttpError> {
-        let query = format!("?sid={}&email={}", sid, urlencoding::encode(email));
-        self.private_get(SET_EMAIL_FOR_SUBACCOUNT, &query).await
+        let params = RequestParams::new().add("sid", sid).add("email", email);
+        self.private_post(SET_EMAIL_FOR_SUBACCOUNT, params).await
+    }
+
+    /// Set password for a subaccount
+    ///
+    /// Sets the web login password of a subaccount. The password travels in the
+    /// JSON-RPC body, never in the URL.
+    ///
+    /// # Arguments
+    ///
+    /// * `sid` - The subaccount ID
+    /// * `password` - The new password
+    ///
+    /// # Returns
+    ///
+    /// Returns `"ok"` on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns `HttpError` if the request fails or the password is rejected.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use deribit_http::DeribitHttpClient;
+    ///
+    /// let client = DeribitHttpClient::new();
+    /// // let result = client.set_password_for_subaccount(123, "n3w-Passw0rd").await?;
+    /// // assert_eq!(result, "ok");
+    /// ```
+    pub async fn set_password_for_subaccount(
+        &self,
+        sid: u64,
+        password: &str,
+    ) -> Result<String, HttpError> {
+        let params = RequestParams::new()
+            .add("sid", sid)
+            .add("password", password);
+        self.private_post(SET_PASSWORD_FOR_SUBACCOUNT, params).await
     }
 
     /// Enable or disable notifications for a subaccount
@@ -332,8 +336,8 @@ impl DeribitHttpClient {
         sid: u64,
         state: bool,
     ) -> Result<String, HttpError> {
-        let query = format!("?sid={}&state={}", sid, state);
-        self.private_get(TOGGLE_NOTIFICATIONS_FROM_SUBACCOUNT, &query)
+        let params = RequestParams::new().add("sid", sid).add("state", state);
+        self.private_post(TOGGLE_NOTIFICATIONS_FROM_SUBACCOUNT, params)
             .await
     }
 
@@ -364,32 +368,65 @@ impl DeribitHttpClient {
         &self,
         request: TransactionLogRequest,
     ) -> Result<TransactionLogResponse, HttpError> {
-        let mut query_params = vec![
-            ("currency", request.currency.to_string()),
-            ("start_timestamp", request.start_timestamp.to_string()),
-            ("end_timestamp", request.end_timestamp.to_string()),
-        ];
-        if let Some(query) = request.query {
-            query_params.push(("query", query));
-        }
-        if let Some(count) = request.count {
-            query_params.push(("count", count.to_string()));
-        }
-        if let Some(subaccount_id) = request.subaccount_id {
-            query_params.push(("subaccount_id", subaccount_id.to_string()));
-        }
-        if let Some(continuation) = request.continuation {
-            query_params.push(("continuation", continuation.to_string()));
-        }
-        let query = format!(
-            "?{}",
-            query_params
-                .iter()
-                .map(|(k, v)| format!("{}={}", k, urlencoding::encode(v)))
-                .collect::<Vec<_>>()
-                .join("&")
-        );
-        self.private_get(GET_TRANSACTION_LOG, &query).await
+        let params = RequestParams::new()
+            .add("currency", request.currency)
+            .add("start_timestamp", request.start_timestamp)
+            .add("end_timestamp", request.end_timestamp)
+            .add_opt("query", request.query)
+            .add_opt("count", request.count)
+            .add_opt("subaccount_id", request.subaccount_id)
+            .add_opt("continuation", request.continuation);
+        self.private_post(GET_TRANSACTION_LOG, params).await
+    }
+
+    /// Stream transaction log entries
+    ///
+    /// Follows the `continuation` token of
+    /// [`get_transaction_log`](Self::get_transaction_log), starting from
+    /// `request.continuation` if set, until the log is exhausted. Each page
+    /// requests `request.count` entries.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - Currency, time window and filters, as for `get_transaction_log`
+    ///
+    pub fn stream_transaction_log(
+        &self,
+        request: TransactionLogRequest,
+    ) -> impl Stream<Item = Result<TransactionLogEntry, HttpError>> + '_ {
+        let start = request
+            .continuation
+            .map(|continuation| Cursor::Continuation(continuation.into()));
+        paginate(start, move |cursor: Option<Cursor>| {
+            let mut request = request.clone();
+            request.continuation = cursor
+                .as_ref()
+                .and_then(Cursor::continuation)
+                .and_then(|token| token.parse().ok());
+            async move { self.get_transaction_log(request).await }
+        })
+    }
+
+    /// Get every transaction log entry
+    ///
+    /// Collects [`stream_transaction_log`](Self::stream_transaction_log),
+    /// stopping after `max_entries` entries when set so that an unbounded
+    /// window cannot fetch the whole history by accident.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - Currency, time window and filters, as for `get_transaction_log`
+    /// * `max_entries` - Maximum number of entries to return (optional)
+    ///
+    pub async fn get_transaction_log_all(
+        &self,
+        request: TransactionLogRequest,
+        max_entries: Option<usize>,
+    ) -> Result<Vec<TransactionLogEntry>, HttpError> {
+        self.stream_transaction_log(request)
+            .take(max_entries.unwrap_or(usize::MAX))
+            .try_collect()
+            .await
     }
 
     /// Get deposits
@@ -413,18 +450,43 @@ impl DeribitHttpClient {
     /// ```
     pub async fn get_deposits(
         &self,
-        currency: &str,
+        currency: impl AsRef<str>,
         count: Option<u32>,
         offset: Option<u32>,
     ) -> Result<DepositsResponse, HttpError> {
-        let mut query = format!("?currency={}", urlencoding::encode(currency));
-        if let Some(count) = count {
-            query.push_str(&format!("&count={}", count));
-        }
-        if let Some(offset) = offset {
-            query.push_str(&format!("&offset={}", offset));
-        }
-        self.private_get(GET_DEPOSITS, &query).await
+        let currency = currency.as_ref();
+        let params = RequestParams::new()
+            .add("currency", currency)
+            .add_opt("count", count)
+            .add_opt("offset", offset);
+        self.private_post(GET_DEPOSITS, params).await
+    }
+
+    /// Stream deposits
+    ///
+    /// Walks the `count`/`offset` pages of `get_deposits`, yielding every
+    /// deposit until the reported total is reached or a page comes back empty.
+    ///
+    /// # Arguments
+    ///
+    /// * `currency` - Currency symbol (BTC, ETH, etc.)
+    ///
+    pub fn stream_deposits<'a>(
+        &'a self,
+        currency: impl AsRef<str>,
+    ) -> impl Stream<Item = Result<Deposit, HttpError>> + 'a {
+        let currency = currency.as_ref().to_owned();
+        paginate(None, move |cursor: Option<Cursor>| {
+            let currency = currency.clone();
+            async move {
+                self.get_deposits(
+                    &currency,
+                    Some(DEPOSITS_PAGE_COUNT),
+                    cursor.as_ref().and_then(Cursor::offset),
+                )
+                .await
+            }
+        })
     }
 
     /// Get withdrawals
@@ -448,18 +510,43 @@ impl DeribitHttpClient {
     /// ```
     pub async fn get_withdrawals(
         &self,
-        currency: &str,
+        currency: impl AsRef<str>,
         count: Option<u32>,
         offset: Option<u32>,
     ) -> Result<WithdrawalsResponse, HttpError> {
-        let mut query = format!("?currency={}", urlencoding::encode(currency));
-        if let Some(count) = count {
-            query.push_str(&format!("&count={}", count));
-        }
-        if let Some(offset) = offset {
-            query.push_str(&format!("&offset={}", offset));
-        }
-        self.private_get(GET_WITHDRAWALS, &query).await
+        let currency = currency.as_ref();
+        let params = RequestParams::new()
+            .add("currency", currency)
+            .add_opt("count", count)
+            .add_opt("offset", offset);
+        self.private_post(GET_WITHDRAWALS, params).await
+    }
+
+    /// Stream withdrawals
+    ///
+    /// Walks the `count`/`offset` pages of `get_withdrawals`, yielding every
+    /// withdrawal until the reported total is reached or a page comes back empty.
+    ///
+    /// # Arguments
+    ///
+    /// * `currency` - Currency symbol (BTC, ETH, etc.)
+    ///
+    pub fn stream_withdrawals<'a>(
+        &'a self,
+        currency: impl AsRef<str>,
+    ) -> impl Stream<Item = Result<Withdrawal, HttpError>> + 'a {
+        let currency = currency.as_ref().to_owned();
+        paginate(None, move |cursor: Option<Cursor>| {
+            let currency = currency.clone();
+            async move {
+                self.get_withdrawals(
+                    &currency,
+                    Some(WITHDRAWALS_PAGE_COUNT),
+                    cursor.as_ref().and_then(Cursor::offset),
+                )
+                .await
+            }
+        })
     }
 
     /// Submit transfer to subaccount
@@ -483,17 +570,16 @@ impl DeribitHttpClient {
     /// ```
     pub async fn submit_transfer_to_subaccount(
         &self,
-        currency: &str,
+        currency: impl AsRef<str>,
         amount: f64,
         destination: u64,
     ) -> Result<TransferResultResponse, HttpError> {
-        let query = format!(
-            "?currency={}&amount={}&destination={}",
-            urlencoding::encode(currency),
-            amount,
-            destination
-        );
-        self.private_get(SUBMIT_TRANSFER_TO_SUBACCOUNT, &query)
+        let currency = currency.as_ref();
+        let params = RequestParams::new()
+            .add("currency", currency)
+            .add("amount", amount)
+            .add("destination", destination);
+        self.private_post(SUBMIT_TRANSFER_TO_SUBACCOUNT, params)
             .await
     }
 
@@ -518,17 +604,16 @@ impl DeribitHttpClient {
     /// ```
     pub async fn submit_transfer_to_user(
         &self,
-        currency: &str,
+        currency: impl AsRef<str>,
         amount: f64,
         destination: &str,
     ) -> Result<TransferResultResponse, HttpError> {
-        let query = format!(
-            "?currency={}&amount={}&destination={}",
-            urlencoding::encode(currency),
-            amount,
-            urlencoding::encode(destination)
-        );
-        self.private_get(SUBMIT_TRANSFER_TO_USER, &query).await
+        let currency = currency.as_ref();
+        let params = RequestParams::new()
+            .add("currency", currency)
+            .add("amount", amount)
+            .add("destination", destination);
+        self.private_post(SUBMIT_TRANSFER_TO_USER, params).await
     }
 
     /// Get transfers list
@@ -560,18 +645,43 @@ impl DeribitHttpClient {
     /// ```
     pub async fn get_transfers(
         &self,
-        currency: &str,
+        currency: impl AsRef<str>,
         count: Option<u32>,
         offset: Option<u32>,
     ) -> Result<TransfersResponse, HttpError> {
-        let mut query = format!("?currency={}", urlencoding::encode(currency));
-        if let Some(c) = count {
-            query.push_str(&format!("&count={}", c));
-        }
-        if let Some(o) = offset {
-            query.push_str(&format!("&offset={}", o));
-        }
-        self.private_get(GET_TRANSFERS, &query).await
+        let currency = currency.as_ref();
+        let params = RequestParams::new()
+            .add("currency", currency)
+            .add_opt("count", count)
+            .add_opt("offset", offset);
+        self.private_post(GET_TRANSFERS, params).await
+    }
+
+    /// Stream internal transfers
+    ///
+    /// Walks the `count`/`offset` pages of `get_transfers`, yielding every
+    /// transfer until the reported total is reached.
+    ///
+    /// # Arguments
+    ///
+    /// * `currency` - Currency symbol (BTC, ETH, etc.)
+    ///
+    pub fn stream_transfers<'a>(
+        &'a self,
+        currency: impl AsRef<str>,
+    ) -> impl Stream<Item = Result<InternalTransfer, HttpError>> + 'a {
+        let currency = currency.as_ref().to_owned();
+        paginate(None, move |cursor: Option<Cursor>| {
+            let currency = currency.clone();
+            async move {
+                self.get_transfers(
+                    &currency,
+                    Some(TRANSFERS_PAGE_COUNT),
+                    cursor.as_ref().and_then(Cursor::offset),
+                )
+                .await
+            }
+        })
     }
 
     /// Cancel a transfer by ID
@@ -602,11 +712,12 @@ impl DeribitHttpClient {
     /// ```
     pub async fn cancel_transfer_by_id(
         &self,
-        currency: &str,
+        currency: impl AsRef<str>,
         id: i64,
     ) -> Result<InternalTransfer, HttpError> {
-        let query = format!("?currency={}&id={}", urlencoding::encode(currency), id);
-        self.private_get(CANCEL_TRANSFER_BY_ID, &query).await
+        let currency = currency.as_ref();
+        let params = RequestParams::new().add("currency", currency).add("id", id);
+        self.private_post(CANCEL_TRANSFER_BY_ID, params).await
     }
 
     /// Submit transfer between subaccounts
@@ -626,7 +737,9 @@ impl DeribitHttpClient {
     ///
     /// # Errors
     ///
-    /// Returns `HttpError` if the transfer fails or validation fails.
+    /// Returns `HttpErrorKind::ConfigError` without sending the request if the
+    /// amount is not positive or `source` equals `destination`, and
+    /// `HttpError` if the transfer fails or validation fails on Deribit.
     ///
     /// # Examples
     ///
@@ -639,21 +752,33 @@ impl DeribitHttpClient {
     /// ```
     pub async fn submit_transfer_between_subaccounts(
         &self,
-        currency: &str,
+        currency: impl AsRef<str>,
         amount: f64,
         destination: i64,
         source: Option<i64>,
     ) -> Result<InternalTransfer, HttpError> {
-        let mut query = format!(
-            "?currency={}&amount={}&destination={}",
-            urlencoding::encode(currency),
-            amount,
-            destination
-        );
-        if let Some(s) = source {
-            query.push_str(&format!("&source={}", s));
-        }
-        self.private_get(SUBMIT_TRANSFER_BETWEEN_SUBACCOUNTS, &query)
+        let currency = currency.as_ref();
+        if !amount.is_finite() || amount <= 0.0 {
+            return Err(HttpErrorKind::ConfigError(format!(
+                "transfer amount must be positive, got {}",
+                amount
+            ))
+            .into());
+        }
+        if source == Some(destination) {
+            return Err(HttpErrorKind::ConfigError(format!(
+                "transfer source and destination are both subaccount {}",
+                destination
+            ))
+            .into());
+        }
+
+        let params = RequestParams::new()
+            .add("currency", currency)
+            .add("amount", amount)
+            .add("destination", destination)
+            .add_opt("source", source);
+        self.private_post(SUBMIT_TRANSFER_BETWEEN_SUBACCOUNTS, params)
             .await
     }
 
@@ -666,107 +791,10 @@ impl DeribitHttpClient {
     /// * `request` - The buy order request parameters
     ///
     pub async fn buy_order(&self, request: OrderRequest) -> Result<OrderResponse, HttpError> {
-        let mut query_params = vec![
-            ("instrument_name".to_string(), request.instrument_name),
-            (
-                "amount".to_string(),
-                request
-                    .amount
-                    .map_or_else(|| "0".to_string(), |a| a.to_string()),
-            ),
-        ];
-
-        if let Some(order_type) = request.type_ {
-            query_params.push(("type".to_string(), order_type.as_str().to_string()));
-        }
-
-        if let Some(price) = request.price {
-            query_params.push(("price".to_string(), price.to_string()));
-        }
-
-        if let Some(label) = request.label {
-            query_params.push(("label".to_string(), label));
-        }
-
-        if let Some(time_in_force) = request.time_in_force {
-            query_params.push((
-                "time_in_force".to_string(),
-                time_in_force.as_str().to_string(),
-            ));
-        }
-
-        if let Some(post_only) = request.post_only
-            && post_only
-        {
-            query_params.push(("post_only".to_string(), "true".to_string()));
-        }
-
-        if let Some(reduce_only) = request.reduce_only
-            && reduce_only
-        {
-            query_params.push(("reduce_only".to_string(), "true".to_string()));
-        }
-
-        if let Some(trigger_price) = request.trigger_price {
-            query_params.push(("trigger_price".to_string(), trigger_price.to_string()));
-        }
-
-        if let Some(trigger) = request.trigger {
-            let trigger_str = match trigger {
-                Trigger::IndexPrice => "index_price",
-                Trigger::MarkPrice => "mark_price",
-                Trigger::LastPrice => "last_price",
-            };
-            query_params.push(("trigger".to_string(), trigger_str.to_string()));
-        }
-
-        let query_string = query_params
-            .iter()
-            .map(|(k, v)| format!("{}={}", k, urlencoding::encode(v)))
-            .collect::<Vec<_>>()
-            .join("&");
-
-        let url = format!("{}{}?{}", self.base_url(), BUY, query_string);
-
-        let response = self.make_authenticated_request(&url).await?;
-
-        if !response.status().is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-            return Err(HttpError::RequestFailed(format!(
-                "Buy order failed: {}",
-                error_text
-            )));
-        }
-
-        // Debug: capture raw response text first
-        let response_text = response
-            .text()
-            .await
-            .map_err(|e| HttpError::NetworkError(e.to_string()))?;
-
-        tracing::debug!("Raw API response: {}", response_text);
-
-        let api_response: ApiResponse<OrderResponse> = serde_json::from_str(&response_text)
-            .map_err(|e| {
-                HttpError::InvalidResponse(format!(
-                    "Failed to parse JSON: {} - Raw response: {}",
-                    e, response_text
-                ))
-            })?;
+        let request = self.normalize_order_prices(request).await?;
+        let params = order_params(request);
 
-        if let Some(error) = api_response.error {
-            return Err(HttpError::RequestFailed(format!(
-                "API error: {} - {}",
-                error.code, error.message
-            )));
-        }
-
-        api_response
-            .result
-            .ok_or_else(|| HttpError::InvalidResponse("No order data in response".to_string()))
+        self.private_post(BUY, params).await
     }
 
     /// Place a sell order
@@ -777,91 +805,102 @@ impl DeribitHttpClient {
     ///
     /// * `request` - The sell order request parameters
     pub async fn sell_order(&self, request: OrderRequest) -> Result<OrderResponse, HttpError> {
-        let mut query_params = vec![
-            ("instrument_name".to_string(), request.instrument_name),
-            ("amount".to_string(), request.amount.unwrap().to_string()),
-        ];
-
-        if let Some(order_type) = request.type_ {
-            query_params.push(("type".to_string(), order_type.as_str().to_string()));
-        }
+        let request = self.normalize_order_prices(request).await?;
+        let params = order_params(request);
 
-        if let Some(price) = request.price {
-            query_params.push(("price".to_string(), price.to_string()));
-        }
-
-        if let Some(label) = request.label {
-            query_params.push(("label".to_string(), label));
-        }
-
-        if let Some(time_in_force) = request.time_in_force {
-            query_params.push((
-                "time_in_force".to_string(),
-                time_in_force.as_str().to_string(),
-            ));
-        }
+        self.private_post(SELL, params).await
+    }
 
-        if let Some(post_only) = request.post_only
-            && post_only
+    /// Round an order's prices to its instrument's tick size and check its
+    /// amount against the minimum trade amount
+    ///
+    /// Does nothing unless [`HttpConfig::price_normalization`] is set, or when
+    /// the request names no instrument (e.g. an edit by order id only).
+    ///
+    /// [`HttpConfig::price_normalization`]: crate::config::HttpConfig::price_normalization
+    async fn normalize_order_prices(
+        &self,
+        mut request: OrderRequest,
+    ) -> Result<OrderRequest, HttpError> {
+        let Some(normalization) = self.config().price_normalization else {
+            return Ok(request);
+        };
+        if request.instrument_name.is_empty()
+            || (request.price.is_none()
+                && request.trigger_price.is_none()
+                && request.amount.is_none())
         {
-            query_params.push(("post_only".to_string(), "true".to_string()));
+            return Ok(request);
         }
 
-        if let Some(reduce_only) = request.reduce_only
-            && reduce_only
+        let instrument = self.cached_instrument(&request.instrument_name).await?;
+        if let (Some(amount), Some(min)) = (request.amount, instrument.min_trade_amount)
+            && amount < min
         {
-            query_params.push(("reduce_only".to_string(), "true".to_string()));
-        }
-
-        if let Some(trigger_price) = request.trigger_price {
-            query_params.push(("trigger_price".to_string(), trigger_price.to_string()));
-        }
-
-        if let Some(trigger) = request.trigger {
-            let trigger_str = match trigger {
-                Trigger::IndexPrice => "index_price",
-                Trigger::MarkPrice => "mark_price",
-                Trigger::LastPrice => "last_price",
+            return Err(HttpErrorKind::ConfigError(format!(
+                "amount {} of {} is below the minimum trade amount {}",
+                amount, request.instrument_name, min
+            ))
+            .into());
+        }
+        for (field, price) in [
+            ("price", &mut request.price),
+            ("trigger_price", &mut request.trigger_price),
+        ] {
+            let Some(original) = *price else {
+                continue;
             };
-            query_params.push(("trigger".to_string(), trigger_str.to_string()));
-        }
-
-        let query_string = query_params
-            .iter()
-            .map(|(k, v)| format!("{}={}", k, urlencoding::encode(v)))
-            .collect::<Vec<_>>()
-            .join("&");
-
-        let url = format!("{}{}?{}", self.base_url(), SELL, query_string);
-
-        let response = self.make_authenticated_request(&url).await?;
-
-        if !response.status().is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-            return Err(HttpError::RequestFailed(format!(
-                "Sell order failed: {}",
-                error_text
-            )));
-        }
-
-        let api_response: ApiResponse<OrderResponse> = response
-            .json()
-            .await
-            .map_err(|e| HttpError::InvalidResponse(e.to_string()))?;
-
-        if let Some(error) = api_response.error {
-            return Err(HttpError::RequestFailed(format!(
-                "API error: {} - {}",
-                error.code, error.message
-            )));
+            let Some(rounded) = instrument.round_to_tick(original) else {
+                continue;
+            };
+            if (rounded - original).abs() > normalization.max_relative_change * original.abs() {
+                return Err(HttpErrorKind::ConfigError(format!(
+                    "{} {} of {} is off tick by more than the accepted {}; nearest tick is {}",
+                    field,
+                    original,
+                    request.instrument_name,
+                    normalization.max_relative_change,
+                    rounded
+                ))
+                .into());
+            }
+            *price = Some(rounded);
         }
+        Ok(request)
+    }
 
-        api_response
-            .result
-            .ok_or_else(|| HttpError::InvalidResponse("No order data in response".to_string()))
+    /// Place many orders concurrently
+    ///
+    /// Sends every order at once and returns one outcome per order, in the
+    /// same order as `orders`. A rejected order does not stop the others.
+    /// Each request still waits on the matching engine credit pool, so large
+    /// batches are paced by the rate limiter rather than rejected with
+    /// `too_many_requests`.
+    ///
+    /// # Arguments
+    ///
+    /// * `orders` - Side and request of each order
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use deribit_http::DeribitHttpClient;
+    ///
+    /// let client = DeribitHttpClient::new();
+    /// // let ladder = vec![(OrderSide::Buy, bid_1), (OrderSide::Buy, bid_2)];
+    /// // for outcome in client.submit_orders(ladder).await { ... }
+    /// ```
+    pub async fn submit_orders(
+        &self,
+        orders: Vec<(OrderSide, OrderRequest)>,
+    ) -> Vec<Result<OrderResponse, HttpError>> {
+        join_all(orders.into_iter().map(|(side, request)| async move {
+            match side {
+                OrderSide::Buy => self.buy_order(request).await,
+                OrderSide::Sell => self.sell_order(request).await,
+            }
+        }))
+        .await
     }
 
     /// Cancel an order
@@ -873,8 +912,8 @@ impl DeribitHttpClient {
     /// * `order_id` - The order ID to cancel
     ///
     pub async fn cancel_order(&self, order_id: &str) -> Result<OrderInfoResponse, HttpError> {
-        let query = format!("?order_id={}", urlencoding::encode(order_id));
-        self.private_get(CANCEL, &query).await
+        let params = RequestParams::new().add("order_id", order_id);
+        self.private_post(CANCEL, params).await
     }
 
     /// Cancel all orders
@@ -885,7 +924,7 @@ impl DeribitHttpClient {
     ///
     /// Returns the number of cancelled orders.
     pub async fn cancel_all(&self) -> Result<u32, HttpError> {
-        self.private_get(CANCEL_ALL, "").await
+        self.private_post(CANCEL_ALL, RequestParams::new()).await
     }
 
     /// Cancel all orders by currency
@@ -899,9 +938,13 @@ impl DeribitHttpClient {
     /// # Returns
     ///
     /// Returns the number of cancelled orders.
-    pub async fn cancel_all_by_currency(&self, currency: &str) -> Result<u32, HttpError> {
-        let query = format!("?currency={}", urlencoding::encode(currency));
-        self.private_get(CANCEL_ALL_BY_CURRENCY, &query).await
+    pub async fn cancel_all_by_currency(
+        &self,
+        currency: impl AsRef<str>,
+    ) -> Result<u32, HttpError> {
+        let currency = currency.as_ref();
+        let params = RequestParams::new().add("currency", currency);
+        self.private_post(CANCEL_ALL_BY_CURRENCY, params).await
     }
 
     /// Cancel all orders by currency pair
@@ -916,8 +959,8 @@ impl DeribitHttpClient {
     ///
     /// Returns the number of cancelled orders.
     pub async fn cancel_all_by_currency_pair(&self, currency_pair: &str) -> Result<u32, HttpError> {
-        let query = format!("?currency_pair={}", urlencoding::encode(currency_pair));
-        self.private_get(CANCEL_ALL_BY_CURRENCY_PAIR, &query).await
+        let params = RequestParams::new().add("currency_pair", currency_pair);
+        self.private_post(CANCEL_ALL_BY_CURRENCY_PAIR, params).await
     }
 
     /// Cancel all orders by instrument
@@ -932,8 +975,8 @@ impl DeribitHttpClient {
     ///
     /// Returns the number of cancelled orders.
     pub async fn cancel_all_by_instrument(&self, instrument_name: &str) -> Result<u32, HttpError> {
-        let query = format!("?instrument_name={}", urlencoding::encode(instrument_name));
-        self.private_get(CANCEL_ALL_BY_INSTRUMENT, &query).await
+        let params = RequestParams::new().add("instrument_name", instrument_name);
+        self.private_post(CANCEL_ALL_BY_INSTRUMENT, params).await
     }
 
     /// Cancel all orders by kind or type
@@ -950,22 +993,13 @@ impl DeribitHttpClient {
     /// Returns the number of cancelled orders.
     pub async fn cancel_all_by_kind_or_type(
         &self,
-        kind: Option<&str>,
+        kind: Option<InstrumentKind>,
         order_type: Option<&str>,
     ) -> Result<u32, HttpError> {
-        let mut query_params = Vec::new();
-        if let Some(kind) = kind {
-            query_params.push(format!("kind={}", urlencoding::encode(kind)));
-        }
-        if let Some(order_type) = order_type {
-            query_params.push(format!("type={}", urlencoding::encode(order_type)));
-        }
-        let query = if query_params.is_empty() {
-            String::new()
-        } else {
-            format!("?{}", query_params.join("&"))
-        };
-        self.private_get(CANCEL_ALL_BY_KIND_OR_TYPE, &query).await
+        let params = RequestParams::new()
+            .add_opt("kind", kind)
+            .add_opt("type", order_type);
+        self.private_post(CANCEL_ALL_BY_KIND_OR_TYPE, params).await
     }
 
     /// Cancel orders by label
@@ -980,8 +1014,8 @@ impl DeribitHttpClient {
     ///
     /// Returns the number of cancelled orders.
     pub async fn cancel_by_label(&self, label: &str) -> Result<u32, HttpError> {
-        let query = format!("?label={}", urlencoding::encode(label));
-        self.private_get(CANCEL_BY_LABEL, &query).await
+        let params = RequestParams::new().add("label", label);
+        self.private_post(CANCEL_BY_LABEL, params).await
     }
 
     /// Get account summary
@@ -995,14 +1029,39 @@ impl DeribitHttpClient {
     ///
     pub async fn get_account_summary(
         &self,
-        currency: &str,
+        currency: impl AsRef<str>,
         extended: Option<bool>,
     ) -> Result<AccountSummaryResponse, HttpError> {
-        let mut query = format!("?currency={}", urlencoding::encode(currency));
-        if let Some(extended) = extended {
-            query.push_str(&format!("&extended={}", extended));
-        }
-        self.private_get(GET_ACCOUNT_SUMMARY, &query).await
+        let currency = currency.as_ref();
+        let params = RequestParams::new()
+            .add("currency", currency)
+            .add_opt("extended", extended);
+        self.private_post(GET_ACCOUNT_SUMMARY, params).await
+    }
+
+    /// Get account summary for a subaccount
+    ///
+    /// Same as [`get_account_summary`](Self::get_account_summary) but scoped to the
+    /// given subaccount. Requires a main-account token.
+    ///
+    /// # Arguments
+    ///
+    /// * `currency` - Currency to get summary for (BTC, ETH, USDC, etc.)
+    /// * `subaccount_id` - The user id of the subaccount
+    /// * `extended` - Whether to include extended information
+    ///
+    pub async fn get_account_summary_for_subaccount(
+        &self,
+        currency: impl AsRef<str>,
+        subaccount_id: u64,
+        extended: Option<bool>,
+    ) -> Result<AccountSummaryResponse, HttpError> {
+        let currency = currency.as_ref();
+        let params = RequestParams::new()
+            .add("currency", currency)
+            .add("subaccount_id", subaccount_id)
+            .add_opt("extended", extended);
+        self.private_post(GET_ACCOUNT_SUMMARY, params).await
     }
 
     /// Get account summaries for all currencies
@@ -1030,19 +1089,10 @@ impl DeribitHttpClient {
         subaccount_id: Option<i64>,
         extended: Option<bool>,
     ) -> Result<AccountSummariesResponse, HttpError> {
-        let mut params = Vec::new();
-        if let Some(subaccount_id) = subaccount_id {
-            params.push(format!("subaccount_id={}", subaccount_id));
-        }
-        if let Some(extended) = extended {
-            params.push(format!("extended={}", extended));
-        }
-        let query = if params.is_empty() {
-            String::new()
-        } else {
-            format!("?{}", params.join("&"))
-        };
-        self.private_get(GET_ACCOUNT_SUMMARIES, &query).await
+        let params = RequestParams::new()
+            .add_opt("subaccount_id", subaccount_id)
+            .add_opt("extended", extended);
+        self.private_post(GET_ACCOUNT_SUMMARIES, params).await
     }
 
     /// Get positions
@@ -1061,36 +1111,26 @@ impl DeribitHttpClient {
     /// use deribit_http::DeribitHttpClient;
     ///
     /// let client = DeribitHttpClient::new();
-    /// // let positions = client.get_positions(Some("BTC"), Some("future"), None).await?;
+    /// // let positions = client.get_positions(Some("BTC"), Some(InstrumentKind::Future), None).await?;
     /// // println!("Found {} positions", positions.len());
     /// ```
     pub async fn get_positions(
         &self,
-        currency: Option<&str>,
-        kind: Option<&str>,
+        currency: Option<impl AsRef<str>>,
+        kind: Option<InstrumentKind>,
         subaccount_id: Option<i32>,
     ) -> Result<Vec<Position>, HttpError> {
-        let mut params = Vec::new();
-        if let Some(currency) = currency {
-            params.push(format!("currency={}", urlencoding::encode(currency)));
-        }
-        if let Some(kind) = kind {
-            params.push(format!("kind={}", urlencoding::encode(kind)));
-        }
-        if let Some(subaccount_id) = subaccount_id {
-            params.push(format!("subaccount_id={}", subaccount_id));
-        }
-        let query = if params.is_empty() {
-            String::new()
-        } else {
-            format!("?{}", params.join("&"))
-        };
-        self.private_get(GET_POSITIONS, &query).await
+        let params = RequestParams::new()
+            .add_opt("currency", currency.as_ref().map(AsRef::as_ref))
+            .add_opt("kind", kind)
+            .add_opt("subaccount_id", subaccount_id);
+        self.private_post(GET_POSITIONS, params).await
     }
 
     /// Get position for a specific instrument
     ///
-    /// Retrieves the current position for the specified instrument.
+    /// Retrieves the current position for the specified instrument without
+    /// fetching every position in the currency.
     ///
     /// # Arguments
     ///
@@ -1098,11 +1138,21 @@ impl DeribitHttpClient {
     ///
     /// # Returns
     ///
-    /// Returns a vector of positions for the specified instrument
+    /// Returns the position for the instrument; a flat position has size 0
+    /// and direction `zero`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use deribit_http::DeribitHttpClient;
     ///
-    pub async fn get_position(&self, instrument_name: &str) -> Result<Vec<Position>, HttpError> {
-        let query = format!("?instrument_name={}", urlencoding::encode(instrument_name));
-        self.private_get(GET_POSITION, &query).await
+    /// let client = DeribitHttpClient::new();
+    /// // let position = client.get_position("BTC-PERPETUAL").await?;
+    /// // println!("Size: {}", position.size);
+    /// ```
+    pub async fn get_position(&self, instrument_name: &str) -> Result<Position, HttpError> {
+        let params = RequestParams::new().add("instrument_name", instrument_name);
+        self.private_post(GET_POSITION, params).await
     }
 
     /// Edit an order
@@ -1114,71 +1164,18 @@ impl DeribitHttpClient {
     /// * `request` - The edit order request parameters
     ///
     pub async fn edit_order(&self, request: OrderRequest) -> Result<OrderResponse, HttpError> {
+        let request = self.normalize_order_prices(request).await?;
         let order_id = request.order_id.ok_or_else(|| {
-            HttpError::RequestFailed("order_id is required for edit_order".to_string())
+            HttpErrorKind::RequestFailed("order_id is required for edit_order".to_string())
         })?;
-        let mut query_params = vec![("order_id", order_id.as_str())];
-
-        let amount_str;
-        if let Some(amount) = request.amount {
-            amount_str = amount.to_string();
-            query_params.push(("amount", amount_str.as_str()));
-        }
-
-        let price_str;
-        if let Some(price) = request.price {
-            price_str = price.to_string();
-            query_params.push(("price", price_str.as_str()));
-        }
-
-        if let Some(post_only) = request.post_only
-            && post_only
-        {
-            query_params.push(("post_only", "true"));
-        }
-
-        if let Some(reduce_only) = request.reduce_only
-            && reduce_only
-        {
-            query_params.push(("reduce_only", "true"));
-        }
-
-        let query_string = query_params
-            .iter()
-            .map(|(k, v)| format!("{}={}", k, urlencoding::encode(v)))
-            .collect::<Vec<_>>()
-            .join("&");
-
-        let url = format!("{}{}?{}", self.base_url(), EDIT, query_string);
-
-        let response = self.make_authenticated_request(&url).await?;
-
-        if !response.status().is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-            return Err(HttpError::RequestFailed(format!(
-                "Edit order failed: {}",
-                error_text
-            )));
-        }
-
-        let api_response: ApiResponse<OrderResponse> = response
-            .json()
-            .await
-            .map_err(|e| HttpError::InvalidResponse(e.to_string()))?;
-
-        if let Some(error) = api_response.error {
-            return Err(HttpError::RequestFailed(format!(
-                "API error: {} - {}",
-                error.code, error.message
-            )));
-        }
-
-        api_response
-            .result
-            .ok_or_else(|| HttpError::InvalidResponse("No order data in response".to_string()))
+        let params = RequestParams::new()
+            .add("order_id", order_id)
+            .add_opt("amount", request.amount)
+            .add_opt("price", request.price)
+            .add_opt("post_only", request.post_only)
+            .add_opt("reduce_only", request.reduce_only);
+
+        self.private_post(EDIT, params).await
     }
 
     /// Edit an order by label
@@ -1190,6 +1187,15 @@ impl DeribitHttpClient {
     ///
     /// * `request` - The edit order request parameters (must include label and instrument_name)
     ///
+    /// Besides `amount`/`contracts` and `price`, the request's `post_only`,
+    /// `reduce_only`, `reject_post_only`, `advanced`, `trigger_price`, `mmp`
+    /// and `valid_until` are forwarded; other fields are ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns `HttpErrorKind::ConfigError` without sending the request if the
+    /// request has no label.
+    ///
     /// # Examples
     ///
     /// ```rust
@@ -1211,103 +1217,24 @@ impl DeribitHttpClient {
         request: OrderRequest,
     ) -> Result<OrderResponse, HttpError> {
         let label = request.label.ok_or_else(|| {
-            HttpError::RequestFailed("label is required for edit_order_by_label".to_string())
+            HttpErrorKind::ConfigError("label is required for edit_order_by_label".to_string())
         })?;
 
-        let mut query_params = vec![
-            ("label".to_string(), label),
-            ("instrument_name".to_string(), request.instrument_name),
-        ];
-
-        if let Some(amount) = request.amount {
-            query_params.push(("amount".to_string(), amount.to_string()));
-        }
-
-        if let Some(contracts) = request.contracts {
-            query_params.push(("contracts".to_string(), contracts.to_string()));
-        }
-
-        if let Some(price) = request.price {
-            query_params.push(("price".to_string(), price.to_string()));
-        }
-
-        if let Some(post_only) = request.post_only
-            && post_only
-        {
-            query_params.push(("post_only".to_string(), "true".to_string()));
-        }
-
-        if let Some(reduce_only) = request.reduce_only
-            && reduce_only
-        {
-            query_params.push(("reduce_only".to_string(), "true".to_string()));
-        }
-
-        if let Some(reject_post_only) = request.reject_post_only
-            && reject_post_only
-        {
-            query_params.push(("reject_post_only".to_string(), "true".to_string()));
-        }
-
-        if let Some(advanced) = request.advanced {
-            let advanced_str = match advanced {
-                crate::model::request::order::AdvancedOrderType::Usd => "usd",
-                crate::model::request::order::AdvancedOrderType::Implv => "implv",
-            };
-            query_params.push(("advanced".to_string(), advanced_str.to_string()));
-        }
-
-        if let Some(trigger_price) = request.trigger_price {
-            query_params.push(("trigger_price".to_string(), trigger_price.to_string()));
-        }
-
-        if let Some(mmp) = request.mmp
-            && mmp
-        {
-            query_params.push(("mmp".to_string(), "true".to_string()));
-        }
-
-        if let Some(valid_until) = request.valid_until {
-            query_params.push(("valid_until".to_string(), valid_until.to_string()));
-        }
-
-        let query_string = query_params
-            .iter()
-            .map(|(k, v)| format!("{}={}", k, urlencoding::encode(v)))
-            .collect::<Vec<_>>()
-            .join("&");
-
-        let url = format!("{}{}?{}", self.base_url(), EDIT_BY_LABEL, query_string);
-
-        let response = self.make_authenticated_request(&url).await?;
-
-        if !response.status().is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-            return Err(HttpError::RequestFailed(format!(
-                "Edit order by label failed: {}",
-                error_text
-            )));
-        }
-
-        let api_response: ApiResponse<OrderResponse> = response
-            .json()
-            .await
-            .map_err(|e| HttpError::InvalidResponse(e.to_string()))?;
-
-        if let Some(error) = api_response.error {
-            return Err(HttpError::RequestFailed(format!(
-                "API error: {} - {}",
-                error.code, error.message
-            )));
-        }
-
-        api_response
-            .result
-            .ok_or_else(|| HttpError::InvalidResponse("No order data in response".to_string()))
-    }
+        let params = RequestParams::new()
+            .add("label", label)
+            .add("instrument_name", request.instrument_name)
+            .add_opt("amount", request.amount)
+            .add_opt("contracts", request.contracts)
+            .add_opt("price", request.price)
+            .add_opt("post_only", request.post_only)
+            .add_opt("reduce_only", request.reduce_only)
+            .add_opt("reject_post_only", request.reject_post_only)
+            .add_opt("advanced", request.advanced)
+            .add_opt("trigger_price", request.trigger_price)
+            .add_opt("mmp", request.mmp)
+            .add_opt("valid_until", request.valid_until);
+        self.private_post(EDIT_BY_LABEL, params).await
+    }
 
     /// Close an existing position
     ///
@@ -1317,35 +1244,75 @@ impl DeribitHttpClient {
     /// # Arguments
     ///
     /// * `instrument_name` - Instrument identifier (e.g., "BTC-PERPETUAL")
-    /// * `order_type` - Order type: "market" or "limit"
-    /// * `price` - Optional price for limit orders (required if order_type is "limit")
+    /// * `order_type` - `OrderType::Market` or `OrderType::Limit`
+    /// * `price` - Optional price for limit orders (required for `OrderType::Limit`)
+    ///
+    /// # Errors
+    ///
+    /// Returns `HttpErrorKind::ConfigError` without sending the request if
+    /// `order_type` is neither market nor limit, or is limit and no price is
+    /// given.
     ///
     /// # Examples
     ///
     /// ```rust
     /// use deribit_http::DeribitHttpClient;
+    /// use deribit_http::model::order::OrderType;
     ///
     /// let client = DeribitHttpClient::new();
     /// // Close position with market order
-    /// // let result = client.close_position("BTC-PERPETUAL", "market", None).await?;
+    /// // let result = client.close_position("BTC-PERPETUAL", OrderType::Market, None).await?;
     /// // Close position with limit order
-    /// // let result = client.close_position("ETH-PERPETUAL", "limit", Some(2500.0)).await?;
+    /// // let result = client.close_position("ETH-PERPETUAL", OrderType::Limit, Some(2500.0)).await?;
     /// ```
     pub async fn close_position(
         &self,
         instrument_name: &str,
-        order_type: &str,
+        order_type: OrderType,
         price: Option<f64>,
     ) -> Result<OrderResponse, HttpError> {
-        let mut query = format!(
-            "?instrument_name={}&type={}",
-            urlencoding::encode(instrument_name),
-            urlencoding::encode(order_type)
-        );
-        if let Some(price) = price {
-            query.push_str(&format!("&price={}", price));
+        match (order_type, price) {
+            (OrderType::Market, _) | (OrderType::Limit, Some(_)) => {}
+            (OrderType::Limit, None) => {
+                return Err(HttpErrorKind::ConfigError(
+                    "price is required to close a position with a limit order".to_string(),
+                )
+                .into());
+            }
+            (other, _) => {
+                return Err(HttpErrorKind::ConfigError(format!(
+                    "positions close with a market or limit order, not {}",
+                    other.as_str()
+                ))
+                .into());
+            }
         }
-        self.private_get(CLOSE_POSITION, &query).await
+
+        let params = RequestParams::new()
+            .add("instrument_name", instrument_name)
+            .add("type", order_type.as_str())
+            .add_opt("price", price);
+        self.private_post(CLOSE_POSITION, params).await
+    }
+
+    /// Close an existing position at market
+    ///
+    /// Shorthand for `close_position(instrument_name, OrderType::Market, None)`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use deribit_http::DeribitHttpClient;
+    ///
+    /// let client = DeribitHttpClient::new();
+    /// // let result = client.close_position_market("BTC-PERPETUAL").await?;
+    /// ```
+    pub async fn close_position_market(
+        &self,
+        instrument_name: &str,
+    ) -> Result<OrderResponse, HttpError> {
+        self.close_position(instrument_name, OrderType::Market, None)
+            .await
     }
 
     /// Get margin requirements
@@ -1375,13 +1342,11 @@ impl DeribitHttpClient {
         amount: f64,
         price: f64,
     ) -> Result<MarginsResponse, HttpError> {
-        let query = format!(
-            "?instrument_name={}&amount={}&price={}",
-            urlencoding::encode(instrument_name),
-            amount,
-            price
-        );
-        self.private_get(GET_MARGINS, &query).await
+        let params = RequestParams::new()
+            .add("instrument_name", instrument_name)
+            .add("amount", amount)
+            .add("price", price);
+        self.private_post(GET_MARGINS, params).await
     }
 
     /// Get order margin by IDs
@@ -1407,51 +1372,11 @@ impl DeribitHttpClient {
         ids: &[&str],
     ) -> Result<Vec<OrderMargin>, HttpError> {
         if ids.is_empty() {
-            return Err(HttpError::RequestFailed(
-                "ids array cannot be empty".to_string(),
-            ));
-        }
-
-        // Format IDs as JSON array for the query parameter
-        let ids_json = serde_json::to_string(ids)
-            .map_err(|e| HttpError::InvalidResponse(format!("Failed to serialize ids: {}", e)))?;
-
-        let query_string = format!("ids={}", urlencoding::encode(&ids_json));
-        let url = format!(
-            "{}{}?{}",
-            self.base_url(),
-            GET_ORDER_MARGIN_BY_IDS,
-            query_string
-        );
-
-        let response = self.make_authenticated_request(&url).await?;
-
-        if !response.status().is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-            return Err(HttpError::RequestFailed(format!(
-                "Get order margin by IDs failed: {}",
-                error_text
-            )));
-        }
-
-        let api_response: ApiResponse<Vec<OrderMargin>> = response
-            .json()
-            .await
-            .map_err(|e| HttpError::InvalidResponse(e.to_string()))?;
-
-        if let Some(error) = api_response.error {
-            return Err(HttpError::RequestFailed(format!(
-                "API error: {} - {}",
-                error.code, error.message
-            )));
+            return Err(HttpErrorKind::ConfigError("ids array cannot be empty".to_string()).into());
         }
 
-        api_response.result.ok_or_else(|| {
-            HttpError::InvalidResponse("No order margin data in response".to_string())
-        })
+        let params = RequestParams::new().add("ids", ids);
+        self.private_post(GET_ORDER_MARGIN_BY_IDS, params).await
     }
 
     /// Get order state by label
@@ -1476,15 +1401,14 @@ impl DeribitHttpClient {
     /// ```
     pub async fn get_order_state_by_label(
         &self,
-        currency: &str,
+        currency: impl AsRef<str>,
         label: &str,
     ) -> Result<Vec<OrderInfoResponse>, HttpError> {
-        let query = format!(
-            "?currency={}&label={}",
-            urlencoding::encode(currency),
-            urlencoding::encode(label)
-        );
-        self.private_get(GET_ORDER_STATE_BY_LABEL, &query).await
+        let currency = currency.as_ref();
+        let params = RequestParams::new()
+            .add("currency", currency)
+            .add("label", label);
+        self.private_post(GET_ORDER_STATE_BY_LABEL, params).await
     }
 
     /// Get settlement history by currency
@@ -1511,32 +1435,20 @@ impl DeribitHttpClient {
     /// ```
     pub async fn get_settlement_history_by_currency(
         &self,
-        currency: &str,
+        currency: impl AsRef<str>,
         settlement_type: Option<&str>,
         count: Option<u32>,
         continuation: Option<&str>,
-        search_start_timestamp: Option<u64>,
+        search_start_timestamp: Option<TimestampMs>,
     ) -> Result<SettlementsResponse, HttpError> {
-        let mut query = format!("?currency={}", urlencoding::encode(currency));
-        if let Some(settlement_type) = settlement_type {
-            query.push_str(&format!("&type={}", urlencoding::encode(settlement_type)));
-        }
-        if let Some(count) = count {
-            query.push_str(&format!("&count={}", count));
-        }
-        if let Some(continuation) = continuation {
-            query.push_str(&format!(
-                "&continuation={}",
-                urlencoding::encode(continuation)
-            ));
-        }
-        if let Some(search_start_timestamp) = search_start_timestamp {
-            query.push_str(&format!(
-                "&search_start_timestamp={}",
-                search_start_timestamp
-            ));
-        }
-        self.private_get(GET_SETTLEMENT_HISTORY_BY_CURRENCY, &query)
+        let currency = currency.as_ref();
+        let params = RequestParams::new()
+            .add("currency", currency)
+            .add_opt("type", settlement_type)
+            .add_opt("count", count)
+            .add_opt("continuation", continuation)
+            .add_opt("search_start_timestamp", search_start_timestamp);
+        self.private_post(GET_SETTLEMENT_HISTORY_BY_CURRENCY, params)
             .await
     }
 
@@ -1568,28 +1480,15 @@ impl DeribitHttpClient {
         settlement_type: Option<&str>,
         count: Option<u32>,
         continuation: Option<&str>,
-        search_start_timestamp: Option<u64>,
+        search_start_timestamp: Option<TimestampMs>,
     ) -> Result<SettlementsResponse, HttpError> {
-        let mut query = format!("?instrument_name={}", urlencoding::encode(instrument_name));
-        if let Some(settlement_type) = settlement_type {
-            query.push_str(&format!("&type={}", urlencoding::encode(settlement_type)));
-        }
-        if let Some(count) = count {
-            query.push_str(&format!("&count={}", count));
-        }
-        if let Some(continuation) = continuation {
-            query.push_str(&format!(
-                "&continuation={}",
-                urlencoding::encode(continuation)
-            ));
-        }
-        if let Some(search_start_timestamp) = search_start_timestamp {
-            query.push_str(&format!(
-                "&search_start_timestamp={}",
-                search_start_timestamp
-            ));
-        }
-        self.private_get(GET_SETTLEMENT_HISTORY_BY_INSTRUMENT, &query)
+        let params = RequestParams::new()
+            .add("instrument_name", instrument_name)
+            .add_opt("type", settlement_type)
+            .add_opt("count", count)
+            .add_opt("continuation", continuation)
+            .add_opt("search_start_timestamp", search_start_timestamp);
+        self.private_post(GET_SETTLEMENT_HISTORY_BY_INSTRUMENT, params)
             .await
     }
 
@@ -1616,28 +1515,87 @@ impl DeribitHttpClient {
     /// ```
     pub async fn get_trigger_order_history(
         &self,
-        currency: &str,
+        currency: impl AsRef<str>,
         instrument_name: Option<&str>,
         count: Option<u32>,
         continuation: Option<&str>,
     ) -> Result<TriggerOrderHistoryResponse, HttpError> {
-        let mut query = format!("?currency={}", urlencoding::encode(currency));
-        if let Some(instrument_name) = instrument_name {
-            query.push_str(&format!(
-                "&instrument_name={}",
-                urlencoding::encode(instrument_name)
-            ));
-        }
-        if let Some(count) = count {
-            query.push_str(&format!("&count={}", count));
-        }
-        if let Some(continuation) = continuation {
-            query.push_str(&format!(
-                "&continuation={}",
-                urlencoding::encode(continuation)
-            ));
-        }
-        self.private_get(GET_TRIGGER_ORDER_HISTORY, &query).await
+        let currency = currency.as_ref();
+        let params = RequestParams::new()
+            .add("currency", currency)
+            .add_opt("instrument_name", instrument_name)
+            .add_opt("count", count)
+            .add_opt("continuation", continuation);
+        self.private_post(GET_TRIGGER_ORDER_HISTORY, params).await
+    }
+
+    /// Stream trigger order history
+    ///
+    /// Follows the `continuation` token of `get_trigger_order_history`,
+    /// yielding entries newest first until the history is exhausted.
+    ///
+    /// # Arguments
+    ///
+    /// * `currency` - Currency symbol (e.g., "BTC", "ETH", "USDC")
+    /// * `instrument_name` - Filter by specific instrument (optional)
+    ///
+    pub fn stream_trigger_order_history<'a>(
+        &'a self,
+        currency: impl AsRef<str>,
+        instrument_name: Option<&'a str>,
+    ) -> impl Stream<Item = Result<TriggerOrderHistoryEntry, HttpError>> + 'a {
+        let currency = currency.as_ref().to_owned();
+        paginate(None, move |cursor: Option<Cursor>| {
+            let currency = currency.clone();
+            async move {
+                self.get_trigger_order_history(
+                    &currency,
+                    instrument_name,
+                    Some(TRIGGER_ORDER_HISTORY_PAGE_COUNT),
+                    cursor.as_ref().and_then(Cursor::continuation),
+                )
+                .await
+            }
+        })
+    }
+
+    /// Get stop order history
+    ///
+    /// Retrieves the log of stop orders through the legacy
+    /// `private/get_stop_order_history` endpoint. Entries use the stop order
+    /// field names (`stop_id`, `stop_price`); prefer
+    /// [`get_trigger_order_history`](DeribitHttpClient::get_trigger_order_history)
+    /// for new code.
+    ///
+    /// # Arguments
+    ///
+    /// * `currency` - Currency symbol (e.g., "BTC", "ETH", "USDC")
+    /// * `instrument_name` - Filter by specific instrument (optional)
+    /// * `count` - Number of items (default 20, max 1000) (optional)
+    /// * `continuation` - Pagination token (optional)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use deribit_http::DeribitHttpClient;
+    ///
+    /// let client = DeribitHttpClient::new();
+    /// // let history = client.get_stop_order_history("BTC", None, Some(20), None).await?;
+    /// ```
+    pub async fn get_stop_order_history(
+        &self,
+        currency: impl AsRef<str>,
+        instrument_name: Option<&str>,
+        count: Option<u32>,
+        continuation: Option<&str>,
+    ) -> Result<StopOrderHistoryResponse, HttpError> {
+        let currency = currency.as_ref();
+        let params = RequestParams::new()
+            .add("currency", currency)
+            .add_opt("instrument_name", instrument_name)
+            .add_opt("count", count)
+            .add_opt("continuation", continuation);
+        self.private_post(GET_STOP_ORDER_HISTORY, params).await
     }
 
     /// Move positions between subaccounts
@@ -1673,54 +1631,19 @@ impl DeribitHttpClient {
     /// ```
     pub async fn move_positions(
         &self,
-        currency: &str,
+        currency: impl AsRef<str>,
         source_uid: i64,
         target_uid: i64,
         trades: &[MovePositionTrade],
     ) -> Result<Vec<MovePositionResult>, HttpError> {
-        let mut url = format!(
-            "{}{}?currency={}&source_uid={}&target_uid={}",
-            self.base_url(),
-            MOVE_POSITIONS,
-            urlencoding::encode(currency),
-            source_uid,
-            target_uid
-        );
-
-        // Build trades array as JSON
-        let trades_json = serde_json::to_string(trades).map_err(|e| {
-            HttpError::InvalidResponse(format!("Failed to serialize trades: {}", e))
-        })?;
-        url.push_str(&format!("&trades={}", urlencoding::encode(&trades_json)));
-
-        let response = self.make_authenticated_request(&url).await?;
-
-        if !response.status().is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-            return Err(HttpError::RequestFailed(format!(
-                "Move positions failed: {}",
-                error_text
-            )));
-        }
-
-        let api_response: ApiResponse<Vec<MovePositionResult>> = response
-            .json()
-            .await
-            .map_err(|e| HttpError::InvalidResponse(e.to_string()))?;
-
-        if let Some(error) = api_response.error {
-            return Err(HttpError::RequestFailed(format!(
-                "API error: {} - {}",
-                error.code, error.message
-            )));
-        }
-
-        api_response.result.ok_or_else(|| {
-            HttpError::InvalidResponse("No move positions data in response".to_string())
-        })
+        let currency = currency.as_ref();
+        let params = RequestParams::new()
+            .add("currency", currency)
+            .add("source_uid", source_uid)
+            .add("target_uid", target_uid)
+            .add("trades", trades);
+        let response: MovePositionsResponse = self.private_post(MOVE_POSITIONS, params).await?;
+        Ok(response.trades)
     }
 
     /// Get MMP configuration
@@ -1748,24 +1671,11 @@ impl DeribitHttpClient {
         mmp_group: Option<&str>,
         block_rfq: Option<bool>,
     ) -> Result<Vec<MmpConfig>, HttpError> {
-        let mut params = Vec::new();
-        if let Some(index) = index_name {
-            params.push(format!("index_name={}", urlencoding::encode(index)));
-        }
-        if let Some(group) = mmp_group {
-            params.push(format!("mmp_group={}", urlencoding::encode(group)));
-        }
-        if let Some(rfq) = block_rfq
-            && rfq
-        {
-            params.push("block_rfq=true".to_string());
-        }
-        let query = if params.is_empty() {
-            String::new()
-        } else {
-            format!("?{}", params.join("&"))
-        };
-        self.private_get(GET_MMP_CONFIG, &query).await
+        let params = RequestParams::new()
+            .add_opt("index_name", index_name)
+            .add_opt("mmp_group", mmp_group)
+            .add_opt("block_rfq", block_rfq);
+        self.private_post(GET_MMP_CONFIG, params).await
     }
 
     /// Get MMP status
@@ -1793,24 +1703,11 @@ impl DeribitHttpClient {
         mmp_group: Option<&str>,
         block_rfq: Option<bool>,
     ) -> Result<Vec<MmpStatus>, HttpError> {
-        let mut params = Vec::new();
-        if let Some(index) = index_name {
-            params.push(format!("index_name={}", urlencoding::encode(index)));
-        }
-        if let Some(group) = mmp_group {
-            params.push(format!("mmp_group={}", urlencoding::encode(group)));
-        }
-        if let Some(rfq) = block_rfq
-            && rfq
-        {
-            params.push("block_rfq=true".to_string());
-        }
-        let query = if params.is_empty() {
-            String::new()
-        } else {
-            format!("?{}", params.join("&"))
-        };
-        self.private_get(GET_MMP_STATUS, &query).await
+        let params = RequestParams::new()
+            .add_opt("index_name", index_name)
+            .add_opt("mmp_group", mmp_group)
+            .add_opt("block_rfq", block_rfq);
+        self.private_post(GET_MMP_STATUS, params).await
     }
 
     /// Set MMP configuration
@@ -1843,77 +1740,18 @@ impl DeribitHttpClient {
         &self,
         request: SetMmpConfigRequest,
     ) -> Result<MmpConfig, HttpError> {
-        let mut query_params = vec![
-            ("index_name".to_string(), request.index_name),
-            ("interval".to_string(), request.interval.to_string()),
-            ("frozen_time".to_string(), request.frozen_time.to_string()),
-        ];
-
-        if let Some(quantity_limit) = request.quantity_limit {
-            query_params.push(("quantity_limit".to_string(), quantity_limit.to_string()));
-        }
-
-        if let Some(delta_limit) = request.delta_limit {
-            query_params.push(("delta_limit".to_string(), delta_limit.to_string()));
-        }
-
-        if let Some(vega_limit) = request.vega_limit {
-            query_params.push(("vega_limit".to_string(), vega_limit.to_string()));
-        }
-
-        if let Some(max_quote_quantity) = request.max_quote_quantity {
-            query_params.push((
-                "max_quote_quantity".to_string(),
-                max_quote_quantity.to_string(),
-            ));
-        }
-
-        if let Some(mmp_group) = request.mmp_group {
-            query_params.push(("mmp_group".to_string(), mmp_group));
-        }
-
-        if let Some(block_rfq) = request.block_rfq
-            && block_rfq
-        {
-            query_params.push(("block_rfq".to_string(), "true".to_string()));
-        }
-
-        let query_string = query_params
-            .iter()
-            .map(|(k, v)| format!("{}={}", k, urlencoding::encode(v)))
-            .collect::<Vec<_>>()
-            .join("&");
-
-        let url = format!("{}{}?{}", self.base_url(), SET_MMP_CONFIG, query_string);
-
-        let response = self.make_authenticated_request(&url).await?;
-
-        if !response.status().is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-            return Err(HttpError::RequestFailed(format!(
-                "Set MMP config failed: {}",
-                error_text
-            )));
-        }
-
-        let api_response: ApiResponse<MmpConfig> = response
-            .json()
-            .await
-            .map_err(|e| HttpError::InvalidResponse(e.to_string()))?;
-
-        if let Some(error) = api_response.error {
-            return Err(HttpError::RequestFailed(format!(
-                "API error: {} - {}",
-                error.code, error.message
-            )));
-        }
-
-        api_response
-            .result
-            .ok_or_else(|| HttpError::InvalidResponse("No MMP config data in response".to_string()))
+        let params = RequestParams::new()
+            .add("index_name", request.index_name)
+            .add("interval", request.interval)
+            .add("frozen_time", request.frozen_time)
+            .add_opt("quantity_limit", request.quantity_limit)
+            .add_opt("delta_limit", request.delta_limit)
+            .add_opt("vega_limit", request.vega_limit)
+            .add_opt("max_quote_quantity", request.max_quote_quantity)
+            .add_opt("mmp_group", request.mmp_group)
+            .add_opt("block_rfq", request.block_rfq);
+
+        self.private_post(SET_MMP_CONFIG, params).await
     }
 
     /// Reset MMP limits
@@ -1941,16 +1779,11 @@ impl DeribitHttpClient {
         mmp_group: Option<&str>,
         block_rfq: Option<bool>,
     ) -> Result<String, HttpError> {
-        let mut query = format!("?index_name={}", urlencoding::encode(index_name));
-        if let Some(group) = mmp_group {
-            query.push_str(&format!("&mmp_group={}", urlencoding::encode(group)));
-        }
-        if let Some(rfq) = block_rfq
-            && rfq
-        {
-            query.push_str("&block_rfq=true");
-        }
-        self.private_get(RESET_MMP, &query).await
+        let params = RequestParams::new()
+            .add("index_name", index_name)
+            .add_opt("mmp_group", mmp_group)
+            .add_opt("block_rfq", block_rfq);
+        self.private_post(RESET_MMP, params).await
     }
 
     /// Mass quote
@@ -1965,14 +1798,15 @@ impl DeribitHttpClient {
         &self,
         _quotes: MassQuoteRequest,
     ) -> Result<MassQuoteResponse, HttpError> {
-        Err(HttpError::ConfigError(
+        Err(HttpErrorKind::ConfigError(
             "Mass quote endpoint is only available via WebSocket connections. \
              According to Deribit's technical specifications, private/mass_quote requires \
              WebSocket for real-time quote management, MMP group integration, and \
              Cancel-on-Disconnect functionality. Please use the deribit-websocket client \
              for mass quote operations."
                 .to_string(),
-        ))
+        )
+        .into())
     }
 
     /// Get user trades by instrument
@@ -1995,85 +1829,18 @@ impl DeribitHttpClient {
         end_seq: Option<u64>,
         count: Option<u32>,
         include_old: Option<bool>,
-        sorting: Option<&str>,
+        sorting: Option<SortDirection>,
     ) -> Result<UserTradeWithPaginationResponse, HttpError> {
-        let mut query_params = vec![("instrument_name".to_string(), instrument_name.to_string())];
-
-        if let Some(start_seq) = start_seq {
-            query_params.push(("start_seq".to_string(), start_seq.to_string()));
-        }
-
-        if let Some(end_seq) = end_seq {
-            query_params.push(("end_seq".to_string(), end_seq.to_string()));
-        }
-
-        if let Some(count) = count {
-            query_params.push(("count".to_string(), count.to_string()));
-        }
-
-        if let Some(include_old) = include_old {
-            query_params.push(("include_old".to_string(), include_old.to_string()));
-        }
-
-        if let Some(sorting) = sorting {
-            query_params.push(("sorting".to_string(), sorting.to_string()));
-        }
-
-        let query_string = query_params
-            .iter()
-            .map(|(k, v)| format!("{}={}", k, urlencoding::encode(v)))
-            .collect::<Vec<_>>()
-            .join("&");
-
-        let url = format!(
-            "{}{}?{}",
-            self.base_url(),
-            GET_USER_TRADES_BY_INSTRUMENT,
-            query_string
-        );
-
-        let response = self.make_authenticated_request(&url).await?;
-
-        if !response.status().is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-            return Err(HttpError::RequestFailed(format!(
-                "Get user trades by instrument failed: {}",
-                error_text
-            )));
-        }
-
-        // Debug: Log the raw response text before trying to parse it
-        let response_text = response.text().await.map_err(|e| {
-            HttpError::InvalidResponse(format!("Failed to read response text: {}", e))
-        })?;
-
-        tracing::debug!(
-            "Raw API response for get_user_trades_by_instrument: {}",
-            response_text
-        );
-
-        // Try to parse as JSON
-        let api_response: ApiResponse<UserTradeWithPaginationResponse> =
-            serde_json::from_str(&response_text).map_err(|e| {
-                HttpError::InvalidResponse(format!(
-                    "error decoding response body: {} - Raw response: {}",
-                    e, response_text
-                ))
-            })?;
-
-        if let Some(error) = api_response.error {
-            return Err(HttpError::RequestFailed(format!(
-                "API error: {} - {}",
-                error.code, error.message
-            )));
-        }
-
-        api_response.result.ok_or_else(|| {
-            HttpError::InvalidResponse("No user trades data in response".to_string())
-        })
+        let params = RequestParams::new()
+            .add("instrument_name", instrument_name)
+            .add_opt("start_seq", start_seq)
+            .add_opt("end_seq", end_seq)
+            .add_opt("count", count)
+            .add_opt("include_old", include_old)
+            .add_opt("sorting", sorting);
+
+        self.private_post(GET_USER_TRADES_BY_INSTRUMENT, params)
+            .await
     }
 
     /// Cancel quotes
@@ -2085,11 +1852,8 @@ impl DeribitHttpClient {
     /// * `cancel_type` - Type of cancellation ("all", "by_currency", "by_instrument", etc.)
     ///
     pub async fn cancel_quotes(&self, cancel_type: Option<&str>) -> Result<u32, HttpError> {
-        let query = format!(
-            "?cancel_type={}",
-            urlencoding::encode(cancel_type.unwrap_or("all"))
-        );
-        self.private_get(CANCEL_QUOTES, &query).await
+        let params = RequestParams::new().add("cancel_type", cancel_type.unwrap_or("all"));
+        self.private_post(CANCEL_QUOTES, params).await
     }
 
     /// Get open orders
@@ -2103,22 +1867,13 @@ impl DeribitHttpClient {
     ///
     pub async fn get_open_orders(
         &self,
-        kind: Option<&str>,
+        kind: Option<InstrumentKind>,
         order_type: Option<&str>,
     ) -> Result<Vec<OrderInfoResponse>, HttpError> {
-        let mut params = Vec::new();
-        if let Some(kind) = kind {
-            params.push(format!("kind={}", urlencoding::encode(kind)));
-        }
-        if let Some(order_type) = order_type {
-            params.push(format!("type={}", urlencoding::encode(order_type)));
-        }
-        let query = if params.is_empty() {
-            String::new()
-        } else {
-            format!("?{}", params.join("&"))
-        };
-        self.private_get(GET_OPEN_ORDERS, &query).await
+        let params = RequestParams::new()
+            .add_opt("kind", kind)
+            .add_opt("type", order_type);
+        self.private_post(GET_OPEN_ORDERS, params).await
     }
 
     /// Get open orders by label
@@ -2133,14 +1888,13 @@ impl DeribitHttpClient {
     pub async fn get_open_orders_by_label(
         &self,
         label: &str,
-        currency: &str,
+        currency: impl AsRef<str>,
     ) -> Result<Vec<OrderInfoResponse>, HttpError> {
-        let query = format!(
-            "?label={}&currency={}",
-            urlencoding::encode(label),
-            urlencoding::encode(currency)
-        );
-        self.private_get(GET_OPEN_ORDERS_BY_LABEL, &query).await
+        let currency = currency.as_ref();
+        let params = RequestParams::new()
+            .add("label", label)
+            .add("currency", currency);
+        self.private_post(GET_OPEN_ORDERS_BY_LABEL, params).await
     }
 
     /// Get order state
@@ -2152,8 +1906,88 @@ impl DeribitHttpClient {
     /// * `order_id` - The order ID
     ///
     pub async fn get_order_state(&self, order_id: &str) -> Result<OrderInfoResponse, HttpError> {
-        let query = format!("?order_id={}", urlencoding::encode(order_id));
-        self.private_get(GET_ORDER_STATE, &query).await
+        let params = RequestParams::new().add("order_id", order_id);
+        self.private_post(GET_ORDER_STATE, params).await
+    }
+
+    /// Poll the state of an order until it reaches one of `target_states`
+    ///
+    /// Calls [`get_order_state`](Self::get_order_state) every `poll_interval`
+    /// and returns the order once its `order_state` is one of
+    /// `target_states` (e.g. `"filled"`, `"cancelled"`) or `timeout` has
+    /// elapsed. On timeout the last state seen is returned, so compare its
+    /// `order_state` with the targets to tell the two apart.
+    ///
+    /// # Arguments
+    ///
+    /// * `order_id` - The order ID
+    /// * `target_states` - Order states that end the wait
+    /// * `timeout` - Longest time to wait
+    /// * `poll_interval` - Time between polls
+    ///
+    /// # Errors
+    ///
+    /// Returns `HttpError` if a poll fails.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use deribit_http::DeribitHttpClient;
+    /// use std::time::Duration;
+    ///
+    /// # async fn example(client: DeribitHttpClient) -> Result<(), deribit_http::HttpError> {
+    /// let order = client
+    ///     .wait_for_order_state(
+    ///         "ETH-584849853",
+    ///         &["filled", "cancelled"],
+    ///         Duration::from_secs(30),
+    ///         Duration::from_millis(500),
+    ///     )
+    ///     .await?;
+    /// println!("{} is {}", order.order_id, order.order_state);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn wait_for_order_state(
+        &self,
+        order_id: &str,
+        target_states: &[&str],
+        timeout: Duration,
+        poll_interval: Duration,
+    ) -> Result<OrderInfoResponse, HttpError> {
+        let deadline = Instant::now() + timeout;
+        loop {
+            let order = self.get_order_state(order_id).await?;
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if target_states.contains(&order.order_state.as_str()) || remaining.is_zero() {
+                return Ok(order);
+            }
+            sleep(poll_interval.min(remaining)).await;
+        }
+    }
+
+    /// Wait until an order is filled, cancelled or rejected
+    ///
+    /// [`wait_for_order_state`](Self::wait_for_order_state) with the
+    /// [`FINAL_ORDER_STATES`] as targets, polling every
+    /// [`ORDER_STATE_POLL_INTERVAL`]. Returns the last state seen if `timeout`
+    /// elapses first.
+    ///
+    /// # Errors
+    ///
+    /// Returns `HttpError` if a poll fails.
+    pub async fn wait_for_fill(
+        &self,
+        order_id: &str,
+        timeout: Duration,
+    ) -> Result<OrderInfoResponse, HttpError> {
+        self.wait_for_order_state(
+            order_id,
+            FINAL_ORDER_STATES,
+            timeout,
+            ORDER_STATE_POLL_INTERVAL,
+        )
+        .await
     }
 
     /// Get open orders by currency
@@ -2168,18 +2002,16 @@ impl DeribitHttpClient {
     ///
     pub async fn get_open_orders_by_currency(
         &self,
-        currency: &str,
-        kind: Option<&str>,
+        currency: impl AsRef<str>,
+        kind: Option<InstrumentKind>,
         order_type: Option<&str>,
     ) -> Result<Vec<OrderInfoResponse>, HttpError> {
-        let mut query = format!("?currency={}", urlencoding::encode(currency));
-        if let Some(kind) = kind {
-            query.push_str(&format!("&kind={}", urlencoding::encode(kind)));
-        }
-        if let Some(order_type) = order_type {
-            query.push_str(&format!("&type={}", urlencoding::encode(order_type)));
-        }
-        self.private_get(GET_OPEN_ORDERS_BY_CURRENCY, &query).await
+        let currency = currency.as_ref();
+        let params = RequestParams::new()
+            .add("currency", currency)
+            .add_opt("kind", kind)
+            .add_opt("type", order_type);
+        self.private_post(GET_OPEN_ORDERS_BY_CURRENCY, params).await
     }
 
     /// Get open orders by instrument
@@ -2196,11 +2028,10 @@ impl DeribitHttpClient {
         instrument_name: &str,
         order_type: Option<&str>,
     ) -> Result<Vec<OrderInfoResponse>, HttpError> {
-        let mut query = format!("?instrument_name={}", urlencoding::encode(instrument_name));
-        if let Some(order_type) = order_type {
-            query.push_str(&format!("&type={}", urlencoding::encode(order_type)));
-        }
-        self.private_get(GET_OPEN_ORDERS_BY_INSTRUMENT, &query)
+        let params = RequestParams::new()
+            .add("instrument_name", instrument_name)
+            .add_opt("type", order_type);
+        self.private_post(GET_OPEN_ORDERS_BY_INSTRUMENT, params)
             .await
     }
 
@@ -2217,22 +2048,18 @@ impl DeribitHttpClient {
     ///
     pub async fn get_order_history(
         &self,
-        currency: &str,
-        kind: Option<&str>,
+        currency: impl AsRef<str>,
+        kind: Option<InstrumentKind>,
         count: Option<u32>,
         offset: Option<u32>,
     ) -> Result<Vec<OrderInfoResponse>, HttpError> {
-        let mut query = format!("?currency={}", urlencoding::encode(currency));
-        if let Some(kind) = kind {
-            query.push_str(&format!("&kind={}", urlencoding::encode(kind)));
-        }
-        if let Some(count) = count {
-            query.push_str(&format!("&count={}", count));
-        }
-        if let Some(offset) = offset {
-            query.push_str(&format!("&offset={}", offset));
-        }
-        self.private_get(GET_ORDER_HISTORY_BY_CURRENCY, &query)
+        let currency = currency.as_ref();
+        let params = RequestParams::new()
+            .add("currency", currency)
+            .add_opt("kind", kind)
+            .add_opt("count", count)
+            .add_opt("offset", offset);
+        self.private_post(GET_ORDER_HISTORY_BY_CURRENCY, params)
             .await
     }
 
@@ -2249,15 +2076,52 @@ impl DeribitHttpClient {
     ///
     pub async fn get_order_history_by_currency(
         &self,
-        currency: &str,
-        kind: Option<&str>,
+        currency: impl AsRef<str>,
+        kind: Option<InstrumentKind>,
         count: Option<u32>,
         offset: Option<u32>,
     ) -> Result<Vec<OrderInfoResponse>, HttpError> {
+        let currency = currency.as_ref();
         // This is an alias to the existing get_order_history method
         self.get_order_history(currency, kind, count, offset).await
     }
 
+    /// Stream order history by currency
+    ///
+    /// Walks the `count`/`offset` pages of
+    /// [`get_order_history_by_currency`](Self::get_order_history_by_currency),
+    /// yielding every order until a page comes back short.
+    ///
+    /// # Arguments
+    ///
+    /// * `currency` - Currency symbol (BTC, ETH, etc.)
+    /// * `kind` - Instrument kind filter (optional)
+    ///
+    pub fn stream_order_history_by_currency<'a>(
+        &'a self,
+        currency: impl AsRef<str>,
+        kind: Option<InstrumentKind>,
+    ) -> impl Stream<Item = Result<OrderInfoResponse, HttpError>> + 'a {
+        let currency = currency.as_ref().to_owned();
+        paginate(None, move |cursor: Option<Cursor>| {
+            let currency = currency.clone();
+            async move {
+                let items = self
+                    .get_order_history_by_currency(
+                        &currency,
+                        kind,
+                        Some(ORDER_HISTORY_PAGE_COUNT),
+                        cursor.as_ref().and_then(Cursor::offset),
+                    )
+                    .await?;
+                Ok(OffsetPage {
+                    items,
+                    count: ORDER_HISTORY_PAGE_COUNT,
+                })
+            }
+        })
+    }
+
     /// Get order history by instrument
     ///
     /// Retrieves order history for a specific instrument.
@@ -2274,17 +2138,43 @@ impl DeribitHttpClient {
         count: Option<u32>,
         offset: Option<u32>,
     ) -> Result<Vec<OrderInfoResponse>, HttpError> {
-        let mut query = format!("?instrument_name={}", urlencoding::encode(instrument_name));
-        if let Some(count) = count {
-            query.push_str(&format!("&count={}", count));
-        }
-        if let Some(offset) = offset {
-            query.push_str(&format!("&offset={}", offset));
-        }
-        self.private_get(GET_ORDER_HISTORY_BY_INSTRUMENT, &query)
+        let params = RequestParams::new()
+            .add("instrument_name", instrument_name)
+            .add_opt("count", count)
+            .add_opt("offset", offset);
+        self.private_post(GET_ORDER_HISTORY_BY_INSTRUMENT, params)
             .await
     }
 
+    /// Stream order history by instrument
+    ///
+    /// Walks the `count`/`offset` pages of
+    /// [`get_order_history_by_instrument`](Self::get_order_history_by_instrument),
+    /// yielding every order until a page comes back short.
+    ///
+    /// # Arguments
+    ///
+    /// * `instrument_name` - The instrument name
+    ///
+    pub fn stream_order_history_by_instrument<'a>(
+        &'a self,
+        instrument_name: &'a str,
+    ) -> impl Stream<Item = Result<OrderInfoResponse, HttpError>> + 'a {
+        paginate(None, move |cursor: Option<Cursor>| async move {
+            let items = self
+                .get_order_history_by_instrument(
+                    instrument_name,
+                    Some(ORDER_HISTORY_PAGE_COUNT),
+                    cursor.as_ref().and_then(Cursor::offset),
+                )
+                .await?;
+            Ok(OffsetPage {
+                items,
+                count: ORDER_HISTORY_PAGE_COUNT,
+            })
+        })
+    }
+
     /// Get user trades by currency
     ///
     /// Retrieves user trades filtered by currency.
@@ -2309,100 +2199,10 @@ impl DeribitHttpClient {
         &self,
         request: TradesRequest,
     ) -> Result<UserTradeWithPaginationResponse, HttpError> {
-        let mut query_params = vec![("currency".to_string(), request.currency.to_string())];
-
-        if let Some(kind) = request.kind {
-            query_params.push(("kind".to_string(), kind.to_string()));
-        }
-
-        if let Some(start_id) = request.start_id {
-            query_params.push(("start_id".to_string(), start_id));
-        }
+        let params = trades_params(request);
 
-        if let Some(end_id) = request.end_id {
-            query_params.push(("end_id".to_string(), end_id));
-        }
-
-        if let Some(count) = request.count {
-            query_params.push(("count".to_string(), count.to_string()));
-        }
-
-        if let Some(start_timestamp) = request.start_timestamp {
-            query_params.push(("start_timestamp".to_string(), start_timestamp.to_string()));
-        }
-
-        if let Some(end_timestamp) = request.end_timestamp {
-            query_params.push(("end_timestamp".to_string(), end_timestamp.to_string()));
-        }
-
-        if let Some(sorting) = request.sorting {
-            query_params.push(("sorting".to_string(), sorting.to_string()));
-        }
-
-        if let Some(historical) = request.historical {
-            query_params.push(("historical".to_string(), historical.to_string()));
-        }
-
-        if let Some(subaccount_id) = request.subaccount_id {
-            query_params.push(("subaccount_id".to_string(), subaccount_id.to_string()));
-        }
-
-        let query_string = query_params
-            .iter()
-            .map(|(k, v)| format!("{}={}", k, urlencoding::encode(v)))
-            .collect::<Vec<_>>()
-            .join("&");
-
-        let url = format!(
-            "{}{}?{}",
-            self.base_url(),
-            GET_USER_TRADES_BY_CURRENCY,
-            query_string
-        );
-
-        let response = self.make_authenticated_request(&url).await?;
-
-        if !response.status().is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-            return Err(HttpError::RequestFailed(format!(
-                "Get user trades by currency failed: {}",
-                error_text
-            )));
-        }
-
-        // Debug: Log the raw response text before trying to parse it
-        let response_text = response.text().await.map_err(|e| {
-            HttpError::InvalidResponse(format!("Failed to read response text: {}", e))
-        })?;
-
-        tracing::debug!(
-            "Raw API response for get_user_trades_by_order: {}",
-            response_text
-        );
-
-        // Try to parse as JSON
-        let api_response: ApiResponse<UserTradeWithPaginationResponse> =
-            serde_json::from_str(&response_text).map_err(|e| {
-                HttpError::InvalidResponse(format!(
-                    "error decoding response body: {} - Raw response: {}",
-                    e, response_text
-                ))
-            })?;
-
-        if let Some(error) = api_response.error {
-            return Err(HttpError::RequestFailed(format!(
-                "API error: {} - {}",
-                error.code, error.message
-            )));
-        }
-
-        api_response.result.ok_or_else(|| {
-            HttpError::InvalidResponse("No user trades data in response".to_string())
-        })
-    }
+        self.private_post(GET_USER_TRADES_BY_CURRENCY, params).await
+    }
 
     /// Get user trades by currency and time
     ///
@@ -2428,99 +2228,10 @@ impl DeribitHttpClient {
         &self,
         request: TradesRequest,
     ) -> Result<UserTradeWithPaginationResponse, HttpError> {
-        let mut query_params = vec![("currency".to_string(), request.currency.to_string())];
-
-        if let Some(kind) = request.kind {
-            query_params.push(("kind".to_string(), kind.to_string()));
-        }
-
-        if let Some(start_id) = request.start_id {
-            query_params.push(("start_id".to_string(), start_id));
-        }
-
-        if let Some(end_id) = request.end_id {
-            query_params.push(("end_id".to_string(), end_id));
-        }
-
-        if let Some(count) = request.count {
-            query_params.push(("count".to_string(), count.to_string()));
-        }
-
-        if let Some(start_timestamp) = request.start_timestamp {
-            query_params.push(("start_timestamp".to_string(), start_timestamp.to_string()));
-        }
-
-        if let Some(end_timestamp) = request.end_timestamp {
-            query_params.push(("end_timestamp".to_string(), end_timestamp.to_string()));
-        }
-
-        if let Some(sorting) = request.sorting {
-            query_params.push(("sorting".to_string(), sorting.to_string()));
-        }
-
-        if let Some(historical) = request.historical {
-            query_params.push(("historical".to_string(), historical.to_string()));
-        }
-
-        if let Some(subaccount_id) = request.subaccount_id {
-            query_params.push(("subaccount_id".to_string(), subaccount_id.to_string()));
-        }
-
-        let query_string = query_params
-            .iter()
-            .map(|(k, v)| format!("{}={}", k, urlencoding::encode(v)))
-            .collect::<Vec<_>>()
-            .join("&");
+        let params = trades_params(request);
 
-        let url = format!(
-            "{}{}?{}",
-            self.base_url(),
-            GET_USER_TRADES_BY_CURRENCY_AND_TIME,
-            query_string
-        );
-
-        let response = self.make_authenticated_request(&url).await?;
-
-        if !response.status().is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-            return Err(HttpError::RequestFailed(format!(
-                "Get user trades by currency and time failed: {}",
-                error_text
-            )));
-        }
-
-        // Debug: Log the raw response text before trying to parse it
-        let response_text = response.text().await.map_err(|e| {
-            HttpError::InvalidResponse(format!("Failed to read response text: {}", e))
-        })?;
-
-        tracing::debug!(
-            "Raw API response for get_user_trades_by_order: {}",
-            response_text
-        );
-
-        // Try to parse as JSON
-        let api_response: ApiResponse<UserTradeWithPaginationResponse> =
-            serde_json::from_str(&response_text).map_err(|e| {
-                HttpError::InvalidResponse(format!(
-                    "error decoding response body: {} - Raw response: {}",
-                    e, response_text
-                ))
-            })?;
-
-        if let Some(error) = api_response.error {
-            return Err(HttpError::RequestFailed(format!(
-                "API error: {} - {}",
-                error.code, error.message
-            )));
-        }
-
-        api_response.result.ok_or_else(|| {
-            HttpError::InvalidResponse("No user trades data in response".to_string())
-        })
+        self.private_post(GET_USER_TRADES_BY_CURRENCY_AND_TIME, params)
+            .await
     }
 
     /// Get user trades by instrument and time
@@ -2530,8 +2241,7 @@ impl DeribitHttpClient {
     /// # Arguments
     ///
     /// * `instrument_name` - Instrument name
-    /// * `start_timestamp` - Start timestamp in milliseconds
-    /// * `end_timestamp` - End timestamp in milliseconds
+    /// * `range` - Time window to query, e.g. a [`TimeRange`] or a `(TimestampMs, TimestampMs)` pair
     /// * `count` - Number of requested items (optional, default 10)
     /// * `include_old` - Include trades older than 7 days (optional)
     /// * `sorting` - Direction of results sorting (optional)
@@ -2539,84 +2249,150 @@ impl DeribitHttpClient {
     pub async fn get_user_trades_by_instrument_and_time(
         &self,
         instrument_name: &str,
-        start_timestamp: u64,
-        end_timestamp: u64,
+        range: impl Into<TimeRange>,
         count: Option<u32>,
         include_old: Option<bool>,
-        sorting: Option<&str>,
+        sorting: Option<SortDirection>,
     ) -> Result<UserTradeWithPaginationResponse, HttpError> {
-        let mut query_params = vec![
-            ("instrument_name".to_string(), instrument_name.to_string()),
-            ("start_timestamp".to_string(), start_timestamp.to_string()),
-            ("end_timestamp".to_string(), end_timestamp.to_string()),
-        ];
-
-        if let Some(count) = count {
-            query_params.push(("count".to_string(), count.to_string()));
-        }
-
-        if let Some(include_old) = include_old {
-            query_params.push(("include_old".to_string(), include_old.to_string()));
-        }
-
-        if let Some(sorting) = sorting {
-            query_params.push(("sorting".to_string(), sorting.to_string()));
-        }
-
-        let query_string = query_params
-            .iter()
-            .map(|(k, v)| format!("{}={}", k, urlencoding::encode(v)))
-            .collect::<Vec<_>>()
-            .join("&");
-
-        let url = format!(
-            "{}{}?{}",
-            self.base_url(),
-            GET_USER_TRADES_BY_INSTRUMENT_AND_TIME,
-            query_string
-        );
-
-        let response = self.make_authenticated_request(&url).await?;
-
-        if !response.status().is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-            return Err(HttpError::RequestFailed(format!(
-                "Get user trades by instrument and time failed: {}",
-                error_text
-            )));
-        }
+        let (start_timestamp, end_timestamp) = range.into().resolve()?;
+        let params = RequestParams::new()
+            .add("instrument_name", instrument_name)
+            .add("start_timestamp", start_timestamp)
+            .add("end_timestamp", end_timestamp)
+            .add_opt("count", count)
+            .add_opt("include_old", include_old)
+            .add_opt("sorting", sorting);
+
+        self.private_post(GET_USER_TRADES_BY_INSTRUMENT_AND_TIME, params)
+            .await
+    }
 
-        // Debug: Log the raw response text before trying to parse it
-        let response_text = response.text().await.map_err(|e| {
-            HttpError::InvalidResponse(format!("Failed to read response text: {}", e))
-        })?;
+    /// Stream user trades by instrument
+    ///
+    /// Follows `has_more` through [`paginate`], moving `start_seq`
+    /// (ascending) or `end_seq` (descending) past the last trade of each
+    /// page. Arguments match
+    /// [`get_user_trades_by_instrument`](Self::get_user_trades_by_instrument).
+    pub fn stream_user_trades_by_instrument<'a>(
+        &'a self,
+        instrument_name: &'a str,
+        start_seq: Option<u64>,
+        end_seq: Option<u64>,
+        count: Option<u32>,
+        include_old: Option<bool>,
+        sorting: Option<SortDirection>,
+    ) -> impl Stream<Item = Result<UserTrade, HttpError>> + 'a {
+        paginate(None, move |cursor: Option<Cursor>| async move {
+            let (mut start_seq, mut end_seq) = (start_seq, end_seq);
+            if let Some(last) = cursor.as_ref().and_then(Cursor::trade) {
+                if last.ascending_for(sorting.as_ref()) {
+                    start_seq = Some(last.trade_seq + 1);
+                } else {
+                    let Some(before) = last.trade_seq.checked_sub(1) else {
+                        return Ok(UserTradeWithPaginationResponse {
+                            trades: Vec::new(),
+                            has_more: false,
+                        });
+                    };
+                    end_seq = Some(before);
+                }
+            }
+            self.get_user_trades_by_instrument(
+                instrument_name,
+                start_seq,
+                end_seq,
+                count,
+                include_old,
+                sorting,
+            )
+            .await
+        })
+    }
 
-        tracing::debug!(
-            "Raw API response for get_user_trades_by_instrument_and_time: {}",
-            response_text
-        );
-
-        // Try to parse as JSON
-        let api_response: ApiResponse<UserTradeWithPaginationResponse> =
-            serde_json::from_str(&response_text).map_err(|e| {
-                HttpError::InvalidResponse(format!(
-                    "error decoding response body: {} - Raw response: {}",
-                    e, response_text
-                ))
-            })?;
+    /// Stream user trades by instrument and time
+    ///
+    /// Follows `has_more` through [`paginate`], narrowing the time window to
+    /// start (ascending) or end (descending) at the last trade of each page.
+    /// Trades sharing that timestamp are not yielded twice; more of them than
+    /// fit in a page fail with `HttpErrorKind::ConfigError`. Arguments match
+    /// [`get_user_trades_by_instrument_and_time`](Self::get_user_trades_by_instrument_and_time).
+    pub fn stream_user_trades_by_instrument_and_time<'a>(
+        &'a self,
+        instrument_name: &'a str,
+        range: impl Into<TimeRange>,
+        count: Option<u32>,
+        include_old: Option<bool>,
+        sorting: Option<SortDirection>,
+    ) -> impl Stream<Item = Result<UserTrade, HttpError>> + 'a {
+        let bounds = range.into().bounds();
+        paginate(None, move |cursor: Option<Cursor>| async move {
+            let range = trade_window(bounds, cursor.as_ref(), sorting.as_ref());
+            self.get_user_trades_by_instrument_and_time(
+                instrument_name,
+                range,
+                count,
+                include_old,
+                sorting,
+            )
+            .await?
+            .ensure_progress(cursor.as_ref())
+        })
+    }
 
-        if let Some(error) = api_response.error {
-            return Err(HttpError::RequestFailed(format!(
-                "API error: {} - {}",
-                error.code, error.message
-            )));
-        }
+    /// Stream user trades by currency
+    ///
+    /// Follows `has_more` through [`paginate`], moving `start_id` (ascending)
+    /// or `end_id` (descending) to the last trade of each page, which is not
+    /// yielded twice. The id bound is inclusive, so a page must hold at least
+    /// one new trade: a `count` of 1, or a page repeating only trades already
+    /// yielded, fails with `HttpErrorKind::ConfigError`. Arguments match
+    /// [`get_user_trades_by_currency`](Self::get_user_trades_by_currency).
+    pub fn stream_user_trades_by_currency(
+        &self,
+        request: TradesRequest,
+    ) -> impl Stream<Item = Result<UserTrade, HttpError>> + '_ {
+        paginate(None, move |cursor: Option<Cursor>| {
+            let mut request = request.clone();
+            if let Some(last) = cursor.as_ref().and_then(Cursor::trade) {
+                if last.ascending_for(request.sorting.as_ref()) {
+                    request.start_id = Some(last.trade_id.clone());
+                } else {
+                    request.end_id = Some(last.trade_id.clone());
+                }
+            }
+            async move {
+                self.get_user_trades_by_currency(request)
+                    .await?
+                    .ensure_progress(cursor.as_ref())
+            }
+        })
+    }
 
-        api_response.result.ok_or_else(|| {
-            HttpError::InvalidResponse("No user trades data in response".to_string())
+    /// Stream user trades by currency and time
+    ///
+    /// Follows `has_more` through [`paginate`], narrowing the time window to
+    /// start (ascending) or end (descending) at the last trade of each page.
+    /// Trades sharing that timestamp are not yielded twice; more of them than
+    /// fit in a page fail with `HttpErrorKind::ConfigError`. Arguments match
+    /// [`get_user_trades_by_currency_and_time`](Self::get_user_trades_by_currency_and_time).
+    pub fn stream_user_trades_by_currency_and_time(
+        &self,
+        request: TradesRequest,
+    ) -> impl Stream<Item = Result<UserTrade, HttpError>> + '_ {
+        paginate(None, move |cursor: Option<Cursor>| {
+            let mut request = request.clone();
+            if let Some(last) = cursor.as_ref().and_then(Cursor::trade) {
+                if last.ascending_for(request.sorting.as_ref()) {
+                    request.start_timestamp = Some(last.timestamp);
+                } else {
+                    request.end_timestamp = Some(last.timestamp);
+                }
+            }
+            async move {
+                self.get_user_trades_by_currency_and_time(request)
+                    .await?
+                    .ensure_progress(cursor.as_ref())
+            }
         })
     }
 
@@ -2632,17 +2408,14 @@ impl DeribitHttpClient {
     pub async fn get_user_trades_by_order(
         &self,
         order_id: &str,
-        sorting: Option<&str>,
+        sorting: Option<SortDirection>,
         historical: bool,
     ) -> Result<Vec<UserTradeResponseByOrder>, HttpError> {
-        let mut query = format!("?order_id={}", urlencoding::encode(order_id));
-        if let Some(sorting) = sorting {
-            query.push_str(&format!("&sorting={}", urlencoding::encode(sorting)));
-        }
-        if historical {
-            query.push_str("&historical=true");
-        }
-        self.private_get(GET_USER_TRADES_BY_ORDER, &query).await
+        let params = RequestParams::new()
+            .add("order_id", order_id)
+            .add_opt("sorting", sorting)
+            .add("historical", historical);
+        self.private_post(GET_USER_TRADES_BY_ORDER, params).await
     }
 
     // ==================== API Key Management ====================
@@ -2684,58 +2457,13 @@ impl DeribitHttpClient {
         &self,
         request: CreateApiKeyRequest,
     ) -> Result<ApiKeyInfo, HttpError> {
-        let mut query_params = vec![("max_scope".to_string(), request.max_scope)];
-
-        if let Some(name) = request.name {
-            query_params.push(("name".to_string(), name));
-        }
-
-        if let Some(public_key) = request.public_key {
-            query_params.push(("public_key".to_string(), public_key));
-        }
-
-        if let Some(enabled_features) = request.enabled_features {
-            for feature in enabled_features {
-                query_params.push(("enabled_features".to_string(), feature));
-            }
-        }
-
-        let query_string = query_params
-            .iter()
-            .map(|(k, v)| format!("{}={}", k, urlencoding::encode(v)))
-            .collect::<Vec<_>>()
-            .join("&");
-
-        let url = format!("{}{}?{}", self.base_url(), CREATE_API_KEY, query_string);
-
-        let response = self.make_authenticated_request(&url).await?;
-
-        if !response.status().is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-            return Err(HttpError::RequestFailed(format!(
-                "Create API key failed: {}",
-                error_text
-            )));
-        }
-
-        let api_response: ApiResponse<ApiKeyInfo> = response
-            .json()
-            .await
-            .map_err(|e| HttpError::InvalidResponse(e.to_string()))?;
+        let params = RequestParams::new()
+            .add("max_scope", request.max_scope)
+            .add_opt("name", request.name)
+            .add_opt("public_key", request.public_key)
+            .add_opt("enabled_features", request.enabled_features);
 
-        if let Some(error) = api_response.error {
-            return Err(HttpError::RequestFailed(format!(
-                "API error: {} - {}",
-                error.code, error.message
-            )));
-        }
-
-        api_response
-            .result
-            .ok_or_else(|| HttpError::InvalidResponse("No API key data in response".to_string()))
+        self.private_post(CREATE_API_KEY, params).await
     }
 
     /// Edit an existing API key
@@ -2754,67 +2482,15 @@ impl DeribitHttpClient {
     ///
     /// Returns `HttpError` if the request fails or the API key is not found.
     pub async fn edit_api_key(&self, request: EditApiKeyRequest) -> Result<ApiKeyInfo, HttpError> {
-        let mut query_params = vec![
-            ("id".to_string(), request.id.to_string()),
-            ("max_scope".to_string(), request.max_scope),
-        ];
-
-        if let Some(name) = request.name {
-            query_params.push(("name".to_string(), name));
-        }
-
-        if let Some(enabled) = request.enabled {
-            query_params.push(("enabled".to_string(), enabled.to_string()));
-        }
-
-        if let Some(enabled_features) = request.enabled_features {
-            for feature in enabled_features {
-                query_params.push(("enabled_features".to_string(), feature));
-            }
-        }
-
-        if let Some(ip_whitelist) = request.ip_whitelist {
-            for ip in ip_whitelist {
-                query_params.push(("ip_whitelist".to_string(), ip));
-            }
-        }
-
-        let query_string = query_params
-            .iter()
-            .map(|(k, v)| format!("{}={}", k, urlencoding::encode(v)))
-            .collect::<Vec<_>>()
-            .join("&");
-
-        let url = format!("{}{}?{}", self.base_url(), EDIT_API_KEY, query_string);
-
-        let response = self.make_authenticated_request(&url).await?;
-
-        if !response.status().is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-            return Err(HttpError::RequestFailed(format!(
-                "Edit API key failed: {}",
-                error_text
-            )));
-        }
-
-        let api_response: ApiResponse<ApiKeyInfo> = response
-            .json()
-            .await
-            .map_err(|e| HttpError::InvalidResponse(e.to_string()))?;
-
-        if let Some(error) = api_response.error {
-            return Err(HttpError::RequestFailed(format!(
-                "API error: {} - {}",
-                error.code, error.message
-            )));
-        }
-
-        api_response
-            .result
-            .ok_or_else(|| HttpError::InvalidResponse("No API key data in response".to_string()))
+        let params = RequestParams::new()
+            .add("id", request.id)
+            .add("max_scope", request.max_scope)
+            .add_opt("name", request.name)
+            .add_opt("enabled", request.enabled)
+            .add_opt("enabled_features", request.enabled_features)
+            .add_opt("ip_whitelist", request.ip_whitelist);
+
+        self.private_post(EDIT_API_KEY, params).await
     }
 
     /// Disable an API key
@@ -2834,8 +2510,8 @@ impl DeribitHttpClient {
     ///
     /// Returns `HttpError` if the request fails or the API key is not found.
     pub async fn disable_api_key(&self, id: u64) -> Result<ApiKeyInfo, HttpError> {
-        let query = format!("?id={}", id);
-        self.private_get(DISABLE_API_KEY, &query).await
+        let params = RequestParams::new().add("id", id);
+        self.private_post(DISABLE_API_KEY, params).await
     }
 
     /// Enable an API key
@@ -2854,8 +2530,8 @@ impl DeribitHttpClient {
     ///
     /// Returns `HttpError` if the request fails or the API key is not found.
     pub async fn enable_api_key(&self, id: u64) -> Result<ApiKeyInfo, HttpError> {
-        let query = format!("?id={}", id);
-        self.private_get(ENABLE_API_KEY, &query).await
+        let params = RequestParams::new().add("id", id);
+        self.private_post(ENABLE_API_KEY, params).await
     }
 
     /// List all API keys
@@ -2885,7 +2561,7 @@ impl DeribitHttpClient {
     /// # }
     /// ```
     pub async fn list_api_keys(&self) -> Result<Vec<ApiKeyInfo>, HttpError> {
-        self.private_get(LIST_API_KEYS, "").await
+        self.private_post(LIST_API_KEYS, RequestParams::new()).await
     }
 
     /// Remove an API key
@@ -2904,8 +2580,8 @@ impl DeribitHttpClient {
     ///
     /// Returns `HttpError` if the request fails or the API key is not found.
     pub async fn remove_api_key(&self, id: u64) -> Result<String, HttpError> {
-        let query = format!("?id={}", id);
-        self.private_get(REMOVE_API_KEY, &query).await
+        let params = RequestParams::new().add("id", id);
+        self.private_post(REMOVE_API_KEY, params).await
     }
 
     /// Reset an API key secret
@@ -2925,8 +2601,8 @@ impl DeribitHttpClient {
     ///
     /// Returns `HttpError` if the request fails or the API key is not found.
     pub async fn reset_api_key(&self, id: u64) -> Result<ApiKeyInfo, HttpError> {
-        let query = format!("?id={}", id);
-        self.private_get(RESET_API_KEY, &query).await
+        let params = RequestParams::new().add("id", id);
+        self.private_post(RESET_API_KEY, params).await
     }
 
     /// Change API key name
@@ -2946,8 +2622,8 @@ impl DeribitHttpClient {
     ///
     /// Returns `HttpError` if the request fails or the API key is not found.
     pub async fn change_api_key_name(&self, id: u64, name: &str) -> Result<ApiKeyInfo, HttpError> {
-        let query = format!("?id={}&name={}", id, urlencoding::encode(name));
-        self.private_get(CHANGE_API_KEY_NAME, &query).await
+        let params = RequestParams::new().add("id", id).add("name", name);
+        self.private_post(CHANGE_API_KEY_NAME, params).await
     }
 
     /// Change API key scope
@@ -2971,8 +2647,10 @@ impl DeribitHttpClient {
         id: u64,
         max_scope: &str,
     ) -> Result<ApiKeyInfo, HttpError> {
-        let query = format!("?id={}&max_scope={}", id, urlencoding::encode(max_scope));
-        self.private_get(CHANGE_SCOPE_IN_API_KEY, &query).await
+        let params = RequestParams::new()
+            .add("id", id)
+            .add("max_scope", max_scope);
+        self.private_post(CHANGE_SCOPE_IN_API_KEY, params).await
     }
 
     // ========================================================================
@@ -3019,89 +2697,34 @@ impl DeribitHttpClient {
         &self,
         request: &crate::model::SaveAddressBeneficiaryRequest,
     ) -> Result<crate::model::AddressBeneficiary, HttpError> {
-        let mut params = vec![
-            format!("currency={}", urlencoding::encode(&request.currency)),
-            format!("address={}", urlencoding::encode(&request.address)),
-            format!("agreed={}", request.agreed),
-            format!("personal={}", request.personal),
-            format!("unhosted={}", request.unhosted),
-            format!(
-                "beneficiary_vasp_name={}",
-                urlencoding::encode(&request.beneficiary_vasp_name)
-            ),
-            format!(
-                "beneficiary_vasp_did={}",
-                urlencoding::encode(&request.beneficiary_vasp_did)
-            ),
-            format!(
-                "beneficiary_address={}",
-                urlencoding::encode(&request.beneficiary_address)
-            ),
-        ];
-
-        if let Some(ref tag) = request.tag {
-            params.push(format!("tag={}", urlencoding::encode(tag)));
-        }
-        if let Some(ref website) = request.beneficiary_vasp_website {
-            params.push(format!(
-                "beneficiary_vasp_website={}",
-                urlencoding::encode(website)
-            ));
-        }
-        if let Some(ref first_name) = request.beneficiary_first_name {
-            params.push(format!(
-                "beneficiary_first_name={}",
-                urlencoding::encode(first_name)
-            ));
-        }
-        if let Some(ref last_name) = request.beneficiary_last_name {
-            params.push(format!(
-                "beneficiary_last_name={}",
-                urlencoding::encode(last_name)
-            ));
-        }
-        if let Some(ref company_name) = request.beneficiary_company_name {
-            params.push(format!(
-                "beneficiary_company_name={}",
-                urlencoding::encode(company_name)
-            ));
-        }
-
-        let url = format!(
-            "{}{}?{}",
-            self.base_url(),
-            SAVE_ADDRESS_BENEFICIARY,
-            params.join("&")
-        );
-
-        let response = self.make_authenticated_request(&url).await?;
-
-        if !response.status().is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-            return Err(HttpError::RequestFailed(format!(
-                "Save address beneficiary failed: {}",
-                error_text
-            )));
-        }
-
-        let api_response: ApiResponse<crate::model::AddressBeneficiary> = response
-            .json()
-            .await
-            .map_err(|e| HttpError::InvalidResponse(e.to_string()))?;
-
-        if let Some(error) = api_response.error {
-            return Err(HttpError::RequestFailed(format!(
-                "API error: {} - {}",
-                error.code, error.message
-            )));
-        }
+        let params = RequestParams::new()
+            .add("currency", &request.currency)
+            .add("address", &request.address)
+            .add("agreed", request.agreed)
+            .add("personal", request.personal)
+            .add("unhosted", request.unhosted)
+            .add("beneficiary_vasp_name", &request.beneficiary_vasp_name)
+            .add("beneficiary_vasp_did", &request.beneficiary_vasp_did)
+            .add("beneficiary_address", &request.beneficiary_address)
+            .add_opt("tag", request.tag.as_ref())
+            .add_opt(
+                "beneficiary_vasp_website",
+                request.beneficiary_vasp_website.as_ref(),
+            )
+            .add_opt(
+                "beneficiary_first_name",
+                request.beneficiary_first_name.as_ref(),
+            )
+            .add_opt(
+                "beneficiary_last_name",
+                request.beneficiary_last_name.as_ref(),
+            )
+            .add_opt(
+                "beneficiary_company_name",
+                request.beneficiary_company_name.as_ref(),
+            );
 
-        api_response.result.ok_or_else(|| {
-            HttpError::InvalidResponse("No beneficiary data in response".to_string())
-        })
+        self.private_post(SAVE_ADDRESS_BENEFICIARY, params).await
     }
 
     /// Delete address beneficiary information.
@@ -3131,19 +2754,16 @@ impl DeribitHttpClient {
     /// ```
     pub async fn delete_address_beneficiary(
         &self,
-        currency: &str,
+        currency: impl AsRef<str>,
         address: &str,
         tag: Option<&str>,
     ) -> Result<String, HttpError> {
-        let mut query = format!(
-            "?currency={}&address={}",
-            urlencoding::encode(currency),
-            urlencoding::encode(address)
-        );
-        if let Some(t) = tag {
-            query.push_str(&format!("&tag={}", urlencoding::encode(t)));
-        }
-        self.private_get(DELETE_ADDRESS_BENEFICIARY, &query).await
+        let currency = currency.as_ref();
+        let params = RequestParams::new()
+            .add("currency", currency)
+            .add("address", address)
+            .add_opt("tag", tag);
+        self.private_post(DELETE_ADDRESS_BENEFICIARY, params).await
     }
 
     /// Get address beneficiary information.
@@ -3173,19 +2793,16 @@ impl DeribitHttpClient {
     /// ```
     pub async fn get_address_beneficiary(
         &self,
-        currency: &str,
+        currency: impl AsRef<str>,
         address: &str,
         tag: Option<&str>,
     ) -> Result<crate::model::AddressBeneficiary, HttpError> {
-        let mut query = format!(
-            "?currency={}&address={}",
-            urlencoding::encode(currency),
-            urlencoding::encode(address)
-        );
-        if let Some(t) = tag {
-            query.push_str(&format!("&tag={}", urlencoding::encode(t)));
-        }
-        self.private_get(GET_ADDRESS_BENEFICIARY, &query).await
+        let currency = currency.as_ref();
+        let params = RequestParams::new()
+            .add("currency", currency)
+            .add("address", address)
+            .add_opt("tag", tag);
+        self.private_post(GET_ADDRESS_BENEFICIARY, params).await
     }
 
     /// List address beneficiaries with filtering and pagination.
@@ -3221,104 +2838,29 @@ impl DeribitHttpClient {
         &self,
         request: Option<&crate::model::ListAddressBeneficiariesRequest>,
     ) -> Result<crate::model::ListAddressBeneficiariesResponse, HttpError> {
-        let mut params: Vec<String> = Vec::new();
-
-        if let Some(req) = request {
-            if let Some(ref currency) = req.currency {
-                params.push(format!("currency={}", urlencoding::encode(currency)));
-            }
-            if let Some(ref address) = req.address {
-                params.push(format!("address={}", urlencoding::encode(address)));
-            }
-            if let Some(ref tag) = req.tag {
-                params.push(format!("tag={}", urlencoding::encode(tag)));
-            }
-            if let Some(created_before) = req.created_before {
-                params.push(format!("created_before={}", created_before));
-            }
-            if let Some(created_after) = req.created_after {
-                params.push(format!("created_after={}", created_after));
-            }
-            if let Some(updated_before) = req.updated_before {
-                params.push(format!("updated_before={}", updated_before));
-            }
-            if let Some(updated_after) = req.updated_after {
-                params.push(format!("updated_after={}", updated_after));
-            }
-            if let Some(personal) = req.personal {
-                params.push(format!("personal={}", personal));
-            }
-            if let Some(unhosted) = req.unhosted {
-                params.push(format!("unhosted={}", unhosted));
-            }
-            if let Some(ref vasp_name) = req.beneficiary_vasp_name {
-                params.push(format!(
-                    "beneficiary_vasp_name={}",
-                    urlencoding::encode(vasp_name)
-                ));
-            }
-            if let Some(ref vasp_did) = req.beneficiary_vasp_did {
-                params.push(format!(
-                    "beneficiary_vasp_did={}",
-                    urlencoding::encode(vasp_did)
-                ));
-            }
-            if let Some(ref vasp_website) = req.beneficiary_vasp_website {
-                params.push(format!(
-                    "beneficiary_vasp_website={}",
-                    urlencoding::encode(vasp_website)
-                ));
-            }
-            if let Some(limit) = req.limit {
-                params.push(format!("limit={}", limit));
-            }
-            if let Some(ref continuation) = req.continuation {
-                params.push(format!(
-                    "continuation={}",
-                    urlencoding::encode(continuation)
-                ));
-            }
-        }
-
-        let url = if params.is_empty() {
-            format!("{}{}", self.base_url(), LIST_ADDRESS_BENEFICIARIES)
-        } else {
-            format!(
-                "{}{}?{}",
-                self.base_url(),
-                LIST_ADDRESS_BENEFICIARIES,
-                params.join("&")
-            )
+        let params = match request {
+            Some(req) => RequestParams::new()
+                .add_opt("currency", req.currency.as_ref())
+                .add_opt("address", req.address.as_ref())
+                .add_opt("tag", req.tag.as_ref())
+                .add_opt("created_before", req.created_before)
+                .add_opt("created_after", req.created_after)
+                .add_opt("updated_before", req.updated_before)
+                .add_opt("updated_after", req.updated_after)
+                .add_opt("personal", req.personal)
+                .add_opt("unhosted", req.unhosted)
+                .add_opt("beneficiary_vasp_name", req.beneficiary_vasp_name.as_ref())
+                .add_opt("beneficiary_vasp_did", req.beneficiary_vasp_did.as_ref())
+                .add_opt(
+                    "beneficiary_vasp_website",
+                    req.beneficiary_vasp_website.as_ref(),
+                )
+                .add_opt("limit", req.limit)
+                .add_opt("continuation", req.continuation.as_ref()),
+            None => RequestParams::new(),
         };
 
-        let response = self.make_authenticated_request(&url).await?;
-
-        if !response.status().is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-            return Err(HttpError::RequestFailed(format!(
-                "List address beneficiaries failed: {}",
-                error_text
-            )));
-        }
-
-        let api_response: ApiResponse<crate::model::ListAddressBeneficiariesResponse> = response
-            .json()
-            .await
-            .map_err(|e| HttpError::InvalidResponse(e.to_string()))?;
-
-        if let Some(error) = api_response.error {
-            return Err(HttpError::RequestFailed(format!(
-                "API error: {} - {}",
-                error.code, error.message
-            )));
-        }
-
-        api_response.result.ok_or_else(|| {
-            HttpError::InvalidResponse("No beneficiaries data in response".to_string())
-        })
+        self.private_post(LIST_ADDRESS_BENEFICIARIES, params).await
     }
 
     /// Set clearance originator for a deposit.
@@ -3365,49 +2907,11 @@ impl DeribitHttpClient {
         deposit_id: &crate::model::DepositId,
         originator: &crate::model::Originator,
     ) -> Result<crate::model::ClearanceDepositResult, HttpError> {
-        let deposit_id_json = serde_json::to_string(deposit_id).map_err(|e| {
-            HttpError::InvalidResponse(format!("Failed to serialize deposit_id: {}", e))
-        })?;
-        let originator_json = serde_json::to_string(originator).map_err(|e| {
-            HttpError::InvalidResponse(format!("Failed to serialize originator: {}", e))
-        })?;
-
-        let url = format!(
-            "{}{}?deposit_id={}&originator={}",
-            self.base_url(),
-            SET_CLEARANCE_ORIGINATOR,
-            urlencoding::encode(&deposit_id_json),
-            urlencoding::encode(&originator_json)
-        );
-
-        let response = self.make_authenticated_request(&url).await?;
-
-        if !response.status().is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-            return Err(HttpError::RequestFailed(format!(
-                "Set clearance originator failed: {}",
-                error_text
-            )));
-        }
-
-        let api_response: ApiResponse<crate::model::ClearanceDepositResult> = response
-            .json()
-            .await
-            .map_err(|e| HttpError::InvalidResponse(e.to_string()))?;
-
-        if let Some(error) = api_response.error {
-            return Err(HttpError::RequestFailed(format!(
-                "API error: {} - {}",
-                error.code, error.message
-            )));
-        }
+        let params = RequestParams::new()
+            .add("deposit_id", deposit_id)
+            .add("originator", originator);
 
-        api_response
-            .result
-            .ok_or_else(|| HttpError::InvalidResponse("No deposit result in response".to_string()))
+        self.private_post(SET_CLEARANCE_ORIGINATOR, params).await
     }
 
     /// Get account access log
@@ -3424,19 +2928,10 @@ impl DeribitHttpClient {
         count: Option<u32>,
         offset: Option<u32>,
     ) -> Result<crate::model::AccessLogResponse, HttpError> {
-        let mut params = Vec::new();
-        if let Some(count) = count {
-            params.push(format!("count={}", count));
-        }
-        if let Some(offset) = offset {
-            params.push(format!("offset={}", offset));
-        }
-        let query = if params.is_empty() {
-            String::new()
-        } else {
-            format!("?{}", params.join("&"))
-        };
-        self.private_get(crate::constants::endpoints::GET_ACCESS_LOG, &query)
+        let params = RequestParams::new()
+            .add_opt("count", count)
+            .add_opt("offset", offset);
+        self.private_post(crate::constants::endpoints::GET_ACCESS_LOG, params)
             .await
     }
 
@@ -3445,8 +2940,11 @@ impl DeribitHttpClient {
     /// Retrieves information about any locks on the user's account.
     ///
     pub async fn get_user_locks(&self) -> Result<Vec<crate::model::UserLock>, HttpError> {
-        self.private_get(crate::constants::endpoints::GET_USER_LOCKS, "")
-            .await
+        self.private_post(
+            crate::constants::endpoints::GET_USER_LOCKS,
+            RequestParams::new(),
+        )
+        .await
     }
 
     /// List custody accounts
@@ -3459,10 +2957,37 @@ impl DeribitHttpClient {
     ///
     pub async fn list_custody_accounts(
         &self,
-        currency: &str,
+        currency: impl AsRef<str>,
     ) -> Result<Vec<crate::model::CustodyAccount>, HttpError> {
-        let query = format!("?currency={}", urlencoding::encode(currency));
-        self.private_get(crate::constants::endpoints::LIST_CUSTODY_ACCOUNTS, &query)
+        let currency = currency.as_ref();
+        let params = RequestParams::new().add("currency", currency);
+        self.private_post(crate::constants::endpoints::LIST_CUSTODY_ACCOUNTS, params)
+            .await
+    }
+
+    /// Get portfolio margins
+    ///
+    /// Returns the portfolio margin details of a currency for PM accounts.
+    /// With `simulated_positions` (instrument name to position size), the
+    /// projected figures include those positions on top of the current ones,
+    /// giving a what-if margin before trading.
+    ///
+    /// # Arguments
+    ///
+    /// * `currency` - Currency symbol (BTC, ETH, etc.)
+    /// * `simulated_positions` - Optional hypothetical positions to add
+    ///
+    pub async fn get_portfolio_margins(
+        &self,
+        currency: impl AsRef<str>,
+        simulated_positions: Option<std::collections::HashMap<String, f64>>,
+    ) -> Result<crate::model::PortfolioMarginsResponse, HttpError> {
+        let currency = currency.as_ref();
+        let params = RequestParams::new()
+            .add("currency", currency)
+            .add_opt("add_positions", simulated_positions.as_ref().map(|_| true))
+            .add_opt("simulated_positions", simulated_positions);
+        self.private_post(crate::constants::endpoints::GET_PORTFOLIO_MARGINS, params)
             .await
     }
 
@@ -3478,59 +3003,13 @@ impl DeribitHttpClient {
         &self,
         request: crate::model::SimulatePortfolioRequest,
     ) -> Result<crate::model::SimulatePortfolioResponse, HttpError> {
-        let mut query_params = vec![format!(
-            "currency={}",
-            urlencoding::encode(&request.currency)
-        )];
-
-        if let Some(add_positions) = request.add_positions {
-            query_params.push(format!("add_positions={}", add_positions));
-        }
-
-        if let Some(ref positions) = request.simulated_positions {
-            let positions_json = serde_json::to_string(positions)
-                .map_err(|e| HttpError::InvalidResponse(e.to_string()))?;
-            query_params.push(format!(
-                "simulated_positions={}",
-                urlencoding::encode(&positions_json)
-            ));
-        }
-
-        let url = format!(
-            "{}{}?{}",
-            self.base_url(),
-            crate::constants::endpoints::SIMULATE_PORTFOLIO,
-            query_params.join("&")
-        );
+        let params = RequestParams::new()
+            .add("currency", &request.currency)
+            .add_opt("add_positions", request.add_positions)
+            .add_opt("simulated_positions", request.simulated_positions.as_ref());
 
-        let response = self.make_authenticated_request(&url).await?;
-
-        if !response.status().is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-            return Err(HttpError::RequestFailed(format!(
-                "Simulate portfolio failed: {}",
-                error_text
-            )));
-        }
-
-        let api_response: ApiResponse<crate::model::SimulatePortfolioResponse> = response
-            .json()
+        self.private_post(crate::constants::endpoints::SIMULATE_PORTFOLIO, params)
             .await
-            .map_err(|e| HttpError::InvalidResponse(e.to_string()))?;
-
-        if let Some(error) = api_response.error {
-            return Err(HttpError::RequestFailed(format!(
-                "API error: {} - {}",
-                error.code, error.message
-            )));
-        }
-
-        api_response.result.ok_or_else(|| {
-            HttpError::InvalidResponse("No portfolio simulation data in response".to_string())
-        })
     }
 
     /// PME margin simulation
@@ -3543,10 +3022,11 @@ impl DeribitHttpClient {
     ///
     pub async fn pme_simulate(
         &self,
-        currency: &str,
+        currency: impl AsRef<str>,
     ) -> Result<crate::model::PmeSimulateResponse, HttpError> {
-        let query = format!("?currency={}", urlencoding::encode(currency));
-        self.private_get(crate::constants::endpoints::PME_SIMULATE, &query)
+        let currency = currency.as_ref();
+        let params = RequestParams::new().add("currency", currency);
+        self.private_post(crate::constants::endpoints::PME_SIMULATE, params)
             .await
     }
 
@@ -3566,51 +3046,13 @@ impl DeribitHttpClient {
         user_id: Option<u64>,
         dry_run: Option<bool>,
     ) -> Result<crate::model::ChangeMarginModelResponse, HttpError> {
-        let mut query_params = vec![format!("margin_model={}", margin_model.as_str())];
-
-        if let Some(user_id) = user_id {
-            query_params.push(format!("user_id={}", user_id));
-        }
-
-        if let Some(dry_run) = dry_run {
-            query_params.push(format!("dry_run={}", dry_run));
-        }
-
-        let url = format!(
-            "{}{}?{}",
-            self.base_url(),
-            crate::constants::endpoints::CHANGE_MARGIN_MODEL,
-            query_params.join("&")
-        );
-
-        let response = self.make_authenticated_request(&url).await?;
-
-        if !response.status().is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-            return Err(HttpError::RequestFailed(format!(
-                "Change margin model failed: {}",
-                error_text
-            )));
-        }
+        let params = RequestParams::new()
+            .add("margin_model", margin_model.as_str())
+            .add_opt("user_id", user_id)
+            .add_opt("dry_run", dry_run);
 
-        let api_response: ApiResponse<crate::model::ChangeMarginModelResponse> = response
-            .json()
+        self.private_post(crate::constants::endpoints::CHANGE_MARGIN_MODEL, params)
             .await
-            .map_err(|e| HttpError::InvalidResponse(e.to_string()))?;
-
-        if let Some(error) = api_response.error {
-            return Err(HttpError::RequestFailed(format!(
-                "API error: {} - {}",
-                error.code, error.message
-            )));
-        }
-
-        api_response.result.ok_or_else(|| {
-            HttpError::InvalidResponse("No margin model change data in response".to_string())
-        })
     }
 
     /// Set self-trading configuration
@@ -3629,102 +3071,110 @@ impl DeribitHttpClient {
         extended_to_subaccounts: bool,
         block_rfq_self_match_prevention: Option<bool>,
     ) -> Result<bool, HttpError> {
-        let mut query_params = vec![
-            format!("mode={}", mode.as_str()),
-            format!("extended_to_subaccounts={}", extended_to_subaccounts),
-        ];
-
-        if let Some(block_rfq) = block_rfq_self_match_prevention {
-            query_params.push(format!("block_rfq_self_match_prevention={}", block_rfq));
-        }
-
-        let url = format!(
-            "{}{}?{}",
-            self.base_url(),
-            crate::constants::endpoints::SET_SELF_TRADING_CONFIG,
-            query_params.join("&")
-        );
-
-        let response = self.make_authenticated_request(&url).await?;
-
-        if !response.status().is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-            return Err(HttpError::RequestFailed(format!(
-                "Set self trading config failed: {}",
-                error_text
-            )));
-        }
-
-        let api_response: ApiResponse<String> = response
-            .json()
-            .await
-            .map_err(|e| HttpError::InvalidResponse(e.to_string()))?;
-
-        if let Some(error) = api_response.error {
-            return Err(HttpError::RequestFailed(format!(
-                "API error: {} - {}",
-                error.code, error.message
-            )));
-        }
-
-        Ok(api_response.result.map(|s| s == "ok").unwrap_or(true))
+        let params = RequestParams::new()
+            .add("mode", mode.as_str())
+            .add("extended_to_subaccounts", extended_to_subaccounts)
+            .add_opt(
+                "block_rfq_self_match_prevention",
+                block_rfq_self_match_prevention,
+            );
+
+        let result = self
+            .private_post_optional::<String>(
+                crate::constants::endpoints::SET_SELF_TRADING_CONFIG,
+                params,
+            )
+            .await?;
+        Ok(result.map(|s| s == "ok").unwrap_or(true))
     }
 
-    /// Set disabled trading products
+    /// Get cancel-on-disconnect configuration
     ///
-    /// Disables specific trading products for a user.
+    /// Reads whether open orders are cancelled when the session disconnects.
+    /// Useful for checking a setting made over WebSocket or by another API key.
     ///
     /// # Arguments
     ///
-    /// * `trading_products` - List of trading products to disable
-    /// * `user_id` - User ID to apply the setting to
+    /// * `scope` - `Account` for the account-wide setting; defaults to `Connection`
     ///
-    pub async fn set_disabled_trading_products(
+    pub async fn get_cancel_on_disconnect(
         &self,
-        trading_products: &[crate::model::TradingProduct],
-        user_id: u64,
-    ) -> Result<bool, HttpError> {
-        let products: Vec<&str> = trading_products.iter().map(|p| p.as_str()).collect();
-        let products_json = serde_json::to_string(&products)
-            .map_err(|e| HttpError::InvalidResponse(e.to_string()))?;
-
-        let url = format!(
-            "{}{}?trading_products={}&user_id={}",
-            self.base_url(),
-            crate::constants::endpoints::SET_DISABLED_TRADING_PRODUCTS,
-            urlencoding::encode(&products_json),
-            user_id
-        );
-
-        let response = self.make_authenticated_request(&url).await?;
-
-        if !response.status().is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-            return Err(HttpError::RequestFailed(format!(
-                "Set disabled trading products failed: {}",
-                error_text
-            )));
-        }
+        scope: Option<crate::model::CancelOnDisconnectScope>,
+    ) -> Result<crate::model::CancelOnDisconnect, HttpError> {
+        let params = RequestParams::new().add_opt("scope", scope);
+        self.private_post(
+            crate::constants::endpoints::GET_CANCEL_ON_DISCONNECT,
+            params,
+        )
+        .await
+    }
 
-        let api_response: ApiResponse<String> = response
-            .json()
-            .await
-            .map_err(|e| HttpError::InvalidResponse(e.to_string()))?;
+    /// Enable cancel-on-disconnect
+    ///
+    /// # Arguments
+    ///
+    /// * `scope` - `Account` for the account-wide setting; defaults to `Connection`
+    ///
+    pub async fn enable_cancel_on_disconnect(
+        &self,
+        scope: Option<crate::model::CancelOnDisconnectScope>,
+    ) -> Result<bool, HttpError> {
+        let params = RequestParams::new().add_opt("scope", scope);
+        let result: String = self
+            .private_post(
+                crate::constants::endpoints::ENABLE_CANCEL_ON_DISCONNECT,
+                params,
+            )
+            .await?;
+        Ok(result == "ok")
+    }
 
-        if let Some(error) = api_response.error {
-            return Err(HttpError::RequestFailed(format!(
-                "API error: {} - {}",
-                error.code, error.message
-            )));
-        }
+    /// Disable cancel-on-disconnect
+    ///
+    /// # Arguments
+    ///
+    /// * `scope` - `Account` for the account-wide setting; defaults to `Connection`
+    ///
+    pub async fn disable_cancel_on_disconnect(
+        &self,
+        scope: Option<crate::model::CancelOnDisconnectScope>,
+    ) -> Result<bool, HttpError> {
+        let params = RequestParams::new().add_opt("scope", scope);
+        let result: String = self
+            .private_post(
+                crate::constants::endpoints::DISABLE_CANCEL_ON_DISCONNECT,
+                params,
+            )
+            .await?;
+        Ok(result == "ok")
+    }
 
-        Ok(api_response.result.map(|s| s == "ok").unwrap_or(true))
+    /// Set disabled trading products
+    ///
+    /// Disables specific trading products for a user.
+    ///
+    /// # Arguments
+    ///
+    /// * `trading_products` - List of trading products to disable
+    /// * `user_id` - User ID to apply the setting to
+    ///
+    pub async fn set_disabled_trading_products(
+        &self,
+        trading_products: &[crate::model::TradingProduct],
+        user_id: u64,
+    ) -> Result<bool, HttpError> {
+        let products: Vec<&str> = trading_products.iter().map(|p| p.as_str()).collect();
+        let params = RequestParams::new()
+            .add("trading_products", products)
+            .add("user_id", user_id);
+
+        let result = self
+            .private_post_optional::<String>(
+                crate::constants::endpoints::SET_DISABLED_TRADING_PRODUCTS,
+                params,
+            )
+            .await?;
+        Ok(result.map(|s| s == "ok").unwrap_or(true))
     }
 
     /// Get new (unread) announcements
@@ -3734,8 +3184,11 @@ impl DeribitHttpClient {
     pub async fn get_new_announcements(
         &self,
     ) -> Result<Vec<crate::model::Announcement>, HttpError> {
-        self.private_get(crate::constants::endpoints::GET_NEW_ANNOUNCEMENTS, "")
-            .await
+        self.private_post(
+            crate::constants::endpoints::GET_NEW_ANNOUNCEMENTS,
+            RequestParams::new(),
+        )
+        .await
     }
 
     /// Mark announcement as read
@@ -3747,11 +3200,11 @@ impl DeribitHttpClient {
     /// * `announcement_id` - ID of the announcement to mark as read
     ///
     pub async fn set_announcement_as_read(&self, announcement_id: u64) -> Result<bool, HttpError> {
-        let query = format!("?announcement_id={}", announcement_id);
+        let params = RequestParams::new().add("announcement_id", announcement_id);
         let result: String = self
-            .private_get(
+            .private_post(
                 crate::constants::endpoints::SET_ANNOUNCEMENT_AS_READ,
-                &query,
+                params,
             )
             .await?;
         Ok(result == "ok")
@@ -3763,7 +3216,10 @@ impl DeribitHttpClient {
     ///
     pub async fn enable_affiliate_program(&self) -> Result<bool, HttpError> {
         let result: String = self
-            .private_get(crate::constants::endpoints::ENABLE_AFFILIATE_PROGRAM, "")
+            .private_post(
+                crate::constants::endpoints::ENABLE_AFFILIATE_PROGRAM,
+                RequestParams::new(),
+            )
             .await?;
         Ok(result == "ok")
     }
@@ -3775,8 +3231,11 @@ impl DeribitHttpClient {
     pub async fn get_affiliate_program_info(
         &self,
     ) -> Result<crate::model::AffiliateProgramInfo, HttpError> {
-        self.private_get(crate::constants::endpoints::GET_AFFILIATE_PROGRAM_INFO, "")
-            .await
+        self.private_post(
+            crate::constants::endpoints::GET_AFFILIATE_PROGRAM_INFO,
+            RequestParams::new(),
+        )
+        .await
     }
 
     /// Set email language preference
@@ -3791,9 +3250,9 @@ impl DeribitHttpClient {
         &self,
         language: crate::model::EmailLanguage,
     ) -> Result<bool, HttpError> {
-        let query = format!("?language={}", language.as_str());
+        let params = RequestParams::new().add("language", language.as_str());
         let result: String = self
-            .private_get(crate::constants::endpoints::SET_EMAIL_LANGUAGE, &query)
+            .private_post(crate::constants::endpoints::SET_EMAIL_LANGUAGE, params)
             .await?;
         Ok(result == "ok")
     }
@@ -3803,8 +3262,11 @@ impl DeribitHttpClient {
     /// Retrieves the current email language preference.
     ///
     pub async fn get_email_language(&self) -> Result<String, HttpError> {
-        self.private_get(crate::constants::endpoints::GET_EMAIL_LANGUAGE, "")
-            .await
+        self.private_post(
+            crate::constants::endpoints::GET_EMAIL_LANGUAGE,
+            RequestParams::new(),
+        )
+        .await
     }
 
     // ========================================================================
@@ -3832,57 +3294,18 @@ impl DeribitHttpClient {
     /// Returns `HttpError` if the request fails or the address is not in the address book.
     pub async fn withdraw(
         &self,
-        currency: &str,
+        currency: impl AsRef<str>,
         address: &str,
         amount: f64,
         priority: Option<crate::model::wallet::WithdrawalPriorityLevel>,
     ) -> Result<crate::model::Withdrawal, HttpError> {
-        let mut query_params = vec![
-            ("currency".to_string(), currency.to_string()),
-            ("address".to_string(), address.to_string()),
-            ("amount".to_string(), amount.to_string()),
-        ];
-
-        if let Some(p) = priority {
-            query_params.push(("priority".to_string(), p.as_str().to_string()));
-        }
-
-        let query_string = query_params
-            .iter()
-            .map(|(k, v)| format!("{}={}", k, urlencoding::encode(v)))
-            .collect::<Vec<_>>()
-            .join("&");
-
-        let url = format!("{}{}?{}", self.base_url(), WITHDRAW, query_string);
-
-        let response = self.make_authenticated_request(&url).await?;
-
-        if !response.status().is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-            return Err(HttpError::RequestFailed(format!(
-                "Withdraw failed: {}",
-                error_text
-            )));
-        }
-
-        let api_response: ApiResponse<crate::model::Withdrawal> = response
-            .json()
-            .await
-            .map_err(|e| HttpError::InvalidResponse(e.to_string()))?;
-
-        if let Some(error) = api_response.error {
-            return Err(HttpError::RequestFailed(format!(
-                "API error: {} - {}",
-                error.code, error.message
-            )));
-        }
-
-        api_response
-            .result
-            .ok_or_else(|| HttpError::InvalidResponse("No withdrawal data in response".to_string()))
+        let currency = currency.as_ref();
+        let params = RequestParams::new()
+            .add("currency", currency)
+            .add("address", address)
+            .add("amount", amount)
+            .add_opt("priority", priority.map(|p| p.as_str()));
+        self.private_post(WITHDRAW, params).await
     }
 
     /// Cancel a pending withdrawal
@@ -3903,11 +3326,12 @@ impl DeribitHttpClient {
     /// Returns `HttpError` if the withdrawal cannot be cancelled or does not exist.
     pub async fn cancel_withdrawal(
         &self,
-        currency: &str,
+        currency: impl AsRef<str>,
         id: u64,
     ) -> Result<crate::model::Withdrawal, HttpError> {
-        let query = format!("?currency={}&id={}", urlencoding::encode(currency), id);
-        self.private_get(CANCEL_WITHDRAWAL, &query).await
+        let currency = currency.as_ref();
+        let params = RequestParams::new().add("currency", currency).add("id", id);
+        self.private_post(CANCEL_WITHDRAWAL, params).await
     }
 
     /// Create a new deposit address
@@ -3927,10 +3351,11 @@ impl DeribitHttpClient {
     /// Returns `HttpError` if address creation fails.
     pub async fn create_deposit_address(
         &self,
-        currency: &str,
+        currency: impl AsRef<str>,
     ) -> Result<crate::model::wallet::DepositAddress, HttpError> {
-        let query = format!("?currency={}", urlencoding::encode(currency));
-        self.private_get(CREATE_DEPOSIT_ADDRESS, &query).await
+        let currency = currency.as_ref();
+        let params = RequestParams::new().add("currency", currency);
+        self.private_post(CREATE_DEPOSIT_ADDRESS, params).await
     }
 
     /// Get the current deposit address
@@ -3950,10 +3375,11 @@ impl DeribitHttpClient {
     /// Returns `HttpError` if no address exists or the request fails.
     pub async fn get_current_deposit_address(
         &self,
-        currency: &str,
+        currency: impl AsRef<str>,
     ) -> Result<crate::model::wallet::DepositAddress, HttpError> {
-        let query = format!("?currency={}", urlencoding::encode(currency));
-        self.private_get(GET_CURRENT_DEPOSIT_ADDRESS, &query).await
+        let currency = currency.as_ref();
+        let params = RequestParams::new().add("currency", currency);
+        self.private_post(GET_CURRENT_DEPOSIT_ADDRESS, params).await
     }
 
     /// Add an address to the address book
@@ -3977,67 +3403,21 @@ impl DeribitHttpClient {
     /// Returns `HttpError` if the address is invalid or already exists.
     pub async fn add_to_address_book(
         &self,
-        currency: &str,
+        currency: impl AsRef<str>,
         address_type: crate::model::wallet::AddressBookType,
         address: &str,
         label: Option<&str>,
         tag: Option<&str>,
     ) -> Result<crate::model::wallet::AddressBookEntry, HttpError> {
-        let mut query_params = vec![
-            ("currency".to_string(), currency.to_string()),
-            ("type".to_string(), address_type.as_str().to_string()),
-            ("address".to_string(), address.to_string()),
-        ];
-
-        if let Some(l) = label {
-            query_params.push(("label".to_string(), l.to_string()));
-        }
-
-        if let Some(t) = tag {
-            query_params.push(("tag".to_string(), t.to_string()));
-        }
-
-        let query_string = query_params
-            .iter()
-            .map(|(k, v)| format!("{}={}", k, urlencoding::encode(v)))
-            .collect::<Vec<_>>()
-            .join("&");
-
-        let url = format!(
-            "{}{}?{}",
-            self.base_url(),
-            ADD_TO_ADDRESS_BOOK,
-            query_string
-        );
-
-        let response = self.make_authenticated_request(&url).await?;
-
-        if !response.status().is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-            return Err(HttpError::RequestFailed(format!(
-                "Add to address book failed: {}",
-                error_text
-            )));
-        }
-
-        let api_response: ApiResponse<crate::model::wallet::AddressBookEntry> = response
-            .json()
-            .await
-            .map_err(|e| HttpError::InvalidResponse(e.to_string()))?;
-
-        if let Some(error) = api_response.error {
-            return Err(HttpError::RequestFailed(format!(
-                "API error: {} - {}",
-                error.code, error.message
-            )));
-        }
-
-        api_response.result.ok_or_else(|| {
-            HttpError::InvalidResponse("No address book entry in response".to_string())
-        })
+        let currency = currency.as_ref();
+        let params = RequestParams::new()
+            .add("currency", currency)
+            .add("type", address_type.as_str())
+            .add("address", address)
+            .add_opt("label", label)
+            .add_opt("tag", tag);
+
+        self.private_post(ADD_TO_ADDRESS_BOOK, params).await
     }
 
     /// Remove an address from the address book
@@ -4059,17 +3439,16 @@ impl DeribitHttpClient {
     /// Returns `HttpError` if the address does not exist or cannot be removed.
     pub async fn remove_from_address_book(
         &self,
-        currency: &str,
+        currency: impl AsRef<str>,
         address_type: crate::model::wallet::AddressBookType,
         address: &str,
     ) -> Result<bool, HttpError> {
-        let query = format!(
-            "?currency={}&type={}&address={}",
-            urlencoding::encode(currency),
-            urlencoding::encode(address_type.as_str()),
-            urlencoding::encode(address)
-        );
-        let result: String = self.private_get(REMOVE_FROM_ADDRESS_BOOK, &query).await?;
+        let currency = currency.as_ref();
+        let params = RequestParams::new()
+            .add("currency", currency)
+            .add("type", address_type.as_str())
+            .add("address", address);
+        let result: String = self.private_post(REMOVE_FROM_ADDRESS_BOOK, params).await?;
         Ok(result == "ok")
     }
 
@@ -4093,89 +3472,38 @@ impl DeribitHttpClient {
         &self,
         request: &crate::model::request::wallet::UpdateInAddressBookRequest,
     ) -> Result<bool, HttpError> {
-        let mut query_params = vec![
-            ("currency".to_string(), request.currency.clone()),
-            (
-                "type".to_string(),
-                request.address_type.as_str().to_string(),
-            ),
-            ("address".to_string(), request.address.clone()),
-            ("label".to_string(), request.label.clone()),
-            ("agreed".to_string(), request.agreed.to_string()),
-            ("personal".to_string(), request.personal.to_string()),
-            (
-                "beneficiary_vasp_name".to_string(),
-                request.beneficiary_vasp_name.clone(),
-            ),
-            (
-                "beneficiary_vasp_did".to_string(),
-                request.beneficiary_vasp_did.clone(),
-            ),
-            (
-                "beneficiary_address".to_string(),
-                request.beneficiary_address.clone(),
-            ),
-        ];
-
-        if let Some(ref website) = request.beneficiary_vasp_website {
-            query_params.push(("beneficiary_vasp_website".to_string(), website.clone()));
-        }
-
-        if let Some(ref first_name) = request.beneficiary_first_name {
-            query_params.push(("beneficiary_first_name".to_string(), first_name.clone()));
-        }
-
-        if let Some(ref last_name) = request.beneficiary_last_name {
-            query_params.push(("beneficiary_last_name".to_string(), last_name.clone()));
-        }
-
-        if let Some(ref company_name) = request.beneficiary_company_name {
-            query_params.push(("beneficiary_company_name".to_string(), company_name.clone()));
-        }
-
-        if let Some(ref tag) = request.tag {
-            query_params.push(("tag".to_string(), tag.clone()));
-        }
-
-        let query_string = query_params
-            .iter()
-            .map(|(k, v)| format!("{}={}", k, urlencoding::encode(v)))
-            .collect::<Vec<_>>()
-            .join("&");
-
-        let url = format!(
-            "{}{}?{}",
-            self.base_url(),
-            UPDATE_IN_ADDRESS_BOOK,
-            query_string
-        );
-
-        let response = self.make_authenticated_request(&url).await?;
-
-        if !response.status().is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-            return Err(HttpError::RequestFailed(format!(
-                "Update in address book failed: {}",
-                error_text
-            )));
-        }
-
-        let api_response: ApiResponse<String> = response
-            .json()
-            .await
-            .map_err(|e| HttpError::InvalidResponse(e.to_string()))?;
-
-        if let Some(error) = api_response.error {
-            return Err(HttpError::RequestFailed(format!(
-                "API error: {} - {}",
-                error.code, error.message
-            )));
-        }
+        let params = RequestParams::new()
+            .add("currency", &request.currency)
+            .add("type", request.address_type.as_str())
+            .add("address", &request.address)
+            .add("label", &request.label)
+            .add("agreed", request.agreed)
+            .add("personal", request.personal)
+            .add("beneficiary_vasp_name", &request.beneficiary_vasp_name)
+            .add("beneficiary_vasp_did", &request.beneficiary_vasp_did)
+            .add("beneficiary_address", &request.beneficiary_address)
+            .add_opt(
+                "beneficiary_vasp_website",
+                request.beneficiary_vasp_website.as_ref(),
+            )
+            .add_opt(
+                "beneficiary_first_name",
+                request.beneficiary_first_name.as_ref(),
+            )
+            .add_opt(
+                "beneficiary_last_name",
+                request.beneficiary_last_name.as_ref(),
+            )
+            .add_opt(
+                "beneficiary_company_name",
+                request.beneficiary_company_name.as_ref(),
+            )
+            .add_opt("tag", request.tag.as_ref());
 
-        Ok(api_response.result.map(|s| s == "ok").unwrap_or(true))
+        let result = self
+            .private_post_optional::<String>(UPDATE_IN_ADDRESS_BOOK, params)
+            .await?;
+        Ok(result.map(|s| s == "ok").unwrap_or(true))
     }
 
     /// Get addresses from the address book
@@ -4196,15 +3524,14 @@ impl DeribitHttpClient {
     /// Returns `HttpError` if the request fails.
     pub async fn get_address_book(
         &self,
-        currency: &str,
+        currency: impl AsRef<str>,
         address_type: crate::model::wallet::AddressBookType,
     ) -> Result<Vec<crate::model::wallet::AddressBookEntry>, HttpError> {
-        let query = format!(
-            "?currency={}&type={}",
-            urlencoding::encode(currency),
-            urlencoding::encode(address_type.as_str())
-        );
-        self.private_get(GET_ADDRESS_BOOK, &query).await
+        let currency = currency.as_ref();
+        let params = RequestParams::new()
+            .add("currency", currency)
+            .add("type", address_type.as_str());
+        self.private_post(GET_ADDRESS_BOOK, params).await
     }
 
     // ========================================================================
@@ -4233,17 +3560,15 @@ impl DeribitHttpClient {
     /// Returns `HttpError` if the request fails or the block trade cannot be approved.
     pub async fn approve_block_trade(
         &self,
-        timestamp: u64,
+        timestamp: TimestampMs,
         nonce: &str,
         role: crate::model::block_trade::BlockTradeRole,
     ) -> Result<bool, HttpError> {
-        let query = format!(
-            "?timestamp={}&nonce={}&role={}",
-            timestamp,
-            urlencoding::encode(nonce),
-            urlencoding::encode(&role.to_string())
-        );
-        let result: String = self.private_get(APPROVE_BLOCK_TRADE, &query).await?;
+        let params = RequestParams::new()
+            .add("timestamp", timestamp)
+            .add("nonce", nonce)
+            .add("role", role.to_string());
+        let result: String = self.private_post(APPROVE_BLOCK_TRADE, params).await?;
         Ok(result == "ok")
     }
 
@@ -4268,62 +3593,14 @@ impl DeribitHttpClient {
         &self,
         request: &crate::model::block_trade::ExecuteBlockTradeRequest,
     ) -> Result<crate::model::block_trade::BlockTradeResult, HttpError> {
-        let trades_json = serde_json::to_string(&request.trades).map_err(|e| {
-            HttpError::InvalidResponse(format!("Failed to serialize trades: {}", e))
-        })?;
-
-        let query_params = [
-            ("timestamp".to_string(), request.timestamp.to_string()),
-            ("nonce".to_string(), request.nonce.clone()),
-            ("role".to_string(), request.role.to_string()),
-            ("trades".to_string(), trades_json),
-            (
-                "counterparty_signature".to_string(),
-                request.counterparty_signature.clone(),
-            ),
-        ];
-
-        let query_string = query_params
-            .iter()
-            .map(|(k, v)| format!("{}={}", k, urlencoding::encode(v)))
-            .collect::<Vec<_>>()
-            .join("&");
-
-        let url = format!(
-            "{}{}?{}",
-            self.base_url(),
-            EXECUTE_BLOCK_TRADE,
-            query_string
-        );
-
-        let response = self.make_authenticated_request(&url).await?;
-
-        if !response.status().is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-            return Err(HttpError::RequestFailed(format!(
-                "Execute block trade failed: {}",
-                error_text
-            )));
-        }
-
-        let api_response: ApiResponse<crate::model::block_trade::BlockTradeResult> = response
-            .json()
-            .await
-            .map_err(|e| HttpError::InvalidResponse(e.to_string()))?;
-
-        if let Some(error) = api_response.error {
-            return Err(HttpError::RequestFailed(format!(
-                "API error: {} - {}",
-                error.code, error.message
-            )));
-        }
-
-        api_response.result.ok_or_else(|| {
-            HttpError::InvalidResponse("No block trade result in response".to_string())
-        })
+        let params = RequestParams::new()
+            .add("timestamp", request.timestamp)
+            .add("nonce", &request.nonce)
+            .add("role", request.role.to_string())
+            .add("trades", &request.trades)
+            .add("counterparty_signature", &request.counterparty_signature);
+
+        self.private_post(EXECUTE_BLOCK_TRADE, params).await
     }
 
     /// Get a specific block trade by ID
@@ -4345,8 +3622,8 @@ impl DeribitHttpClient {
         &self,
         id: &str,
     ) -> Result<crate::model::block_trade::BlockTrade, HttpError> {
-        let query = format!("?id={}", urlencoding::encode(id));
-        self.private_get(GET_BLOCK_TRADE, &query).await
+        let params = RequestParams::new().add("id", id);
+        self.private_post(GET_BLOCK_TRADE, params).await
     }
 
     /// Get pending block trade requests
@@ -4370,58 +3647,15 @@ impl DeribitHttpClient {
         &self,
         broker_code: Option<&str>,
     ) -> Result<Vec<crate::model::block_trade::BlockTradeRequest>, HttpError> {
-        let mut query_params = Vec::new();
-
-        if let Some(code) = broker_code {
-            query_params.push(("broker_code".to_string(), code.to_string()));
-        }
+        let params = RequestParams::new().add_opt("broker_code", broker_code);
 
-        let query_string = if query_params.is_empty() {
-            String::new()
-        } else {
-            format!(
-                "?{}",
-                query_params
-                    .iter()
-                    .map(|(k, v)| format!("{}={}", k, urlencoding::encode(v)))
-                    .collect::<Vec<_>>()
-                    .join("&")
+        let result = self
+            .private_post_optional::<Vec<crate::model::block_trade::BlockTradeRequest>>(
+                GET_BLOCK_TRADE_REQUESTS,
+                params,
             )
-        };
-
-        let url = format!(
-            "{}{}{}",
-            self.base_url(),
-            GET_BLOCK_TRADE_REQUESTS,
-            query_string
-        );
-
-        let response = self.make_authenticated_request(&url).await?;
-
-        if !response.status().is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-            return Err(HttpError::RequestFailed(format!(
-                "Get block trade requests failed: {}",
-                error_text
-            )));
-        }
-
-        let api_response: ApiResponse<Vec<crate::model::block_trade::BlockTradeRequest>> = response
-            .json()
-            .await
-            .map_err(|e| HttpError::InvalidResponse(e.to_string()))?;
-
-        if let Some(error) = api_response.error {
-            return Err(HttpError::RequestFailed(format!(
-                "API error: {} - {}",
-                error.code, error.message
-            )));
-        }
-
-        Ok(api_response.result.unwrap_or_default())
+            .await?;
+        Ok(result.unwrap_or_default())
     }
 
     /// Get block trades with optional filters
@@ -4443,65 +3677,20 @@ impl DeribitHttpClient {
         &self,
         request: &crate::model::block_trade::GetBlockTradesRequest,
     ) -> Result<Vec<crate::model::block_trade::BlockTrade>, HttpError> {
-        let mut query_params = Vec::new();
-
-        if let Some(ref currency) = request.currency {
-            query_params.push(("currency".to_string(), currency.clone()));
-        }
-        if let Some(count) = request.count {
-            query_params.push(("count".to_string(), count.to_string()));
-        }
-        if let Some(ref continuation) = request.continuation {
-            query_params.push(("continuation".to_string(), continuation.clone()));
-        }
-        if let Some(start_ts) = request.start_timestamp {
-            query_params.push(("start_timestamp".to_string(), start_ts.to_string()));
-        }
-        if let Some(end_ts) = request.end_timestamp {
-            query_params.push(("end_timestamp".to_string(), end_ts.to_string()));
-        }
-
-        let query_string = if query_params.is_empty() {
-            String::new()
-        } else {
-            format!(
-                "?{}",
-                query_params
-                    .iter()
-                    .map(|(k, v)| format!("{}={}", k, urlencoding::encode(v)))
-                    .collect::<Vec<_>>()
-                    .join("&")
+        let params = RequestParams::new()
+            .add_opt("currency", request.currency.as_ref())
+            .add_opt("count", request.count)
+            .add_opt("continuation", request.continuation.as_ref())
+            .add_opt("start_timestamp", request.start_timestamp)
+            .add_opt("end_timestamp", request.end_timestamp);
+
+        let result = self
+            .private_post_optional::<Vec<crate::model::block_trade::BlockTrade>>(
+                GET_BLOCK_TRADES,
+                params,
             )
-        };
-
-        let url = format!("{}{}{}", self.base_url(), GET_BLOCK_TRADES, query_string);
-
-        let response = self.make_authenticated_request(&url).await?;
-
-        if !response.status().is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-            return Err(HttpError::RequestFailed(format!(
-                "Get block trades failed: {}",
-                error_text
-            )));
-        }
-
-        let api_response: ApiResponse<Vec<crate::model::block_trade::BlockTrade>> = response
-            .json()
-            .await
-            .map_err(|e| HttpError::InvalidResponse(e.to_string()))?;
-
-        if let Some(error) = api_response.error {
-            return Err(HttpError::RequestFailed(format!(
-                "API error: {} - {}",
-                error.code, error.message
-            )));
-        }
-
-        Ok(api_response.result.unwrap_or_default())
+            .await?;
+        Ok(result.unwrap_or_default())
     }
 
     /// Get broker trade requests
@@ -4518,7 +3707,8 @@ impl DeribitHttpClient {
     pub async fn get_broker_trade_requests(
         &self,
     ) -> Result<Vec<crate::model::block_trade::BlockTradeRequest>, HttpError> {
-        self.private_get(GET_BROKER_TRADE_REQUESTS, "").await
+        self.private_post(GET_BROKER_TRADE_REQUESTS, RequestParams::new())
+            .await
     }
 
     /// Get broker trades with optional filters
@@ -4540,65 +3730,20 @@ impl DeribitHttpClient {
         &self,
         request: &crate::model::block_trade::GetBlockTradesRequest,
     ) -> Result<Vec<crate::model::block_trade::BlockTrade>, HttpError> {
-        let mut query_params = Vec::new();
-
-        if let Some(ref currency) = request.currency {
-            query_params.push(("currency".to_string(), currency.clone()));
-        }
-        if let Some(count) = request.count {
-            query_params.push(("count".to_string(), count.to_string()));
-        }
-        if let Some(ref continuation) = request.continuation {
-            query_params.push(("continuation".to_string(), continuation.clone()));
-        }
-        if let Some(start_ts) = request.start_timestamp {
-            query_params.push(("start_timestamp".to_string(), start_ts.to_string()));
-        }
-        if let Some(end_ts) = request.end_timestamp {
-            query_params.push(("end_timestamp".to_string(), end_ts.to_string()));
-        }
-
-        let query_string = if query_params.is_empty() {
-            String::new()
-        } else {
-            format!(
-                "?{}",
-                query_params
-                    .iter()
-                    .map(|(k, v)| format!("{}={}", k, urlencoding::encode(v)))
-                    .collect::<Vec<_>>()
-                    .join("&")
+        let params = RequestParams::new()
+            .add_opt("currency", request.currency.as_ref())
+            .add_opt("count", request.count)
+            .add_opt("continuation", request.continuation.as_ref())
+            .add_opt("start_timestamp", request.start_timestamp)
+            .add_opt("end_timestamp", request.end_timestamp);
+
+        let result = self
+            .private_post_optional::<Vec<crate::model::block_trade::BlockTrade>>(
+                GET_BROKER_TRADES,
+                params,
             )
-        };
-
-        let url = format!("{}{}{}", self.base_url(), GET_BROKER_TRADES, query_string);
-
-        let response = self.make_authenticated_request(&url).await?;
-
-        if !response.status().is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-            return Err(HttpError::RequestFailed(format!(
-                "Get broker trades failed: {}",
-                error_text
-            )));
-        }
-
-        let api_response: ApiResponse<Vec<crate::model::block_trade::BlockTrade>> = response
-            .json()
-            .await
-            .map_err(|e| HttpError::InvalidResponse(e.to_string()))?;
-
-        if let Some(error) = api_response.error {
-            return Err(HttpError::RequestFailed(format!(
-                "API error: {} - {}",
-                error.code, error.message
-            )));
-        }
-
-        Ok(api_response.result.unwrap_or_default())
+            .await?;
+        Ok(result.unwrap_or_default())
     }
 
     /// Invalidate a block trade signature
@@ -4620,9 +3765,9 @@ impl DeribitHttpClient {
         &self,
         signature: &str,
     ) -> Result<bool, HttpError> {
-        let query = format!("?signature={}", urlencoding::encode(signature));
+        let params = RequestParams::new().add("signature", signature);
         let result: String = self
-            .private_get(INVALIDATE_BLOCK_TRADE_SIGNATURE, &query)
+            .private_post(INVALIDATE_BLOCK_TRADE_SIGNATURE, params)
             .await?;
         Ok(result == "ok")
     }
@@ -4646,23 +3791,23 @@ impl DeribitHttpClient {
     /// Returns `HttpError` if the request fails.
     pub async fn reject_block_trade(
         &self,
-        timestamp: u64,
+        timestamp: TimestampMs,
         nonce: &str,
         role: crate::model::block_trade::BlockTradeRole,
     ) -> Result<bool, HttpError> {
-        let query = format!(
-            "?timestamp={}&nonce={}&role={}",
-            timestamp,
-            urlencoding::encode(nonce),
-            urlencoding::encode(&role.to_string())
-        );
-        let result: String = self.private_get(REJECT_BLOCK_TRADE, &query).await?;
+        let params = RequestParams::new()
+            .add("timestamp", timestamp)
+            .add("nonce", nonce)
+            .add("role", role.to_string());
+        let result: String = self.private_post(REJECT_BLOCK_TRADE, params).await?;
         Ok(result == "ok")
     }
 
     /// Simulate a block trade
     ///
-    /// Checks if a block trade can be executed.
+    /// Checks if a block trade can be executed, including pricing and margin,
+    /// before signing it with `verify_block_trade` and calling
+    /// `execute_block_trade`.
     ///
     /// # Arguments
     ///
@@ -4674,60 +3819,31 @@ impl DeribitHttpClient {
     ///
     /// # Errors
     ///
-    /// Returns `HttpError` if the request fails.
+    /// Returns `HttpError` if the request fails or the response has no result.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use deribit_http::DeribitHttpClient;
+    /// use deribit_http::model::block_trade::{
+    ///     BlockTradeItem, BlockTradeRole, SimulateBlockTradeRequest, TradeDirection,
+    /// };
+    ///
+    /// let client = DeribitHttpClient::new();
+    /// let request = SimulateBlockTradeRequest::new(
+    ///     Some(BlockTradeRole::Maker),
+    ///     vec![BlockTradeItem::new("BTC-PERPETUAL", 50000.0, Some(40.0), TradeDirection::Buy)],
+    /// );
+    /// // let executable = client.simulate_block_trade(&request).await?;
+    /// ```
     pub async fn simulate_block_trade(
         &self,
         request: &crate::model::block_trade::SimulateBlockTradeRequest,
     ) -> Result<bool, HttpError> {
-        let trades_json = serde_json::to_string(&request.trades).map_err(|e| {
-            HttpError::InvalidResponse(format!("Failed to serialize trades: {}", e))
-        })?;
-
-        let mut query_params = vec![("trades".to_string(), trades_json)];
-
-        if let Some(ref role) = request.role {
-            query_params.push(("role".to_string(), role.to_string()));
-        }
-
-        let query_string = query_params
-            .iter()
-            .map(|(k, v)| format!("{}={}", k, urlencoding::encode(v)))
-            .collect::<Vec<_>>()
-            .join("&");
-
-        let url = format!(
-            "{}{}?{}",
-            self.base_url(),
-            SIMULATE_BLOCK_TRADE,
-            query_string
-        );
-
-        let response = self.make_authenticated_request(&url).await?;
-
-        if !response.status().is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-            return Err(HttpError::RequestFailed(format!(
-                "Simulate block trade failed: {}",
-                error_text
-            )));
-        }
-
-        let api_response: ApiResponse<bool> = response
-            .json()
-            .await
-            .map_err(|e| HttpError::InvalidResponse(e.to_string()))?;
-
-        if let Some(error) = api_response.error {
-            return Err(HttpError::RequestFailed(format!(
-                "API error: {} - {}",
-                error.code, error.message
-            )));
-        }
-
-        Ok(api_response.result.unwrap_or(false))
+        let params = RequestParams::new()
+            .add("trades", &request.trades)
+            .add_opt("role", request.role.as_ref().map(|role| role.to_string()));
+        self.private_post(SIMULATE_BLOCK_TRADE, params).await
     }
 
     /// Verify and create a block trade signature
@@ -4750,53 +3866,13 @@ impl DeribitHttpClient {
         &self,
         request: &crate::model::block_trade::VerifyBlockTradeRequest,
     ) -> Result<crate::model::block_trade::BlockTradeSignature, HttpError> {
-        let trades_json = serde_json::to_string(&request.trades).map_err(|e| {
-            HttpError::InvalidResponse(format!("Failed to serialize trades: {}", e))
-        })?;
-
-        let query_params = [
-            ("timestamp".to_string(), request.timestamp.to_string()),
-            ("nonce".to_string(), request.nonce.clone()),
-            ("role".to_string(), request.role.to_string()),
-            ("trades".to_string(), trades_json),
-        ];
-
-        let query_string = query_params
-            .iter()
-            .map(|(k, v)| format!("{}={}", k, urlencoding::encode(v)))
-            .collect::<Vec<_>>()
-            .join("&");
-
-        let url = format!("{}{}?{}", self.base_url(), VERIFY_BLOCK_TRADE, query_string);
+        let params = RequestParams::new()
+            .add("timestamp", request.timestamp)
+            .add("nonce", &request.nonce)
+            .add("role", request.role.to_string())
+            .add("trades", &request.trades);
 
-        let response = self.make_authenticated_request(&url).await?;
-
-        if !response.status().is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-            return Err(HttpError::RequestFailed(format!(
-                "Verify block trade failed: {}",
-                error_text
-            )));
-        }
-
-        let api_response: ApiResponse<crate::model::block_trade::BlockTradeSignature> = response
-            .json()
-            .await
-            .map_err(|e| HttpError::InvalidResponse(e.to_string()))?;
-
-        if let Some(error) = api_response.error {
-            return Err(HttpError::RequestFailed(format!(
-                "API error: {} - {}",
-                error.code, error.message
-            )));
-        }
-
-        api_response
-            .result
-            .ok_or_else(|| HttpError::InvalidResponse("No signature in response".to_string()))
+        self.private_post(VERIFY_BLOCK_TRADE, params).await
     }
 
     // ========================================================================
@@ -4806,75 +3882,45 @@ impl DeribitHttpClient {
     /// Create a combo book
     ///
     /// Verifies and creates a combo book or returns an existing combo
-    /// matching the given trades.
+    /// matching the given legs.
     ///
     /// # Arguments
     ///
-    /// * `trades` - List of trades used to create a combo
+    /// * `request` - Legs used to create the combo
     ///
     /// # Errors
     ///
-    /// Returns `HttpError` if the request fails or the response is invalid.
+    /// Returns `HttpErrorKind::ConfigError` if the request has no legs, or
+    /// `HttpError` if the request fails or the response is invalid.
     ///
     /// # Examples
     ///
     /// ```rust,no_run
     /// use deribit_http::DeribitHttpClient;
-    /// use deribit_http::model::ComboTrade;
+    /// use deribit_http::model::CreateComboRequest;
     ///
     /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
     /// let client = DeribitHttpClient::new();
-    /// let trades = vec![
-    ///     ComboTrade::buy("BTC-29APR22-37500-C", Some(1.0)),
-    ///     ComboTrade::sell("BTC-29APR22-37500-P", Some(1.0)),
-    /// ];
-    /// // let combo = client.create_combo(&trades).await?;
+    /// let request = CreateComboRequest::default()
+    ///     .buy("BTC-29APR22-37500-C", Some(1.0))
+    ///     .sell("BTC-29APR22-37500-P", Some(1.0));
+    /// // let combo = client.create_combo(&request).await?;
     /// # Ok(())
     /// # }
     /// ```
     pub async fn create_combo(
         &self,
-        trades: &[crate::model::ComboTrade],
+        request: &crate::model::CreateComboRequest,
     ) -> Result<crate::model::Combo, HttpError> {
-        let trades_json = serde_json::to_string(trades).map_err(|e| {
-            HttpError::InvalidResponse(format!("Failed to serialize trades: {}", e))
-        })?;
-
-        let url = format!(
-            "{}{}?trades={}",
-            self.base_url(),
-            CREATE_COMBO,
-            urlencoding::encode(&trades_json)
-        );
-
-        let response = self.make_authenticated_request(&url).await?;
-
-        if !response.status().is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-            return Err(HttpError::RequestFailed(format!(
-                "Create combo failed: {}",
-                error_text
-            )));
-        }
-
-        let api_response: ApiResponse<crate::model::Combo> = response
-            .json()
-            .await
-            .map_err(|e| HttpError::InvalidResponse(e.to_string()))?;
-
-        if let Some(error) = api_response.error {
-            return Err(HttpError::RequestFailed(format!(
-                "API error: {} - {}",
-                error.code, error.message
-            )));
+        if request.trades.is_empty() {
+            return Err(HttpErrorKind::ConfigError(
+                "create_combo requires at least one leg".to_string(),
+            )
+            .into());
         }
 
-        api_response
-            .result
-            .ok_or_else(|| HttpError::InvalidResponse("No combo data in response".to_string()))
+        let params = RequestParams::new().add("trades", &request.trades);
+        self.private_post(CREATE_COMBO, params).await
     }
 
     /// Get leg prices for a combo structure
@@ -4913,45 +3959,9 @@ impl DeribitHttpClient {
         legs: &[crate::model::LegInput],
         price: f64,
     ) -> Result<crate::model::LegPricesResponse, HttpError> {
-        let legs_json = serde_json::to_string(legs)
-            .map_err(|e| HttpError::InvalidResponse(format!("Failed to serialize legs: {}", e)))?;
-
-        let url = format!(
-            "{}{}?legs={}&price={}",
-            self.base_url(),
-            GET_LEG_PRICES,
-            urlencoding::encode(&legs_json),
-            price
-        );
-
-        let response = self.make_authenticated_request(&url).await?;
-
-        if !response.status().is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-            return Err(HttpError::RequestFailed(format!(
-                "Get leg prices failed: {}",
-                error_text
-            )));
-        }
-
-        let api_response: ApiResponse<crate::model::LegPricesResponse> = response
-            .json()
-            .await
-            .map_err(|e| HttpError::InvalidResponse(e.to_string()))?;
+        let params = RequestParams::new().add("legs", legs).add("price", price);
 
-        if let Some(error) = api_response.error {
-            return Err(HttpError::RequestFailed(format!(
-                "API error: {} - {}",
-                error.code, error.message
-            )));
-        }
-
-        api_response
-            .result
-            .ok_or_else(|| HttpError::InvalidResponse("No leg prices in response".to_string()))
+        self.private_post(GET_LEG_PRICES, params).await
     }
 
     // ========================================================================
@@ -4981,78 +3991,16 @@ impl DeribitHttpClient {
         non_anonymous: Option<bool>,
         trade_allocations: Option<&[crate::model::response::BlockRfqTradeAllocation]>,
     ) -> Result<crate::model::response::BlockRfq, HttpError> {
-        let legs_json = serde_json::to_string(legs)
-            .map_err(|e| HttpError::InvalidResponse(format!("Failed to serialize legs: {}", e)))?;
-
-        let mut query_params = vec![format!("legs={}", urlencoding::encode(&legs_json))];
-
-        if let Some(h) = hedge {
-            let hedge_json = serde_json::to_string(h).map_err(|e| {
-                HttpError::InvalidResponse(format!("Failed to serialize hedge: {}", e))
-            })?;
-            query_params.push(format!("hedge={}", urlencoding::encode(&hedge_json)));
-        }
-
-        if let Some(l) = label {
-            query_params.push(format!("label={}", urlencoding::encode(l)));
-        }
-
-        if let Some(m) = makers {
-            let makers_json = serde_json::to_string(m).map_err(|e| {
-                HttpError::InvalidResponse(format!("Failed to serialize makers: {}", e))
-            })?;
-            query_params.push(format!("makers={}", urlencoding::encode(&makers_json)));
-        }
-
-        if let Some(na) = non_anonymous {
-            query_params.push(format!("non_anonymous={}", na));
-        }
-
-        if let Some(ta) = trade_allocations {
-            let ta_json = serde_json::to_string(ta).map_err(|e| {
-                HttpError::InvalidResponse(format!("Failed to serialize trade_allocations: {}", e))
-            })?;
-            query_params.push(format!(
-                "trade_allocations={}",
-                urlencoding::encode(&ta_json)
-            ));
-        }
-
-        let url = format!(
-            "{}{}?{}",
-            self.base_url(),
-            crate::constants::endpoints::CREATE_BLOCK_RFQ,
-            query_params.join("&")
-        );
-
-        let response = self.make_authenticated_request(&url).await?;
-
-        if !response.status().is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-            return Err(HttpError::RequestFailed(format!(
-                "Create Block RFQ failed: {}",
-                error_text
-            )));
-        }
-
-        let api_response: ApiResponse<crate::model::response::BlockRfq> = response
-            .json()
+        let params = RequestParams::new()
+            .add("legs", legs)
+            .add_opt("hedge", hedge)
+            .add_opt("label", label)
+            .add_opt("makers", makers)
+            .add_opt("non_anonymous", non_anonymous)
+            .add_opt("trade_allocations", trade_allocations);
+
+        self.private_post(crate::constants::endpoints::CREATE_BLOCK_RFQ, params)
             .await
-            .map_err(|e| HttpError::InvalidResponse(e.to_string()))?;
-
-        if let Some(error) = api_response.error {
-            return Err(HttpError::RequestFailed(format!(
-                "API error: {} - {}",
-                error.code, error.message
-            )));
-        }
-
-        api_response
-            .result
-            .ok_or_else(|| HttpError::InvalidResponse("No Block RFQ in response".to_string()))
     }
 
     /// Cancels a Block RFQ (taker method).
@@ -5068,8 +4016,8 @@ impl DeribitHttpClient {
         &self,
         block_rfq_id: i64,
     ) -> Result<crate::model::response::BlockRfq, HttpError> {
-        let query = format!("?block_rfq_id={}", block_rfq_id);
-        self.private_get(crate::constants::endpoints::CANCEL_BLOCK_RFQ, &query)
+        let params = RequestParams::new().add("block_rfq_id", block_rfq_id);
+        self.private_post(crate::constants::endpoints::CANCEL_BLOCK_RFQ, params)
             .await
     }
 
@@ -5099,75 +4047,28 @@ impl DeribitHttpClient {
         time_in_force: Option<crate::model::response::BlockRfqTimeInForce>,
         hedge: Option<&crate::model::response::BlockRfqHedge>,
     ) -> Result<crate::model::response::AcceptBlockRfqResponse, HttpError> {
-        let legs_json = serde_json::to_string(legs)
-            .map_err(|e| HttpError::InvalidResponse(format!("Failed to serialize legs: {}", e)))?;
-
         let direction_str = match direction {
             crate::model::types::Direction::Buy => "buy",
             crate::model::types::Direction::Sell => "sell",
             crate::model::types::Direction::Unknown => "buy",
         };
 
-        let mut query_params = vec![
-            format!("block_rfq_id={}", block_rfq_id),
-            format!("legs={}", urlencoding::encode(&legs_json)),
-            format!("price={}", price),
-            format!("direction={}", direction_str),
-            format!("amount={}", amount),
-        ];
-
-        if let Some(tif) = time_in_force {
-            let tif_str = match tif {
-                crate::model::response::BlockRfqTimeInForce::FillOrKill => "fill_or_kill",
-                crate::model::response::BlockRfqTimeInForce::GoodTilCancelled => {
-                    "good_til_cancelled"
-                }
-            };
-            query_params.push(format!("time_in_force={}", tif_str));
-        }
-
-        if let Some(h) = hedge {
-            let hedge_json = serde_json::to_string(h).map_err(|e| {
-                HttpError::InvalidResponse(format!("Failed to serialize hedge: {}", e))
-            })?;
-            query_params.push(format!("hedge={}", urlencoding::encode(&hedge_json)));
-        }
-
-        let url = format!(
-            "{}{}?{}",
-            self.base_url(),
-            crate::constants::endpoints::ACCEPT_BLOCK_RFQ,
-            query_params.join("&")
-        );
-
-        let response = self.make_authenticated_request(&url).await?;
-
-        if !response.status().is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-            return Err(HttpError::RequestFailed(format!(
-                "Accept Block RFQ failed: {}",
-                error_text
-            )));
-        }
-
-        let api_response: ApiResponse<crate::model::response::AcceptBlockRfqResponse> = response
-            .json()
+        let tif_str = time_in_force.map(|tif| match tif {
+            crate::model::response::BlockRfqTimeInForce::FillOrKill => "fill_or_kill",
+            crate::model::response::BlockRfqTimeInForce::GoodTilCancelled => "good_til_cancelled",
+        });
+
+        let params = RequestParams::new()
+            .add("block_rfq_id", block_rfq_id)
+            .add("legs", legs)
+            .add("price", price)
+            .add("direction", direction_str)
+            .add("amount", amount)
+            .add_opt("time_in_force", tif_str)
+            .add_opt("hedge", hedge);
+
+        self.private_post(crate::constants::endpoints::ACCEPT_BLOCK_RFQ, params)
             .await
-            .map_err(|e| HttpError::InvalidResponse(e.to_string()))?;
-
-        if let Some(error) = api_response.error {
-            return Err(HttpError::RequestFailed(format!(
-                "API error: {} - {}",
-                error.code, error.message
-            )));
-        }
-
-        api_response
-            .result
-            .ok_or_else(|| HttpError::InvalidResponse("No accept Block RFQ response".to_string()))
     }
 
     /// Retrieves Block RFQs.
@@ -5191,91 +4092,34 @@ impl DeribitHttpClient {
         role: Option<crate::model::response::BlockRfqRole>,
         continuation: Option<&str>,
         block_rfq_id: Option<i64>,
-        currency: Option<&str>,
+        currency: Option<impl AsRef<str>>,
     ) -> Result<crate::model::response::BlockRfqsResponse, HttpError> {
-        let mut query_params: Vec<String> = Vec::new();
-
-        if let Some(c) = count {
-            query_params.push(format!("count={}", c));
-        }
-
-        if let Some(s) = state {
-            let state_str = match s {
-                crate::model::response::BlockRfqState::Open => "open",
-                crate::model::response::BlockRfqState::Filled => "filled",
-                crate::model::response::BlockRfqState::Traded => "traded",
-                crate::model::response::BlockRfqState::Cancelled => "cancelled",
-                crate::model::response::BlockRfqState::Expired => "expired",
-                crate::model::response::BlockRfqState::Closed => "closed",
-                crate::model::response::BlockRfqState::Created => "created",
-            };
-            query_params.push(format!("state={}", state_str));
-        }
-
-        if let Some(r) = role {
-            let role_str = match r {
-                crate::model::response::BlockRfqRole::Taker => "taker",
-                crate::model::response::BlockRfqRole::Maker => "maker",
-                crate::model::response::BlockRfqRole::Any => "any",
-            };
-            query_params.push(format!("role={}", role_str));
-        }
-
-        if let Some(cont) = continuation {
-            query_params.push(format!("continuation={}", urlencoding::encode(cont)));
-        }
-
-        if let Some(id) = block_rfq_id {
-            query_params.push(format!("block_rfq_id={}", id));
-        }
-
-        if let Some(curr) = currency {
-            query_params.push(format!("currency={}", curr));
-        }
-
-        let url = if query_params.is_empty() {
-            format!(
-                "{}{}",
-                self.base_url(),
-                crate::constants::endpoints::GET_BLOCK_RFQS
-            )
-        } else {
-            format!(
-                "{}{}?{}",
-                self.base_url(),
-                crate::constants::endpoints::GET_BLOCK_RFQS,
-                query_params.join("&")
-            )
-        };
-
-        let response = self.make_authenticated_request(&url).await?;
-
-        if !response.status().is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-            return Err(HttpError::RequestFailed(format!(
-                "Get Block RFQs failed: {}",
-                error_text
-            )));
-        }
-
-        let api_response: ApiResponse<crate::model::response::BlockRfqsResponse> = response
-            .json()
+        let state_str = state.map(|s| match s {
+            crate::model::response::BlockRfqState::Open => "open",
+            crate::model::response::BlockRfqState::Filled => "filled",
+            crate::model::response::BlockRfqState::Traded => "traded",
+            crate::model::response::BlockRfqState::Cancelled => "cancelled",
+            crate::model::response::BlockRfqState::Expired => "expired",
+            crate::model::response::BlockRfqState::Closed => "closed",
+            crate::model::response::BlockRfqState::Created => "created",
+        });
+
+        let role_str = role.map(|r| match r {
+            crate::model::response::BlockRfqRole::Taker => "taker",
+            crate::model::response::BlockRfqRole::Maker => "maker",
+            crate::model::response::BlockRfqRole::Any => "any",
+        });
+
+        let params = RequestParams::new()
+            .add_opt("count", count)
+            .add_opt("state", state_str)
+            .add_opt("role", role_str)
+            .add_opt("continuation", continuation)
+            .add_opt("block_rfq_id", block_rfq_id)
+            .add_opt("currency", currency.as_ref().map(AsRef::as_ref));
+
+        self.private_post(crate::constants::endpoints::GET_BLOCK_RFQS, params)
             .await
-            .map_err(|e| HttpError::InvalidResponse(e.to_string()))?;
-
-        if let Some(error) = api_response.error {
-            return Err(HttpError::RequestFailed(format!(
-                "API error: {} - {}",
-                error.code, error.message
-            )));
-        }
-
-        api_response
-            .result
-            .ok_or_else(|| HttpError::InvalidResponse("No Block RFQs in response".to_string()))
     }
 
     /// Retrieves open quotes for Block RFQs (maker method).
@@ -5295,63 +4139,13 @@ impl DeribitHttpClient {
         label: Option<&str>,
         block_rfq_quote_id: Option<i64>,
     ) -> Result<Vec<crate::model::response::BlockRfqQuote>, HttpError> {
-        let mut query_params: Vec<String> = Vec::new();
-
-        if let Some(id) = block_rfq_id {
-            query_params.push(format!("block_rfq_id={}", id));
-        }
-
-        if let Some(l) = label {
-            query_params.push(format!("label={}", urlencoding::encode(l)));
-        }
-
-        if let Some(qid) = block_rfq_quote_id {
-            query_params.push(format!("block_rfq_quote_id={}", qid));
-        }
-
-        let url = if query_params.is_empty() {
-            format!(
-                "{}{}",
-                self.base_url(),
-                crate::constants::endpoints::GET_BLOCK_RFQ_QUOTES
-            )
-        } else {
-            format!(
-                "{}{}?{}",
-                self.base_url(),
-                crate::constants::endpoints::GET_BLOCK_RFQ_QUOTES,
-                query_params.join("&")
-            )
-        };
-
-        let response = self.make_authenticated_request(&url).await?;
+        let params = RequestParams::new()
+            .add_opt("block_rfq_id", block_rfq_id)
+            .add_opt("label", label)
+            .add_opt("block_rfq_quote_id", block_rfq_quote_id);
 
-        if !response.status().is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-            return Err(HttpError::RequestFailed(format!(
-                "Get Block RFQ quotes failed: {}",
-                error_text
-            )));
-        }
-
-        let api_response: ApiResponse<Vec<crate::model::response::BlockRfqQuote>> = response
-            .json()
+        self.private_post(crate::constants::endpoints::GET_BLOCK_RFQ_QUOTES, params)
             .await
-            .map_err(|e| HttpError::InvalidResponse(e.to_string()))?;
-
-        if let Some(error) = api_response.error {
-            return Err(HttpError::RequestFailed(format!(
-                "API error: {} - {}",
-                error.code, error.message
-            )));
-        }
-
-        api_response.result.ok_or_else(|| {
-            HttpError::InvalidResponse("No Block RFQ quotes in response".to_string())
-        })
     }
 
     /// Adds a quote to a Block RFQ (maker method).
@@ -5382,80 +4176,29 @@ impl DeribitHttpClient {
         execution_instruction: Option<crate::model::response::ExecutionInstruction>,
         expires_at: Option<i64>,
     ) -> Result<crate::model::response::BlockRfqQuote, HttpError> {
-        let legs_json = serde_json::to_string(legs)
-            .map_err(|e| HttpError::InvalidResponse(format!("Failed to serialize legs: {}", e)))?;
-
         let direction_str = match direction {
             crate::model::types::Direction::Buy => "buy",
             crate::model::types::Direction::Sell => "sell",
             crate::model::types::Direction::Unknown => "buy",
         };
 
-        let mut query_params = vec![
-            format!("block_rfq_id={}", block_rfq_id),
-            format!("amount={}", amount),
-            format!("direction={}", direction_str),
-            format!("legs={}", urlencoding::encode(&legs_json)),
-        ];
-
-        if let Some(l) = label {
-            query_params.push(format!("label={}", urlencoding::encode(l)));
-        }
-
-        if let Some(h) = hedge {
-            let hedge_json = serde_json::to_string(h).map_err(|e| {
-                HttpError::InvalidResponse(format!("Failed to serialize hedge: {}", e))
-            })?;
-            query_params.push(format!("hedge={}", urlencoding::encode(&hedge_json)));
-        }
-
-        if let Some(ei) = execution_instruction {
-            let ei_str = match ei {
-                crate::model::response::ExecutionInstruction::AllOrNone => "all_or_none",
-                crate::model::response::ExecutionInstruction::AnyPartOf => "any_part_of",
-            };
-            query_params.push(format!("execution_instruction={}", ei_str));
-        }
-
-        if let Some(exp) = expires_at {
-            query_params.push(format!("expires_at={}", exp));
-        }
-
-        let url = format!(
-            "{}{}?{}",
-            self.base_url(),
-            crate::constants::endpoints::ADD_BLOCK_RFQ_QUOTE,
-            query_params.join("&")
-        );
-
-        let response = self.make_authenticated_request(&url).await?;
-
-        if !response.status().is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-            return Err(HttpError::RequestFailed(format!(
-                "Add Block RFQ quote failed: {}",
-                error_text
-            )));
-        }
-
-        let api_response: ApiResponse<crate::model::response::BlockRfqQuote> = response
-            .json()
+        let ei_str = execution_instruction.map(|ei| match ei {
+            crate::model::response::ExecutionInstruction::AllOrNone => "all_or_none",
+            crate::model::response::ExecutionInstruction::AnyPartOf => "any_part_of",
+        });
+
+        let params = RequestParams::new()
+            .add("block_rfq_id", block_rfq_id)
+            .add("amount", amount)
+            .add("direction", direction_str)
+            .add("legs", legs)
+            .add_opt("label", label)
+            .add_opt("hedge", hedge)
+            .add_opt("execution_instruction", ei_str)
+            .add_opt("expires_at", expires_at);
+
+        self.private_post(crate::constants::endpoints::ADD_BLOCK_RFQ_QUOTE, params)
             .await
-            .map_err(|e| HttpError::InvalidResponse(e.to_string()))?;
-
-        if let Some(error) = api_response.error {
-            return Err(HttpError::RequestFailed(format!(
-                "API error: {} - {}",
-                error.code, error.message
-            )));
-        }
-
-        api_response
-            .result
-            .ok_or_else(|| HttpError::InvalidResponse("No Block RFQ quote in response".to_string()))
     }
 
     /// Edits a Block RFQ quote (maker method).
@@ -5486,85 +4229,23 @@ impl DeribitHttpClient {
         execution_instruction: Option<crate::model::response::ExecutionInstruction>,
         expires_at: Option<i64>,
     ) -> Result<crate::model::response::BlockRfqQuote, HttpError> {
-        let mut query_params: Vec<String> = Vec::new();
-
-        if let Some(qid) = block_rfq_quote_id {
-            query_params.push(format!("block_rfq_quote_id={}", qid));
-        }
-
-        if let Some(id) = block_rfq_id {
-            query_params.push(format!("block_rfq_id={}", id));
-        }
-
-        if let Some(l) = label {
-            query_params.push(format!("label={}", urlencoding::encode(l)));
-        }
-
-        if let Some(a) = amount {
-            query_params.push(format!("amount={}", a));
-        }
-
-        if let Some(l) = legs {
-            let legs_json = serde_json::to_string(l).map_err(|e| {
-                HttpError::InvalidResponse(format!("Failed to serialize legs: {}", e))
-            })?;
-            query_params.push(format!("legs={}", urlencoding::encode(&legs_json)));
-        }
-
-        if let Some(h) = hedge {
-            let hedge_json = serde_json::to_string(h).map_err(|e| {
-                HttpError::InvalidResponse(format!("Failed to serialize hedge: {}", e))
-            })?;
-            query_params.push(format!("hedge={}", urlencoding::encode(&hedge_json)));
-        }
-
-        if let Some(ei) = execution_instruction {
-            let ei_str = match ei {
-                crate::model::response::ExecutionInstruction::AllOrNone => "all_or_none",
-                crate::model::response::ExecutionInstruction::AnyPartOf => "any_part_of",
-            };
-            query_params.push(format!("execution_instruction={}", ei_str));
-        }
-
-        if let Some(exp) = expires_at {
-            query_params.push(format!("expires_at={}", exp));
-        }
-
-        let url = format!(
-            "{}{}?{}",
-            self.base_url(),
-            crate::constants::endpoints::EDIT_BLOCK_RFQ_QUOTE,
-            query_params.join("&")
-        );
-
-        let response = self.make_authenticated_request(&url).await?;
-
-        if !response.status().is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-            return Err(HttpError::RequestFailed(format!(
-                "Edit Block RFQ quote failed: {}",
-                error_text
-            )));
-        }
-
-        let api_response: ApiResponse<crate::model::response::BlockRfqQuote> = response
-            .json()
+        let ei_str = execution_instruction.map(|ei| match ei {
+            crate::model::response::ExecutionInstruction::AllOrNone => "all_or_none",
+            crate::model::response::ExecutionInstruction::AnyPartOf => "any_part_of",
+        });
+
+        let params = RequestParams::new()
+            .add_opt("block_rfq_quote_id", block_rfq_quote_id)
+            .add_opt("block_rfq_id", block_rfq_id)
+            .add_opt("label", label)
+            .add_opt("amount", amount)
+            .add_opt("legs", legs)
+            .add_opt("hedge", hedge)
+            .add_opt("execution_instruction", ei_str)
+            .add_opt("expires_at", expires_at);
+
+        self.private_post(crate::constants::endpoints::EDIT_BLOCK_RFQ_QUOTE, params)
             .await
-            .map_err(|e| HttpError::InvalidResponse(e.to_string()))?;
-
-        if let Some(error) = api_response.error {
-            return Err(HttpError::RequestFailed(format!(
-                "API error: {} - {}",
-                error.code, error.message
-            )));
-        }
-
-        api_response
-            .result
-            .ok_or_else(|| HttpError::InvalidResponse("No Block RFQ quote in response".to_string()))
     }
 
     /// Cancels a single Block RFQ quote (maker method).
@@ -5584,55 +4265,13 @@ impl DeribitHttpClient {
         block_rfq_id: Option<i64>,
         label: Option<&str>,
     ) -> Result<crate::model::response::BlockRfqQuote, HttpError> {
-        let mut query_params: Vec<String> = Vec::new();
-
-        if let Some(qid) = block_rfq_quote_id {
-            query_params.push(format!("block_rfq_quote_id={}", qid));
-        }
-
-        if let Some(id) = block_rfq_id {
-            query_params.push(format!("block_rfq_id={}", id));
-        }
+        let params = RequestParams::new()
+            .add_opt("block_rfq_quote_id", block_rfq_quote_id)
+            .add_opt("block_rfq_id", block_rfq_id)
+            .add_opt("label", label);
 
-        if let Some(l) = label {
-            query_params.push(format!("label={}", urlencoding::encode(l)));
-        }
-
-        let url = format!(
-            "{}{}?{}",
-            self.base_url(),
-            crate::constants::endpoints::CANCEL_BLOCK_RFQ_QUOTE,
-            query_params.join("&")
-        );
-
-        let response = self.make_authenticated_request(&url).await?;
-
-        if !response.status().is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-            return Err(HttpError::RequestFailed(format!(
-                "Cancel Block RFQ quote failed: {}",
-                error_text
-            )));
-        }
-
-        let api_response: ApiResponse<crate::model::response::BlockRfqQuote> = response
-            .json()
+        self.private_post(crate::constants::endpoints::CANCEL_BLOCK_RFQ_QUOTE, params)
             .await
-            .map_err(|e| HttpError::InvalidResponse(e.to_string()))?;
-
-        if let Some(error) = api_response.error {
-            return Err(HttpError::RequestFailed(format!(
-                "API error: {} - {}",
-                error.code, error.message
-            )));
-        }
-
-        api_response
-            .result
-            .ok_or_else(|| HttpError::InvalidResponse("No Block RFQ quote in response".to_string()))
     }
 
     /// Cancels all Block RFQ quotes (maker method).
@@ -5643,7 +4282,111 @@ impl DeribitHttpClient {
     pub async fn cancel_all_block_rfq_quotes(
         &self,
     ) -> Result<Vec<crate::model::response::BlockRfqQuote>, HttpError> {
-        self.private_get(crate::constants::endpoints::CANCEL_ALL_BLOCK_RFQ_QUOTES, "")
-            .await
+        self.private_post(
+            crate::constants::endpoints::CANCEL_ALL_BLOCK_RFQ_QUOTES,
+            RequestParams::new(),
+        )
+        .await
     }
+
+    // ========================================================================
+    // RFQ endpoints
+    // ========================================================================
+
+    /// Sends a request for quote on an instrument.
+    ///
+    /// Market makers see the instrument, and the amount and side if given,
+    /// and may respond by quoting it.
+    ///
+    /// # Arguments
+    ///
+    /// * `instrument_name` - Instrument to request quotes for
+    /// * `amount` - Amount to trade, optional
+    /// * `side` - Side to trade, optional
+    ///
+    /// # Errors
+    ///
+    /// Returns `HttpError` if the request fails.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use deribit_http::DeribitHttpClient;
+    /// use deribit_http::model::RfqSide;
+    ///
+    /// let client = DeribitHttpClient::new();
+    /// // let sent = client.send_rfq("BTC-27DEC24-60000-C", Some(25.0), Some(RfqSide::Buy)).await?;
+    /// ```
+    pub async fn send_rfq(
+        &self,
+        instrument_name: &str,
+        amount: Option<f64>,
+        side: Option<crate::model::RfqSide>,
+    ) -> Result<bool, HttpError> {
+        let params = RequestParams::new()
+            .add("instrument_name", instrument_name)
+            .add_opt("amount", amount)
+            .add_opt("side", side);
+        let result: String = self
+            .private_post(crate::constants::endpoints::SEND_RFQ, params)
+            .await?;
+        Ok(result == "ok")
+    }
+
+    /// Cancels the request for quote on an instrument.
+    ///
+    /// # Arguments
+    ///
+    /// * `instrument_name` - Instrument the RFQ was sent for
+    ///
+    /// # Errors
+    ///
+    /// Returns `HttpError` if the request fails.
+    pub async fn cancel_rfq(&self, instrument_name: &str) -> Result<bool, HttpError> {
+        let params = RequestParams::new().add("instrument_name", instrument_name);
+        let result: String = self
+            .private_post(crate::constants::endpoints::CANCEL_RFQ, params)
+            .await?;
+        Ok(result == "ok")
+    }
+}
+
+/// JSON-RPC parameters for the `get_user_trades_by_currency*` methods
+fn trades_params(request: TradesRequest) -> RequestParams {
+    RequestParams::new()
+        .add("currency", request.currency.to_string())
+        .add_opt("kind", request.kind)
+        .add_opt("start_id", request.start_id)
+        .add_opt("end_id", request.end_id)
+        .add_opt("count", request.count)
+        .add_opt("start_timestamp", request.start_timestamp)
+        .add_opt("end_timestamp", request.end_timestamp)
+        .add_opt("sorting", request.sorting)
+        .add_opt("historical", request.historical)
+        .add_opt("subaccount_id", request.subaccount_id)
+}
+
+/// JSON-RPC parameters for `private/buy` and `private/sell`
+fn order_params(request: OrderRequest) -> RequestParams {
+    RequestParams::new()
+        .add("instrument_name", request.instrument_name)
+        .add_opt("amount", request.amount)
+        .add_opt("contracts", request.contracts)
+        .add_opt("type", request.type_)
+        .add_opt("label", request.label)
+        .add_opt("price", request.price)
+        .add_opt("time_in_force", request.time_in_force)
+        .add_opt("display_amount", request.display_amount)
+        .add_opt("post_only", request.post_only)
+        .add_opt("reject_post_only", request.reject_post_only)
+        .add_opt("reduce_only", request.reduce_only)
+        .add_opt("trigger_price", request.trigger_price)
+        .add_opt("trigger_offset", request.trigger_offset)
+        .add_opt("trigger", request.trigger)
+        .add_opt("advanced", request.advanced)
+        .add_opt("mmp", request.mmp)
+        .add_opt("valid_until", request.valid_until)
+        .add_opt("linked_order_type", request.linked_order_type)
+        .add_opt("trigger_fill_condition", request.trigger_fill_condition)
+        .add_opt("otoco_config", request.otoco_config)
 }
@@ -5,25 +5,40 @@
 
 use crate::DeribitHttpClient;
 use crate::constants::endpoints::*;
-use crate::error::HttpError;
+use crate::constants::{
+    APR_HISTORY_PAGE_LIMIT, CHART_CANDLES_PER_REQUEST, DELIVERY_PRICES_PAGE_LIMIT,
+    OPTION_TICKER_CONCURRENCY, SETTLEMENTS_PAGE_COUNT,
+};
+use crate::error::{HttpError, HttpErrorKind};
 use crate::model::LastTradesResponse;
 use crate::model::book::{BookSummary, OrderBook};
 use crate::model::currency::CurrencyStruct;
-use crate::model::funding::{FundingChartData, FundingRateData};
+use crate::model::funding::{FundingChartData, FundingChartLength, FundingRateData};
 use crate::model::index::{IndexChartDataPoint, IndexData, IndexPriceData};
-use crate::model::instrument::{Instrument, OptionType};
+use crate::model::instrument::{Instrument, InstrumentKind, OptionType};
+use crate::model::option_chain::OptionChain;
 use crate::model::order::OrderSide;
-use crate::model::other::{OptionInstrument, OptionInstrumentPair};
-use crate::model::response::api_response::ApiResponse;
+use crate::model::other::{
+    DeliveryPriceData, OptionFilter, OptionInstrument, OptionInstrumentPair, SortDirection,
+};
 use crate::model::response::other::{
-    AprHistoryResponse, ContractSizeResponse, DeliveryPricesResponse, ExpirationsResponse,
-    IndexNameInfo, MarkPriceHistoryPoint, SettlementsResponse, StatusResponse, TestResponse,
-    TradeVolume, VolatilityIndexData,
+    AprDataPoint, AprHistoryResponse, ContractSizeResponse, DeliveryPricesResponse,
+    ExpirationsResponse, IndexNameInfo, MarkPriceHistoryPoint, SettlementsResponse, StatusResponse,
+    TestResponse, TradeVolume, VolatilityIndexCandle, VolatilityIndexData,
+    VolatilityIndexResolution,
 };
+use crate::model::settlement::Settlement;
 use crate::model::ticker::TickerData;
-use crate::model::trade::{Liquidity, Trade};
-use crate::model::tradingview::TradingViewChartData;
-use std::collections::HashMap;
+use crate::model::time_range::TimeRange;
+use crate::model::timestamp::TimestampMs;
+use crate::model::trade::{LastTrade, Liquidity, Trade};
+use crate::model::tradingview::{Resolution, TradingViewChartData};
+use crate::pagination::{Cursor, paginate, trade_window};
+use chrono::NaiveDate;
+use futures_util::future;
+use futures_util::stream::{self, Stream, StreamExt, TryStreamExt};
+use std::collections::{HashMap, HashSet};
+use std::ops::{Bound, RangeBounds};
 
 /// Market data endpoints
 impl DeribitHttpClient {
@@ -59,7 +74,8 @@ impl DeribitHttpClient {
     ///
     /// * `currency` - The currency symbol (BTC, ETH, USDC, USDT, EURR)
     ///
-    pub async fn get_index(&self, currency: &str) -> Result<IndexData, HttpError> {
+    pub async fn get_index(&self, currency: impl AsRef<str>) -> Result<IndexData, HttpError> {
+        let currency = currency.as_ref();
         let query = format!("?currency={}", currency);
         self.public_get(GET_INDEX, &query).await
     }
@@ -166,10 +182,11 @@ impl DeribitHttpClient {
     ///
     /// ```rust
     /// # use deribit_http::DeribitHttpClient;
+    /// # use deribit_http::model::InstrumentKind;
     /// # #[tokio::main]
     /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
     /// let client = DeribitHttpClient::new(); // testnet
-    /// let summaries = client.get_book_summary_by_currency("BTC", Some("future")).await?;
+    /// let summaries = client.get_book_summary_by_currency("BTC", Some(InstrumentKind::Future)).await?;
     /// for summary in summaries {
     ///     println!("Instrument: {} - Volume: {}", summary.instrument_name, summary.volume);
     /// }
@@ -178,9 +195,10 @@ impl DeribitHttpClient {
     /// ```
     pub async fn get_book_summary_by_currency(
         &self,
-        currency: &str,
-        kind: Option<&str>,
+        currency: impl AsRef<str>,
+        kind: Option<InstrumentKind>,
     ) -> Result<Vec<BookSummary>, HttpError> {
+        let currency = currency.as_ref();
         let mut query = format!("?currency={}", currency);
         if let Some(kind) = kind {
             query.push_str(&format!("&kind={}", kind));
@@ -214,6 +232,64 @@ impl DeribitHttpClient {
         self.public_get(GET_INSTRUMENT, &query).await
     }
 
+    /// Get an instrument through the client's instrument cache
+    ///
+    /// Returns the cached entry if there is one, otherwise calls
+    /// [`get_instrument`](Self::get_instrument) and caches the result.
+    ///
+    /// # Arguments
+    ///
+    /// * `instrument_name` - The name of the instrument
+    ///
+    pub async fn cached_instrument(&self, instrument_name: &str) -> Result<Instrument, HttpError> {
+        if let Some(instrument) = self.instrument_cache().get(instrument_name).await {
+            return Ok(instrument);
+        }
+        let instrument = self.get_instrument(instrument_name).await?;
+        self.instrument_cache().insert(instrument.clone()).await;
+        Ok(instrument)
+    }
+
+    /// Tick size of an instrument, from the instrument cache
+    ///
+    /// The base tick size; coarser steps at higher prices are in
+    /// [`Instrument::tick_size_steps`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `HttpError` if the instrument cannot be fetched, or
+    /// `HttpErrorKind::InvalidResponse` if it has no tick size.
+    pub async fn cached_tick_size(&self, instrument_name: &str) -> Result<f64, HttpError> {
+        let instrument = self.cached_instrument(instrument_name).await?;
+        required(instrument.tick_size, instrument_name, "tick size")
+    }
+
+    /// Contract size of an instrument, from the instrument cache
+    ///
+    /// # Errors
+    ///
+    /// Returns `HttpError` if the instrument cannot be fetched, or
+    /// `HttpErrorKind::InvalidResponse` if it has no contract size.
+    pub async fn cached_contract_size(&self, instrument_name: &str) -> Result<f64, HttpError> {
+        let instrument = self.cached_instrument(instrument_name).await?;
+        required(instrument.contract_size, instrument_name, "contract size")
+    }
+
+    /// Minimum trade amount of an instrument, from the instrument cache
+    ///
+    /// # Errors
+    ///
+    /// Returns `HttpError` if the instrument cannot be fetched, or
+    /// `HttpErrorKind::InvalidResponse` if it has no minimum trade amount.
+    pub async fn cached_min_trade_amount(&self, instrument_name: &str) -> Result<f64, HttpError> {
+        let instrument = self.cached_instrument(instrument_name).await?;
+        required(
+            instrument.min_trade_amount,
+            instrument_name,
+            "minimum trade amount",
+        )
+    }
+
     /// Get book summary by instrument
     ///
     /// Retrieves the summary information such as open interest, 24h volume, etc.
@@ -234,7 +310,9 @@ impl DeribitHttpClient {
             .public_get(GET_BOOK_SUMMARY_BY_INSTRUMENT, &query)
             .await?;
         book_summaries.into_iter().next().ok_or_else(|| {
-            HttpError::InvalidResponse("Empty book summary array in response".to_string())
+            HttpError::from(HttpErrorKind::InvalidResponse(
+                "Empty book summary array in response".to_string(),
+            ))
         })
     }
 
@@ -303,53 +381,32 @@ impl DeribitHttpClient {
     pub async fn get_status(&self) -> Result<StatusResponse, HttpError> {
         let url = format!("{}{}", self.base_url(), GET_STATUS);
 
-        let response = self
-            .http_client()
-            .get(&url)
-            .send()
-            .await
-            .map_err(|e| HttpError::NetworkError(e.to_string()))?;
-
-        if !response.status().is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-            return Err(HttpError::RequestFailed(format!(
-                "Get status failed: {}",
-                error_text
-            )));
-        }
+        self.traced(GET_STATUS, String::new, async {
+            let response = self.make_request(&url).await?;
 
-        // Try direct deserialization first (non-JSON-RPC response)
-        match response.json::<StatusResponse>().await {
-            Ok(status) => Ok(status),
-            Err(_) => {
-                // Fallback to JSON-RPC wrapper format
-                let response = self
-                    .http_client()
-                    .get(&url)
-                    .send()
-                    .await
-                    .map_err(|e| HttpError::NetworkError(e.to_string()))?;
-
-                let api_response: ApiResponse<StatusResponse> = response
-                    .json()
-                    .await
-                    .map_err(|e| HttpError::InvalidResponse(e.to_string()))?;
+            if !response.status().is_success() {
+                return Err(self.error_from_response(response).await);
+            }
 
-                if let Some(error) = api_response.error {
-                    return Err(HttpError::RequestFailed(format!(
-                        "API error: {} - {}",
-                        error.code, error.message
-                    )));
-                }
+            let body = response.bytes().await.map_err(|e| {
+                HttpErrorKind::InvalidResponse(format!("Failed to read response body: {}", e))
+            })?;
+            let value: serde_json::Value = serde_json::from_slice(&body)
+                .map_err(|e| HttpErrorKind::InvalidResponse(format!("Invalid JSON: {}", e)))?;
 
-                api_response.result.ok_or_else(|| {
-                    HttpError::InvalidResponse("No status data in response".to_string())
-                })
-            }
-        }
+            // JSON-RPC wrapper, or the bare status object
+            let status = match value.get("result") {
+                Some(result) => result.clone(),
+                None => value,
+            };
+            serde_json::from_value(status).map_err(|e| {
+                HttpError::from(HttpErrorKind::InvalidResponse(format!(
+                    "Invalid status: {}",
+                    e
+                )))
+            })
+        })
+        .await
     }
 
     /// Get APR history for yield tokens
@@ -365,10 +422,11 @@ impl DeribitHttpClient {
     ///
     pub async fn get_apr_history(
         &self,
-        currency: &str,
+        currency: impl AsRef<str>,
         limit: Option<u32>,
         before: Option<i32>,
     ) -> Result<AprHistoryResponse, HttpError> {
+        let currency = currency.as_ref();
         let mut query = format!("?currency={}", currency);
         if let Some(limit) = limit {
             query.push_str(&format!("&limit={}", limit));
@@ -379,6 +437,67 @@ impl DeribitHttpClient {
         self.public_get(GET_APR_HISTORY, &query).await
     }
 
+    /// Stream APR history over a range of epoch days
+    ///
+    /// Pages backwards through `get_apr_history` using the `before` cursor until
+    /// `from_day` is reached or the history is exhausted. Points are yielded in the
+    /// order the API returns them (newest first) and restricted to
+    /// `from_day..=to_day`.
+    ///
+    /// # Arguments
+    ///
+    /// * `currency` - Currency for which to retrieve APR history (usde or steth)
+    /// * `from_day` - Oldest epoch day to include
+    /// * `to_day` - Newest epoch day to include
+    ///
+    pub fn stream_apr_history<'a>(
+        &'a self,
+        currency: impl AsRef<str>,
+        from_day: i32,
+        to_day: i32,
+    ) -> impl Stream<Item = Result<AprDataPoint, HttpError>> + 'a {
+        let currency = currency.as_ref().to_owned();
+        let start = u64::try_from(to_day.saturating_add(1))
+            .ok()
+            .map(Cursor::Seq);
+        paginate(start, move |cursor: Option<Cursor>| {
+            let currency = currency.clone();
+            async move {
+                let before = cursor
+                    .as_ref()
+                    .and_then(Cursor::seq)
+                    .and_then(|day| i32::try_from(day).ok());
+                self.get_apr_history(&currency, Some(APR_HISTORY_PAGE_LIMIT), before)
+                    .await
+            }
+        })
+        .try_take_while(move |point: &AprDataPoint| future::ready(Ok(point.day >= from_day)))
+        .try_filter(move |point| future::ready(point.day <= to_day))
+    }
+
+    /// Get all APR history over a range of epoch days
+    ///
+    /// Collects [`stream_apr_history`](Self::stream_apr_history) into a vector,
+    /// newest first.
+    ///
+    /// # Arguments
+    ///
+    /// * `currency` - Currency for which to retrieve APR history (usde or steth)
+    /// * `from_day` - Oldest epoch day to include
+    /// * `to_day` - Newest epoch day to include
+    ///
+    pub async fn get_apr_history_range(
+        &self,
+        currency: impl AsRef<str>,
+        from_day: i32,
+        to_day: i32,
+    ) -> Result<Vec<AprDataPoint>, HttpError> {
+        let currency = currency.as_ref();
+        self.stream_apr_history(currency, from_day, to_day)
+            .try_collect()
+            .await
+    }
+
     /// Get ticker information for an instrument
     ///
     /// Returns ticker data including last price, bid/ask, volume, etc.
@@ -434,6 +553,8 @@ impl DeribitHttpClient {
     ///
     /// * `currency` - A string slice that represents the name of the currency (e.g., "BTC", "ETH").
     /// * `expiry` - A string slice representing the expiry date for the options (e.g., "20231027").
+    /// * `filter` - Strikes, moneyness window and option type to keep; tickers
+    ///   are only fetched for the options that pass it.
     ///
     /// # Returns
     ///
@@ -455,33 +576,97 @@ impl DeribitHttpClient {
     /// # Implementation Details
     ///
     /// 1. Fetches instruments for the specified `currency` filtered by type `option`.
-    /// 2. Filters the instruments to ensure they match the `currency`-`expiry` base name.
+    /// 2. Filters the instruments to ensure they match the `currency`-`expiry` base name
+    ///    and `filter`, fetching the `{currency}_usd` index price first if `filter`
+    ///    has a moneyness bound.
     /// 3. Constructs an `OptionInstrument` for each filtered instrument, including
-    ///    the instrument details and ticker information.
+    ///    the instrument details and ticker information. Tickers are fetched
+    ///    [`OPTION_TICKER_CONCURRENCY`]
+    ///    at a time, each still waiting on the rate limiter, and returned in
+    ///    the order of the instruments.
     ///
     pub async fn get_options(
         &self,
-        currency: &str,
+        currency: impl AsRef<str>,
         expiry: &str,
+        filter: OptionFilter,
     ) -> Result<Vec<OptionInstrument>, HttpError> {
+        let currency = currency.as_ref();
         let mut instruments = self
-            .get_instruments(currency, Some("option"), Some(false))
+            .get_instruments(currency, Some(InstrumentKind::Option), Some(false))
             .await
-            .map_err(|e| HttpError::RequestFailed(e.to_string()))?;
+            .map_err(|e| HttpErrorKind::RequestFailed(e.to_string()))?;
 
         let base_name = format!("{}-{}", currency, expiry).to_uppercase();
         // filter instruments by base name in instrument_name
         instruments.retain(|i| i.instrument_name.starts_with(&base_name));
+        let index_price = if filter.needs_index_price() && !instruments.is_empty() {
+            let index_name = format!("{}_usd", currency.to_lowercase());
+            Some(self.get_index_price(&index_name).await?.index_price)
+        } else {
+            None
+        };
+        instruments.retain(|i| filter.matches(i, index_price));
 
-        let mut options: Vec<OptionInstrument> = Vec::with_capacity(instruments.len());
-        for instrument in instruments {
-            let option = OptionInstrument {
-                instrument: instrument.clone(),
-                ticker: self.get_ticker(instrument.instrument_name.as_str()).await?,
-            };
-            options.push(option)
-        }
-        Ok(options)
+        stream::iter(instruments)
+            .map(|instrument| async move {
+                let ticker = self.get_ticker(&instrument.instrument_name).await?;
+                Ok(OptionInstrument { instrument, ticker })
+            })
+            .buffered(OPTION_TICKER_CONCURRENCY)
+            .try_collect()
+            .await
+    }
+
+    /// Option chain of one expiry, with quotes, implied volatilities and greeks per strike
+    ///
+    /// Builds on [`get_options_pair`](Self::get_options_pair): strikes are
+    /// sorted in ascending order and each call and put leg carries its bid,
+    /// ask and mark prices and IVs along with the ticker's delta, gamma, vega
+    /// and theta.
+    ///
+    /// # Arguments
+    ///
+    /// * `currency` - The currency symbol (e.g., "BTC", "ETH")
+    /// * `expiry` - The expiry date in format "DDMMMYY" (e.g., "27DEC24")
+    /// * `filter` - Strikes, moneyness window and option type to keep
+    ///
+    /// # Errors
+    ///
+    /// Returns `HttpError` if the instruments, the index price or a ticker
+    /// cannot be fetched.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use deribit_http::DeribitHttpClient;
+    /// # use deribit_http::model::OptionFilter;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = DeribitHttpClient::new();
+    /// let chain = client
+    ///     .get_option_chain("BTC", "27DEC24", OptionFilter::default().with_moneyness(0.8, 1.2))
+    ///     .await?;
+    /// for row in &chain.strikes {
+    ///     let call_iv = row.call.as_ref().and_then(|leg| leg.mark_iv);
+    ///     println!("{}: call IV {:?}", row.strike, call_iv);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_option_chain(
+        &self,
+        currency: impl AsRef<str>,
+        expiry: &str,
+        filter: OptionFilter,
+    ) -> Result<OptionChain, HttpError> {
+        let currency = currency.as_ref();
+        let pairs = self.get_options_pair(currency, expiry, filter).await?;
+        Ok(OptionChain::from_pairs(
+            currency.to_uppercase(),
+            expiry.to_uppercase(),
+            pairs.into_values(),
+        ))
     }
 
     /// Fetches option instruments for a given currency and expiry date, grouped by strike price.
@@ -494,6 +679,8 @@ impl DeribitHttpClient {
     ///
     /// * `currency` - The currency symbol (e.g., "BTC", "ETH")
     /// * `expiry` - The expiry date in format "DDMMMYY" (e.g., "10SEP25")
+    /// * `filter` - Strikes, moneyness window and option type to keep, as in
+    ///   [`get_options`](Self::get_options)
     ///
     /// # Returns
     ///
@@ -512,10 +699,12 @@ impl DeribitHttpClient {
     ///
     /// ```no_run
     /// # use deribit_http::DeribitHttpClient;
+    /// # use deribit_http::model::OptionFilter;
     /// # #[tokio::main]
     /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
     /// let client = DeribitHttpClient::new();
-    /// let pairs = client.get_options_pair("BTC", "10SEP25").await?;
+    /// let filter = OptionFilter::default().with_moneyness(0.8, 1.2);
+    /// let pairs = client.get_options_pair("BTC", "10SEP25", filter).await?;
     ///
     /// for (strike, pair) in pairs {
     ///     println!("Strike {}: Call={:?}, Put={:?}",
@@ -526,10 +715,12 @@ impl DeribitHttpClient {
     /// ```
     pub async fn get_options_pair(
         &self,
-        currency: &str,
+        currency: impl AsRef<str>,
         expiry: &str,
+        filter: OptionFilter,
     ) -> Result<HashMap<u64, OptionInstrumentPair>, HttpError> {
-        let option_instruments = self.get_options(currency, expiry).await?;
+        let currency = currency.as_ref();
+        let option_instruments = self.get_options(currency, expiry, filter).await?;
 
         let mut strikes_map: HashMap<u64, OptionInstrumentPair> =
             HashMap::with_capacity(option_instruments.len() / 2);
@@ -551,9 +742,10 @@ impl DeribitHttpClient {
                     }
                 },
                 None => {
-                    return Err(HttpError::RequestFailed(
+                    return Err(HttpErrorKind::RequestFailed(
                         "Option instrument has no option type".to_string(),
-                    ));
+                    )
+                    .into());
                 }
             }
         }
@@ -568,14 +760,15 @@ impl DeribitHttpClient {
     /// # Arguments
     ///
     /// * `currency` - The currency (e.g., "BTC", "ETH")
-    /// * `kind` - Optional instrument kind ("future", "option", "spot")
+    /// * `kind` - Optional instrument kind filter
     /// * `expired` - Whether to include expired instruments
     pub async fn get_instruments(
         &self,
-        currency: &str,
-        kind: Option<&str>,
+        currency: impl AsRef<str>,
+        kind: Option<InstrumentKind>,
         expired: Option<bool>,
     ) -> Result<Vec<Instrument>, HttpError> {
+        let currency = currency.as_ref();
         let mut query = format!("?currency={}", currency);
         if let Some(k) = kind {
             query.push_str(&format!("&kind={}", k));
@@ -629,7 +822,7 @@ impl DeribitHttpClient {
                     },
                     amount: last_trade.amount,
                     price: last_trade.price,
-                    timestamp: last_trade.timestamp as i64,
+                    timestamp: last_trade.timestamp,
                     fee: 0.0,                    // Not available in LastTrade
                     fee_currency: String::new(), // Not available in LastTrade
                     liquidity: Liquidity::Taker, // Default
@@ -671,8 +864,9 @@ impl DeribitHttpClient {
     /// ```
     pub async fn get_historical_volatility(
         &self,
-        currency: &str,
+        currency: impl AsRef<str>,
     ) -> Result<Vec<[f64; 2]>, HttpError> {
+        let currency = currency.as_ref();
         let query = format!("?currency={}", urlencoding::encode(currency));
         self.public_get(GET_HISTORICAL_VOLATILITY, &query).await
     }
@@ -689,8 +883,7 @@ impl DeribitHttpClient {
     /// # Arguments
     ///
     /// * `instrument_name` - Unique instrument identifier (e.g., "BTC-25JUN21-50000-C")
-    /// * `start_timestamp` - The earliest timestamp to return results from (milliseconds since Unix epoch)
-    /// * `end_timestamp` - The most recent timestamp to return results from (milliseconds since Unix epoch)
+    /// * `range` - Time window to query, e.g. a [`TimeRange`] or a `(TimestampMs, TimestampMs)` pair
     ///
     /// # Returns
     ///
@@ -708,8 +901,7 @@ impl DeribitHttpClient {
     /// let client = DeribitHttpClient::new();
     /// // let history = client.get_mark_price_history(
     /// //     "BTC-25JUN21-50000-C",
-    /// //     1609376800000,
-    /// //     1609376810000
+    /// //     (TimestampMs::from_millis(1609376800000), TimestampMs::from_millis(1609376810000)),
     /// // ).await?;
     /// // for point in history {
     /// //     println!("Time: {}, Mark Price: {}", point.timestamp, point.mark_price);
@@ -718,9 +910,9 @@ impl DeribitHttpClient {
     pub async fn get_mark_price_history(
         &self,
         instrument_name: &str,
-        start_timestamp: u64,
-        end_timestamp: u64,
+        range: impl Into<TimeRange>,
     ) -> Result<Vec<MarkPriceHistoryPoint>, HttpError> {
+        let (start_timestamp, end_timestamp) = range.into().resolve()?;
         let query = format!(
             "?instrument_name={}&start_timestamp={}&end_timestamp={}",
             urlencoding::encode(instrument_name),
@@ -860,9 +1052,8 @@ impl DeribitHttpClient {
     /// # Arguments
     ///
     /// * `currency` - Currency symbol (e.g., "BTC", "ETH")
-    /// * `start_timestamp` - Start timestamp in milliseconds since UNIX epoch
-    /// * `end_timestamp` - End timestamp in milliseconds since UNIX epoch
-    /// * `resolution` - Candle interval ("1", "60", "3600", "43200", "1D")
+    /// * `range` - Time window to query, e.g. a [`TimeRange`] or a `(TimestampMs, TimestampMs)` pair
+    /// * `resolution` - Candle interval (1 s, 1 min, 1 h, 12 h or 1 day)
     ///
     /// # Returns
     ///
@@ -881,9 +1072,8 @@ impl DeribitHttpClient {
     /// // Get 1-hour VIX candles for BTC
     /// // let vix_data = client.get_volatility_index_data(
     /// //     "BTC",
-    /// //     1599373800000,
-    /// //     1599376800000,
-    /// //     "60"
+    /// //     (TimestampMs::from_millis(1599373800000), TimestampMs::from_millis(1599376800000)),
+    /// //     VolatilityIndexResolution::Minute1
     /// // ).await?;
     /// // for candle in &vix_data.data {
     /// //     println!("ts={}, close={}", candle.timestamp, candle.close);
@@ -891,11 +1081,12 @@ impl DeribitHttpClient {
     /// ```
     pub async fn get_volatility_index_data(
         &self,
-        currency: &str,
-        start_timestamp: u64,
-        end_timestamp: u64,
-        resolution: &str,
+        currency: impl AsRef<str>,
+        range: impl Into<TimeRange>,
+        resolution: VolatilityIndexResolution,
     ) -> Result<VolatilityIndexData, HttpError> {
+        let currency = currency.as_ref();
+        let (start_timestamp, end_timestamp) = range.into().resolve()?;
         let query = format!(
             "?currency={}&start_timestamp={}&end_timestamp={}&resolution={}",
             currency, start_timestamp, end_timestamp, resolution
@@ -903,6 +1094,66 @@ impl DeribitHttpClient {
         self.public_get(GET_VOLATILITY_INDEX_DATA, &query).await
     }
 
+    /// Get all volatility index candles in a time window
+    ///
+    /// Deribit caps the candles per `get_volatility_index_data` response and
+    /// returns a `continuation` timestamp to use as the next `end_timestamp`.
+    /// This follows it until the start of `range` is reached, then returns
+    /// the candles oldest first, deduplicated by timestamp.
+    ///
+    /// # Arguments
+    ///
+    /// * `currency` - Currency symbol (e.g., "BTC", "ETH")
+    /// * `range` - Time window to query, e.g. a [`TimeRange`] or a `(TimestampMs, TimestampMs)` pair
+    /// * `resolution` - Candle interval (1 s, 1 min, 1 h, 12 h or 1 day)
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use deribit_http::DeribitHttpClient;
+    /// # use deribit_http::model::VolatilityIndexResolution;
+    /// # use deribit_http::model::TimeRange;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = DeribitHttpClient::new();
+    /// let dvol = client
+    ///     .get_all_volatility_index_data("BTC", TimeRange::LastHours(24 * 30), VolatilityIndexResolution::Hour1)
+    ///     .await?;
+    /// println!("{} hourly DVOL candles", dvol.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_all_volatility_index_data(
+        &self,
+        currency: impl AsRef<str>,
+        range: impl Into<TimeRange>,
+        resolution: VolatilityIndexResolution,
+    ) -> Result<Vec<VolatilityIndexCandle>, HttpError> {
+        let currency = currency.as_ref();
+        let (start, end) = range.into().resolve()?;
+        let mut candles: Vec<VolatilityIndexCandle> =
+            paginate(None, move |cursor: Option<Cursor>| async move {
+                let end = cursor
+                    .as_ref()
+                    .and_then(Cursor::continuation)
+                    .and_then(|continuation| continuation.parse().ok())
+                    .map_or(end, TimestampMs);
+                if end < start {
+                    return Ok(VolatilityIndexData {
+                        data: Vec::new(),
+                        continuation: None,
+                    });
+                }
+                self.get_volatility_index_data(currency, (start, end), resolution)
+                    .await
+            })
+            .try_collect()
+            .await?;
+        candles.sort_by_key(|candle| candle.timestamp);
+        candles.dedup_by_key(|candle| candle.timestamp);
+        Ok(candles)
+    }
+
     /// Get funding chart data
     ///
     /// Retrieves the list of the latest PERPETUAL funding chart points within a given time period.
@@ -910,7 +1161,7 @@ impl DeribitHttpClient {
     /// # Arguments
     ///
     /// * `instrument_name` - Instrument name
-    /// * `length` - Time period (8h, 24h, 1m)
+    /// * `length` - Time period (8 hours, 24 hours or 1 month)
     ///
     /// # Examples
     ///
@@ -918,18 +1169,18 @@ impl DeribitHttpClient {
     /// use deribit_http::DeribitHttpClient;
     ///
     /// let client = DeribitHttpClient::new();
-    /// // let funding_data = client.get_funding_chart_data("BTC-PERPETUAL", "8h").await?;
+    /// // let funding_data = client.get_funding_chart_data("BTC-PERPETUAL", FundingChartLength::Hours8).await?;
     /// // tracing::info!("Current interest: {}", funding_data.current_interest);
     /// ```
     pub async fn get_funding_chart_data(
         &self,
         instrument_name: &str,
-        length: &str,
+        length: FundingChartLength,
     ) -> Result<FundingChartData, HttpError> {
         let query = format!(
             "?instrument_name={}&length={}",
             urlencoding::encode(instrument_name),
-            urlencoding::encode(length)
+            length.as_str()
         );
         self.public_get(GET_FUNDING_CHART_DATA, &query).await
     }
@@ -941,9 +1192,8 @@ impl DeribitHttpClient {
     /// # Arguments
     ///
     /// * `instrument_name` - Instrument name
-    /// * `start_timestamp` - Start timestamp in milliseconds
-    /// * `end_timestamp` - End timestamp in milliseconds
-    /// * `resolution` - Chart resolution (1, 3, 5, 10, 15, 30, 60, 120, 180, 360)
+    /// * `range` - Time window to query, e.g. a [`TimeRange`] or a `(TimestampMs, TimestampMs)` pair
+    /// * `resolution` - Candle resolution (1 minute to 12 hours, or 1 day)
     ///
     /// # Examples
     ///
@@ -951,26 +1201,87 @@ impl DeribitHttpClient {
     /// use deribit_http::DeribitHttpClient;
     ///
     /// let client = DeribitHttpClient::new();
-    /// // let chart_data = client.get_tradingview_chart_data("BTC-PERPETUAL", 1554373800000, 1554376800000, "30").await?;
+    /// // let chart_data = client.get_tradingview_chart_data("BTC-PERPETUAL", (TimestampMs::from_millis(1554373800000), TimestampMs::from_millis(1554376800000)), Resolution::Minute30).await?;
     /// // tracing::info!("Chart status: {}", chart_data.status);
     /// ```
     pub async fn get_tradingview_chart_data(
         &self,
         instrument_name: &str,
-        start_timestamp: u64,
-        end_timestamp: u64,
-        resolution: &str,
+        range: impl Into<TimeRange>,
+        resolution: Resolution,
     ) -> Result<TradingViewChartData, HttpError> {
+        let (start_timestamp, end_timestamp) = range.into().resolve()?;
         let query = format!(
             "?instrument_name={}&start_timestamp={}&end_timestamp={}&resolution={}",
             urlencoding::encode(instrument_name),
             start_timestamp,
             end_timestamp,
-            urlencoding::encode(resolution)
+            resolution.as_str()
         );
         self.public_get(GET_TRADINGVIEW_CHART_DATA, &query).await
     }
 
+    /// Download candles over a window of any length
+    ///
+    /// Splits `range` into windows of
+    /// [`CHART_CANDLES_PER_REQUEST`]
+    /// candles, requests them one after another through the rate limiter, and
+    /// stitches the results in time order. The candle on the boundary of two
+    /// windows is kept once.
+    ///
+    /// # Arguments
+    ///
+    /// * `instrument_name` - Instrument name
+    /// * `range` - Time window to download, e.g. a [`TimeRange`] or a `(TimestampMs, TimestampMs)` pair
+    /// * `resolution` - Candle resolution
+    ///
+    /// # Errors
+    ///
+    /// Returns `HttpErrorKind::ConfigError` if the window is inverted, or
+    /// `HttpError` if a request fails.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use deribit_http::DeribitHttpClient;
+    /// use deribit_http::model::{Resolution, TimeRange};
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = DeribitHttpClient::new();
+    /// let year = client
+    ///     .download_candles("BTC-PERPETUAL", TimeRange::last_hours(24 * 365), Resolution::Minute1)
+    ///     .await?;
+    /// println!("{} candles", year.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn download_candles(
+        &self,
+        instrument_name: &str,
+        range: impl Into<TimeRange>,
+        resolution: Resolution,
+    ) -> Result<TradingViewChartData, HttpError> {
+        let (start, end) = range.into().resolve()?;
+        let span = resolution.duration().as_millis() as u64 * CHART_CANDLES_PER_REQUEST;
+        let mut candles = TradingViewChartData::new();
+        let mut window_start = start;
+        loop {
+            let window_end = TimestampMs(window_start.as_millis().saturating_add(span)).min(end);
+            let chunk = self
+                .get_tradingview_chart_data(instrument_name, (window_start, window_end), resolution)
+                .await?;
+            candles.append_after_last(&chunk);
+            if window_end >= end {
+                break;
+            }
+            window_start = window_end;
+        }
+        if candles.is_empty() {
+            candles.status = "no_data".to_string();
+        }
+        Ok(candles)
+    }
+
     /// Get delivery prices
     ///
     /// Retrieves delivery prices for the given index.
@@ -1012,6 +1323,71 @@ impl DeribitHttpClient {
         self.public_get(GET_DELIVERY_PRICES, &query).await
     }
 
+    /// Get all delivery prices within a date range
+    ///
+    /// Walks `get_delivery_prices` with `count`/`offset` until the oldest delivery
+    /// in `range` has been reached or all records have been read. Entries are
+    /// deduplicated by date, since offsets shift when a new delivery is published
+    /// mid-walk, and returned newest first.
+    ///
+    /// # Arguments
+    ///
+    /// * `index_name` - Index identifier (e.g., "btc_usd", "eth_usd")
+    /// * `range` - Delivery dates to include (e.g., `..` for the full history)
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use deribit_http::DeribitHttpClient;
+    /// # use chrono::NaiveDate;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = DeribitHttpClient::new();
+    /// let from = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+    /// let prices = client.get_all_delivery_prices("btc_usd", from..).await?;
+    /// println!("{} deliveries since {}", prices.len(), from);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_all_delivery_prices(
+        &self,
+        index_name: &str,
+        range: impl RangeBounds<NaiveDate>,
+    ) -> Result<Vec<DeliveryPriceData>, HttpError> {
+        let mut seen = HashSet::new();
+        paginate(
+            Some(Cursor::Offset(0)),
+            |cursor: Option<Cursor>| async move {
+                let offset = cursor.as_ref().and_then(Cursor::offset);
+                self.get_delivery_prices(index_name, Some(DELIVERY_PRICES_PAGE_LIMIT), offset)
+                    .await
+            },
+        )
+        .and_then(|price: DeliveryPriceData| {
+            let date = NaiveDate::parse_from_str(&price.date, "%Y-%m-%d").map_err(|e| {
+                HttpError::from(HttpErrorKind::ParseError(format!(
+                    "Invalid delivery date '{}': {}",
+                    price.date, e
+                )))
+            });
+            future::ready(date.map(|date| (date, price)))
+        })
+        .try_take_while(|(date, _)| {
+            let before_start = match range.start_bound() {
+                Bound::Included(start) => date < start,
+                Bound::Excluded(start) => date <= start,
+                Bound::Unbounded => false,
+            };
+            future::ready(Ok(!before_start))
+        })
+        .try_filter_map(|(date, price)| {
+            let keep = range.contains(&date) && seen.insert(date);
+            future::ready(Ok(keep.then_some(price)))
+        })
+        .try_collect()
+        .await
+    }
+
     /// Get expirations
     ///
     /// Retrieves expirations for instruments. This method can be used to see instrument expirations.
@@ -1020,19 +1396,20 @@ impl DeribitHttpClient {
     /// # Arguments
     ///
     /// * `currency` - The currency symbol (BTC, ETH, USDC, USDT, any, grouped)
-    /// * `kind` - Instrument kind (future, option, any)
+    /// * `kind` - Instrument kind (`Future`, `Option` or `Any`)
     /// * `currency_pair` - Currency pair identifier (optional)
     ///
     pub async fn get_expirations(
         &self,
-        currency: &str,
-        kind: &str,
+        currency: impl AsRef<str>,
+        kind: InstrumentKind,
         currency_pair: Option<&str>,
     ) -> Result<ExpirationsResponse, HttpError> {
+        let currency = currency.as_ref();
         let mut query = format!(
             "?currency={}&kind={}",
             urlencoding::encode(currency),
-            urlencoding::encode(kind)
+            kind.as_str()
         );
         if let Some(currency_pair) = currency_pair {
             query.push_str(&format!(
@@ -1051,15 +1428,14 @@ impl DeribitHttpClient {
     /// # Arguments
     ///
     /// * `instrument_name` - Instrument name
-    /// * `start_timestamp` - The earliest timestamp to return result from (milliseconds since UNIX epoch)
-    /// * `end_timestamp` - The most recent timestamp to return result from (milliseconds since UNIX epoch)
+    /// * `range` - Time window to query, e.g. a [`TimeRange`] or a `(TimestampMs, TimestampMs)` pair
     ///
     pub async fn get_funding_rate_history(
         &self,
         instrument_name: &str,
-        start_timestamp: u64,
-        end_timestamp: u64,
+        range: impl Into<TimeRange>,
     ) -> Result<Vec<FundingRateData>, HttpError> {
+        let (start_timestamp, end_timestamp) = range.into().resolve()?;
         let query = format!(
             "?instrument_name={}&start_timestamp={}&end_timestamp={}",
             urlencoding::encode(instrument_name),
@@ -1077,17 +1453,18 @@ impl DeribitHttpClient {
     /// # Arguments
     ///
     /// * `instrument_name` - Instrument name
-    /// * `start_timestamp` - The earliest timestamp to return result from (milliseconds since UNIX epoch)
-    /// * `end_timestamp` - The most recent timestamp to return result from (milliseconds since UNIX epoch)
+    /// * `range` - Time window to query, e.g. a [`TimeRange`] or a `(TimestampMs, TimestampMs)` pair
     ///
     /// # Examples
     ///
     /// ```rust
     /// # use deribit_http::DeribitHttpClient;
+    /// # use deribit_http::model::TimestampMs;
     /// # #[tokio::main]
     /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
     /// let client = DeribitHttpClient::new(); // testnet
-    /// let funding_rate = client.get_funding_rate_value("BTC-PERPETUAL", 1569888000000, 1569974400000).await?;
+    /// let range = (TimestampMs::from_millis(1569888000000), TimestampMs::from_millis(1569974400000));
+    /// let funding_rate = client.get_funding_rate_value("BTC-PERPETUAL", range).await?;
     /// println!("Funding rate for period: {}", funding_rate);
     /// # Ok(())
     /// # }
@@ -1095,9 +1472,9 @@ impl DeribitHttpClient {
     pub async fn get_funding_rate_value(
         &self,
         instrument_name: &str,
-        start_timestamp: u64,
-        end_timestamp: u64,
+        range: impl Into<TimeRange>,
     ) -> Result<f64, HttpError> {
+        let (start_timestamp, end_timestamp) = range.into().resolve()?;
         let query = format!(
             "?instrument_name={}&start_timestamp={}&end_timestamp={}",
             urlencoding::encode(instrument_name),
@@ -1122,12 +1499,13 @@ impl DeribitHttpClient {
     ///
     pub async fn get_last_settlements_by_currency(
         &self,
-        currency: &str,
+        currency: impl AsRef<str>,
         settlement_type: Option<&str>,
         count: Option<u32>,
         continuation: Option<&str>,
-        search_start_timestamp: Option<u64>,
+        search_start_timestamp: Option<TimestampMs>,
     ) -> Result<SettlementsResponse, HttpError> {
+        let currency = currency.as_ref();
         let mut query = format!("?currency={}", urlencoding::encode(currency));
         if let Some(settlement_type) = settlement_type {
             query.push_str(&format!("&type={}", urlencoding::encode(settlement_type)));
@@ -1170,7 +1548,7 @@ impl DeribitHttpClient {
         settlement_type: Option<&str>,
         count: Option<u32>,
         continuation: Option<&str>,
-        search_start_timestamp: Option<u64>,
+        search_start_timestamp: Option<TimestampMs>,
     ) -> Result<SettlementsResponse, HttpError> {
         let mut query = format!("?instrument_name={}", urlencoding::encode(instrument_name));
         if let Some(settlement_type) = settlement_type {
@@ -1195,6 +1573,84 @@ impl DeribitHttpClient {
             .await
     }
 
+    /// Stream settlements by currency
+    ///
+    /// Follows the `continuation` token of `get_last_settlements_by_currency`,
+    /// yielding events newest first until the history is exhausted or an event
+    /// older than `since_timestamp` is reached.
+    ///
+    /// # Arguments
+    ///
+    /// * `currency` - The currency symbol (BTC, ETH, USDC, USDT, EURR)
+    /// * `settlement_type` - Settlement type (settlement, delivery, bankruptcy) - optional
+    /// * `search_start_timestamp` - The latest timestamp to return result from (optional)
+    /// * `since_timestamp` - Stop at events older than this timestamp in milliseconds (optional)
+    ///
+    pub fn stream_last_settlements_by_currency<'a>(
+        &'a self,
+        currency: impl AsRef<str>,
+        settlement_type: Option<&'a str>,
+        search_start_timestamp: Option<TimestampMs>,
+        since_timestamp: Option<TimestampMs>,
+    ) -> impl Stream<Item = Result<Settlement, HttpError>> + 'a {
+        let currency = currency.as_ref().to_owned();
+        paginate(None, move |cursor: Option<Cursor>| {
+            let currency = currency.clone();
+            async move {
+                self.get_last_settlements_by_currency(
+                    &currency,
+                    settlement_type,
+                    Some(SETTLEMENTS_PAGE_COUNT),
+                    cursor.as_ref().and_then(Cursor::continuation),
+                    search_start_timestamp,
+                )
+                .await
+            }
+        })
+        .try_take_while(move |settlement: &Settlement| {
+            future::ready(Ok(
+                since_timestamp.is_none_or(|since| settlement.timestamp >= since)
+            ))
+        })
+    }
+
+    /// Stream settlements by instrument
+    ///
+    /// Follows the `continuation` token of `get_last_settlements_by_instrument`,
+    /// yielding events newest first until the history is exhausted or an event
+    /// older than `since_timestamp` is reached.
+    ///
+    /// # Arguments
+    ///
+    /// * `instrument_name` - Instrument name
+    /// * `settlement_type` - Settlement type (settlement, delivery, bankruptcy) - optional
+    /// * `search_start_timestamp` - The latest timestamp to return result from (optional)
+    /// * `since_timestamp` - Stop at events older than this timestamp in milliseconds (optional)
+    ///
+    pub fn stream_last_settlements_by_instrument<'a>(
+        &'a self,
+        instrument_name: &'a str,
+        settlement_type: Option<&'a str>,
+        search_start_timestamp: Option<TimestampMs>,
+        since_timestamp: Option<TimestampMs>,
+    ) -> impl Stream<Item = Result<Settlement, HttpError>> + 'a {
+        paginate(None, move |cursor: Option<Cursor>| async move {
+            self.get_last_settlements_by_instrument(
+                instrument_name,
+                settlement_type,
+                Some(SETTLEMENTS_PAGE_COUNT),
+                cursor.as_ref().and_then(Cursor::continuation),
+                search_start_timestamp,
+            )
+            .await
+        })
+        .try_take_while(move |settlement: &Settlement| {
+            future::ready(Ok(
+                since_timestamp.is_none_or(|since| settlement.timestamp >= since)
+            ))
+        })
+    }
+
     /// Get last trades by currency
     ///
     /// Retrieves the latest trades that have occurred for instruments in a specific currency.
@@ -1212,10 +1668,11 @@ impl DeribitHttpClient {
     ///
     /// ```rust
     /// # use deribit_http::DeribitHttpClient;
+    /// # use deribit_http::model::{InstrumentKind, SortDirection};
     /// # #[tokio::main]
     /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
     /// let client = DeribitHttpClient::new(); // testnet
-    /// let trades = client.get_last_trades_by_currency("BTC", Some("future"), Some(10), Some(false), Some("desc")).await?;
+    /// let trades = client.get_last_trades_by_currency("BTC", Some(InstrumentKind::Future), Some(10), Some(false), Some(SortDirection::Desc)).await?;
     /// for trade in trades.trades {
     ///     println!("Trade: {} {} at {}", trade.amount, trade.instrument_name, trade.price);
     /// }
@@ -1224,15 +1681,16 @@ impl DeribitHttpClient {
     /// ```
     pub async fn get_last_trades_by_currency(
         &self,
-        currency: &str,
-        kind: Option<&str>,
+        currency: impl AsRef<str>,
+        kind: Option<InstrumentKind>,
         count: Option<u32>,
         include_old: Option<bool>,
-        sorting: Option<&str>,
+        sorting: Option<SortDirection>,
     ) -> Result<LastTradesResponse, HttpError> {
+        let currency = currency.as_ref();
         let mut query = format!("?currency={}", urlencoding::encode(currency));
         if let Some(kind) = kind {
-            query.push_str(&format!("&kind={}", urlencoding::encode(kind)));
+            query.push_str(&format!("&kind={}", kind.as_str()));
         }
         if let Some(count) = count {
             query.push_str(&format!("&count={}", count));
@@ -1241,7 +1699,7 @@ impl DeribitHttpClient {
             query.push_str(&format!("&include_old={}", include_old));
         }
         if let Some(sorting) = sorting {
-            query.push_str(&format!("&sorting={}", urlencoding::encode(sorting)));
+            query.push_str(&format!("&sorting={}", sorting.as_str()));
         }
         self.public_get(GET_LAST_TRADES_BY_CURRENCY, &query).await
     }
@@ -1254,8 +1712,7 @@ impl DeribitHttpClient {
     /// # Arguments
     ///
     /// * `currency` - The currency symbol (BTC, ETH, USDC, USDT, EURR)
-    /// * `start_timestamp` - The earliest timestamp to return result from (milliseconds since UNIX epoch)
-    /// * `end_timestamp` - The most recent timestamp to return result from (milliseconds since UNIX epoch)
+    /// * `range` - Time window to query, e.g. a [`TimeRange`] or a `(TimestampMs, TimestampMs)` pair
     /// * `kind` - Instrument kind (future, option, spot, etc.) - optional
     /// * `count` - Number of requested items (optional, default 10)
     /// * `include_old` - Include trades older than 7 days (optional)
@@ -1265,10 +1722,12 @@ impl DeribitHttpClient {
     ///
     /// ```rust
     /// # use deribit_http::DeribitHttpClient;
+    /// # use deribit_http::model::{InstrumentKind, SortDirection, TimestampMs};
     /// # #[tokio::main]
     /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
     /// let client = DeribitHttpClient::new(); // testnet
-    /// let trades = client.get_last_trades_by_currency_and_time("BTC", 1569888000000, 1569974400000, Some("future"), Some(10), Some(false), Some("desc")).await?;
+    /// let range = (TimestampMs::from_millis(1569888000000), TimestampMs::from_millis(1569974400000));
+    /// let trades = client.get_last_trades_by_currency_and_time("BTC", range, Some(InstrumentKind::Future), Some(10), Some(false), Some(SortDirection::Desc)).await?;
     /// for trade in trades.trades {
     ///     println!("Trade: {} {} at {}", trade.amount, trade.instrument_name, trade.price);
     /// }
@@ -1278,14 +1737,15 @@ impl DeribitHttpClient {
     #[allow(clippy::too_many_arguments)]
     pub async fn get_last_trades_by_currency_and_time(
         &self,
-        currency: &str,
-        start_timestamp: u64,
-        end_timestamp: u64,
-        kind: Option<&str>,
+        currency: impl AsRef<str>,
+        range: impl Into<TimeRange>,
+        kind: Option<InstrumentKind>,
         count: Option<u32>,
         include_old: Option<bool>,
-        sorting: Option<&str>,
+        sorting: Option<SortDirection>,
     ) -> Result<LastTradesResponse, HttpError> {
+        let currency = currency.as_ref();
+        let (start_timestamp, end_timestamp) = range.into().resolve()?;
         let mut query = format!(
             "?currency={}&start_timestamp={}&end_timestamp={}",
             urlencoding::encode(currency),
@@ -1293,7 +1753,7 @@ impl DeribitHttpClient {
             end_timestamp
         );
         if let Some(kind) = kind {
-            query.push_str(&format!("&kind={}", urlencoding::encode(kind)));
+            query.push_str(&format!("&kind={}", kind.as_str()));
         }
         if let Some(count) = count {
             query.push_str(&format!("&count={}", count));
@@ -1302,7 +1762,7 @@ impl DeribitHttpClient {
             query.push_str(&format!("&include_old={}", include_old));
         }
         if let Some(sorting) = sorting {
-            query.push_str(&format!("&sorting={}", urlencoding::encode(sorting)));
+            query.push_str(&format!("&sorting={}", sorting.as_str()));
         }
         self.public_get(GET_LAST_TRADES_BY_CURRENCY_AND_TIME, &query)
             .await
@@ -1316,8 +1776,7 @@ impl DeribitHttpClient {
     /// # Arguments
     ///
     /// * `instrument_name` - Instrument name
-    /// * `start_timestamp` - The earliest timestamp to return result from (milliseconds since UNIX epoch)
-    /// * `end_timestamp` - The most recent timestamp to return result from (milliseconds since UNIX epoch)
+    /// * `range` - Time window to query, e.g. a [`TimeRange`] or a `(TimestampMs, TimestampMs)` pair
     /// * `count` - Number of requested items (optional, default 10)
     /// * `include_old` - Include trades older than 7 days (optional)
     /// * `sorting` - Direction of results sorting (optional)
@@ -1326,10 +1785,12 @@ impl DeribitHttpClient {
     ///
     /// ```rust
     /// # use deribit_http::DeribitHttpClient;
+    /// # use deribit_http::model::{SortDirection, TimestampMs};
     /// # #[tokio::main]
     /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
     /// let client = DeribitHttpClient::new(); // testnet
-    /// let trades = client.get_last_trades_by_instrument_and_time("BTC-PERPETUAL", 1569888000000, 1569974400000, Some(10), Some(false), Some("desc")).await?;
+    /// let range = (TimestampMs::from_millis(1569888000000), TimestampMs::from_millis(1569974400000));
+    /// let trades = client.get_last_trades_by_instrument_and_time("BTC-PERPETUAL", range, Some(10), Some(false), Some(SortDirection::Desc)).await?;
     /// for trade in trades.trades {
     ///     println!("Trade: {} at {} ({})", trade.amount, trade.price, trade.direction);
     /// }
@@ -1339,12 +1800,12 @@ impl DeribitHttpClient {
     pub async fn get_last_trades_by_instrument_and_time(
         &self,
         instrument_name: &str,
-        start_timestamp: u64,
-        end_timestamp: u64,
+        range: impl Into<TimeRange>,
         count: Option<u32>,
         include_old: Option<bool>,
-        sorting: Option<&str>,
+        sorting: Option<SortDirection>,
     ) -> Result<LastTradesResponse, HttpError> {
+        let (start_timestamp, end_timestamp) = range.into().resolve()?;
         let mut query = format!(
             "?instrument_name={}&start_timestamp={}&end_timestamp={}",
             urlencoding::encode(instrument_name),
@@ -1358,12 +1819,78 @@ impl DeribitHttpClient {
             query.push_str(&format!("&include_old={}", include_old));
         }
         if let Some(sorting) = sorting {
-            query.push_str(&format!("&sorting={}", urlencoding::encode(sorting)));
+            query.push_str(&format!("&sorting={}", sorting.as_str()));
         }
         self.public_get(GET_LAST_TRADES_BY_INSTRUMENT_AND_TIME, &query)
             .await
     }
 
+    /// Stream last trades by currency and time
+    ///
+    /// Follows `has_more` through [`paginate`], narrowing the time window to
+    /// start (ascending) or end (descending) at the last trade of each page.
+    /// Trades sharing that timestamp are not yielded twice; more of them than
+    /// fit in a page fail with `HttpErrorKind::ConfigError`. Arguments match
+    /// [`get_last_trades_by_currency_and_time`](Self::get_last_trades_by_currency_and_time).
+    pub fn stream_last_trades_by_currency_and_time<'a>(
+        &'a self,
+        currency: impl AsRef<str>,
+        range: impl Into<TimeRange>,
+        kind: Option<InstrumentKind>,
+        count: Option<u32>,
+        include_old: Option<bool>,
+        sorting: Option<SortDirection>,
+    ) -> impl Stream<Item = Result<LastTrade, HttpError>> + 'a {
+        let currency = currency.as_ref().to_owned();
+        let bounds = range.into().bounds();
+        paginate(None, move |cursor: Option<Cursor>| {
+            let currency = currency.clone();
+            async move {
+                let range = trade_window(bounds, cursor.as_ref(), sorting.as_ref());
+                self.get_last_trades_by_currency_and_time(
+                    &currency,
+                    range,
+                    kind,
+                    count,
+                    include_old,
+                    sorting,
+                )
+                .await?
+                .ensure_progress(cursor.as_ref())
+            }
+        })
+    }
+
+    /// Stream last trades by instrument and time
+    ///
+    /// Follows `has_more` through [`paginate`], narrowing the time window to
+    /// start (ascending) or end (descending) at the last trade of each page.
+    /// Trades sharing that timestamp are not yielded twice; more of them than
+    /// fit in a page fail with `HttpErrorKind::ConfigError`. Arguments match
+    /// [`get_last_trades_by_instrument_and_time`](Self::get_last_trades_by_instrument_and_time).
+    pub fn stream_last_trades_by_instrument_and_time<'a>(
+        &'a self,
+        instrument_name: &'a str,
+        range: impl Into<TimeRange>,
+        count: Option<u32>,
+        include_old: Option<bool>,
+        sorting: Option<SortDirection>,
+    ) -> impl Stream<Item = Result<LastTrade, HttpError>> + 'a {
+        let bounds = range.into().bounds();
+        paginate(None, move |cursor: Option<Cursor>| async move {
+            let range = trade_window(bounds, cursor.as_ref(), sorting.as_ref());
+            self.get_last_trades_by_instrument_and_time(
+                instrument_name,
+                range,
+                count,
+                include_old,
+                sorting,
+            )
+            .await?
+            .ensure_progress(cursor.as_ref())
+        })
+    }
+
     /// Get order book by instrument ID
     ///
     /// Retrieves the order book for the specified instrument by its ID.
@@ -1429,7 +1956,7 @@ impl DeribitHttpClient {
     pub async fn get_announcements(
         &self,
         count: Option<u32>,
-        start_timestamp: Option<u64>,
+        start_timestamp: Option<TimestampMs>,
     ) -> Result<Vec<crate::model::Announcement>, HttpError> {
         let mut query_params = Vec::new();
         if let Some(count) = count {
@@ -1512,9 +2039,10 @@ impl DeribitHttpClient {
     /// ```
     pub async fn get_combo_ids(
         &self,
-        currency: &str,
+        currency: impl AsRef<str>,
         state: Option<&str>,
     ) -> Result<Vec<String>, HttpError> {
+        let currency = currency.as_ref();
         let mut query = format!("?currency={}", urlencoding::encode(currency));
         if let Some(s) = state {
             query.push_str(&format!("&state={}", urlencoding::encode(s)));
@@ -1549,7 +2077,11 @@ impl DeribitHttpClient {
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn get_combos(&self, currency: &str) -> Result<Vec<crate::model::Combo>, HttpError> {
+    pub async fn get_combos(
+        &self,
+        currency: impl AsRef<str>,
+    ) -> Result<Vec<crate::model::Combo>, HttpError> {
+        let currency = currency.as_ref();
         let query = format!("?currency={}", urlencoding::encode(currency));
         self.public_get(GET_COMBOS, &query).await
     }
@@ -1587,19 +2119,19 @@ impl DeribitHttpClient {
     /// ```
     pub async fn get_block_rfq_trades(
         &self,
-        currency: Option<&str>,
+        currency: Option<impl AsRef<str>>,
         count: Option<u32>,
         continuation: Option<&str>,
     ) -> Result<crate::model::response::BlockRfqTradesResponse, HttpError> {
         let mut query_params: Vec<String> = Vec::new();
         if let Some(curr) = currency {
-            query_params.push(format!("currency={}", curr));
+            query_params.push(format!("currency={}", urlencoding::encode(curr.as_ref())));
         }
         if let Some(c) = count {
             query_params.push(format!("count={}", c));
         }
         if let Some(cont) = continuation {
-            query_params.push(format!("continuation={}", cont));
+            query_params.push(format!("continuation={}", urlencoding::encode(cont)));
         }
         let query = if query_params.is_empty() {
             String::new()
@@ -1609,4 +2141,59 @@ impl DeribitHttpClient {
         self.public_get(crate::constants::endpoints::GET_BLOCK_RFQ_TRADES, &query)
             .await
     }
+
+    // ========================================================================
+    // RFQ Endpoints
+    // ========================================================================
+
+    /// Get active requests for quote
+    ///
+    /// Lists the RFQs currently open on instruments of a currency, so market
+    /// makers can see which instruments are being asked for.
+    ///
+    /// # Arguments
+    ///
+    /// * `currency` - The currency symbol (BTC, ETH, USDC, USDT, EURR)
+    /// * `kind` - Instrument kind filter (future, option, ...), optional
+    ///
+    /// # Errors
+    ///
+    /// Returns `HttpError` if the request fails or the response cannot be parsed.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use deribit_http::DeribitHttpClient;
+    /// use deribit_http::model::InstrumentKind;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = DeribitHttpClient::new();
+    /// let rfqs = client.get_rfqs("BTC", Some(InstrumentKind::Option)).await?;
+    /// println!("Found {} active RFQs", rfqs.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_rfqs(
+        &self,
+        currency: impl AsRef<str>,
+        kind: Option<InstrumentKind>,
+    ) -> Result<Vec<crate::model::Rfq>, HttpError> {
+        let currency = currency.as_ref();
+        let mut query = format!("?currency={}", urlencoding::encode(currency));
+        if let Some(kind) = kind {
+            query.push_str(&format!("&kind={}", kind.as_str()));
+        }
+        self.public_get(crate::constants::endpoints::GET_RFQS, &query)
+            .await
+    }
+}
+
+/// Value of an instrument field the cached lookups need
+fn required(value: Option<f64>, instrument_name: &str, field: &str) -> Result<f64, HttpError> {
+    value.ok_or_else(|| {
+        HttpError::from(HttpErrorKind::InvalidResponse(format!(
+            "Instrument {} has no {}",
+            instrument_name, field
+        )))
+    })
 }
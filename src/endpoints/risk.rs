@@ -0,0 +1,133 @@
+//! Account-wide risk controls
+//!
+//! [`emergency_flatten`] is the panic button of an automated trader: it
+//! cancels every open order and closes every open position in the given
+//! currencies, reporting what happened to each of them in a
+//! [`FlattenReport`].
+//!
+//! [`emergency_flatten`]: DeribitHttpClient::emergency_flatten
+
+use crate::DeribitHttpClient;
+use crate::error::HttpError;
+use crate::model::response::order::OrderResponse;
+use futures_util::future::join_all;
+
+/// Outcome of [`DeribitHttpClient::emergency_flatten`]
+///
+/// Every step is attempted even when others fail, so check
+/// [`is_complete`](Self::is_complete) and [`failures`](Self::failures)
+/// before assuming the account is flat.
+#[derive(Debug)]
+pub struct FlattenReport {
+    /// Number of orders cancelled, per currency
+    pub cancellations: Vec<(String, Result<u32, HttpError>)>,
+    /// Currencies whose positions could not be listed, and so were not closed
+    pub position_errors: Vec<(String, HttpError)>,
+    /// Close order of each open position, per instrument
+    pub closes: Vec<(String, Result<OrderResponse, HttpError>)>,
+}
+
+impl FlattenReport {
+    /// Whether every cancellation, position lookup and close succeeded
+    pub fn is_complete(&self) -> bool {
+        self.failures().next().is_none()
+    }
+
+    /// Total number of orders cancelled
+    pub fn cancelled_orders(&self) -> u32 {
+        self.cancellations
+            .iter()
+            .filter_map(|(_, outcome)| outcome.as_ref().ok())
+            .sum()
+    }
+
+    /// Currency or instrument of each failed step, with its error
+    pub fn failures(&self) -> impl Iterator<Item = (&str, &HttpError)> {
+        let cancellations = self
+            .cancellations
+            .iter()
+            .filter_map(|(currency, outcome)| Some((currency.as_str(), outcome.as_ref().err()?)));
+        let lookups = self
+            .position_errors
+            .iter()
+            .map(|(currency, error)| (currency.as_str(), error));
+        let closes = self.closes.iter().filter_map(|(instrument, outcome)| {
+            Some((instrument.as_str(), outcome.as_ref().err()?))
+        });
+        cancellations.chain(lookups).chain(closes)
+    }
+}
+
+impl DeribitHttpClient {
+    /// Cancel all open orders and close all open positions in `currencies`
+    ///
+    /// First cancels every order in each currency, so resting orders cannot
+    /// reopen positions, then lists the positions and closes each non-zero
+    /// one with a reduce-only market order
+    /// ([`close_position_market`](Self::close_position_market)). Requests
+    /// within each step run concurrently, and a failure never stops the other
+    /// steps; the outcome of each is in the returned [`FlattenReport`].
+    ///
+    /// The requests skip the circuit breaker and a pending scope downgrade, so
+    /// an open circuit or an unacknowledged downgrade cannot stop the kill
+    /// switch.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use deribit_http::DeribitHttpClient;
+    ///
+    /// # async fn example(client: DeribitHttpClient) {
+    /// let report = client.emergency_flatten(&["BTC", "ETH"]).await;
+    /// for (target, error) in report.failures() {
+    ///     eprintln!("failed to flatten {}: {}", target, error);
+    /// }
+    /// # }
+    /// ```
+    pub async fn emergency_flatten(&self, currencies: &[impl AsRef<str>]) -> FlattenReport {
+        let client = self.emergency();
+        let client = &client;
+        let cancellations = join_all(currencies.iter().map(|currency| async move {
+            let currency = currency.as_ref();
+            (
+                currency.to_string(),
+                client.cancel_all_by_currency(currency).await,
+            )
+        }))
+        .await;
+
+        let lookups = join_all(currencies.iter().map(|currency| async move {
+            let currency = currency.as_ref();
+            (
+                currency.to_string(),
+                client.get_positions(Some(currency), None, None).await,
+            )
+        }))
+        .await;
+        let mut position_errors = Vec::new();
+        let mut open = Vec::new();
+        for (currency, lookup) in lookups {
+            match lookup {
+                Ok(positions) => open.extend(
+                    positions
+                        .into_iter()
+                        .filter(|position| position.size != 0.0)
+                        .map(|position| position.instrument_name),
+                ),
+                Err(e) => position_errors.push((currency, e)),
+            }
+        }
+
+        let closes = join_all(open.into_iter().map(|instrument| async move {
+            let outcome = client.close_position_market(&instrument).await;
+            (instrument, outcome)
+        }))
+        .await;
+
+        FlattenReport {
+            cancellations,
+            position_errors,
+            closes,
+        }
+    }
+}
@@ -0,0 +1,123 @@
+//! Multi-leg option strategy execution
+//!
+//! Resolves an [`OptionStrategy`] against the live option chain and executes
+//! it either as a single combo instrument ([`create_strategy_combo`]) or as
+//! one order per leg sharing a label ([`place_strategy_orders`]).
+//!
+//! [`create_strategy_combo`]: DeribitHttpClient::create_strategy_combo
+//! [`place_strategy_orders`]: DeribitHttpClient::place_strategy_orders
+
+use crate::DeribitHttpClient;
+use crate::error::HttpError;
+use crate::model::combo::{Combo, CreateComboRequest};
+use crate::model::instrument::InstrumentKind;
+use crate::model::order::{OrderSide, OrderType};
+use crate::model::request::order::OrderRequest;
+use crate::model::response::order::OrderResponse;
+use crate::model::strategy::{OptionStrategy, StrategyLeg};
+
+impl DeribitHttpClient {
+    /// Resolve a strategy's legs against the current option chain
+    ///
+    /// Fetches the active options for the strategy's currency and validates
+    /// strikes, expiries and amounts. See [`OptionStrategy::resolve`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use deribit_http::DeribitHttpClient;
+    /// use deribit_http::model::OptionStrategy;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = DeribitHttpClient::new();
+    /// let spread = OptionStrategy::bull_call_spread("BTC", "27DEC24", 60000.0, 70000.0, 1.0);
+    /// let legs = client.resolve_strategy(&spread).await?;
+    /// assert_eq!(legs.len(), 2);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn resolve_strategy(
+        &self,
+        strategy: &OptionStrategy,
+    ) -> Result<Vec<StrategyLeg>, HttpError> {
+        let instruments = self
+            .get_instruments(
+                strategy.currency(),
+                Some(InstrumentKind::Option),
+                Some(false),
+            )
+            .await?;
+        strategy.resolve(&instruments)
+    }
+
+    /// Create (or look up) the combo instrument for a strategy
+    ///
+    /// Resolves the legs and passes them to
+    /// [`create_combo`](DeribitHttpClient::create_combo). The returned combo
+    /// can then be traded as a single instrument.
+    pub async fn create_strategy_combo(
+        &self,
+        strategy: &OptionStrategy,
+    ) -> Result<Combo, HttpError> {
+        let legs = self.resolve_strategy(strategy).await?;
+        let request: CreateComboRequest = legs
+            .iter()
+            .map(StrategyLeg::to_combo_trade)
+            .collect::<Vec<_>>()
+            .into();
+        self.create_combo(&request).await
+    }
+
+    /// Execute a strategy as one market order per leg
+    ///
+    /// Every order carries `label`, so the legs can be found or cancelled
+    /// together. Legs are sent in order and execution stops at the first
+    /// failure; legs already placed are not unwound, so check open orders and
+    /// positions by `label` when this returns an error. Prefer
+    /// [`create_strategy_combo`](DeribitHttpClient::create_strategy_combo) when
+    /// the legs must fill atomically.
+    pub async fn place_strategy_orders(
+        &self,
+        strategy: &OptionStrategy,
+        label: &str,
+    ) -> Result<Vec<OrderResponse>, HttpError> {
+        let legs = self.resolve_strategy(strategy).await?;
+        let mut orders = Vec::with_capacity(legs.len());
+        for leg in legs {
+            let side = leg.side;
+            let request = leg_order_request(leg, label);
+            let order = match side {
+                OrderSide::Buy => self.buy_order(request).await?,
+                OrderSide::Sell => self.sell_order(request).await?,
+            };
+            orders.push(order);
+        }
+        Ok(orders)
+    }
+}
+
+fn leg_order_request(leg: StrategyLeg, label: &str) -> OrderRequest {
+    OrderRequest {
+        order_id: None,
+        instrument_name: leg.instrument_name,
+        amount: Some(leg.amount),
+        contracts: None,
+        type_: Some(OrderType::Market),
+        label: Some(label.to_string()),
+        price: None,
+        time_in_force: None,
+        display_amount: None,
+        post_only: None,
+        reject_post_only: None,
+        reduce_only: None,
+        trigger_price: None,
+        trigger_offset: None,
+        trigger: None,
+        advanced: None,
+        mmp: None,
+        valid_until: None,
+        linked_order_type: None,
+        trigger_fill_condition: None,
+        otoco_config: None,
+    }
+}
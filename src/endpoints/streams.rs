@@ -0,0 +1,166 @@
+//! Polling streams over the REST endpoints
+//!
+//! Websocket-like updates for pure-HTTP deployments: each stream polls an
+//! endpoint on a background task and yields only the results that changed
+//! ([`ticker_stream`], [`order_book_stream`], [`positions_stream`]). See
+//! [`PollingStream`] for the polling rules.
+//!
+//! [`ticker_stream`]: DeribitHttpClient::ticker_stream
+//! [`order_book_stream`]: DeribitHttpClient::order_book_stream
+//! [`positions_stream`]: DeribitHttpClient::positions_stream
+//! [`PollingStream`]: crate::polling::PollingStream
+
+use crate::DeribitHttpClient;
+use crate::model::book::OrderBook;
+use crate::model::position::{Position, PositionChange};
+use crate::model::ticker::TickerData;
+use crate::polling::PollingStream;
+use std::time::Duration;
+
+impl DeribitHttpClient {
+    /// Stream the ticker of `instrument` polled every `interval`
+    ///
+    /// Tickers whose timestamp has not advanced past the last one yielded
+    /// are skipped. Polls go through the client's rate limiter.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called outside a tokio runtime.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use deribit_http::DeribitHttpClient;
+    /// use deribit_http::prelude::StreamExt;
+    /// use std::time::Duration;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), deribit_http::HttpError> {
+    /// let client = DeribitHttpClient::new();
+    /// let mut tickers = client.ticker_stream("BTC-PERPETUAL", Duration::from_millis(500));
+    /// while let Some(ticker) = tickers.next().await {
+    ///     println!("{}", ticker?.mark_price);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn ticker_stream(
+        &self,
+        instrument: impl Into<String>,
+        interval: Duration,
+    ) -> PollingStream<TickerData> {
+        let client = self.clone();
+        let instrument = instrument.into();
+        PollingStream::spawn(
+            interval,
+            move || {
+                let client = client.clone();
+                let instrument = instrument.clone();
+                async move { client.get_ticker(&instrument).await }
+            },
+            |ticker: &TickerData| ticker.timestamp,
+        )
+    }
+
+    /// Stream the order book of `instrument` polled every `interval`
+    ///
+    /// `depth` is passed to `get_order_book`. Books whose `change_id` has not
+    /// advanced past the last one yielded are skipped, so each item is a book
+    /// that changed. Polls go through the client's rate limiter.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called outside a tokio runtime.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use deribit_http::DeribitHttpClient;
+    /// use deribit_http::prelude::StreamExt;
+    /// use std::time::Duration;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), deribit_http::HttpError> {
+    /// let client = DeribitHttpClient::new();
+    /// let mut books = client.order_book_stream("BTC-PERPETUAL", Some(10), Duration::from_secs(1));
+    /// while let Some(book) = books.next().await {
+    ///     let book = book?;
+    ///     println!("{} bids, {} asks", book.bids.len(), book.asks.len());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn order_book_stream(
+        &self,
+        instrument: impl Into<String>,
+        depth: Option<u32>,
+        interval: Duration,
+    ) -> PollingStream<OrderBook> {
+        let client = self.clone();
+        let instrument = instrument.into();
+        PollingStream::spawn(
+            interval,
+            move || {
+                let client = client.clone();
+                let instrument = instrument.clone();
+                async move { client.get_order_book(&instrument, depth).await }
+            },
+            |book: &OrderBook| book.change_id,
+        )
+    }
+
+    /// Stream the changes to the positions in `currency`, polled every `interval`
+    ///
+    /// Each item lists the [`PositionChange`]s since the previous poll, and
+    /// polls without changes are skipped. The first item reports every open
+    /// position as opened, so consumers start from the full picture.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called outside a tokio runtime.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use deribit_http::DeribitHttpClient;
+    /// use deribit_http::model::position::PositionChange;
+    /// use deribit_http::prelude::StreamExt;
+    /// use std::time::Duration;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), deribit_http::HttpError> {
+    /// let client = DeribitHttpClient::new();
+    /// let mut changes = client.positions_stream("BTC", Duration::from_secs(2));
+    /// while let Some(changes) = changes.next().await {
+    ///     for change in changes? {
+    ///         if let PositionChange::Closed(position) = change {
+    ///             println!("closed {}", position.instrument_name);
+    ///         }
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn positions_stream(
+        &self,
+        currency: impl Into<String>,
+        interval: Duration,
+    ) -> PollingStream<Vec<PositionChange>> {
+        let client = self.clone();
+        let currency = currency.into();
+        let mut previous: Vec<Position> = Vec::new();
+        PollingStream::spawn_map(
+            interval,
+            move || {
+                let client = client.clone();
+                let currency = currency.clone();
+                async move { client.get_positions(Some(&currency), None, None).await }
+            },
+            move |positions: Vec<Position>| {
+                let changes = PositionChange::between(&previous, &positions);
+                previous = positions;
+                (!changes.is_empty()).then_some(changes)
+            },
+        )
+    }
+}
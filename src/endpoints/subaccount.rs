@@ -0,0 +1,168 @@
+//! Subaccount-scoped view over the private endpoints
+//!
+//! Deribit lets a main-account token query some private methods on behalf of a
+//! subaccount by passing `subaccount_id`. [`SubaccountClient`] pins that id once
+//! so callers don't have to thread it through every request.
+//!
+//! Only the methods that accept `subaccount_id` on Deribit are exposed here:
+//! account summaries, positions, the transaction log and user trades by
+//! currency. Open orders, order history and wallet queries (deposits,
+//! withdrawals, transfers) have no such parameter; to read those for a
+//! subaccount, obtain a subaccount token with
+//! [`exchange_token`](DeribitHttpClient::exchange_token) instead.
+
+use crate::DeribitHttpClient;
+use crate::error::{HttpError, HttpErrorKind};
+use crate::model::instrument::InstrumentKind;
+use crate::model::position::Position;
+use crate::model::request::trade::TradesRequest;
+use crate::model::response::other::{
+    AccountSummariesResponse, AccountSummaryResponse, TransactionLogResponse,
+};
+use crate::model::{TransactionLogRequest, UserTradeWithPaginationResponse};
+
+/// Private endpoints scoped to a single subaccount
+///
+/// Created with [`DeribitHttpClient::for_subaccount`]. Shares the underlying
+/// connection pool, rate limiter and authentication state with the parent client.
+#[derive(Debug, Clone)]
+pub struct SubaccountClient {
+    client: DeribitHttpClient,
+    subaccount_id: u64,
+}
+
+impl DeribitHttpClient {
+    /// Scope private queries to a subaccount
+    ///
+    /// Returns a [`SubaccountClient`] that adds `subaccount_id` to every
+    /// request that supports it. The client must be authenticated as the main
+    /// account.
+    ///
+    /// # Arguments
+    ///
+    /// * `subaccount_id` - The user id of the subaccount
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use deribit_http::DeribitHttpClient;
+    ///
+    /// let client = DeribitHttpClient::new();
+    /// let sub = client.for_subaccount(12345);
+    /// assert_eq!(sub.subaccount_id(), 12345);
+    /// // let summary = sub.get_account_summary("BTC", None).await?;
+    /// ```
+    pub fn for_subaccount(&self, subaccount_id: u64) -> SubaccountClient {
+        SubaccountClient {
+            client: self.clone(),
+            subaccount_id,
+        }
+    }
+}
+
+impl SubaccountClient {
+    /// The subaccount id applied to requests
+    pub fn subaccount_id(&self) -> u64 {
+        self.subaccount_id
+    }
+
+    /// The parent client, for endpoints that are not subaccount-scoped
+    pub fn client(&self) -> &DeribitHttpClient {
+        &self.client
+    }
+
+    /// Get the subaccount's account summary for a currency
+    ///
+    /// See [`DeribitHttpClient::get_account_summary_for_subaccount`].
+    pub async fn get_account_summary(
+        &self,
+        currency: impl AsRef<str>,
+        extended: Option<bool>,
+    ) -> Result<AccountSummaryResponse, HttpError> {
+        let currency = currency.as_ref();
+        self.client
+            .get_account_summary_for_subaccount(currency, self.subaccount_id, extended)
+            .await
+    }
+
+    /// Get the subaccount's account summaries for all currencies
+    ///
+    /// See [`DeribitHttpClient::get_account_summaries`].
+    pub async fn get_account_summaries(
+        &self,
+        extended: Option<bool>,
+    ) -> Result<AccountSummariesResponse, HttpError> {
+        let subaccount_id: i64 = subaccount_id(self.subaccount_id)?;
+        self.client
+            .get_account_summaries(Some(subaccount_id), extended)
+            .await
+    }
+
+    /// Get the subaccount's open positions
+    ///
+    /// See [`DeribitHttpClient::get_positions`].
+    pub async fn get_positions(
+        &self,
+        currency: Option<impl AsRef<str>>,
+        kind: Option<InstrumentKind>,
+    ) -> Result<Vec<Position>, HttpError> {
+        let subaccount_id: i32 = subaccount_id(self.subaccount_id)?;
+        self.client
+            .get_positions(currency, kind, Some(subaccount_id))
+            .await
+    }
+
+    /// Get the subaccount's transaction log
+    ///
+    /// Any `subaccount_id` set on `request` is overridden.
+    /// See [`DeribitHttpClient::get_transaction_log`].
+    pub async fn get_transaction_log(
+        &self,
+        mut request: TransactionLogRequest,
+    ) -> Result<TransactionLogResponse, HttpError> {
+        request.subaccount_id = Some(self.subaccount_id);
+        self.client.get_transaction_log(request).await
+    }
+
+    /// Get the subaccount's trades by currency
+    ///
+    /// Any `subaccount_id` set on `request` is overridden.
+    /// See [`DeribitHttpClient::get_user_trades_by_currency`].
+    pub async fn get_user_trades_by_currency(
+        &self,
+        request: TradesRequest,
+    ) -> Result<UserTradeWithPaginationResponse, HttpError> {
+        let request = self.scope_trades_request(request)?;
+        self.client.get_user_trades_by_currency(request).await
+    }
+
+    /// Get the subaccount's trades by currency and time range
+    ///
+    /// Any `subaccount_id` set on `request` is overridden.
+    /// See [`DeribitHttpClient::get_user_trades_by_currency_and_time`].
+    pub async fn get_user_trades_by_currency_and_time(
+        &self,
+        request: TradesRequest,
+    ) -> Result<UserTradeWithPaginationResponse, HttpError> {
+        let request = self.scope_trades_request(request)?;
+        self.client
+            .get_user_trades_by_currency_and_time(request)
+            .await
+    }
+
+    fn scope_trades_request(&self, mut request: TradesRequest) -> Result<TradesRequest, HttpError> {
+        let subaccount_id: u32 = subaccount_id(self.subaccount_id)?;
+        request.subaccount_id = Some(subaccount_id);
+        Ok(request)
+    }
+}
+
+/// `id` as the integer type an endpoint takes
+fn subaccount_id<T: TryFrom<u64>>(id: u64) -> Result<T, HttpError> {
+    T::try_from(id).map_err(|_| {
+        HttpError::from(HttpErrorKind::ConfigError(format!(
+            "Subaccount id out of range: {}",
+            id
+        )))
+    })
+}
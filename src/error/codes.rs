@@ -0,0 +1,151 @@
+//! Well-known Deribit API error codes
+//!
+//! [`DeribitErrorCode`] names the codes callers most often need to branch on.
+//! Codes not listed here are still available as the raw `code` of
+//! [`HttpErrorKind::Api`](crate::HttpErrorKind::Api).
+
+macro_rules! deribit_error_codes {
+    ($($(#[$doc:meta])* $variant:ident = $code:literal => $name:literal,)+) => {
+        /// Well-known Deribit error code
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        #[non_exhaustive]
+        pub enum DeribitErrorCode {
+            $($(#[$doc])* $variant,)+
+        }
+
+        impl DeribitErrorCode {
+            /// The error for a numeric code, if it is a well-known one
+            pub fn from_code(code: i64) -> Option<Self> {
+                match code {
+                    $($code => Some(DeribitErrorCode::$variant),)+
+                    _ => None,
+                }
+            }
+
+            /// Numeric Deribit error code
+            pub fn code(&self) -> i64 {
+                match self {
+                    $(DeribitErrorCode::$variant => $code,)+
+                }
+            }
+
+            /// Deribit's name for the error, e.g. `"not_enough_funds"`
+            pub fn as_str(&self) -> &'static str {
+                match self {
+                    $(DeribitErrorCode::$variant => $name,)+
+                }
+            }
+        }
+    };
+}
+
+deribit_error_codes! {
+    /// 10000: the method requires authentication
+    AuthorizationRequired = 10000 => "authorization_required",
+    /// 10001: generic error
+    Error = 10001 => "error",
+    /// 10002: order quantity below the minimum
+    QtyTooLow = 10002 => "qty_too_low",
+    /// 10003: the order would overlap an existing one
+    OrderOverlap = 10003 => "order_overlap",
+    /// 10004: no such order
+    OrderNotFound = 10004 => "order_not_found",
+    /// 10005: price below the price band
+    PriceTooLow = 10005 => "price_too_low",
+    /// 10006: price too low relative to the index
+    PriceTooLow4Idx = 10006 => "price_too_low4idx",
+    /// 10007: price above the price band
+    PriceTooHigh = 10007 => "price_too_high",
+    /// 10008: price too high relative to the index
+    PriceTooHigh4Idx = 10008 => "price_too_high4idx",
+    /// 10009: insufficient funds for the order or withdrawal
+    NotEnoughFunds = 10009 => "not_enough_funds",
+    /// 10010: the order is already closed
+    AlreadyClosed = 10010 => "already_closed",
+    /// 10011: price not allowed for this order
+    PriceNotAllowed = 10011 => "price_not_allowed",
+    /// 10012: the order book is closed
+    BookClosed = 10012 => "book_closed",
+    /// 10019: the account is locked by an administrator
+    LockedByAdmin = 10019 => "locked_by_admin",
+    /// 10020: unknown or unsupported instrument
+    InvalidOrUnsupportedInstrument = 10020 => "invalid_or_unsupported_instrument",
+    /// 10021: invalid amount
+    InvalidAmount = 10021 => "invalid_amount",
+    /// 10022: invalid quantity
+    InvalidQuantity = 10022 => "invalid_quantity",
+    /// 10023: invalid price
+    InvalidPrice = 10023 => "invalid_price",
+    /// 10024: invalid `max_show`
+    InvalidMaxShow = 10024 => "invalid_max_show",
+    /// 10025: invalid order id
+    InvalidOrderId = 10025 => "invalid_order_id",
+    /// 10026: price has more decimals than the instrument allows
+    PricePrecisionExceeded = 10026 => "price_precision_exceeded",
+    /// 10027: contract amount must be an integer
+    NonIntegerContractAmount = 10027 => "non_integer_contract_amount",
+    /// 10028: credit pool exhausted
+    TooManyRequests = 10028 => "too_many_requests",
+    /// 10029: the order belongs to another user
+    NotOwnerOfOrder = 10029 => "not_owner_of_order",
+    /// 10030: the method is only available over WebSocket
+    MustBeWebsocketRequest = 10030 => "must_be_websocket_request",
+    /// 10031: arguments not valid for this instrument
+    InvalidArgsForInstrument = 10031 => "invalid_args_for_instrument",
+    /// 10032: total order cost below the minimum
+    WholeCostTooLow = 10032 => "whole_cost_too_low",
+    /// 10033: the method is not implemented
+    NotImplemented = 10033 => "not_implemented",
+    /// 10034: trigger price above the allowed range
+    TriggerPriceTooHigh = 10034 => "trigger_price_too_high",
+    /// 10035: trigger price below the allowed range
+    TriggerPriceTooLow = 10035 => "trigger_price_too_low",
+    /// 10040: the request can't be processed right now, retry later
+    Retry = 10040 => "retry",
+    /// 10041: settlement in progress
+    SettlementInProgress = 10041 => "settlement_in_progress",
+    /// 10043: price is not a multiple of the tick size
+    PriceWrongTick = 10043 => "price_wrong_tick",
+    /// 10044: trigger price is not a multiple of the tick size
+    TriggerPriceWrongTick = 10044 => "trigger_price_wrong_tick",
+    /// 10045: liquidation orders can't be cancelled
+    CanNotCancelLiquidationOrder = 10045 => "can_not_cancel_liquidation_order",
+    /// 10046: liquidation orders can't be edited
+    CanNotEditLiquidationOrder = 10046 => "can_not_edit_liquidation_order",
+    /// 10047: the matching engine queue is full
+    MatchingEngineQueueFull = 10047 => "matching_engine_queue_full",
+    /// 11008: the order is already filled
+    AlreadyFilled = 11008 => "already_filled",
+    /// 11029: invalid arguments
+    InvalidArguments = 11029 => "invalid_arguments",
+    /// 11042: the API key lacks the required scope
+    PermissionDenied = 11042 => "permission_denied",
+    /// 11044: the order is not open
+    NotOpenOrder = 11044 => "not_open_order",
+    /// 11050: malformed request
+    BadRequest = 11050 => "bad_request",
+    /// 11051: the platform is under maintenance
+    SystemMaintenance = 11051 => "system_maintenance",
+    /// 11094: internal server error
+    InternalServerError = 11094 => "internal_server_error",
+    /// 13004: wrong client id or secret
+    InvalidCredentials = 13004 => "invalid_credentials",
+    /// 13009: invalid or expired token
+    Unauthorized = 13009 => "unauthorized",
+    /// 13028: the service is temporarily unavailable
+    TemporarilyUnavailable = 13028 => "temporarily_unavailable",
+    /// -32600: request entity too large
+    RequestEntityTooLarge = -32600 => "request_entity_too_large",
+    /// -32601: unknown method
+    MethodNotFound = -32601 => "method_not_found",
+    /// -32602: invalid parameters
+    InvalidParams = -32602 => "invalid_params",
+    /// -32000: missing parameters
+    MissingParams = -32000 => "missing_params",
+}
+
+impl std::fmt::Display for DeribitErrorCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} ({})", self.as_str(), self.code())
+    }
+}
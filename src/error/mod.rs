@@ -1,8 +1,42 @@
 //! Error handling module for HTTP client
 
+use crate::auth::ScopeDowngrade;
+use crate::model::request::order::OrderValidationError;
+use crate::model::types::ApiError;
+use crate::rate_limit::TOO_MANY_REQUESTS_CODE;
+use std::fmt;
+use std::time::Duration;
+
+mod codes;
+
+pub use codes::DeribitErrorCode;
+
+/// HTTP client error
+///
+/// What went wrong is in [`kind`](Self::kind); errors returned by the
+/// client's endpoints also carry the [`ErrorContext`] of the failed call.
+///
+/// # Examples
+///
+/// ```rust
+/// use deribit_http::{ErrorContext, HttpError, HttpErrorKind};
+///
+/// let error = HttpError::from(HttpErrorKind::NetworkError("connection reset".to_string()))
+///     .with_context(ErrorContext::for_endpoint("/public/ticker"));
+/// assert!(matches!(error.kind(), HttpErrorKind::NetworkError(_)));
+/// assert_eq!(error.endpoint(), Some("public/ticker"));
+/// assert_eq!(error.to_string(), "Network error: connection reset (public/ticker)");
+/// ```
+#[derive(Debug, Clone)]
+pub struct HttpError {
+    kind: HttpErrorKind,
+    // Boxed to keep `Result<T, HttpError>` small
+    context: Box<ErrorContext>,
+}
+
 /// HTTP client error types
-#[derive(Debug, thiserror::Error)]
-pub enum HttpError {
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum HttpErrorKind {
     /// Config error
     #[error("Configuration error: {0}")]
     ConfigError(String),
@@ -15,9 +49,25 @@ pub enum HttpError {
     #[error("Authentication failed: {0}")]
     AuthenticationFailed(String),
 
-    /// API rate limit has been exceeded
+    /// API rate limit has been exceeded (HTTP 429 or `too_many_requests`, 10028)
     #[error("Rate limit exceeded")]
-    RateLimitExceeded,
+    RateLimitExceeded {
+        /// How long to wait before retrying, from the `Retry-After` header
+        retry_after: Option<Duration>,
+    },
+
+    /// Resource or method not found (HTTP 404)
+    #[error("Not found: {0}")]
+    NotFound(String),
+
+    /// Server-side failure (HTTP 5xx)
+    #[error("Server error {status}: {message}")]
+    ServerError {
+        /// HTTP status code
+        status: u16,
+        /// Error message or response body
+        message: String,
+    },
 
     /// Invalid response format received from API
     #[error("Invalid response format: {0}")]
@@ -30,4 +80,230 @@ pub enum HttpError {
     /// Error parsing
     #[error("Parse error: {0}")]
     ParseError(String),
+
+    /// The client's circuit breaker is open; the request was not sent
+    #[error("Circuit open, retry in {retry_after:?}")]
+    CircuitOpen {
+        /// Time left until the breaker lets a probe request through
+        retry_after: Duration,
+    },
+
+    /// Token scopes were reduced by the last (re-)authentication
+    #[error("Token scope downgraded: {0}")]
+    ScopeDowngraded(ScopeDowngrade),
+
+    /// Error object returned by the Deribit API, whatever the HTTP status
+    #[error("API error: {code} - {message}")]
+    Api {
+        /// Deribit error code
+        code: i64,
+        /// Error message
+        message: String,
+        /// Additional error data, e.g. the offending parameter
+        data: Option<serde_json::Value>,
+    },
+}
+
+/// Where an [`HttpError`] happened
+///
+/// Errors returned by the client's endpoints carry whatever was known when
+/// they occurred: a transport error has no HTTP status, and responses without
+/// a JSON-RPC `id` have no request id. When the HTTP status decides the
+/// [`HttpErrorKind`] (401/403, 404, 429, 5xx) or a `too_many_requests` error
+/// becomes [`HttpErrorKind::RateLimitExceeded`], the Deribit error object is
+/// kept here.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ErrorContext {
+    /// JSON-RPC method of the call, e.g. `private/buy`
+    pub endpoint: Option<String>,
+    /// HTTP status of the response
+    pub status: Option<u16>,
+    /// JSON-RPC id of the request
+    pub request_id: Option<u64>,
+    /// Deribit JSON-RPC error object in the response
+    pub api_error: Option<Box<ApiError>>,
+}
+
+impl ErrorContext {
+    /// Context of a call to `endpoint`, with or without its leading `/`
+    pub fn for_endpoint(endpoint: &str) -> Self {
+        Self {
+            endpoint: Some(endpoint.trim_start_matches('/').to_string()),
+            ..Self::default()
+        }
+    }
+
+    /// Set the HTTP status
+    pub fn with_status(mut self, status: u16) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Set the JSON-RPC request id, if known
+    pub fn with_request_id(mut self, request_id: Option<u64>) -> Self {
+        self.request_id = request_id;
+        self
+    }
+
+    /// Set the Deribit error object
+    pub fn with_api_error(mut self, error: &ApiError) -> Self {
+        self.api_error = Some(Box::new(error.clone()));
+        self
+    }
+
+    /// Whether nothing is known about the call
+    pub fn is_empty(&self) -> bool {
+        self == &Self::default()
+    }
+
+    /// Fill the fields this context lacks from `other`
+    fn or(self, other: Self) -> Self {
+        Self {
+            endpoint: self.endpoint.or(other.endpoint),
+            status: self.status.or(other.status),
+            request_id: self.request_id.or(other.request_id),
+            api_error: self.api_error.or(other.api_error),
+        }
+    }
+}
+
+impl fmt::Display for ErrorContext {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut parts = Vec::new();
+        if let Some(endpoint) = &self.endpoint {
+            parts.push(endpoint.clone());
+        }
+        if let Some(status) = self.status {
+            parts.push(format!("HTTP {}", status));
+        }
+        if let Some(request_id) = self.request_id {
+            parts.push(format!("request id {}", request_id));
+        }
+        write!(f, "{}", parts.join(", "))
+    }
+}
+
+impl HttpError {
+    /// What went wrong; match on this to tell errors apart
+    pub fn kind(&self) -> &HttpErrorKind {
+        &self.kind
+    }
+
+    /// Take what went wrong, dropping the [`ErrorContext`]
+    pub fn into_kind(self) -> HttpErrorKind {
+        self.kind
+    }
+
+    /// Code of the Deribit error object behind this error
+    ///
+    /// Taken from an [`HttpErrorKind::Api`] error, or from the
+    /// [`ErrorContext`] when the HTTP status decided the kind, so an expired
+    /// token (13009) or an exhausted credit pool (10028) stays recognisable.
+    pub fn api_code(&self) -> Option<i64> {
+        match &self.kind {
+            HttpErrorKind::Api { code, .. } => Some(*code),
+            _ => self.context.api_error.as_ref().map(|e| i64::from(e.code)),
+        }
+    }
+
+    /// The well-known Deribit error code behind this error
+    ///
+    /// See [`api_code`](Self::api_code).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use deribit_http::{DeribitErrorCode, HttpError, HttpErrorKind};
+    ///
+    /// let error = HttpError::from(HttpErrorKind::Api {
+    ///     code: 10009,
+    ///     message: "not_enough_funds".to_string(),
+    ///     data: None,
+    /// });
+    /// assert_eq!(error.error_code(), Some(DeribitErrorCode::NotEnoughFunds));
+    /// ```
+    pub fn error_code(&self) -> Option<DeribitErrorCode> {
+        self.api_code().and_then(DeribitErrorCode::from_code)
+    }
+
+    /// Attach `context` to this error
+    ///
+    /// Fields the error already carries win, so context added on the way
+    /// out of a call only fills the gaps left by the layers below it.
+    pub fn with_context(mut self, context: ErrorContext) -> Self {
+        *self.context = std::mem::take(&mut *self.context).or(context);
+        self
+    }
+
+    /// Endpoint, HTTP status, request id and Deribit error of the failed call
+    pub fn context(&self) -> &ErrorContext {
+        &self.context
+    }
+
+    /// JSON-RPC method of the failed call, e.g. `public/ticker`
+    pub fn endpoint(&self) -> Option<&str> {
+        self.context.endpoint.as_deref()
+    }
+
+    /// HTTP status of the response that produced the error
+    pub fn status(&self) -> Option<u16> {
+        self.context.status.or(match &self.kind {
+            HttpErrorKind::ServerError { status, .. } => Some(*status),
+            _ => None,
+        })
+    }
+
+    /// JSON-RPC id of the failed request
+    pub fn request_id(&self) -> Option<u64> {
+        self.context.request_id
+    }
+}
+
+impl fmt::Display for HttpError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // The Deribit error object is not part of the context's text
+        let context = self.context.to_string();
+        if context.is_empty() {
+            write!(f, "{}", self.kind)
+        } else {
+            write!(f, "{} ({})", self.kind, context)
+        }
+    }
+}
+
+impl std::error::Error for HttpError {}
+
+impl From<HttpErrorKind> for HttpError {
+    fn from(kind: HttpErrorKind) -> Self {
+        Self {
+            kind,
+            context: Box::default(),
+        }
+    }
+}
+
+impl From<ApiError> for HttpErrorKind {
+    fn from(error: ApiError) -> Self {
+        if i64::from(error.code) == TOO_MANY_REQUESTS_CODE {
+            return HttpErrorKind::RateLimitExceeded { retry_after: None };
+        }
+        HttpErrorKind::Api {
+            code: i64::from(error.code),
+            message: error.message,
+            data: error.data,
+        }
+    }
+}
+
+impl From<ApiError> for HttpError {
+    fn from(error: ApiError) -> Self {
+        let context = ErrorContext::default().with_api_error(&error);
+        HttpError::from(HttpErrorKind::from(error)).with_context(context)
+    }
+}
+
+impl From<OrderValidationError> for HttpError {
+    fn from(error: OrderValidationError) -> Self {
+        HttpErrorKind::ConfigError(format!("Invalid order: {}", error)).into()
+    }
 }
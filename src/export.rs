@@ -0,0 +1,449 @@
+//! CSV and Parquet export of candles, trades and transaction log entries
+//!
+//! Enabled by the `export` feature. Each exportable type converts into a
+//! [`Table`] of typed columns, which [`write_csv`] and [`write_parquet`]
+//! serialize to any writer, and [`export_csv`] and [`export_parquet`] to a
+//! file. Timestamps are exported as milliseconds since the Unix epoch and
+//! enums as their serialized names.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! use deribit_http::DeribitHttpClient;
+//! use deribit_http::export::{export_csv, export_parquet};
+//! use deribit_http::model::{Resolution, TimeRange};
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let client = DeribitHttpClient::new();
+//! let candles = client
+//!     .download_candles("BTC-PERPETUAL", TimeRange::last_hours(24), Resolution::Minute1)
+//!     .await?;
+//! export_csv(&candles, "btc-perpetual-1m.csv")?;
+//! export_parquet(&candles, "btc-perpetual-1m.parquet")?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! [`Table`]: crate::export::Table
+//! [`write_csv`]: crate::export::write_csv
+//! [`write_parquet`]: crate::export::write_parquet
+//! [`export_csv`]: crate::export::export_csv
+//! [`export_parquet`]: crate::export::export_parquet
+
+use crate::model::trade::{LastTrade, Trade, UserTrade};
+use crate::model::tradingview::{Candle, TradingViewChartData};
+use crate::model::transaction::TransactionLogEntry;
+use arrow_array::{
+    ArrayRef, BooleanArray, Float64Array, Int64Array, RecordBatch, StringArray, UInt64Array,
+};
+use arrow_schema::{DataType, Field, Schema};
+use parquet::arrow::ArrowWriter;
+use serde::Serialize;
+use std::fs::File;
+use std::io::Write;
+use std::path::Path;
+use std::sync::Arc;
+use thiserror::Error;
+
+/// Failure to write an export
+#[derive(Error, Debug)]
+pub enum ExportError {
+    /// The file could not be created or written
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+    /// The CSV writer failed
+    #[error("CSV error: {0}")]
+    Csv(String),
+    /// The Parquet writer failed
+    #[error("Parquet error: {0}")]
+    Parquet(String),
+}
+
+/// Values of one column, `None` for missing fields
+#[derive(Debug, Clone, PartialEq)]
+pub enum Column {
+    /// Unsigned integers, e.g. timestamps and ids
+    UInt64(Vec<Option<u64>>),
+    /// Signed integers
+    Int64(Vec<Option<i64>>),
+    /// Floating point numbers, e.g. prices and amounts
+    Float64(Vec<Option<f64>>),
+    /// Text, e.g. names and enum values
+    Utf8(Vec<Option<String>>),
+    /// Flags
+    Boolean(Vec<Option<bool>>),
+}
+
+impl Column {
+    /// Number of values
+    pub fn len(&self) -> usize {
+        match self {
+            Column::UInt64(values) => values.len(),
+            Column::Int64(values) => values.len(),
+            Column::Float64(values) => values.len(),
+            Column::Utf8(values) => values.len(),
+            Column::Boolean(values) => values.len(),
+        }
+    }
+
+    /// Whether the column has no values
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn cell(&self, row: usize) -> String {
+        match self {
+            Column::UInt64(values) => values[row].map(|v| v.to_string()),
+            Column::Int64(values) => values[row].map(|v| v.to_string()),
+            Column::Float64(values) => values[row].map(|v| v.to_string()),
+            Column::Utf8(values) => values[row].clone(),
+            Column::Boolean(values) => values[row].map(|v| v.to_string()),
+        }
+        .unwrap_or_default()
+    }
+
+    fn data_type(&self) -> DataType {
+        match self {
+            Column::UInt64(_) => DataType::UInt64,
+            Column::Int64(_) => DataType::Int64,
+            Column::Float64(_) => DataType::Float64,
+            Column::Utf8(_) => DataType::Utf8,
+            Column::Boolean(_) => DataType::Boolean,
+        }
+    }
+
+    fn to_array(&self) -> ArrayRef {
+        match self {
+            Column::UInt64(values) => Arc::new(UInt64Array::from(values.clone())),
+            Column::Int64(values) => Arc::new(Int64Array::from(values.clone())),
+            Column::Float64(values) => Arc::new(Float64Array::from(values.clone())),
+            Column::Utf8(values) => Arc::new(StringArray::from(values.clone())),
+            Column::Boolean(values) => Arc::new(BooleanArray::from(values.clone())),
+        }
+    }
+}
+
+/// Named columns of equal length, the common form of every export
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Table {
+    columns: Vec<(&'static str, Column)>,
+}
+
+impl Table {
+    /// Empty table
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a column
+    ///
+    /// # Panics
+    ///
+    /// Panics if the column's length differs from the columns added before it.
+    pub fn with_column(mut self, name: &'static str, column: Column) -> Self {
+        if let Some((first, existing)) = self.columns.first() {
+            assert_eq!(
+                existing.len(),
+                column.len(),
+                "column {} has {} values, {} has {}",
+                name,
+                column.len(),
+                first,
+                existing.len()
+            );
+        }
+        self.columns.push((name, column));
+        self
+    }
+
+    /// Column names, in order
+    pub fn column_names(&self) -> Vec<&'static str> {
+        self.columns.iter().map(|(name, _)| *name).collect()
+    }
+
+    /// Column by name
+    pub fn column(&self, name: &str) -> Option<&Column> {
+        self.columns
+            .iter()
+            .find(|(column, _)| *column == name)
+            .map(|(_, column)| column)
+    }
+
+    /// Number of rows
+    pub fn num_rows(&self) -> usize {
+        self.columns.first().map_or(0, |(_, column)| column.len())
+    }
+
+    fn record_batch(&self) -> Result<RecordBatch, ExportError> {
+        let schema = Schema::new(
+            self.columns
+                .iter()
+                .map(|(name, column)| Field::new(*name, column.data_type(), true))
+                .collect::<Vec<_>>(),
+        );
+        let arrays = self
+            .columns
+            .iter()
+            .map(|(_, column)| column.to_array())
+            .collect();
+        RecordBatch::try_new(Arc::new(schema), arrays)
+            .map_err(|e| ExportError::Parquet(e.to_string()))
+    }
+}
+
+/// Data that can be exported as a [`Table`]
+pub trait ToTable {
+    /// One row per record, one column per field
+    fn to_table(&self) -> Table;
+}
+
+impl<T> ToTable for Vec<T>
+where
+    [T]: ToTable,
+{
+    fn to_table(&self) -> Table {
+        self.as_slice().to_table()
+    }
+}
+
+/// Write `data` as CSV, with a header row
+///
+/// # Errors
+///
+/// Returns `ExportError` if writing fails.
+pub fn write_csv<D: ToTable + ?Sized>(data: &D, writer: impl Write) -> Result<(), ExportError> {
+    let table = data.to_table();
+    let mut csv = csv::Writer::from_writer(writer);
+    let csv_error = |e: csv::Error| ExportError::Csv(e.to_string());
+    csv.write_record(table.column_names()).map_err(csv_error)?;
+    for row in 0..table.num_rows() {
+        csv.write_record(table.columns.iter().map(|(_, column)| column.cell(row)))
+            .map_err(csv_error)?;
+    }
+    csv.flush()?;
+    Ok(())
+}
+
+/// Write `data` as a Parquet file, one row group, Snappy compressed
+///
+/// # Errors
+///
+/// Returns `ExportError` if writing fails.
+pub fn write_parquet<D: ToTable + ?Sized>(
+    data: &D,
+    writer: impl Write + Send,
+) -> Result<(), ExportError> {
+    let batch = data.to_table().record_batch()?;
+    let parquet_error = |e: parquet::errors::ParquetError| ExportError::Parquet(e.to_string());
+    let properties = parquet::file::properties::WriterProperties::builder()
+        .set_compression(parquet::basic::Compression::SNAPPY)
+        .build();
+    let mut parquet =
+        ArrowWriter::try_new(writer, batch.schema(), Some(properties)).map_err(parquet_error)?;
+    parquet.write(&batch).map_err(parquet_error)?;
+    parquet.close().map_err(parquet_error)?;
+    Ok(())
+}
+
+/// Write `data` to a CSV file at `path`, replacing it if it exists
+///
+/// # Errors
+///
+/// Returns `ExportError` if the file cannot be created or written.
+pub fn export_csv<D: ToTable + ?Sized>(
+    data: &D,
+    path: impl AsRef<Path>,
+) -> Result<(), ExportError> {
+    write_csv(data, File::create(path)?)
+}
+
+/// Write `data` to a Parquet file at `path`, replacing it if it exists
+///
+/// # Errors
+///
+/// Returns `ExportError` if the file cannot be created or written.
+pub fn export_parquet<D: ToTable + ?Sized>(
+    data: &D,
+    path: impl AsRef<Path>,
+) -> Result<(), ExportError> {
+    write_parquet(data, File::create(path)?)
+}
+
+/// Serialized name of an enum value, e.g. `T` for `Liquidity::Taker`
+fn serialized_name<T: Serialize>(value: &T) -> Option<String> {
+    match serde_json::to_value(value).ok()? {
+        serde_json::Value::String(name) => Some(name),
+        serde_json::Value::Null => None,
+        other => Some(other.to_string()),
+    }
+}
+
+fn strings<T>(rows: &[T], field: impl Fn(&T) -> Option<String>) -> Column {
+    Column::Utf8(rows.iter().map(field).collect())
+}
+
+fn floats<T>(rows: &[T], field: impl Fn(&T) -> Option<f64>) -> Column {
+    Column::Float64(rows.iter().map(field).collect())
+}
+
+fn unsigned<T>(rows: &[T], field: impl Fn(&T) -> Option<u64>) -> Column {
+    Column::UInt64(rows.iter().map(field).collect())
+}
+
+fn signed<T>(rows: &[T], field: impl Fn(&T) -> Option<i64>) -> Column {
+    Column::Int64(rows.iter().map(field).collect())
+}
+
+fn flags<T>(rows: &[T], field: impl Fn(&T) -> Option<bool>) -> Column {
+    Column::Boolean(rows.iter().map(field).collect())
+}
+
+impl ToTable for TradingViewChartData {
+    fn to_table(&self) -> Table {
+        let rows = self.len();
+        let column =
+            |values: &[f64]| Column::Float64((0..rows).map(|i| values.get(i).copied()).collect());
+        Table::new()
+            .with_column(
+                "timestamp",
+                Column::UInt64(self.ticks.iter().map(|tick| Some(tick.0)).collect()),
+            )
+            .with_column("open", column(&self.open))
+            .with_column("high", column(&self.high))
+            .with_column("low", column(&self.low))
+            .with_column("close", column(&self.close))
+            .with_column("volume", column(&self.volume))
+            .with_column("cost", column(&self.cost))
+    }
+}
+
+impl ToTable for [Candle] {
+    fn to_table(&self) -> Table {
+        Table::new()
+            .with_column("timestamp", unsigned(self, |c| Some(c.timestamp.0)))
+            .with_column("open", floats(self, |c| Some(c.open)))
+            .with_column("high", floats(self, |c| Some(c.high)))
+            .with_column("low", floats(self, |c| Some(c.low)))
+            .with_column("close", floats(self, |c| Some(c.close)))
+            .with_column("volume", floats(self, |c| Some(c.volume)))
+            .with_column("cost", floats(self, |c| Some(c.cost)))
+    }
+}
+
+impl ToTable for [LastTrade] {
+    fn to_table(&self) -> Table {
+        Table::new()
+            .with_column("timestamp", unsigned(self, |t| Some(t.timestamp.0)))
+            .with_column("trade_id", strings(self, |t| Some(t.trade_id.clone())))
+            .with_column("trade_seq", unsigned(self, |t| Some(t.trade_seq)))
+            .with_column(
+                "instrument_name",
+                strings(self, |t| Some(t.instrument_name.clone())),
+            )
+            .with_column("direction", strings(self, |t| Some(t.direction.clone())))
+            .with_column("price", floats(self, |t| Some(t.price)))
+            .with_column("amount", floats(self, |t| Some(t.amount)))
+            .with_column("index_price", floats(self, |t| Some(t.index_price)))
+            .with_column("iv", floats(self, |t| t.iv))
+            .with_column(
+                "tick_direction",
+                signed(self, |t| Some(t.tick_direction.into())),
+            )
+            .with_column("liquidity", strings(self, |t| t.liquid.clone()))
+    }
+}
+
+impl ToTable for [Trade] {
+    fn to_table(&self) -> Table {
+        Table::new()
+            .with_column("timestamp", unsigned(self, |t| Some(t.timestamp.0)))
+            .with_column("trade_id", strings(self, |t| Some(t.trade_id.clone())))
+            .with_column("trade_seq", unsigned(self, |t| t.trade_seq))
+            .with_column(
+                "instrument_name",
+                strings(self, |t| Some(t.instrument_name.clone())),
+            )
+            .with_column("order_id", strings(self, |t| Some(t.order_id.clone())))
+            .with_column(
+                "direction",
+                strings(self, |t| serialized_name(&t.direction)),
+            )
+            .with_column("price", floats(self, |t| Some(t.price)))
+            .with_column("amount", floats(self, |t| Some(t.amount)))
+            .with_column("fee", floats(self, |t| Some(t.fee)))
+            .with_column(
+                "fee_currency",
+                strings(self, |t| Some(t.fee_currency.clone())),
+            )
+            .with_column(
+                "liquidity",
+                strings(self, |t| serialized_name(&t.liquidity)),
+            )
+            .with_column("mark_price", floats(self, |t| Some(t.mark_price)))
+            .with_column("index_price", floats(self, |t| Some(t.index_price)))
+            .with_column("iv", floats(self, |t| t.iv))
+            .with_column("profit_loss", floats(self, |t| t.profit_loss))
+            .with_column("label", strings(self, |t| t.label.clone()))
+    }
+}
+
+impl ToTable for [UserTrade] {
+    fn to_table(&self) -> Table {
+        Table::new()
+            .with_column("timestamp", unsigned(self, |t| Some(t.timestamp.0)))
+            .with_column("trade_id", strings(self, |t| Some(t.trade_id.clone())))
+            .with_column("trade_seq", unsigned(self, |t| Some(t.trade_seq)))
+            .with_column(
+                "instrument_name",
+                strings(self, |t| Some(t.instrument_name.clone())),
+            )
+            .with_column("order_id", strings(self, |t| Some(t.order_id.clone())))
+            .with_column("order_type", strings(self, |t| Some(t.order_type.clone())))
+            .with_column("direction", strings(self, |t| Some(t.direction.clone())))
+            .with_column("price", floats(self, |t| Some(t.price)))
+            .with_column("amount", floats(self, |t| Some(t.amount)))
+            .with_column("fee", floats(self, |t| Some(t.fee)))
+            .with_column(
+                "fee_currency",
+                strings(self, |t| Some(t.fee_currency.clone())),
+            )
+            .with_column("liquidity", strings(self, |t| Some(t.liquidity.clone())))
+            .with_column("mark_price", floats(self, |t| Some(t.mark_price)))
+            .with_column("index_price", floats(self, |t| Some(t.index_price)))
+            .with_column("iv", floats(self, |t| t.iv))
+            .with_column("profit_loss", floats(self, |t| t.profit_loss))
+            .with_column("state", strings(self, |t| Some(t.state.clone())))
+            .with_column("label", strings(self, |t| t.label.clone()))
+            .with_column("self_trade", flags(self, |t| Some(t.self_trade)))
+    }
+}
+
+impl ToTable for [TransactionLogEntry] {
+    fn to_table(&self) -> Table {
+        Table::new()
+            .with_column("timestamp", unsigned(self, |e| Some(e.timestamp.0)))
+            .with_column("id", unsigned(self, |e| Some(e.id)))
+            .with_column("user_seq", unsigned(self, |e| Some(e.user_seq)))
+            .with_column("currency", strings(self, |e| Some(e.currency.clone())))
+            .with_column("type", strings(self, |e| Some(e.transaction_type.clone())))
+            .with_column(
+                "instrument_name",
+                strings(self, |e| e.instrument_name.clone()),
+            )
+            .with_column(
+                "side",
+                strings(self, |e| e.side.as_ref().and_then(serialized_name)),
+            )
+            .with_column("amount", floats(self, |e| e.amount))
+            .with_column("price", floats(self, |e| e.price))
+            .with_column("position", floats(self, |e| e.position))
+            .with_column("change", floats(self, |e| Some(e.change)))
+            .with_column("cashflow", floats(self, |e| Some(e.cashflow)))
+            .with_column("balance", floats(self, |e| Some(e.balance)))
+            .with_column("equity", floats(self, |e| Some(e.equity)))
+            .with_column("commission", floats(self, |e| e.commission))
+            .with_column("interest_pl", floats(self, |e| e.interest_pl))
+            .with_column("trade_id", strings(self, |e| e.trade_id.clone()))
+            .with_column("order_id", strings(self, |e| e.order_id.clone()))
+    }
+}
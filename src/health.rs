@@ -0,0 +1,274 @@
+//! Health checks of the connection to Deribit
+//!
+//! [`DeribitHttpClient::check_health`] calls `public/test`, timing a single
+//! round trip, and `public/status` for the platform lock state, and keeps the result
+//! for [`DeribitHttpClient::health`]. On native targets
+//! [`DeribitHttpClient::spawn_health_monitor`] repeats the check in the
+//! background and emits a [`HealthEvent`] whenever connectivity degrades or
+//! recovers. Checks share the client's rate limiter and circuit breaker with
+//! every other call; the timed `public/test` call is never retried.
+//!
+//! [`HealthEvent`]: crate::health::HealthEvent
+
+use crate::client::DeribitHttpClient;
+use crate::constants::endpoints::TEST_CONNECTION;
+use crate::error::HttpError;
+use crate::model::response::other::TestResponse;
+use crate::time_compat::Instant;
+use std::time::Duration;
+
+#[cfg(not(target_arch = "wasm32"))]
+use crate::constants::{HEALTH_EVENT_CAPACITY, MIN_POLL_INTERVAL};
+#[cfg(not(target_arch = "wasm32"))]
+use tokio::sync::broadcast;
+#[cfg(not(target_arch = "wasm32"))]
+use tokio::task::JoinHandle;
+#[cfg(not(target_arch = "wasm32"))]
+use tokio::time::MissedTickBehavior;
+
+/// Outcome of a health check
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HealthStatus {
+    /// The API answered and the platform is not locked
+    Healthy,
+    /// The API answered but the platform is locked
+    Locked,
+    /// `public/test` or `public/status` failed
+    Unreachable,
+}
+
+/// Result of the last health check
+#[derive(Debug, Clone)]
+pub struct Health {
+    /// Overall status
+    pub status: HealthStatus,
+    /// Round trip of the `public/test` call, if it succeeded
+    pub latency: Option<Duration>,
+    /// API version reported by `public/test`
+    pub version: Option<String>,
+    /// Whether the platform is locked, if `public/status` answered
+    pub locked: Option<bool>,
+    /// Currency indices locked on the platform
+    pub locked_indices: Vec<String>,
+    /// Error of the failed call, while unreachable
+    pub error: Option<HttpError>,
+    /// When the check finished
+    pub checked_at: Instant,
+}
+
+impl Health {
+    /// Whether the API answered and the platform is not locked
+    pub fn is_healthy(&self) -> bool {
+        self.status == HealthStatus::Healthy
+    }
+
+    /// Result of a check whose call failed with `error`
+    fn unreachable(error: HttpError) -> Self {
+        Self {
+            status: HealthStatus::Unreachable,
+            latency: None,
+            version: None,
+            locked: None,
+            locked_indices: Vec::new(),
+            error: Some(error),
+            checked_at: Instant::now(),
+        }
+    }
+}
+
+/// Change of connectivity seen by a health monitor
+#[derive(Debug, Clone)]
+pub enum HealthEvent {
+    /// A check found the API unreachable or the platform locked, after a
+    /// check with another status
+    Degraded(Health),
+    /// A check was healthy again
+    Recovered(Health),
+}
+
+impl HealthEvent {
+    /// The check that caused the event
+    pub fn health(&self) -> &Health {
+        match self {
+            HealthEvent::Degraded(health) | HealthEvent::Recovered(health) => health,
+        }
+    }
+
+    /// Event for `health` following a check with status `previous`
+    #[cfg(not(target_arch = "wasm32"))]
+    fn between(previous: HealthStatus, health: &Health) -> Option<Self> {
+        if previous == health.status {
+            return None;
+        }
+        Some(match health.status {
+            HealthStatus::Healthy => HealthEvent::Recovered(health.clone()),
+            _ => HealthEvent::Degraded(health.clone()),
+        })
+    }
+}
+
+impl DeribitHttpClient {
+    /// Check connectivity and the platform lock state now
+    ///
+    /// Calls `public/test`, then `public/status` if it answered. The
+    /// `public/test` call is sent once, without retries or joining a request
+    /// in flight, and its latency excludes the wait for rate limit permission.
+    /// The result is also kept for [`health`](Self::health), shared with
+    /// clones of the client. Failures are reported in the result rather than
+    /// as errors.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use deribit_http::DeribitHttpClient;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// let client = DeribitHttpClient::new();
+    /// let health = client.check_health().await;
+    /// println!("{:?}, latency {:?}", health.status, health.latency);
+    /// # }
+    /// ```
+    pub async fn check_health(&self) -> Health {
+        let health = match self.probe().await {
+            Ok((version, latency)) => {
+                let latency = Some(latency);
+                match self.get_status().await {
+                    Ok(status) => Health {
+                        status: match status.locked {
+                            Some(true) => HealthStatus::Locked,
+                            _ => HealthStatus::Healthy,
+                        },
+                        latency,
+                        version: Some(version),
+                        locked: status.locked,
+                        locked_indices: status.locked_indices.unwrap_or_default(),
+                        error: None,
+                        checked_at: Instant::now(),
+                    },
+                    Err(e) => Health {
+                        latency,
+                        version: Some(version),
+                        ..Health::unreachable(e)
+                    },
+                }
+            }
+            Err(e) => Health::unreachable(e),
+        };
+        self.record_health(health.clone());
+        health
+    }
+
+    /// Call `public/test` once, timing only its round trip
+    async fn probe(&self) -> Result<(String, Duration), HttpError> {
+        let url = format!("{}{}", self.base_url(), TEST_CONNECTION);
+        self.traced(TEST_CONNECTION, String::new, async {
+            let (response, latency) = self.timed_request(&url).await?;
+            let test: TestResponse = self.extract_result(response, TEST_CONNECTION).await?;
+            Ok((test.version, latency))
+        })
+        .await
+    }
+
+    /// Check connectivity now and then every `interval`
+    ///
+    /// Runs [`check_health`](Self::check_health) on a tokio task until the
+    /// returned monitor is dropped, emitting a [`HealthEvent`] when the status
+    /// changes: [`Degraded`](HealthEvent::Degraded) when the API becomes
+    /// unreachable or the platform locked, including on the first check, and
+    /// [`Recovered`](HealthEvent::Recovered) when a check is healthy again.
+    /// Intervals shorter than [`MIN_POLL_INTERVAL`] are raised to it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called outside a tokio runtime.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use deribit_http::DeribitHttpClient;
+    /// use deribit_http::health::HealthEvent;
+    /// use std::time::Duration;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// let client = DeribitHttpClient::new();
+    /// let mut monitor = client.spawn_health_monitor(Duration::from_secs(10));
+    /// while let Some(event) = monitor.recv().await {
+    ///     match event {
+    ///         HealthEvent::Degraded(health) => println!("degraded: {:?}", health.status),
+    ///         HealthEvent::Recovered(health) => println!("recovered in {:?}", health.latency),
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn spawn_health_monitor(&self, interval: Duration) -> HealthMonitor {
+        let client = self.clone();
+        let (sender, events) = broadcast::channel(HEALTH_EVENT_CAPACITY);
+        let task = tokio::spawn(async move {
+            let mut ticks = tokio::time::interval(interval.max(MIN_POLL_INTERVAL));
+            ticks.set_missed_tick_behavior(MissedTickBehavior::Delay);
+            let mut previous = HealthStatus::Healthy;
+            loop {
+                ticks.tick().await;
+                let health = client.check_health().await;
+                if let Some(event) = HealthEvent::between(previous, &health) {
+                    match &event {
+                        HealthEvent::Degraded(health) => tracing::warn!(
+                            status = ?health.status,
+                            error = ?health.error.as_ref().map(ToString::to_string),
+                            "Deribit connectivity degraded"
+                        ),
+                        HealthEvent::Recovered(health) => tracing::info!(
+                            latency_ms = health.latency.map(|latency| latency.as_millis() as u64),
+                            "Deribit connectivity recovered"
+                        ),
+                    }
+                    // Only fails once the monitor, and every receiver, is gone
+                    let _ = sender.send(event);
+                }
+                previous = health.status;
+            }
+        });
+        HealthMonitor { events, task }
+    }
+}
+
+/// Background health checks started by
+/// [`DeribitHttpClient::spawn_health_monitor`], stopped when dropped
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Debug)]
+pub struct HealthMonitor {
+    events: broadcast::Receiver<HealthEvent>,
+    task: JoinHandle<()>,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl HealthMonitor {
+    /// Wait for the next event
+    ///
+    /// Events are buffered from the first check on; if more than
+    /// [`HEALTH_EVENT_CAPACITY`] go unread, the oldest are skipped.
+    pub async fn recv(&mut self) -> Option<HealthEvent> {
+        loop {
+            match self.events.recv().await {
+                Ok(event) => return Some(event),
+                Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                Err(broadcast::error::RecvError::Closed) => return None,
+            }
+        }
+    }
+
+    /// Another receiver of the events emitted from now on
+    pub fn subscribe(&self) -> broadcast::Receiver<HealthEvent> {
+        self.events.resubscribe()
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl Drop for HealthMonitor {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
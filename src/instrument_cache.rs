@@ -0,0 +1,262 @@
+//! Instrument metadata cache
+//!
+//! Order helpers that need static instrument metadata, such as tick sizes for
+//! [`PriceNormalization`](crate::config::PriceNormalization) or minimum trade
+//! amounts, read it through [`InstrumentCache`] so that `get_instrument` is
+//! called once per instrument rather than once per order. Clones share the
+//! same entries.
+//!
+//! Entries expire after the cache's time to live and are fetched again on the
+//! next lookup. [`DeribitHttpClient::refresh_instruments`] loads every
+//! instrument of a currency at once, and
+//! [`DeribitHttpClient::spawn_instrument_refresh`] keeps doing so in the
+//! background.
+//!
+//! [`DeribitHttpClient::refresh_instruments`]: crate::DeribitHttpClient::refresh_instruments
+//! [`DeribitHttpClient::spawn_instrument_refresh`]: crate::DeribitHttpClient::spawn_instrument_refresh
+//! [`InstrumentCache`]: crate::instrument_cache::InstrumentCache
+
+use crate::DeribitHttpClient;
+use crate::error::HttpError;
+use crate::model::instrument::Instrument;
+use crate::sync_compat::Mutex;
+use crate::time_compat::Instant;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+#[cfg(not(target_arch = "wasm32"))]
+use tracing::warn;
+
+/// How long entries stay fresh unless set with [`InstrumentCache::with_ttl`]
+pub const DEFAULT_INSTRUMENT_TTL: Duration = Duration::from_secs(60 * 60);
+
+/// Instruments by name, shared by every clone of a client
+#[derive(Debug, Clone)]
+pub struct InstrumentCache {
+    entries: Arc<Mutex<HashMap<String, Entry>>>,
+    ttl: Duration,
+}
+
+/// Cached instrument and when it was fetched
+#[derive(Debug, Clone)]
+struct Entry {
+    instrument: Instrument,
+    fetched_at: Instant,
+}
+
+impl Default for InstrumentCache {
+    fn default() -> Self {
+        Self {
+            entries: Arc::default(),
+            ttl: DEFAULT_INSTRUMENT_TTL,
+        }
+    }
+}
+
+impl InstrumentCache {
+    /// Create an empty cache whose entries live for [`DEFAULT_INSTRUMENT_TTL`]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create an empty cache whose entries stay fresh for `ttl`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use deribit_http::DeribitHttpClient;
+    /// use deribit_http::instrument_cache::InstrumentCache;
+    /// use std::time::Duration;
+    ///
+    /// let client = DeribitHttpClient::builder()
+    ///     .instrument_cache(InstrumentCache::with_ttl(Duration::from_secs(300)))
+    ///     .build()
+    ///     .unwrap();
+    /// assert_eq!(client.instrument_cache().ttl(), Duration::from_secs(300));
+    /// ```
+    pub fn with_ttl(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            ..Self::default()
+        }
+    }
+
+    /// How long entries stay fresh
+    pub fn ttl(&self) -> Duration {
+        self.ttl
+    }
+
+    /// Cached instrument by name, unless it is missing or stale
+    pub async fn get(&self, instrument_name: &str) -> Option<Instrument> {
+        self.entries
+            .lock()
+            .await
+            .get(instrument_name)
+            .filter(|entry| entry.fetched_at.elapsed() < self.ttl)
+            .map(|entry| entry.instrument.clone())
+    }
+
+    /// Store an instrument, replacing any entry with the same name
+    pub async fn insert(&self, instrument: Instrument) {
+        self.insert_all([instrument]).await;
+    }
+
+    /// Store instruments, e.g. a `get_instruments` result, replacing any
+    /// entries with the same names
+    pub async fn insert_all(&self, instruments: impl IntoIterator<Item = Instrument>) {
+        let fetched_at = Instant::now();
+        let mut entries = self.entries.lock().await;
+        for instrument in instruments {
+            entries.insert(
+                instrument.instrument_name.clone(),
+                Entry {
+                    instrument,
+                    fetched_at,
+                },
+            );
+        }
+    }
+
+    /// Drop a single instrument, e.g. after it expired
+    pub async fn remove(&self, instrument_name: &str) -> Option<Instrument> {
+        self.entries
+            .lock()
+            .await
+            .remove(instrument_name)
+            .map(|entry| entry.instrument)
+    }
+
+    /// Drop every cached instrument
+    pub async fn clear(&self) {
+        self.entries.lock().await.clear();
+    }
+
+    /// Drop the entries older than the time to live
+    pub async fn evict_stale(&self) {
+        self.entries
+            .lock()
+            .await
+            .retain(|_, entry| entry.fetched_at.elapsed() < self.ttl);
+    }
+
+    /// Number of cached instruments, stale ones included
+    pub async fn len(&self) -> usize {
+        self.entries.lock().await.len()
+    }
+
+    /// Whether the cache is empty
+    pub async fn is_empty(&self) -> bool {
+        self.entries.lock().await.is_empty()
+    }
+}
+
+impl DeribitHttpClient {
+    /// Load every live instrument of `currency` into the instrument cache
+    ///
+    /// One `get_instruments` call replaces the `get_instrument` calls lookups
+    /// would otherwise make one instrument at a time. Returns the number of
+    /// instruments loaded.
+    ///
+    /// # Errors
+    ///
+    /// Returns `HttpError` if `get_instruments` fails; the cache is left as it was.
+    pub async fn refresh_instruments(&self, currency: impl AsRef<str>) -> Result<usize, HttpError> {
+        let instruments = self.get_instruments(currency, None, None).await?;
+        let count = instruments.len();
+        self.instrument_cache().insert_all(instruments).await;
+        Ok(count)
+    }
+
+    /// Refresh the instruments of `currencies` now and then every `interval`
+    ///
+    /// Runs [`refresh_instruments`](Self::refresh_instruments) on a tokio task
+    /// until the returned handle is dropped. Failed refreshes are logged and
+    /// retried at the next interval, while lookups keep falling back to
+    /// `get_instrument` for stale entries.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called outside a tokio runtime.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use deribit_http::DeribitHttpClient;
+    /// use std::time::Duration;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), deribit_http::HttpError> {
+    /// let client = DeribitHttpClient::new();
+    /// let _refresh = client.spawn_instrument_refresh(["BTC", "ETH"], Duration::from_secs(600));
+    /// let tick = client.cached_tick_size("BTC-PERPETUAL").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn spawn_instrument_refresh<I, S>(
+        &self,
+        currencies: I,
+        interval: Duration,
+    ) -> InstrumentRefresh
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let client = self.clone();
+        let currencies: Vec<String> = currencies.into_iter().map(Into::into).collect();
+        let task = tokio::spawn(async move {
+            loop {
+                for currency in &currencies {
+                    if let Err(e) = client.refresh_instruments(currency).await {
+                        warn!("Failed to refresh {} instruments: {}", currency, e);
+                    }
+                }
+                crate::sleep_compat::sleep(interval).await;
+            }
+        });
+        InstrumentRefresh { task }
+    }
+}
+
+/// Background instrument refresh started by
+/// [`DeribitHttpClient::spawn_instrument_refresh`], stopped when dropped
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Debug)]
+pub struct InstrumentRefresh {
+    task: tokio::task::JoinHandle<()>,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl Drop for InstrumentRefresh {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn instrument(name: &str) -> Instrument {
+        serde_json::from_value(serde_json::json!({ "instrument_name": name })).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_entries_expire_after_the_ttl() {
+        let cache = InstrumentCache::with_ttl(Duration::from_millis(20));
+        cache
+            .insert_all([instrument("BTC-PERPETUAL"), instrument("ETH-PERPETUAL")])
+            .await;
+        assert!(cache.get("BTC-PERPETUAL").await.is_some());
+
+        tokio::time::sleep(Duration::from_millis(30)).await;
+        assert!(cache.get("BTC-PERPETUAL").await.is_none());
+        assert_eq!(cache.len().await, 2);
+
+        cache.insert(instrument("BTC-PERPETUAL")).await;
+        cache.evict_stale().await;
+        assert_eq!(cache.len().await, 1);
+        assert!(cache.get("BTC-PERPETUAL").await.is_some());
+    }
+}
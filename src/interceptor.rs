@@ -0,0 +1,135 @@
+//! Hooks run around every HTTP request the client sends
+//!
+//! Register a [`RequestInterceptor`] with
+//! [`DeribitHttpClient::with_interceptor`](crate::DeribitHttpClient::with_interceptor)
+//! to add headers, audit traffic or veto requests without touching the
+//! endpoint methods. Interceptors run in registration order, once per attempt,
+//! so a retried request passes through them again. Token requests made by the
+//! authentication manager, simulated responses of the `testing` feature and
+//! private calls answered by [paper trading](crate::paper) do not go through
+//! them.
+//!
+//! [`RequestInterceptor`]: crate::interceptor::RequestInterceptor
+
+use crate::error::HttpError;
+use std::fmt;
+use std::sync::Arc;
+
+/// Future returned by interceptor hooks, `Send` except on WASM
+#[cfg(not(target_arch = "wasm32"))]
+pub type HookFuture<'a, T> = futures_util::future::BoxFuture<'a, T>;
+
+/// Future returned by interceptor hooks, `Send` except on WASM
+#[cfg(target_arch = "wasm32")]
+pub type HookFuture<'a, T> = futures_util::future::LocalBoxFuture<'a, T>;
+
+/// Async hooks around each request attempt
+///
+/// Both methods default to doing nothing, so implementors only override the
+/// ones they need.
+///
+/// # Examples
+///
+/// ```rust
+/// use deribit_http::interceptor::{HookFuture, RequestInterceptor};
+/// use deribit_http::{DeribitHttpClient, HttpError};
+///
+/// struct Tagger;
+///
+/// impl RequestInterceptor for Tagger {
+///     fn before_request<'a>(
+///         &'a self,
+///         request: &'a mut reqwest::Request,
+///     ) -> HookFuture<'a, Result<(), HttpError>> {
+///         Box::pin(async move {
+///             request
+///                 .headers_mut()
+///                 .insert("x-desk", reqwest::header::HeaderValue::from_static("options"));
+///             Ok(())
+///         })
+///     }
+/// }
+///
+/// let client = DeribitHttpClient::new().with_interceptor(Tagger);
+/// ```
+pub trait RequestInterceptor: Send + Sync {
+    /// Called before an attempt is sent
+    ///
+    /// May modify the request, e.g. to add headers. Returning an error aborts
+    /// the call with that error, without retrying.
+    fn before_request<'a>(
+        &'a self,
+        request: &'a mut reqwest::Request,
+    ) -> HookFuture<'a, Result<(), HttpError>> {
+        let _ = request;
+        Box::pin(async { Ok(()) })
+    }
+
+    /// Called with every response received, before its body is read
+    ///
+    /// Not called when the request fails before a response arrives.
+    fn after_response<'a>(&'a self, response: &'a reqwest::Response) -> HookFuture<'a, ()> {
+        let _ = response;
+        Box::pin(async {})
+    }
+}
+
+/// Shares one interceptor, and the state it collects, across clients
+impl<T: RequestInterceptor + ?Sized> RequestInterceptor for Arc<T> {
+    fn before_request<'a>(
+        &'a self,
+        request: &'a mut reqwest::Request,
+    ) -> HookFuture<'a, Result<(), HttpError>> {
+        self.as_ref().before_request(request)
+    }
+
+    fn after_response<'a>(&'a self, response: &'a reqwest::Response) -> HookFuture<'a, ()> {
+        self.as_ref().after_response(response)
+    }
+}
+
+/// Interceptors registered on a client, shared by its clones
+#[derive(Clone, Default)]
+pub(crate) struct Interceptors(Arc<Vec<Arc<dyn RequestInterceptor>>>);
+
+impl Interceptors {
+    /// A copy of this list with `interceptor` appended
+    pub(crate) fn with(&self, interceptor: Arc<dyn RequestInterceptor>) -> Self {
+        let mut interceptors = self.0.as_ref().clone();
+        interceptors.push(interceptor);
+        Interceptors(Arc::new(interceptors))
+    }
+
+    /// Identity of this list, shared by the clients it was cloned into
+    ///
+    /// Stable while the list is alive; a request in flight keeps its client's
+    /// list alive, so the identity is not reused under it.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub(crate) fn id(&self) -> usize {
+        Arc::as_ptr(&self.0) as *const () as usize
+    }
+
+    /// Run every `before_request` hook, stopping at the first error
+    pub(crate) async fn before_request(
+        &self,
+        request: &mut reqwest::Request,
+    ) -> Result<(), HttpError> {
+        for interceptor in self.0.iter() {
+            interceptor.before_request(request).await?;
+        }
+        Ok(())
+    }
+
+    /// Run every `after_response` hook
+    pub(crate) async fn after_response(&self, response: &reqwest::Response) {
+        for interceptor in self.0.iter() {
+            interceptor.after_response(response).await;
+        }
+    }
+}
+
+impl fmt::Debug for Interceptors {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Interceptors").field(&self.0.len()).finish()
+    }
+}
@@ -23,6 +23,34 @@
 //! tokio = { version = "1", features = ["full"] }
 //! ```
 //!
+//! Enable the `decimal` feature for exact `rust_decimal::Decimal` views of the prices,
+//! amounts, fees and margins of orders, user trades, positions and account summaries
+//! (`price_decimal()`, `fee_decimal()`, ...) and `Decimal` setters on order builders. The
+//! fields themselves stay `f64`, so the feature is additive (see `model::decimal`).
+//!
+//! Without an async runtime, for example in scripts or `evcxr` notebooks, enable the
+//! `blocking` feature and use `DeribitHttpBlockingClient`, which has the same endpoints
+//! as synchronous methods: `client.get_ticker("BTC-PERPETUAL")?`.
+//!
+//! Requests go through `reqwest` by default. Other HTTP libraries plug in by implementing
+//! `transport::HttpBackend` and passing it to `HttpConfig::with_backend`; the `hyper-backend`
+//! feature provides `transport::HyperBackend`.
+//!
+//! The `export` feature adds `export::export_csv` and `export::export_parquet`, which write
+//! candles, trades and transaction log entries to CSV or Parquet files for offline analysis.
+//!
+//! For tests, the `testing` feature adds `testing::MockDeribitClient`, which answers
+//! from canned fixtures without network access, and `mock-server` adds
+//! `testing::MockServer`, a local Deribit-compatible HTTP server with configurable
+//! auth failures, rate limiting and partial fills.
+//!
+//! Credentials can come from a `config::CredentialsProvider` consulted on every token
+//! renewal, so they rotate without a restart: `EnvCredentials` and `FileCredentials` are
+//! built in, and the `vault` and `aws-secrets-manager` features add HashiCorp Vault and
+//! AWS Secrets Manager providers. Neither feature pulls in a dependency: both call their
+//! service's HTTP API with `reqwest`, and AWS requests are signed by a small built-in
+//! Signature Version 4 signer rather than the AWS SDK.
+//!
 //! ## Quick start
 //! ```rust
 //! use deribit_http::DeribitHttpClient;
@@ -47,23 +75,28 @@
 //! ## Authentication and private endpoints
 //! - OAuth2 (Client Credentials): `DeribitHttpClient::authenticate_oauth2(client_id, client_secret)` returns an `AuthToken` and keeps it in the `AuthManager`.
 //! - Helpers: `is_authenticated()`, `get_auth_token()`.
-//! - Session management: `exchange_token(refresh_token, subject_id, scope)` and `fork_token(refresh_token, session_name, scope)`.
+//! - Session management: `exchange_token(refresh_token, subject_id, scope)` and `fork_token(refresh_token, session_name, scope)`, or `AuthManager::exchange_token`/`fork_token` with `ExchangeTokenRequest`/`ForkTokenRequest`.
 //! - API Key: the `authenticate_api_key` method exists but is currently not implemented and will return an error.
 //!
 //! ## Configuration
 //! - Environment shortcut: `DeribitHttpClient::new()` for Testnet and `new(false)` for Production.
 //! - Custom configuration: `DeribitHttpClient::with_config(HttpConfig)` lets you set `base_url`, `timeout`, `user_agent`, `testnet`, and optional credentials.
+//! - Builder: `DeribitHttpClient::builder()` sets the base URL, credentials or a credentials provider, timeout, retries, rate limiter, interceptors and transport in one chain; it reads nothing from the environment unless `from_env()` is called, and `build()` returns an error instead of panicking.
+//! - Proxy: `HttpConfig::with_proxy(ProxyConfig)` sends requests through an HTTP, HTTPS or SOCKS5 proxy, with optional credentials and a no-proxy host list; configurations loaded from the environment read `HTTPS_PROXY`/`ALL_PROXY` and `NO_PROXY`.
+//! - Per-request options: `client.with_options(RequestOptions)` returns a copy of the client, sharing its pool and session, whose requests use another timeout or retry count.
+//! - Headers: `HttpConfig::with_user_agent` sets the `User-Agent` and `HttpConfig::with_header` adds default headers, e.g. client identification required by a gateway, to every request including token requests.
+//! - Connection pool: `HttpConfig::with_pool(PoolConfig)` sets the idle connections kept per host, their idle timeout, TCP keepalive and the connect timeout, so sparse but latency-sensitive traffic can keep warm connections to Deribit.
 //! - Validation: configuration is validated on client creation.
 //!
 //! ## Project structure (modules)
 //! - `auth`: `AuthManager` (OAuth2, token management) and related types (e.g. `AuthRequest`).
 //! - `client`: `DeribitHttpClient`, public/private methods, auth helpers, `exchange_token` and `fork_token`.
 //! - `config`: `HttpConfig` and environment helpers (testnet/production) and headers/base_url.
-//! - `connection` and `session`: infrastructure support types (shared across the ecosystem).
+//! - `connection` and `session`: infrastructure support types (shared across the ecosystem) and the `CircuitBreaker`.
 //! - `endpoints`: HTTP implementation of public and private methods (see coverage below).
 //! - `error`: `HttpError` variants such as `NetworkError`, `RequestFailed`, `InvalidResponse`, `AuthenticationFailed`, `ConfigError`.
 //! - `message` and `model`: HTTP types (`ApiResponse`, `ApiError`, `AuthToken`, etc.).
-//! - `rate_limit`: `RateLimiter` and `categorize_endpoint` with per-category limits.
+//! - `rate_limit`: `RateLimiter`, `CreditLimits` and `categorize_endpoint` for Deribit's credit pools.
 //! - `constants`: base URLs (production/testnet), endpoint routes, and common headers.
 //!
 //! ## Public endpoints (30+)
@@ -75,14 +108,15 @@
 //! | **Indices** | `get_index()`, `get_index_price()`, `get_index_price_names()`, `get_index_chart_data()` |
 //! | **Instruments** | `get_instrument()`, `get_instruments()`, `get_contract_size()` |
 //! | **Book Summary** | `get_book_summary_by_currency()`, `get_book_summary_by_instrument()` |
-//! | **Market Data** | `get_ticker()`, `get_order_book()`, `get_order_book_by_instrument_id()` |
-//! | **Trades** | `get_last_trades()`, `get_last_trades_by_currency()`, `get_last_trades_by_*_and_time()` |
+//! | **Market Data** | `get_ticker()`, `get_order_book()`, `get_order_book_by_instrument_id()`, `get_mark_price_history()` |
+//! | **Trades** | `get_last_trades()`, `get_last_trades_by_currency()`, `get_last_trades_by_*_and_time()`, `get_trade_volumes()` |
 //! | **Funding** | `get_funding_chart_data()`, `get_funding_rate_history()`, `get_funding_rate_value()` |
-//! | **Volatility** | `get_historical_volatility()`, `get_volatility_index_data()` |
+//! | **Volatility** | `get_historical_volatility()`, `get_volatility_index_data()`, `get_all_volatility_index_data()` |
 //! | **Settlements** | `get_last_settlements_by_currency()`, `get_last_settlements_by_instrument()` |
 //! | **TradingView** | `get_tradingview_chart_data()` |
 //! | **Combo Books** | `get_combo_details()`, `get_combo_ids()`, `get_combos()` |
 //! | **Block RFQ** | `get_block_rfq_trades()` |
+//! | **RFQ** | `get_rfqs()` |
 //!
 //! ## Private endpoints (70+)
 //!
@@ -90,38 +124,62 @@
 //!
 //! | Category | Endpoints |
 //! |----------|-----------|
-//! | **Trading** | `buy_order()`, `sell_order()`, `edit_order()`, `cancel_order()`, `cancel_all()`, `cancel_all_by_*()` |
-//! | **Orders** | `get_open_orders()`, `get_order_state()`, `get_order_history_by_currency()`, `get_order_history_by_instrument()` |
-//! | **Positions** | `get_position()`, `get_positions()`, `close_position()`, `move_positions()` |
+//! | **Trading** | `buy_order()`, `sell_order()`, `submit_orders()`, `edit_order()`, `edit_order_by_label()`, `cancel_order()`, `cancel_all()`, `cancel_all_by_*()` |
+//! | **Orders** | `get_open_orders()`, `get_order_state()`, `get_order_history_by_currency()`, `get_order_history_by_instrument()`, `stream_order_history_by_currency()`, `stream_order_history_by_instrument()`, `get_stop_order_history()`, `get_trigger_order_history()`, `stream_trigger_order_history()` |
+//! | **Positions** | `get_position()`, `get_positions()`, `close_position()`, `close_position_market()`, `move_positions()` |
 //! | **User Trades** | `get_user_trades_by_instrument()`, `get_user_trades_by_currency()`, `get_user_trades_by_order()` |
-//! | **Account** | `get_account_summary()`, `get_account_summaries()`, `get_subaccounts()`, `get_subaccounts_details()` |
+//! | **Account** | `get_account_summary()`, `get_account_summaries()`, `get_subaccounts()`, `get_subaccounts_details()`, `get_transaction_log()`, `stream_transaction_log()`, `get_transaction_log_all()` |
 //! | **Subaccounts** | `create_subaccount()`, `change_subaccount_name()`, `toggle_subaccount_login()`, `remove_subaccount()` |
 //! | **API Keys** | `create_api_key()`, `edit_api_key()`, `remove_api_key()`, `list_api_keys()`, `enable_api_key()`, `disable_api_key()` |
-//! | **Wallet** | `get_deposits()`, `get_withdrawals()`, `withdraw()`, `cancel_withdrawal()`, `create_deposit_address()` |
-//! | **Transfers** | `get_transfers()`, `submit_transfer_to_subaccount()`, `submit_transfer_between_subaccounts()`, `cancel_transfer_by_id()` |
+//! | **Wallet** | `get_deposits()`, `get_withdrawals()`, `stream_deposits()`, `stream_withdrawals()`, `withdraw()`, `cancel_withdrawal()`, `create_deposit_address()` |
+//! | **Transfers** | `get_transfers()`, `stream_transfers()`, `submit_transfer_to_subaccount()`, `submit_transfer_to_user()`, `submit_transfer_between_subaccounts()`, `cancel_transfer_by_id()` |
 //! | **Block Trade** | `execute_block_trade()`, `verify_block_trade()`, `get_block_trade()`, `get_block_trades()`, `simulate_block_trade()` |
 //! | **Block RFQ** | `create_block_rfq()`, `accept_block_rfq()`, `add_block_rfq_quote()`, `cancel_block_rfq()`, `get_block_rfqs()` |
+//! | **RFQ** | `send_rfq()`, `cancel_rfq()` |
 //! | **Combo Books** | `create_combo()`, `get_leg_prices()` |
 //! | **MMP** | `get_mmp_config()`, `set_mmp_config()`, `reset_mmp()`, `get_mmp_status()` |
+//! | **Cancel on Disconnect** | `get_cancel_on_disconnect()`, `enable_cancel_on_disconnect()`, `disable_cancel_on_disconnect()` |
 //! | **Mass Quote** | `mass_quote()`, `cancel_quotes()` |
-//! | **Margins** | `get_margins()`, `get_order_margin_by_ids()` |
+//! | **Margins** | `get_margins()`, `get_order_margin_by_ids()`, `get_portfolio_margins()` |
 //! | **Settlement** | `get_settlement_history_by_currency()`, `get_settlement_history_by_instrument()` |
 //!
 //! ## Limitations and important notes
 //! - This crate does not implement WebSocket or streaming. Some Deribit endpoints exist only over WS
-//!   (for example, `/public/hello` and `/private/logout`) and are not available in this HTTP client.
+//!   (for example, `/public/hello`) and are not available in this HTTP client. `logout()` calls
+//!   `/private/logout` and always clears the local token, even if the server rejects the call.
 //! - API Key authentication: the `authenticate_api_key` stub exists but is not yet implemented in the HTTP client.
 //! - Deribit uses JSON-RPC over HTTP; this client exposes ergonomic methods that build URLs with query params
 //!   and parse `ApiResponse<T>` in a strongly-typed manner.
 //!
 //! ## Error handling
-//! The `HttpError` type centralizes common failures: network issues (`NetworkError`),
+//! The `HttpErrorKind` enum centralizes common failures: network issues (`NetworkError`),
 //! non-success HTTP responses (`RequestFailed`), parsing/structure errors (`InvalidResponse`),
 //! authentication failures (`AuthenticationFailed`), and configuration conditions (`ConfigError`).
+//! `HttpError` pairs a kind with the JSON-RPC method, HTTP status and request id of the failed
+//! call; match on `error.kind()` to tell errors apart.
 //!
 //! ## Rate limiting
-//! The `RateLimiter` categorizes each URL and applies a token-bucket scheme per category
-//! (Trading, MarketData, Account, Auth, General). You can inspect it via `rate_limiter()`.
+//! The `RateLimiter` follows Deribit's credit model: matching engine requests (order entry,
+//! edits, cancels) and all other requests draw from separate credit pools that refill
+//! continuously, and requests wait until their pool can cover them. Inspect the pools with
+//! `remaining_credits(category)` and `credit_refill_rate(category)`, and set limits for higher
+//! account tiers with `with_credit_limits`. The limits apply per account, so several clients of one
+//! account (one per strategy, say) should share a limiter: pass clones of the same `RateLimiter` to
+//! `DeribitHttpClient::builder().rate_limiter(...)` or `with_rate_limiter`. While a pool is
+//! throttled, waiting requests are admitted by `RequestPriority`: cancels and position closes first,
+//! then order entry, account queries and, last, market data and history downloads. Identical
+//! public requests made while one is in flight, with the same request options and interceptors,
+//! share its response instead of being sent again.
+//!
+//! Opt in to a `CircuitBreaker` (`builder().circuit_breaker(...)` or `with_circuit_breaker`) to stop
+//! sending during outages: once the share of connection errors and 5xx responses over the last
+//! requests reaches its threshold, requests fail immediately with `HttpErrorKind::CircuitOpen` until
+//! the open period ends, then single probe requests decide whether the circuit closes again.
+//!
+//! `check_health()` calls `public/test` and `public/status` and reports the round-trip latency and
+//! platform lock state, also available afterwards from `client.health()`. `spawn_health_monitor`
+//! repeats the check in the background and emits `HealthEvent::Degraded` and
+//! `HealthEvent::Recovered` when connectivity changes.
 //!
 //! ## Examples
 //!
@@ -144,25 +202,63 @@
 //! | Native (tokio) | ✅ Full support |
 //! | WASM (browser) | ✅ Full support |
 //! | Cloudflare Workers | ✅ Full support |
+//!
+//! For WASM, depend on the crate with `default-features = false, features = ["wasm"]`.
+//! Requests go through `fetch`, retries and rate limiting wait on JavaScript timers, and
+//! OAuth2 tokens are obtained and refreshed as on native, so private endpoints work from
+//! Workers; pass the credentials with `HttpConfig::with_oauth2`, for example from Worker
+//! secrets (see `examples/cloudflare-worker`). Environment configuration, proxies, pool
+//! settings, paper trading, polling streams, the health monitor, request coalescing and
+//! `HttpBackend` are native-only.
 
 pub mod auth;
+/// Blocking client for synchronous code (requires the `blocking` feature)
+#[cfg(feature = "blocking")]
+pub mod blocking;
 pub mod client;
+/// Coalescing of identical concurrent public requests
+#[cfg(not(target_arch = "wasm32"))]
+mod coalesce;
 pub mod config;
 pub mod connection;
 /// HTTP API endpoints implementation for public and private Deribit API methods
 pub mod endpoints;
 pub mod error;
+/// CSV and Parquet export of candles, trades and transactions (requires the `export` feature)
+#[cfg(feature = "export")]
+pub mod export;
+/// Health checks and background health monitoring of the connection to Deribit
+pub mod health;
+/// Shared cache of instrument metadata used by order helpers
+pub mod instrument_cache;
+/// Request interceptor hooks
+pub mod interceptor;
 pub mod message;
 pub mod model;
+/// Unified pagination: cursors, pages and stream adapters for list endpoints
+pub mod pagination;
+/// Paper trading: private order endpoints simulated against live books
+#[cfg(not(target_arch = "wasm32"))]
+pub mod paper;
+/// Polling streams that emulate subscriptions over plain HTTP
+#[cfg(not(target_arch = "wasm32"))]
+pub mod polling;
 pub mod prelude;
 pub mod rate_limit;
+/// Retry policy and attempt metadata for transient failures
+pub mod retry;
 pub mod session;
 /// Cross-platform async sleep for native and WASM targets
 pub mod sleep_compat;
 /// Cross-platform Mutex re-export for native and WASM targets
 pub mod sync_compat;
+/// Test utilities for integration tests (requires the `testing` feature)
+#[cfg(feature = "testing")]
+pub mod testing;
 /// Cross-platform time utilities for native and WASM targets
 pub mod time_compat;
+/// Pluggable HTTP transport
+pub mod transport;
 
 // Constants
 /// Application constants and configuration
@@ -178,9 +274,14 @@ pub use error::*;
 
 // Re-export specific types to avoid conflicts
 pub use auth::AuthRequest;
-pub use auth::{ApiKeyAuth, AuthManager};
+pub use auth::{ApiKeyAuth, AuthManager, ExchangeTokenRequest, ForkTokenRequest, ScopeDowngrade};
+#[cfg(feature = "blocking")]
+pub use blocking::DeribitHttpBlockingClient;
 pub use config::ApiCredentials;
 pub use config::HttpConfig;
 pub use connection::*;
+pub use endpoints::SubaccountClient;
 pub use message::{HttpMessageBuilder, HttpRequestBuilder, HttpResponseHandler};
+pub use pagination::{ContinuationToken, Cursor, Page, Paginated, TradeCursor};
+pub use retry::RetryMetadata;
 pub use session::*;
@@ -71,6 +71,108 @@ pub fn setup_logger() {
     });
 }
 
+/// Placeholder logged instead of a sensitive value
+pub const REDACTED: &str = "[REDACTED]";
+
+/// Parameter and body keys whose values are never logged
+const SENSITIVE_KEY_PARTS: [&str; 5] = ["secret", "password", "token", "signature", "tfa"];
+
+/// Whether values under `key` must not be logged
+fn is_sensitive(key: &str) -> bool {
+    let key = key.to_ascii_lowercase();
+    SENSITIVE_KEY_PARTS.iter().any(|part| key.contains(part))
+}
+
+/// Replace secrets, tokens, passwords and signatures in `value` with [`REDACTED`]
+///
+/// Walks nested objects and arrays, so both request parameters and response
+/// bodies can be logged safely.
+pub fn redact(value: &mut serde_json::Value) {
+    match value {
+        serde_json::Value::Object(map) => {
+            for (key, value) in map.iter_mut() {
+                if is_sensitive(key) {
+                    *value = serde_json::Value::String(REDACTED.to_string());
+                } else {
+                    redact(value);
+                }
+            }
+        }
+        serde_json::Value::Array(items) => items.iter_mut().for_each(redact),
+        _ => {}
+    }
+}
+
+/// Redacted JSON text of request parameters
+pub fn redacted_params(mut params: serde_json::Value) -> String {
+    redact(&mut params);
+    params.to_string()
+}
+
+/// Redacted JSON text of a query string such as `?currency=BTC&kind=future`
+pub fn redacted_query(query: &str) -> String {
+    let params: serde_json::Map<String, serde_json::Value> =
+        url::form_urlencoded::parse(query.trim_start_matches('?').as_bytes())
+            .map(|(key, value)| (key.into_owned(), value.into_owned().into()))
+            .collect();
+    redacted_params(serde_json::Value::Object(params))
+}
+
+/// Redacted text of a response body, or the body itself if it is not JSON
+pub fn redacted_body(body: &str) -> String {
+    match serde_json::from_str::<serde_json::Value>(body) {
+        Ok(mut value) => {
+            redact(&mut value);
+            value.to_string()
+        }
+        Err(_) => body.to_string(),
+    }
+}
+
+/// Write `value` as redacted JSON, pretty-printed if `pretty`
+pub(crate) fn fmt_redacted<T: serde::Serialize + ?Sized>(
+    value: &T,
+    pretty: bool,
+    f: &mut std::fmt::Formatter<'_>,
+) -> std::fmt::Result {
+    let mut value = match serde_json::to_value(value) {
+        Ok(value) => value,
+        Err(e) => return write!(f, "Error serializing to JSON: {}", e),
+    };
+    redact(&mut value);
+    let json = if pretty {
+        serde_json::to_string_pretty(&value)
+    } else {
+        serde_json::to_string(&value)
+    };
+    match json {
+        Ok(json) => write!(f, "{}", json),
+        Err(e) => write!(f, "Error serializing to JSON: {}", e),
+    }
+}
+
+/// `Debug` and `Display` printing like `DebugPretty` and `DisplaySimple`, with
+/// secrets and tokens replaced by [`REDACTED`]
+macro_rules! impl_redacted_display {
+    ($($ty:ty),+ $(,)?) => {
+        $(
+            impl std::fmt::Debug for $ty {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    $crate::logger::fmt_redacted(self, true, f)
+                }
+            }
+
+            impl std::fmt::Display for $ty {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    $crate::logger::fmt_redacted(self, false, f)
+                }
+            }
+        )+
+    };
+}
+
+pub(crate) use impl_redacted_display;
+
 #[cfg(all(test, not(target_arch = "wasm32")))]
 mod tests_setup_logger {
     use super::setup_logger;
@@ -303,3 +405,57 @@ mod tests_setup_logger_bis {
         }
     }
 }
+
+#[cfg(test)]
+mod tests_redaction {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_redact_nested_values() {
+        let mut value = json!({
+            "grant_type": "client_credentials",
+            "client_secret": "s3cr3t",
+            "result": {
+                "access_token": "abc",
+                "refresh_token": "def",
+                "items": [{"signature": "sig", "amount": 10}]
+            }
+        });
+        redact(&mut value);
+        assert_eq!(value["grant_type"], "client_credentials");
+        assert_eq!(value["client_secret"], REDACTED);
+        assert_eq!(value["result"]["access_token"], REDACTED);
+        assert_eq!(value["result"]["refresh_token"], REDACTED);
+        assert_eq!(value["result"]["items"][0]["signature"], REDACTED);
+        assert_eq!(value["result"]["items"][0]["amount"], 10);
+    }
+
+    #[test]
+    fn test_redacted_query() {
+        let text = redacted_query("?client_id=id&client_secret=s3cr3t&currency=BTC");
+        assert!(!text.contains("s3cr3t"));
+        assert!(text.contains(REDACTED));
+        assert!(text.contains("\"currency\":\"BTC\""));
+        assert_eq!(redacted_query(""), "{}");
+    }
+
+    #[test]
+    fn test_redacted_body() {
+        let body = r#"{"jsonrpc":"2.0","result":{"access_token":"abc","expires_in":900}}"#;
+        let text = redacted_body(body);
+        assert!(!text.contains("abc"));
+        assert!(text.contains("900"));
+        assert_eq!(
+            redacted_body("<html>bad gateway</html>"),
+            "<html>bad gateway</html>"
+        );
+    }
+
+    #[test]
+    fn test_redacted_params_keeps_plain_values() {
+        let text = redacted_params(json!({"instrument_name": "BTC-PERPETUAL", "password": "pw"}));
+        assert!(text.contains("BTC-PERPETUAL"));
+        assert!(!text.contains("\"pw\""));
+    }
+}
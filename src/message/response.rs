@@ -1,10 +1,11 @@
 //! HTTP response message handling
 
-use crate::error::HttpError;
+use crate::error::{ErrorContext, HttpError, HttpErrorKind};
 use crate::model::response::api_response::{ApiResponse, HttpResponse};
 use crate::model::types::ApiError;
 use pretty_simple_display::{DebugPretty, DisplaySimple};
 use serde::{Deserialize, Serialize};
+use std::time::Duration;
 
 /// Response handler for HTTP messages
 #[derive(DebugPretty, DisplaySimple, Clone, Serialize, Deserialize)]
@@ -22,13 +23,75 @@ impl HttpResponseHandler {
         T: for<'de> Deserialize<'de>,
     {
         if response.status >= 400 {
-            return Err(HttpError::RequestFailed(format!(
-                "HTTP {} - {}",
-                response.status, response.body
-            )));
+            return Err(self.error_for_status(
+                response.status,
+                retry_after(response),
+                &response.body,
+            ));
         }
 
-        serde_json::from_str(&response.body).map_err(|e| HttpError::InvalidResponse(e.to_string()))
+        serde_json::from_str(&response.body)
+            .map_err(|e| HttpError::from(HttpErrorKind::InvalidResponse(e.to_string())))
+    }
+
+    /// Error for a non-success HTTP response
+    ///
+    /// The single place where HTTP statuses map to error variants:
+    ///
+    /// * 401/403 → `HttpErrorKind::AuthenticationFailed`
+    /// * 404 → `HttpErrorKind::NotFound`
+    /// * 429 → `HttpErrorKind::RateLimitExceeded`, with `retry_after` as the hint
+    /// * 5xx → `HttpErrorKind::ServerError`
+    ///
+    /// Any other status with a Deribit JSON-RPC error body becomes
+    /// `HttpErrorKind::Api`, exactly as a 200 response carrying that error would;
+    /// remaining bodies are returned as `HttpErrorKind::RequestFailed`. Either
+    /// way, a Deribit error body is kept in the error's [`ErrorContext`](crate::ErrorContext), see
+    /// [`HttpError::api_code`].
+    pub fn error_for_status(
+        &self,
+        status: u16,
+        retry_after: Option<Duration>,
+        body: &str,
+    ) -> HttpError {
+        let api_error = match serde_json::from_str::<ApiResponse<serde_json::Value>>(body) {
+            Ok(ApiResponse {
+                error: Some(error), ..
+            }) => Some(error),
+            _ => None,
+        };
+        let context = match &api_error {
+            Some(error) => ErrorContext::default().with_api_error(error),
+            None => ErrorContext::default(),
+        };
+        let message = || match &api_error {
+            Some(error) => format!("{} ({})", error.message, error.code),
+            None => body.to_string(),
+        };
+
+        let kind = match status {
+            401 | 403 => HttpErrorKind::AuthenticationFailed(message()),
+            404 => HttpErrorKind::NotFound(message()),
+            429 => HttpErrorKind::RateLimitExceeded { retry_after },
+            500..=599 => HttpErrorKind::ServerError {
+                status,
+                message: message(),
+            },
+            _ => match api_error {
+                Some(error) => HttpErrorKind::from(error),
+                None => HttpErrorKind::RequestFailed(format!("HTTP {} - {}", status, body)),
+            },
+        };
+        HttpError::from(kind).with_context(context)
+    }
+
+    /// Parse a `Retry-After` header value given in seconds
+    pub fn parse_retry_after(value: &str) -> Option<Duration> {
+        value
+            .trim()
+            .parse::<f64>()
+            .ok()
+            .and_then(|secs| Duration::try_from_secs_f64(secs).ok())
     }
 
     /// Check if response is successful
@@ -49,7 +112,10 @@ impl HttpResponseHandler {
     /// Handle rate limiting
     pub fn handle_rate_limit(&self, response: &HttpResponse) -> Result<(), HttpError> {
         if response.status == 429 {
-            return Err(HttpError::RateLimitExceeded);
+            return Err(HttpErrorKind::RateLimitExceeded {
+                retry_after: retry_after(response),
+            }
+            .into());
         }
         Ok(())
     }
@@ -57,14 +123,24 @@ impl HttpResponseHandler {
     /// Handle authentication errors
     pub fn handle_auth_error(&self, response: &HttpResponse) -> Result<(), HttpError> {
         if response.status == 401 || response.status == 403 {
-            return Err(HttpError::AuthenticationFailed(
+            return Err(HttpErrorKind::AuthenticationFailed(
                 "Authentication failed or expired".to_string(),
-            ));
+            )
+            .into());
         }
         Ok(())
     }
 }
 
+/// `Retry-After` hint from the response headers
+fn retry_after(response: &HttpResponse) -> Option<Duration> {
+    response
+        .headers
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case("retry-after"))
+        .and_then(|(_, value)| HttpResponseHandler::parse_retry_after(value))
+}
+
 impl Default for HttpResponseHandler {
     fn default() -> Self {
         Self::new()
@@ -2,13 +2,15 @@
 //!
 //! This module contains types for account access history.
 
+use crate::model::timestamp::TimestampMs;
+use crate::pagination::ContinuationToken;
 use serde::{Deserialize, Serialize};
 
 /// Access log entry representing a single access event
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct AccessLogEntry {
     /// Timestamp of the access event in milliseconds
-    pub timestamp: u64,
+    pub timestamp: TimestampMs,
     /// IP address from which the access occurred
     pub ip: String,
     /// Action performed (e.g., "login", "api_call")
@@ -38,7 +40,7 @@ pub struct AccessLogResponse {
     pub data: Vec<AccessLogEntry>,
     /// Continuation token for pagination
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub continuation: Option<String>,
+    pub continuation: Option<ContinuationToken>,
 }
 
 #[cfg(test)]
@@ -57,7 +59,7 @@ mod tests {
         }"#;
 
         let entry: AccessLogEntry = serde_json::from_str(json).expect("Failed to parse");
-        assert_eq!(entry.timestamp, 1550058362000);
+        assert_eq!(entry.timestamp.as_millis(), 1550058362000);
         assert_eq!(entry.ip, "192.168.1.1");
         assert_eq!(entry.action, Some("login".to_string()));
     }
@@ -76,6 +78,6 @@ mod tests {
 
         let response: AccessLogResponse = serde_json::from_str(json).expect("Failed to parse");
         assert_eq!(response.data.len(), 1);
-        assert_eq!(response.continuation, Some("abc123".to_string()));
+        assert_eq!(response.continuation.as_deref(), Some("abc123"));
     }
 }
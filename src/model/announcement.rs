@@ -2,6 +2,7 @@
 //!
 //! This module contains types for platform announcements.
 
+use crate::model::timestamp::TimestampMs;
 use serde::{Deserialize, Serialize};
 
 /// Platform announcement
@@ -14,7 +15,7 @@ pub struct Announcement {
     /// Announcement body/content in HTML format
     pub body: String,
     /// Publication timestamp in milliseconds
-    pub publication_timestamp: u64,
+    pub publication_timestamp: TimestampMs,
     /// Whether the announcement is important
     pub important: bool,
     /// Optional action URL
@@ -1,5 +1,6 @@
 //! API key management models and types
 
+use crate::model::timestamp::TimestampMs;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
@@ -32,7 +33,7 @@ pub struct ApiKeyInfo {
     /// Whether this API key is the default (deprecated, will be removed)
     pub default: bool,
     /// Timestamp when the key was created or last modified, in milliseconds since Unix epoch
-    pub timestamp: u64,
+    pub timestamp: TimestampMs,
     /// List of enabled advanced on-key features.
     ///
     /// Available options:
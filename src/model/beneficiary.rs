@@ -1,5 +1,7 @@
 //! Address beneficiary models for wallet endpoints
 
+use crate::model::timestamp::TimestampMs;
+use crate::pagination::ContinuationToken;
 use serde::{Deserialize, Serialize};
 
 /// Address beneficiary information returned by save/get/list operations.
@@ -47,10 +49,10 @@ pub struct AddressBeneficiary {
     pub tag: Option<String>,
     /// Creation timestamp in milliseconds since Unix epoch
     #[serde(default, alias = "created")]
-    pub creation_timestamp: Option<u64>,
+    pub creation_timestamp: Option<TimestampMs>,
     /// Update timestamp in milliseconds since Unix epoch
     #[serde(default, alias = "updated")]
-    pub update_timestamp: Option<u64>,
+    pub update_timestamp: Option<TimestampMs>,
 }
 
 /// Request parameters for saving an address beneficiary.
@@ -149,7 +151,7 @@ pub struct ListAddressBeneficiariesResponse {
     pub count: Option<u64>,
     /// Continuation token for fetching the next page
     #[serde(default)]
-    pub continuation: Option<String>,
+    pub continuation: Option<ContinuationToken>,
 }
 
 /// Deposit identifier for `set_clearance_originator`.
@@ -211,10 +213,10 @@ pub struct ClearanceDepositResult {
     pub source_address: Option<String>,
     /// Timestamp when deposit was received, in milliseconds
     #[serde(default)]
-    pub received_timestamp: Option<u64>,
+    pub received_timestamp: Option<TimestampMs>,
     /// Timestamp when deposit was last updated, in milliseconds
     #[serde(default)]
-    pub updated_timestamp: Option<u64>,
+    pub updated_timestamp: Option<TimestampMs>,
     /// Optional note
     #[serde(default)]
     pub note: Option<String>,
@@ -265,7 +267,10 @@ mod tests {
             Some("Money's Gone".to_string())
         );
         assert_eq!(beneficiary.beneficiary_first_name, Some("John".to_string()));
-        assert_eq!(beneficiary.creation_timestamp, Some(1536569522277));
+        assert_eq!(
+            beneficiary.creation_timestamp,
+            Some(TimestampMs(1536569522277))
+        );
     }
 
     #[test]
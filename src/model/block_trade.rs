@@ -3,6 +3,8 @@
 //! This module contains request and response types for block trade endpoints,
 //! including executing, verifying, and managing block trades.
 
+use crate::model::timestamp::TimestampMs;
+use crate::pagination::ContinuationToken;
 use serde::{Deserialize, Serialize};
 
 /// Role in a block trade (maker or taker).
@@ -89,7 +91,7 @@ impl BlockTradeItem {
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ExecuteBlockTradeRequest {
     /// Timestamp shared with other party, in milliseconds since UNIX epoch
-    pub timestamp: u64,
+    pub timestamp: TimestampMs,
     /// Nonce shared with other party
     pub nonce: String,
     /// Role in the trade (maker or taker)
@@ -104,7 +106,7 @@ pub struct ExecuteBlockTradeRequest {
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct VerifyBlockTradeRequest {
     /// Timestamp shared with other party, in milliseconds since UNIX epoch
-    pub timestamp: u64,
+    pub timestamp: TimestampMs,
     /// Nonce shared with other party
     pub nonce: String,
     /// Role in the trade (maker or taker)
@@ -123,6 +125,19 @@ pub struct SimulateBlockTradeRequest {
     pub trades: Vec<BlockTradeItem>,
 }
 
+impl SimulateBlockTradeRequest {
+    /// Creates a new simulation request.
+    ///
+    /// # Arguments
+    ///
+    /// * `role` - Role in the trade; `None` lets the exchange check both sides
+    /// * `trades` - The legs that would be passed to `execute_block_trade`
+    #[must_use]
+    pub fn new(role: Option<BlockTradeRole>, trades: Vec<BlockTradeItem>) -> Self {
+        Self { role, trades }
+    }
+}
+
 /// Request parameters for getting block trades with filters.
 #[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 pub struct GetBlockTradesRequest {
@@ -137,10 +152,10 @@ pub struct GetBlockTradesRequest {
     pub continuation: Option<String>,
     /// Filter trades from this timestamp (milliseconds since UNIX epoch)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_timestamp: Option<u64>,
+    pub start_timestamp: Option<TimestampMs>,
     /// Filter trades up to this timestamp (milliseconds since UNIX epoch)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_timestamp: Option<u64>,
+    pub end_timestamp: Option<TimestampMs>,
 }
 
 /// Request parameters for getting block trade requests.
@@ -160,7 +175,7 @@ pub struct BlockTradeTradeInfo {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub trade_seq: Option<u64>,
     /// Timestamp in milliseconds since UNIX epoch
-    pub timestamp: u64,
+    pub timestamp: TimestampMs,
     /// Direction of the tick (0=Plus, 1=Zero-Plus, 2=Minus, 3=Zero-Minus)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tick_direction: Option<i32>,
@@ -249,7 +264,7 @@ pub struct BlockTrade {
     /// Block trade ID
     pub id: String,
     /// Timestamp in milliseconds since UNIX epoch
-    pub timestamp: u64,
+    pub timestamp: TimestampMs,
     /// List of trades in this block trade
     pub trades: Vec<BlockTradeTradeInfo>,
     /// Name of the application that executed the block trade (optional)
@@ -269,7 +284,7 @@ pub struct BlockTradeResult {
     /// Block trade ID
     pub id: String,
     /// Timestamp in milliseconds since UNIX epoch
-    pub timestamp: u64,
+    pub timestamp: TimestampMs,
     /// List of executed trades
     pub trades: Vec<BlockTradeTradeInfo>,
 }
@@ -285,7 +300,7 @@ pub struct BlockTradeSignature {
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct BlockTradeRequest {
     /// Timestamp in milliseconds since UNIX epoch
-    pub timestamp: u64,
+    pub timestamp: TimestampMs,
     /// Nonce shared with other party
     pub nonce: String,
     /// Role expected for this request
@@ -311,7 +326,7 @@ pub struct GetBlockTradesResponse {
     pub block_trades: Vec<BlockTrade>,
     /// Continuation token for pagination
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub continuation: Option<String>,
+    pub continuation: Option<ContinuationToken>,
 }
 
 #[cfg(test)]
@@ -366,7 +381,7 @@ mod tests {
     #[test]
     fn test_execute_block_trade_request_serialization() {
         let request = ExecuteBlockTradeRequest {
-            timestamp: 1565172650935,
+            timestamp: TimestampMs(1565172650935),
             nonce: "test_nonce".to_string(),
             role: BlockTradeRole::Maker,
             trades: vec![BlockTradeItem::new(
@@ -387,7 +402,7 @@ mod tests {
     #[test]
     fn test_verify_block_trade_request_serialization() {
         let request = VerifyBlockTradeRequest {
-            timestamp: 1565172650935,
+            timestamp: TimestampMs(1565172650935),
             nonce: "test_nonce".to_string(),
             role: BlockTradeRole::Taker,
             trades: vec![BlockTradeItem::new(
@@ -3,6 +3,7 @@
    Email: jb@taunais.com
    Date: 15/9/25
 ******************************************************************************/
+use crate::model::timestamp::TimestampMs;
 use pretty_simple_display::{DebugPretty, DisplaySimple};
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
@@ -48,7 +49,7 @@ pub struct BookSummary {
     /// 8h funding rate (perpetuals only)
     pub funding_8h: Option<f64>,
     /// Creation timestamp (milliseconds since Unix epoch)
-    pub creation_timestamp: i64,
+    pub creation_timestamp: TimestampMs,
     /// Underlying index name
     pub underlying_index: Option<String>,
     /// Underlying price
@@ -64,7 +65,7 @@ impl BookSummary {
         base_currency: String,
         quote_currency: String,
         mark_price: f64,
-        creation_timestamp: i64,
+        creation_timestamp: TimestampMs,
     ) -> Self {
         Self {
             instrument_name,
@@ -289,7 +290,7 @@ pub struct OrderBook {
     /// Instrument name
     pub instrument_name: String,
     /// Timestamp of the order book
-    pub timestamp: i64,
+    pub timestamp: TimestampMs,
     /// Bid levels (sorted by price descending)
     pub bids: Vec<OrderBookEntry>,
     /// Ask levels (sorted by price ascending)
@@ -302,7 +303,7 @@ pub struct OrderBook {
 
 impl OrderBook {
     /// Create a new empty order book
-    pub fn new(instrument_name: String, timestamp: i64, change_id: u64) -> Self {
+    pub fn new(instrument_name: String, timestamp: TimestampMs, change_id: u64) -> Self {
         Self {
             instrument_name,
             timestamp,
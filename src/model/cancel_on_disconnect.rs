@@ -0,0 +1,66 @@
+//! Cancel-on-disconnect models for Deribit API
+//!
+//! This module contains types for the cancel-on-disconnect (COD) setting,
+//! which cancels open orders when the session's connection drops.
+
+use serde::{Deserialize, Serialize};
+
+/// Scope of the cancel-on-disconnect setting
+///
+/// `Connection` only applies to the WebSocket connection making the call, so
+/// over HTTP the `Account` scope is the meaningful one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CancelOnDisconnectScope {
+    /// The current connection only (Deribit's default)
+    #[default]
+    Connection,
+    /// Every connection of the account
+    Account,
+}
+
+impl CancelOnDisconnectScope {
+    /// Returns the scope as a string for API requests
+    #[must_use]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Connection => "connection",
+            Self::Account => "account",
+        }
+    }
+}
+
+impl std::fmt::Display for CancelOnDisconnectScope {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// Cancel-on-disconnect setting
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CancelOnDisconnect {
+    /// Scope the setting applies to
+    pub scope: CancelOnDisconnectScope,
+    /// Whether open orders are cancelled on disconnect
+    pub enabled: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scope_serialization() {
+        let json = serde_json::to_string(&CancelOnDisconnectScope::Account).unwrap();
+        assert_eq!(json, "\"account\"");
+        assert_eq!(CancelOnDisconnectScope::default().as_str(), "connection");
+    }
+
+    #[test]
+    fn test_cancel_on_disconnect_deserialization() {
+        let json = r#"{"scope": "account", "enabled": true}"#;
+        let cod: CancelOnDisconnect = serde_json::from_str(json).unwrap();
+        assert_eq!(cod.scope, CancelOnDisconnectScope::Account);
+        assert!(cod.enabled);
+    }
+}
@@ -8,6 +8,7 @@
 //! This module provides types for combo instrument operations including
 //! creating combos and calculating leg prices.
 
+use crate::model::timestamp::TimestampMs;
 use serde::{Deserialize, Serialize};
 
 /// Combo state enumeration
@@ -84,9 +85,9 @@ pub struct Combo {
     /// Current state of the combo
     pub state: ComboState,
     /// Timestamp of the last state change in milliseconds since Unix epoch
-    pub state_timestamp: u64,
+    pub state_timestamp: TimestampMs,
     /// Timestamp when the combo was created in milliseconds since Unix epoch
-    pub creation_timestamp: u64,
+    pub creation_timestamp: TimestampMs,
     /// List of legs that make up this combo
     pub legs: Vec<ComboLeg>,
 }
@@ -162,6 +163,44 @@ impl ComboTrade {
     }
 }
 
+/// Request for the `create_combo` endpoint
+///
+/// Lists the legs of the combo; Deribit returns the existing combo if one
+/// with the same legs is already listed.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CreateComboRequest {
+    /// Legs of the combo
+    pub trades: Vec<ComboTrade>,
+}
+
+impl CreateComboRequest {
+    /// Creates a request from a list of legs
+    #[must_use]
+    pub fn new(trades: Vec<ComboTrade>) -> Self {
+        Self { trades }
+    }
+
+    /// Adds a bought leg
+    #[must_use]
+    pub fn buy(mut self, instrument_name: impl Into<String>, amount: Option<f64>) -> Self {
+        self.trades.push(ComboTrade::buy(instrument_name, amount));
+        self
+    }
+
+    /// Adds a sold leg
+    #[must_use]
+    pub fn sell(mut self, instrument_name: impl Into<String>, amount: Option<f64>) -> Self {
+        self.trades.push(ComboTrade::sell(instrument_name, amount));
+        self
+    }
+}
+
+impl From<Vec<ComboTrade>> for CreateComboRequest {
+    fn from(trades: Vec<ComboTrade>) -> Self {
+        Self::new(trades)
+    }
+}
+
 /// Leg input for `get_leg_prices` endpoint
 ///
 /// Specifies the parameters for calculating individual leg prices.
@@ -307,6 +346,20 @@ mod tests {
         assert!(trade.amount.is_none());
     }
 
+    #[test]
+    fn test_create_combo_request_builder() {
+        let request = CreateComboRequest::default()
+            .buy("BTC-29APR22-37500-C", Some(1.0))
+            .sell("BTC-29APR22-37500-P", Some(1.0));
+        assert_eq!(
+            request,
+            CreateComboRequest::from(vec![
+                ComboTrade::buy("BTC-29APR22-37500-C", Some(1.0)),
+                ComboTrade::sell("BTC-29APR22-37500-P", Some(1.0)),
+            ])
+        );
+    }
+
     #[test]
     fn test_leg_input_new() {
         let leg = LegInput::new("BTC-1NOV24-67000-C", 2.0, "buy");
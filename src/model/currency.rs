@@ -9,7 +9,11 @@ use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
 /// Supported cryptocurrency currencies in the Deribit platform
-#[derive(Debug, Clone, Serialize, Deserialize)]
+///
+/// Endpoints taking `currency: impl AsRef<str>` and request constructors
+/// taking `currency: impl Into<String>` accept either this enum or a plain
+/// currency symbol.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "UPPERCASE")]
 pub enum Currency {
     /// Bitcoin cryptocurrency
@@ -24,18 +28,37 @@ pub enum Currency {
     Eurr,
 }
 
-impl std::fmt::Display for Currency {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl Currency {
+    /// Returns the currency symbol as sent to the API
+    pub fn as_str(&self) -> &'static str {
         match self {
-            Currency::Btc => write!(f, "BTC"),
-            Currency::Eth => write!(f, "ETH"),
-            Currency::Usdc => write!(f, "USDC"),
-            Currency::Usdt => write!(f, "USDT"),
-            Currency::Eurr => write!(f, "EURR"),
+            Currency::Btc => "BTC",
+            Currency::Eth => "ETH",
+            Currency::Usdc => "USDC",
+            Currency::Usdt => "USDT",
+            Currency::Eurr => "EURR",
         }
     }
 }
 
+impl AsRef<str> for Currency {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl From<Currency> for String {
+    fn from(currency: Currency) -> Self {
+        currency.as_str().to_string()
+    }
+}
+
+impl std::fmt::Display for Currency {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
 /// Currency structure
 #[skip_serializing_none]
 #[derive(DebugPretty, DisplaySimple, Clone, Serialize, Deserialize)]
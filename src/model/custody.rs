@@ -2,6 +2,7 @@
 //!
 //! This module contains types for custody accounts.
 
+use crate::model::timestamp::TimestampMs;
 use serde::{Deserialize, Serialize};
 
 /// Custody account information
@@ -23,7 +24,7 @@ pub struct CustodyAccount {
     pub status: Option<String>,
     /// Account creation timestamp in milliseconds
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_timestamp: Option<u64>,
+    pub creation_timestamp: Option<TimestampMs>,
 }
 
 #[cfg(test)]
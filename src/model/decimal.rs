@@ -0,0 +1,231 @@
+/******************************************************************************
+   Author: Joaquín Béjar García
+   Email: jb@taunais.com
+   Date: 15/10/26
+******************************************************************************/
+
+//! Exact decimal views of monetary fields (requires the `decimal` feature)
+//!
+//! Models keep their `f64` fields whatever the features, so enabling
+//! `decimal` never changes a public type. It adds `*_decimal` accessors for
+//! the price, amount, fee and margin fields of orders, user trades, positions
+//! and account summaries, and `*_decimal` setters on order request builders.
+//!
+//! Fields are converted through their shortest round-trip representation, so
+//! a value Deribit sent as `50000.1` reads back as exactly `50000.1`, not as
+//! the nearest binary float.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use deribit_http::model::decimal::{Decimal, to_decimal};
+//! use std::str::FromStr;
+//!
+//! assert_eq!(to_decimal(0.1), Some(Decimal::from_str("0.1").unwrap()));
+//! assert_eq!(to_decimal(f64::NAN), None);
+//! ```
+
+use crate::model::position::Position;
+use crate::model::request::linked_order::LinkedOrder;
+use crate::model::request::order::OrderRequestBuilder;
+use crate::model::response::order::OrderInfoResponse;
+use crate::model::response::other::AccountResult;
+use crate::model::trade::UserTrade;
+use rust_decimal::prelude::{FromPrimitive, ToPrimitive};
+use std::str::FromStr;
+
+pub use rust_decimal::Decimal;
+
+/// Converts an `f64` into the [`Decimal`] with the same shortest representation
+///
+/// Returns `None` for NaN, infinities and values outside `Decimal`'s range.
+pub fn to_decimal(value: f64) -> Option<Decimal> {
+    if !value.is_finite() {
+        return None;
+    }
+    Decimal::from_str(&value.to_string())
+        .ok()
+        .or_else(|| Decimal::from_f64(value))
+}
+
+/// Converts a [`Decimal`] into the nearest `f64`
+pub fn from_decimal(value: Decimal) -> f64 {
+    value.to_f64().unwrap_or_default()
+}
+
+/// A monetary field that can be read as a [`Decimal`]
+trait DecimalField {
+    fn decimal(&self) -> Option<Decimal>;
+}
+
+impl DecimalField for f64 {
+    fn decimal(&self) -> Option<Decimal> {
+        to_decimal(*self)
+    }
+}
+
+impl DecimalField for Option<f64> {
+    fn decimal(&self) -> Option<Decimal> {
+        self.and_then(to_decimal)
+    }
+}
+
+/// Adds a `Decimal` accessor per listed field of a model
+macro_rules! decimal_accessors {
+    ($model:ty { $($field:ident => $accessor:ident),* $(,)? }) => {
+        impl $model {
+            $(
+                #[doc = concat!("`", stringify!($field), "` as a [`Decimal`], if set and finite")]
+                pub fn $accessor(&self) -> Option<Decimal> {
+                    self.$field.decimal()
+                }
+            )*
+        }
+    };
+}
+
+decimal_accessors!(OrderInfoResponse {
+    amount => amount_decimal,
+    average_price => average_price_decimal,
+    filled_amount => filled_amount_decimal,
+    max_show => max_show_decimal,
+    price => price_decimal,
+    profit_loss => profit_loss_decimal,
+});
+
+decimal_accessors!(UserTrade {
+    amount => amount_decimal,
+    contracts => contracts_decimal,
+    fee => fee_decimal,
+    index_price => index_price_decimal,
+    mark_price => mark_price_decimal,
+    price => price_decimal,
+    profit_loss => profit_loss_decimal,
+});
+
+decimal_accessors!(Position {
+    average_price => average_price_decimal,
+    average_price_usd => average_price_usd_decimal,
+    estimated_liquidation_price => estimated_liquidation_price_decimal,
+    floating_profit_loss => floating_profit_loss_decimal,
+    floating_profit_loss_usd => floating_profit_loss_usd_decimal,
+    index_price => index_price_decimal,
+    initial_margin => initial_margin_decimal,
+    maintenance_margin => maintenance_margin_decimal,
+    mark_price => mark_price_decimal,
+    open_orders_margin => open_orders_margin_decimal,
+    realized_funding => realized_funding_decimal,
+    realized_profit_loss => realized_profit_loss_decimal,
+    settlement_price => settlement_price_decimal,
+    size => size_decimal,
+    size_currency => size_currency_decimal,
+    total_profit_loss => total_profit_loss_decimal,
+    unrealized_profit_loss => unrealized_profit_loss_decimal,
+});
+
+decimal_accessors!(AccountResult {
+    balance => balance_decimal,
+    equity => equity_decimal,
+    available_funds => available_funds_decimal,
+    margin_balance => margin_balance_decimal,
+    total_pl => total_pl_decimal,
+    session_rpl => session_rpl_decimal,
+    session_upl => session_upl_decimal,
+    maintenance_margin => maintenance_margin_decimal,
+    initial_margin => initial_margin_decimal,
+    available_withdrawal_funds => available_withdrawal_funds_decimal,
+    projected_initial_margin => projected_initial_margin_decimal,
+    projected_maintenance_margin => projected_maintenance_margin_decimal,
+    fee_balance => fee_balance_decimal,
+});
+
+impl OrderRequestBuilder {
+    /// Set the amount from a [`Decimal`]
+    pub fn amount_decimal(self, amount: Decimal) -> Self {
+        self.amount(from_decimal(amount))
+    }
+
+    /// Set the number of contracts from a [`Decimal`]
+    pub fn contracts_decimal(self, contracts: Decimal) -> Self {
+        self.contracts(from_decimal(contracts))
+    }
+
+    /// Set the limit price from a [`Decimal`]
+    pub fn price_decimal(self, price: Decimal) -> Self {
+        self.price(from_decimal(price))
+    }
+
+    /// Set the trigger price from a [`Decimal`]
+    pub fn trigger_price_decimal(self, trigger_price: Decimal) -> Self {
+        self.trigger_price(from_decimal(trigger_price))
+    }
+}
+
+impl LinkedOrder {
+    /// Set the limit price from a [`Decimal`]
+    pub fn price_decimal(self, price: Decimal) -> Self {
+        self.price(from_decimal(price))
+    }
+
+    /// Set the trigger price from a [`Decimal`]
+    pub fn trigger_price_decimal(self, trigger_price: Decimal) -> Self {
+        self.trigger_price(from_decimal(trigger_price))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::request::order::OrderRequest;
+    use serde_json::json;
+
+    #[test]
+    fn test_to_decimal_rejects_non_finite() {
+        assert_eq!(to_decimal(f64::NAN), None);
+        assert_eq!(to_decimal(f64::INFINITY), None);
+        let price = to_decimal(50_000.5).unwrap();
+        assert_eq!(from_decimal(price), 50_000.5);
+    }
+
+    #[test]
+    fn test_user_trade_decimals_keep_the_sent_digits() {
+        let trade: UserTrade = serde_json::from_value(json!({
+            "amount": 10.0,
+            "direction": "buy",
+            "fee": 0.0000012,
+            "fee_currency": "BTC",
+            "index_price": 50_000.12,
+            "instrument_name": "BTC-PERPETUAL",
+            "liquidity": "T",
+            "mark_price": 50_001.5,
+            "order_id": "1",
+            "order_type": "limit",
+            "price": 50_000.1,
+            "self_trade": false,
+            "state": "filled",
+            "tick_direction": 0,
+            "timestamp": 1_700_000_000_000u64,
+            "trade_id": "2",
+            "trade_seq": 3
+        }))
+        .unwrap();
+
+        assert_eq!(trade.price, 50_000.1);
+        assert_eq!(trade.price_decimal().unwrap().to_string(), "50000.1");
+        assert_eq!(trade.fee_decimal().unwrap().to_string(), "0.0000012");
+        assert_eq!(trade.profit_loss_decimal(), None);
+    }
+
+    #[test]
+    fn test_order_request_builder_takes_decimals() {
+        let request: OrderRequest = OrderRequest::builder()
+            .instrument_name("BTC-PERPETUAL")
+            .amount_decimal(Decimal::from(10))
+            .price_decimal(Decimal::from_str("50000.5").unwrap())
+            .build()
+            .unwrap();
+
+        assert_eq!(request.amount, Some(10.0));
+        assert_eq!(request.price, Some(50_000.5));
+    }
+}
@@ -3,6 +3,7 @@
    Email: jb@taunais.com
    Date: 15/9/25
 ******************************************************************************/
+use crate::model::timestamp::TimestampMs;
 use pretty_simple_display::{DebugPretty, DisplaySimple};
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
@@ -20,10 +21,10 @@ pub struct Deposit {
     /// Current state of the deposit
     pub state: String,
     /// Timestamp when deposit was received
-    pub received_timestamp: u64,
+    pub received_timestamp: TimestampMs,
     /// Transaction ID on the blockchain
     pub transaction_id: Option<String>,
     /// Timestamp when deposit was last updated
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub updated_timestamp: Option<u64>,
+    pub updated_timestamp: Option<TimestampMs>,
 }
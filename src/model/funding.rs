@@ -3,8 +3,42 @@
    Email: jb@taunais.com
    Date: 15/9/25
 ******************************************************************************/
+use crate::model::timestamp::TimestampMs;
+use crate::model::transaction::TransactionLogEntry;
 use pretty_simple_display::{DebugPretty, DisplaySimple};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Period covered by `get_funding_chart_data`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum FundingChartLength {
+    /// Last 8 hours
+    #[serde(rename = "8h")]
+    Hours8,
+    /// Last 24 hours
+    #[serde(rename = "24h")]
+    Hours24,
+    /// Last month
+    #[serde(rename = "1m")]
+    Month1,
+}
+
+impl FundingChartLength {
+    /// Returns the string representation of the length
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FundingChartLength::Hours8 => "8h",
+            FundingChartLength::Hours24 => "24h",
+            FundingChartLength::Month1 => "1m",
+        }
+    }
+}
+
+impl std::fmt::Display for FundingChartLength {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
 
 /// Funding chart data structure
 #[derive(DebugPretty, DisplaySimple, Clone, Serialize, Deserialize)]
@@ -42,12 +76,12 @@ pub struct FundingDataPoint {
     /// 8h interest rate
     pub interest_8h: f64,
     /// Timestamp of the data point
-    pub timestamp: u64,
+    pub timestamp: TimestampMs,
 }
 
 impl FundingDataPoint {
     /// Create new funding data point
-    pub fn new(index_price: f64, interest_8h: f64, timestamp: u64) -> Self {
+    pub fn new(index_price: f64, interest_8h: f64, timestamp: TimestampMs) -> Self {
         Self {
             index_price,
             interest_8h,
@@ -60,7 +94,7 @@ impl FundingDataPoint {
 #[derive(DebugPretty, DisplaySimple, Clone, Serialize, Deserialize)]
 pub struct FundingRateData {
     /// Timestamp of the funding event
-    pub timestamp: u64,
+    pub timestamp: TimestampMs,
     /// Index price at the time
     pub index_price: f64,
     /// 8h interest rate
@@ -74,7 +108,7 @@ pub struct FundingRateData {
 impl FundingRateData {
     /// Create new funding rate data
     pub fn new(
-        timestamp: u64,
+        timestamp: TimestampMs,
         index_price: f64,
         interest_8h: f64,
         interest_1h: f64,
@@ -89,3 +123,93 @@ impl FundingRateData {
         }
     }
 }
+
+/// Funding realized on one perpetual in a [`FundingReport`]
+#[derive(DebugPretty, DisplaySimple, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct FundingPnl {
+    /// Perpetual instrument name, e.g. `BTC-PERPETUAL`
+    pub instrument_name: String,
+    /// Net funding, in the report's currency; positive when received
+    pub realized: f64,
+    /// Funding received, summed over the entries that paid the account
+    pub received: f64,
+    /// Funding paid, as a positive amount
+    pub paid: f64,
+    /// Number of transaction log entries that realized funding
+    pub entries: usize,
+    /// Mean 8h funding rate of the instrument over the report's window, if fetched
+    pub average_rate_8h: Option<f64>,
+}
+
+/// Funding realized per perpetual over a time window, built by
+/// `DeribitHttpClient::get_funding_pnl`
+///
+/// Funding is read from the `interest_pl` of the transaction log's trade and
+/// settlement entries on perpetuals, which Deribit books when a position
+/// changes and at each settlement.
+#[derive(DebugPretty, DisplaySimple, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FundingReport {
+    /// Currency of the transaction log and of the amounts
+    pub currency: String,
+    /// Start of the window
+    pub start_timestamp: TimestampMs,
+    /// End of the window
+    pub end_timestamp: TimestampMs,
+    /// Funding per perpetual, sorted by instrument name
+    pub instruments: Vec<FundingPnl>,
+}
+
+impl FundingReport {
+    /// Sum the funding realized by `entries` per perpetual
+    ///
+    /// Entries on other instruments, or without `interest_pl`, are ignored.
+    pub fn from_transactions<'a>(
+        currency: impl Into<String>,
+        start_timestamp: TimestampMs,
+        end_timestamp: TimestampMs,
+        entries: impl IntoIterator<Item = &'a TransactionLogEntry>,
+    ) -> Self {
+        let mut instruments: BTreeMap<&str, FundingPnl> = BTreeMap::new();
+        for entry in entries {
+            let (Some(instrument_name), Some(funding)) =
+                (entry.instrument_name.as_deref(), entry.interest_pl)
+            else {
+                continue;
+            };
+            if !instrument_name.ends_with("-PERPETUAL") {
+                continue;
+            }
+            let pnl = instruments
+                .entry(instrument_name)
+                .or_insert_with(|| FundingPnl {
+                    instrument_name: instrument_name.to_string(),
+                    ..FundingPnl::default()
+                });
+            pnl.realized += funding;
+            if funding >= 0.0 {
+                pnl.received += funding;
+            } else {
+                pnl.paid -= funding;
+            }
+            pnl.entries += 1;
+        }
+        Self {
+            currency: currency.into(),
+            start_timestamp,
+            end_timestamp,
+            instruments: instruments.into_values().collect(),
+        }
+    }
+
+    /// Funding of `instrument_name`, if it realized any
+    pub fn instrument(&self, instrument_name: &str) -> Option<&FundingPnl> {
+        self.instruments
+            .iter()
+            .find(|pnl| pnl.instrument_name == instrument_name)
+    }
+
+    /// Net funding across instruments; positive when received
+    pub fn total(&self) -> f64 {
+        self.instruments.iter().map(|pnl| pnl.realized).sum()
+    }
+}
@@ -3,6 +3,7 @@
    Email: jb@taunais.com
    Date: 15/9/25
 ******************************************************************************/
+use crate::model::timestamp::TimestampMs;
 use pretty_simple_display::{DebugPretty, DisplaySimple};
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
@@ -41,7 +42,7 @@ pub struct IndexPriceData {
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct IndexChartDataPoint {
     /// Timestamp in milliseconds since Unix epoch
-    pub timestamp: u64,
+    pub timestamp: TimestampMs,
     /// Average index price at that timestamp
     pub price: f64,
 }
@@ -51,10 +52,10 @@ impl IndexChartDataPoint {
     ///
     /// # Arguments
     ///
-    /// * `timestamp` - Timestamp in milliseconds since Unix epoch
+    /// * `timestamp` - Time of the data point
     /// * `price` - Average index price at that timestamp
     #[must_use]
-    pub fn new(timestamp: u64, price: f64) -> Self {
+    pub fn new(timestamp: TimestampMs, price: f64) -> Self {
         Self { timestamp, price }
     }
 }
@@ -77,7 +78,7 @@ impl<'de> Deserialize<'de> for IndexChartDataPoint {
         // Deserialize from [timestamp, price] tuple
         let (timestamp_f64, price): (f64, f64) = Deserialize::deserialize(deserializer)?;
         // Convert timestamp from f64 to u64 (API returns it as a number)
-        let timestamp = timestamp_f64 as u64;
+        let timestamp = TimestampMs(timestamp_f64 as u64);
         Ok(Self { timestamp, price })
     }
 }
@@ -3,13 +3,16 @@
    Email: jb@taunais.com
    Date: 15/9/25
 ******************************************************************************/
+use crate::model::timestamp::TimestampMs;
 use pretty_simple_display::{DebugPretty, DisplaySimple};
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 use std::fmt::Display;
 
 /// Instrument kind enumeration
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+///
+/// `Combo` and `Any` only appear as endpoint filters, never on instruments.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum InstrumentKind {
     /// Future contract
@@ -24,20 +27,33 @@ pub enum InstrumentKind {
     /// Option combo
     #[serde(rename = "option_combo")]
     OptionCombo,
+    /// Any combo (filter only)
+    Combo,
+    /// Any kind (filter only)
+    Any,
 }
 
-impl Display for InstrumentKind {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl InstrumentKind {
+    /// Returns the string representation of the instrument kind
+    pub fn as_str(&self) -> &'static str {
         match self {
-            InstrumentKind::Future => write!(f, "future"),
-            InstrumentKind::Option => write!(f, "option"),
-            InstrumentKind::Spot => write!(f, "spot"),
-            InstrumentKind::FutureCombo => write!(f, "future_combo"),
-            InstrumentKind::OptionCombo => write!(f, "option_combo"),
+            InstrumentKind::Future => "future",
+            InstrumentKind::Option => "option",
+            InstrumentKind::Spot => "spot",
+            InstrumentKind::FutureCombo => "future_combo",
+            InstrumentKind::OptionCombo => "option_combo",
+            InstrumentKind::Combo => "combo",
+            InstrumentKind::Any => "any",
         }
     }
 }
 
+impl Display for InstrumentKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
 /// Instrument type enumeration
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
@@ -63,13 +79,15 @@ pub struct Instrument {
     /// Whether the instrument is active for trading
     pub is_active: Option<bool>,
     /// Expiration timestamp (None for perpetuals)
-    pub expiration_timestamp: Option<i64>,
+    pub expiration_timestamp: Option<TimestampMs>,
     /// Strike price (for options)
     pub strike: Option<f64>,
     /// Option type (call/put, for options only)
     pub option_type: Option<OptionType>,
     /// Minimum price movement
     pub tick_size: Option<f64>,
+    /// Coarser tick sizes that apply above given prices
+    pub tick_size_steps: Option<Vec<TickSizeStep>>,
     /// Minimum trade amount
     pub min_trade_amount: Option<f64>,
     /// Contract size
@@ -83,7 +101,7 @@ pub struct Instrument {
     /// Settlement currency
     pub settlement_currency: Option<String>,
     /// Creation timestamp
-    pub creation_timestamp: Option<i64>,
+    pub creation_timestamp: Option<TimestampMs>,
     /// Maximum leverage
     pub max_leverage: Option<f64>,
     /// Maker commission rate
@@ -98,7 +116,42 @@ pub struct Instrument {
     pub counter_currency: Option<String>,
 }
 
+/// Tick size that applies above a price threshold
+#[derive(DebugPretty, DisplaySimple, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct TickSizeStep {
+    /// Price above which `tick_size` applies
+    pub above_price: f64,
+    /// Tick size above `above_price`
+    pub tick_size: f64,
+}
+
 impl Instrument {
+    /// Tick size that applies at `price`
+    ///
+    /// Uses the coarsest `tick_size_steps` entry whose threshold `price`
+    /// exceeds, falling back to the base `tick_size`.
+    pub fn tick_size_at(&self, price: f64) -> Option<f64> {
+        let step = self
+            .tick_size_steps
+            .iter()
+            .flatten()
+            .filter(|step| price.abs() > step.above_price)
+            .max_by(|a, b| a.above_price.total_cmp(&b.above_price));
+        step.map(|step| step.tick_size).or(self.tick_size)
+    }
+
+    /// Round `price` to the nearest valid tick
+    ///
+    /// Returns `None` if the instrument has no positive tick size.
+    pub fn round_to_tick(&self, price: f64) -> Option<f64> {
+        let tick = self.tick_size_at(price).filter(|tick| *tick > 0.0)?;
+        let rounded = (price / tick).round() * tick;
+        // Trim the float noise left by the multiplication, e.g. 0.1 * 3
+        let decimals = (-tick.log10()).ceil().max(0.0) as i32 + 2;
+        let scale = 10f64.powi(decimals);
+        Some((rounded * scale).round() / scale)
+    }
+
     /// Check if the instrument is a perpetual contract
     pub fn is_perpetual(&self) -> bool {
         self.expiration_timestamp.is_none()
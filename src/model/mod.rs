@@ -17,12 +17,17 @@ pub mod beneficiary;
 pub mod block_trade;
 /// Order book models
 pub mod book;
+/// Cancel-on-disconnect models
+pub mod cancel_on_disconnect;
 /// Combo books models for multi-leg instruments
 pub mod combo;
 /// Currency and expiration models
 pub mod currency;
 /// Custody account models
 pub mod custody;
+/// Exact decimal views of monetary fields (requires the `decimal` feature)
+#[cfg(feature = "decimal")]
+pub mod decimal;
 /// Deposit-related models
 pub mod deposit;
 /// Email settings models
@@ -41,10 +46,14 @@ pub mod margin_model;
 pub mod mass_quote;
 /// Option contract models and types
 pub mod option;
+/// Option chain of one expiry with quotes, IVs and greeks per strike
+pub mod option_chain;
 /// Order models and types
 pub mod order;
 /// Other miscellaneous models
 pub mod other;
+/// Cross-currency portfolio overview models
+pub mod portfolio;
 /// Portfolio simulation models
 pub mod portfolio_simulation;
 /// Position models
@@ -53,12 +62,20 @@ pub mod position;
 pub mod request;
 /// Response models and structures
 pub mod response;
+/// Request-for-quote models
+pub mod rfq;
 /// Self-trading configuration models
 pub mod self_trading;
 /// Settlement models
 pub mod settlement;
+/// Multi-leg option strategy models
+pub mod strategy;
 /// Ticker data models
 pub mod ticker;
+/// Time window type for time-bounded endpoints
+pub mod time_range;
+/// Millisecond timestamp type
+pub mod timestamp;
 /// Trade execution models
 pub mod trade;
 /// Trading products configuration
@@ -88,6 +105,7 @@ pub use api_key::*;
 pub use beneficiary::*;
 pub use block_trade::*;
 pub use book::*;
+pub use cancel_on_disconnect::*;
 pub use combo::*;
 pub use currency::*;
 pub use custody::*;
@@ -100,15 +118,21 @@ pub use instrument::*;
 pub use margin_model::*;
 pub use mass_quote::*;
 pub use option::*;
+pub use option_chain::*;
 pub use order::*;
 pub use other::*;
+pub use portfolio::*;
 pub use portfolio_simulation::*;
 pub use position::*;
 pub use request::*;
 pub use response::*;
+pub use rfq::*;
 pub use self_trading::*;
 pub use settlement::*;
+pub use strategy::*;
 pub use ticker::*;
+pub use time_range::*;
+pub use timestamp::*;
 pub use trade::*;
 pub use trading_products::*;
 pub use tradingview::*;
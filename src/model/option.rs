@@ -3,8 +3,8 @@
    Email: jb@taunais.com
    Date: 20/9/25
 ******************************************************************************/
-use crate::HttpError;
 use crate::prelude::OptionType;
+use crate::{HttpError, HttpErrorKind};
 use chrono::{DateTime, Utc};
 use pretty_simple_display::{DebugPretty, DisplaySimple};
 use serde::{Deserialize, Serialize};
@@ -34,7 +34,7 @@ impl OptionInfo {
 
         // Validate we have exactly 4 parts
         if parts.len() != 4 {
-            return Err(HttpError::ParseError("InvalidFormat".to_string()));
+            return Err(HttpErrorKind::ParseError("InvalidFormat".to_string()).into());
         }
 
         let symbol = parts[0].to_string();
@@ -43,18 +43,18 @@ impl OptionInfo {
         // Parse strike price
         let strike_price = parts[2]
             .parse::<f64>()
-            .map_err(|_| HttpError::ParseError("InvalidStrikePrice".to_string()))?;
+            .map_err(|_| HttpErrorKind::ParseError("InvalidStrikePrice".to_string()))?;
 
         // Parse option type
         let option_type = match parts[3].to_uppercase().as_str() {
             "C" => OptionType::Call,
             "P" => OptionType::Put,
-            _ => return Err(HttpError::ParseError("InvalidOptionType".to_string())),
+            _ => return Err(HttpErrorKind::ParseError("InvalidOptionType".to_string()).into()),
         };
 
         // Basic validation for expiration date format (DDMMMYY)
         if expiration_date.len() != 7 {
-            return Err(HttpError::ParseError("InvalidExpirationDate".to_string()));
+            return Err(HttpErrorKind::ParseError("InvalidExpirationDate".to_string()).into());
         };
 
         Ok(OptionInfo {
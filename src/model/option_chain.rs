@@ -0,0 +1,151 @@
+/******************************************************************************
+   Author: Joaquín Béjar García
+   Email: jb@taunais.com
+   Date: 15/10/26
+******************************************************************************/
+use crate::model::other::{OptionInstrument, OptionInstrumentPair};
+use crate::model::timestamp::TimestampMs;
+use pretty_simple_display::{DebugPretty, DisplaySimple};
+use serde::{Deserialize, Serialize};
+
+/// Quotes, implied volatilities and greeks of one option in an [`OptionChain`]
+#[derive(DebugPretty, DisplaySimple, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OptionChainLeg {
+    /// Instrument name, e.g. `BTC-27DEC24-50000-C`
+    pub instrument_name: String,
+    /// Best bid price
+    pub bid: Option<f64>,
+    /// Best ask price
+    pub ask: Option<f64>,
+    /// Mark price
+    pub mark: f64,
+    /// Implied volatility at the best bid
+    pub bid_iv: Option<f64>,
+    /// Implied volatility at the best ask
+    pub ask_iv: Option<f64>,
+    /// Mark implied volatility
+    pub mark_iv: Option<f64>,
+    /// Delta
+    pub delta: Option<f64>,
+    /// Gamma
+    pub gamma: Option<f64>,
+    /// Vega
+    pub vega: Option<f64>,
+    /// Theta
+    pub theta: Option<f64>,
+    /// Open interest
+    pub open_interest: Option<f64>,
+    /// 24h volume
+    pub volume: f64,
+}
+
+impl From<&OptionInstrument> for OptionChainLeg {
+    fn from(option: &OptionInstrument) -> Self {
+        let ticker = &option.ticker;
+        let greeks = ticker.greeks.as_ref();
+        Self {
+            instrument_name: option.instrument.instrument_name.clone(),
+            bid: ticker.best_bid_price,
+            ask: ticker.best_ask_price,
+            mark: ticker.mark_price,
+            bid_iv: ticker.bid_iv,
+            ask_iv: ticker.ask_iv,
+            mark_iv: ticker.mark_iv,
+            delta: greeks.and_then(|g| g.delta),
+            gamma: greeks.and_then(|g| g.gamma),
+            vega: greeks.and_then(|g| g.vega),
+            theta: greeks.and_then(|g| g.theta),
+            open_interest: ticker.open_interest,
+            volume: ticker.stats.volume,
+        }
+    }
+}
+
+/// Call and put of one strike in an [`OptionChain`]
+#[derive(DebugPretty, DisplaySimple, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OptionChainStrike {
+    /// Strike price
+    pub strike: f64,
+    /// Call at this strike, if listed
+    pub call: Option<OptionChainLeg>,
+    /// Put at this strike, if listed
+    pub put: Option<OptionChainLeg>,
+}
+
+/// Option chain of one expiry, built by `DeribitHttpClient::get_option_chain`
+#[derive(DebugPretty, DisplaySimple, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OptionChain {
+    /// Currency of the options, e.g. `BTC`
+    pub currency: String,
+    /// Expiry as in the instrument names, e.g. `27DEC24`
+    pub expiry: String,
+    /// Expiration time, if the instruments report it
+    pub expiration_timestamp: Option<TimestampMs>,
+    /// Underlying price of the expiry, from the first ticker that reports one
+    pub underlying_price: Option<f64>,
+    /// Strikes in ascending order
+    pub strikes: Vec<OptionChainStrike>,
+}
+
+impl OptionChain {
+    /// Build a chain from `get_options_pair` results, sorting the strikes
+    pub fn from_pairs(
+        currency: impl Into<String>,
+        expiry: impl Into<String>,
+        pairs: impl IntoIterator<Item = OptionInstrumentPair>,
+    ) -> Self {
+        let mut expiration_timestamp = None;
+        let mut underlying_price = None;
+        let mut strikes: Vec<OptionChainStrike> = pairs
+            .into_iter()
+            .filter_map(|pair| {
+                let first = pair.call.as_ref().or(pair.put.as_ref())?;
+                let strike = first.instrument.strike?;
+                expiration_timestamp =
+                    expiration_timestamp.or(first.instrument.expiration_timestamp);
+                underlying_price = underlying_price.or(first.ticker.underlying_price);
+                Some(OptionChainStrike {
+                    strike,
+                    call: pair.call.as_ref().map(OptionChainLeg::from),
+                    put: pair.put.as_ref().map(OptionChainLeg::from),
+                })
+            })
+            .collect();
+        strikes.sort_by(|a, b| a.strike.total_cmp(&b.strike));
+        Self {
+            currency: currency.into(),
+            expiry: expiry.into(),
+            expiration_timestamp,
+            underlying_price,
+            strikes,
+        }
+    }
+
+    /// Row of `strike`, if listed
+    pub fn strike(&self, strike: f64) -> Option<&OptionChainStrike> {
+        self.strikes
+            .binary_search_by(|row| row.strike.total_cmp(&strike))
+            .ok()
+            .map(|index| &self.strikes[index])
+    }
+
+    /// Row whose strike is closest to the underlying price
+    pub fn at_the_money(&self) -> Option<&OptionChainStrike> {
+        let underlying = self.underlying_price?;
+        self.strikes.iter().min_by(|a, b| {
+            (a.strike - underlying)
+                .abs()
+                .total_cmp(&(b.strike - underlying).abs())
+        })
+    }
+
+    /// Number of strikes
+    pub fn len(&self) -> usize {
+        self.strikes.len()
+    }
+
+    /// Whether the chain has no strikes
+    pub fn is_empty(&self) -> bool {
+        self.strikes.is_empty()
+    }
+}
@@ -6,7 +6,7 @@
 use crate::model::instrument::Instrument;
 use crate::model::ticker::TickerData;
 use crate::model::{BasicGreeks, BasicOptionData, OptionType, Spread};
-use chrono::{DateTime, TimeZone, Utc};
+use chrono::{DateTime, Utc};
 use pretty_simple_display::{DebugPretty, DisplaySimple};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
@@ -51,6 +51,98 @@ pub struct OptionInstrument {
     pub ticker: TickerData,
 }
 
+/// Which options of an expiry `get_options` and `get_options_pair` fetch tickers for
+///
+/// Unset fields select everything, so `OptionFilter::default()` loads the
+/// whole chain. Bounds are inclusive. Moneyness is the strike divided by the
+/// currency's USD index price (e.g. `btc_usd`), so `0.9..=1.1` keeps strikes
+/// within 10% of spot.
+///
+/// # Examples
+///
+/// ```rust
+/// use deribit_http::model::{OptionFilter, OptionType};
+///
+/// let near_calls = OptionFilter::default()
+///     .with_option_type(OptionType::Call)
+///     .with_moneyness(0.9, 1.1);
+/// assert!(near_calls.needs_index_price());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct OptionFilter {
+    /// Lowest strike to keep
+    pub min_strike: Option<f64>,
+    /// Highest strike to keep
+    pub max_strike: Option<f64>,
+    /// Lowest strike / index price ratio to keep
+    pub min_moneyness: Option<f64>,
+    /// Highest strike / index price ratio to keep
+    pub max_moneyness: Option<f64>,
+    /// Keep only calls or only puts
+    pub option_type: Option<OptionType>,
+}
+
+impl OptionFilter {
+    /// Keep strikes between `min` and `max`
+    pub fn with_strikes(mut self, min: f64, max: f64) -> Self {
+        self.min_strike = Some(min);
+        self.max_strike = Some(max);
+        self
+    }
+
+    /// Keep strikes whose ratio to the index price is between `min` and `max`
+    pub fn with_moneyness(mut self, min: f64, max: f64) -> Self {
+        self.min_moneyness = Some(min);
+        self.max_moneyness = Some(max);
+        self
+    }
+
+    /// Keep only calls or only puts
+    pub fn with_option_type(mut self, option_type: OptionType) -> Self {
+        self.option_type = Some(option_type);
+        self
+    }
+
+    /// Whether the filter has a moneyness bound, and so needs the index price
+    pub fn needs_index_price(&self) -> bool {
+        self.min_moneyness.is_some() || self.max_moneyness.is_some()
+    }
+
+    /// Whether `instrument` passes the filter
+    ///
+    /// `index_price` is only read for moneyness bounds; without it, or
+    /// without a strike, those bounds reject the instrument.
+    pub fn matches(&self, instrument: &Instrument, index_price: Option<f64>) -> bool {
+        if let Some(option_type) = &self.option_type
+            && instrument.option_type.as_ref() != Some(option_type)
+        {
+            return false;
+        }
+        if self.min_strike.is_none() && self.max_strike.is_none() && !self.needs_index_price() {
+            return true;
+        }
+        let Some(strike) = instrument.strike else {
+            return false;
+        };
+        if !within(strike, self.min_strike, self.max_strike) {
+            return false;
+        }
+        if !self.needs_index_price() {
+            return true;
+        }
+        match index_price {
+            Some(price) if price > 0.0 => {
+                within(strike / price, self.min_moneyness, self.max_moneyness)
+            }
+            _ => false,
+        }
+    }
+}
+
+fn within(value: f64, min: Option<f64>, max: Option<f64>) -> bool {
+    min.is_none_or(|min| value >= min) && max.is_none_or(|max| value <= max)
+}
+
 /// A pair of option instruments representing both call and put options for the same underlying asset
 ///
 /// This structure groups together the call and put options for a specific underlying asset,
@@ -80,7 +172,7 @@ impl OptionInstrumentPair {
         };
 
         if let Some(expiration_timestamp) = expiration_timestamp {
-            Utc.timestamp_millis_opt(expiration_timestamp).single()
+            expiration_timestamp.to_datetime()
         } else {
             None
         }
@@ -327,7 +419,7 @@ pub struct ParsedOptionWithTicker {
 }
 
 /// Sort direction options
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 #[serde(rename_all = "lowercase")]
 pub enum SortDirection {
     /// Ascending sort order
@@ -339,12 +431,19 @@ pub enum SortDirection {
     Default,
 }
 
-impl std::fmt::Display for SortDirection {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl SortDirection {
+    /// Returns the string representation of the sort direction
+    pub fn as_str(&self) -> &'static str {
         match self {
-            SortDirection::Asc => write!(f, "asc"),
-            SortDirection::Desc => write!(f, "desc"),
-            SortDirection::Default => write!(f, "default"),
+            SortDirection::Asc => "asc",
+            SortDirection::Desc => "desc",
+            SortDirection::Default => "default",
         }
     }
 }
+
+impl std::fmt::Display for SortDirection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
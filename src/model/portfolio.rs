@@ -0,0 +1,245 @@
+//! Cross-currency portfolio models
+//!
+//! Aggregated views built from several per-currency endpoints, valued in USD
+//! with the currencies' index prices.
+
+use crate::model::other::Greeks;
+use crate::model::position::Position;
+use crate::model::response::other::AccountResult;
+use pretty_simple_display::{DebugPretty, DisplaySimple};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+
+/// Balances, margins and open positions of one currency in a [`PortfolioOverview`]
+#[derive(DebugPretty, DisplaySimple, Clone, Serialize, Deserialize)]
+pub struct CurrencyOverview {
+    /// Currency symbol, e.g. `BTC`
+    pub currency: String,
+    /// Account summary of the currency
+    pub summary: AccountResult,
+    /// Open positions settled in the currency
+    pub positions: Vec<Position>,
+    /// USD price of one unit of the currency; `None` if it has no USD index
+    pub index_price_usd: Option<f64>,
+}
+
+impl CurrencyOverview {
+    /// Equity in the currency
+    pub fn equity(&self) -> f64 {
+        self.summary.equity
+    }
+
+    /// Margin balance in the currency
+    pub fn margin_balance(&self) -> f64 {
+        self.summary.margin_balance
+    }
+
+    /// Initial margin in use, in the currency
+    pub fn initial_margin(&self) -> f64 {
+        self.summary.initial_margin
+    }
+
+    /// Maintenance margin in use, in the currency
+    pub fn maintenance_margin(&self) -> f64 {
+        self.summary.maintenance_margin
+    }
+
+    /// Share of the margin balance held as initial margin
+    ///
+    /// `None` when the margin balance is zero.
+    pub fn margin_usage(&self) -> Option<f64> {
+        ratio(self.initial_margin(), self.margin_balance())
+    }
+
+    /// Equity in USD, if the currency has an index price
+    pub fn equity_usd(&self) -> Option<f64> {
+        self.index_price_usd.map(|price| self.equity() * price)
+    }
+
+    /// Margin balance in USD, if the currency has an index price
+    pub fn margin_balance_usd(&self) -> Option<f64> {
+        self.index_price_usd
+            .map(|price| self.margin_balance() * price)
+    }
+
+    /// Initial margin in USD, if the currency has an index price
+    pub fn initial_margin_usd(&self) -> Option<f64> {
+        self.index_price_usd
+            .map(|price| self.initial_margin() * price)
+    }
+
+    /// Maintenance margin in USD, if the currency has an index price
+    pub fn maintenance_margin_usd(&self) -> Option<f64> {
+        self.index_price_usd
+            .map(|price| self.maintenance_margin() * price)
+    }
+}
+
+/// Account-wide view across currencies, built by
+/// `DeribitHttpClient::get_portfolio_overview`
+///
+/// USD totals only include currencies with an index price; the others are
+/// listed by [`unpriced_currencies`](Self::unpriced_currencies).
+#[derive(DebugPretty, DisplaySimple, Clone, Serialize, Deserialize)]
+pub struct PortfolioOverview {
+    /// Per-currency balances and positions, in the order of the account summaries
+    pub currencies: Vec<CurrencyOverview>,
+}
+
+impl PortfolioOverview {
+    /// Overview of `currency`, if the account holds it
+    pub fn currency(&self, currency: &str) -> Option<&CurrencyOverview> {
+        self.currencies
+            .iter()
+            .find(|overview| overview.currency.eq_ignore_ascii_case(currency))
+    }
+
+    /// Every open position, across currencies
+    pub fn positions(&self) -> impl Iterator<Item = &Position> {
+        self.currencies
+            .iter()
+            .flat_map(|overview| overview.positions.iter())
+    }
+
+    /// Total equity in USD
+    pub fn total_equity_usd(&self) -> f64 {
+        self.sum_usd(CurrencyOverview::equity_usd)
+    }
+
+    /// Total margin balance in USD
+    pub fn total_margin_balance_usd(&self) -> f64 {
+        self.sum_usd(CurrencyOverview::margin_balance_usd)
+    }
+
+    /// Total initial margin in USD
+    pub fn total_initial_margin_usd(&self) -> f64 {
+        self.sum_usd(CurrencyOverview::initial_margin_usd)
+    }
+
+    /// Total maintenance margin in USD
+    pub fn total_maintenance_margin_usd(&self) -> f64 {
+        self.sum_usd(CurrencyOverview::maintenance_margin_usd)
+    }
+
+    /// Share of the USD margin balance held as initial margin
+    ///
+    /// `None` when the margin balance is zero.
+    pub fn margin_usage(&self) -> Option<f64> {
+        ratio(
+            self.total_initial_margin_usd(),
+            self.total_margin_balance_usd(),
+        )
+    }
+
+    /// Currencies left out of the USD totals for lack of an index price
+    pub fn unpriced_currencies(&self) -> Vec<&str> {
+        self.currencies
+            .iter()
+            .filter(|overview| overview.index_price_usd.is_none())
+            .map(|overview| overview.currency.as_str())
+            .collect()
+    }
+
+    fn sum_usd(&self, value: impl Fn(&CurrencyOverview) -> Option<f64>) -> f64 {
+        self.currencies.iter().filter_map(value).sum()
+    }
+}
+
+/// Net greeks of the positions on one underlying in an [`Exposure`]
+#[derive(DebugPretty, DisplaySimple, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct UnderlyingExposure {
+    /// Underlying, the instrument name prefix, e.g. `BTC` or `ETH_USDC`
+    pub underlying: String,
+    /// Net delta, in units of the underlying
+    pub delta: f64,
+    /// Net gamma
+    pub gamma: f64,
+    /// Net vega
+    pub vega: f64,
+    /// Net theta
+    pub theta: f64,
+    /// Number of open positions included
+    pub positions: usize,
+}
+
+/// Net greeks of a currency's positions per underlying, built by
+/// `DeribitHttpClient::get_exposure`
+#[derive(DebugPretty, DisplaySimple, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Exposure {
+    /// Currency the positions were fetched for
+    pub currency: String,
+    /// Exposure per underlying, sorted by underlying
+    pub underlyings: Vec<UnderlyingExposure>,
+}
+
+impl Exposure {
+    /// Sum the greeks of `positions` per underlying
+    ///
+    /// Position greeks are used as reported. Options missing any of them
+    /// fall back to the per-contract greeks in `option_greeks`, keyed by
+    /// instrument name and scaled by the position size; futures missing a
+    /// delta use their size in currency units. Closed positions are skipped.
+    pub fn from_positions(
+        currency: impl Into<String>,
+        positions: &[Position],
+        option_greeks: &HashMap<String, Greeks>,
+    ) -> Self {
+        let mut underlyings: BTreeMap<&str, UnderlyingExposure> = BTreeMap::new();
+        for position in positions {
+            let size = position.size;
+            if size == 0.0 {
+                continue;
+            }
+            let underlying = underlying_of(&position.instrument_name);
+            let entry = underlyings
+                .entry(underlying)
+                .or_insert_with(|| UnderlyingExposure {
+                    underlying: underlying.to_string(),
+                    ..UnderlyingExposure::default()
+                });
+            let ticker = option_greeks.get(&position.instrument_name);
+            let scaled = |greek: fn(&Greeks) -> Option<f64>| {
+                ticker.and_then(greek).map(|value| value * size)
+            };
+            let delta = position.delta.or_else(|| scaled(|g| g.delta)).or_else(|| {
+                (!position.is_option()).then(|| position.size_currency.unwrap_or_default())
+            });
+            entry.delta += delta.unwrap_or_default();
+            entry.gamma += position
+                .gamma
+                .or_else(|| scaled(|g| g.gamma))
+                .unwrap_or_default();
+            entry.vega += position
+                .vega
+                .or_else(|| scaled(|g| g.vega))
+                .unwrap_or_default();
+            entry.theta += position
+                .theta
+                .or_else(|| scaled(|g| g.theta))
+                .unwrap_or_default();
+            entry.positions += 1;
+        }
+        Self {
+            currency: currency.into(),
+            underlyings: underlyings.into_values().collect(),
+        }
+    }
+
+    /// Exposure to `underlying`, if any position is on it
+    pub fn underlying(&self, underlying: &str) -> Option<&UnderlyingExposure> {
+        self.underlyings
+            .iter()
+            .find(|exposure| exposure.underlying == underlying)
+    }
+}
+
+/// Underlying of an instrument, the part of its name before the first `-`
+fn underlying_of(instrument_name: &str) -> &str {
+    instrument_name
+        .split_once('-')
+        .map_or(instrument_name, |(underlying, _)| underlying)
+}
+
+fn ratio(part: f64, whole: f64) -> Option<f64> {
+    (whole != 0.0).then(|| part / whole)
+}
@@ -67,6 +67,48 @@ pub struct SimulatePortfolioResponse {
     pub additional: HashMap<String, serde_json::Value>,
 }
 
+/// Response for get_portfolio_margins endpoint
+///
+/// Portfolio margin details for a currency, optionally including simulated
+/// positions. Components Deribit adds beyond the ones named here are kept in
+/// `additional`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PortfolioMarginsResponse {
+    /// Currency of the margin figures
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<String>,
+    /// Current initial margin
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initial_margin: Option<f64>,
+    /// Current maintenance margin
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub maintenance_margin: Option<f64>,
+    /// Initial margin including the simulated positions
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub projected_initial_margin: Option<f64>,
+    /// Maintenance margin including the simulated positions
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub projected_maintenance_margin: Option<f64>,
+    /// Margin balance
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub margin_balance: Option<f64>,
+    /// Funds available for new positions
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub available_funds: Option<f64>,
+    /// Total delta across the portfolio
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delta_total: Option<f64>,
+    /// Price shocks of the risk matrix
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price_range: Option<Vec<f64>>,
+    /// Volatility shocks of the risk matrix
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vol_range: Option<Vec<f64>>,
+    /// Additional margin components
+    #[serde(flatten)]
+    pub additional: HashMap<String, serde_json::Value>,
+}
+
 /// Response for PME (Portfolio Margin Engine) simulation
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct PmeSimulateResponse {
@@ -118,6 +160,26 @@ mod tests {
         assert_eq!(response.projected_maintenance_margin, Some(0.03));
     }
 
+    #[test]
+    fn test_portfolio_margins_response_deserialization() {
+        let json = r#"{
+            "currency": "BTC",
+            "initial_margin": 0.2,
+            "maintenance_margin": 0.15,
+            "projected_initial_margin": 0.25,
+            "projected_maintenance_margin": 0.18,
+            "vol_range": [-0.5, 0.5],
+            "risk_matrix": {"BTC-PERPETUAL": [0.1, 0.2]}
+        }"#;
+
+        let response: PortfolioMarginsResponse =
+            serde_json::from_str(json).expect("Failed to parse");
+        assert_eq!(response.currency.as_deref(), Some("BTC"));
+        assert_eq!(response.projected_initial_margin, Some(0.25));
+        assert_eq!(response.vol_range, Some(vec![-0.5, 0.5]));
+        assert!(response.additional.contains_key("risk_matrix"));
+    }
+
     #[test]
     fn test_pme_simulate_response_deserialization() {
         let json = r#"{
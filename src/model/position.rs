@@ -7,6 +7,7 @@ use crate::model::types::Direction;
 use pretty_simple_display::{DebugPretty, DisplaySimple};
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
+use std::collections::HashMap;
 
 /// Position structure
 #[skip_serializing_none]
@@ -68,3 +69,79 @@ pub struct Position {
     /// Unrealized profit/loss
     pub unrealized_profit_loss: Option<f64>,
 }
+
+impl Position {
+    /// Whether the position is on an option, by kind or by instrument name
+    pub fn is_option(&self) -> bool {
+        match self.kind.as_deref() {
+            Some(kind) => kind == "option",
+            None => self.instrument_name.ends_with("-C") || self.instrument_name.ends_with("-P"),
+        }
+    }
+}
+
+/// Change of one position between two `get_positions` snapshots
+#[derive(DebugPretty, DisplaySimple, Clone, Serialize, Deserialize)]
+pub enum PositionChange {
+    /// A position was opened
+    Opened(Position),
+    /// A position was closed; holds its last open state
+    Closed(Position),
+    /// The size of an open position changed
+    SizeChanged {
+        /// Size before the change
+        previous_size: f64,
+        /// Position after the change
+        position: Position,
+    },
+}
+
+impl PositionChange {
+    /// Changes from `previous` to `current`, matched by instrument name
+    ///
+    /// Positions of size zero count as closed. Openings and size changes are
+    /// listed in the order of `current`, followed by closings sorted by
+    /// instrument name.
+    pub fn between(previous: &[Position], current: &[Position]) -> Vec<PositionChange> {
+        let open = |positions: &[Position]| -> Vec<Position> {
+            positions
+                .iter()
+                .filter(|position| position.size != 0.0)
+                .cloned()
+                .collect()
+        };
+        let current = open(current);
+        let mut previous: HashMap<String, Position> = open(previous)
+            .into_iter()
+            .map(|position| (position.instrument_name.clone(), position))
+            .collect();
+        let mut changes = Vec::new();
+        for position in current {
+            match previous.remove(&position.instrument_name) {
+                None => changes.push(PositionChange::Opened(position)),
+                Some(before) => {
+                    let previous_size = before.size;
+                    if previous_size != position.size {
+                        changes.push(PositionChange::SizeChanged {
+                            previous_size,
+                            position,
+                        });
+                    }
+                }
+            }
+        }
+        let mut closed: Vec<Position> = previous.into_values().collect();
+        closed.sort_by(|a, b| a.instrument_name.cmp(&b.instrument_name));
+        changes.extend(closed.into_iter().map(PositionChange::Closed));
+        changes
+    }
+
+    /// Position the change refers to
+    pub fn position(&self) -> &Position {
+        match self {
+            PositionChange::Opened(position)
+            | PositionChange::Closed(position)
+            | PositionChange::SizeChanged { position, .. } => position,
+        }
+    }
+}
@@ -21,3 +21,28 @@ pub struct HttpRequest {
     /// Optional request body content
     pub body: Option<String>,
 }
+
+/// JSON-RPC 2.0 request body
+#[derive(DebugPretty, DisplaySimple, Clone, Serialize, Deserialize)]
+pub struct JsonRpcRequest {
+    /// Protocol version, always "2.0"
+    pub jsonrpc: String,
+    /// Request identifier, echoed back in the response
+    pub id: u64,
+    /// Method name (e.g., "private/buy")
+    pub method: String,
+    /// Method parameters as a JSON object
+    pub params: serde_json::Value,
+}
+
+impl JsonRpcRequest {
+    /// Create a request for an endpoint path such as "/private/buy"
+    pub fn new(id: u64, endpoint: &str, params: serde_json::Value) -> Self {
+        Self {
+            jsonrpc: "2.0".to_string(),
+            id,
+            method: endpoint.trim_start_matches('/').to_string(),
+            params,
+        }
+    }
+}
@@ -0,0 +1,277 @@
+/******************************************************************************
+   Author: Joaquín Béjar García
+   Email: jb@taunais.com
+   Date: 15/10/26
+******************************************************************************/
+use crate::model::order::{OrderSide, OrderType};
+use crate::model::response::order::LinkedOrderType;
+use crate::model::trigger::{Trigger, TriggerFillCondition};
+use crate::model::types::TimeInForce;
+use pretty_simple_display::{DebugPretty, DisplaySimple};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+/// Secondary order of a linked (OTO, OCO or OTOCO) order
+///
+/// Serializes to one entry of the `otoco_config` array sent with the
+/// primary order. The secondary order trades the primary's instrument.
+#[skip_serializing_none]
+#[derive(DebugPretty, DisplaySimple, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LinkedOrder {
+    /// Order amount
+    pub amount: f64,
+    /// Order direction: "buy" or "sell"
+    pub direction: String,
+    /// Order type
+    #[serde(rename = "type")]
+    pub type_: OrderType,
+    /// User-defined label for the order
+    pub label: Option<String>,
+    /// Limit price for the order
+    pub price: Option<f64>,
+    /// Whether this order only reduces position
+    pub reduce_only: Option<bool>,
+    /// Time in force specification
+    pub time_in_force: Option<TimeInForce>,
+    /// Whether the order should only be posted (not taken)
+    pub post_only: Option<bool>,
+    /// Whether to reject if the order would be posted only
+    pub reject_post_only: Option<bool>,
+    /// Trigger price for stop and take orders
+    pub trigger_price: Option<f64>,
+    /// Trigger offset for trailing stops
+    pub trigger_offset: Option<f64>,
+    /// Trigger type for stop and take orders
+    pub trigger: Option<Trigger>,
+}
+
+impl LinkedOrder {
+    /// Creates a secondary order of the given side, size and type
+    #[must_use]
+    pub fn new(direction: OrderSide, amount: f64, order_type: OrderType) -> Self {
+        let direction = match direction {
+            OrderSide::Buy => "buy",
+            OrderSide::Sell => "sell",
+        };
+        Self {
+            amount,
+            direction: direction.to_string(),
+            type_: order_type,
+            label: None,
+            price: None,
+            reduce_only: None,
+            time_in_force: None,
+            post_only: None,
+            reject_post_only: None,
+            trigger_price: None,
+            trigger_offset: None,
+            trigger: None,
+        }
+    }
+
+    /// Creates a limit secondary order
+    #[must_use]
+    pub fn limit(direction: OrderSide, amount: f64, price: f64) -> Self {
+        Self::new(direction, amount, OrderType::Limit).price(price)
+    }
+
+    /// Creates a stop-market secondary order
+    #[must_use]
+    pub fn stop_market(
+        direction: OrderSide,
+        amount: f64,
+        trigger_price: f64,
+        trigger: Trigger,
+    ) -> Self {
+        Self::new(direction, amount, OrderType::StopMarket)
+            .trigger_price(trigger_price)
+            .trigger(trigger)
+    }
+
+    /// Creates a take-market secondary order
+    #[must_use]
+    pub fn take_market(
+        direction: OrderSide,
+        amount: f64,
+        trigger_price: f64,
+        trigger: Trigger,
+    ) -> Self {
+        Self::new(direction, amount, OrderType::TakeMarket)
+            .trigger_price(trigger_price)
+            .trigger(trigger)
+    }
+
+    /// User-defined label
+    #[must_use]
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Limit price
+    #[must_use]
+    pub fn price(mut self, price: f64) -> Self {
+        self.price = Some(price);
+        self
+    }
+
+    /// Only reduce the position
+    #[must_use]
+    pub fn reduce_only(mut self, reduce_only: bool) -> Self {
+        self.reduce_only = Some(reduce_only);
+        self
+    }
+
+    /// Time in force
+    #[must_use]
+    pub fn time_in_force(mut self, time_in_force: TimeInForce) -> Self {
+        self.time_in_force = Some(time_in_force);
+        self
+    }
+
+    /// Only post the order to the book
+    #[must_use]
+    pub fn post_only(mut self, post_only: bool) -> Self {
+        self.post_only = Some(post_only);
+        self
+    }
+
+    /// Reject instead of repricing a post-only order
+    #[must_use]
+    pub fn reject_post_only(mut self, reject_post_only: bool) -> Self {
+        self.reject_post_only = Some(reject_post_only);
+        self
+    }
+
+    /// Trigger price for stop and take orders
+    #[must_use]
+    pub fn trigger_price(mut self, trigger_price: f64) -> Self {
+        self.trigger_price = Some(trigger_price);
+        self
+    }
+
+    /// Trigger offset for trailing stops
+    #[must_use]
+    pub fn trigger_offset(mut self, trigger_offset: f64) -> Self {
+        self.trigger_offset = Some(trigger_offset);
+        self
+    }
+
+    /// Trigger type for stop and take orders
+    #[must_use]
+    pub fn trigger(mut self, trigger: Trigger) -> Self {
+        self.trigger = Some(trigger);
+        self
+    }
+}
+
+/// Builder for the linked-order fields of an order request
+///
+/// Produces the `linked_order_type`, `trigger_fill_condition` and
+/// `otoco_config` fields; pass it to
+/// [`OrderRequestBuilder::linked_orders`](crate::model::request::order::OrderRequestBuilder::linked_orders).
+///
+/// # Examples
+///
+/// ```rust
+/// use deribit_http::model::order::OrderSide;
+/// use deribit_http::model::request::linked_order::{LinkedOrder, LinkedOrders};
+/// use deribit_http::model::request::order::OrderRequest;
+/// use deribit_http::model::trigger::{Trigger, TriggerFillCondition};
+///
+/// let bracket = LinkedOrders::one_triggers_one_cancels_other()
+///     .trigger_fill_condition(TriggerFillCondition::FirstHit)
+///     .order(LinkedOrder::limit(OrderSide::Sell, 10.0, 55_000.0).reduce_only(true))
+///     .order(
+///         LinkedOrder::stop_market(OrderSide::Sell, 10.0, 45_000.0, Trigger::MarkPrice)
+///             .reduce_only(true),
+///     );
+///
+/// let request = OrderRequest::builder()
+///     .instrument_name("BTC-PERPETUAL")
+///     .amount(10.0)
+///     .limit(50_000.0)
+///     .linked_orders(bracket)
+///     .build()
+///     .unwrap();
+/// assert_eq!(request.otoco_config.map(|orders| orders.len()), Some(2));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct LinkedOrders {
+    linked_order_type: LinkedOrderType,
+    trigger_fill_condition: Option<TriggerFillCondition>,
+    orders: Vec<LinkedOrder>,
+}
+
+impl LinkedOrders {
+    /// Creates an empty set of linked orders of the given type
+    #[must_use]
+    pub fn new(linked_order_type: LinkedOrderType) -> Self {
+        Self {
+            linked_order_type,
+            trigger_fill_condition: None,
+            orders: Vec::new(),
+        }
+    }
+
+    /// The primary order places the secondary orders once it fills (OTO)
+    #[must_use]
+    pub fn one_triggers_other() -> Self {
+        Self::new(LinkedOrderType::OneTriggersOther)
+    }
+
+    /// Filling one order cancels the others (OCO)
+    #[must_use]
+    pub fn one_cancels_other() -> Self {
+        Self::new(LinkedOrderType::OneCancelsOther)
+    }
+
+    /// The primary order places an OCO pair once it fills (OTOCO)
+    #[must_use]
+    pub fn one_triggers_one_cancels_other() -> Self {
+        Self::new(LinkedOrderType::OneTriggersOneCancelsOther)
+    }
+
+    /// When the secondary orders are placed
+    #[must_use]
+    pub fn trigger_fill_condition(mut self, trigger_fill_condition: TriggerFillCondition) -> Self {
+        self.trigger_fill_condition = Some(trigger_fill_condition);
+        self
+    }
+
+    /// Adds a secondary order
+    #[must_use]
+    pub fn order(mut self, order: LinkedOrder) -> Self {
+        self.orders.push(order);
+        self
+    }
+
+    /// Linked order type
+    #[must_use]
+    pub fn linked_order_type(&self) -> &LinkedOrderType {
+        &self.linked_order_type
+    }
+
+    /// Secondary orders added so far
+    #[must_use]
+    pub fn orders(&self) -> &[LinkedOrder] {
+        &self.orders
+    }
+
+    /// Splits into the `linked_order_type`, `trigger_fill_condition` and
+    /// `otoco_config` request fields
+    #[must_use]
+    pub fn into_parts(
+        self,
+    ) -> (
+        LinkedOrderType,
+        Option<TriggerFillCondition>,
+        Vec<LinkedOrder>,
+    ) {
+        (
+            self.linked_order_type,
+            self.trigger_fill_condition,
+            self.orders,
+        )
+    }
+}
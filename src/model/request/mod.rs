@@ -6,6 +6,8 @@
 
 /// API request structures and utilities
 pub mod api_request;
+/// Linked (OTO, OCO, OTOCO) order request models
+pub mod linked_order;
 /// Mass quote request models
 pub mod mass_quote;
 /// Order request models and types
@@ -18,6 +20,7 @@ pub mod trade;
 pub mod wallet;
 
 pub use api_request::*;
+pub use linked_order::*;
 pub use mass_quote::*;
 pub use order::*;
 pub use position::*;
@@ -4,6 +4,7 @@
    Date: 15/9/25
 ******************************************************************************/
 use crate::model::order::OrderType;
+use crate::model::request::linked_order::{LinkedOrder, LinkedOrders};
 use crate::model::response::order::LinkedOrderType;
 use crate::model::trigger::{Trigger, TriggerFillCondition};
 use crate::model::types::TimeInForce;
@@ -56,8 +57,8 @@ pub struct OrderRequest {
     pub linked_order_type: Option<LinkedOrderType>,
     /// Trigger fill condition for linked orders
     pub trigger_fill_condition: Option<TriggerFillCondition>,
-    /// Configuration for OTOCO (One-Triggers-One-Cancels-Other) orders
-    pub otoco_config: Option<Vec<String>>,
+    /// Secondary orders of a linked (OTO, OCO, OTOCO) order
+    pub otoco_config: Option<Vec<LinkedOrder>>,
 }
 
 /// Advanced order type
@@ -69,3 +70,366 @@ pub enum AdvancedOrderType {
     /// Implied volatility
     Implv,
 }
+
+/// Reason an [`OrderRequestBuilder`] could not build a request
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum OrderValidationError {
+    /// No instrument name was set
+    #[error("instrument_name is required")]
+    MissingInstrument,
+    /// Neither `amount` nor `contracts` was set
+    #[error("either amount or contracts is required")]
+    MissingAmount,
+    /// Both `amount` and `contracts` were set
+    #[error("amount and contracts are mutually exclusive")]
+    AmountAndContracts,
+    /// `amount` or `contracts` is zero, negative or not finite
+    #[error("order size must be positive, got {0}")]
+    InvalidAmount(f64),
+    /// A limit-style order has no price
+    #[error("price is required for {} orders", .0.as_str())]
+    MissingPrice(OrderType),
+    /// A stop or take order has no trigger price
+    #[error("trigger_price is required for {} orders", .0.as_str())]
+    MissingTriggerPrice(OrderType),
+    /// A trailing stop has no trigger offset
+    #[error("trigger_offset is required for trailing_stop orders")]
+    MissingTriggerOffset,
+    /// A linked order type was set without any secondary orders
+    #[error("linked orders require at least one secondary order")]
+    MissingLinkedOrders,
+    /// The secondary order at the given index is invalid
+    #[error("linked order {0}: {1}")]
+    InvalidLinkedOrder(usize, Box<OrderValidationError>),
+}
+
+impl OrderRequest {
+    /// Start building an order request
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use deribit_http::model::request::order::OrderRequest;
+    ///
+    /// let request = OrderRequest::builder()
+    ///     .instrument_name("BTC-PERPETUAL")
+    ///     .amount(10.0)
+    ///     .limit(50_000.0)
+    ///     .post_only(true)
+    ///     .build()
+    ///     .unwrap();
+    /// assert_eq!(request.price, Some(50_000.0));
+    /// ```
+    #[must_use]
+    pub fn builder() -> OrderRequestBuilder {
+        OrderRequestBuilder::default()
+    }
+}
+
+/// Builder for [`OrderRequest`], created with [`OrderRequest::builder`]
+///
+/// [`build`](OrderRequestBuilder::build) checks that exactly one of `amount`
+/// and `contracts` is set, that limit-style orders carry a price, and that
+/// stop, take and trailing orders carry their trigger.
+#[derive(Debug, Clone, Default)]
+pub struct OrderRequestBuilder {
+    order_id: Option<String>,
+    instrument_name: Option<String>,
+    amount: Option<f64>,
+    contracts: Option<f64>,
+    type_: Option<OrderType>,
+    label: Option<String>,
+    price: Option<f64>,
+    time_in_force: Option<TimeInForce>,
+    display_amount: Option<f64>,
+    post_only: Option<bool>,
+    reject_post_only: Option<bool>,
+    reduce_only: Option<bool>,
+    trigger_price: Option<f64>,
+    trigger_offset: Option<f64>,
+    trigger: Option<Trigger>,
+    advanced: Option<AdvancedOrderType>,
+    mmp: Option<bool>,
+    valid_until: Option<i64>,
+    linked_order_type: Option<LinkedOrderType>,
+    trigger_fill_condition: Option<TriggerFillCondition>,
+    otoco_config: Option<Vec<LinkedOrder>>,
+}
+
+impl OrderRequestBuilder {
+    /// Order id, for requests that edit an existing order
+    #[must_use]
+    pub fn order_id(mut self, order_id: impl Into<String>) -> Self {
+        self.order_id = Some(order_id.into());
+        self
+    }
+
+    /// Instrument to trade
+    #[must_use]
+    pub fn instrument_name(mut self, instrument_name: impl Into<String>) -> Self {
+        self.instrument_name = Some(instrument_name.into());
+        self
+    }
+
+    /// Size in the instrument's amount units (USD for inverse contracts)
+    #[must_use]
+    pub fn amount(mut self, amount: f64) -> Self {
+        self.amount = Some(amount);
+        self
+    }
+
+    /// Size in contracts
+    #[must_use]
+    pub fn contracts(mut self, contracts: f64) -> Self {
+        self.contracts = Some(contracts);
+        self
+    }
+
+    /// Order type
+    #[must_use]
+    pub fn order_type(mut self, order_type: OrderType) -> Self {
+        self.type_ = Some(order_type);
+        self
+    }
+
+    /// Limit order at `price`
+    #[must_use]
+    pub fn limit(self, price: f64) -> Self {
+        self.order_type(OrderType::Limit).price(price)
+    }
+
+    /// Market order
+    #[must_use]
+    pub fn market(self) -> Self {
+        self.order_type(OrderType::Market)
+    }
+
+    /// User-defined label
+    #[must_use]
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Limit price
+    #[must_use]
+    pub fn price(mut self, price: f64) -> Self {
+        self.price = Some(price);
+        self
+    }
+
+    /// Time in force
+    #[must_use]
+    pub fn time_in_force(mut self, time_in_force: TimeInForce) -> Self {
+        self.time_in_force = Some(time_in_force);
+        self
+    }
+
+    /// Amount shown in the order book (iceberg orders)
+    #[must_use]
+    pub fn display_amount(mut self, display_amount: f64) -> Self {
+        self.display_amount = Some(display_amount);
+        self
+    }
+
+    /// Only add liquidity
+    #[must_use]
+    pub fn post_only(mut self, post_only: bool) -> Self {
+        self.post_only = Some(post_only);
+        self
+    }
+
+    /// Reject a post-only order that would cross instead of repricing it
+    #[must_use]
+    pub fn reject_post_only(mut self, reject_post_only: bool) -> Self {
+        self.reject_post_only = Some(reject_post_only);
+        self
+    }
+
+    /// Only reduce the current position
+    #[must_use]
+    pub fn reduce_only(mut self, reduce_only: bool) -> Self {
+        self.reduce_only = Some(reduce_only);
+        self
+    }
+
+    /// Trigger price of a stop or take order
+    #[must_use]
+    pub fn trigger_price(mut self, trigger_price: f64) -> Self {
+        self.trigger_price = Some(trigger_price);
+        self
+    }
+
+    /// Trigger offset of a trailing stop
+    #[must_use]
+    pub fn trigger_offset(mut self, trigger_offset: f64) -> Self {
+        self.trigger_offset = Some(trigger_offset);
+        self
+    }
+
+    /// Price the trigger watches
+    #[must_use]
+    pub fn trigger(mut self, trigger: Trigger) -> Self {
+        self.trigger = Some(trigger);
+        self
+    }
+
+    /// Quote an option price in USD or implied volatility
+    #[must_use]
+    pub fn advanced(mut self, advanced: AdvancedOrderType) -> Self {
+        self.advanced = Some(advanced);
+        self
+    }
+
+    /// Attach market maker protection
+    #[must_use]
+    pub fn mmp(mut self, mmp: bool) -> Self {
+        self.mmp = Some(mmp);
+        self
+    }
+
+    /// Reject the request if it reaches the matching engine after this time (ms)
+    #[must_use]
+    pub fn valid_until(mut self, valid_until: i64) -> Self {
+        self.valid_until = Some(valid_until);
+        self
+    }
+
+    /// Linked order type (OTO, OCO, OTOCO)
+    #[must_use]
+    pub fn linked_order_type(mut self, linked_order_type: LinkedOrderType) -> Self {
+        self.linked_order_type = Some(linked_order_type);
+        self
+    }
+
+    /// When the secondary orders of a linked order are placed
+    #[must_use]
+    pub fn trigger_fill_condition(mut self, trigger_fill_condition: TriggerFillCondition) -> Self {
+        self.trigger_fill_condition = Some(trigger_fill_condition);
+        self
+    }
+
+    /// Secondary orders of a linked order
+    #[must_use]
+    pub fn otoco_config(mut self, otoco_config: Vec<LinkedOrder>) -> Self {
+        self.otoco_config = Some(otoco_config);
+        self
+    }
+
+    /// Linked order type, fill condition and secondary orders in one go
+    #[must_use]
+    pub fn linked_orders(mut self, linked_orders: LinkedOrders) -> Self {
+        let (linked_order_type, trigger_fill_condition, orders) = linked_orders.into_parts();
+        self.linked_order_type = Some(linked_order_type);
+        self.trigger_fill_condition = trigger_fill_condition;
+        self.otoco_config = Some(orders);
+        self
+    }
+
+    /// Validate and build the request
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`OrderValidationError`] if the instrument is missing, if
+    /// not exactly one of `amount` and `contracts` is set or it is not
+    /// positive, if a limit, stop-limit or take-limit order has no price, if
+    /// a stop or take order has no trigger price, if a trailing stop has
+    /// no trigger offset, or if a linked order type has no valid secondary
+    /// orders.
+    pub fn build(self) -> Result<OrderRequest, OrderValidationError> {
+        let instrument_name = self
+            .instrument_name
+            .filter(|name| !name.is_empty())
+            .ok_or(OrderValidationError::MissingInstrument)?;
+
+        let size = match (self.amount, self.contracts) {
+            (Some(_), Some(_)) => return Err(OrderValidationError::AmountAndContracts),
+            (None, None) => return Err(OrderValidationError::MissingAmount),
+            (Some(size), None) | (None, Some(size)) => size,
+        };
+        if !size.is_finite() || size <= 0.0 {
+            return Err(OrderValidationError::InvalidAmount(size));
+        }
+
+        if let Some(order_type) = self.type_ {
+            check_order_type(
+                order_type,
+                self.price,
+                self.trigger_price,
+                self.trigger_offset,
+            )?;
+        }
+
+        if self.linked_order_type.is_some() && self.otoco_config.as_ref().is_none_or(Vec::is_empty)
+        {
+            return Err(OrderValidationError::MissingLinkedOrders);
+        }
+        for (index, order) in self.otoco_config.iter().flatten().enumerate() {
+            check_linked_order(order).map_err(|error| {
+                OrderValidationError::InvalidLinkedOrder(index, Box::new(error))
+            })?;
+        }
+
+        Ok(OrderRequest {
+            order_id: self.order_id,
+            instrument_name,
+            amount: self.amount,
+            contracts: self.contracts,
+            type_: self.type_,
+            label: self.label,
+            price: self.price,
+            time_in_force: self.time_in_force,
+            display_amount: self.display_amount,
+            post_only: self.post_only,
+            reject_post_only: self.reject_post_only,
+            reduce_only: self.reduce_only,
+            trigger_price: self.trigger_price,
+            trigger_offset: self.trigger_offset,
+            trigger: self.trigger,
+            advanced: self.advanced,
+            mmp: self.mmp,
+            valid_until: self.valid_until,
+            linked_order_type: self.linked_order_type,
+            trigger_fill_condition: self.trigger_fill_condition,
+            otoco_config: self.otoco_config,
+        })
+    }
+}
+
+fn check_order_type(
+    order_type: OrderType,
+    price: Option<f64>,
+    trigger_price: Option<f64>,
+    trigger_offset: Option<f64>,
+) -> Result<(), OrderValidationError> {
+    let needs_price = matches!(
+        order_type,
+        OrderType::Limit | OrderType::StopLimit | OrderType::TakeLimit
+    );
+    if needs_price && price.is_none() {
+        return Err(OrderValidationError::MissingPrice(order_type));
+    }
+    let needs_trigger_price = matches!(
+        order_type,
+        OrderType::StopLimit | OrderType::StopMarket | OrderType::TakeLimit | OrderType::TakeMarket
+    );
+    if needs_trigger_price && trigger_price.is_none() {
+        return Err(OrderValidationError::MissingTriggerPrice(order_type));
+    }
+    if order_type == OrderType::TrailingStop && trigger_offset.is_none() {
+        return Err(OrderValidationError::MissingTriggerOffset);
+    }
+    Ok(())
+}
+
+fn check_linked_order(order: &LinkedOrder) -> Result<(), OrderValidationError> {
+    if !order.amount.is_finite() || order.amount <= 0.0 {
+        return Err(OrderValidationError::InvalidAmount(order.amount));
+    }
+    check_order_type(
+        order.type_,
+        order.price,
+        order.trigger_price,
+        order.trigger_offset,
+    )
+}
@@ -3,7 +3,8 @@
    Email: jb@taunais.com
    Date: 15/9/25
 ******************************************************************************/
-use crate::model::{Currency, InstrumentKind, SortDirection};
+use crate::model::timestamp::TimestampMs;
+use crate::model::{Currency, InstrumentKind, SortDirection, TimeRange};
 use serde::{Deserialize, Serialize};
 
 /// Parameters for requesting user trades
@@ -20,9 +21,9 @@ pub struct TradesRequest {
     /// Number of requested items, default - 10, maximum - 1000 (optional)
     pub count: Option<u32>,
     /// The earliest timestamp to return result from (milliseconds since UNIX epoch) (optional)
-    pub start_timestamp: Option<u64>,
+    pub start_timestamp: Option<TimestampMs>,
     /// The most recent timestamp to return result from (milliseconds since UNIX epoch) (optional)
-    pub end_timestamp: Option<u64>,
+    pub end_timestamp: Option<TimestampMs>,
     /// Direction of results sorting (optional)
     pub sorting: Option<SortDirection>,
     /// Determines whether historical trade records should be retrieved (optional)
@@ -30,3 +31,14 @@ pub struct TradesRequest {
     /// The user id for the subaccount (optional)
     pub subaccount_id: Option<u32>,
 }
+
+impl TradesRequest {
+    /// Set `start_timestamp` and `end_timestamp` from a time window
+    #[must_use]
+    pub fn with_time_range(mut self, range: impl Into<TimeRange>) -> Self {
+        let (start_timestamp, end_timestamp) = range.into().bounds();
+        self.start_timestamp = Some(start_timestamp);
+        self.end_timestamp = Some(end_timestamp);
+        self
+    }
+}
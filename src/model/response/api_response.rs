@@ -8,6 +8,7 @@ use pretty_simple_display::{DebugPretty, DisplaySimple};
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 use std::collections::HashMap;
+use std::time::Duration;
 
 /// HTTP response structure
 #[skip_serializing_none]
@@ -45,3 +46,46 @@ pub struct ApiResponse<T> {
     /// Whether this is a testnet response
     pub testnet: Option<bool>,
 }
+
+impl<T> ApiResponse<T> {
+    /// Envelope metadata of this response
+    pub fn meta(&self) -> ResponseMeta {
+        ResponseMeta {
+            id: self.id,
+            us_in: self.us_in,
+            us_out: self.us_out,
+            us_diff: self.us_diff,
+            testnet: self.testnet,
+        }
+    }
+}
+
+/// JSON-RPC envelope metadata of a response
+///
+/// The timestamps are the server's, in microseconds since the UNIX epoch.
+#[skip_serializing_none]
+#[derive(
+    DebugPretty, DisplaySimple, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize,
+)]
+pub struct ResponseMeta {
+    /// Request ID echoed by the server
+    pub id: Option<u64>,
+    /// When the server received the request
+    #[serde(rename = "usIn")]
+    pub us_in: Option<u64>,
+    /// When the server sent the response
+    #[serde(rename = "usOut")]
+    pub us_out: Option<u64>,
+    /// Server processing time in microseconds
+    #[serde(rename = "usDiff")]
+    pub us_diff: Option<u64>,
+    /// Whether the response came from testnet
+    pub testnet: Option<bool>,
+}
+
+impl ResponseMeta {
+    /// Server processing time, from `usDiff`
+    pub fn server_time(&self) -> Option<Duration> {
+        self.us_diff.map(Duration::from_micros)
+    }
+}
@@ -5,7 +5,9 @@
 ******************************************************************************/
 //! Block RFQ response models for Request for Quote workflow.
 
+use crate::model::timestamp::TimestampMs;
 use crate::model::types::Direction;
+use crate::pagination::ContinuationToken;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
@@ -122,7 +124,7 @@ pub struct BlockRfqBidAsk {
     pub price: f64,
     /// Timestamp of last update (milliseconds since Unix epoch)
     #[serde(default)]
-    pub last_update_timestamp: Option<i64>,
+    pub last_update_timestamp: Option<TimestampMs>,
     /// Execution instruction
     #[serde(default)]
     pub execution_instruction: Option<ExecutionInstruction>,
@@ -196,9 +198,9 @@ pub struct BlockRfq {
     #[serde(default)]
     pub hedge: Option<BlockRfqHedge>,
     /// Creation timestamp (milliseconds since Unix epoch)
-    pub creation_timestamp: i64,
+    pub creation_timestamp: TimestampMs,
     /// Expiration timestamp (milliseconds since Unix epoch)
-    pub expiration_timestamp: i64,
+    pub expiration_timestamp: TimestampMs,
     /// User-defined label
     #[serde(default)]
     pub label: Option<String>,
@@ -297,9 +299,9 @@ pub struct BlockRfqQuote {
     #[serde(default)]
     pub execution_instruction: Option<ExecutionInstruction>,
     /// Creation timestamp (milliseconds since Unix epoch)
-    pub creation_timestamp: i64,
+    pub creation_timestamp: TimestampMs,
     /// Last update timestamp (milliseconds since Unix epoch)
-    pub last_update_timestamp: i64,
+    pub last_update_timestamp: TimestampMs,
     /// Whether the quote was replaced/edited
     #[serde(default)]
     pub replaced: Option<bool>,
@@ -341,7 +343,7 @@ pub struct BlockRfqPublicTrade {
     /// Block RFQ ID
     pub id: i64,
     /// Timestamp (milliseconds since Unix epoch)
-    pub timestamp: i64,
+    pub timestamp: TimestampMs,
     /// Combo ID
     #[serde(default)]
     pub combo_id: Option<String>,
@@ -370,7 +372,7 @@ pub struct BlockRfqPublicTrade {
 pub struct BlockRfqTradesResponse {
     /// Continuation token for pagination
     #[serde(default)]
-    pub continuation: Option<String>,
+    pub continuation: Option<ContinuationToken>,
     /// List of Block RFQ trades
     pub block_rfqs: Vec<BlockRfqPublicTrade>,
 }
@@ -400,7 +402,7 @@ impl BlockRfqTradesResponse {
 pub struct BlockRfqsResponse {
     /// Continuation token for pagination
     #[serde(default)]
-    pub continuation: Option<String>,
+    pub continuation: Option<ContinuationToken>,
     /// List of Block RFQs
     pub block_rfqs: Vec<BlockRfq>,
 }
@@ -437,7 +439,7 @@ pub struct BlockRfqAcceptTrade {
     /// Instrument name
     pub instrument_name: String,
     /// Timestamp (milliseconds since Unix epoch)
-    pub timestamp: i64,
+    pub timestamp: TimestampMs,
     /// Trade state
     pub state: String,
     /// Fee
@@ -517,7 +519,7 @@ pub struct BlockRfqAcceptBlockTrade {
     /// Block trade ID
     pub id: String,
     /// Timestamp (milliseconds since Unix epoch)
-    pub timestamp: i64,
+    pub timestamp: TimestampMs,
     /// Individual trades
     pub trades: Vec<BlockRfqAcceptTrade>,
 }
@@ -3,6 +3,7 @@
    Email: jb@taunais.com
    Date: 15/9/25
 ******************************************************************************/
+use crate::model::timestamp::TimestampMs;
 use crate::model::trade::TradeExecution;
 use pretty_simple_display::{DebugPretty, DisplaySimple};
 use serde::{Deserialize, Serialize};
@@ -17,6 +18,16 @@ pub struct OrderResponse {
     pub trades: Vec<TradeExecution>,
 }
 
+impl OrderResponse {
+    /// Ids of the secondary orders linked to this order
+    ///
+    /// Empty unless the order was placed with a linked order type.
+    #[must_use]
+    pub fn linked_order_ids(&self) -> &[String] {
+        self.order.oto_order_ids.as_deref().unwrap_or_default()
+    }
+}
+
 /// Types of linked orders supported by Deribit
 #[derive(DebugPretty, DisplaySimple, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -40,7 +51,7 @@ pub struct OrderInfoResponse {
     /// Average execution price
     pub average_price: Option<f64>,
     /// Order creation timestamp
-    pub creation_timestamp: u64,
+    pub creation_timestamp: TimestampMs,
     /// Order direction (buy/sell)
     pub direction: String,
     /// Amount that has been filled
@@ -52,7 +63,7 @@ pub struct OrderInfoResponse {
     /// Order label
     pub label: String,
     /// Last update timestamp
-    pub last_update_timestamp: u64,
+    pub last_update_timestamp: TimestampMs,
     /// Maximum amount to show in order book (optional)
     pub max_show: Option<f64>,
     /// Unique order identifier
@@ -85,4 +96,14 @@ pub struct OrderInfoResponse {
     pub usd: Option<f64>,
     /// Whether order was placed via web interface
     pub web: bool,
+    /// Whether this is the primary order of an OTO or OTOCO group
+    pub is_primary_otoco: Option<bool>,
+    /// Whether this is a secondary order placed by an OTO trigger
+    pub is_secondary_oto: Option<bool>,
+    /// Ids of the secondary orders placed when this order triggers
+    pub oto_order_ids: Option<Vec<String>>,
+    /// Id of the primary order this secondary order belongs to
+    pub primary_order_id: Option<String>,
+    /// Id of the OCO group this order belongs to
+    pub oco_ref: Option<String>,
 }
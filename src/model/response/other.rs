@@ -3,6 +3,8 @@
    Email: jb@taunais.com
    Date: 15/9/25
 ******************************************************************************/
+use crate::model::timestamp::TimestampMs;
+use crate::pagination::ContinuationToken;
 use crate::prelude::*;
 use pretty_simple_display::{DebugPretty, DisplaySimple};
 use serde::{Deserialize, Serialize};
@@ -98,7 +100,9 @@ pub struct TestResponse {
 #[skip_serializing_none]
 #[derive(DebugPretty, DisplaySimple, Clone, Serialize, Deserialize)]
 pub struct StatusResponse {
-    /// Whether the system is locked (optional)
+    /// Whether the whole platform is locked (optional); a partial lock reads
+    /// `false`, with the locked indices in `locked_indices`
+    #[serde(default, deserialize_with = "deserialize_locked")]
     pub locked: Option<bool>,
     /// Status message (optional)
     pub message: Option<String>,
@@ -109,6 +113,24 @@ pub struct StatusResponse {
     pub additional_fields: std::collections::HashMap<String, serde_json::Value>,
 }
 
+/// Deserialize the lock state, sent as `"true"`, `"partial"` or `"false"`
+fn deserialize_locked<'de, D>(deserializer: D) -> Result<Option<bool>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    use serde::de::Error;
+    match Option::<serde_json::Value>::deserialize(deserializer)? {
+        None | Some(serde_json::Value::Null) => Ok(None),
+        Some(serde_json::Value::Bool(locked)) => Ok(Some(locked)),
+        Some(serde_json::Value::String(locked)) => match locked.as_str() {
+            "true" => Ok(Some(true)),
+            "partial" | "false" => Ok(Some(false)),
+            other => Err(D::Error::custom(format!("invalid lock state: {}", other))),
+        },
+        Some(other) => Err(D::Error::custom(format!("invalid lock state: {}", other))),
+    }
+}
+
 /// APR history response
 #[skip_serializing_none]
 #[derive(DebugPretty, DisplaySimple, Clone, Serialize, Deserialize)]
@@ -116,7 +138,7 @@ pub struct AprHistoryResponse {
     /// List of APR data points
     pub data: Vec<AprDataPoint>,
     /// Continuation token for pagination
-    pub continuation: Option<String>,
+    pub continuation: Option<ContinuationToken>,
 }
 
 /// Hello response
@@ -142,7 +164,7 @@ pub struct AprDataPoint {
     /// Annual percentage rate
     pub apr: f64,
     /// Timestamp of the data point (optional)
-    pub timestamp: Option<u64>,
+    pub timestamp: Option<TimestampMs>,
     /// Day of the data point
     pub day: i32,
 }
@@ -174,7 +196,7 @@ pub struct LastTradesResponse {
 #[derive(DebugPretty, DisplaySimple, Clone, Serialize, Deserialize)]
 pub struct SettlementsResponse {
     /// Continuation token for pagination
-    pub continuation: Option<String>,
+    pub continuation: Option<ContinuationToken>,
     /// List of settlement events
     pub settlements: Vec<Settlement>,
 }
@@ -191,10 +213,10 @@ impl SettlementsResponse {
     /// Create settlements response with continuation token
     pub fn with_continuation(
         settlements: Vec<crate::model::settlement::Settlement>,
-        continuation: String,
+        continuation: impl Into<ContinuationToken>,
     ) -> Self {
         Self {
-            continuation: Some(continuation),
+            continuation: Some(continuation.into()),
             settlements,
         }
     }
@@ -239,7 +261,7 @@ pub struct AccountInfo {
     /// When Block RFQ Self Match Prevention is enabled
     pub block_rfq_self_match_prevention: Option<bool>,
     /// Time at which the account was created (milliseconds since the Unix epoch)
-    pub creation_timestamp: Option<u64>,
+    pub creation_timestamp: Option<TimestampMs>,
     /// Account type
     #[serde(rename = "type")]
     pub account_type: Option<String>,
@@ -280,7 +302,7 @@ pub struct AccountSummaryResponse {
     pub block_rfq_self_match_prevention: bool,
     /// Time at which the account was created (milliseconds since the Unix epoch)
     #[serde(default)]
-    pub creation_timestamp: u64,
+    pub creation_timestamp: TimestampMs,
     /// Account type
     #[serde(rename = "type", default)]
     pub account_type: String,
@@ -323,6 +345,23 @@ pub struct AccountSummariesResponse {
     pub summaries: Vec<AccountResult>,
 }
 
+impl AccountSummariesResponse {
+    /// Summary of `currency`, if the account holds it
+    pub fn summary(&self, currency: &str) -> Option<&AccountResult> {
+        self.summaries
+            .iter()
+            .find(|summary| summary.currency.eq_ignore_ascii_case(currency))
+    }
+
+    /// Currencies with a summary, in response order
+    pub fn currencies(&self) -> Vec<&str> {
+        self.summaries
+            .iter()
+            .map(|summary| summary.currency.as_str())
+            .collect()
+    }
+}
+
 /// Mark price history data point
 ///
 /// Represents a single data point in mark price history.
@@ -331,7 +370,7 @@ pub struct AccountSummariesResponse {
 #[serde(from = "(u64, f64)", into = "(u64, f64)")]
 pub struct MarkPriceHistoryPoint {
     /// Timestamp in milliseconds since Unix epoch
-    pub timestamp: u64,
+    pub timestamp: TimestampMs,
     /// Mark price value
     pub mark_price: f64,
 }
@@ -339,7 +378,7 @@ pub struct MarkPriceHistoryPoint {
 impl From<(u64, f64)> for MarkPriceHistoryPoint {
     fn from((timestamp, mark_price): (u64, f64)) -> Self {
         Self {
-            timestamp,
+            timestamp: TimestampMs(timestamp),
             mark_price,
         }
     }
@@ -347,7 +386,7 @@ impl From<(u64, f64)> for MarkPriceHistoryPoint {
 
 impl From<MarkPriceHistoryPoint> for (u64, f64) {
     fn from(point: MarkPriceHistoryPoint) -> Self {
-        (point.timestamp, point.mark_price)
+        (point.timestamp.as_millis(), point.mark_price)
     }
 }
 
@@ -407,7 +446,7 @@ pub struct TradeVolume {
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct VolatilityIndexCandle {
     /// Timestamp in milliseconds since UNIX epoch
-    pub timestamp: u64,
+    pub timestamp: TimestampMs,
     /// Open value
     pub open: f64,
     /// High value
@@ -418,6 +457,45 @@ pub struct VolatilityIndexCandle {
     pub close: f64,
 }
 
+/// Candle resolution of `get_volatility_index_data`, in seconds or one day
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum VolatilityIndexResolution {
+    /// 1 second
+    #[serde(rename = "1")]
+    Second1,
+    /// 1 minute
+    #[serde(rename = "60")]
+    Minute1,
+    /// 1 hour
+    #[serde(rename = "3600")]
+    Hour1,
+    /// 12 hours
+    #[serde(rename = "43200")]
+    Hour12,
+    /// 1 day
+    #[serde(rename = "1D")]
+    Day1,
+}
+
+impl VolatilityIndexResolution {
+    /// Returns the string representation of the resolution
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            VolatilityIndexResolution::Second1 => "1",
+            VolatilityIndexResolution::Minute1 => "60",
+            VolatilityIndexResolution::Hour1 => "3600",
+            VolatilityIndexResolution::Hour12 => "43200",
+            VolatilityIndexResolution::Day1 => "1D",
+        }
+    }
+}
+
+impl std::fmt::Display for VolatilityIndexResolution {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
 /// Response from get_volatility_index_data
 ///
 /// Contains volatility index candles and optional continuation token.
@@ -464,7 +542,7 @@ where
             .ok_or_else(|| D::Error::custom("invalid close"))?;
 
         candles.push(VolatilityIndexCandle {
-            timestamp,
+            timestamp: TimestampMs(timestamp),
             open,
             high,
             low,
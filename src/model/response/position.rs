@@ -58,6 +58,34 @@ impl MovePositionResult {
     }
 }
 
+/// Response from move_positions endpoint
+///
+/// Deribit documents the result as `{"trades": [...]}`; a bare list of
+/// trades is accepted as well.
+#[derive(DebugPretty, DisplaySimple, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(from = "MovePositionsPayload")]
+pub struct MovePositionsResponse {
+    /// Trades executed to move the positions
+    pub trades: Vec<MovePositionResult>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum MovePositionsPayload {
+    Trades { trades: Vec<MovePositionResult> },
+    List(Vec<MovePositionResult>),
+}
+
+impl From<MovePositionsPayload> for MovePositionsResponse {
+    fn from(payload: MovePositionsPayload) -> Self {
+        match payload {
+            MovePositionsPayload::Trades { trades } | MovePositionsPayload::List(trades) => {
+                Self { trades }
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -106,4 +134,17 @@ mod tests {
         assert_eq!(result.source_uid, 3);
         assert_eq!(result.target_uid, 23);
     }
+
+    #[test]
+    fn test_move_positions_response_accepts_both_shapes() {
+        let trade = r#"{"target_uid": 23, "source_uid": 3, "price": 35800.0,
+            "instrument_name": "BTC-PERPETUAL", "direction": "buy", "amount": 110.0}"#;
+
+        let wrapped: MovePositionsResponse =
+            serde_json::from_str(&format!(r#"{{"trades": [{}]}}"#, trade)).unwrap();
+        let bare: MovePositionsResponse = serde_json::from_str(&format!("[{}]", trade)).unwrap();
+
+        assert_eq!(wrapped, bare);
+        assert_eq!(wrapped.trades[0].target_uid, 23);
+    }
 }
@@ -6,6 +6,7 @@
 //! Subaccount response models
 
 use crate::model::position::Position;
+use crate::model::response::order::OrderInfoResponse;
 use pretty_simple_display::{DebugPretty, DisplaySimple};
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
@@ -22,7 +23,7 @@ pub struct SubaccountDetails {
     /// List of positions for this subaccount
     pub positions: Vec<Position>,
     /// Open orders (optional, when with_open_orders=true)
-    pub open_orders: Option<Vec<serde_json::Value>>,
+    pub open_orders: Option<Vec<OrderInfoResponse>>,
 }
 
 impl SubaccountDetails {
@@ -39,7 +40,7 @@ impl SubaccountDetails {
     pub fn with_open_orders(
         uid: i64,
         positions: Vec<Position>,
-        open_orders: Vec<serde_json::Value>,
+        open_orders: Vec<OrderInfoResponse>,
     ) -> Self {
         Self {
             uid,
@@ -62,53 +63,43 @@ impl SubaccountDetails {
     pub fn has_open_orders(&self) -> bool {
         self.open_orders
             .as_ref()
-            .map(|orders: &Vec<serde_json::Value>| !orders.is_empty())
+            .map(|orders| !orders.is_empty())
             .unwrap_or(false)
     }
 
     /// Get the number of open orders
     pub fn open_orders_count(&self) -> usize {
-        self.open_orders
-            .as_ref()
-            .map(|orders: &Vec<serde_json::Value>| orders.len())
-            .unwrap_or(0)
+        self.open_orders.as_ref().map(Vec::len).unwrap_or(0)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::model::types::Direction;
 
     fn create_test_position() -> Position {
-        Position {
-            average_price: 49571.3,
-            average_price_usd: None,
-            delta: Some(0.004152776),
-            direction: Direction::Buy,
-            estimated_liquidation_price: Some(2.33),
-            floating_profit_loss: Some(-0.00003451),
-            floating_profit_loss_usd: None,
-            gamma: None,
-            index_price: Some(47897.12),
-            initial_margin: Some(0.000122508),
-            instrument_name: "BTC-PERPETUAL".to_string(),
-            interest_value: None,
-            kind: Some("future".to_string()),
-            leverage: Some(34),
-            maintenance_margin: Some(0.000089286),
-            mark_price: Some(48160.55),
-            open_orders_margin: Some(0.0),
-            realized_funding: Some(-8.8e-7),
-            realized_profit_loss: Some(-8.79e-7),
-            settlement_price: Some(48150.36),
-            size: 200.0,
-            size_currency: Some(0.004152776),
-            theta: None,
-            total_profit_loss: Some(-0.000118183),
-            vega: None,
-            unrealized_profit_loss: None,
-        }
+        serde_json::from_value(serde_json::json!({
+            "average_price": 49571.3,
+            "delta": 0.004152776,
+            "direction": "buy",
+            "estimated_liquidation_price": 2.33,
+            "floating_profit_loss": -0.00003451,
+            "index_price": 47897.12,
+            "initial_margin": 0.000122508,
+            "instrument_name": "BTC-PERPETUAL",
+            "kind": "future",
+            "leverage": 34,
+            "maintenance_margin": 0.000089286,
+            "mark_price": 48160.55,
+            "open_orders_margin": 0.0,
+            "realized_funding": -8.8e-7,
+            "realized_profit_loss": -8.79e-7,
+            "settlement_price": 48150.36,
+            "size": 200.0,
+            "size_currency": 0.004152776,
+            "total_profit_loss": -0.000118183
+        }))
+        .unwrap()
     }
 
     #[test]
@@ -166,4 +157,43 @@ mod tests {
         assert_eq!(details.position_count(), 1);
         assert_eq!(details.positions[0].instrument_name, "BTC-PERPETUAL");
     }
+
+    #[test]
+    fn test_subaccount_details_with_open_orders_deserialization() {
+        let json = r#"{
+            "uid": 7,
+            "positions": [],
+            "open_orders": [
+                {
+                    "amount": 10.0,
+                    "api": true,
+                    "creation_timestamp": 1700000000000,
+                    "direction": "buy",
+                    "filled_amount": 0.0,
+                    "instrument_name": "BTC-PERPETUAL",
+                    "is_liquidation": false,
+                    "label": "",
+                    "last_update_timestamp": 1700000000000,
+                    "order_id": "BTC-1",
+                    "order_state": "open",
+                    "order_type": "limit",
+                    "post_only": false,
+                    "price": 30000.0,
+                    "reduce_only": false,
+                    "replaced": false,
+                    "risk_reducing": false,
+                    "time_in_force": "good_til_cancelled",
+                    "web": false
+                }
+            ]
+        }"#;
+
+        let details: SubaccountDetails = serde_json::from_str(json).unwrap();
+        assert!(details.has_open_orders());
+        assert_eq!(details.open_orders_count(), 1);
+        assert_eq!(
+            details.open_orders.unwrap()[0].instrument_name,
+            "BTC-PERPETUAL"
+        );
+    }
 }
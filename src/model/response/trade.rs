@@ -4,6 +4,7 @@
    Date: 15/9/25
 ******************************************************************************/
 use crate::model::TradeAllocation;
+use crate::model::timestamp::TimestampMs;
 use pretty_simple_display::{DebugPretty, DisplaySimple};
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
@@ -118,7 +119,7 @@ pub struct UserTradeResponseByOrder {
     pub tick_direction: i32,
 
     /// The timestamp of the trade (milliseconds since the UNIX epoch)
-    pub timestamp: u64,
+    pub timestamp: TimestampMs,
 
     /// List of allocations for Block RFQ pre-allocation
     pub trade_allocations: Option<Vec<TradeAllocation>>,
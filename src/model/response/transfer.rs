@@ -5,6 +5,7 @@
 ******************************************************************************/
 //! Transfer response models for internal transfers between subaccounts.
 
+use crate::model::timestamp::TimestampMs;
 use serde::{Deserialize, Serialize};
 
 /// State of an internal transfer
@@ -66,9 +67,9 @@ pub struct InternalTransfer {
     #[serde(rename = "type")]
     pub transfer_type: InternalTransferType,
     /// Creation timestamp in milliseconds since Unix epoch
-    pub created_timestamp: i64,
+    pub created_timestamp: TimestampMs,
     /// Last update timestamp in milliseconds since Unix epoch
-    pub updated_timestamp: i64,
+    pub updated_timestamp: TimestampMs,
 }
 
 impl InternalTransfer {
@@ -194,8 +195,8 @@ mod tests {
             other_side: "test".to_string(),
             state: InternalTransferState::Prepared,
             transfer_type: InternalTransferType::Subaccount,
-            created_timestamp: 0,
-            updated_timestamp: 0,
+            created_timestamp: TimestampMs(0),
+            updated_timestamp: TimestampMs(0),
         };
 
         assert!(transfer.is_pending());
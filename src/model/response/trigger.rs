@@ -3,8 +3,10 @@
    Email: jb@taunais.com
    Date: 7/3/26
 ******************************************************************************/
-//! Trigger order response models
+//! Trigger and stop order history response models
 
+use crate::model::timestamp::TimestampMs;
+use crate::pagination::ContinuationToken;
 use pretty_simple_display::{DebugPretty, DisplaySimple};
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
@@ -17,7 +19,7 @@ use serde_with::skip_serializing_none;
 #[derive(DebugPretty, DisplaySimple, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TriggerOrderHistoryEntry {
     /// Timestamp of the event in milliseconds since Unix epoch
-    pub timestamp: i64,
+    pub timestamp: TimestampMs,
     /// Trigger type: "index_price", "mark_price", or "last_price"
     pub trigger: Option<String>,
     /// Trigger price (only for future trigger orders)
@@ -55,7 +57,7 @@ pub struct TriggerOrderHistoryEntry {
     /// Source of the order linked to trigger order
     pub trigger_source: Option<String>,
     /// Last update timestamp in milliseconds since Unix epoch
-    pub last_update_timestamp: Option<i64>,
+    pub last_update_timestamp: Option<TimestampMs>,
 }
 
 /// Response from get_trigger_order_history endpoint
@@ -68,7 +70,7 @@ pub struct TriggerOrderHistoryResponse {
     /// List of trigger order history entries
     pub entries: Vec<TriggerOrderHistoryEntry>,
     /// Continuation token for pagination
-    pub continuation: Option<String>,
+    pub continuation: Option<ContinuationToken>,
 }
 
 impl TriggerOrderHistoryResponse {
@@ -81,10 +83,13 @@ impl TriggerOrderHistoryResponse {
     }
 
     /// Create response with continuation token
-    pub fn with_continuation(entries: Vec<TriggerOrderHistoryEntry>, continuation: String) -> Self {
+    pub fn with_continuation(
+        entries: Vec<TriggerOrderHistoryEntry>,
+        continuation: impl Into<ContinuationToken>,
+    ) -> Self {
         Self {
             entries,
-            continuation: Some(continuation),
+            continuation: Some(continuation.into()),
         }
     }
 
@@ -110,6 +115,68 @@ impl Default for TriggerOrderHistoryResponse {
     }
 }
 
+/// A single entry in the stop order history
+///
+/// Returned by the legacy `get_stop_order_history` endpoint, which names
+/// trigger fields after stop orders (`stop_id`, `stop_price`).
+#[skip_serializing_none]
+#[derive(DebugPretty, DisplaySimple, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StopOrderHistoryEntry {
+    /// Timestamp of the event in milliseconds since Unix epoch
+    pub timestamp: TimestampMs,
+    /// Trigger type: "index_price", "mark_price", or "last_price"
+    pub trigger: Option<String>,
+    /// Stop price
+    pub stop_price: Option<f64>,
+    /// Maximum deviation from price peak for trailing stop orders
+    pub offset: Option<f64>,
+    /// ID of the stop order before triggering
+    pub stop_id: String,
+    /// Unique order identifier after triggering
+    pub order_id: Option<String>,
+    /// Order state: "triggered", "cancelled", or "rejected"
+    pub order_state: String,
+    /// Unique instrument identifier
+    pub instrument_name: String,
+    /// Type of last request: "cancel" or "trigger:order"
+    pub request: Option<String>,
+    /// Direction: "buy" or "sell"
+    pub direction: String,
+    /// Price in base currency
+    pub price: Option<f64>,
+    /// Order size (USD for perpetual/inverse, base currency for options/linear)
+    pub amount: f64,
+    /// True for reduce-only orders
+    pub reduce_only: Option<bool>,
+    /// True for post-only orders
+    pub post_only: Option<bool>,
+    /// Order type: "limit" or "market"
+    pub order_type: Option<String>,
+    /// User defined label
+    pub label: Option<String>,
+    /// Source of the stop order
+    pub source: Option<String>,
+    /// Last update timestamp in milliseconds since Unix epoch
+    pub last_update_timestamp: Option<TimestampMs>,
+}
+
+/// Response from get_stop_order_history endpoint
+#[skip_serializing_none]
+#[derive(DebugPretty, DisplaySimple, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub struct StopOrderHistoryResponse {
+    /// List of stop order history entries
+    pub entries: Vec<StopOrderHistoryEntry>,
+    /// Continuation token for pagination
+    pub continuation: Option<ContinuationToken>,
+}
+
+impl StopOrderHistoryResponse {
+    /// Check if there are more results
+    pub fn has_more(&self) -> bool {
+        self.continuation.is_some()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -127,7 +194,7 @@ mod tests {
         let response =
             TriggerOrderHistoryResponse::with_continuation(vec![], "token123".to_string());
         assert!(response.entries.is_empty());
-        assert_eq!(response.continuation, Some("token123".to_string()));
+        assert_eq!(response.continuation.as_deref(), Some("token123"));
         assert!(response.has_more());
     }
 
@@ -148,7 +215,7 @@ mod tests {
         }"#;
 
         let entry: TriggerOrderHistoryEntry = serde_json::from_str(json).unwrap();
-        assert_eq!(entry.timestamp, 1555918941451);
+        assert_eq!(entry.timestamp.as_millis(), 1555918941451);
         assert_eq!(entry.trigger, Some("index_price".to_string()));
         assert_eq!(entry.trigger_price, Some(5285.0));
         assert_eq!(entry.trigger_order_id, "SLIS-103");
@@ -158,4 +225,32 @@ mod tests {
         assert_eq!(entry.direction, "buy");
         assert_eq!(entry.amount, 10.0);
     }
+
+    #[test]
+    fn test_stop_order_history_response_deserialization() {
+        let json = r#"{
+            "entries": [{
+                "timestamp": 1555918941451,
+                "trigger": "index_price",
+                "stop_price": 5285.0,
+                "stop_id": "SLIS-103",
+                "order_id": "671473",
+                "order_state": "triggered",
+                "instrument_name": "BTC-PERPETUAL",
+                "request": "trigger:order",
+                "direction": "buy",
+                "price": 5179.28,
+                "amount": 10.0
+            }],
+            "continuation": "1555918941451"
+        }"#;
+
+        let response: StopOrderHistoryResponse = serde_json::from_str(json).unwrap();
+        assert!(response.has_more());
+        let entry = &response.entries[0];
+        assert_eq!(entry.stop_id, "SLIS-103");
+        assert_eq!(entry.stop_price, Some(5285.0));
+        assert_eq!(entry.order_state, "triggered");
+        assert_eq!(entry.amount, 10.0);
+    }
 }
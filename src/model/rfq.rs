@@ -0,0 +1,84 @@
+//! Request-for-quote models for Deribit API
+//!
+//! A request for quote (RFQ) asks market makers to quote an instrument,
+//! typically a large option trade, without revealing the full order.
+
+use crate::model::timestamp::TimestampMs;
+use serde::{Deserialize, Serialize};
+
+/// Side of an RFQ
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RfqSide {
+    /// The requester wants to buy
+    Buy,
+    /// The requester wants to sell
+    Sell,
+}
+
+impl RfqSide {
+    /// Returns the side as a string for API requests
+    #[must_use]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Buy => "buy",
+            Self::Sell => "sell",
+        }
+    }
+}
+
+impl std::fmt::Display for RfqSide {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// An active request for quote
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Rfq {
+    /// Instrument the quote is requested for
+    pub instrument_name: String,
+    /// Requested amount, if disclosed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub amount: Option<f64>,
+    /// Requested side, if disclosed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub side: Option<RfqSide>,
+    /// Whether the RFQ is still active
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state: Option<bool>,
+    /// Time of the last update of the RFQ
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_update_timestamp: Option<TimestampMs>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_side_serialization() {
+        assert_eq!(serde_json::to_string(&RfqSide::Sell).unwrap(), "\"sell\"");
+        assert_eq!(RfqSide::Buy.to_string(), "buy");
+    }
+
+    #[test]
+    fn test_rfq_deserialization() {
+        let json = r#"{
+            "state": true,
+            "side": "buy",
+            "last_update_timestamp": 1634816143836,
+            "instrument_name": "BTC-PERPETUAL",
+            "amount": 40000
+        }"#;
+        let rfq: Rfq = serde_json::from_str(json).unwrap();
+        assert_eq!(rfq.instrument_name, "BTC-PERPETUAL");
+        assert_eq!(rfq.side, Some(RfqSide::Buy));
+        assert_eq!(rfq.amount, Some(40000.0));
+        assert_eq!(rfq.state, Some(true));
+
+        let anonymous: Rfq =
+            serde_json::from_str(r#"{"instrument_name": "ETH-PERPETUAL"}"#).unwrap();
+        assert!(anonymous.side.is_none());
+    }
+}
@@ -3,6 +3,7 @@
    Email: jb@taunais.com
    Date: 21/7/25
 ******************************************************************************/
+use crate::model::timestamp::TimestampMs;
 use pretty_simple_display::{DebugPretty, DisplaySimple};
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
@@ -29,7 +30,7 @@ pub struct Settlement {
     #[serde(alias = "type")]
     pub settlement_type: SettlementType,
     /// Timestamp of the settlement event (milliseconds since Unix epoch)
-    pub timestamp: i64,
+    pub timestamp: TimestampMs,
     /// Instrument name (settlement and delivery only)
     pub instrument_name: Option<String>,
     /// Position size in quote currency (settlement and delivery only)
@@ -60,7 +61,7 @@ pub struct Settlement {
 
 impl Settlement {
     /// Create a new settlement event
-    pub fn new(settlement_type: SettlementType, timestamp: i64) -> Self {
+    pub fn new(settlement_type: SettlementType, timestamp: TimestampMs) -> Self {
         Self {
             settlement_type,
             timestamp,
@@ -82,7 +83,7 @@ impl Settlement {
     /// Create a settlement event for an instrument
     pub fn for_instrument(
         settlement_type: SettlementType,
-        timestamp: i64,
+        timestamp: TimestampMs,
         instrument_name: String,
     ) -> Self {
         Self {
@@ -141,7 +142,7 @@ impl Settlement {
 
 impl Default for Settlement {
     fn default() -> Self {
-        Self::new(SettlementType::default(), 0)
+        Self::new(SettlementType::default(), TimestampMs::EPOCH)
     }
 }
 
@@ -198,9 +199,12 @@ mod tests {
 
     #[test]
     fn test_settlement_creation() {
-        let settlement = Settlement::new(SettlementType::Settlement, 1640995200000);
+        let settlement = Settlement::new(
+            SettlementType::Settlement,
+            TimestampMs::from_millis(1640995200000),
+        );
         assert_eq!(settlement.settlement_type, SettlementType::Settlement);
-        assert_eq!(settlement.timestamp, 1640995200000);
+        assert_eq!(settlement.timestamp.as_millis(), 1640995200000);
         assert!(settlement.instrument_name.is_none());
     }
 
@@ -208,7 +212,7 @@ mod tests {
     fn test_settlement_builder() {
         let settlement = Settlement::for_instrument(
             SettlementType::Delivery,
-            1640995200000,
+            TimestampMs::from_millis(1640995200000),
             "BTC-25MAR23".to_string(),
         )
         .with_position(1.5, 45000.0, 44950.0)
@@ -222,7 +226,7 @@ mod tests {
 
     #[test]
     fn test_settlement_type_checks() {
-        let settlement = Settlement::new(SettlementType::Settlement, 0);
+        let settlement = Settlement::new(SettlementType::Settlement, TimestampMs::EPOCH);
         assert!(settlement.is_settlement());
         assert!(!settlement.is_delivery());
         assert!(!settlement.is_bankruptcy());
@@ -231,8 +235,14 @@ mod tests {
     #[test]
     fn test_settlements_collection() {
         let mut settlements = Settlements::new();
-        settlements.add(Settlement::new(SettlementType::Settlement, 1000));
-        settlements.add(Settlement::new(SettlementType::Delivery, 2000));
+        settlements.add(Settlement::new(
+            SettlementType::Settlement,
+            TimestampMs::from_millis(1000),
+        ));
+        settlements.add(Settlement::new(
+            SettlementType::Delivery,
+            TimestampMs::from_millis(2000),
+        ));
 
         assert_eq!(settlements.settlements.len(), 2);
         assert_eq!(settlements.by_type(SettlementType::Settlement).len(), 1);
@@ -243,7 +253,7 @@ mod tests {
     fn test_serde() {
         let settlement = Settlement::for_instrument(
             SettlementType::Settlement,
-            1640995200000,
+            TimestampMs::from_millis(1640995200000),
             "BTC-PERPETUAL".to_string(),
         )
         .with_funding(0.0001);
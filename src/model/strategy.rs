@@ -0,0 +1,443 @@
+/******************************************************************************
+   Author: Joaquín Béjar García
+   Email: jb@taunais.com
+   Date: 15/10/26
+******************************************************************************/
+//! Multi-leg option strategy models
+//!
+//! [`OptionStrategy`] describes a standard option structure by currency,
+//! expiry and strike. [`OptionStrategy::resolve`] turns it into concrete
+//! [`StrategyLeg`]s against the option chain returned by `get_instruments`,
+//! checking that every leg exists and that strikes and expiries are consistent.
+
+use crate::error::{HttpError, HttpErrorKind};
+use crate::model::combo::ComboTrade;
+use crate::model::instrument::{Instrument, OptionType};
+use crate::model::order::OrderSide;
+use pretty_simple_display::{DebugPretty, DisplaySimple};
+use serde::{Deserialize, Serialize};
+
+/// Standard multi-leg option structure
+///
+/// Expiries use Deribit's `DDMMMYY` format (e.g. "27DEC24"). Amounts are in
+/// contracts and apply to every leg.
+#[derive(DebugPretty, DisplaySimple, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "strategy", rename_all = "snake_case")]
+pub enum OptionStrategy {
+    /// Buy one strike and sell another of the same type and expiry
+    VerticalSpread {
+        /// Underlying currency (e.g. "BTC")
+        currency: String,
+        /// Expiry in `DDMMMYY` format
+        expiry: String,
+        /// Call or put
+        option_type: OptionType,
+        /// Strike of the bought leg
+        long_strike: f64,
+        /// Strike of the sold leg
+        short_strike: f64,
+        /// Contracts per leg
+        amount: f64,
+    },
+    /// Call and put at the same strike and expiry, both bought or both sold
+    Straddle {
+        /// Underlying currency (e.g. "BTC")
+        currency: String,
+        /// Expiry in `DDMMMYY` format
+        expiry: String,
+        /// Strike of both legs
+        strike: f64,
+        /// Buy or sell both legs
+        side: OrderSide,
+        /// Contracts per leg
+        amount: f64,
+    },
+    /// Out-of-the-money put and call at the same expiry, both bought or both sold
+    Strangle {
+        /// Underlying currency (e.g. "BTC")
+        currency: String,
+        /// Expiry in `DDMMMYY` format
+        expiry: String,
+        /// Strike of the put leg, below the call strike
+        put_strike: f64,
+        /// Strike of the call leg
+        call_strike: f64,
+        /// Buy or sell both legs
+        side: OrderSide,
+        /// Contracts per leg
+        amount: f64,
+    },
+    /// Sell the near expiry and buy the far expiry at the same strike
+    Calendar {
+        /// Underlying currency (e.g. "BTC")
+        currency: String,
+        /// Call or put
+        option_type: OptionType,
+        /// Strike of both legs
+        strike: f64,
+        /// Expiry of the sold leg, in `DDMMMYY` format
+        near_expiry: String,
+        /// Expiry of the bought leg, in `DDMMMYY` format
+        far_expiry: String,
+        /// Contracts per leg
+        amount: f64,
+    },
+}
+
+/// A resolved leg of an [`OptionStrategy`]
+#[derive(DebugPretty, DisplaySimple, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StrategyLeg {
+    /// Option instrument name
+    pub instrument_name: String,
+    /// Buy or sell
+    pub side: OrderSide,
+    /// Contracts
+    pub amount: f64,
+}
+
+impl StrategyLeg {
+    /// The leg as a `create_combo` trade
+    pub fn to_combo_trade(&self) -> ComboTrade {
+        match self.side {
+            OrderSide::Buy => ComboTrade::buy(&self.instrument_name, Some(self.amount)),
+            OrderSide::Sell => ComboTrade::sell(&self.instrument_name, Some(self.amount)),
+        }
+    }
+}
+
+impl OptionStrategy {
+    /// Bull call spread: buy the lower strike call, sell the higher
+    pub fn bull_call_spread(
+        currency: &str,
+        expiry: &str,
+        lower_strike: f64,
+        upper_strike: f64,
+        amount: f64,
+    ) -> Self {
+        OptionStrategy::VerticalSpread {
+            currency: currency.to_string(),
+            expiry: expiry.to_string(),
+            option_type: OptionType::Call,
+            long_strike: lower_strike,
+            short_strike: upper_strike,
+            amount,
+        }
+    }
+
+    /// Bear put spread: buy the higher strike put, sell the lower
+    pub fn bear_put_spread(
+        currency: &str,
+        expiry: &str,
+        upper_strike: f64,
+        lower_strike: f64,
+        amount: f64,
+    ) -> Self {
+        OptionStrategy::VerticalSpread {
+            currency: currency.to_string(),
+            expiry: expiry.to_string(),
+            option_type: OptionType::Put,
+            long_strike: upper_strike,
+            short_strike: lower_strike,
+            amount,
+        }
+    }
+
+    /// Long straddle at `strike`
+    pub fn long_straddle(currency: &str, expiry: &str, strike: f64, amount: f64) -> Self {
+        OptionStrategy::Straddle {
+            currency: currency.to_string(),
+            expiry: expiry.to_string(),
+            strike,
+            side: OrderSide::Buy,
+            amount,
+        }
+    }
+
+    /// Long strangle between `put_strike` and `call_strike`
+    pub fn long_strangle(
+        currency: &str,
+        expiry: &str,
+        put_strike: f64,
+        call_strike: f64,
+        amount: f64,
+    ) -> Self {
+        OptionStrategy::Strangle {
+            currency: currency.to_string(),
+            expiry: expiry.to_string(),
+            put_strike,
+            call_strike,
+            side: OrderSide::Buy,
+            amount,
+        }
+    }
+
+    /// Long calendar: sell `near_expiry`, buy `far_expiry`
+    pub fn calendar(
+        currency: &str,
+        option_type: OptionType,
+        strike: f64,
+        near_expiry: &str,
+        far_expiry: &str,
+        amount: f64,
+    ) -> Self {
+        OptionStrategy::Calendar {
+            currency: currency.to_string(),
+            option_type,
+            strike,
+            near_expiry: near_expiry.to_string(),
+            far_expiry: far_expiry.to_string(),
+            amount,
+        }
+    }
+
+    /// The same structure with every leg's side reversed
+    ///
+    /// Turns a long straddle into a short one, a bull call spread into a bear
+    /// call spread, and so on.
+    pub fn reversed(self) -> Self {
+        match self {
+            OptionStrategy::VerticalSpread {
+                currency,
+                expiry,
+                option_type,
+                long_strike,
+                short_strike,
+                amount,
+            } => OptionStrategy::VerticalSpread {
+                currency,
+                expiry,
+                option_type,
+                long_strike: short_strike,
+                short_strike: long_strike,
+                amount,
+            },
+            OptionStrategy::Straddle {
+                currency,
+                expiry,
+                strike,
+                side,
+                amount,
+            } => OptionStrategy::Straddle {
+                currency,
+                expiry,
+                strike,
+                side: opposite(side),
+                amount,
+            },
+            OptionStrategy::Strangle {
+                currency,
+                expiry,
+                put_strike,
+                call_strike,
+                side,
+                amount,
+            } => OptionStrategy::Strangle {
+                currency,
+                expiry,
+                put_strike,
+                call_strike,
+                side: opposite(side),
+                amount,
+            },
+            OptionStrategy::Calendar {
+                currency,
+                option_type,
+                strike,
+                near_expiry,
+                far_expiry,
+                amount,
+            } => OptionStrategy::Calendar {
+                currency,
+                option_type,
+                strike,
+                near_expiry: far_expiry,
+                far_expiry: near_expiry,
+                amount,
+            },
+        }
+    }
+
+    /// Underlying currency of every leg
+    pub fn currency(&self) -> &str {
+        match self {
+            OptionStrategy::VerticalSpread { currency, .. }
+            | OptionStrategy::Straddle { currency, .. }
+            | OptionStrategy::Strangle { currency, .. }
+            | OptionStrategy::Calendar { currency, .. } => currency,
+        }
+    }
+
+    /// Contracts per leg
+    pub fn amount(&self) -> f64 {
+        match self {
+            OptionStrategy::VerticalSpread { amount, .. }
+            | OptionStrategy::Straddle { amount, .. }
+            | OptionStrategy::Strangle { amount, .. }
+            | OptionStrategy::Calendar { amount, .. } => *amount,
+        }
+    }
+
+    /// Resolve the legs against an option chain
+    ///
+    /// `instruments` is typically the result of
+    /// `get_instruments(currency, Some(InstrumentKind::Option), Some(false))`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `HttpErrorKind::ConfigError` if the amount is not positive or below
+    /// a leg's minimum trade amount, if strikes or expiries are inconsistent
+    /// with the structure, or if a leg is missing from the chain.
+    pub fn resolve(&self, instruments: &[Instrument]) -> Result<Vec<StrategyLeg>, HttpError> {
+        let amount = self.amount();
+        if !amount.is_finite() || amount <= 0.0 {
+            return Err(invalid(format!("amount must be positive, got {}", amount)));
+        }
+
+        let currency = self.currency();
+        let legs = match self {
+            OptionStrategy::VerticalSpread {
+                expiry,
+                option_type,
+                long_strike,
+                short_strike,
+                ..
+            } => {
+                if long_strike == short_strike {
+                    return Err(invalid(format!(
+                        "vertical spread legs share strike {}",
+                        long_strike
+                    )));
+                }
+                vec![
+                    (
+                        find(instruments, currency, expiry, *long_strike, option_type)?,
+                        OrderSide::Buy,
+                    ),
+                    (
+                        find(instruments, currency, expiry, *short_strike, option_type)?,
+                        OrderSide::Sell,
+                    ),
+                ]
+            }
+            OptionStrategy::Straddle {
+                expiry,
+                strike,
+                side,
+                ..
+            } => vec![
+                (
+                    find(instruments, currency, expiry, *strike, &OptionType::Call)?,
+                    *side,
+                ),
+                (
+                    find(instruments, currency, expiry, *strike, &OptionType::Put)?,
+                    *side,
+                ),
+            ],
+            OptionStrategy::Strangle {
+                expiry,
+                put_strike,
+                call_strike,
+                side,
+                ..
+            } => {
+                if put_strike >= call_strike {
+                    return Err(invalid(format!(
+                        "strangle put strike {} must be below call strike {}",
+                        put_strike, call_strike
+                    )));
+                }
+                vec![
+                    (
+                        find(
+                            instruments,
+                            currency,
+                            expiry,
+                            *call_strike,
+                            &OptionType::Call,
+                        )?,
+                        *side,
+                    ),
+                    (
+                        find(instruments, currency, expiry, *put_strike, &OptionType::Put)?,
+                        *side,
+                    ),
+                ]
+            }
+            OptionStrategy::Calendar {
+                option_type,
+                strike,
+                near_expiry,
+                far_expiry,
+                ..
+            } => {
+                let near = find(instruments, currency, near_expiry, *strike, option_type)?;
+                let far = find(instruments, currency, far_expiry, *strike, option_type)?;
+                if near.expiration_timestamp == far.expiration_timestamp {
+                    return Err(invalid(format!(
+                        "calendar legs share expiry {}",
+                        near_expiry
+                    )));
+                }
+                vec![(near, OrderSide::Sell), (far, OrderSide::Buy)]
+            }
+        };
+
+        legs.into_iter()
+            .map(|(instrument, side)| {
+                if let Some(min) = instrument.min_trade_amount
+                    && amount < min
+                {
+                    return Err(invalid(format!(
+                        "amount {} is below the minimum {} for {}",
+                        amount, min, instrument.instrument_name
+                    )));
+                }
+                Ok(StrategyLeg {
+                    instrument_name: instrument.instrument_name.clone(),
+                    side,
+                    amount,
+                })
+            })
+            .collect()
+    }
+}
+
+fn opposite(side: OrderSide) -> OrderSide {
+    match side {
+        OrderSide::Buy => OrderSide::Sell,
+        OrderSide::Sell => OrderSide::Buy,
+    }
+}
+
+fn invalid(reason: String) -> HttpError {
+    HttpError::from(HttpErrorKind::ConfigError(format!(
+        "Invalid option strategy: {}",
+        reason
+    )))
+}
+
+/// Find the option with the given expiry, strike and type in the chain
+fn find<'a>(
+    instruments: &'a [Instrument],
+    currency: &str,
+    expiry: &str,
+    strike: f64,
+    option_type: &OptionType,
+) -> Result<&'a Instrument, HttpError> {
+    let prefix = format!("{}-{}-", currency, expiry).to_uppercase();
+    instruments
+        .iter()
+        .find(|instrument| {
+            instrument.instrument_name.starts_with(&prefix)
+                && instrument.strike == Some(strike)
+                && instrument.option_type.as_ref() == Some(option_type)
+        })
+        .ok_or_else(|| {
+            let suffix = match option_type {
+                OptionType::Call => "C",
+                OptionType::Put => "P",
+            };
+            invalid(format!("no option {}{}-{} listed", prefix, strike, suffix))
+        })
+}
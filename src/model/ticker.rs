@@ -5,6 +5,7 @@
 ******************************************************************************/
 use crate::model::instrument::InstrumentKind;
 use crate::model::other::Greeks;
+use crate::model::timestamp::TimestampMs;
 use pretty_simple_display::{DebugPretty, DisplaySimple};
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
@@ -64,7 +65,7 @@ pub struct TickerData {
     /// Mark implied volatility
     pub mark_iv: Option<f64>,
     /// Timestamp of the ticker data
-    pub timestamp: u64,
+    pub timestamp: TimestampMs,
     /// Current state of the instrument
     pub state: String,
     /// Settlement price (for expired instruments)
@@ -96,7 +97,7 @@ pub struct Ticker {
     /// Instrument name
     pub instrument_name: String,
     /// Timestamp of the ticker data
-    pub timestamp: i64,
+    pub timestamp: TimestampMs,
     /// Best bid price
     pub best_bid_price: Option<f64>,
     /// Best bid amount
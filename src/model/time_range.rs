@@ -0,0 +1,116 @@
+/******************************************************************************
+   Author: Joaquín Béjar García
+   Email: jb@taunais.com
+   Date: 15/10/26
+******************************************************************************/
+use crate::error::{HttpError, HttpErrorKind};
+use crate::model::timestamp::TimestampMs;
+use chrono::{DateTime, TimeZone};
+use pretty_simple_display::{DebugPretty, DisplaySimple};
+use serde::{Deserialize, Serialize};
+use std::ops::{Range, RangeFrom, RangeToInclusive};
+
+/// Milliseconds in one hour
+const HOUR_MS: u64 = 3_600_000;
+
+/// Time window for time-bounded endpoints
+///
+/// Open-ended variants are resolved against the current time when the request
+/// is made.
+#[derive(DebugPretty, DisplaySimple, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TimeRange {
+    /// From the given timestamp until now
+    From(TimestampMs),
+    /// From the UNIX epoch until the given timestamp
+    Until(TimestampMs),
+    /// Between two timestamps, inclusive
+    Between(TimestampMs, TimestampMs),
+    /// The last given number of hours, ending now
+    LastHours(u32),
+}
+
+impl TimeRange {
+    /// Window between two points in time
+    pub fn between<Tz: TimeZone>(start: DateTime<Tz>, end: DateTime<Tz>) -> Self {
+        TimeRange::Between(start.into(), end.into())
+    }
+
+    /// Window from a point in time until now
+    pub fn since<Tz: TimeZone>(start: DateTime<Tz>) -> Self {
+        TimeRange::From(start.into())
+    }
+
+    /// Window from the UNIX epoch until a point in time
+    pub fn until<Tz: TimeZone>(end: DateTime<Tz>) -> Self {
+        TimeRange::Until(end.into())
+    }
+
+    /// Window covering the last `hours` hours
+    pub fn last_hours(hours: u32) -> Self {
+        TimeRange::LastHours(hours)
+    }
+
+    /// Start and end timestamps, resolved against `now`
+    pub fn bounds_at(&self, now: TimestampMs) -> (TimestampMs, TimestampMs) {
+        match *self {
+            TimeRange::From(start) => (start, now),
+            TimeRange::Until(end) => (TimestampMs::EPOCH, end),
+            TimeRange::Between(start, end) => (start, end),
+            TimeRange::LastHours(hours) => (
+                TimestampMs(
+                    now.as_millis()
+                        .saturating_sub(u64::from(hours).saturating_mul(HOUR_MS)),
+                ),
+                now,
+            ),
+        }
+    }
+
+    /// Start and end timestamps, resolved against the current time
+    pub fn bounds(&self) -> (TimestampMs, TimestampMs) {
+        self.bounds_at(TimestampMs::now())
+    }
+
+    /// Start and end timestamps, rejecting inverted windows
+    ///
+    /// # Errors
+    ///
+    /// Returns `HttpErrorKind::ConfigError` if the start is after the end.
+    pub fn resolve(&self) -> Result<(TimestampMs, TimestampMs), HttpError> {
+        let (start, end) = self.bounds();
+        if start > end {
+            return Err(HttpErrorKind::ConfigError(format!(
+                "Invalid time range: start {} is after end {}",
+                start, end
+            ))
+            .into());
+        }
+        Ok((start, end))
+    }
+}
+
+impl From<(TimestampMs, TimestampMs)> for TimeRange {
+    fn from((start, end): (TimestampMs, TimestampMs)) -> Self {
+        TimeRange::Between(start, end)
+    }
+}
+
+impl<Tz: TimeZone> From<Range<DateTime<Tz>>> for TimeRange {
+    fn from(range: Range<DateTime<Tz>>) -> Self {
+        let end = TimestampMs::from(range.end).as_millis().saturating_sub(1);
+        TimeRange::Between(range.start.into(), TimestampMs(end))
+    }
+}
+
+impl<Tz: TimeZone> From<RangeFrom<DateTime<Tz>>> for TimeRange {
+    fn from(range: RangeFrom<DateTime<Tz>>) -> Self {
+        TimeRange::since(range.start)
+    }
+}
+
+impl<Tz: TimeZone> From<RangeToInclusive<DateTime<Tz>>> for TimeRange {
+    fn from(range: RangeToInclusive<DateTime<Tz>>) -> Self {
+        TimeRange::until(range.end)
+    }
+}
@@ -0,0 +1,184 @@
+/******************************************************************************
+   Author: Joaquín Béjar García
+   Email: jb@taunais.com
+   Date: 15/10/26
+******************************************************************************/
+use crate::time_compat::{SystemTime, UNIX_EPOCH};
+use chrono::{DateTime, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::time::Duration;
+
+/// Timestamp in milliseconds since the UNIX epoch
+///
+/// Deribit expresses every timestamp in milliseconds. Wrapping them in a distinct
+/// type keeps seconds from being passed where milliseconds are expected; use
+/// [`TimestampMs::from_secs`] or the `SystemTime`/chrono conversions instead of
+/// manual `as_millis()` arithmetic. Serializes as a bare integer.
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+#[serde(transparent)]
+pub struct TimestampMs(pub u64);
+
+impl TimestampMs {
+    /// The UNIX epoch
+    pub const EPOCH: TimestampMs = TimestampMs(0);
+
+    /// Current time
+    pub fn now() -> Self {
+        Self::from_system_time(SystemTime::now())
+    }
+
+    /// From milliseconds since the UNIX epoch
+    pub const fn from_millis(millis: u64) -> Self {
+        TimestampMs(millis)
+    }
+
+    /// From seconds since the UNIX epoch
+    pub const fn from_secs(secs: u64) -> Self {
+        TimestampMs(secs.saturating_mul(1000))
+    }
+
+    /// From a `SystemTime`, clamped to the epoch for earlier instants
+    pub fn from_system_time(time: SystemTime) -> Self {
+        time.duration_since(UNIX_EPOCH)
+            .map(|elapsed| TimestampMs(elapsed.as_millis() as u64))
+            .unwrap_or(Self::EPOCH)
+    }
+
+    /// Milliseconds since the UNIX epoch
+    pub const fn as_millis(self) -> u64 {
+        self.0
+    }
+
+    /// Whole seconds since the UNIX epoch
+    pub const fn as_secs(self) -> u64 {
+        self.0 / 1000
+    }
+
+    /// As a UTC date-time, if representable
+    pub fn to_datetime(self) -> Option<DateTime<Utc>> {
+        i64::try_from(self.0)
+            .ok()
+            .and_then(DateTime::from_timestamp_millis)
+    }
+
+    /// This timestamp moved forward by `duration`, saturating
+    pub fn saturating_add(self, duration: Duration) -> Self {
+        TimestampMs(self.0.saturating_add(duration.as_millis() as u64))
+    }
+
+    /// This timestamp moved back by `duration`, saturating at the epoch
+    pub fn saturating_sub(self, duration: Duration) -> Self {
+        TimestampMs(self.0.saturating_sub(duration.as_millis() as u64))
+    }
+}
+
+impl fmt::Display for TimestampMs {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<TimestampMs> for u64 {
+    fn from(timestamp: TimestampMs) -> Self {
+        timestamp.0
+    }
+}
+
+impl From<SystemTime> for TimestampMs {
+    fn from(time: SystemTime) -> Self {
+        Self::from_system_time(time)
+    }
+}
+
+impl<Tz: TimeZone> From<DateTime<Tz>> for TimestampMs {
+    fn from(datetime: DateTime<Tz>) -> Self {
+        TimestampMs(u64::try_from(datetime.timestamp_millis()).unwrap_or(0))
+    }
+}
+
+impl TryFrom<TimestampMs> for DateTime<Utc> {
+    type Error = TimestampMs;
+
+    /// Fails with the original timestamp when it is out of chrono's range
+    fn try_from(timestamp: TimestampMs) -> Result<Self, Self::Error> {
+        timestamp.to_datetime().ok_or(timestamp)
+    }
+}
+
+/// Serde adapter for `DateTime<Utc>` fields carried as epoch milliseconds
+///
+/// Use with `#[serde(with = "deribit_http::model::timestamp::datetime_millis")]`
+/// to deserialize Deribit timestamps straight into chrono date-times; the
+/// [`option`](datetime_millis::option) submodule handles `Option<DateTime<Utc>>`.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::{DateTime, Utc};
+/// use serde::Deserialize;
+///
+/// #[derive(Deserialize)]
+/// struct Fill {
+///     #[serde(with = "deribit_http::model::timestamp::datetime_millis")]
+///     timestamp: DateTime<Utc>,
+/// }
+///
+/// let fill: Fill = serde_json::from_str(r#"{"timestamp": 1700000000000}"#).unwrap();
+/// assert_eq!(fill.timestamp.timestamp_millis(), 1_700_000_000_000);
+/// ```
+pub mod datetime_millis {
+    use super::TimestampMs;
+    use chrono::{DateTime, Utc};
+    use serde::de::Error;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    /// Serializes a date-time as epoch milliseconds
+    pub fn serialize<S: Serializer>(
+        datetime: &DateTime<Utc>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        TimestampMs::from(*datetime).serialize(serializer)
+    }
+
+    /// Deserializes epoch milliseconds into a date-time
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<DateTime<Utc>, D::Error> {
+        let timestamp = TimestampMs::deserialize(deserializer)?;
+        timestamp
+            .to_datetime()
+            .ok_or_else(|| D::Error::custom(format!("timestamp {timestamp} out of range")))
+    }
+
+    /// Serde adapter for `Option<DateTime<Utc>>` fields carried as epoch milliseconds
+    pub mod option {
+        use super::super::TimestampMs;
+        use chrono::{DateTime, Utc};
+        use serde::de::Error;
+        use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+        /// Serializes an optional date-time as epoch milliseconds
+        pub fn serialize<S: Serializer>(
+            datetime: &Option<DateTime<Utc>>,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            datetime.map(TimestampMs::from).serialize(serializer)
+        }
+
+        /// Deserializes optional epoch milliseconds into a date-time
+        pub fn deserialize<'de, D: Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Option<DateTime<Utc>>, D::Error> {
+            Option::<TimestampMs>::deserialize(deserializer)?
+                .map(|timestamp| {
+                    timestamp.to_datetime().ok_or_else(|| {
+                        D::Error::custom(format!("timestamp {timestamp} out of range"))
+                    })
+                })
+                .transpose()
+        }
+    }
+}
@@ -5,6 +5,7 @@
 ******************************************************************************/
 use crate::model::instrument::InstrumentKind;
 use crate::model::order::OrderSide;
+use crate::model::timestamp::TimestampMs;
 use pretty_simple_display::{DebugPretty, DisplaySimple};
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
@@ -50,7 +51,7 @@ pub struct TradeExecution {
     /// Price tick direction (1=up, -1=down, 0=no change)
     pub tick_direction: i32,
     /// Execution timestamp
-    pub timestamp: u64,
+    pub timestamp: TimestampMs,
     /// Unique trade identifier
     pub trade_id: String,
     /// Trade sequence number
@@ -114,7 +115,7 @@ pub struct UserTrade {
     /// Price tick direction (1=up, -1=down, 0=no change)
     pub tick_direction: i32,
     /// Execution timestamp (milliseconds since UNIX epoch)
-    pub timestamp: u64,
+    pub timestamp: TimestampMs,
     /// Unique trade identifier
     pub trade_id: String,
     /// Trade sequence number
@@ -146,7 +147,7 @@ pub struct LastTrade {
     /// Price tick direction (1=up, -1=down, 0=no change)
     pub tick_direction: i32,
     /// Execution timestamp
-    pub timestamp: u64,
+    pub timestamp: TimestampMs,
     /// Unique trade identifier
     pub trade_id: String,
     /// Trade sequence number
@@ -184,7 +185,7 @@ pub struct Trade {
     /// Execution price
     pub price: f64,
     /// Trade timestamp
-    pub timestamp: i64,
+    pub timestamp: TimestampMs,
     /// Fee amount
     pub fee: f64,
     /// Fee currency
@@ -3,16 +3,155 @@
    Email: jb@taunais.com
    Date: 15/9/25
 ******************************************************************************/
+use crate::error::{HttpError, HttpErrorKind};
+use crate::model::timestamp::TimestampMs;
 use pretty_simple_display::{DebugPretty, DisplaySimple};
 use serde::{Deserialize, Serialize};
 
+/// Candle resolution of `get_tradingview_chart_data`, in minutes or one day
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Resolution {
+    /// 1 minute
+    #[serde(rename = "1")]
+    Minute1,
+    /// 3 minutes
+    #[serde(rename = "3")]
+    Minute3,
+    /// 5 minutes
+    #[serde(rename = "5")]
+    Minute5,
+    /// 10 minutes
+    #[serde(rename = "10")]
+    Minute10,
+    /// 15 minutes
+    #[serde(rename = "15")]
+    Minute15,
+    /// 30 minutes
+    #[serde(rename = "30")]
+    Minute30,
+    /// 1 hour
+    #[serde(rename = "60")]
+    Hour1,
+    /// 2 hours
+    #[serde(rename = "120")]
+    Hour2,
+    /// 3 hours
+    #[serde(rename = "180")]
+    Hour3,
+    /// 6 hours
+    #[serde(rename = "360")]
+    Hour6,
+    /// 12 hours
+    #[serde(rename = "720")]
+    Hour12,
+    /// 1 day
+    #[serde(rename = "1D")]
+    Day1,
+}
+
+impl Resolution {
+    /// Returns the string representation of the resolution
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Resolution::Minute1 => "1",
+            Resolution::Minute3 => "3",
+            Resolution::Minute5 => "5",
+            Resolution::Minute10 => "10",
+            Resolution::Minute15 => "15",
+            Resolution::Minute30 => "30",
+            Resolution::Hour1 => "60",
+            Resolution::Hour2 => "120",
+            Resolution::Hour3 => "180",
+            Resolution::Hour6 => "360",
+            Resolution::Hour12 => "720",
+            Resolution::Day1 => "1D",
+        }
+    }
+}
+
+impl Resolution {
+    /// Length of one candle
+    pub fn duration(&self) -> std::time::Duration {
+        let minutes = match self {
+            Resolution::Minute1 => 1,
+            Resolution::Minute3 => 3,
+            Resolution::Minute5 => 5,
+            Resolution::Minute10 => 10,
+            Resolution::Minute15 => 15,
+            Resolution::Minute30 => 30,
+            Resolution::Hour1 => 60,
+            Resolution::Hour2 => 120,
+            Resolution::Hour3 => 180,
+            Resolution::Hour6 => 360,
+            Resolution::Hour12 => 720,
+            Resolution::Day1 => 1440,
+        };
+        std::time::Duration::from_secs(minutes * 60)
+    }
+}
+
+impl std::str::FromStr for Resolution {
+    type Err = HttpError;
+
+    /// Parse the API form, e.g. `60` or `1D`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        [
+            Resolution::Minute1,
+            Resolution::Minute3,
+            Resolution::Minute5,
+            Resolution::Minute10,
+            Resolution::Minute15,
+            Resolution::Minute30,
+            Resolution::Hour1,
+            Resolution::Hour2,
+            Resolution::Hour3,
+            Resolution::Hour6,
+            Resolution::Hour12,
+            Resolution::Day1,
+        ]
+        .into_iter()
+        .find(|resolution| resolution.as_str().eq_ignore_ascii_case(s))
+        .ok_or_else(|| {
+            HttpError::from(HttpErrorKind::ParseError(format!(
+                "Invalid resolution: {}",
+                s
+            )))
+        })
+    }
+}
+
+impl std::fmt::Display for Resolution {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// One OHLCV candle of [`TradingViewChartData`]
+#[derive(DebugPretty, DisplaySimple, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Candle {
+    /// Open time of the candle
+    pub timestamp: TimestampMs,
+    /// Open price
+    pub open: f64,
+    /// Highest price
+    pub high: f64,
+    /// Lowest price
+    pub low: f64,
+    /// Close price
+    pub close: f64,
+    /// Traded volume, in base currency
+    pub volume: f64,
+    /// Traded volume, in quote currency
+    pub cost: f64,
+}
+
 /// TradingView chart data structure
 #[derive(DebugPretty, DisplaySimple, Clone, Serialize, Deserialize)]
 pub struct TradingViewChartData {
     /// Status of the data
     pub status: String,
     /// Array of timestamps
-    pub ticks: Vec<u64>,
+    pub ticks: Vec<TimestampMs>,
     /// Array of open prices
     pub open: Vec<f64>,
     /// Array of high prices
@@ -46,7 +185,7 @@ impl TradingViewChartData {
     #[allow(clippy::too_many_arguments)]
     pub fn add_candle(
         &mut self,
-        timestamp: u64,
+        timestamp: TimestampMs,
         open: f64,
         high: f64,
         low: f64,
@@ -64,6 +203,115 @@ impl TradingViewChartData {
     }
 }
 
+impl TradingViewChartData {
+    /// Number of candles
+    pub fn len(&self) -> usize {
+        self.ticks.len()
+    }
+
+    /// Whether there are no candles
+    pub fn is_empty(&self) -> bool {
+        self.ticks.is_empty()
+    }
+
+    /// Append the candles of `other` that are later than the last candle
+    ///
+    /// Used to stitch consecutive windows whose boundary candle is returned
+    /// by both requests.
+    pub fn append_after_last(&mut self, other: &TradingViewChartData) {
+        let last = self.ticks.last().copied();
+        for candle in other.zipped() {
+            if last.is_some_and(|last| candle.timestamp <= last) {
+                continue;
+            }
+            self.push(candle);
+        }
+    }
+
+    /// Candles as rows
+    ///
+    /// # Errors
+    ///
+    /// Returns `HttpErrorKind::InvalidResponse` if the arrays differ in length.
+    pub fn candles(&self) -> Result<Vec<Candle>, HttpError> {
+        self.validate()?;
+        Ok(self.zipped().collect())
+    }
+
+    /// Check that every array has one value per timestamp
+    ///
+    /// # Errors
+    ///
+    /// Returns `HttpErrorKind::InvalidResponse` naming the first array whose
+    /// length differs from `ticks`.
+    pub fn validate(&self) -> Result<(), HttpError> {
+        let columns = [
+            ("open", self.open.len()),
+            ("high", self.high.len()),
+            ("low", self.low.len()),
+            ("close", self.close.len()),
+            ("volume", self.volume.len()),
+            ("cost", self.cost.len()),
+        ];
+        match columns.iter().find(|(_, len)| *len != self.ticks.len()) {
+            Some((name, len)) => Err(HttpErrorKind::InvalidResponse(format!(
+                "Chart data has {} ticks but {} {} values",
+                self.ticks.len(),
+                len,
+                name
+            ))
+            .into()),
+            None => Ok(()),
+        }
+    }
+
+    /// Append `candle` as the last candle
+    pub fn push(&mut self, candle: Candle) {
+        self.add_candle(
+            candle.timestamp,
+            candle.open,
+            candle.high,
+            candle.low,
+            candle.close,
+            candle.volume,
+            candle.cost,
+        );
+    }
+
+    /// Candles up to the shortest array
+    fn zipped(&self) -> impl Iterator<Item = Candle> + '_ {
+        self.ticks
+            .iter()
+            .zip(&self.open)
+            .zip(&self.high)
+            .zip(&self.low)
+            .zip(&self.close)
+            .zip(&self.volume)
+            .zip(&self.cost)
+            .map(
+                |((((((tick, open), high), low), close), volume), cost)| Candle {
+                    timestamp: *tick,
+                    open: *open,
+                    high: *high,
+                    low: *low,
+                    close: *close,
+                    volume: *volume,
+                    cost: *cost,
+                },
+            )
+    }
+}
+
+impl FromIterator<Candle> for TradingViewChartData {
+    fn from_iter<I: IntoIterator<Item = Candle>>(candles: I) -> Self {
+        let mut chart_data = Self::new();
+        for candle in candles {
+            chart_data.push(candle);
+        }
+        chart_data
+    }
+}
+
 impl Default for TradingViewChartData {
     fn default() -> Self {
         Self::new()
@@ -3,6 +3,8 @@
    Email: jb@taunais.com
    Date: 15/9/25
 ******************************************************************************/
+use crate::model::time_range::TimeRange;
+use crate::model::timestamp::TimestampMs;
 use pretty_simple_display::{DebugPretty, DisplaySimple};
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
@@ -47,7 +49,7 @@ pub struct TransactionLogEntry {
     /// Cash balance after the transaction
     pub balance: f64,
     /// The timestamp (milliseconds since the Unix epoch)
-    pub timestamp: u64,
+    pub timestamp: TimestampMs,
     /// Transaction category/type. Common types: trade, deposit, withdrawal, settlement, delivery, transfer, swap, correction
     #[serde(rename = "type")]
     pub transaction_type: String,
@@ -155,9 +157,9 @@ pub struct TransactionLogRequest {
     /// Currency code (e.g., "BTC", "ETH", "USDC")
     pub currency: String,
     /// Start timestamp in milliseconds since Unix epoch
-    pub start_timestamp: u64,
+    pub start_timestamp: TimestampMs,
     /// End timestamp in milliseconds since Unix epoch
-    pub end_timestamp: u64,
+    pub end_timestamp: TimestampMs,
     /// Optional search query string
     pub query: Option<String>,
     /// Maximum number of entries to return
@@ -167,3 +169,16 @@ pub struct TransactionLogRequest {
     /// Continuation token for pagination
     pub continuation: Option<u64>,
 }
+
+impl TransactionLogRequest {
+    /// Create a request for a currency over a time window
+    pub fn new(currency: impl Into<String>, range: impl Into<TimeRange>) -> Self {
+        let (start_timestamp, end_timestamp) = range.into().bounds();
+        Self {
+            currency: currency.into(),
+            start_timestamp,
+            end_timestamp,
+            ..Default::default()
+        }
+    }
+}
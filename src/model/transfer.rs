@@ -3,6 +3,7 @@
    Email: jb@taunais.com
    Date: 15/9/25
 ******************************************************************************/
+use crate::model::timestamp::TimestampMs;
 use pretty_simple_display::{DebugPretty, DisplaySimple};
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
@@ -46,11 +47,11 @@ pub struct Transfer {
     /// Current transfer state
     pub state: TransferState,
     /// Creation timestamp (milliseconds since Unix epoch)
-    pub created_timestamp: i64,
+    pub created_timestamp: TimestampMs,
     /// Last update timestamp (milliseconds since Unix epoch)
-    pub updated_timestamp: i64,
+    pub updated_timestamp: TimestampMs,
     /// Confirmation timestamp (milliseconds since Unix epoch)
-    pub confirmed_timestamp: Option<i64>,
+    pub confirmed_timestamp: Option<TimestampMs>,
     /// Transfer type description
     pub transfer_type: Option<String>,
 }
@@ -63,7 +64,7 @@ impl Transfer {
         amount: f64,
         fee: f64,
         address: String,
-        created_timestamp: i64,
+        created_timestamp: TimestampMs,
     ) -> Self {
         Self {
             id,
@@ -99,14 +100,14 @@ impl Transfer {
     }
 
     /// Confirm the transfer
-    pub fn confirm(&mut self, timestamp: i64) {
+    pub fn confirm(&mut self, timestamp: TimestampMs) {
         self.state = TransferState::Confirmed;
         self.confirmed_timestamp = Some(timestamp);
         self.updated_timestamp = timestamp;
     }
 
     /// Cancel the transfer
-    pub fn cancel(&mut self, timestamp: i64) {
+    pub fn cancel(&mut self, timestamp: TimestampMs) {
         self.state = TransferState::Cancelled;
         self.updated_timestamp = timestamp;
     }
@@ -218,7 +219,7 @@ pub struct SubaccountTransfer {
     /// Transfer state
     pub state: TransferState,
     /// Transfer timestamp (milliseconds since Unix epoch)
-    pub timestamp: i64,
+    pub timestamp: TimestampMs,
     /// Type of transfer
     pub transfer_type: String,
 }
@@ -231,7 +232,7 @@ impl SubaccountTransfer {
         currency: String,
         source: i64,
         destination: i64,
-        timestamp: i64,
+        timestamp: TimestampMs,
     ) -> Self {
         Self {
             amount,
@@ -1,13 +1,16 @@
 //! HTTP-specific types and models
 
+use crate::logger::impl_redacted_display;
+use crate::model::timestamp::TimestampMs;
 use pretty_simple_display::{DebugPretty, DisplaySimple};
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 use std::collections::HashMap;
+use zeroize::Zeroize;
 
 /// API error structure
 #[skip_serializing_none]
-#[derive(DebugPretty, DisplaySimple, Clone, Serialize, Deserialize)]
+#[derive(DebugPretty, DisplaySimple, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ApiError {
     /// Error code number
     pub code: i32,
@@ -18,8 +21,11 @@ pub struct ApiError {
 }
 
 /// Authentication token structure
+///
+/// `Debug` and `Display` print the access and refresh tokens as
+/// `[REDACTED]`, and both are zeroed in memory when the token is dropped.
 #[skip_serializing_none]
-#[derive(DebugPretty, DisplaySimple, Clone, Serialize, Deserialize)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct AuthToken {
     /// OAuth2 access token
     pub access_token: String,
@@ -33,6 +39,21 @@ pub struct AuthToken {
     pub scope: String,
 }
 
+impl Zeroize for AuthToken {
+    fn zeroize(&mut self) {
+        self.access_token.zeroize();
+        self.refresh_token.zeroize();
+    }
+}
+
+impl Drop for AuthToken {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl_redacted_display!(AuthToken);
+
 /// Request parameters
 #[derive(DebugPretty, DisplaySimple, Clone, Default, Serialize, Deserialize)]
 pub struct RequestParams {
@@ -53,6 +74,14 @@ impl RequestParams {
         self
     }
 
+    /// Add a parameter if it is set
+    pub fn add_opt<T: Serialize>(self, key: &str, value: Option<T>) -> Self {
+        match value {
+            Some(value) => self.add(key, value),
+            None => self,
+        }
+    }
+
     /// Convert to JSON value
     pub fn to_json(&self) -> serde_json::Value {
         serde_json::to_value(&self.params).unwrap_or(serde_json::Value::Null)
@@ -112,9 +141,9 @@ pub struct Withdrawal {
     /// Current state of the withdrawal
     pub state: String,
     /// Timestamp when withdrawal was created
-    pub created_timestamp: u64,
+    pub created_timestamp: TimestampMs,
     /// Timestamp when withdrawal was last updated
-    pub updated_timestamp: Option<u64>,
+    pub updated_timestamp: Option<TimestampMs>,
     /// Transaction ID on the blockchain
     pub transaction_id: Option<String>,
 }
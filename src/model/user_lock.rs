@@ -2,6 +2,7 @@
 //!
 //! This module contains types for user account locks.
 
+use crate::model::timestamp::TimestampMs;
 use serde::{Deserialize, Serialize};
 
 /// User account lock information
@@ -18,10 +19,10 @@ pub struct UserLock {
     pub reason: Option<String>,
     /// Timestamp when the lock was applied in milliseconds
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub timestamp: Option<u64>,
+    pub timestamp: Option<TimestampMs>,
     /// When the lock expires in milliseconds, if applicable
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub expiration_timestamp: Option<u64>,
+    pub expiration_timestamp: Option<TimestampMs>,
     /// Whether the lock is currently active
     #[serde(skip_serializing_if = "Option::is_none")]
     pub locked: Option<bool>,
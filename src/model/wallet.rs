@@ -5,6 +5,7 @@
 ******************************************************************************/
 //! Wallet-related models for deposit addresses and address book operations.
 
+use crate::model::timestamp::TimestampMs;
 use pretty_simple_display::{DebugPretty, DisplaySimple};
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
@@ -107,7 +108,7 @@ pub struct DepositAddress {
     #[serde(rename = "type")]
     pub address_type: Option<String>,
     /// Timestamp when the address was created, in milliseconds since Unix epoch
-    pub creation_timestamp: Option<u64>,
+    pub creation_timestamp: Option<TimestampMs>,
     /// Status of the address
     pub status: Option<String>,
 }
@@ -143,9 +144,9 @@ pub struct AddressBookEntry {
     /// User-defined label for the address
     pub label: Option<String>,
     /// Timestamp when the entry was created, in milliseconds since Unix epoch
-    pub creation_timestamp: Option<u64>,
+    pub creation_timestamp: Option<TimestampMs>,
     /// Timestamp when the entry was last updated, in milliseconds since Unix epoch
-    pub update_timestamp: Option<u64>,
+    pub update_timestamp: Option<TimestampMs>,
     /// Whether the user agreed to share information with third parties
     pub agreed: Option<bool>,
     /// Whether the address belongs to the user (personal/un-hosted wallet)
@@ -267,7 +268,7 @@ mod tests {
             address: "0xtest123".to_string(),
             currency: "ETH".to_string(),
             address_type: Some("deposit".to_string()),
-            creation_timestamp: Some(1234567890000),
+            creation_timestamp: Some(TimestampMs(1234567890000)),
             status: Some("active".to_string()),
         };
 
@@ -293,7 +294,7 @@ mod tests {
             currency: "BTC".to_string(),
             entry_type: Some("withdrawal".to_string()),
             label: Some("Main wallet".to_string()),
-            creation_timestamp: Some(1234567890000),
+            creation_timestamp: Some(TimestampMs(1234567890000)),
             update_timestamp: None,
             agreed: Some(true),
             personal: Some(false),
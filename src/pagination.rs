@@ -0,0 +1,722 @@
+//! Unified pagination for list endpoints
+//!
+//! Deribit pages list results in three ways: an opaque `continuation` token,
+//! `count`/`offset` windows, and sequence bounds signalled by `has_more`.
+//! [`Paginated`] maps every list response onto a single [`Page`] shape with a
+//! [`Cursor`] to the next page, and [`paginate`]/[`collect_all`] drive any
+//! endpoint from that cursor until the results are exhausted.
+//!
+//! [`paginate`]: crate::pagination::paginate
+//! [`collect_all`]: crate::pagination::collect_all
+
+use crate::error::{HttpError, HttpErrorKind};
+use crate::model::access_log::{AccessLogEntry, AccessLogResponse};
+use crate::model::beneficiary::{AddressBeneficiary, ListAddressBeneficiariesResponse};
+use crate::model::block_trade::{BlockTrade, GetBlockTradesResponse};
+use crate::model::deposit::Deposit;
+use crate::model::other::{DeliveryPriceData, SortDirection};
+use crate::model::response::block_rfq::{BlockRfqPublicTrade, BlockRfqTradesResponse};
+use crate::model::response::deposit::DepositsResponse;
+use crate::model::response::other::{
+    AprDataPoint, AprHistoryResponse, DeliveryPricesResponse, LastTradesResponse,
+    SettlementsResponse, TransactionLogResponse, UserTradeWithPaginationResponse,
+    VolatilityIndexCandle, VolatilityIndexData,
+};
+use crate::model::response::transfer::{InternalTransfer, TransfersResponse};
+use crate::model::response::trigger::{
+    StopOrderHistoryEntry, StopOrderHistoryResponse, TriggerOrderHistoryEntry,
+    TriggerOrderHistoryResponse,
+};
+use crate::model::response::withdrawal::WithdrawalsResponse;
+use crate::model::settlement::Settlement;
+use crate::model::timestamp::TimestampMs;
+use crate::model::trade::{LastTrade, UserTrade};
+use crate::model::transaction::TransactionLogEntry;
+use crate::model::types::Withdrawal;
+use futures_util::stream::{self, Stream, TryStreamExt};
+use serde::{Deserialize, Deserializer, Serialize};
+use std::fmt;
+use std::ops::Deref;
+
+/// Opaque continuation token returned by a paginated endpoint
+///
+/// Deribit sends continuations as strings or, on some endpoints, integers;
+/// both deserialize into a token holding the textual form. `"none"` and the
+/// empty string mark the last page (see [`is_end`](Self::is_end)).
+/// Serializes as a string and dereferences to `&str`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize)]
+#[serde(transparent)]
+pub struct ContinuationToken(String);
+
+impl ContinuationToken {
+    /// Wrap a token returned by the API
+    pub fn new(token: impl Into<String>) -> Self {
+        Self(token.into())
+    }
+
+    /// The token as sent to and received from the API
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Whether the token marks the end of the listing
+    pub fn is_end(&self) -> bool {
+        self.0.is_empty() || self.0 == "none"
+    }
+
+    /// Consume the token, returning its textual form
+    pub fn into_string(self) -> String {
+        self.0
+    }
+}
+
+impl Deref for ContinuationToken {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AsRef<str> for ContinuationToken {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for ContinuationToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl From<String> for ContinuationToken {
+    fn from(token: String) -> Self {
+        Self(token)
+    }
+}
+
+impl From<&str> for ContinuationToken {
+    fn from(token: &str) -> Self {
+        Self(token.to_string())
+    }
+}
+
+impl From<u64> for ContinuationToken {
+    fn from(token: u64) -> Self {
+        Self(token.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for ContinuationToken {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Raw {
+            Text(String),
+            Number(u64),
+        }
+
+        Ok(match Raw::deserialize(deserializer)? {
+            Raw::Text(token) => Self(token),
+            Raw::Number(token) => Self::from(token),
+        })
+    }
+}
+
+/// Position of the next page in a paginated listing
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Cursor {
+    /// Opaque continuation token returned by the API
+    Continuation(ContinuationToken),
+    /// Record offset for `count`/`offset` endpoints
+    Offset(u32),
+    /// Sequence bound (epoch day) for the next request
+    Seq(u64),
+    /// Last trade returned by a `has_more` trade listing
+    Trade(TradeCursor),
+}
+
+impl Cursor {
+    /// Continuation token, if this is a continuation cursor
+    pub fn continuation(&self) -> Option<&str> {
+        match self {
+            Cursor::Continuation(token) => Some(token.as_str()),
+            _ => None,
+        }
+    }
+
+    /// Record offset, if this is an offset cursor
+    pub fn offset(&self) -> Option<u32> {
+        match self {
+            Cursor::Offset(offset) => Some(*offset),
+            _ => None,
+        }
+    }
+
+    /// Sequence bound, if this is a sequence cursor
+    pub fn seq(&self) -> Option<u64> {
+        match self {
+            Cursor::Seq(seq) => Some(*seq),
+            _ => None,
+        }
+    }
+
+    /// Boundary trade, if this is a trade cursor
+    pub fn trade(&self) -> Option<&TradeCursor> {
+        match self {
+            Cursor::Trade(trade) => Some(trade),
+            _ => None,
+        }
+    }
+}
+
+/// Boundary of the next page of a `has_more` trade listing
+///
+/// Holds the last trade of the page just read. Fetch the next page past it:
+/// from `trade_seq + 1` or up to `trade_seq - 1` on instrument endpoints,
+/// from or up to `trade_id` on currency endpoints, or from or up to
+/// `timestamp` on time-window endpoints. Trades listed in `returned` are
+/// dropped from the next page, so inclusive id and timestamp bounds do not
+/// yield a trade twice.
+///
+/// An inclusive bound cannot move past a page that only repeats trades
+/// already returned: a time window stuck on a millisecond holding more
+/// trades than a page, or an id bound with a `count` of 1. The id and
+/// time-window streams fail with `HttpErrorKind::ConfigError` rather than
+/// drop the trades that did not fit, and a larger `count` reads them.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct TradeCursor {
+    /// Whether pages run oldest to newest
+    pub ascending: bool,
+    /// Sequence number of the trade within its instrument
+    pub trade_seq: u64,
+    /// Id of the trade
+    pub trade_id: String,
+    /// Execution time of the trade
+    pub timestamp: TimestampMs,
+    /// Ids of the trades already returned at `timestamp`
+    pub returned: Vec<String>,
+}
+
+impl TradeCursor {
+    /// Whether to request the next page oldest to newest: as `sorting` when
+    /// explicit, otherwise as the pages seen so far
+    pub fn ascending_for(&self, sorting: Option<&SortDirection>) -> bool {
+        match sorting {
+            Some(SortDirection::Asc) => true,
+            Some(SortDirection::Desc) => false,
+            _ => self.ascending,
+        }
+    }
+}
+
+/// A single page of results and the cursor to the next one
+#[derive(Debug, Clone, PartialEq)]
+pub struct Page<T> {
+    /// Items on this page
+    pub items: Vec<T>,
+    /// Cursor to the next page, `None` when this is the last page
+    pub next: Option<Cursor>,
+}
+
+impl<T> Page<T> {
+    /// Whether another page follows this one
+    pub fn has_next(&self) -> bool {
+        self.next.is_some()
+    }
+
+    /// Continuation token of the next page, for continuation-paged endpoints
+    pub fn continuation(&self) -> Option<&ContinuationToken> {
+        match &self.next {
+            Some(Cursor::Continuation(token)) => Some(token),
+            _ => None,
+        }
+    }
+}
+
+/// A list response that can be split into items and a next-page cursor
+pub trait Paginated<T> {
+    /// Cursor to the next page, given the cursor that fetched this one
+    fn next_cursor(&self, current: Option<&Cursor>) -> Option<Cursor>;
+
+    /// Consume the response, returning its items
+    fn into_items(self) -> Vec<T>;
+
+    /// Consume the response, returning a [`Page`]
+    fn into_page(self, current: Option<&Cursor>) -> Page<T>
+    where
+        Self: Sized,
+    {
+        let next = self.next_cursor(current);
+        Page {
+            items: self.into_items(),
+            next,
+        }
+    }
+}
+
+/// Stream every item of a paginated listing
+///
+/// `fetch` is called with `start` first and then with each page's next cursor.
+/// The stream ends when a page has no next cursor, is empty, or returns the
+/// cursor it was fetched with.
+pub fn paginate<'a, T, R, F, Fut>(
+    start: Option<Cursor>,
+    fetch: F,
+) -> impl Stream<Item = Result<T, HttpError>> + 'a
+where
+    T: 'a,
+    R: Paginated<T>,
+    F: Fn(Option<Cursor>) -> Fut + 'a,
+    Fut: Future<Output = Result<R, HttpError>> + 'a,
+{
+    stream::try_unfold(
+        (Some(start), fetch),
+        |(cursor, fetch): (Option<Option<Cursor>>, F)| async move {
+            let Some(cursor) = cursor else {
+                return Ok::<_, HttpError>(None);
+            };
+            let page = fetch(cursor.clone()).await?.into_page(cursor.as_ref());
+            let next = match page.next {
+                Some(next) if !page.items.is_empty() && Some(&next) != cursor.as_ref() => {
+                    Some(Some(next))
+                }
+                _ => None,
+            };
+            Ok(Some((
+                stream::iter(page.items.into_iter().map(Ok)),
+                (next, fetch),
+            )))
+        },
+    )
+    .try_flatten()
+}
+
+/// Collect every item of a paginated listing
+///
+/// See [`paginate`].
+pub async fn collect_all<T, R, F, Fut>(start: Option<Cursor>, fetch: F) -> Result<Vec<T>, HttpError>
+where
+    R: Paginated<T>,
+    F: Fn(Option<Cursor>) -> Fut,
+    Fut: Future<Output = Result<R, HttpError>>,
+{
+    paginate(start, fetch).try_collect().await
+}
+
+/// Time window of the next page of a trade listing over `bounds`, starting
+/// (ascending) or ending (descending) at the cursor's trade
+pub(crate) fn trade_window(
+    (start, end): (TimestampMs, TimestampMs),
+    cursor: Option<&Cursor>,
+    sorting: Option<&SortDirection>,
+) -> (TimestampMs, TimestampMs) {
+    match cursor.and_then(Cursor::trade) {
+        Some(last) if last.ascending_for(sorting) => (last.timestamp, end),
+        Some(last) => (start, last.timestamp),
+        None => (start, end),
+    }
+}
+
+/// Continuation cursor from an API token, treating `"none"` as the end
+fn continuation_cursor(token: Option<&ContinuationToken>) -> Option<Cursor> {
+    token
+        .filter(|token| !token.is_end())
+        .map(|token| Cursor::Continuation(token.clone()))
+}
+
+/// Offset cursor following a `count`/`offset` page
+fn offset_cursor(current: Option<&Cursor>, page_len: usize, total: u64) -> Option<Cursor> {
+    let offset = current.and_then(Cursor::offset).unwrap_or(0);
+    let next = offset.checked_add(u32::try_from(page_len).ok()?)?;
+    (page_len > 0 && u64::from(next) < total).then_some(Cursor::Offset(next))
+}
+
+/// A `count`/`offset` page of a listing that reports no total
+///
+/// A page shorter than the requested `count` is taken as the last one.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OffsetPage<T> {
+    /// Items on this page
+    pub items: Vec<T>,
+    /// Number of items that were requested
+    pub count: u32,
+}
+
+impl<T> Paginated<T> for OffsetPage<T> {
+    fn next_cursor(&self, current: Option<&Cursor>) -> Option<Cursor> {
+        let page_len = u32::try_from(self.items.len()).ok()?;
+        if page_len == 0 || page_len < self.count {
+            return None;
+        }
+        let offset = current.and_then(Cursor::offset).unwrap_or(0);
+        offset.checked_add(page_len).map(Cursor::Offset)
+    }
+
+    fn into_items(self) -> Vec<T> {
+        self.items
+    }
+}
+
+macro_rules! impl_continuation_paginated {
+    ($response:ty, $item:ty, $items:ident) => {
+        impl Paginated<$item> for $response {
+            fn next_cursor(&self, _current: Option<&Cursor>) -> Option<Cursor> {
+                continuation_cursor(self.continuation.as_ref())
+            }
+
+            fn into_items(self) -> Vec<$item> {
+                self.$items
+            }
+        }
+    };
+}
+
+macro_rules! impl_offset_paginated {
+    ($response:ty, $item:ty, $items:ident, $total:ident) => {
+        impl Paginated<$item> for $response {
+            fn next_cursor(&self, current: Option<&Cursor>) -> Option<Cursor> {
+                offset_cursor(current, self.$items.len(), u64::from(self.$total))
+            }
+
+            fn into_items(self) -> Vec<$item> {
+                self.$items
+            }
+        }
+    };
+}
+
+impl_continuation_paginated!(SettlementsResponse, Settlement, settlements);
+impl_continuation_paginated!(
+    TriggerOrderHistoryResponse,
+    TriggerOrderHistoryEntry,
+    entries
+);
+impl_continuation_paginated!(StopOrderHistoryResponse, StopOrderHistoryEntry, entries);
+impl_continuation_paginated!(BlockRfqTradesResponse, BlockRfqPublicTrade, block_rfqs);
+impl_continuation_paginated!(GetBlockTradesResponse, BlockTrade, block_trades);
+impl_continuation_paginated!(AccessLogResponse, AccessLogEntry, data);
+impl_continuation_paginated!(ListAddressBeneficiariesResponse, AddressBeneficiary, data);
+
+impl_offset_paginated!(
+    DeliveryPricesResponse,
+    DeliveryPriceData,
+    data,
+    records_total
+);
+impl_offset_paginated!(DepositsResponse, Deposit, data, count);
+impl_offset_paginated!(WithdrawalsResponse, Withdrawal, data, count);
+impl_offset_paginated!(TransfersResponse, InternalTransfer, data, count);
+
+impl Paginated<TransactionLogEntry> for TransactionLogResponse {
+    fn next_cursor(&self, _current: Option<&Cursor>) -> Option<Cursor> {
+        self.continuation
+            .map(|continuation| Cursor::Continuation(continuation.into()))
+    }
+
+    fn into_items(self) -> Vec<TransactionLogEntry> {
+        self.logs
+    }
+}
+
+impl Paginated<VolatilityIndexCandle> for VolatilityIndexData {
+    fn next_cursor(&self, _current: Option<&Cursor>) -> Option<Cursor> {
+        self.continuation
+            .map(|continuation| Cursor::Continuation(continuation.into()))
+    }
+
+    fn into_items(self) -> Vec<VolatilityIndexCandle> {
+        self.data
+    }
+}
+
+/// Pages backwards by epoch day: the next cursor is the oldest day seen, to be
+/// passed as `before`
+impl Paginated<AprDataPoint> for AprHistoryResponse {
+    fn next_cursor(&self, _current: Option<&Cursor>) -> Option<Cursor> {
+        let oldest = self.data.iter().map(|point| point.day).min()?;
+        u64::try_from(oldest).ok().map(Cursor::Seq)
+    }
+
+    fn into_items(self) -> Vec<AprDataPoint> {
+        self.data
+    }
+}
+
+/// Pages past the last trade of each page (see [`TradeCursor`])
+///
+/// The direction is taken from the current cursor or, on the first page, from
+/// the order of the page's timestamps, defaulting to newest first.
+macro_rules! impl_trade_paginated {
+    ($response:ty, $item:ty) => {
+        impl Paginated<$item> for $response {
+            fn next_cursor(&self, current: Option<&Cursor>) -> Option<Cursor> {
+                if !self.has_more {
+                    return None;
+                }
+                let (first, last) = (self.trades.first()?, self.trades.last()?);
+                let current = current.and_then(Cursor::trade);
+                let ascending = current.map_or(first.timestamp < last.timestamp, |c| c.ascending);
+                let mut returned = match current {
+                    Some(current) if current.timestamp == last.timestamp => {
+                        current.returned.clone()
+                    }
+                    _ => Vec::new(),
+                };
+                for trade in &self.trades {
+                    if trade.timestamp == last.timestamp && !returned.contains(&trade.trade_id) {
+                        returned.push(trade.trade_id.clone());
+                    }
+                }
+                Some(Cursor::Trade(TradeCursor {
+                    ascending,
+                    trade_seq: last.trade_seq,
+                    trade_id: last.trade_id.clone(),
+                    timestamp: last.timestamp,
+                    returned,
+                }))
+            }
+
+            fn into_items(self) -> Vec<$item> {
+                self.trades
+            }
+
+            fn into_page(self, current: Option<&Cursor>) -> Page<$item> {
+                let next = self.next_cursor(current);
+                let returned = current
+                    .and_then(Cursor::trade)
+                    .map(|current| current.returned.as_slice())
+                    .unwrap_or_default();
+                let items = self
+                    .trades
+                    .into_iter()
+                    .filter(|trade| !returned.contains(&trade.trade_id))
+                    .collect();
+                Page { items, next }
+            }
+        }
+
+        impl $response {
+            /// Fail when a `has_more` page only repeats trades already
+            /// returned at the cursor, which an inclusive id or timestamp
+            /// bound cannot move past
+            pub(crate) fn ensure_progress(
+                self,
+                current: Option<&Cursor>,
+            ) -> Result<Self, HttpError> {
+                let Some(current) = current.and_then(Cursor::trade) else {
+                    return Ok(self);
+                };
+                let stalled = self.has_more
+                    && !self.trades.is_empty()
+                    && self
+                        .trades
+                        .iter()
+                        .all(|trade| current.returned.contains(&trade.trade_id));
+                if stalled {
+                    return Err(HttpErrorKind::ConfigError(format!(
+                        "Trade pages cannot move past trade {} at {}; raise count above {}",
+                        current.trade_id,
+                        current.timestamp,
+                        self.trades.len()
+                    ))
+                    .into());
+                }
+                Ok(self)
+            }
+        }
+    };
+}
+
+impl_trade_paginated!(UserTradeWithPaginationResponse, UserTrade);
+impl_trade_paginated!(LastTradesResponse, LastTrade);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_offset_cursor_stops_at_total() {
+        assert_eq!(offset_cursor(None, 2, 5), Some(Cursor::Offset(2)));
+        assert_eq!(
+            offset_cursor(Some(&Cursor::Offset(2)), 3, 5),
+            None,
+            "offset 5 reaches the total"
+        );
+        assert_eq!(offset_cursor(Some(&Cursor::Offset(2)), 0, 5), None);
+    }
+
+    #[test]
+    fn test_offset_page_stops_on_short_page() {
+        let page = |len: usize| OffsetPage {
+            items: vec![0; len],
+            count: 3,
+        };
+        assert_eq!(page(3).next_cursor(None), Some(Cursor::Offset(3)));
+        assert_eq!(
+            page(3).next_cursor(Some(&Cursor::Offset(3))),
+            Some(Cursor::Offset(6))
+        );
+        assert_eq!(page(2).next_cursor(Some(&Cursor::Offset(3))), None);
+        assert_eq!(page(0).next_cursor(None), None);
+    }
+
+    #[test]
+    fn test_continuation_cursor_treats_none_as_end() {
+        assert_eq!(continuation_cursor(Some(&"none".into())), None);
+        assert_eq!(continuation_cursor(Some(&"".into())), None);
+        assert_eq!(continuation_cursor(None), None);
+        assert_eq!(
+            continuation_cursor(Some(&"abc".into())),
+            Some(Cursor::Continuation("abc".into()))
+        );
+    }
+
+    #[test]
+    fn test_continuation_token_accepts_strings_and_numbers() {
+        let text: ContinuationToken = serde_json::from_str(r#""2000.SLIS-2""#).unwrap();
+        let number: ContinuationToken = serde_json::from_str("12345").unwrap();
+
+        assert_eq!(text.as_str(), "2000.SLIS-2");
+        assert_eq!(number.as_str(), "12345");
+        assert_eq!(serde_json::to_string(&number).unwrap(), r#""12345""#);
+        assert!(ContinuationToken::from("none").is_end());
+        assert!(!text.is_end());
+    }
+
+    #[test]
+    fn test_page_exposes_continuation() {
+        let page = SettlementsResponse {
+            continuation: Some("p2".into()),
+            settlements: Vec::new(),
+        }
+        .into_page(None);
+
+        assert_eq!(
+            page.continuation().map(ContinuationToken::as_str),
+            Some("p2")
+        );
+        assert_eq!(
+            Page::<u8> {
+                items: Vec::new(),
+                next: Some(Cursor::Offset(3))
+            }
+            .continuation(),
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn test_collect_all_follows_cursors() {
+        let settlements = |millis| Settlement::new(Default::default(), TimestampMs(millis));
+        let items = collect_all(None, |cursor: Option<Cursor>| {
+            let page = match cursor.as_ref().and_then(Cursor::continuation) {
+                None => SettlementsResponse {
+                    continuation: Some("p2".into()),
+                    settlements: vec![settlements(2), settlements(1)],
+                },
+                Some(_) => SettlementsResponse {
+                    continuation: Some("none".into()),
+                    settlements: vec![settlements(0)],
+                },
+            };
+            async move { Ok(page) }
+        })
+        .await
+        .unwrap();
+
+        let timestamps: Vec<u64> = items.iter().map(|s| s.timestamp.as_millis()).collect();
+        assert_eq!(timestamps, vec![2, 1, 0]);
+    }
+
+    #[test]
+    fn test_trade_cursor_skips_trades_returned_at_the_boundary() {
+        let page = |trades: serde_json::Value, has_more: bool| -> LastTradesResponse {
+            serde_json::from_value(serde_json::json!({ "trades": trades, "has_more": has_more }))
+                .unwrap()
+        };
+        let trade = |seq: u64, timestamp: u64| {
+            serde_json::json!({
+                "trade_id": format!("BTC-{}", seq),
+                "trade_seq": seq,
+                "instrument_name": "BTC-PERPETUAL",
+                "timestamp": timestamp,
+                "direction": "buy",
+                "price": 1.0,
+                "amount": 1.0,
+                "index_price": 1.0,
+                "mark_price": 1.0,
+                "tick_direction": 0
+            })
+        };
+
+        let first = page(serde_json::json!([trade(3, 2000), trade(2, 1000)]), true).into_page(None);
+        let Some(Cursor::Trade(cursor)) = first.next.clone() else {
+            panic!("expected a trade cursor, got {:?}", first.next);
+        };
+        assert!(!cursor.ascending);
+        assert_eq!(cursor.trade_seq, 2);
+        assert_eq!(cursor.timestamp, TimestampMs(1000));
+        assert_eq!(cursor.returned, vec!["BTC-2".to_string()]);
+        assert!(cursor.ascending_for(Some(&SortDirection::Asc)));
+
+        let second = page(serde_json::json!([trade(2, 1000), trade(1, 1000)]), true)
+            .into_page(first.next.as_ref());
+        let seqs: Vec<u64> = second.items.iter().map(|trade| trade.trade_seq).collect();
+        assert_eq!(seqs, vec![1]);
+        assert_eq!(
+            second
+                .next
+                .as_ref()
+                .and_then(Cursor::trade)
+                .map(|c| c.returned.clone()),
+            Some(vec!["BTC-2".to_string(), "BTC-1".to_string()])
+        );
+
+        assert_eq!(
+            page(serde_json::json!([trade(1, 1000)]), false).next_cursor(None),
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn test_trade_window_fails_on_more_trades_than_a_page_at_one_timestamp() {
+        let count = 2;
+        let trades: Vec<serde_json::Value> = (1..=count + 1)
+            .map(|seq| {
+                serde_json::json!({
+                    "trade_id": format!("BTC-{}", seq),
+                    "trade_seq": seq,
+                    "instrument_name": "BTC-PERPETUAL",
+                    "timestamp": 1000,
+                    "direction": "buy",
+                    "price": 1.0,
+                    "amount": 1.0,
+                    "index_price": 1.0,
+                    "mark_price": 1.0,
+                    "tick_direction": 0
+                })
+            })
+            .collect();
+
+        // Every window starts at the shared timestamp, so the API keeps
+        // answering with the first `count` trades
+        let result = collect_all(None, |cursor: Option<Cursor>| {
+            let page: LastTradesResponse = serde_json::from_value(serde_json::json!({
+                "trades": trades[..count].to_vec(),
+                "has_more": true,
+            }))
+            .unwrap();
+            async move { page.ensure_progress(cursor.as_ref()) }
+        })
+        .await;
+
+        let error = result.unwrap_err();
+        assert!(matches!(error.kind(), HttpErrorKind::ConfigError(_)));
+        assert_eq!(
+            error.to_string(),
+            "Configuration error: Trade pages cannot move past trade BTC-2 at 1000; raise count above 2"
+        );
+    }
+}
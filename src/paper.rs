@@ -0,0 +1,924 @@
+//! Paper trading: private order endpoints simulated locally
+//!
+//! A client switched to paper trading with
+//! [`DeribitHttpClient::with_paper_trading`] keeps sending public requests to
+//! the configured API, but answers the order, position and account endpoints
+//! listed in [`SIMULATED_METHODS`] from an in-memory exchange. Orders are
+//! matched against the live order book fetched through the public API, so
+//! strategies can be validated against real prices without credentials or
+//! testnet funds. Other private endpoints fail with `HttpErrorKind::ConfigError`,
+//! so nothing ever reaches a real account.
+//!
+//! The simulation is deliberately simple:
+//!
+//! - An order is matched against the book at the time it is placed, walking
+//!   price levels up to its limit price. Fills don't consume the book's
+//!   liquidity, so every order sees the full snapshot.
+//! - Resting limit orders are matched again, as maker at their own price,
+//!   against a fresh book whenever a simulated endpoint is called.
+//! - Market, `immediate_or_cancel` and `fill_or_kill` orders never rest.
+//!   Post-only orders never take liquidity: crossing ones are rejected when
+//!   `reject_post_only` is set and otherwise rest at the best price on their
+//!   own side.
+//! - Futures and perpetuals settled in the base currency are inverse, with
+//!   amounts in USD; options and `_USDC` instruments are linear. Profit and
+//!   loss is realized when a position is reduced.
+//! - Margin, `reduce_only`, trigger orders and funding are not simulated.
+//!
+//! [`SIMULATED_METHODS`]: crate::paper::SIMULATED_METHODS
+
+use crate::DeribitHttpClient;
+use crate::error::{DeribitErrorCode, HttpError, HttpErrorKind};
+use crate::model::book::OrderBook;
+use crate::model::timestamp::TimestampMs;
+use crate::transport::json_response;
+use futures_util::future::try_join_all;
+use serde_json::{Map, Value, json};
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Arc, Mutex, PoisonError};
+
+/// JSON-RPC methods answered by the simulated exchange
+pub const SIMULATED_METHODS: &[&str] = &[
+    "private/buy",
+    "private/sell",
+    "private/cancel",
+    "private/cancel_all",
+    "private/cancel_all_by_currency",
+    "private/cancel_all_by_instrument",
+    "private/cancel_by_label",
+    "private/get_order_state",
+    "private/get_open_orders",
+    "private/get_open_orders_by_currency",
+    "private/get_open_orders_by_instrument",
+    "private/get_positions",
+    "private/get_position",
+    "private/get_account_summary",
+];
+
+/// Deribit's error code for a post-only order that would take liquidity
+const POST_ONLY_REJECT: i64 = 11054;
+
+/// Starting balances and fees of a paper-trading account
+///
+/// # Examples
+///
+/// ```rust
+/// use deribit_http::DeribitHttpClient;
+/// use deribit_http::paper::PaperTradingConfig;
+///
+/// let client = DeribitHttpClient::new().with_paper_trading(
+///     PaperTradingConfig::default()
+///         .with_balance("BTC", 0.5)
+///         .with_fees(0.0, 0.0005),
+/// );
+/// assert!(client.is_paper_trading());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct PaperTradingConfig {
+    /// Starting balance per currency; other currencies start empty
+    pub balances: HashMap<String, f64>,
+    /// Fee rate charged on fills of resting orders
+    pub maker_fee: f64,
+    /// Fee rate charged on fills taking liquidity
+    pub taker_fee: f64,
+    /// Levels per side of the live book fetched to match orders against
+    pub book_depth: u32,
+}
+
+impl Default for PaperTradingConfig {
+    /// 1 BTC, 10 ETH and 10,000 USDC, Deribit's futures fees and 20 levels
+    fn default() -> Self {
+        Self {
+            balances: HashMap::from([
+                ("BTC".to_string(), 1.0),
+                ("ETH".to_string(), 10.0),
+                ("USDC".to_string(), 10_000.0),
+            ]),
+            maker_fee: 0.0,
+            taker_fee: 0.0005,
+            book_depth: 20,
+        }
+    }
+}
+
+impl PaperTradingConfig {
+    /// Set the starting balance of `currency`
+    #[must_use]
+    pub fn with_balance(mut self, currency: &str, amount: f64) -> Self {
+        self.balances.insert(currency.to_string(), amount);
+        self
+    }
+
+    /// Set the maker and taker fee rates
+    #[must_use]
+    pub fn with_fees(mut self, maker_fee: f64, taker_fee: f64) -> Self {
+        self.maker_fee = maker_fee;
+        self.taker_fee = taker_fee;
+        self
+    }
+}
+
+impl DeribitHttpClient {
+    /// Simulate private order endpoints locally instead of sending them
+    ///
+    /// Public endpoints keep hitting the configured API; see the
+    /// [`paper`](crate::paper) module for what is simulated. No credentials
+    /// are needed. Clones made afterwards share the simulated account.
+    pub fn with_paper_trading(mut self, config: PaperTradingConfig) -> Self {
+        self.paper = Some(Arc::new(PaperExchange::new(config)));
+        self
+    }
+
+    /// Whether private order endpoints are simulated, see
+    /// [`with_paper_trading`](Self::with_paper_trading)
+    pub fn is_paper_trading(&self) -> bool {
+        self.paper.is_some()
+    }
+
+    /// Simulated response to a private request, if paper trading is on
+    ///
+    /// `params` is the `params` member of a JSON-RPC body; without one the
+    /// query string of `url` is used.
+    pub(crate) async fn paper_response(
+        &self,
+        url: &str,
+        params: Option<Value>,
+    ) -> Option<Result<reqwest::Response, HttpError>> {
+        let exchange = self.paper.as_ref()?;
+        let method = method_of(url);
+        if !SIMULATED_METHODS.contains(&method.as_str()) {
+            return Some(Err(HttpErrorKind::ConfigError(format!(
+                "{} is not simulated in paper trading mode",
+                method
+            ))
+            .into()));
+        }
+        let params = match params {
+            Some(Value::Object(params)) => params,
+            Some(_) => Map::new(),
+            None => query_params(url),
+        };
+
+        let outcome = match exchange.instruments_to_match(&method, &params) {
+            Ok(instruments) => {
+                let depth = Some(exchange.config.book_depth);
+                let books = try_join_all(
+                    instruments
+                        .iter()
+                        .map(|instrument| self.get_order_book(instrument, depth)),
+                );
+                match books.await {
+                    Ok(books) => exchange.handle(&method, &params, &books),
+                    Err(e) => return Some(Err(e)),
+                }
+            }
+            Err(rejection) => Err(rejection),
+        };
+
+        let (status, body) = match outcome {
+            Ok(result) => (200, json!({"jsonrpc": "2.0", "result": result})),
+            Err(rejection) => (
+                400,
+                json!({
+                    "jsonrpc": "2.0",
+                    "error": {"code": rejection.code, "message": rejection.message}
+                }),
+            ),
+        };
+        Some(Ok(json_response(status, body.to_string())))
+    }
+}
+
+/// Error returned by the simulated exchange
+#[derive(Debug)]
+struct Rejection {
+    code: i64,
+    message: String,
+}
+
+impl From<DeribitErrorCode> for Rejection {
+    fn from(code: DeribitErrorCode) -> Self {
+        Rejection {
+            code: code.code(),
+            message: code.as_str().to_string(),
+        }
+    }
+}
+
+/// Simulated account shared by a client and its clones
+#[derive(Debug)]
+pub(crate) struct PaperExchange {
+    config: PaperTradingConfig,
+    state: Mutex<PaperState>,
+}
+
+impl PaperExchange {
+    fn new(config: PaperTradingConfig) -> Self {
+        Self {
+            config,
+            state: Mutex::default(),
+        }
+    }
+
+    fn state(&self) -> std::sync::MutexGuard<'_, PaperState> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Instruments whose live book is needed to answer `method`
+    ///
+    /// New orders need their instrument's book; every other call first
+    /// re-matches resting orders and marks open positions.
+    fn instruments_to_match(
+        &self,
+        method: &str,
+        params: &Map<String, Value>,
+    ) -> Result<Vec<String>, Rejection> {
+        if matches!(method, "private/buy" | "private/sell") {
+            let instrument = text(params, "instrument_name")
+                .filter(|instrument| !instrument.is_empty())
+                .ok_or(DeribitErrorCode::InvalidParams)?;
+            return Ok(vec![instrument.to_string()]);
+        }
+        let state = self.state();
+        let mut instruments: Vec<String> = state
+            .orders
+            .iter()
+            .filter(|order| order.state == OrderState::Open)
+            .map(|order| order.instrument_name.clone())
+            .chain(
+                state
+                    .positions
+                    .iter()
+                    .filter(|(_, position)| position.size != 0.0)
+                    .map(|(instrument, _)| instrument.clone()),
+            )
+            .collect();
+        instruments.sort();
+        instruments.dedup();
+        Ok(instruments)
+    }
+
+    /// Answer `method` once the live `books` have been fetched
+    fn handle(
+        &self,
+        method: &str,
+        params: &Map<String, Value>,
+        books: &[OrderBook],
+    ) -> Result<Value, Rejection> {
+        let now = TimestampMs::now().0;
+        let mut state = self.state();
+        for book in books {
+            state.match_resting(book, self.config.maker_fee, now);
+        }
+
+        let instrument = text(params, "instrument_name").unwrap_or_default();
+        let currency = text(params, "currency").unwrap_or("any");
+        match method {
+            "private/buy" | "private/sell" => {
+                let side = if method == "private/buy" {
+                    Side::Buy
+                } else {
+                    Side::Sell
+                };
+                let book = books
+                    .iter()
+                    .find(|book| book.instrument_name == instrument)
+                    .ok_or(DeribitErrorCode::InvalidOrUnsupportedInstrument)?;
+                state.place(params, side, book, self.config.taker_fee, now)
+            }
+            "private/cancel" => state.cancel(text(params, "order_id").unwrap_or_default(), now),
+            "private/cancel_all" => Ok(json!(state.cancel_where(|_| true, now))),
+            "private/cancel_all_by_currency" => Ok(json!(
+                state.cancel_where(|order| in_currency(&order.instrument_name, currency), now)
+            )),
+            "private/cancel_all_by_instrument" => Ok(json!(
+                state.cancel_where(|order| order.instrument_name == instrument, now)
+            )),
+            "private/cancel_by_label" => {
+                let label = text(params, "label").unwrap_or_default();
+                Ok(json!(state.cancel_where(|order| order.label == label, now)))
+            }
+            "private/get_order_state" => {
+                let order_id = text(params, "order_id").unwrap_or_default();
+                state
+                    .orders
+                    .iter()
+                    .find(|order| order.order_id == order_id)
+                    .map(PaperOrder::to_json)
+                    .ok_or_else(|| DeribitErrorCode::OrderNotFound.into())
+            }
+            "private/get_open_orders" => Ok(state.open_orders(|_| true)),
+            "private/get_open_orders_by_currency" => {
+                Ok(state.open_orders(|order| in_currency(&order.instrument_name, currency)))
+            }
+            "private/get_open_orders_by_instrument" => {
+                Ok(state.open_orders(|order| order.instrument_name == instrument))
+            }
+            "private/get_positions" => Ok(state
+                .positions
+                .keys()
+                .filter(|name| in_currency(name, currency))
+                .map(|name| state.position(name))
+                .collect()),
+            "private/get_position" => Ok(state.position(instrument)),
+            "private/get_account_summary" => Ok(state.account_summary(currency, &self.config)),
+            _ => Err(DeribitErrorCode::MethodNotFound.into()),
+        }
+    }
+}
+
+/// Orders, positions and realized profit of the simulated account
+#[derive(Debug, Default)]
+struct PaperState {
+    orders: Vec<PaperOrder>,
+    positions: BTreeMap<String, PaperPosition>,
+    /// Realized profit and loss net of fees, by settlement currency
+    realized: HashMap<String, f64>,
+    /// Mid price of the last book seen, by instrument
+    marks: HashMap<String, f64>,
+    next_id: u64,
+}
+
+impl PaperState {
+    /// Place a new order against `book` and return `{order, trades}`
+    fn place(
+        &mut self,
+        params: &Map<String, Value>,
+        side: Side,
+        book: &OrderBook,
+        taker_fee: f64,
+        now: u64,
+    ) -> Result<Value, Rejection> {
+        let amount = number(params, "amount")
+            .or_else(|| number(params, "contracts"))
+            .filter(|amount| *amount > 0.0)
+            .ok_or(DeribitErrorCode::InvalidAmount)?;
+        let order_type = text(params, "type").unwrap_or("limit");
+        let market = match order_type {
+            "market" => true,
+            "limit" => false,
+            _ => return Err(DeribitErrorCode::NotImplemented.into()),
+        };
+        let time_in_force = text(params, "time_in_force").unwrap_or("good_til_cancelled");
+        let post_only = boolean(params, "post_only");
+
+        let mut price = match number(params, "price") {
+            Some(price) if price > 0.0 => price,
+            _ if market => 0.0,
+            _ => return Err(DeribitErrorCode::InvalidPrice.into()),
+        };
+        let touch = side.opposite_levels(book).first().map(|level| level.price);
+        if post_only && touch.is_some_and(|touch| side.accepts(price, touch)) {
+            if boolean(params, "reject_post_only") {
+                return Err(Rejection {
+                    code: POST_ONLY_REJECT,
+                    message: "post_only_reject".to_string(),
+                });
+            }
+            if let Some(best) = side.own_levels(book).first() {
+                price = best.price;
+            }
+        }
+
+        let mut fills = if post_only {
+            Vec::new()
+        } else {
+            match_levels(book, side, (!market).then_some(price), amount)
+        };
+        let filled: f64 = fills.iter().map(|(_, amount)| amount).sum();
+        if time_in_force == "fill_or_kill" && filled < amount {
+            fills.clear();
+        }
+
+        self.next_id += 1;
+        let mut order = PaperOrder {
+            order_id: format!(
+                "{}-PAPER-{}",
+                settlement_currency(&book.instrument_name),
+                self.next_id
+            ),
+            instrument_name: book.instrument_name.clone(),
+            side,
+            order_type: order_type.to_string(),
+            price,
+            amount,
+            filled_amount: 0.0,
+            average_price: 0.0,
+            state: OrderState::Open,
+            label: text(params, "label").unwrap_or_default().to_string(),
+            time_in_force: time_in_force.to_string(),
+            post_only,
+            reduce_only: boolean(params, "reduce_only"),
+            creation_timestamp: now,
+            last_update_timestamp: now,
+        };
+        let trades: Vec<Value> = fills
+            .into_iter()
+            .map(|(price, amount)| self.execute(&mut order, price, amount, taker_fee, "T", now))
+            .collect();
+        if order.state == OrderState::Open
+            && (market || matches!(time_in_force, "immediate_or_cancel" | "fill_or_kill"))
+        {
+            order.state = OrderState::Cancelled;
+        }
+
+        let order_json = order.to_json();
+        self.orders.push(order);
+        Ok(json!({"order": order_json, "trades": trades}))
+    }
+
+    /// Fill resting orders `book` now trades through, as maker at their price
+    fn match_resting(&mut self, book: &OrderBook, maker_fee: f64, now: u64) {
+        let mid = match (book.best_bid(), book.best_ask()) {
+            (Some(bid), Some(ask)) => Some((bid + ask) / 2.0),
+            (bid, ask) => bid.or(ask),
+        };
+        if let Some(mid) = mid {
+            self.marks.insert(book.instrument_name.clone(), mid);
+        }
+
+        let mut orders = std::mem::take(&mut self.orders);
+        for order in orders.iter_mut().filter(|order| {
+            order.state == OrderState::Open && order.instrument_name == book.instrument_name
+        }) {
+            let remaining = order.amount - order.filled_amount;
+            let available: f64 = match_levels(book, order.side, Some(order.price), remaining)
+                .iter()
+                .map(|(_, amount)| amount)
+                .sum();
+            if available > 0.0 {
+                let price = order.price;
+                self.execute(order, price, available, maker_fee, "M", now);
+            }
+        }
+        self.orders = orders;
+    }
+
+    /// Fill `amount` of `order` at `price` and return the trade
+    fn execute(
+        &mut self,
+        order: &mut PaperOrder,
+        price: f64,
+        amount: f64,
+        fee_rate: f64,
+        liquidity: &str,
+        now: u64,
+    ) -> Value {
+        let instrument = order.instrument_name.as_str();
+        let inverse = is_inverse(instrument);
+        let currency = settlement_currency(instrument);
+        let fee = if inverse {
+            amount / price * fee_rate
+        } else {
+            amount * price * fee_rate
+        };
+        let realized = self
+            .positions
+            .entry(instrument.to_string())
+            .or_default()
+            .trade(order.side.sign() * amount, price, inverse);
+        *self.realized.entry(currency.to_string()).or_default() += realized - fee;
+
+        order.average_price = (order.average_price * order.filled_amount + price * amount)
+            / (order.filled_amount + amount);
+        order.filled_amount += amount;
+        order.last_update_timestamp = now;
+        if order.filled_amount >= order.amount {
+            order.state = OrderState::Filled;
+        }
+
+        self.next_id += 1;
+        json!({
+            "trade_id": format!("{}-PAPER-T{}", currency, self.next_id),
+            "trade_seq": self.next_id,
+            "order_id": order.order_id,
+            "order_type": order.order_type,
+            "instrument_name": instrument,
+            "direction": order.side.as_str(),
+            "amount": amount,
+            "price": price,
+            "index_price": self.marks.get(instrument).copied().unwrap_or(price),
+            "mark_price": self.marks.get(instrument).copied().unwrap_or(price),
+            "fee": fee,
+            "fee_currency": currency,
+            "label": order.label,
+            "liquidity": liquidity,
+            "self_trade": false,
+            "state": order.state.as_str(),
+            "tick_direction": 0,
+            "timestamp": now
+        })
+    }
+
+    /// Cancel the open orders matching `filter` and return how many there were
+    fn cancel_where(&mut self, filter: impl Fn(&PaperOrder) -> bool, now: u64) -> usize {
+        let mut cancelled = 0;
+        for order in self
+            .orders
+            .iter_mut()
+            .filter(|order| order.state == OrderState::Open && filter(order))
+        {
+            order.state = OrderState::Cancelled;
+            order.last_update_timestamp = now;
+            cancelled += 1;
+        }
+        cancelled
+    }
+
+    fn cancel(&mut self, order_id: &str, now: u64) -> Result<Value, Rejection> {
+        let order = self
+            .orders
+            .iter_mut()
+            .find(|order| order.order_id == order_id)
+            .ok_or(DeribitErrorCode::OrderNotFound)?;
+        if order.state != OrderState::Open {
+            return Err(DeribitErrorCode::NotOpenOrder.into());
+        }
+        order.state = OrderState::Cancelled;
+        order.last_update_timestamp = now;
+        Ok(order.to_json())
+    }
+
+    fn open_orders(&self, filter: impl Fn(&PaperOrder) -> bool) -> Value {
+        self.orders
+            .iter()
+            .filter(|order| order.state == OrderState::Open && filter(order))
+            .map(PaperOrder::to_json)
+            .collect()
+    }
+
+    fn position(&self, instrument: &str) -> Value {
+        let position = self.positions.get(instrument).copied().unwrap_or_default();
+        let mark = self
+            .marks
+            .get(instrument)
+            .copied()
+            .unwrap_or(position.average_price);
+        let floating = position.floating(mark, is_inverse(instrument));
+        let direction = match position.size {
+            size if size > 0.0 => "buy",
+            size if size < 0.0 => "sell",
+            _ => "zero",
+        };
+        json!({
+            "instrument_name": instrument,
+            "kind": kind(instrument),
+            "direction": direction,
+            "size": position.size,
+            "average_price": position.average_price,
+            "mark_price": mark,
+            "floating_profit_loss": floating,
+            "realized_profit_loss": position.realized,
+            "total_profit_loss": position.realized + floating,
+            "initial_margin": 0.0,
+            "maintenance_margin": 0.0
+        })
+    }
+
+    /// Balances of `currency`, flat as Deribit returns them and in `summaries`
+    fn account_summary(&self, currency: &str, config: &PaperTradingConfig) -> Value {
+        let initial = config.balances.get(currency).copied().unwrap_or_default();
+        let balance = initial + self.realized.get(currency).copied().unwrap_or_default();
+        let floating: f64 = self
+            .positions
+            .iter()
+            .filter(|(name, _)| settlement_currency(name) == currency)
+            .map(|(name, position)| {
+                let mark = self
+                    .marks
+                    .get(name)
+                    .copied()
+                    .unwrap_or(position.average_price);
+                position.floating(mark, is_inverse(name))
+            })
+            .sum();
+        let balances = json!({
+            "currency": currency,
+            "balance": balance,
+            "equity": balance + floating,
+            "available_funds": balance + floating,
+            "available_withdrawal_funds": balance,
+            "margin_balance": balance + floating,
+            "initial_margin": 0.0,
+            "maintenance_margin": 0.0,
+            "total_pl": balance - initial + floating,
+            "session_rpl": balance - initial,
+            "session_upl": floating
+        });
+        let mut summary = json!({
+            "username": "paper",
+            "system_name": "paper",
+            "type": "main",
+            "summaries": [balances.clone()]
+        });
+        if let (Value::Object(summary), Value::Object(balances)) = (&mut summary, balances) {
+            summary.extend(balances);
+        }
+        summary
+    }
+}
+
+/// Side of an order
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Side {
+    Buy,
+    Sell,
+}
+
+impl Side {
+    fn as_str(self) -> &'static str {
+        match self {
+            Side::Buy => "buy",
+            Side::Sell => "sell",
+        }
+    }
+
+    /// Sign of the position change
+    fn sign(self) -> f64 {
+        match self {
+            Side::Buy => 1.0,
+            Side::Sell => -1.0,
+        }
+    }
+
+    /// Whether an order limited to `limit` trades at `price`
+    fn accepts(self, limit: f64, price: f64) -> bool {
+        match self {
+            Side::Buy => price <= limit,
+            Side::Sell => price >= limit,
+        }
+    }
+
+    /// Levels an order on this side trades against, best first
+    fn opposite_levels(self, book: &OrderBook) -> &[crate::model::book::OrderBookEntry] {
+        match self {
+            Side::Buy => &book.asks,
+            Side::Sell => &book.bids,
+        }
+    }
+
+    /// Levels of orders on this side, best first
+    fn own_levels(self, book: &OrderBook) -> &[crate::model::book::OrderBookEntry] {
+        self.opposite().opposite_levels(book)
+    }
+
+    fn opposite(self) -> Side {
+        match self {
+            Side::Buy => Side::Sell,
+            Side::Sell => Side::Buy,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum OrderState {
+    Open,
+    Filled,
+    Cancelled,
+}
+
+impl OrderState {
+    fn as_str(self) -> &'static str {
+        match self {
+            OrderState::Open => "open",
+            OrderState::Filled => "filled",
+            OrderState::Cancelled => "cancelled",
+        }
+    }
+}
+
+/// Order placed on the simulated exchange
+#[derive(Debug, Clone)]
+struct PaperOrder {
+    order_id: String,
+    instrument_name: String,
+    side: Side,
+    order_type: String,
+    price: f64,
+    amount: f64,
+    filled_amount: f64,
+    average_price: f64,
+    state: OrderState,
+    label: String,
+    time_in_force: String,
+    post_only: bool,
+    reduce_only: bool,
+    creation_timestamp: u64,
+    last_update_timestamp: u64,
+}
+
+impl PaperOrder {
+    fn to_json(&self) -> Value {
+        json!({
+            "order_id": self.order_id,
+            "instrument_name": self.instrument_name,
+            "direction": self.side.as_str(),
+            "order_type": self.order_type,
+            "order_state": self.state.as_str(),
+            "price": self.price,
+            "amount": self.amount,
+            "filled_amount": self.filled_amount,
+            "average_price": self.average_price,
+            "label": self.label,
+            "time_in_force": self.time_in_force,
+            "post_only": self.post_only,
+            "reduce_only": self.reduce_only,
+            "creation_timestamp": self.creation_timestamp,
+            "last_update_timestamp": self.last_update_timestamp,
+            "api": true,
+            "web": false,
+            "is_liquidation": false,
+            "replaced": false,
+            "risk_reducing": false
+        })
+    }
+}
+
+/// Net position in one instrument
+#[derive(Debug, Default, Clone, Copy)]
+struct PaperPosition {
+    size: f64,
+    average_price: f64,
+    realized: f64,
+}
+
+impl PaperPosition {
+    /// Apply a fill of signed `amount` at `price` and return the profit it realized
+    fn trade(&mut self, amount: f64, price: f64, inverse: bool) -> f64 {
+        let closing = self.size != 0.0 && self.size.signum() != amount.signum();
+        let realized = if closing {
+            amount.abs().min(self.size.abs())
+                * self.size.signum()
+                * profit_per_unit(self.average_price, price, inverse)
+        } else {
+            0.0
+        };
+
+        let size = self.size + amount;
+        self.average_price = if size == 0.0 {
+            0.0
+        } else if self.size == 0.0 || self.size.signum() != size.signum() {
+            price
+        } else if size.abs() > self.size.abs() {
+            (self.average_price * self.size.abs() + price * amount.abs()) / size.abs()
+        } else {
+            self.average_price
+        };
+        self.size = size;
+        self.realized += realized;
+        realized
+    }
+
+    /// Unrealized profit at `mark`
+    fn floating(&self, mark: f64, inverse: bool) -> f64 {
+        if self.size == 0.0 {
+            return 0.0;
+        }
+        self.size * profit_per_unit(self.average_price, mark, inverse)
+    }
+}
+
+/// Profit of one unit bought at `entry` and sold at `exit`
+///
+/// Inverse contracts are quoted in USD and settled in the base currency.
+fn profit_per_unit(entry: f64, exit: f64, inverse: bool) -> f64 {
+    if inverse {
+        1.0 / entry - 1.0 / exit
+    } else {
+        exit - entry
+    }
+}
+
+/// Fills an order on `side` limited to `limit` gets from `book`, up to `amount`
+fn match_levels(book: &OrderBook, side: Side, limit: Option<f64>, amount: f64) -> Vec<(f64, f64)> {
+    let mut remaining = amount;
+    let mut fills = Vec::new();
+    for level in side.opposite_levels(book) {
+        if remaining <= 0.0 || limit.is_some_and(|limit| !side.accepts(limit, level.price)) {
+            break;
+        }
+        let amount = remaining.min(level.amount);
+        fills.push((level.price, amount));
+        remaining -= amount;
+    }
+    fills
+}
+
+/// Currency profits and fees of `instrument` are paid in
+fn settlement_currency(instrument: &str) -> &str {
+    let prefix = instrument.split('-').next().unwrap_or(instrument);
+    prefix.split_once('_').map_or(prefix, |(_, quote)| quote)
+}
+
+/// Whether `instrument` settles in `currency`, `"any"` matching everything
+fn in_currency(instrument: &str, currency: &str) -> bool {
+    currency == "any" || settlement_currency(instrument) == currency
+}
+
+fn kind(instrument: &str) -> &'static str {
+    if instrument.ends_with("-C") || instrument.ends_with("-P") {
+        "option"
+    } else if instrument.contains('-') {
+        "future"
+    } else {
+        "spot"
+    }
+}
+
+/// Whether `instrument` is an inverse future, quoted in USD
+fn is_inverse(instrument: &str) -> bool {
+    kind(instrument) == "future" && !instrument.contains('_')
+}
+
+/// JSON-RPC method a URL addresses, e.g. `private/buy`
+fn method_of(url: &str) -> String {
+    let path = url.split('?').next().unwrap_or(url);
+    let start = path
+        .find("/private/")
+        .or_else(|| path.find("/public/"))
+        .map_or(0, |index| index + 1);
+    path[start..].to_string()
+}
+
+/// Parameters in the query string of `url`
+fn query_params(url: &str) -> Map<String, Value> {
+    url::Url::parse(url)
+        .map(|url| {
+            url.query_pairs()
+                .map(|(key, value)| (key.into_owned(), Value::String(value.into_owned())))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// String parameter
+fn text<'a>(params: &'a Map<String, Value>, key: &str) -> Option<&'a str> {
+    params.get(key).and_then(Value::as_str)
+}
+
+/// Numeric parameter, sent as a JSON number or a query string value
+fn number(params: &Map<String, Value>, key: &str) -> Option<f64> {
+    match params.get(key)? {
+        Value::String(value) => value.parse().ok(),
+        value => value.as_f64(),
+    }
+}
+
+/// Boolean parameter, sent as a JSON bool or a query string value
+fn boolean(params: &Map<String, Value>, key: &str) -> bool {
+    match params.get(key) {
+        Some(Value::String(value)) => value == "true",
+        Some(value) => value.as_bool().unwrap_or(false),
+        None => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_settlement_currency() {
+        assert_eq!(settlement_currency("BTC-PERPETUAL"), "BTC");
+        assert_eq!(settlement_currency("ETH-27DEC24-3000-C"), "ETH");
+        assert_eq!(settlement_currency("BTC_USDC-PERPETUAL"), "USDC");
+        assert_eq!(settlement_currency("ETH_USDC"), "USDC");
+    }
+
+    #[test]
+    fn test_instrument_kind() {
+        assert!(is_inverse("BTC-PERPETUAL"));
+        assert!(is_inverse("BTC-27DEC24"));
+        assert!(!is_inverse("BTC_USDC-PERPETUAL"));
+        assert!(!is_inverse("BTC-27DEC24-50000-P"));
+        assert_eq!(kind("ETH_USDC"), "spot");
+    }
+
+    #[test]
+    fn test_inverse_position_realizes_profit_in_base_currency() {
+        let mut position = PaperPosition::default();
+        assert_eq!(position.trade(100.0, 50_000.0, true), 0.0);
+        let realized = position.trade(-40.0, 62_500.0, true);
+        assert!((realized - 40.0 * (1.0 / 50_000.0 - 1.0 / 62_500.0)).abs() < 1e-12);
+        assert_eq!(position.size, 60.0);
+        assert_eq!(position.average_price, 50_000.0);
+    }
+
+    #[test]
+    fn test_linear_position_flips_direction() {
+        let mut position = PaperPosition::default();
+        position.trade(-2.0, 100.0, false);
+        let realized = position.trade(3.0, 90.0, false);
+        assert_eq!(realized, 20.0);
+        assert_eq!(position.size, 1.0);
+        assert_eq!(position.average_price, 90.0);
+    }
+
+    #[test]
+    fn test_method_of_url() {
+        assert_eq!(
+            method_of("https://test.deribit.com/api/v2/private/get_position?instrument_name=X"),
+            "private/get_position"
+        );
+    }
+}
@@ -0,0 +1,120 @@
+//! Polling streams that emulate subscriptions over plain HTTP
+//!
+//! A [`PollingStream`] runs a fetch on a tokio task at a fixed interval and
+//! yields the results that changed since the previous one, for deployments that
+//! cannot hold a websocket open. Requests go through the client as usual, so
+//! they share its rate limiter and retry policy with every other call.
+//!
+//! [`PollingStream`]: crate::polling::PollingStream
+
+use crate::constants::MIN_POLL_INTERVAL;
+use crate::error::HttpError;
+use futures_util::stream::Stream;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Duration;
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+use tokio::time::MissedTickBehavior;
+
+/// Stream of polled values, stopped when dropped
+///
+/// Holds at most one value ahead of the consumer: while a value waits to be
+/// read, polling pauses instead of piling up requests. Failed polls are
+/// yielded as errors and polling resumes at the next interval, so the stream
+/// only ends when it is dropped.
+#[derive(Debug)]
+pub struct PollingStream<T> {
+    receiver: mpsc::Receiver<Result<T, HttpError>>,
+    task: JoinHandle<()>,
+}
+
+impl<T: Send + 'static> PollingStream<T> {
+    /// Call `fetch` every `interval` and yield each result whose `key` has
+    /// advanced past the last one yielded
+    ///
+    /// The first result is always yielded; results with the same or an
+    /// older key are skipped. See [`spawn_map`](Self::spawn_map) for the
+    /// polling rules.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called outside a tokio runtime.
+    pub fn spawn<K, F, Fut>(
+        interval: Duration,
+        fetch: F,
+        key: impl Fn(&T) -> K + Send + 'static,
+    ) -> Self
+    where
+        K: PartialOrd + Send + 'static,
+        F: FnMut() -> Fut + Send + 'static,
+        Fut: Future<Output = Result<T, HttpError>> + Send,
+    {
+        let mut last: Option<K> = None;
+        Self::spawn_map(interval, fetch, move |value: T| {
+            let current = key(&value);
+            if last.as_ref().is_some_and(|last| current <= *last) {
+                return None;
+            }
+            last = Some(current);
+            Some(value)
+        })
+    }
+
+    /// Call `fetch` every `interval` and yield what `map` makes of each
+    /// result, skipping the results it maps to `None`
+    ///
+    /// `map` sees every successful result in order, so it can keep the state
+    /// needed to compare consecutive polls. Intervals shorter than
+    /// [`MIN_POLL_INTERVAL`] are raised to it, and a poll that takes longer
+    /// than `interval` delays the next one rather than bursting to catch up.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called outside a tokio runtime.
+    pub fn spawn_map<R, F, Fut>(
+        interval: Duration,
+        mut fetch: F,
+        mut map: impl FnMut(R) -> Option<T> + Send + 'static,
+    ) -> Self
+    where
+        F: FnMut() -> Fut + Send + 'static,
+        Fut: Future<Output = Result<R, HttpError>> + Send,
+        R: Send + 'static,
+    {
+        let (sender, receiver) = mpsc::channel(1);
+        let task = tokio::spawn(async move {
+            let mut ticks = tokio::time::interval(interval.max(MIN_POLL_INTERVAL));
+            ticks.set_missed_tick_behavior(MissedTickBehavior::Delay);
+            loop {
+                ticks.tick().await;
+                let item = match fetch().await {
+                    Ok(value) => match map(value) {
+                        Some(item) => Ok(item),
+                        None => continue,
+                    },
+                    Err(e) => Err(e),
+                };
+                if sender.send(item).await.is_err() {
+                    break;
+                }
+            }
+        });
+        Self { receiver, task }
+    }
+}
+
+impl<T> Stream for PollingStream<T> {
+    type Item = Result<T, HttpError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.receiver.poll_recv(cx)
+    }
+}
+
+impl<T> Drop for PollingStream<T> {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
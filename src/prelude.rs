@@ -4,16 +4,25 @@
 //! Import everything with a single `use deribit_http::prelude::*;` statement.
 
 // Re-export main client
-pub use crate::client::DeribitHttpClient;
+pub use crate::client::{DeribitHttpClient, DeribitHttpClientBuilder, WithMeta, WithRaw};
+pub use crate::endpoints::SubaccountClient;
 
 // Re-export configuration types
-pub use crate::config::{ApiCredentials, HttpConfig};
+pub use crate::config::{ApiCredentials, CredentialsProvider, HttpConfig, RequestOptions};
 
 // Re-export error types
-pub use crate::error::HttpError;
+pub use crate::error::{DeribitErrorCode, HttpError, HttpErrorKind};
 
 // Re-export authentication types
-pub use crate::auth::{ApiKeyAuth, AuthManager, AuthRequest};
+pub use crate::auth::{
+    ApiKeyAuth, AuthManager, AuthRequest, ExchangeTokenRequest, ForkTokenRequest, ScopeDowngrade,
+};
+
+// Re-export interceptor types
+pub use crate::interceptor::{HookFuture, RequestInterceptor};
+
+// Re-export transport types
+pub use crate::transport::{HttpTransport, TransportError};
 
 // Re-export message types
 pub use crate::message::{HttpMessageBuilder, HttpRequestBuilder, HttpResponseHandler};
@@ -21,8 +30,23 @@ pub use crate::message::{HttpMessageBuilder, HttpRequestBuilder, HttpResponseHan
 // Re-export session types
 pub use crate::session::HttpSession;
 
+// Re-export pagination types
+pub use crate::pagination::{ContinuationToken, Cursor, Page, Paginated, collect_all, paginate};
+
+// Re-export health check types
+#[cfg(not(target_arch = "wasm32"))]
+pub use crate::health::HealthMonitor;
+pub use crate::health::{Health, HealthEvent, HealthStatus};
+
+// Re-export polling types
+#[cfg(not(target_arch = "wasm32"))]
+pub use crate::polling::PollingStream;
+
 // Re-export rate limiting types
-pub use crate::rate_limit::{RateLimitCategory, RateLimiter, categorize_endpoint};
+pub use crate::rate_limit::{
+    CreditLimits, RateLimitCategory, RateLimiter, RequestPriority, categorize_endpoint,
+    request_priority,
+};
 
 // Re-export constants
 pub use crate::constants::{DEFAULT_TIMEOUT, MAX_RETRIES, PRODUCTION_BASE_URL, TESTNET_BASE_URL};
@@ -40,4 +64,5 @@ pub use crate::model::*;
 pub use crate::utils::*;
 
 // Re-export commonly used external types
+pub use futures_util::stream::{Stream, StreamExt, TryStreamExt};
 pub use serde_json::{Value, json};
@@ -1,158 +1,291 @@
 //! Rate limiting implementation for Deribit HTTP client
 //!
-//! This module provides automatic rate limiting to comply with Deribit API limits.
-//! It implements a token bucket algorithm with different limits for different
-//! endpoint categories.
+//! This module models Deribit's credit-based rate limits. Every request costs
+//! credits from one of two pools: the matching engine pool (order entry, edits
+//! and cancels) or the non-matching-engine pool (everything else). Each pool
+//! holds up to `max_credits` and refills continuously at `refill_per_sec`.
+//! Requests wait until their pool can cover the cost, so the client paces itself
+//! instead of being rejected with `too_many_requests` (10028).
+//!
+//! Deribit enforces the limits per account, so clients of the same account
+//! (one per strategy, say) should share one [`RateLimiter`]: clones draw from
+//! the same pools, and [`DeribitHttpClientBuilder::rate_limiter`] or
+//! [`DeribitHttpClient::with_rate_limiter`] hand a clone to each client.
+//!
+//! While a pool is throttled, waiting requests are admitted by
+//! [`RequestPriority`] (cancels, then order entry, then account queries, then
+//! market data), so risk-reducing requests are not stuck behind bulk history
+//! downloads.
+//!
+//! [`DeribitHttpClientBuilder::rate_limiter`]: crate::DeribitHttpClientBuilder::rate_limiter
+//! [`DeribitHttpClient::with_rate_limiter`]: crate::DeribitHttpClient::with_rate_limiter
 
+use crate::constants::endpoints::*;
 use crate::sleep_compat::sleep;
 use crate::sync_compat::Mutex;
 use crate::time_compat::Instant;
-use std::collections::HashMap;
 use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::time::Duration;
 
-/// Rate limiter for different endpoint categories
+/// Rate limiter holding one credit pool per [`RateLimitCategory`]
+///
+/// Clones share the same pools.
 #[derive(Debug, Clone)]
 pub struct RateLimiter {
-    limiters: Arc<Mutex<HashMap<RateLimitCategory, TokenBucket>>>,
+    matching_engine: Arc<QueuedPool>,
+    non_matching_engine: Arc<QueuedPool>,
 }
 
-/// Categories of rate limits based on Deribit API documentation
-#[derive(Debug, Clone, Hash, Eq, PartialEq)]
+/// Deribit credit pools
+#[derive(Debug, Clone, Copy, Hash, Eq, PartialEq)]
 pub enum RateLimitCategory {
-    /// Trading endpoints (buy, sell, cancel, etc.)
-    Trading,
-    /// Market data endpoints (ticker, orderbook, etc.)
+    /// Order entry, edits and cancels, served by the matching engine
+    MatchingEngine,
+    /// All other requests, including market data, account and auth
+    NonMatchingEngine,
+}
+
+/// Order in which requests waiting on a throttled pool are admitted
+///
+/// A request is only charged once no request of a higher priority is
+/// waiting on the same pool; requests of equal priority race as before.
+#[derive(Debug, Clone, Copy, Hash, Eq, PartialEq, Ord, PartialOrd)]
+pub enum RequestPriority {
+    /// Public market data and history, admitted last
     MarketData,
-    /// Account management endpoints (summary, positions, etc.)
+    /// Private account queries and authentication
     Account,
-    /// Authentication endpoints
-    Auth,
-    /// General/other endpoints
-    General,
+    /// Order entry and edits
+    OrderEntry,
+    /// Cancels and position closes, admitted first
+    Cancel,
+}
+
+impl RequestPriority {
+    /// Every priority, lowest first
+    pub const ALL: [RequestPriority; 4] = [
+        RequestPriority::MarketData,
+        RequestPriority::Account,
+        RequestPriority::OrderEntry,
+        RequestPriority::Cancel,
+    ];
 }
 
-/// Token bucket implementation for rate limiting
+/// Size, refill rate and per-request cost of one credit pool
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CreditLimits {
+    /// Pool size, and the credits available at start
+    pub max_credits: u64,
+    /// Credits restored per second
+    pub refill_per_sec: u64,
+    /// Credits charged per request
+    pub cost: u64,
+}
+
+impl CreditLimits {
+    /// Deribit's default non-matching-engine pool: 20 requests/s, burst of 100
+    pub const NON_MATCHING_ENGINE: CreditLimits = CreditLimits {
+        max_credits: 50_000,
+        refill_per_sec: 10_000,
+        cost: 500,
+    };
+
+    /// Deribit's default matching-engine pool: 5 requests/s, burst of 20
+    pub const MATCHING_ENGINE: CreditLimits = CreditLimits {
+        max_credits: 20_000,
+        refill_per_sec: 5_000,
+        cost: 1_000,
+    };
+}
+
+/// A credit pool and the number of requests waiting on it, per priority
 #[derive(Debug)]
-struct TokenBucket {
-    /// Maximum number of tokens
-    capacity: u32,
-    /// Current number of tokens
-    tokens: u32,
-    /// Rate of token refill (tokens per second)
-    refill_rate: u32,
-    /// Last refill time
+struct QueuedPool {
+    credits: Mutex<CreditPool>,
+    waiting: [AtomicUsize; RequestPriority::ALL.len()],
+}
+
+impl QueuedPool {
+    fn new(limits: CreditLimits) -> Self {
+        Self {
+            credits: Mutex::new(CreditPool::new(limits)),
+            waiting: Default::default(),
+        }
+    }
+
+    /// Count a request as waiting until the returned guard is dropped
+    fn enqueue(&self, priority: RequestPriority) -> Queued<'_> {
+        self.waiting[priority as usize].fetch_add(1, Ordering::SeqCst);
+        Queued {
+            waiting: &self.waiting[priority as usize],
+        }
+    }
+
+    /// Requests waiting with a higher priority than `priority`
+    fn waiting_above(&self, priority: RequestPriority) -> usize {
+        self.waiting[priority as usize + 1..]
+            .iter()
+            .map(|count| count.load(Ordering::SeqCst))
+            .sum()
+    }
+}
+
+/// A request counted as waiting on a [`QueuedPool`]
+struct Queued<'a> {
+    waiting: &'a AtomicUsize,
+}
+
+impl Drop for Queued<'_> {
+    fn drop(&mut self) {
+        self.waiting.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+/// A credit pool that refills continuously
+#[derive(Debug)]
+pub(crate) struct CreditPool {
+    limits: CreditLimits,
+    credits: f64,
     last_refill: Instant,
 }
 
-impl TokenBucket {
-    /// Create a new token bucket
-    fn new(capacity: u32, refill_rate: u32) -> Self {
+impl CreditPool {
+    /// Create a full pool
+    pub(crate) fn new(limits: CreditLimits) -> Self {
         Self {
-            capacity,
-            tokens: capacity,
-            refill_rate,
+            limits,
+            credits: limits.max_credits as f64,
             last_refill: Instant::now(),
         }
     }
 
-    /// Try to consume a token, returns true if successful
-    fn try_consume(&mut self) -> bool {
+    /// Charge one request, returning whether the pool could cover it
+    pub(crate) fn try_charge(&mut self) -> bool {
         self.refill();
-        if self.tokens > 0 {
-            self.tokens -= 1;
+        let cost = self.limits.cost as f64;
+        if self.credits >= cost {
+            self.credits -= cost;
             true
         } else {
             false
         }
     }
 
-    /// Get time until next token is available
-    fn time_until_token(&self) -> Duration {
-        if self.tokens > 0 {
-            Duration::from_secs(0)
+    /// Drain the pool so it takes `duration` before it can cover a request
+    fn drain_for(&mut self, duration: Duration) {
+        self.refill();
+        let floor =
+            self.limits.cost as f64 - duration.as_secs_f64() * self.limits.refill_per_sec as f64;
+        self.credits = self.credits.min(floor);
+    }
+
+    /// Time until the pool can cover `requests` requests
+    fn time_until_covered(&self, requests: usize) -> Duration {
+        let missing = (self.limits.cost * requests as u64) as f64 - self.credits;
+        if missing <= 0.0 || self.limits.refill_per_sec == 0 {
+            Duration::ZERO
         } else {
-            Duration::from_secs_f64(1.0 / self.refill_rate as f64)
+            Duration::from_secs_f64(missing / self.limits.refill_per_sec as f64)
         }
     }
 
-    /// Refill tokens based on elapsed time
+    /// Restore credits for the time elapsed since the last refill
     fn refill(&mut self) {
         let now = Instant::now();
-        let elapsed = now.duration_since(self.last_refill);
-        let tokens_to_add = (elapsed.as_secs_f64() * self.refill_rate as f64) as u32;
-
-        if tokens_to_add > 0 {
-            self.tokens = (self.tokens + tokens_to_add).min(self.capacity);
-            self.last_refill = now;
-        }
+        let elapsed = now.duration_since(self.last_refill).as_secs_f64();
+        self.credits = (self.credits + elapsed * self.limits.refill_per_sec as f64)
+            .min(self.limits.max_credits as f64);
+        self.last_refill = now;
     }
 }
 
 impl RateLimiter {
-    /// Create a new rate limiter with default Deribit limits
+    /// Create a new rate limiter with Deribit's default-tier limits
     pub fn new() -> Self {
-        let mut limiters = HashMap::new();
-
-        // Based on Deribit API documentation
-        // Trading: 200 requests per second with burst of 250
-        limiters.insert(RateLimitCategory::Trading, TokenBucket::new(250, 200));
-
-        // Market data: Higher limits for public endpoints
-        limiters.insert(RateLimitCategory::MarketData, TokenBucket::new(500, 400));
-
-        // Account: Moderate limits
-        limiters.insert(RateLimitCategory::Account, TokenBucket::new(200, 150));
-
-        // Auth: Lower limits to prevent abuse
-        limiters.insert(RateLimitCategory::Auth, TokenBucket::new(50, 30));
-
-        // General: Default limits
-        limiters.insert(RateLimitCategory::General, TokenBucket::new(300, 200));
+        Self::with_limits(
+            CreditLimits::NON_MATCHING_ENGINE,
+            CreditLimits::MATCHING_ENGINE,
+        )
+    }
 
+    /// Create a rate limiter with custom pools, e.g. for a higher account tier
+    pub fn with_limits(non_matching_engine: CreditLimits, matching_engine: CreditLimits) -> Self {
         Self {
-            limiters: Arc::new(Mutex::new(limiters)),
+            matching_engine: Arc::new(QueuedPool::new(matching_engine)),
+            non_matching_engine: Arc::new(QueuedPool::new(non_matching_engine)),
         }
     }
 
-    /// Wait for rate limit permission for the given category
+    fn pool(&self, category: RateLimitCategory) -> &QueuedPool {
+        match category {
+            RateLimitCategory::MatchingEngine => &self.matching_engine,
+            RateLimitCategory::NonMatchingEngine => &self.non_matching_engine,
+        }
+    }
+
+    /// Wait until the category's pool can cover a request, then charge it
+    ///
+    /// Waits at the lowest priority, [`RequestPriority::MarketData`]; see
+    /// [`wait_for_permission_with_priority`](Self::wait_for_permission_with_priority).
     pub async fn wait_for_permission(&self, category: RateLimitCategory) {
+        self.wait_for_permission_with_priority(category, RequestPriority::MarketData)
+            .await
+    }
+
+    /// Wait until the category's pool can cover a request, then charge it,
+    /// letting requests of a higher `priority` go first
+    pub async fn wait_for_permission_with_priority(
+        &self,
+        category: RateLimitCategory,
+        priority: RequestPriority,
+    ) {
+        let pool = self.pool(category);
+        let _queued = pool.enqueue(priority);
         loop {
             let wait_time = {
-                let mut limiters = self.limiters.lock().await;
-                let bucket = limiters
-                    .get_mut(&category)
-                    .expect("Rate limit category should exist");
-
-                if bucket.try_consume() {
-                    return; // Permission granted
-                } else {
-                    bucket.time_until_token()
+                let mut credits = pool.credits.lock().await;
+                let ahead = pool.waiting_above(priority);
+                if ahead == 0 && credits.try_charge() {
+                    return;
                 }
+                credits.refill();
+                credits.time_until_covered(ahead + 1)
             };
 
-            // Wait before trying again
-            sleep(wait_time.max(Duration::from_millis(10))).await;
+            sleep(wait_time.max(Duration::from_millis(1))).await;
         }
     }
 
-    /// Check if permission is available without waiting
+    /// Charge a request if the pool can cover it, without waiting
     pub async fn check_permission(&self, category: RateLimitCategory) -> bool {
-        let mut limiters = self.limiters.lock().await;
-        let bucket = limiters
-            .get_mut(&category)
-            .expect("Rate limit category should exist");
-        bucket.try_consume()
+        self.pool(category).credits.lock().await.try_charge()
+    }
+
+    /// Credits currently available in a pool
+    pub async fn remaining_credits(&self, category: RateLimitCategory) -> u64 {
+        let mut pool = self.pool(category).credits.lock().await;
+        pool.refill();
+        pool.credits.max(0.0) as u64
     }
 
-    /// Get current token count for a category (for monitoring)
-    pub async fn get_tokens(&self, category: RateLimitCategory) -> u32 {
-        let mut limiters = self.limiters.lock().await;
-        let bucket = limiters
-            .get_mut(&category)
-            .expect("Rate limit category should exist");
-        bucket.refill();
-        bucket.tokens
+    /// Hold back requests in a pool for `duration`
+    ///
+    /// Called when the server rejects a request as rate limited, so that
+    /// every clone of the client backs off, not just the rejected request.
+    pub async fn cool_down(&self, category: RateLimitCategory, duration: Duration) {
+        self.pool(category).credits.lock().await.drain_for(duration);
+    }
+
+    /// Whether `other` draws from the same credit pools, e.g. a clone of this limiter
+    pub fn shares_pools_with(&self, other: &RateLimiter) -> bool {
+        Arc::ptr_eq(&self.matching_engine, &other.matching_engine)
+            && Arc::ptr_eq(&self.non_matching_engine, &other.non_matching_engine)
+    }
+
+    /// Size, refill rate and request cost of a pool
+    pub async fn limits(&self, category: RateLimitCategory) -> CreditLimits {
+        self.pool(category).credits.lock().await.limits
     }
 }
 
@@ -162,99 +295,267 @@ impl Default for RateLimiter {
     }
 }
 
+/// Deribit error code for an exhausted credit pool (`too_many_requests`)
+pub const TOO_MANY_REQUESTS_CODE: i64 = 10028;
+
+/// Endpoints that go through the matching engine and draw from its own pool
+const MATCHING_ENGINE_ENDPOINTS: &[&str] = &[
+    BUY,
+    SELL,
+    EDIT,
+    EDIT_BY_LABEL,
+    CANCEL,
+    CANCEL_ALL,
+    CANCEL_ALL_BY_CURRENCY,
+    CANCEL_ALL_BY_CURRENCY_PAIR,
+    CANCEL_ALL_BY_INSTRUMENT,
+    CANCEL_ALL_BY_KIND_OR_TYPE,
+    CANCEL_BY_LABEL,
+    CANCEL_QUOTES,
+    CLOSE_POSITION,
+];
+
+/// Whether a request path is served by the matching engine
+pub fn is_matching_engine(path: &str) -> bool {
+    MATCHING_ENGINE_ENDPOINTS.contains(&api_path(path))
+}
+
+/// `/public/...` or `/private/...` part of a request URL, without the query
+pub(crate) fn api_path(url: &str) -> &str {
+    let path = url.split('?').next().unwrap_or(url);
+    ["/public/", "/private/"]
+        .iter()
+        .filter_map(|prefix| path.find(prefix))
+        .min()
+        .map_or(path, |start| &path[start..])
+}
+
+/// Endpoints that cancel orders or close positions, admitted first when throttled
+const CANCEL_ENDPOINTS: &[&str] = &[
+    CANCEL,
+    CANCEL_ALL,
+    CANCEL_ALL_BY_CURRENCY,
+    CANCEL_ALL_BY_CURRENCY_PAIR,
+    CANCEL_ALL_BY_INSTRUMENT,
+    CANCEL_ALL_BY_KIND_OR_TYPE,
+    CANCEL_BY_LABEL,
+    CANCEL_QUOTES,
+    CLOSE_POSITION,
+];
+
+/// Priority of a request path when its credit pool is throttled
+pub fn request_priority(endpoint: &str) -> RequestPriority {
+    let path = api_path(endpoint);
+    if CANCEL_ENDPOINTS.contains(&path) {
+        RequestPriority::Cancel
+    } else if is_matching_engine(endpoint) {
+        RequestPriority::OrderEntry
+    } else if path.starts_with("/private/") || path == AUTH {
+        RequestPriority::Account
+    } else {
+        RequestPriority::MarketData
+    }
+}
+
 /// Helper function to categorize endpoints
 pub fn categorize_endpoint(endpoint: &str) -> RateLimitCategory {
-    if endpoint.contains("/private/buy")
-        || endpoint.contains("/private/sell")
-        || endpoint.contains("/private/cancel")
-        || endpoint.contains("/private/edit")
-    {
-        RateLimitCategory::Trading
-    } else if endpoint.contains("/public/ticker")
-        || endpoint.contains("/public/get_order_book")
-        || endpoint.contains("/public/get_last_trades")
-        || endpoint.contains("/public/get_instruments")
-    {
-        RateLimitCategory::MarketData
-    } else if endpoint.contains("/private/get_account_summary")
-        || endpoint.contains("/private/get_positions")
-        || endpoint.contains("/private/get_subaccounts")
-    {
-        RateLimitCategory::Account
-    } else if endpoint.contains("/public/auth") || endpoint.contains("/private/logout") {
-        RateLimitCategory::Auth
+    if is_matching_engine(endpoint) {
+        RateLimitCategory::MatchingEngine
     } else {
-        RateLimitCategory::General
+        RateLimitCategory::NonMatchingEngine
     }
 }
 
 #[cfg(all(test, not(target_arch = "wasm32")))]
 mod tests {
     use super::*;
-    use crate::sleep_compat::sleep;
+
+    const SMALL: CreditLimits = CreditLimits {
+        max_credits: 2_000,
+        refill_per_sec: 10_000,
+        cost: 1_000,
+    };
 
     #[tokio::test]
-    async fn test_token_bucket_basic() {
-        let mut bucket = TokenBucket::new(10, 5);
+    async fn test_credit_pool_basic() {
+        let mut pool = CreditPool::new(CreditLimits {
+            refill_per_sec: 0,
+            ..SMALL
+        });
+
+        assert!(pool.try_charge());
+        assert!(pool.try_charge());
+        assert!(!pool.try_charge());
+    }
 
-        // Should be able to consume initial tokens
-        for _ in 0..10 {
-            assert!(bucket.try_consume());
-        }
+    #[tokio::test]
+    async fn test_credit_pool_refill() {
+        let mut pool = CreditPool::new(SMALL);
+
+        assert!(pool.try_charge());
+        assert!(pool.try_charge());
+        assert!(pool.time_until_covered(1) > Duration::ZERO);
+        assert!(pool.time_until_covered(1) <= Duration::from_millis(100));
 
-        // Should be empty now
-        assert!(!bucket.try_consume());
+        // 1,000 credits at 10,000/s take 100ms
+        crate::sleep_compat::sleep(Duration::from_millis(150)).await;
+        assert!(pool.try_charge());
     }
 
     #[tokio::test]
-    async fn test_token_bucket_refill() {
-        let mut bucket = TokenBucket::new(5, 10); // 10 tokens per second
-
-        // Consume all tokens
-        for _ in 0..5 {
-            assert!(bucket.try_consume());
+    async fn test_wait_for_permission_delays_until_refilled() {
+        let limiter = RateLimiter::with_limits(SMALL, CreditLimits::MATCHING_ENGINE);
+
+        let started = Instant::now();
+        for _ in 0..3 {
+            limiter
+                .wait_for_permission(RateLimitCategory::NonMatchingEngine)
+                .await;
         }
-        assert!(!bucket.try_consume());
-
-        // Wait for refill (100ms should give us 1 token at 10/sec rate)
-        sleep(Duration::from_millis(200)).await;
 
-        // Should have at least 1 token now
-        assert!(bucket.try_consume());
+        assert!(started.elapsed() >= Duration::from_millis(90));
+        assert_eq!(
+            limiter
+                .remaining_credits(RateLimitCategory::MatchingEngine)
+                .await,
+            CreditLimits::MATCHING_ENGINE.max_credits
+        );
     }
 
     #[tokio::test]
-    async fn test_rate_limiter() {
+    async fn test_rate_limiter_reports_credits_and_limits() {
         let limiter = RateLimiter::new();
 
-        // Should be able to get permission initially
-        assert!(limiter.check_permission(RateLimitCategory::Trading).await);
-
-        // Test waiting for permission
-        limiter
-            .wait_for_permission(RateLimitCategory::MarketData)
+        assert!(
+            limiter
+                .check_permission(RateLimitCategory::MatchingEngine)
+                .await
+        );
+        let remaining = limiter
+            .remaining_credits(RateLimitCategory::MatchingEngine)
             .await;
-        // If we get here, the wait succeeded
+        assert!((19_000..20_000).contains(&remaining));
+        assert_eq!(
+            limiter.limits(RateLimitCategory::NonMatchingEngine).await,
+            CreditLimits::NON_MATCHING_ENGINE
+        );
     }
 
     #[test]
     fn test_endpoint_categorization() {
         assert_eq!(
             categorize_endpoint("/private/buy"),
-            RateLimitCategory::Trading
+            RateLimitCategory::MatchingEngine
         );
         assert_eq!(
-            categorize_endpoint("/public/ticker"),
-            RateLimitCategory::MarketData
+            categorize_endpoint("https://test.deribit.com/api/v2/private/cancel_all?x=1"),
+            RateLimitCategory::MatchingEngine
         );
         assert_eq!(
-            categorize_endpoint("/private/get_account_summary"),
-            RateLimitCategory::Account
+            categorize_endpoint("/private/get_open_orders"),
+            RateLimitCategory::NonMatchingEngine
+        );
+        assert_eq!(
+            categorize_endpoint("/public/auth"),
+            RateLimitCategory::NonMatchingEngine
+        );
+    }
+
+    #[test]
+    fn test_request_priorities() {
+        assert_eq!(
+            request_priority("https://test.deribit.com/api/v2/private/cancel_all"),
+            RequestPriority::Cancel
         );
-        assert_eq!(categorize_endpoint("/public/auth"), RateLimitCategory::Auth);
         assert_eq!(
-            categorize_endpoint("/public/get_time"),
-            RateLimitCategory::General
+            request_priority("/private/close_position"),
+            RequestPriority::Cancel
         );
+        assert_eq!(
+            request_priority("/private/buy"),
+            RequestPriority::OrderEntry
+        );
+        assert_eq!(
+            request_priority("/private/get_positions"),
+            RequestPriority::Account
+        );
+        assert_eq!(request_priority("/public/auth"), RequestPriority::Account);
+        assert_eq!(
+            request_priority("/public/get_tradingview_chart_data?resolution=1"),
+            RequestPriority::MarketData
+        );
+        assert!(RequestPriority::Cancel > RequestPriority::OrderEntry);
+        assert!(RequestPriority::Account > RequestPriority::MarketData);
+    }
+
+    #[tokio::test]
+    async fn test_higher_priority_waiters_are_admitted_first() {
+        // One request per 100ms
+        let limits = CreditLimits {
+            max_credits: 1_000,
+            refill_per_sec: 10_000,
+            cost: 1_000,
+        };
+        let limiter = RateLimiter::with_limits(limits, limits);
+        limiter
+            .wait_for_permission(RateLimitCategory::NonMatchingEngine)
+            .await;
+
+        let admitted = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let waiter = |priority: RequestPriority| {
+            let limiter = limiter.clone();
+            let admitted = admitted.clone();
+            tokio::spawn(async move {
+                limiter
+                    .wait_for_permission_with_priority(
+                        RateLimitCategory::NonMatchingEngine,
+                        priority,
+                    )
+                    .await;
+                admitted.lock().unwrap().push(priority);
+            })
+        };
+        let history = waiter(RequestPriority::MarketData);
+        sleep(Duration::from_millis(10)).await;
+        let account = waiter(RequestPriority::Account);
+        history.await.unwrap();
+        account.await.unwrap();
+
+        assert_eq!(
+            *admitted.lock().unwrap(),
+            vec![RequestPriority::Account, RequestPriority::MarketData]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_dropped_waiter_no_longer_blocks_lower_priorities() {
+        let limits = CreditLimits {
+            max_credits: 1_000,
+            refill_per_sec: 10_000,
+            cost: 1_000,
+        };
+        let limiter = RateLimiter::with_limits(limits, limits);
+        limiter
+            .wait_for_permission(RateLimitCategory::MatchingEngine)
+            .await;
+
+        let cancel = limiter.wait_for_permission_with_priority(
+            RateLimitCategory::MatchingEngine,
+            RequestPriority::Cancel,
+        );
+        assert!(
+            tokio::time::timeout(Duration::from_millis(10), cancel)
+                .await
+                .is_err()
+        );
+
+        let started = Instant::now();
+        limiter
+            .wait_for_permission_with_priority(
+                RateLimitCategory::MatchingEngine,
+                RequestPriority::OrderEntry,
+            )
+            .await;
+        assert!(started.elapsed() < Duration::from_millis(200));
     }
 }
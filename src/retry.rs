@@ -0,0 +1,179 @@
+//! Retry policy for transient request failures
+//!
+//! Requests are retried up to [`HttpConfig::max_retries`](crate::HttpConfig)
+//! times with exponential backoff. Only failures where a retry cannot duplicate
+//! a side effect are retried: connection errors, where the request never left
+//! the client, and rate-limited responses (HTTP 429 or error
+//! `too_many_requests`, 10028), which Deribit returns before processing the
+//! request, on any endpoint; and timeouts and 5xx responses on public
+//! endpoints. Private requests that may have reached the server are never
+//! retried, so an order is never placed twice.
+//!
+//! A rate-limited response also cools down the client's rate limiter for the
+//! `Retry-After` period, or the backoff delay when the header is missing, so
+//! concurrent requests pause too.
+//!
+//! Every request that needed more than one attempt emits a tracing event on the
+//! `deribit_http::retry` target carrying its [`RetryMetadata`].
+
+use crate::constants::{RETRY_BASE_DELAY_MS, RETRY_MAX_DELAY_MS};
+use crate::message::HttpResponseHandler;
+use crate::transport::TransportError;
+use pretty_simple_display::{DebugPretty, DisplaySimple};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// Attempts made for a single request
+#[derive(DebugPretty, DisplaySimple, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct RetryMetadata {
+    /// Number of attempts, including the first
+    pub attempts: u32,
+    /// Time from the first attempt to the final outcome
+    pub elapsed: Duration,
+    /// Errors of the attempts that were retried, oldest first
+    pub errors: Vec<String>,
+}
+
+impl RetryMetadata {
+    /// Whether the request needed more than one attempt
+    pub fn retried(&self) -> bool {
+        self.attempts > 1
+    }
+
+    /// Emit the retry tracing event, if the request was retried
+    pub(crate) fn trace(&self, url: &str, succeeded: bool) {
+        if !self.retried() {
+            return;
+        }
+        let path = url.split('?').next().unwrap_or(url);
+        let elapsed_ms = self.elapsed.as_millis() as u64;
+        if succeeded {
+            tracing::info!(
+                target: "deribit_http::retry",
+                url = %path,
+                attempts = self.attempts,
+                elapsed_ms,
+                errors = ?self.errors,
+                "Request succeeded after retries"
+            );
+        } else {
+            tracing::warn!(
+                target: "deribit_http::retry",
+                url = %path,
+                attempts = self.attempts,
+                elapsed_ms,
+                errors = ?self.errors,
+                "Request failed after retries"
+            );
+        }
+    }
+}
+
+/// Delay before retry number `retry` (1-based)
+pub(crate) fn backoff(retry: u32) -> Duration {
+    let delay = RETRY_BASE_DELAY_MS.saturating_mul(1u64 << retry.saturating_sub(1).min(16));
+    Duration::from_millis(delay.min(RETRY_MAX_DELAY_MS))
+}
+
+/// Whether `outcome` is a rate-limited response: HTTP 429, or Deribit's
+/// `too_many_requests` error (10028), which is also sent with other statuses
+///
+/// The body of other failed responses is read to find the error code, and
+/// the response rebuilt from it. WASM responses cannot be rebuilt, so there
+/// only 429 is recognised.
+pub(crate) async fn throttled(
+    outcome: Result<reqwest::Response, TransportError>,
+) -> (Result<reqwest::Response, TransportError>, bool) {
+    let response = match outcome {
+        Ok(response) if response.status().as_u16() == 429 => return (Ok(response), true),
+        Ok(response) if !response.status().is_success() => response,
+        other => return (other, false),
+    };
+
+    #[cfg(target_arch = "wasm32")]
+    {
+        (Ok(response), false)
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        let status = response.status();
+        let headers = response.headers().clone();
+        let body = match response.bytes().await {
+            Ok(body) => body,
+            Err(e) => return (Err(e.into()), false),
+        };
+        let code = serde_json::from_slice::<serde_json::Value>(&body)
+            .ok()
+            .and_then(|value| value.pointer("/error/code").and_then(|code| code.as_i64()));
+
+        let mut rebuilt = http::Response::new(body);
+        *rebuilt.status_mut() = status;
+        *rebuilt.headers_mut() = headers;
+        (
+            Ok(reqwest::Response::from(rebuilt)),
+            code == Some(crate::rate_limit::TOO_MANY_REQUESTS_CODE),
+        )
+    }
+}
+
+/// Reason to retry a failed send, if it is safe to
+///
+/// `throttled` is what [`throttled`] found for `outcome`.
+pub(crate) fn retry_reason(
+    outcome: &Result<reqwest::Response, TransportError>,
+    throttled: bool,
+    idempotent: bool,
+) -> Option<String> {
+    match outcome {
+        Ok(response) if throttled => {
+            Some(format!("HTTP {} (too many requests)", response.status()))
+        }
+        Ok(response) => {
+            let status = response.status();
+            (idempotent && status.is_server_error()).then(|| format!("HTTP {}", status))
+        }
+        Err(e) if e.is_connect() || (idempotent && e.is_timeout()) => Some(e.to_string()),
+        Err(_) => None,
+    }
+}
+
+/// How long to pause after a rate-limited response, if it was one
+///
+/// Uses the `Retry-After` header when present, otherwise the backoff delay
+/// for retry number `retry`.
+pub(crate) fn cool_down(
+    outcome: &Result<reqwest::Response, TransportError>,
+    throttled: bool,
+    retry: u32,
+) -> Option<Duration> {
+    let response = outcome.as_ref().ok().filter(|_| throttled)?;
+    let retry_after = response
+        .headers()
+        .get(reqwest::header::RETRY_AFTER)
+        .and_then(|value| value.to_str().ok())
+        .and_then(HttpResponseHandler::parse_retry_after);
+    Some(retry_after.unwrap_or_else(|| backoff(retry)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_backoff_doubles_and_caps() {
+        assert_eq!(backoff(1), Duration::from_millis(RETRY_BASE_DELAY_MS));
+        assert_eq!(backoff(2), Duration::from_millis(RETRY_BASE_DELAY_MS * 2));
+        assert_eq!(backoff(40), Duration::from_millis(RETRY_MAX_DELAY_MS));
+    }
+
+    #[test]
+    fn test_retried_needs_more_than_one_attempt() {
+        let mut metadata = RetryMetadata {
+            attempts: 1,
+            ..Default::default()
+        };
+        assert!(!metadata.retried());
+        metadata.attempts = 2;
+        assert!(metadata.retried());
+    }
+}
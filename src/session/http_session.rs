@@ -56,10 +56,8 @@ impl HttpSession {
 
     /// Get authorization header value
     pub async fn authorization_header(&self) -> Option<String> {
-        if let Some(token) = self.auth_token().await {
-            Some(format!("{} {}", token.token_type, token.access_token))
-        } else {
-            None
-        }
+        self.auth_token()
+            .await
+            .map(|token| format!("{} {}", token.token_type, token.access_token))
     }
 }
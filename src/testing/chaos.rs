@@ -0,0 +1,240 @@
+/******************************************************************************
+   Author: Joaquín Béjar García
+   Email: jb@taunais.com
+   Date: 15/10/26
+******************************************************************************/
+use crate::DeribitHttpClient;
+use crate::rate_limit::api_path;
+use rand::rngs::StdRng;
+use rand::{RngExt, SeedableRng};
+use serde_json::json;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, PoisonError};
+
+/// A realistic Deribit error that chaos mode can inject
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ChaosFault {
+    /// 10009 `not_enough_funds`
+    NotEnoughFunds,
+    /// 10007 `price_too_high`, outside the price band
+    PriceTooHigh,
+    /// 10005 `price_too_low`, outside the price band
+    PriceTooLow,
+    /// 10047 `matching_engine_queue_full`
+    MatchingEngineQueueFull,
+    /// 10040 `retry`, the request can't be processed right now
+    Retry,
+    /// 11051 `system_maintenance`
+    SystemMaintenance,
+    /// 13028 `temporarily_unavailable`
+    TemporarilyUnavailable,
+}
+
+impl ChaosFault {
+    /// Every fault chaos mode knows about
+    pub const ALL: [ChaosFault; 7] = [
+        ChaosFault::NotEnoughFunds,
+        ChaosFault::PriceTooHigh,
+        ChaosFault::PriceTooLow,
+        ChaosFault::MatchingEngineQueueFull,
+        ChaosFault::Retry,
+        ChaosFault::SystemMaintenance,
+        ChaosFault::TemporarilyUnavailable,
+    ];
+
+    /// Deribit error code
+    pub fn code(&self) -> i64 {
+        match self {
+            ChaosFault::NotEnoughFunds => 10009,
+            ChaosFault::PriceTooHigh => 10007,
+            ChaosFault::PriceTooLow => 10005,
+            ChaosFault::MatchingEngineQueueFull => 10047,
+            ChaosFault::Retry => 10040,
+            ChaosFault::SystemMaintenance => 11051,
+            ChaosFault::TemporarilyUnavailable => 13028,
+        }
+    }
+
+    /// Deribit error message
+    pub fn message(&self) -> &'static str {
+        match self {
+            ChaosFault::NotEnoughFunds => "not_enough_funds",
+            ChaosFault::PriceTooHigh => "price_too_high",
+            ChaosFault::PriceTooLow => "price_too_low",
+            ChaosFault::MatchingEngineQueueFull => "matching_engine_queue_full",
+            ChaosFault::Retry => "retry",
+            ChaosFault::SystemMaintenance => "system_maintenance",
+            ChaosFault::TemporarilyUnavailable => "temporarily_unavailable",
+        }
+    }
+
+    /// HTTP status Deribit answers with
+    pub fn http_status(&self) -> u16 {
+        match self {
+            ChaosFault::SystemMaintenance | ChaosFault::TemporarilyUnavailable => 503,
+            _ => 400,
+        }
+    }
+
+    /// JSON-RPC error body, as Deribit would send it
+    pub fn body(&self) -> String {
+        json!({
+            "jsonrpc": "2.0",
+            "error": {
+                "code": self.code(),
+                "message": self.message(),
+            },
+            "testnet": true,
+        })
+        .to_string()
+    }
+}
+
+/// Injection rate and faults for one endpoint
+#[derive(Debug, Clone, PartialEq)]
+pub struct EndpointChaos {
+    /// Probability in `[0, 1]` that a request fails
+    pub rate: f64,
+    /// Faults to pick from; empty means the config-wide faults
+    pub faults: Vec<ChaosFault>,
+}
+
+/// Chaos mode configuration
+///
+/// Each request to a configured endpoint fails with probability `rate`, with
+/// one of its faults picked uniformly. Endpoints are matched on their API path,
+/// e.g. `"/private/buy"`; the default rate applies to all others.
+///
+/// # Examples
+///
+/// ```rust
+/// use deribit_http::DeribitHttpClient;
+/// use deribit_http::testing::{ChaosConfig, ChaosFault};
+///
+/// let config = ChaosConfig::new(0.01)
+///     .with_endpoint("/private/buy", 0.2, [ChaosFault::NotEnoughFunds, ChaosFault::PriceTooHigh])
+///     .with_seed(7);
+/// let client = DeribitHttpClient::new().with_chaos(config);
+/// assert_eq!(client.chaos_injected(), 0);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChaosConfig {
+    /// Probability in `[0, 1]` for endpoints without their own rate
+    pub default_rate: f64,
+    /// Faults used when an endpoint has none of its own
+    pub faults: Vec<ChaosFault>,
+    /// Per-endpoint overrides, keyed by API path
+    pub endpoints: HashMap<String, EndpointChaos>,
+    /// RNG seed for reproducible runs
+    pub seed: Option<u64>,
+}
+
+impl ChaosConfig {
+    /// Inject every known fault at `default_rate` on all endpoints
+    pub fn new(default_rate: f64) -> Self {
+        Self {
+            default_rate: default_rate.clamp(0.0, 1.0),
+            faults: ChaosFault::ALL.to_vec(),
+            endpoints: HashMap::new(),
+            seed: None,
+        }
+    }
+
+    /// Restrict the config-wide faults
+    pub fn with_faults(mut self, faults: impl IntoIterator<Item = ChaosFault>) -> Self {
+        self.faults = faults.into_iter().collect();
+        self
+    }
+
+    /// Set the rate and faults for one endpoint
+    pub fn with_endpoint(
+        mut self,
+        endpoint: impl Into<String>,
+        rate: f64,
+        faults: impl IntoIterator<Item = ChaosFault>,
+    ) -> Self {
+        self.endpoints.insert(
+            endpoint.into(),
+            EndpointChaos {
+                rate: rate.clamp(0.0, 1.0),
+                faults: faults.into_iter().collect(),
+            },
+        );
+        self
+    }
+
+    /// Seed the RNG so the same requests fail on every run
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+}
+
+/// Chaos mode state shared by a client and its clones
+#[derive(Debug)]
+pub(crate) struct Chaos {
+    config: ChaosConfig,
+    rng: Mutex<StdRng>,
+    injected: AtomicU64,
+}
+
+impl Chaos {
+    fn new(config: ChaosConfig) -> Self {
+        let rng = match config.seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => rand::make_rng(),
+        };
+        Self {
+            config,
+            rng: Mutex::new(rng),
+            injected: AtomicU64::new(0),
+        }
+    }
+
+    /// Roll for a fault on a request to `url`
+    pub(crate) fn roll(&self, url: &str) -> Option<ChaosFault> {
+        let endpoint = self.config.endpoints.get(api_path(url));
+        let (rate, faults) = match endpoint {
+            Some(endpoint) if !endpoint.faults.is_empty() => (endpoint.rate, &endpoint.faults),
+            Some(endpoint) => (endpoint.rate, &self.config.faults),
+            None => (self.config.default_rate, &self.config.faults),
+        };
+        if faults.is_empty() || rate <= 0.0 {
+            return None;
+        }
+
+        let mut rng = self.rng.lock().unwrap_or_else(PoisonError::into_inner);
+        if !rng.random_bool(rate) {
+            return None;
+        }
+        let fault = faults[rng.random_range(0..faults.len())];
+        self.injected.fetch_add(1, Ordering::Relaxed);
+        Some(fault)
+    }
+}
+
+/// Synthetic HTTP response carrying a JSON-RPC error
+pub(crate) fn error_response(status: u16, body: String) -> reqwest::Response {
+    crate::transport::json_response(status, body)
+}
+
+impl DeribitHttpClient {
+    /// Enable chaos mode
+    ///
+    /// Requests made through this client and its clones randomly fail with
+    /// realistic Deribit errors before reaching the network, so recovery paths
+    /// can be exercised. Authentication requests are not affected.
+    pub fn with_chaos(mut self, config: ChaosConfig) -> Self {
+        self.simulation.chaos = Some(Arc::new(Chaos::new(config)));
+        self
+    }
+
+    /// Number of faults injected so far by chaos mode
+    pub fn chaos_injected(&self) -> u64 {
+        self.simulation
+            .chaos
+            .as_ref()
+            .map_or(0, |chaos| chaos.injected.load(Ordering::Relaxed))
+    }
+}
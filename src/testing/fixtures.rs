@@ -0,0 +1,274 @@
+/******************************************************************************
+   Author: Joaquín Béjar García
+   Email: jb@taunais.com
+   Date: 15/10/26
+******************************************************************************/
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use serde_json::{Value, json};
+
+/// Timestamp shared by every fixture, 2023-11-14T22:13:20Z
+pub const FIXTURE_TIMESTAMP: u64 = 1_700_000_000_000;
+
+/// `result` payload of a Deribit response, captured from testnet
+///
+/// Start from one of the constructors in this module and override the fields
+/// a test cares about.
+///
+/// # Examples
+///
+/// ```rust
+/// use deribit_http::model::ticker::TickerData;
+/// use deribit_http::testing::fixtures;
+///
+/// let ticker: TickerData = fixtures::ticker("ETH-PERPETUAL")
+///     .set("mark_price", 2_500.0)
+///     .parse()
+///     .unwrap();
+/// assert_eq!(ticker.instrument_name, "ETH-PERPETUAL");
+/// assert_eq!(ticker.mark_price, 2_500.0);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Fixture(Value);
+
+impl Fixture {
+    /// Wraps any JSON value
+    pub fn new(value: Value) -> Self {
+        Fixture(value)
+    }
+
+    /// Replaces or adds the top-level field `key`
+    ///
+    /// # Panics
+    ///
+    /// Panics if the fixture is not a JSON object or `value` does not
+    /// serialize to JSON.
+    #[must_use]
+    pub fn set(mut self, key: &str, value: impl Serialize) -> Self {
+        let value = serde_json::to_value(value).expect("fixture value must serialize to JSON");
+        self.0
+            .as_object_mut()
+            .expect("fixture must be a JSON object")
+            .insert(key.to_string(), value);
+        self
+    }
+
+    /// Removes the top-level field `key`, if present
+    #[must_use]
+    pub fn remove(mut self, key: &str) -> Self {
+        if let Some(object) = self.0.as_object_mut() {
+            object.remove(key);
+        }
+        self
+    }
+
+    /// Deserializes the fixture into a model type
+    pub fn parse<T: DeserializeOwned>(&self) -> Result<T, serde_json::Error> {
+        T::deserialize(&self.0)
+    }
+
+    /// The JSON value
+    pub fn into_value(self) -> Value {
+        self.0
+    }
+}
+
+impl From<Fixture> for Value {
+    fn from(fixture: Fixture) -> Self {
+        fixture.0
+    }
+}
+
+impl From<Value> for Fixture {
+    fn from(value: Value) -> Self {
+        Fixture(value)
+    }
+}
+
+/// `public/ticker` result for a perpetual around 50,000 USD
+pub fn ticker(instrument_name: &str) -> Fixture {
+    Fixture(json!({
+        "timestamp": FIXTURE_TIMESTAMP,
+        "stats": {
+            "volume_usd": 412_847_630.0,
+            "volume": 8_262.41,
+            "price_change": 1.6214,
+            "low": 49_120.5,
+            "high": 50_387.0
+        },
+        "state": "open",
+        "settlement_price": 49_612.87,
+        "open_interest": 612_455_840.0,
+        "min_price": 49_259.5,
+        "max_price": 50_760.5,
+        "mark_price": 50_010.32,
+        "last_price": 50_012.5,
+        "interest_value": 0.0,
+        "instrument_name": instrument_name,
+        "index_price": 49_998.71,
+        "funding_8h": 0.00002181,
+        "estimated_delivery_price": 49_998.71,
+        "current_funding": 0.0,
+        "best_bid_price": 50_010.0,
+        "best_bid_amount": 25_400.0,
+        "best_ask_price": 50_010.5,
+        "best_ask_amount": 4_120.0
+    }))
+}
+
+/// `public/get_order_book` result with five levels on each side
+pub fn order_book(instrument_name: &str) -> Fixture {
+    Fixture(json!({
+        "timestamp": FIXTURE_TIMESTAMP,
+        "stats": {
+            "volume_usd": 412_847_630.0,
+            "volume": 8_262.41,
+            "price_change": 1.6214,
+            "low": 49_120.5,
+            "high": 50_387.0
+        },
+        "state": "open",
+        "settlement_price": 49_612.87,
+        "open_interest": 612_455_840.0,
+        "min_price": 49_259.5,
+        "max_price": 50_760.5,
+        "mark_price": 50_010.32,
+        "last_price": 50_012.5,
+        "instrument_name": instrument_name,
+        "index_price": 49_998.71,
+        "funding_8h": 0.00002181,
+        "current_funding": 0.0,
+        "change_id": 68_412_957_701_u64,
+        "bids": [
+            [50_010.0, 25_400.0],
+            [50_009.5, 12_000.0],
+            [50_009.0, 3_500.0],
+            [50_008.0, 61_230.0],
+            [50_007.5, 10.0]
+        ],
+        "asks": [
+            [50_010.5, 4_120.0],
+            [50_011.0, 18_750.0],
+            [50_012.5, 900.0],
+            [50_013.0, 42_000.0],
+            [50_015.0, 125_000.0]
+        ],
+        "best_bid_price": 50_010.0,
+        "best_bid_amount": 25_400.0,
+        "best_ask_price": 50_010.5,
+        "best_ask_amount": 4_120.0
+    }))
+}
+
+/// Open limit order as returned in `order` by `private/buy`, `private/sell`
+/// and `private/get_order_state`
+///
+/// `direction` is `"buy"` or `"sell"`.
+pub fn order(instrument_name: &str, direction: &str) -> Fixture {
+    let currency = instrument_name.split('-').next().unwrap_or(instrument_name);
+    Fixture(json!({
+        "web": false,
+        "time_in_force": "good_til_cancelled",
+        "risk_reducing": false,
+        "replaced": false,
+        "reduce_only": false,
+        "price": 49_500.0,
+        "post_only": false,
+        "order_type": "limit",
+        "order_state": "open",
+        "order_id": format!("{}-1873252521", currency),
+        "max_show": 100.0,
+        "last_update_timestamp": FIXTURE_TIMESTAMP,
+        "label": "",
+        "is_rebalance": false,
+        "is_liquidation": false,
+        "instrument_name": instrument_name,
+        "filled_amount": 0.0,
+        "direction": direction,
+        "creation_timestamp": FIXTURE_TIMESTAMP,
+        "average_price": 0.0,
+        "api": true,
+        "amount": 100.0
+    }))
+}
+
+/// `private/buy` or `private/sell` result: the placed order and no fills yet
+pub fn order_response(instrument_name: &str, direction: &str) -> Fixture {
+    Fixture(json!({
+        "trades": [],
+        "order": order(instrument_name, direction).into_value()
+    }))
+}
+
+/// `private/get_account_summary` result for a standard-margin account
+///
+/// Account fields and the currency's balances, margins and greeks side by side,
+/// as Deribit returns them.
+pub fn account_summary(currency: &str) -> Fixture {
+    let mut summary = json!({
+        "id": 41_226,
+        "email": "trader@example.com",
+        "system_name": "trader",
+        "username": "trader",
+        "type": "main",
+        "creation_timestamp": 1_650_000_000_000_u64,
+        "login_enabled": true,
+        "security_keys_enabled": false,
+        "mmp_enabled": false,
+        "interuser_transfers_enabled": false,
+        "self_trading_reject_mode": "cancel_maker",
+        "self_trading_extended_to_subaccounts": false,
+        "block_rfq_self_match_prevention": false
+    });
+    let balances = json!({
+        "currency": currency,
+        "balance": 1.2576,
+        "equity": 1.2601,
+        "available_funds": 1.1873,
+        "available_withdrawal_funds": 1.1848,
+        "margin_balance": 1.2601,
+        "initial_margin": 0.0728,
+        "maintenance_margin": 0.0534,
+        "projected_initial_margin": 0.0728,
+        "projected_maintenance_margin": 0.0534,
+        "total_pl": 0.0025,
+        "session_rpl": 0.0,
+        "session_upl": 0.0025,
+        "futures_pl": 0.0025,
+        "futures_session_rpl": 0.0,
+        "futures_session_upl": 0.0025,
+        "options_pl": 0.0,
+        "options_session_rpl": 0.0,
+        "options_session_upl": 0.0,
+        "options_value": 0.0,
+        "options_delta": 0.0,
+        "options_gamma": 0.0,
+        "options_theta": 0.0,
+        "options_vega": 0.0,
+        "delta_total": 0.2001,
+        "projected_delta_total": 0.2001,
+        "fee_balance": 0.0,
+        "spot_reserve": 0.0,
+        "additional_reserve": 0.0,
+        "estimated_liquidation_ratio": 0.0424,
+        "margin_model": "segregated_sm",
+        "cross_collateral_enabled": false,
+        "portfolio_margining_enabled": false,
+        "deposit_address": "2N8udZGBc1hLRCFsU9kGwMPpmYUwMFTuCwB"
+    });
+    if let (Value::Object(summary), Value::Object(balances)) = (&mut summary, balances) {
+        summary.extend(balances);
+    }
+    Fixture(summary)
+}
+
+/// `public/auth` result granting a read-write token for one hour
+pub fn auth_token() -> Fixture {
+    Fixture(json!({
+        "access_token": "mock_access_token",
+        "expires_in": 3600,
+        "refresh_token": "mock_refresh_token",
+        "scope": "account:read_write trade:read_write wallet:read_write",
+        "token_type": "bearer"
+    }))
+}
@@ -0,0 +1,267 @@
+/******************************************************************************
+   Author: Joaquín Béjar García
+   Email: jb@taunais.com
+   Date: 15/10/26
+******************************************************************************/
+use crate::DeribitHttpClient;
+use crate::config::HttpConfig;
+use crate::interceptor::HookFuture;
+use crate::testing::fixtures;
+use crate::transport::{HttpTransport, TransportError, json_response};
+use serde_json::{Value, json};
+use std::collections::HashMap;
+use std::ops::Deref;
+use std::sync::{Arc, Mutex, PoisonError};
+
+/// Instrument the preloaded fixtures describe
+pub const MOCK_INSTRUMENT: &str = "BTC-PERPETUAL";
+
+/// Client id accepted by the mocks
+pub const MOCK_CLIENT_ID: &str = "mock_client_id";
+
+/// Client secret accepted by the mocks
+pub const MOCK_CLIENT_SECRET: &str = "mock_client_secret";
+
+/// Deribit's error code for an unknown JSON-RPC method
+const METHOD_NOT_FOUND: i64 = -32601;
+
+/// Client whose requests are answered from canned responses
+///
+/// Wraps a [`DeribitHttpClient`] whose [transport](crate::transport) never
+/// touches the network: every call of a method gets the result or error
+/// registered for it, and methods without one fail with Deribit's
+/// "Method not found" error. The client dereferences to
+/// [`DeribitHttpClient`], so code under test can take either. Credentials are
+/// preset and `public/auth` is answered with [`fixtures::auth_token`], so
+/// private endpoints work too.
+///
+/// [`new`](Self::new) preloads the [`fixtures`] for [`MOCK_INSTRUMENT`]:
+/// `public/get_time`, `public/ticker`, `public/get_order_book`,
+/// `private/get_account_summary`, `private/buy`, `private/sell`,
+/// `private/get_order_state`, `private/get_open_orders`, `private/cancel` and
+/// `private/cancel_all`.
+///
+/// # Examples
+///
+/// ```rust
+/// use deribit_http::testing::{MockDeribitClient, fixtures};
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let mock = MockDeribitClient::new()
+///     .with_result("public/ticker", fixtures::ticker("BTC-PERPETUAL").set("mark_price", 42_000.0));
+///
+/// let ticker = mock.get_ticker("BTC-PERPETUAL").await?;
+/// assert_eq!(ticker.mark_price, 42_000.0);
+/// assert_eq!(mock.requests_for("public/ticker").len(), 1);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct MockDeribitClient {
+    client: DeribitHttpClient,
+    transport: Arc<MockTransport>,
+}
+
+/// Request received by a [`MockDeribitClient`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct MockRequest {
+    /// JSON-RPC method, e.g. `private/buy`
+    pub method: String,
+    /// Query parameters of a GET, or the `params` member of a JSON-RPC POST
+    pub params: Value,
+}
+
+impl MockDeribitClient {
+    /// Mock preloaded with the default fixtures
+    pub fn new() -> Self {
+        Self::empty()
+            .with_result("public/get_time", fixtures::FIXTURE_TIMESTAMP)
+            .with_result("public/ticker", fixtures::ticker(MOCK_INSTRUMENT))
+            .with_result(
+                "public/get_order_book",
+                fixtures::order_book(MOCK_INSTRUMENT),
+            )
+            .with_result(
+                "private/get_account_summary",
+                fixtures::account_summary("BTC"),
+            )
+            .with_result(
+                "private/buy",
+                fixtures::order_response(MOCK_INSTRUMENT, "buy"),
+            )
+            .with_result(
+                "private/sell",
+                fixtures::order_response(MOCK_INSTRUMENT, "sell"),
+            )
+            .with_result(
+                "private/get_order_state",
+                fixtures::order(MOCK_INSTRUMENT, "buy"),
+            )
+            .with_result(
+                "private/get_open_orders",
+                json!([fixtures::order(MOCK_INSTRUMENT, "buy").into_value()]),
+            )
+            .with_result(
+                "private/cancel",
+                fixtures::order(MOCK_INSTRUMENT, "buy").set("order_state", "cancelled"),
+            )
+            .with_result("private/cancel_all", 1)
+    }
+
+    /// Mock that only answers `public/auth`
+    pub fn empty() -> Self {
+        let transport = Arc::new(MockTransport::default());
+        let config = HttpConfig::testnet()
+            .with_oauth2(MOCK_CLIENT_ID.to_string(), MOCK_CLIENT_SECRET.to_string())
+            .with_transport(transport.clone());
+        let mock = Self {
+            client: DeribitHttpClient::with_config(config),
+            transport,
+        };
+        mock.with_result("public/auth", fixtures::auth_token())
+    }
+
+    /// Answer every call of `method` with `result`
+    ///
+    /// `method` is the JSON-RPC method without leading slash, e.g.
+    /// `"private/buy"`. Replaces any result or error set before.
+    #[must_use]
+    pub fn with_result(self, method: &str, result: impl Into<Value>) -> Self {
+        self.transport.set(
+            method,
+            json!({"jsonrpc": "2.0", "result": result.into()}),
+            200,
+        );
+        self
+    }
+
+    /// Answer every call of `method` with a Deribit error
+    ///
+    /// See [`DeribitErrorCode`](crate::DeribitErrorCode) for the codes.
+    #[must_use]
+    pub fn with_error(self, method: &str, code: i64, message: &str) -> Self {
+        self.transport.set(
+            method,
+            json!({"jsonrpc": "2.0", "error": {"code": code, "message": message}}),
+            400,
+        );
+        self
+    }
+
+    /// The wrapped client
+    pub fn client(&self) -> &DeribitHttpClient {
+        &self.client
+    }
+
+    /// Every request received so far, in order, token requests included
+    pub fn requests(&self) -> Vec<MockRequest> {
+        self.transport.requests()
+    }
+
+    /// Parameters of each call of `method` received so far
+    pub fn requests_for(&self, method: &str) -> Vec<Value> {
+        self.requests()
+            .into_iter()
+            .filter(|request| request.method == method)
+            .map(|request| request.params)
+            .collect()
+    }
+}
+
+impl Default for MockDeribitClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Deref for MockDeribitClient {
+    type Target = DeribitHttpClient;
+
+    fn deref(&self) -> &DeribitHttpClient {
+        &self.client
+    }
+}
+
+/// Canned responses by method, shared by a mock and its clones
+#[derive(Debug, Default)]
+struct MockTransport {
+    responses: Mutex<HashMap<String, (Value, u16)>>,
+    requests: Mutex<Vec<MockRequest>>,
+}
+
+impl MockTransport {
+    fn set(&self, method: &str, body: Value, status: u16) {
+        self.responses
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(method.trim_start_matches('/').to_string(), (body, status));
+    }
+
+    fn requests(&self) -> Vec<MockRequest> {
+        self.requests
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    fn respond(&self, request: &reqwest::Request) -> reqwest::Response {
+        let received = MockRequest {
+            method: method_of(request),
+            params: params_of(request),
+        };
+        let response = self
+            .responses
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(&received.method)
+            .cloned();
+        self.requests
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push(received);
+
+        let (body, status) = response.unwrap_or_else(|| {
+            let error = json!({"code": METHOD_NOT_FOUND, "message": "Method not found"});
+            (json!({"jsonrpc": "2.0", "error": error}), 400)
+        });
+        json_response(status, body.to_string())
+    }
+}
+
+impl HttpTransport for MockTransport {
+    fn send(
+        &self,
+        request: reqwest::Request,
+    ) -> HookFuture<'_, Result<reqwest::Response, TransportError>> {
+        let response = self.respond(&request);
+        Box::pin(async move { Ok(response) })
+    }
+}
+
+/// JSON-RPC method a request URL addresses
+fn method_of(request: &reqwest::Request) -> String {
+    let path = request.url().path();
+    let start = path
+        .find("/public/")
+        .or_else(|| path.find("/private/"))
+        .map_or(0, |index| index + 1);
+    path[start..].to_string()
+}
+
+/// Parameters carried by the query string or the JSON-RPC body
+fn params_of(request: &reqwest::Request) -> Value {
+    let body_params = request
+        .body()
+        .and_then(|body| body.as_bytes())
+        .and_then(|bytes| serde_json::from_slice::<Value>(bytes).ok())
+        .and_then(|mut body| body.get_mut("params").map(Value::take));
+    body_params.unwrap_or_else(|| {
+        request
+            .url()
+            .query_pairs()
+            .map(|(key, value)| (key.into_owned(), Value::String(value.into_owned())))
+            .collect::<serde_json::Map<_, _>>()
+            .into()
+    })
+}
@@ -0,0 +1,67 @@
+//! Test utilities for code built on this client
+//!
+//! Available with the `testing` feature. Intended for integration tests that
+//! run against the Deribit testnet and need to leave the account clean, for
+//! exercising error handling and pacing against simulated server behaviour,
+//! and for unit testing trading logic offline against a
+//! [`MockDeribitClient`] serving canned [`fixtures`].
+//!
+//! [`MockDeribitClient`]: crate::testing::MockDeribitClient
+//! [`fixtures`]: crate::testing::fixtures
+
+/// Random injection of realistic Deribit errors
+pub mod chaos;
+/// Canned responses captured from Deribit
+pub mod fixtures;
+/// Client answering from canned responses, without network access
+pub mod mock;
+/// Order tracking and cleanup for integration tests
+pub mod order_guard;
+/// Emulation of Deribit's credit-based rate limits
+pub mod rate_limit;
+/// Embedded Deribit-compatible HTTP server (requires the `mock-server` feature)
+#[cfg(feature = "mock-server")]
+pub mod server;
+
+pub use chaos::{ChaosConfig, ChaosFault, EndpointChaos};
+pub use fixtures::Fixture;
+pub use mock::{
+    MOCK_CLIENT_ID, MOCK_CLIENT_SECRET, MOCK_INSTRUMENT, MockDeribitClient, MockRequest,
+};
+pub use order_guard::{TestOrderGuard, cancel_stale_test_orders};
+pub use rate_limit::{CreditLimits, RateLimitSimulation};
+#[cfg(feature = "mock-server")]
+pub use server::{MockServer, Scenario};
+
+use crate::DeribitHttpClient;
+use chaos::Chaos;
+use rate_limit::CreditSimulator;
+use std::sync::Arc;
+
+/// Simulated server behaviour attached to a client
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Simulation {
+    pub(crate) chaos: Option<Arc<Chaos>>,
+    pub(crate) credits: Option<Arc<CreditSimulator>>,
+}
+
+impl DeribitHttpClient {
+    /// Response to return instead of sending the request, if the simulated
+    /// server rejects it
+    ///
+    /// Rate limits are checked first, as Deribit rejects over-limit requests
+    /// before processing them.
+    pub(crate) fn simulated_response(&self, url: &str) -> Option<reqwest::Response> {
+        if let Some(credits) = &self.simulation.credits
+            && !credits.admit(url)
+        {
+            return Some(chaos::error_response(
+                429,
+                rate_limit::too_many_requests_body(),
+            ));
+        }
+
+        let fault = self.simulation.chaos.as_ref()?.roll(url)?;
+        Some(chaos::error_response(fault.http_status(), fault.body()))
+    }
+}
@@ -0,0 +1,212 @@
+/******************************************************************************
+   Author: Joaquín Béjar García
+   Email: jb@taunais.com
+   Date: 15/10/26
+******************************************************************************/
+use crate::DeribitHttpClient;
+use crate::error::{HttpError, HttpErrorKind};
+use crate::model::response::order::OrderResponse;
+use std::sync::{Mutex, PoisonError};
+use tracing::{debug, warn};
+
+/// Tracks orders created by a test and cancels the ones still open
+///
+/// Register every order the test places with [`track`](Self::track) or
+/// [`track_response`](Self::track_response). Call [`cleanup`](Self::cleanup)
+/// at the end of the test; if the guard is dropped without it (an early return
+/// or a panic), the remaining orders are cancelled from `Drop` on a separate
+/// thread with its own runtime and a fresh client built from the same
+/// configuration.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use deribit_http::DeribitHttpClient;
+/// use deribit_http::testing::TestOrderGuard;
+///
+/// # async fn example() -> Result<(), deribit_http::HttpError> {
+/// let client = DeribitHttpClient::new();
+/// let guard = TestOrderGuard::new(client.clone());
+/// // let response = client.buy_order(request).await?;
+/// // guard.track_response(&response);
+/// guard.cleanup().await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct TestOrderGuard {
+    client: DeribitHttpClient,
+    order_ids: Mutex<Vec<String>>,
+}
+
+impl TestOrderGuard {
+    /// Create a guard that cancels orders through `client`
+    pub fn new(client: DeribitHttpClient) -> Self {
+        Self {
+            client,
+            order_ids: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// The client used to cancel orders
+    pub fn client(&self) -> &DeribitHttpClient {
+        &self.client
+    }
+
+    /// Register an order to cancel on cleanup
+    pub fn track(&self, order_id: impl Into<String>) {
+        let order_id = order_id.into();
+        let mut order_ids = self.lock();
+        if !order_ids.contains(&order_id) {
+            order_ids.push(order_id);
+        }
+    }
+
+    /// Register the order from a `buy`/`sell`/`edit` response
+    pub fn track_response(&self, response: &OrderResponse) {
+        self.track(response.order.order_id.clone());
+    }
+
+    /// Stop tracking an order, e.g. after the test cancelled it itself
+    ///
+    /// Returns whether the order was tracked.
+    pub fn untrack(&self, order_id: &str) -> bool {
+        let mut order_ids = self.lock();
+        let len = order_ids.len();
+        order_ids.retain(|id| id != order_id);
+        order_ids.len() != len
+    }
+
+    /// Ids of the orders currently tracked
+    pub fn tracked(&self) -> Vec<String> {
+        self.lock().clone()
+    }
+
+    /// Cancel every tracked order that is still open
+    ///
+    /// Orders that are already filled or cancelled are skipped. The guard is
+    /// empty afterwards, so dropping it does nothing further.
+    ///
+    /// # Returns
+    ///
+    /// Returns the number of orders cancelled.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error other than a closed order; every tracked order
+    /// is still attempted.
+    pub async fn cleanup(&self) -> Result<usize, HttpError> {
+        let order_ids = std::mem::take(&mut *self.lock());
+        cancel_orders(&self.client, &order_ids).await
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Vec<String>> {
+        self.order_ids
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl Drop for TestOrderGuard {
+    fn drop(&mut self) {
+        let order_ids = std::mem::take(
+            self.order_ids
+                .get_mut()
+                .unwrap_or_else(PoisonError::into_inner),
+        );
+        if order_ids.is_empty() {
+            return;
+        }
+
+        // The caller's runtime may be blocked on this very drop, so pooled
+        // connections from `self.client` cannot be relied on here.
+        let config = self.client.config().clone();
+        let handle = std::thread::spawn(move || {
+            let runtime = tokio::runtime::Builder::new_current_thread()
+                .enable_all()
+                .build()
+                .map_err(|e| {
+                    HttpErrorKind::ConfigError(format!("Failed to build runtime: {}", e))
+                })?;
+            let client = DeribitHttpClient::with_config(config);
+            runtime.block_on(cancel_orders(&client, &order_ids))
+        });
+
+        match handle.join() {
+            Ok(Ok(cancelled)) => debug!(cancelled, "Cancelled test orders on drop"),
+            Ok(Err(e)) => warn!(error = %e, "Failed to cancel test orders on drop"),
+            Err(_) => warn!("Test order cleanup thread panicked"),
+        }
+    }
+}
+
+/// Cancel open orders whose label starts with `label_prefix`
+///
+/// Sweeps up orders left behind by earlier test runs that did not clean up,
+/// e.g. because the process was killed.
+///
+/// # Arguments
+///
+/// * `client` - Authenticated client
+/// * `label_prefix` - Label prefix used by the test orders
+///
+/// # Returns
+///
+/// Returns the number of orders cancelled.
+///
+/// # Errors
+///
+/// Returns `HttpErrorKind::ConfigError` for an empty prefix, which would match
+/// every unlabelled order, and propagates errors from listing open orders.
+pub async fn cancel_stale_test_orders(
+    client: &DeribitHttpClient,
+    label_prefix: &str,
+) -> Result<usize, HttpError> {
+    if label_prefix.is_empty() {
+        return Err(
+            HttpErrorKind::ConfigError("Label prefix must not be empty".to_string()).into(),
+        );
+    }
+
+    let order_ids: Vec<String> = client
+        .get_open_orders(None, None)
+        .await?
+        .into_iter()
+        .filter(|order| order.label.starts_with(label_prefix))
+        .map(|order| order.order_id)
+        .collect();
+
+    cancel_orders(client, &order_ids).await
+}
+
+/// Cancel each order, skipping those that are no longer open
+async fn cancel_orders(
+    client: &DeribitHttpClient,
+    order_ids: &[String],
+) -> Result<usize, HttpError> {
+    let mut cancelled = 0;
+    let mut first_error = None;
+
+    for order_id in order_ids {
+        match client.cancel_order(order_id).await {
+            Ok(_) => cancelled += 1,
+            Err(e) if is_order_closed(&e) => {
+                debug!(order_id = %order_id, "Test order already closed");
+            }
+            Err(e) => {
+                warn!(order_id = %order_id, error = %e, "Failed to cancel test order");
+                first_error.get_or_insert(e);
+            }
+        }
+    }
+
+    match first_error {
+        Some(e) => Err(e),
+        None => Ok(cancelled),
+    }
+}
+
+/// Deribit error 11044 `not_open_order`: the order was filled or cancelled
+fn is_order_closed(error: &HttpError) -> bool {
+    matches!(error.kind(), HttpErrorKind::Api { code: 11044, .. })
+}
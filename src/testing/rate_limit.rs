@@ -0,0 +1,116 @@
+/******************************************************************************
+   Author: Joaquín Béjar García
+   Email: jb@taunais.com
+   Date: 15/10/26
+******************************************************************************/
+use crate::DeribitHttpClient;
+use crate::rate_limit::CreditPool;
+use serde_json::json;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, PoisonError};
+
+pub use crate::rate_limit::{CreditLimits, TOO_MANY_REQUESTS_CODE, is_matching_engine};
+
+/// Credit scheme emulated by the rate limit simulation
+///
+/// Defaults to Deribit's limits for a default-tier account.
+///
+/// # Examples
+///
+/// ```rust
+/// use deribit_http::DeribitHttpClient;
+/// use deribit_http::testing::{CreditLimits, RateLimitSimulation};
+///
+/// let simulation = RateLimitSimulation {
+///     matching_engine: CreditLimits { max_credits: 2_000, refill_per_sec: 1_000, cost: 1_000 },
+///     ..Default::default()
+/// };
+/// let client = DeribitHttpClient::new().with_rate_limit_simulation(simulation);
+/// assert_eq!(client.rate_limit_rejections(), 0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimitSimulation {
+    /// Pool for all requests outside the matching engine
+    pub non_matching_engine: CreditLimits,
+    /// Pool for order entry, edits and cancels
+    pub matching_engine: CreditLimits,
+}
+
+impl Default for RateLimitSimulation {
+    fn default() -> Self {
+        Self {
+            non_matching_engine: CreditLimits::NON_MATCHING_ENGINE,
+            matching_engine: CreditLimits::MATCHING_ENGINE,
+        }
+    }
+}
+
+/// Server-side credit accounting shared by a client and its clones
+#[derive(Debug)]
+pub(crate) struct CreditSimulator {
+    non_matching_engine: Mutex<CreditPool>,
+    matching_engine: Mutex<CreditPool>,
+    rejections: AtomicU64,
+}
+
+impl CreditSimulator {
+    fn new(simulation: RateLimitSimulation) -> Self {
+        Self {
+            non_matching_engine: Mutex::new(CreditPool::new(simulation.non_matching_engine)),
+            matching_engine: Mutex::new(CreditPool::new(simulation.matching_engine)),
+            rejections: AtomicU64::new(0),
+        }
+    }
+
+    /// Charge a request to `url`, returning whether the server would accept it
+    pub(crate) fn admit(&self, url: &str) -> bool {
+        let pool = if is_matching_engine(url) {
+            &self.matching_engine
+        } else {
+            &self.non_matching_engine
+        };
+        let admitted = pool
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .try_charge();
+        if !admitted {
+            self.rejections.fetch_add(1, Ordering::Relaxed);
+        }
+        admitted
+    }
+}
+
+/// JSON-RPC body Deribit sends for an exhausted credit pool
+pub(crate) fn too_many_requests_body() -> String {
+    json!({
+        "jsonrpc": "2.0",
+        "error": {
+            "code": TOO_MANY_REQUESTS_CODE,
+            "message": "too_many_requests",
+        },
+        "testnet": true,
+    })
+    .to_string()
+}
+
+impl DeribitHttpClient {
+    /// Enable the rate limit simulation
+    ///
+    /// Requests made through this client and its clones are charged against
+    /// emulated Deribit credit pools before reaching the network; once a pool is
+    /// exhausted they fail with `too_many_requests` (10028) until it refills.
+    /// This validates the client's own pacing without live endpoints.
+    /// Authentication requests are not charged.
+    pub fn with_rate_limit_simulation(mut self, simulation: RateLimitSimulation) -> Self {
+        self.simulation.credits = Some(Arc::new(CreditSimulator::new(simulation)));
+        self
+    }
+
+    /// Number of requests rejected by the rate limit simulation so far
+    pub fn rate_limit_rejections(&self) -> u64 {
+        self.simulation
+            .credits
+            .as_ref()
+            .map_or(0, |credits| credits.rejections.load(Ordering::Relaxed))
+    }
+}
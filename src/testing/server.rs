@@ -0,0 +1,585 @@
+/******************************************************************************
+   Author: Joaquín Béjar García
+   Email: jb@taunais.com
+   Date: 15/10/26
+******************************************************************************/
+use crate::DeribitHttpClient;
+use crate::config::HttpConfig;
+use crate::error::DeribitErrorCode;
+use crate::testing::fixtures;
+use crate::testing::mock::{MOCK_CLIENT_ID, MOCK_CLIENT_SECRET, MockRequest};
+use http_body_util::{BodyExt, Full};
+use hyper::body::{Bytes, Incoming};
+use hyper::server::conn::http1;
+use hyper::service::service_fn;
+use hyper::{Request, Response};
+use hyper_util::rt::TokioIo;
+use serde_json::{Map, Value, json};
+use std::collections::{BTreeMap, HashMap};
+use std::convert::Infallible;
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::{SystemTime, UNIX_EPOCH};
+use tokio::net::TcpListener;
+use tokio::task::JoinHandle;
+use url::Url;
+
+/// Server behaviour to exercise, set with [`MockServer::set_scenario`]
+///
+/// The default scenario accepts the mock credentials, never rate limits and
+/// fills marketable orders in full while other limit orders rest.
+///
+/// # Examples
+///
+/// ```rust
+/// use deribit_http::testing::Scenario;
+///
+/// let scenario = Scenario {
+///     rate_limited_requests: 1,
+///     ..Scenario::partial_fills(0.25)
+/// };
+/// assert_eq!(scenario.fill_ratio, Some(0.25));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Scenario {
+    /// Reject every `public/auth` request with `invalid_credentials`
+    pub reject_auth: bool,
+    /// Answer this many of the next requests with HTTP 429 and
+    /// `too_many_requests`
+    pub rate_limited_requests: u32,
+    /// Share of each new order's amount filled on placement, at the order's
+    /// price or the touch for market orders; the remainder rests in the book
+    pub fill_ratio: Option<f64>,
+}
+
+impl Scenario {
+    /// Authentication always fails
+    pub fn auth_failure() -> Self {
+        Self {
+            reject_auth: true,
+            ..Self::default()
+        }
+    }
+
+    /// The next `requests` requests are rate limited
+    pub fn rate_limited(requests: u32) -> Self {
+        Self {
+            rate_limited_requests: requests,
+            ..Self::default()
+        }
+    }
+
+    /// Every new order fills `ratio` of its amount
+    pub fn partial_fills(ratio: f64) -> Self {
+        Self {
+            fill_ratio: Some(ratio.clamp(0.0, 1.0)),
+            ..Self::default()
+        }
+    }
+}
+
+/// Deribit-compatible HTTP server running in the test process
+///
+/// Serves the JSON-RPC-over-HTTP shape of the main endpoints on a local
+/// port: `public/auth`, `public/get_time`, `public/test`, `public/ticker`,
+/// `public/get_order_book`, `private/buy`, `private/sell`, `private/cancel`,
+/// `private/cancel_all`, `private/get_order_state`, `private/get_open_orders`,
+/// `private/get_open_orders_by_instrument`, `private/get_positions`,
+/// `private/get_position` and `private/get_account_summary`. Other methods
+/// fail with "Method not found". Orders, fills and positions are kept in
+/// memory; market data comes from [`fixtures`].
+///
+/// Unlike [`MockDeribitClient`](crate::testing::MockDeribitClient) requests
+/// cross a real socket, so timeouts, retries and connection handling run as
+/// in production. Requires the `mock-server` feature and a Tokio runtime;
+/// the server stops when dropped.
+///
+/// # Examples
+///
+/// ```rust
+/// use deribit_http::model::request::order::OrderRequest;
+/// use deribit_http::testing::{MockServer, Scenario};
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let server = MockServer::start().await;
+/// server.set_scenario(Scenario::partial_fills(0.5));
+/// let client = server.client();
+///
+/// let request = OrderRequest::builder()
+///     .instrument_name("BTC-PERPETUAL")
+///     .amount(100.0)
+///     .limit(49_000.0)
+///     .build()?;
+/// let response = client.buy_order(request).await?;
+/// assert_eq!(response.order.order_state, "open");
+/// assert_eq!(response.trades.len(), 1);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct MockServer {
+    address: SocketAddr,
+    exchange: Arc<Mutex<Exchange>>,
+    task: JoinHandle<()>,
+}
+
+impl MockServer {
+    /// Start a server on a free local port
+    ///
+    /// # Panics
+    ///
+    /// Panics if no local port can be bound.
+    pub async fn start() -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0")
+            .await
+            .expect("failed to bind mock server");
+        let address = listener.local_addr().expect("mock server has no address");
+        let exchange = Arc::new(Mutex::new(Exchange::default()));
+
+        let task = tokio::spawn(serve(listener, exchange.clone()));
+        Self {
+            address,
+            exchange,
+            task,
+        }
+    }
+
+    /// API base URL, e.g. `http://127.0.0.1:41234/api/v2`
+    pub fn url(&self) -> String {
+        format!("http://{}/api/v2", self.address)
+    }
+
+    /// Testnet configuration pointing at this server with the mock credentials
+    pub fn config(&self) -> HttpConfig {
+        HttpConfig {
+            base_url: Url::parse(&self.url()).expect("mock server URL is valid"),
+            ..HttpConfig::testnet()
+        }
+        .with_oauth2(MOCK_CLIENT_ID.to_string(), MOCK_CLIENT_SECRET.to_string())
+    }
+
+    /// New client using [`config`](Self::config)
+    pub fn client(&self) -> DeribitHttpClient {
+        DeribitHttpClient::with_config(self.config())
+    }
+
+    /// Switch to `scenario` for the following requests
+    pub fn set_scenario(&self, scenario: Scenario) {
+        self.exchange().scenario = scenario;
+    }
+
+    /// Every request received so far, in order
+    pub fn requests(&self) -> Vec<MockRequest> {
+        self.exchange().requests.clone()
+    }
+
+    /// Parameters of each call of `method` received so far
+    pub fn requests_for(&self, method: &str) -> Vec<Value> {
+        self.exchange()
+            .requests
+            .iter()
+            .filter(|request| request.method == method)
+            .map(|request| request.params.clone())
+            .collect()
+    }
+
+    fn exchange(&self) -> std::sync::MutexGuard<'_, Exchange> {
+        self.exchange.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl Drop for MockServer {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+/// Accept connections until the server is dropped
+async fn serve(listener: TcpListener, exchange: Arc<Mutex<Exchange>>) {
+    loop {
+        let Ok((stream, _)) = listener.accept().await else {
+            continue;
+        };
+        let exchange = exchange.clone();
+        tokio::spawn(async move {
+            let service = service_fn(move |request| {
+                let exchange = exchange.clone();
+                async move { Ok::<_, Infallible>(handle(request, &exchange).await) }
+            });
+            let _ = http1::Builder::new()
+                .serve_connection(TokioIo::new(stream), service)
+                .await;
+        });
+    }
+}
+
+/// Answer one HTTP request
+async fn handle(request: Request<Incoming>, exchange: &Mutex<Exchange>) -> Response<Full<Bytes>> {
+    let (parts, body) = request.into_parts();
+    let body = body
+        .collect()
+        .await
+        .map(|body| body.to_bytes())
+        .unwrap_or_default();
+
+    let path = parts.uri.path();
+    let method = path
+        .find("/public/")
+        .or_else(|| path.find("/private/"))
+        .map_or(path, |index| &path[index + 1..])
+        .to_string();
+    let rpc: Value = serde_json::from_slice(&body).unwrap_or(Value::Null);
+    let params = match rpc.get("params") {
+        Some(Value::Object(params)) => params.clone(),
+        _ => parts
+            .uri
+            .query()
+            .map(|query| {
+                url::form_urlencoded::parse(query.as_bytes())
+                    .map(|(key, value)| (key.into_owned(), Value::String(value.into_owned())))
+                    .collect()
+            })
+            .unwrap_or_default(),
+    };
+    let token = parts
+        .headers
+        .get(hyper::header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.split_once(' '))
+        .map(|(_, token)| token.to_string());
+
+    let us_in = now_ms() * 1_000;
+    let outcome = exchange
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .dispatch(&method, params, token.as_deref());
+
+    let mut envelope = json!({
+        "jsonrpc": "2.0",
+        "id": rpc.get("id").cloned().unwrap_or(json!(0)),
+        "usIn": us_in,
+        "usOut": now_ms() * 1_000,
+        "usDiff": 0,
+        "testnet": true
+    });
+    let status = match outcome {
+        Ok(result) => {
+            envelope["result"] = result;
+            200
+        }
+        Err(Rejection { status, code }) => {
+            envelope["error"] = json!({"code": code.code(), "message": code.as_str()});
+            status
+        }
+    };
+    let mut response = Response::new(Full::new(Bytes::from(envelope.to_string())));
+    *response.status_mut() = hyper::StatusCode::from_u16(status).unwrap_or_default();
+    response.headers_mut().insert(
+        hyper::header::CONTENT_TYPE,
+        hyper::header::HeaderValue::from_static("application/json"),
+    );
+    response
+}
+
+/// Error response: HTTP status and Deribit error
+struct Rejection {
+    status: u16,
+    code: DeribitErrorCode,
+}
+
+impl From<DeribitErrorCode> for Rejection {
+    fn from(code: DeribitErrorCode) -> Self {
+        Rejection { status: 400, code }
+    }
+}
+
+/// In-memory exchange state
+#[derive(Debug, Default)]
+struct Exchange {
+    scenario: Scenario,
+    requests: Vec<MockRequest>,
+    tokens: Vec<String>,
+    orders: BTreeMap<String, Value>,
+    positions: HashMap<String, PositionState>,
+    next_id: u64,
+}
+
+/// Net position in one instrument
+#[derive(Debug, Default, Clone, Copy)]
+struct PositionState {
+    size: f64,
+    average_price: f64,
+}
+
+impl Exchange {
+    fn dispatch(
+        &mut self,
+        method: &str,
+        params: Map<String, Value>,
+        token: Option<&str>,
+    ) -> Result<Value, Rejection> {
+        self.requests.push(MockRequest {
+            method: method.to_string(),
+            params: Value::Object(params.clone()),
+        });
+
+        if self.scenario.rate_limited_requests > 0 {
+            self.scenario.rate_limited_requests -= 1;
+            return Err(Rejection {
+                status: 429,
+                code: DeribitErrorCode::TooManyRequests,
+            });
+        }
+        if method.starts_with("private/")
+            && !token.is_some_and(|token| self.tokens.iter().any(|issued| issued == token))
+        {
+            return Err(DeribitErrorCode::Unauthorized.into());
+        }
+
+        let instrument = text(&params, "instrument_name").unwrap_or(super::MOCK_INSTRUMENT);
+        match method {
+            "public/auth" => self.authenticate(&params),
+            "public/get_time" => Ok(json!(now_ms())),
+            "public/test" => Ok(json!({"version": "1.2.26"})),
+            "public/ticker" => Ok(fixtures::ticker(instrument).into_value()),
+            "public/get_order_book" => Ok(fixtures::order_book(instrument).into_value()),
+            "private/buy" => Ok(self.place(&params, "buy")),
+            "private/sell" => Ok(self.place(&params, "sell")),
+            "private/cancel" => self.cancel(text(&params, "order_id").unwrap_or_default()),
+            "private/cancel_all" => Ok(json!(self.cancel_all())),
+            "private/get_order_state" => self
+                .orders
+                .get(text(&params, "order_id").unwrap_or_default())
+                .cloned()
+                .ok_or_else(|| DeribitErrorCode::OrderNotFound.into()),
+            "private/get_open_orders" => Ok(self.open_orders(|_| true)),
+            "private/get_open_orders_by_instrument" => {
+                Ok(self.open_orders(|order| order["instrument_name"] == instrument))
+            }
+            "private/get_positions" => Ok(self
+                .positions
+                .keys()
+                .map(|instrument| self.position(instrument))
+                .collect()),
+            "private/get_position" => Ok(self.position(instrument)),
+            "private/get_account_summary" => {
+                let currency = text(&params, "currency").unwrap_or("BTC");
+                Ok(fixtures::account_summary(currency).into_value())
+            }
+            _ => Err(Rejection {
+                status: 400,
+                code: DeribitErrorCode::MethodNotFound,
+            }),
+        }
+    }
+
+    /// Grant a token for the mock credentials or a refresh token
+    fn authenticate(&mut self, params: &Map<String, Value>) -> Result<Value, Rejection> {
+        let valid = match text(params, "grant_type") {
+            Some("client_credentials") => {
+                text(params, "client_id") == Some(MOCK_CLIENT_ID)
+                    && text(params, "client_secret") == Some(MOCK_CLIENT_SECRET)
+            }
+            Some("refresh_token") => text(params, "refresh_token")
+                .is_some_and(|refresh| refresh.starts_with("mock_refresh_token")),
+            _ => false,
+        };
+        if self.scenario.reject_auth || !valid {
+            return Err(DeribitErrorCode::InvalidCredentials.into());
+        }
+
+        self.next_id += 1;
+        let access_token = format!("mock_access_token_{}", self.next_id);
+        self.tokens.push(access_token.clone());
+        Ok(fixtures::auth_token()
+            .set("access_token", access_token)
+            .set(
+                "refresh_token",
+                format!("mock_refresh_token_{}", self.next_id),
+            )
+            .into_value())
+    }
+
+    /// Accept an order, fill it per the scenario and return `{order, trades}`
+    fn place(&mut self, params: &Map<String, Value>, direction: &str) -> Value {
+        let instrument = text(params, "instrument_name")
+            .unwrap_or(super::MOCK_INSTRUMENT)
+            .to_string();
+        let amount = number(params, "amount")
+            .or_else(|| number(params, "contracts"))
+            .unwrap_or_default();
+        let order_type = text(params, "type").unwrap_or("limit").to_string();
+        let book: crate::model::book::OrderBook = fixtures::order_book(&instrument)
+            .parse()
+            .expect("order book fixture is valid");
+        let touch = match direction {
+            "buy" => book.best_ask().unwrap_or_default(),
+            _ => book.best_bid().unwrap_or_default(),
+        };
+        let price = number(params, "price").unwrap_or(touch);
+        let marketable = order_type == "market"
+            || match direction {
+                "buy" => price >= touch,
+                _ => price <= touch,
+            };
+        let fill_ratio = self
+            .scenario
+            .fill_ratio
+            .unwrap_or(if marketable { 1.0 } else { 0.0 });
+        let filled = amount * fill_ratio;
+        let fill_price = if order_type == "market" { touch } else { price };
+
+        self.next_id += 1;
+        let currency = instrument.split('-').next().unwrap_or_default();
+        let order_id = format!("{}-{}", currency, self.next_id);
+        let now = now_ms();
+        let order = fixtures::order(&instrument, direction)
+            .set("order_id", &order_id)
+            .set("order_type", &order_type)
+            .set("amount", amount)
+            .set("price", price)
+            .set("filled_amount", filled)
+            .set("average_price", if filled > 0.0 { fill_price } else { 0.0 })
+            .set(
+                "order_state",
+                if filled >= amount { "filled" } else { "open" },
+            )
+            .set("label", text(params, "label").unwrap_or_default())
+            .set("post_only", boolean(params, "post_only"))
+            .set("reduce_only", boolean(params, "reduce_only"))
+            .set(
+                "time_in_force",
+                text(params, "time_in_force").unwrap_or("good_til_cancelled"),
+            )
+            .set("creation_timestamp", now)
+            .set("last_update_timestamp", now)
+            .into_value();
+        self.orders.insert(order_id.clone(), order.clone());
+
+        let mut trades = Vec::new();
+        if filled > 0.0 {
+            self.fill(&instrument, direction, filled, fill_price);
+            trades.push(json!({
+                "trade_id": format!("{}-T{}", currency, self.next_id),
+                "trade_seq": self.next_id,
+                "order_id": order_id,
+                "order_type": order_type,
+                "instrument_name": instrument,
+                "direction": direction,
+                "amount": filled,
+                "price": fill_price,
+                "index_price": book.best_bid().unwrap_or_default(),
+                "mark_price": fill_price,
+                "fee": 0.0,
+                "fee_currency": currency,
+                "label": order["label"],
+                "liquidity": "T",
+                "self_trade": false,
+                "state": order["order_state"],
+                "tick_direction": 0,
+                "timestamp": now
+            }));
+        }
+        json!({"order": order, "trades": trades})
+    }
+
+    /// Update the position in `instrument` with a fill
+    fn fill(&mut self, instrument: &str, direction: &str, amount: f64, price: f64) {
+        let signed = if direction == "buy" { amount } else { -amount };
+        let position = self.positions.entry(instrument.to_string()).or_default();
+        let size = position.size + signed;
+        position.average_price = if position.size == 0.0 || position.size.signum() != size.signum()
+        {
+            price
+        } else if size.abs() > position.size.abs() {
+            (position.average_price * position.size.abs() + price * amount) / size.abs()
+        } else {
+            position.average_price
+        };
+        position.size = size;
+    }
+
+    fn cancel(&mut self, order_id: &str) -> Result<Value, Rejection> {
+        let order = self
+            .orders
+            .get_mut(order_id)
+            .ok_or(DeribitErrorCode::OrderNotFound)?;
+        if order["order_state"] != "open" {
+            return Err(DeribitErrorCode::NotOpenOrder.into());
+        }
+        order["order_state"] = json!("cancelled");
+        order["last_update_timestamp"] = json!(now_ms());
+        Ok(order.clone())
+    }
+
+    fn cancel_all(&mut self) -> usize {
+        let open: Vec<String> = self
+            .orders
+            .iter()
+            .filter(|(_, order)| order["order_state"] == "open")
+            .map(|(order_id, _)| order_id.clone())
+            .collect();
+        for order_id in &open {
+            let _ = self.cancel(order_id);
+        }
+        open.len()
+    }
+
+    fn open_orders(&self, filter: impl Fn(&Value) -> bool) -> Value {
+        self.orders
+            .values()
+            .filter(|order| order["order_state"] == "open" && filter(order))
+            .cloned()
+            .collect()
+    }
+
+    fn position(&self, instrument: &str) -> Value {
+        let position = self.positions.get(instrument).copied().unwrap_or_default();
+        let direction = match position.size {
+            size if size > 0.0 => "buy",
+            size if size < 0.0 => "sell",
+            _ => "zero",
+        };
+        json!({
+            "instrument_name": instrument,
+            "kind": "future",
+            "direction": direction,
+            "size": position.size,
+            "average_price": position.average_price,
+            "mark_price": position.average_price,
+            "floating_profit_loss": 0.0,
+            "realized_profit_loss": 0.0,
+            "total_profit_loss": 0.0
+        })
+    }
+}
+
+/// String parameter
+fn text<'a>(params: &'a Map<String, Value>, key: &str) -> Option<&'a str> {
+    params.get(key).and_then(Value::as_str)
+}
+
+/// Numeric parameter, sent as a JSON number or a query string value
+fn number(params: &Map<String, Value>, key: &str) -> Option<f64> {
+    match params.get(key)? {
+        Value::String(value) => value.parse().ok(),
+        value => value.as_f64(),
+    }
+}
+
+/// Boolean parameter, sent as a JSON bool or a query string value
+fn boolean(params: &Map<String, Value>, key: &str) -> bool {
+    match params.get(key) {
+        Some(Value::String(value)) => value == "true",
+        Some(value) => value.as_bool().unwrap_or(false),
+        None => false,
+    }
+}
+
+fn now_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_millis() as u64)
+        .unwrap_or_default()
+}
@@ -0,0 +1,153 @@
+use super::{HttpTransport, TransportError};
+use crate::interceptor::HookFuture;
+use crate::sleep_compat::sleep;
+use futures_util::future::{Either, select};
+use std::sync::Arc;
+
+/// HTTP library that sends the client's requests
+///
+/// A narrower seam than [`HttpTransport`] for HTTP stacks other than
+/// `reqwest`: requests and responses are plain [`http`] types with buffered
+/// bodies, so implementations need no `reqwest` code. Wrap one in a
+/// [`BackendTransport`] to use it, which
+/// [`HttpConfig::with_backend`](crate::HttpConfig::with_backend) does.
+///
+/// # Examples
+///
+/// A backend answering every request without touching the network:
+///
+/// ```rust
+/// use deribit_http::interceptor::HookFuture;
+/// use deribit_http::transport::{HttpBackend, TransportError};
+/// use deribit_http::{DeribitHttpClient, HttpConfig};
+///
+/// struct FixedTime;
+///
+/// impl HttpBackend for FixedTime {
+///     fn execute(
+///         &self,
+///         request: http::Request<Vec<u8>>,
+///     ) -> HookFuture<'_, Result<http::Response<Vec<u8>>, TransportError>> {
+///         assert!(request.uri().path().ends_with("/public/get_time"));
+///         let body = br#"{"jsonrpc":"2.0","result":17}"#.to_vec();
+///         Box::pin(async { Ok(http::Response::new(body)) })
+///     }
+/// }
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = DeribitHttpClient::with_config(HttpConfig::default().with_backend(FixedTime));
+/// assert_eq!(client.get_server_time().await?, 17);
+/// # Ok(())
+/// # }
+/// ```
+pub trait HttpBackend: Send + Sync {
+    /// Send `request` and return the response, whatever its status
+    ///
+    /// As with [`HttpTransport::send`], only failures to get a response at all
+    /// are errors. The request timeout is enforced by the [`BackendTransport`].
+    fn execute(
+        &self,
+        request: http::Request<Vec<u8>>,
+    ) -> HookFuture<'_, Result<http::Response<Vec<u8>>, TransportError>>;
+}
+
+/// Shares one backend, and its connection pool, across clients
+impl<T: HttpBackend + ?Sized> HttpBackend for Arc<T> {
+    fn execute(
+        &self,
+        request: http::Request<Vec<u8>>,
+    ) -> HookFuture<'_, Result<http::Response<Vec<u8>>, TransportError>> {
+        self.as_ref().execute(request)
+    }
+}
+
+/// [`HttpTransport`] sending requests through an [`HttpBackend`]
+///
+/// Converts between `reqwest` and [`http`] types and fails requests that take
+/// longer than their timeout with [`TransportError::Timeout`].
+#[derive(Debug, Clone)]
+pub struct BackendTransport<B> {
+    backend: B,
+}
+
+impl<B: HttpBackend> BackendTransport<B> {
+    /// Transport sending requests through `backend`
+    pub fn new(backend: B) -> Self {
+        Self { backend }
+    }
+
+    /// The backend requests go through
+    pub fn backend(&self) -> &B {
+        &self.backend
+    }
+}
+
+impl<B: HttpBackend> HttpTransport for BackendTransport<B> {
+    fn send(
+        &self,
+        request: reqwest::Request,
+    ) -> HookFuture<'_, Result<reqwest::Response, TransportError>> {
+        Box::pin(async move {
+            let timeout = request.timeout().copied();
+            let exchange = self.backend.execute(into_http_request(request)?);
+            let response = match timeout {
+                Some(timeout) => match select(exchange, Box::pin(sleep(timeout))).await {
+                    Either::Left((response, _)) => response?,
+                    Either::Right(_) => {
+                        return Err(TransportError::Timeout(format!(
+                            "no response within {:?}",
+                            timeout
+                        )));
+                    }
+                },
+                None => exchange.await?,
+            };
+            Ok(reqwest::Response::from(response))
+        })
+    }
+}
+
+/// `request` as an [`http::Request`] with its body in memory
+fn into_http_request(request: reqwest::Request) -> Result<http::Request<Vec<u8>>, TransportError> {
+    let body = match request.body() {
+        Some(body) => body
+            .as_bytes()
+            .ok_or_else(|| {
+                TransportError::Other("streaming request bodies are not supported".into())
+            })?
+            .to_vec(),
+        None => Vec::new(),
+    };
+    let mut builder = http::Request::builder()
+        .method(request.method().clone())
+        .uri(request.url().as_str());
+    if let Some(headers) = builder.headers_mut() {
+        *headers = request.headers().clone();
+    }
+    builder
+        .body(body)
+        .map_err(|e| TransportError::Other(e.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::Client;
+
+    #[test]
+    fn test_into_http_request_keeps_method_headers_and_body() {
+        let request = Client::new()
+            .post("https://test.deribit.com/api/v2/private/buy")
+            .header("x-desk", "options")
+            .body(r#"{"amount":10}"#)
+            .build()
+            .unwrap();
+
+        let request = into_http_request(request).unwrap();
+        assert_eq!(request.method(), http::Method::POST);
+        assert_eq!(request.uri(), "https://test.deribit.com/api/v2/private/buy");
+        assert_eq!(request.headers()["x-desk"], "options");
+        assert_eq!(request.body(), br#"{"amount":10}"#);
+    }
+}
@@ -0,0 +1,115 @@
+use super::{HttpBackend, TransportError};
+use crate::config::PoolConfig;
+use crate::error::{HttpError, HttpErrorKind};
+use crate::interceptor::HookFuture;
+use http_body_util::{BodyExt, Full};
+use hyper::body::Bytes;
+use hyper_rustls::{HttpsConnector, HttpsConnectorBuilder};
+use hyper_util::client::legacy::Client;
+use hyper_util::client::legacy::connect::HttpConnector;
+use hyper_util::rt::TokioExecutor;
+use std::error::Error as _;
+
+/// [`HttpBackend`] on `hyper`, with `rustls` and the platform's root certificates
+///
+/// Speaks HTTP/1.1 and keeps a pool of connections. Proxies are not
+/// supported; use the default `reqwest` transport to connect through one.
+///
+/// # Examples
+///
+/// ```rust
+/// use deribit_http::transport::HyperBackend;
+/// use deribit_http::{DeribitHttpClient, HttpError};
+///
+/// # fn main() -> Result<(), HttpError> {
+/// let client = DeribitHttpClient::builder()
+///     .backend(HyperBackend::new()?)
+///     .build()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct HyperBackend {
+    client: Client<HttpsConnector<HttpConnector>, Full<Bytes>>,
+}
+
+impl std::fmt::Debug for HyperBackend {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("HyperBackend").finish_non_exhaustive()
+    }
+}
+
+impl HyperBackend {
+    /// Backend with `hyper`'s default connection pool
+    ///
+    /// # Errors
+    ///
+    /// Returns `HttpErrorKind::ConfigError` if the root certificates cannot be loaded.
+    pub fn new() -> Result<Self, HttpError> {
+        Self::with_pool(&PoolConfig::default())
+    }
+
+    /// Backend with the connection settings in `pool`
+    ///
+    /// # Errors
+    ///
+    /// Returns `HttpErrorKind::ConfigError` if the root certificates cannot be loaded.
+    pub fn with_pool(pool: &PoolConfig) -> Result<Self, HttpError> {
+        let mut http = HttpConnector::new();
+        http.enforce_http(false);
+        http.set_connect_timeout(pool.connect_timeout);
+        http.set_keepalive(pool.tcp_keepalive);
+        let https = HttpsConnectorBuilder::new()
+            .with_native_roots()
+            .map_err(|e| {
+                HttpErrorKind::ConfigError(format!("Failed to load root certificates: {}", e))
+            })?
+            .https_or_http()
+            .enable_http1()
+            .wrap_connector(http);
+
+        let mut builder = Client::builder(TokioExecutor::new());
+        if let Some(timeout) = pool.idle_timeout {
+            builder.pool_idle_timeout(timeout);
+        }
+        if let Some(max_idle) = pool.max_idle_per_host {
+            builder.pool_max_idle_per_host(max_idle);
+        }
+        Ok(Self {
+            client: builder.build(https),
+        })
+    }
+}
+
+impl HttpBackend for HyperBackend {
+    fn execute(
+        &self,
+        request: http::Request<Vec<u8>>,
+    ) -> HookFuture<'_, Result<http::Response<Vec<u8>>, TransportError>> {
+        Box::pin(async move {
+            let response = self
+                .client
+                .request(request.map(|body| Full::new(Bytes::from(body))))
+                .await
+                .map_err(|e| {
+                    // The legacy client's own message only names the failing stage
+                    let message = match e.source() {
+                        Some(source) => format!("{}: {}", e, source),
+                        None => e.to_string(),
+                    };
+                    if e.is_connect() {
+                        TransportError::Connect(message)
+                    } else {
+                        TransportError::Other(message)
+                    }
+                })?;
+            let (parts, body) = response.into_parts();
+            let body = body
+                .collect()
+                .await
+                .map_err(|e| TransportError::Other(e.to_string()))?
+                .to_bytes();
+            Ok(http::Response::from_parts(parts, body.to_vec()))
+        })
+    }
+}
@@ -0,0 +1,215 @@
+//! Pluggable HTTP transport
+//!
+//! Every request the client sends, token requests included, ends up in an
+//! [`HttpTransport`]. By default that is the client's `reqwest::Client`; set
+//! another one with [`HttpConfig::with_transport`](crate::HttpConfig::with_transport)
+//! to serve canned responses in unit tests or to send requests through a
+//! different runtime. Requests are still built with `reqwest`, so the transport
+//! only has to turn a [`reqwest::Request`] into a [`reqwest::Response`].
+//!
+//! On native targets, requests can also go through another HTTP library:
+//! implement [`HttpBackend`], which only deals in [`http`] types, and set it
+//! with [`HttpConfig::with_backend`](crate::HttpConfig::with_backend). The
+//! `hyper-backend` feature provides `HyperBackend`. On WASM, requests always
+//! go through `reqwest`'s `fetch` client.
+//!
+//! [`HttpTransport`]: crate::transport::HttpTransport
+//! [`HttpBackend`]: crate::transport::HttpBackend
+
+use crate::config::HttpConfig;
+use crate::interceptor::HookFuture;
+use reqwest::Client;
+use reqwest::header::{HeaderValue, USER_AGENT};
+use std::sync::Arc;
+
+#[cfg(not(target_arch = "wasm32"))]
+mod backend;
+#[cfg(all(feature = "hyper-backend", not(target_arch = "wasm32")))]
+mod hyper_backend;
+
+#[cfg(not(target_arch = "wasm32"))]
+pub use backend::{BackendTransport, HttpBackend};
+#[cfg(all(feature = "hyper-backend", not(target_arch = "wasm32")))]
+pub use hyper_backend::HyperBackend;
+
+/// Sends built requests and returns the server's responses
+///
+/// # Examples
+///
+/// A fake transport answering every request without touching the network:
+///
+/// ```rust
+/// use deribit_http::interceptor::HookFuture;
+/// use deribit_http::transport::{HttpTransport, TransportError, json_response};
+/// use deribit_http::{DeribitHttpClient, HttpConfig};
+///
+/// struct FixedTime;
+///
+/// impl HttpTransport for FixedTime {
+///     fn send(
+///         &self,
+///         request: reqwest::Request,
+///     ) -> HookFuture<'_, Result<reqwest::Response, TransportError>> {
+///         assert!(request.url().path().ends_with("/public/get_time"));
+///         Box::pin(async { Ok(json_response(200, r#"{"jsonrpc":"2.0","result":17}"#)) })
+///     }
+/// }
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = DeribitHttpClient::with_config(HttpConfig::default().with_transport(FixedTime));
+/// assert_eq!(client.get_server_time().await?, 17);
+/// # Ok(())
+/// # }
+/// ```
+pub trait HttpTransport: Send + Sync {
+    /// Send `request` and return the response, whatever its status
+    ///
+    /// Only failures to get a response at all are errors; HTTP error statuses
+    /// are returned as responses so the client can retry and report them.
+    fn send(
+        &self,
+        request: reqwest::Request,
+    ) -> HookFuture<'_, Result<reqwest::Response, TransportError>>;
+}
+
+impl HttpTransport for Client {
+    fn send(
+        &self,
+        request: reqwest::Request,
+    ) -> HookFuture<'_, Result<reqwest::Response, TransportError>> {
+        Box::pin(async move { self.execute(request).await.map_err(TransportError::from) })
+    }
+}
+
+/// Shares one transport, and the state it keeps, across clients
+impl<T: HttpTransport + ?Sized> HttpTransport for Arc<T> {
+    fn send(
+        &self,
+        request: reqwest::Request,
+    ) -> HookFuture<'_, Result<reqwest::Response, TransportError>> {
+        self.as_ref().send(request)
+    }
+}
+
+/// Failure to get a response from the server
+///
+/// The variant decides whether the client retries: connection failures are
+/// always retried, timeouts only for idempotent (public) requests.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum TransportError {
+    /// The connection could not be established
+    #[error("{0}")]
+    Connect(String),
+    /// No response arrived in time
+    #[error("{0}")]
+    Timeout(String),
+    /// Any other failure
+    #[error("{0}")]
+    Other(String),
+}
+
+impl TransportError {
+    /// Whether the connection could not be established
+    pub fn is_connect(&self) -> bool {
+        matches!(self, TransportError::Connect(_))
+    }
+
+    /// Whether no response arrived in time
+    pub fn is_timeout(&self) -> bool {
+        matches!(self, TransportError::Timeout(_))
+    }
+}
+
+impl From<reqwest::Error> for TransportError {
+    fn from(error: reqwest::Error) -> Self {
+        #[cfg(not(target_arch = "wasm32"))]
+        if error.is_connect() {
+            return TransportError::Connect(error.to_string());
+        }
+        if error.is_timeout() {
+            return TransportError::Timeout(error.to_string());
+        }
+        TransportError::Other(error.to_string())
+    }
+}
+
+/// Response with a JSON `body` and the given status, for fake transports
+///
+/// Not available on WASM, where responses can only come from `fetch`.
+///
+/// # Panics
+///
+/// Panics if `status` is not a valid HTTP status code.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn json_response(status: u16, body: impl Into<String>) -> reqwest::Response {
+    let response = http::Response::builder()
+        .status(status)
+        .header("content-type", "application/json")
+        .body(body.into())
+        .expect("invalid HTTP status");
+    reqwest::Response::from(response)
+}
+
+/// Send `request` through the transport configured in `config`, or `client`
+///
+/// Adds the configured default headers the request does not set itself. A
+/// custom transport also gets the configured user agent and timeout, which
+/// are otherwise applied by the `reqwest::Client`; on WASM every request gets
+/// the timeout, since `fetch` clients cannot be given one.
+pub(crate) async fn send(
+    config: &HttpConfig,
+    client: &Client,
+    mut request: reqwest::Request,
+) -> Result<reqwest::Response, TransportError> {
+    if !config.default_headers.is_empty() {
+        let defaults = config
+            .default_header_map()
+            .map_err(|e| TransportError::Other(e.to_string()))?;
+        for (name, value) in defaults {
+            if let Some(name) = name {
+                request.headers_mut().entry(name).or_insert(value);
+            }
+        }
+    }
+    // `fetch` clients have no timeout of their own
+    #[cfg(target_arch = "wasm32")]
+    request.timeout_mut().get_or_insert(config.timeout);
+    match &config.transport {
+        Some(transport) => {
+            if let Ok(user_agent) = HeaderValue::from_str(&config.user_agent) {
+                request
+                    .headers_mut()
+                    .entry(USER_AGENT)
+                    .or_insert(user_agent);
+            }
+            request.timeout_mut().get_or_insert(config.timeout);
+            transport.send(request).await
+        }
+        None => client.send(request).await,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_json_response() {
+        let response = json_response(429, r#"{"error":{"code":10028}}"#);
+        assert_eq!(response.status().as_u16(), 429);
+        assert_eq!(
+            response.headers()["content-type"].to_str().unwrap(),
+            "application/json"
+        );
+    }
+
+    #[test]
+    fn test_transport_error_kind() {
+        assert!(TransportError::Connect("refused".into()).is_connect());
+        assert!(TransportError::Timeout("slow".into()).is_timeout());
+        let other = TransportError::Other("broken".into());
+        assert!(!other.is_connect() && !other.is_timeout());
+        assert_eq!(other.to_string(), "broken");
+    }
+}
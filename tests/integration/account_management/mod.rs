@@ -7,6 +7,7 @@ pub mod account_summary;
 pub mod move_positions;
 pub mod positions;
 pub mod settlement_history;
+pub mod subaccount_admin;
 pub mod subaccounts;
 pub mod transaction_log;
 pub mod user_trades;
@@ -5,6 +5,7 @@
 #[cfg(test)]
 mod positions_log_tests {
     use deribit_http::DeribitHttpClient;
+    use deribit_http::model::instrument::InstrumentKind;
     use std::path::Path;
     use tracing::{debug, info, warn};
 
@@ -40,7 +41,7 @@ mod positions_log_tests {
         let client = DeribitHttpClient::new();
 
         debug!("Getting all positions");
-        let positions = client.get_positions(None, None, None).await?;
+        let positions = client.get_positions(None::<&str>, None, None).await?;
 
         info!(
             "All positions retrieved successfully, count: {}",
@@ -176,7 +177,9 @@ mod positions_log_tests {
         let client = DeribitHttpClient::new();
 
         debug!("Getting future positions");
-        let positions = client.get_positions(None, Some("future"), None).await?;
+        let positions = client
+            .get_positions(None::<&str>, Some(InstrumentKind::Future), None)
+            .await?;
 
         info!(
             "Future positions retrieved successfully, count: {}",
@@ -209,7 +212,9 @@ mod positions_log_tests {
         let client = DeribitHttpClient::new();
 
         debug!("Getting option positions");
-        let positions = client.get_positions(None, Some("option"), None).await?;
+        let positions = client
+            .get_positions(None::<&str>, Some(InstrumentKind::Option), None)
+            .await?;
 
         info!(
             "Option positions retrieved successfully, count: {}",
@@ -243,7 +248,7 @@ mod positions_log_tests {
 
         debug!("Getting BTC future positions");
         let positions = client
-            .get_positions(Some("BTC"), Some("future"), None)
+            .get_positions(Some("BTC"), Some(InstrumentKind::Future), None)
             .await?;
 
         info!(
@@ -0,0 +1,185 @@
+//! Subaccount administration integration tests
+//!
+//! Exercises the subaccount management endpoints against a mock server:
+//! 1. Create a subaccount
+//! 2. Rename it, set its email and password
+//! 3. Toggle its login and notifications
+
+#[cfg(test)]
+mod subaccount_admin_tests {
+    use deribit_http::prelude::*;
+    use mockito::{Matcher, Server, ServerGuard};
+    use serde_json::json;
+    use url::Url;
+
+    async fn create_mock_client() -> (ServerGuard, DeribitHttpClient) {
+        let mut server = Server::new_async().await;
+        server
+            .mock("GET", "/api/v2/public/auth")
+            .match_query(Matcher::Any)
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"{"jsonrpc": "2.0", "id": 1, "result": {"access_token": "t", "expires_in": 3600,
+                "refresh_token": "r", "scope": "account:read_write", "token_type": "bearer"}}"#,
+            )
+            .create_async()
+            .await;
+
+        let config = HttpConfig {
+            base_url: Url::parse(&format!("{}/api/v2", server.url())).unwrap(),
+            ..HttpConfig::testnet()
+        }
+        .with_oauth2("client_id".to_string(), "client_secret".to_string());
+        (server, DeribitHttpClient::with_config(config))
+    }
+
+    async fn mock_ok(
+        server: &mut ServerGuard,
+        method: &str,
+        params: serde_json::Value,
+    ) -> mockito::Mock {
+        server
+            .mock("POST", format!("/api/v2/private/{}", method).as_str())
+            .match_body(Matcher::PartialJson(json!({
+                "method": format!("private/{}", method),
+                "params": params,
+            })))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"jsonrpc": "2.0", "id": 1, "result": "ok"}"#)
+            .expect(1)
+            .create_async()
+            .await
+    }
+
+    #[tokio::test]
+    async fn test_create_subaccount() {
+        let (mut server, client) = create_mock_client().await;
+        let mock = server
+            .mock("POST", "/api/v2/private/create_subaccount")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"{"jsonrpc": "2.0", "id": 1, "result": {
+                    "email": "sub@example.com",
+                    "id": 13,
+                    "is_password": false,
+                    "login_enabled": false,
+                    "portfolio": {},
+                    "receive_notifications": false,
+                    "system_name": "user_1_4",
+                    "security_keys_enabled": false,
+                    "type": "subaccount",
+                    "username": "user_1_4"
+                }}"#,
+            )
+            .create_async()
+            .await;
+
+        let subaccount = client.create_subaccount().await.unwrap();
+
+        mock.assert_async().await;
+        assert_eq!(subaccount.id, 13);
+        assert_eq!(subaccount.username, "user_1_4");
+    }
+
+    #[tokio::test]
+    async fn test_subaccount_profile_updates() {
+        let (mut server, client) = create_mock_client().await;
+        let rename = mock_ok(
+            &mut server,
+            "change_subaccount_name",
+            json!({"sid": 13, "name": "desk"}),
+        )
+        .await;
+        let email = mock_ok(
+            &mut server,
+            "set_email_for_subaccount",
+            json!({"sid": 13, "email": "desk@example.com"}),
+        )
+        .await;
+        let password = mock_ok(
+            &mut server,
+            "set_password_for_subaccount",
+            json!({"sid": 13, "password": "n3w-Passw0rd"}),
+        )
+        .await;
+
+        assert_eq!(
+            client.change_subaccount_name(13, "desk").await.unwrap(),
+            "ok"
+        );
+        assert_eq!(
+            client
+                .set_email_for_subaccount(13, "desk@example.com")
+                .await
+                .unwrap(),
+            "ok"
+        );
+        assert_eq!(
+            client
+                .set_password_for_subaccount(13, "n3w-Passw0rd")
+                .await
+                .unwrap(),
+            "ok"
+        );
+
+        rename.assert_async().await;
+        email.assert_async().await;
+        password.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_subaccount_toggles() {
+        let (mut server, client) = create_mock_client().await;
+        let login = mock_ok(
+            &mut server,
+            "toggle_subaccount_login",
+            json!({"sid": 13, "state": "disable"}),
+        )
+        .await;
+        let notifications = mock_ok(
+            &mut server,
+            "toggle_notifications_from_subaccount",
+            json!({"sid": 13, "state": true}),
+        )
+        .await;
+
+        assert_eq!(
+            client.toggle_subaccount_login(13, "disable").await.unwrap(),
+            "ok"
+        );
+        assert_eq!(
+            client
+                .toggle_notifications_from_subaccount(13, true)
+                .await
+                .unwrap(),
+            "ok"
+        );
+
+        login.assert_async().await;
+        notifications.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_rejected_password_is_an_api_error() {
+        let (mut server, client) = create_mock_client().await;
+        server
+            .mock("POST", "/api/v2/private/set_password_for_subaccount")
+            .with_status(400)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"{"jsonrpc": "2.0", "id": 1, "error": {"code": 11029, "message": "invalid_arguments"}}"#,
+            )
+            .create_async()
+            .await;
+
+        let error = client
+            .set_password_for_subaccount(13, "short")
+            .await
+            .unwrap_err();
+
+        assert_eq!(error.error_code(), Some(DeribitErrorCode::InvalidArguments));
+    }
+}
@@ -10,7 +10,7 @@
 #[cfg(test)]
 mod transaction_log_tests {
     use deribit_http::DeribitHttpClient;
-    use deribit_http::model::TransactionLogRequest;
+    use deribit_http::model::{TimestampMs, TransactionLogRequest};
     use std::path::Path;
     use std::time::{SystemTime, UNIX_EPOCH};
     use tracing::{debug, info};
@@ -50,8 +50,8 @@ mod transaction_log_tests {
         let transaction_log = client
             .get_transaction_log(TransactionLogRequest {
                 currency: "BTC".to_string(),
-                start_timestamp: 0,
-                end_timestamp: 0,
+                start_timestamp: TimestampMs(0),
+                end_timestamp: TimestampMs(0),
                 query: None,
                 count: None,
                 subaccount_id: None,
@@ -79,7 +79,10 @@ mod transaction_log_tests {
             // User sequence field doesn't exist in TransactionLogEntry
             // TransactionType is an enum, validate it exists
             debug!("Transaction type: {:?}", log_entry.transaction_type);
-            assert!(log_entry.timestamp > 0, "Timestamp should be positive");
+            assert!(
+                log_entry.timestamp.as_millis() > 0,
+                "Timestamp should be positive"
+            );
             assert_eq!(log_entry.currency, "BTC", "Currency should be BTC");
             // Amount can be None for some transaction types
             if let Some(amount) = log_entry.amount {
@@ -108,8 +111,8 @@ mod transaction_log_tests {
         let transaction_log = client
             .get_transaction_log(TransactionLogRequest {
                 currency: "ETH".to_string(),
-                start_timestamp: 0,
-                end_timestamp: 0,
+                start_timestamp: TimestampMs(0),
+                end_timestamp: TimestampMs(0),
                 query: None,
                 count: None,
                 subaccount_id: None,
@@ -149,8 +152,8 @@ mod transaction_log_tests {
         let transaction_log = client
             .get_transaction_log(TransactionLogRequest {
                 currency: "BTC".to_string(),
-                start_timestamp: 0,
-                end_timestamp: 0,
+                start_timestamp: TimestampMs(0),
+                end_timestamp: TimestampMs(0),
                 query: None,
                 count: Some(requested_count),
                 subaccount_id: None,
@@ -196,8 +199,8 @@ mod transaction_log_tests {
         let transaction_log = client
             .get_transaction_log(TransactionLogRequest {
                 currency: "BTC".to_string(),
-                start_timestamp: thirty_days_ago,
-                end_timestamp: now,
+                start_timestamp: TimestampMs(thirty_days_ago),
+                end_timestamp: TimestampMs(now),
                 query: None,
                 count: None,
                 subaccount_id: None,
@@ -214,13 +217,13 @@ mod transaction_log_tests {
         // Validate that all entries are within the time range
         for log_entry in &transaction_log.logs {
             assert!(
-                log_entry.timestamp >= thirty_days_ago,
+                log_entry.timestamp.as_millis() >= thirty_days_ago,
                 "Transaction timestamp should be within range: {} >= {}",
                 log_entry.timestamp,
                 thirty_days_ago
             );
             assert!(
-                log_entry.timestamp <= now,
+                log_entry.timestamp.as_millis() <= now,
                 "Transaction timestamp should be within range: {} <= {}",
                 log_entry.timestamp,
                 now
@@ -245,8 +248,8 @@ mod transaction_log_tests {
         let first_page = client
             .get_transaction_log(TransactionLogRequest {
                 currency: "BTC".to_string(),
-                start_timestamp: 0,
-                end_timestamp: 0,
+                start_timestamp: TimestampMs(0),
+                end_timestamp: TimestampMs(0),
                 query: None,
                 count: Some(10),
                 subaccount_id: None,
@@ -265,8 +268,8 @@ mod transaction_log_tests {
             let second_page = client
                 .get_transaction_log(TransactionLogRequest {
                     currency: "BTC".to_string(),
-                    start_timestamp: 0,
-                    end_timestamp: 0,
+                    start_timestamp: TimestampMs(0),
+                    end_timestamp: TimestampMs(0),
                     query: None,
                     count: Some(10),
                     subaccount_id: None,
@@ -325,8 +328,8 @@ mod transaction_log_tests {
         let transaction_log = client
             .get_transaction_log(TransactionLogRequest {
                 currency: "BTC".to_string(),
-                start_timestamp: 0,
-                end_timestamp: 0,
+                start_timestamp: TimestampMs(0),
+                end_timestamp: TimestampMs(0),
                 query: None,
                 count: Some(20),
                 subaccount_id: None,
@@ -347,7 +350,10 @@ mod transaction_log_tests {
 
             // Validate required fields
             assert!(log_entry.id > 0, "Transaction ID should be positive");
-            assert!(log_entry.timestamp > 0, "Timestamp should be positive");
+            assert!(
+                log_entry.timestamp.as_millis() > 0,
+                "Timestamp should be positive"
+            );
             assert!(
                 !log_entry.currency.is_empty(),
                 "Currency should not be empty"
@@ -400,8 +406,8 @@ mod transaction_log_tests {
             let transaction_log = client
                 .get_transaction_log(TransactionLogRequest {
                     currency: currency.to_string(),
-                    start_timestamp: 0,
-                    end_timestamp: 0,
+                    start_timestamp: TimestampMs(0),
+                    end_timestamp: TimestampMs(0),
                     query: None,
                     count: Some(5),
                     subaccount_id: None,
@@ -6,6 +6,7 @@
 #[cfg(test)]
 mod user_trades_log_tests {
     use deribit_http::DeribitHttpClient;
+    use deribit_http::model::other::SortDirection;
     use std::path::Path;
     use tracing::{debug, info};
 
@@ -69,7 +70,10 @@ mod user_trades_log_tests {
             assert!(!trade.direction.is_empty(), "Direction should not be empty");
             assert!(trade.amount > 0.0, "Amount should be positive");
             assert!(trade.price > 0.0, "Price should be positive");
-            assert!(trade.timestamp > 0, "Timestamp should be positive");
+            assert!(
+                trade.timestamp.as_millis() > 0,
+                "Timestamp should be positive"
+            );
             assert!(trade.fee >= 0.0, "Fee should be non-negative");
             assert!(
                 !trade.fee_currency.is_empty(),
@@ -300,7 +304,14 @@ mod user_trades_log_tests {
         // Get trades with ascending sorting
         debug!("Getting trades with ascending sorting");
         let trades_asc = client
-            .get_user_trades_by_instrument("BTC-PERPETUAL", None, None, Some(10), None, Some("asc"))
+            .get_user_trades_by_instrument(
+                "BTC-PERPETUAL",
+                None,
+                None,
+                Some(10),
+                None,
+                Some(SortDirection::Asc),
+            )
             .await?;
 
         // Get trades with descending sorting
@@ -312,7 +323,7 @@ mod user_trades_log_tests {
                 None,
                 Some(10),
                 None,
-                Some("desc"),
+                Some(SortDirection::Desc),
             )
             .await?;
 
@@ -398,7 +409,10 @@ mod user_trades_log_tests {
             assert!(trade.trade_seq > 0, "Trade sequence should be positive");
             assert!(trade.amount > 0.0, "Amount should be positive");
             assert!(trade.price > 0.0, "Price should be positive");
-            assert!(trade.timestamp > 0, "Timestamp should be positive");
+            assert!(
+                trade.timestamp.as_millis() > 0,
+                "Timestamp should be positive"
+            );
             assert!(trade.fee >= 0.0, "Fee should be non-negative");
             assert!(trade.index_price > 0.0, "Index price should be positive");
             assert!(trade.mark_price > 0.0, "Mark price should be positive");
@@ -51,7 +51,7 @@ async fn test_get_block_rfqs() {
     let client = DeribitHttpClient::new();
 
     let result: Result<BlockRfqsResponse, _> = client
-        .get_block_rfqs(Some(10), None, None, None, None, None)
+        .get_block_rfqs(Some(10), None, None, None, None, None::<&str>)
         .await;
 
     assert!(result.is_ok(), "get_block_rfqs failed: {:?}", result.err());
@@ -109,7 +109,14 @@ async fn test_get_block_rfqs_with_state_filter() {
     let client = DeribitHttpClient::new();
 
     let result: Result<BlockRfqsResponse, _> = client
-        .get_block_rfqs(Some(20), Some(BlockRfqState::Open), None, None, None, None)
+        .get_block_rfqs(
+            Some(20),
+            Some(BlockRfqState::Open),
+            None,
+            None,
+            None,
+            None::<&str>,
+        )
         .await;
 
     assert!(result.is_ok(), "get_block_rfqs failed: {:?}", result.err());
@@ -133,7 +140,14 @@ async fn test_get_block_rfqs_with_role_filter() {
 
     // Test as maker
     let maker_result: Result<BlockRfqsResponse, _> = client
-        .get_block_rfqs(Some(10), None, Some(BlockRfqRole::Maker), None, None, None)
+        .get_block_rfqs(
+            Some(10),
+            None,
+            Some(BlockRfqRole::Maker),
+            None,
+            None,
+            None::<&str>,
+        )
         .await;
 
     assert!(
@@ -144,7 +158,14 @@ async fn test_get_block_rfqs_with_role_filter() {
 
     // Test as taker
     let taker_result: Result<BlockRfqsResponse, _> = client
-        .get_block_rfqs(Some(10), None, Some(BlockRfqRole::Taker), None, None, None)
+        .get_block_rfqs(
+            Some(10),
+            None,
+            Some(BlockRfqRole::Taker),
+            None,
+            None,
+            None::<&str>,
+        )
         .await;
 
     assert!(
@@ -226,9 +226,12 @@ mod comprehensive_market_data_tests {
                     }
 
                     // Timestamp validation
-                    assert!(ticker.timestamp > 0, "Timestamp should be positive");
                     assert!(
-                        ticker.timestamp > 1_600_000_000_000,
+                        ticker.timestamp.as_millis() > 0,
+                        "Timestamp should be positive"
+                    );
+                    assert!(
+                        ticker.timestamp.as_millis() > 1_600_000_000_000,
                         "Timestamp should be reasonable"
                     );
 
@@ -293,7 +296,10 @@ mod comprehensive_market_data_tests {
                             order_book.instrument_name, instrument,
                             "Instrument name should match"
                         );
-                        assert!(order_book.timestamp > 0, "Timestamp should be positive");
+                        assert!(
+                            order_book.timestamp.as_millis() > 0,
+                            "Timestamp should be positive"
+                        );
 
                         // Validate bids
                         assert!(
@@ -607,12 +613,12 @@ mod comprehensive_market_data_tests {
                 for point in data.iter() {
                     // Timestamp should be reasonable (after 2020, before 2035)
                     assert!(
-                        point.timestamp > 1_577_836_800_000,
+                        point.timestamp.as_millis() > 1_577_836_800_000,
                         "Timestamp should be after 2020: {}",
                         point.timestamp
                     );
                     assert!(
-                        point.timestamp < 2_051_222_400_000,
+                        point.timestamp.as_millis() < 2_051_222_400_000,
                         "Timestamp should be before 2035: {}",
                         point.timestamp
                     );
@@ -692,14 +698,14 @@ mod comprehensive_market_data_tests {
                     if let Some(first) = data.first() {
                         assert!(first.price > 0.0, "First point price should be positive");
                         assert!(
-                            first.timestamp > 1_577_836_800_000,
+                            first.timestamp.as_millis() > 1_577_836_800_000,
                             "First point timestamp should be valid"
                         );
                     }
                     if let Some(last) = data.last() {
                         assert!(last.price > 0.0, "Last point price should be positive");
                         assert!(
-                            last.timestamp > 1_577_836_800_000,
+                            last.timestamp.as_millis() > 1_577_836_800_000,
                             "Last point timestamp should be valid"
                         );
                     }
@@ -0,0 +1,100 @@
+//! Order Management Against the Embedded Mock Server
+//!
+//! These tests run the full order flow over HTTP against
+//! `deribit_http::testing::MockServer`, so they need neither credentials nor
+//! network access:
+//! 1. Quoting both sides and cancelling the resting orders
+//! 2. Building a position from partial fills
+//! 3. Recovering from rate limiting and failed authentication
+
+#[cfg(test)]
+mod mock_exchange_tests {
+    use deribit_http::model::request::order::OrderRequest;
+    use deribit_http::testing::{MOCK_INSTRUMENT, MockServer, Scenario};
+    use deribit_http::{DeribitHttpClient, HttpError, HttpErrorKind};
+    use tracing::info;
+
+    fn limit_order(amount: f64, price: f64) -> OrderRequest {
+        OrderRequest::builder()
+            .instrument_name(MOCK_INSTRUMENT)
+            .amount(amount)
+            .limit(price)
+            .build()
+            .expect("valid order")
+    }
+
+    /// Quote `levels` price levels on each side around the mid price
+    async fn quote(client: &DeribitHttpClient, levels: u32) -> Result<usize, HttpError> {
+        let book = client.get_order_book(MOCK_INSTRUMENT, Some(5)).await?;
+        let mid = book.mid_price().unwrap_or_default();
+        let mut placed = 0;
+        for level in 1..=levels {
+            let offset = 50.0 * level as f64;
+            client.buy_order(limit_order(10.0, mid - offset)).await?;
+            client.sell_order(limit_order(10.0, mid + offset)).await?;
+            placed += 2;
+        }
+        Ok(placed)
+    }
+
+    #[tokio::test]
+    async fn test_quote_and_cancel_all() -> Result<(), Box<dyn std::error::Error>> {
+        let server = MockServer::start().await;
+        let client = server.client();
+
+        let placed = quote(&client, 3).await?;
+        info!("Placed {} quotes", placed);
+
+        let open = client
+            .get_open_orders_by_instrument(MOCK_INSTRUMENT, None)
+            .await?;
+        assert_eq!(open.len(), placed);
+        assert_eq!(client.cancel_all().await? as usize, placed);
+        assert!(client.get_open_orders(None, None).await?.is_empty());
+        assert_eq!(server.requests_for("public/auth").len(), 1);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_position_from_partial_fills() -> Result<(), Box<dyn std::error::Error>> {
+        let server = MockServer::start().await;
+        server.set_scenario(Scenario::partial_fills(0.5));
+        let client = server.client();
+
+        client.buy_order(limit_order(100.0, 49_000.0)).await?;
+        client.buy_order(limit_order(100.0, 48_000.0)).await?;
+
+        let position = client.get_position(MOCK_INSTRUMENT).await?;
+        assert_eq!(position.size, 100.0);
+        assert_eq!(position.average_price, 48_500.0);
+        assert_eq!(client.get_open_orders(None, None).await?.len(), 2);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_recovers_from_rate_limiting() -> Result<(), Box<dyn std::error::Error>> {
+        let server = MockServer::start().await;
+        let client = server.client();
+        client.cancel_all().await?;
+
+        server.set_scenario(Scenario::rate_limited(2));
+        let placed = client.buy_order(limit_order(10.0, 49_000.0)).await;
+
+        assert!(placed.is_ok(), "{:?}", placed.err());
+        assert_eq!(server.requests_for("private/buy").len(), 3);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_rejected_credentials() {
+        let server = MockServer::start().await;
+        server.set_scenario(Scenario::auth_failure());
+
+        let result = server.client().get_open_orders(None, None).await;
+
+        assert!(matches!(
+            result.map_err(HttpError::into_kind),
+            Err(HttpErrorKind::AuthenticationFailed(_))
+        ));
+    }
+}
@@ -6,9 +6,12 @@
 // Note: Order management tests can be added here as needed
 // These tests cover private trading endpoints that require authentication
 
+pub mod mock_exchange;
+
 #[cfg(test)]
 mod close_position_tests {
     use deribit_http::DeribitHttpClient;
+    use deribit_http::model::order::OrderType;
     use tokio::time::{Duration, Instant};
     use tracing::info;
 
@@ -25,7 +28,9 @@ mod close_position_tests {
 
         info!("Testing close_position with market order");
         let start_time = Instant::now();
-        let result = client.close_position("BTC-PERPETUAL", "market", None).await;
+        let result = client
+            .close_position("BTC-PERPETUAL", OrderType::Market, None)
+            .await;
         let elapsed = start_time.elapsed();
 
         match &result {
@@ -69,7 +74,7 @@ mod close_position_tests {
         let start_time = Instant::now();
         // Use a price far from market to avoid accidental execution
         let result = client
-            .close_position("BTC-PERPETUAL", "limit", Some(1.0))
+            .close_position("BTC-PERPETUAL", OrderType::Limit, Some(1.0))
             .await;
         let elapsed = start_time.elapsed();
 
@@ -342,7 +342,7 @@ mod tests_private_endpoints {
     async fn test_get_positions() {
         let client = create_test_client().await;
 
-        let result = client.get_positions(None, None, None).await;
+        let result = client.get_positions(None::<&str>, None, None).await;
         match result {
             Ok(response) => {
                 println!("get_positions succeeded: {:?}", response);
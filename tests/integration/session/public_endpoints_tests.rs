@@ -3,6 +3,8 @@
 #[cfg(test)]
 mod tests {
     use deribit_http::DeribitHttpClient;
+    use deribit_http::model::funding::FundingChartLength;
+    use deribit_http::model::instrument::InstrumentKind;
 
     async fn create_test_client() -> DeribitHttpClient {
         // Create client with default configuration
@@ -276,7 +278,9 @@ mod tests {
     async fn test_get_funding_chart_data() {
         let client = create_test_client().await;
 
-        let result = client.get_funding_chart_data("BTC-PERPETUAL", "8h").await;
+        let result = client
+            .get_funding_chart_data("BTC-PERPETUAL", FundingChartLength::Hours8)
+            .await;
         match result {
             Ok(funding_data) => {
                 println!("Successfully got funding data: {:?}", funding_data);
@@ -306,7 +310,9 @@ mod tests {
     async fn test_get_expirations() {
         let client = create_test_client().await;
 
-        let result = client.get_expirations("BTC", "future", None).await;
+        let result = client
+            .get_expirations("BTC", InstrumentKind::Future, None)
+            .await;
         match result {
             Ok(expirations) => {
                 println!("Successfully got expirations: {:?}", expirations);
@@ -73,7 +73,7 @@ mod deposits_tests {
             assert!(deposit.amount > 0.0, "Deposit amount should be positive");
             assert_eq!(deposit.currency, "BTC", "Currency should be BTC");
             assert!(
-                deposit.received_timestamp > 0,
+                deposit.received_timestamp.as_millis() > 0,
                 "Received timestamp should be positive"
             );
             assert!(
@@ -84,7 +84,10 @@ mod deposits_tests {
                 assert!(!tx_id.is_empty(), "Transaction ID should not be empty");
             }
             if let Some(updated_ts) = deposit.updated_timestamp {
-                assert!(updated_ts > 0, "Updated timestamp should be positive");
+                assert!(
+                    updated_ts.as_millis() > 0,
+                    "Updated timestamp should be positive"
+                );
             }
 
             // Validate state values
@@ -256,11 +259,14 @@ mod deposits_tests {
             assert!(deposit.amount > 0.0, "Amount should be positive");
             assert!(deposit.amount.is_finite(), "Amount should be finite");
             assert!(
-                deposit.received_timestamp > 0,
+                deposit.received_timestamp.as_millis() > 0,
                 "Received timestamp should be positive"
             );
             if let Some(updated_ts) = deposit.updated_timestamp {
-                assert!(updated_ts > 0, "Updated timestamp should be positive");
+                assert!(
+                    updated_ts.as_millis() > 0,
+                    "Updated timestamp should be positive"
+                );
             }
 
             // Validate currency values
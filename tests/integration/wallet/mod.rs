@@ -5,4 +5,5 @@
 
 pub mod deposits;
 pub mod transfers;
+pub mod withdraw;
 pub mod withdrawals;
@@ -0,0 +1,161 @@
+//! Withdrawal request integration tests
+//!
+//! Exercises the withdrawal write endpoints against a mock server:
+//! 1. Request a withdrawal with and without a priority
+//! 2. Cancel a pending withdrawal
+//! 3. Surface an unknown address as an API error
+
+#[cfg(test)]
+mod withdraw_tests {
+    use deribit_http::model::wallet::WithdrawalPriorityLevel;
+    use deribit_http::prelude::*;
+    use mockito::{Matcher, Server, ServerGuard};
+    use serde_json::json;
+    use url::Url;
+
+    const ADDRESS: &str = "2NBqqD5GRJ8wHy1PYyCXTe9ke5226FhavBz";
+
+    async fn create_mock_client() -> (ServerGuard, DeribitHttpClient) {
+        let mut server = Server::new_async().await;
+        server
+            .mock("GET", "/api/v2/public/auth")
+            .match_query(Matcher::Any)
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"{"jsonrpc": "2.0", "id": 1, "result": {"access_token": "t", "expires_in": 3600,
+                "refresh_token": "r", "scope": "wallet:read_write", "token_type": "bearer"}}"#,
+            )
+            .create_async()
+            .await;
+
+        let config = HttpConfig {
+            base_url: Url::parse(&format!("{}/api/v2", server.url())).unwrap(),
+            ..HttpConfig::testnet()
+        }
+        .with_oauth2("client_id".to_string(), "client_secret".to_string());
+        (server, DeribitHttpClient::with_config(config))
+    }
+
+    fn withdrawal_body(state: &str) -> String {
+        json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "result": {
+                "address": ADDRESS,
+                "amount": 0.4,
+                "currency": "BTC",
+                "fee": 0.0001,
+                "id": 4,
+                "priority": "high",
+                "state": state,
+                "created_timestamp": 1_550_574_558_607_u64,
+                "updated_timestamp": 1_550_574_558_607_u64,
+                "transaction_id": null
+            }
+        })
+        .to_string()
+    }
+
+    #[tokio::test]
+    async fn test_withdraw_with_priority() {
+        let (mut server, client) = create_mock_client().await;
+        let mock = server
+            .mock("POST", "/api/v2/private/withdraw")
+            .match_body(Matcher::PartialJson(json!({
+                "method": "private/withdraw",
+                "params": {
+                    "currency": "BTC",
+                    "address": ADDRESS,
+                    "amount": 0.4,
+                    "priority": "high"
+                }
+            })))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(withdrawal_body("unconfirmed"))
+            .expect(1)
+            .create_async()
+            .await;
+
+        let withdrawal = client
+            .withdraw("BTC", ADDRESS, 0.4, Some(WithdrawalPriorityLevel::High))
+            .await
+            .unwrap();
+
+        mock.assert_async().await;
+        assert_eq!(withdrawal.id, 4);
+        assert_eq!(withdrawal.state, "unconfirmed");
+        assert_eq!(withdrawal.fee, 0.0001);
+        assert!(withdrawal.transaction_id.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_withdraw_without_priority_omits_it() {
+        let (mut server, client) = create_mock_client().await;
+        let mock = server
+            .mock("POST", "/api/v2/private/withdraw")
+            .match_request(|request| {
+                !request
+                    .utf8_lossy_body()
+                    .is_ok_and(|body| body.contains("priority"))
+            })
+            .match_body(Matcher::PartialJson(json!({
+                "params": {"currency": "BTC", "address": ADDRESS, "amount": 0.4}
+            })))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(withdrawal_body("unconfirmed"))
+            .expect(1)
+            .create_async()
+            .await;
+
+        client.withdraw("BTC", ADDRESS, 0.4, None).await.unwrap();
+
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_cancel_withdrawal() {
+        let (mut server, client) = create_mock_client().await;
+        let mock = server
+            .mock("POST", "/api/v2/private/cancel_withdrawal")
+            .match_body(Matcher::PartialJson(json!({
+                "method": "private/cancel_withdrawal",
+                "params": {"currency": "BTC", "id": 4}
+            })))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(withdrawal_body("cancelled"))
+            .expect(1)
+            .create_async()
+            .await;
+
+        let withdrawal = client.cancel_withdrawal("BTC", 4).await.unwrap();
+
+        mock.assert_async().await;
+        assert_eq!(withdrawal.id, 4);
+        assert_eq!(withdrawal.state, "cancelled");
+    }
+
+    #[tokio::test]
+    async fn test_withdraw_to_unknown_address_is_an_api_error() {
+        let (mut server, client) = create_mock_client().await;
+        server
+            .mock("POST", "/api/v2/private/withdraw")
+            .with_status(400)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"{"jsonrpc": "2.0", "id": 1, "error": {"code": 11029, "message": "invalid_arguments"}}"#,
+            )
+            .create_async()
+            .await;
+
+        let error = client
+            .withdraw("BTC", "not-in-address-book", 0.4, None)
+            .await
+            .unwrap_err();
+
+        assert_eq!(error.error_code(), Some(DeribitErrorCode::InvalidArguments));
+    }
+}
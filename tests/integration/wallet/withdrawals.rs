@@ -81,7 +81,7 @@ mod withdrawal_tests {
             );
             assert_eq!(withdrawal.currency, "BTC", "Currency should be BTC");
             assert!(
-                withdrawal.created_timestamp > 0,
+                withdrawal.created_timestamp.as_millis() > 0,
                 "Created timestamp should be positive"
             );
             assert!(!withdrawal.state.is_empty(), "State should not be empty");
@@ -93,7 +93,10 @@ mod withdrawal_tests {
                 assert!(!tx_id.is_empty(), "Transaction ID should not be empty");
             }
             if let Some(updated_ts) = withdrawal.updated_timestamp {
-                assert!(updated_ts > 0, "Updated timestamp should be positive");
+                assert!(
+                    updated_ts.as_millis() > 0,
+                    "Updated timestamp should be positive"
+                );
             }
             assert!(withdrawal.fee >= 0.0, "Fee should be non-negative");
             assert!(withdrawal.id > 0, "Withdrawal ID should be positive");
@@ -301,11 +304,14 @@ mod withdrawal_tests {
             );
             assert!(withdrawal.id > 0, "Withdrawal ID should be positive");
             assert!(
-                withdrawal.created_timestamp > 0,
+                withdrawal.created_timestamp.as_millis() > 0,
                 "Created timestamp should be positive"
             );
             if let Some(updated_ts) = withdrawal.updated_timestamp {
-                assert!(updated_ts > 0, "Updated timestamp should be positive");
+                assert!(
+                    updated_ts.as_millis() > 0,
+                    "Updated timestamp should be positive"
+                );
             }
 
             // Validate currency values
@@ -1,5 +1,6 @@
 //! Unit tests for API key management models
 
+use deribit_http::model::TimestampMs;
 use deribit_http::model::{ApiKeyInfo, CreateApiKeyRequest, EditApiKeyRequest};
 
 #[test]
@@ -32,7 +33,7 @@ fn test_api_key_info_deserialization_full() {
     );
     assert!(info.enabled);
     assert!(!info.default);
-    assert_eq!(info.timestamp, 1560238048714);
+    assert_eq!(info.timestamp.as_millis(), 1560238048714);
     assert_eq!(info.enabled_features.len(), 1);
     assert_eq!(info.enabled_features[0], "restricted_block_trades");
     assert!(info.public_key.is_some());
@@ -114,7 +115,7 @@ fn test_api_key_info_serialization() {
         max_scope: "account:read".to_string(),
         enabled: true,
         default: false,
-        timestamp: 1234567890,
+        timestamp: TimestampMs(1234567890),
         enabled_features: vec!["block_trade_approval".to_string()],
         ip_whitelist: Some(vec!["127.0.0.1".to_string()]),
         public_key: None,
@@ -1,5 +1,6 @@
 //! Unit tests for address beneficiary models
 
+use deribit_http::model::TimestampMs;
 use deribit_http::model::{
     AddressBeneficiary, ClearanceDepositResult, DepositId, ListAddressBeneficiariesRequest,
     ListAddressBeneficiariesResponse, Originator, SaveAddressBeneficiaryRequest,
@@ -49,8 +50,14 @@ fn test_address_beneficiary_deserialize_full() {
         beneficiary.beneficiary_company_name,
         Some("Example Corp".to_string())
     );
-    assert_eq!(beneficiary.creation_timestamp, Some(1536569522277));
-    assert_eq!(beneficiary.update_timestamp, Some(1536569522277));
+    assert_eq!(
+        beneficiary.creation_timestamp,
+        Some(TimestampMs(1536569522277))
+    );
+    assert_eq!(
+        beneficiary.update_timestamp,
+        Some(TimestampMs(1536569522277))
+    );
 }
 
 #[test]
@@ -197,8 +204,8 @@ fn test_list_response_deserialize() {
     assert_eq!(response.data.len(), 2);
     assert_eq!(response.count, Some(2));
     assert_eq!(
-        response.continuation,
-        Some("xY7T6cutS3t2B9YtaDkE6TS379oKnkzTvmEDUnEUP2Msa9xKWNNaT".to_string())
+        response.continuation.as_deref(),
+        Some("xY7T6cutS3t2B9YtaDkE6TS379oKnkzTvmEDUnEUP2Msa9xKWNNaT")
     );
     assert_eq!(response.data[0].address, "bc1qtest1");
     assert_eq!(response.data[1].address, "bc1qtest2");
@@ -333,8 +340,8 @@ fn test_clearance_deposit_result_deserialize_full() {
     assert!(result.transaction_id.is_some());
     assert_eq!(result.note, Some("Test note".to_string()));
     assert_eq!(result.clearance_state, Some("in_progress".to_string()));
-    assert_eq!(result.received_timestamp, Some(1550574558607));
-    assert_eq!(result.updated_timestamp, Some(1550574558807));
+    assert_eq!(result.received_timestamp, Some(TimestampMs(1550574558607)));
+    assert_eq!(result.updated_timestamp, Some(TimestampMs(1550574558807)));
 }
 
 #[test]
@@ -1,5 +1,6 @@
 //! Unit tests for block trade models.
 
+use deribit_http::model::TimestampMs;
 use deribit_http::model::block_trade::{
     BlockTrade, BlockTradeItem, BlockTradeRequest, BlockTradeResult, BlockTradeRole,
     BlockTradeSignature, BlockTradeTradeInfo, ExecuteBlockTradeRequest, GetBlockTradesRequest,
@@ -87,7 +88,7 @@ fn test_block_trade_item_without_amount() {
 #[test]
 fn test_execute_block_trade_request_serialization() {
     let request = ExecuteBlockTradeRequest {
-        timestamp: 1565172650935,
+        timestamp: TimestampMs(1565172650935),
         nonce: "test_nonce_123".to_string(),
         role: BlockTradeRole::Maker,
         trades: vec![
@@ -109,7 +110,7 @@ fn test_execute_block_trade_request_serialization() {
 #[test]
 fn test_verify_block_trade_request_serialization() {
     let request = VerifyBlockTradeRequest {
-        timestamp: 1565172650935,
+        timestamp: TimestampMs(1565172650935),
         nonce: "verify_nonce".to_string(),
         role: BlockTradeRole::Taker,
         trades: vec![BlockTradeItem::new(
@@ -178,8 +179,8 @@ fn test_get_block_trades_request_with_filters() {
         currency: Some("BTC".to_string()),
         count: Some(10),
         continuation: None,
-        start_timestamp: Some(1565172650935),
-        end_timestamp: Some(1565172750935),
+        start_timestamp: Some(TimestampMs(1565172650935)),
+        end_timestamp: Some(TimestampMs(1565172750935)),
     };
 
     let json = serde_json::to_string(&request).unwrap();
@@ -227,7 +228,7 @@ fn test_block_trade_trade_info_deserialization() {
 
     assert_eq!(trade_info.trade_id, "92437");
     assert_eq!(trade_info.trade_seq, Some(37));
-    assert_eq!(trade_info.timestamp, 1565089523719);
+    assert_eq!(trade_info.timestamp.as_millis(), 1565089523719);
     assert_eq!(trade_info.tick_direction, Some(3));
     assert_eq!(trade_info.state, Some("filled".to_string()));
     assert!((trade_info.price - 0.0001).abs() < f64::EPSILON);
@@ -260,7 +261,7 @@ fn test_block_trade_deserialization() {
     let trade: BlockTrade = serde_json::from_str(json).unwrap();
 
     assert_eq!(trade.id, "61");
-    assert_eq!(trade.timestamp, 1565089523720);
+    assert_eq!(trade.timestamp.as_millis(), 1565089523720);
     assert_eq!(trade.trades.len(), 1);
     assert_eq!(trade.broker_code, Some("ABC123".to_string()));
     assert_eq!(trade.broker_name, Some("Test Broker".to_string()));
@@ -287,7 +288,7 @@ fn test_block_trade_result_deserialization() {
     let result: BlockTradeResult = serde_json::from_str(json).unwrap();
 
     assert_eq!(result.id, "6165");
-    assert_eq!(result.timestamp, 1590485535980);
+    assert_eq!(result.timestamp.as_millis(), 1590485535980);
     assert_eq!(result.trades.len(), 1);
     assert_eq!(result.trades[0].trade_id, "48079573");
 }
@@ -303,7 +304,7 @@ fn test_block_trade_request_deserialization() {
 
     let request: BlockTradeRequest = serde_json::from_str(json).unwrap();
 
-    assert_eq!(request.timestamp, 1711468813551);
+    assert_eq!(request.timestamp.as_millis(), 1711468813551);
     assert_eq!(request.nonce, "bt-468nha");
     assert_eq!(request.role, BlockTradeRole::Maker);
     assert_eq!(request.broker_code, Some("jpqYKgg1".to_string()));
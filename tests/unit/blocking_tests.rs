@@ -0,0 +1,111 @@
+use deribit_http::{DeribitHttpBlockingClient, DeribitHttpClient, HttpError, HttpErrorKind};
+use mockito::Matcher;
+use url::Url;
+
+const AUTH_BODY: &str = r#"{"jsonrpc": "2.0", "result": {"access_token": "t", "expires_in": 3600,
+    "refresh_token": "r", "scope": "trade:read_write", "token_type": "bearer"}}"#;
+
+fn client_for(server: &mockito::Server) -> DeribitHttpBlockingClient {
+    DeribitHttpClient::builder()
+        .base_url(Url::parse(&format!("{}/api/v2", server.url())).unwrap())
+        .credentials("id", "secret")
+        .max_retries(0)
+        .build()
+        .unwrap()
+        .into()
+}
+
+#[test]
+fn test_public_endpoints_block_until_the_response_arrives() {
+    let mut server = mockito::Server::new();
+    let time = server
+        .mock("GET", "/api/v2/public/get_time")
+        .with_body(r#"{"jsonrpc": "2.0", "result": 1700000000000}"#)
+        .expect(2)
+        .create();
+    let summaries = server
+        .mock("GET", "/api/v2/public/get_book_summary_by_currency")
+        .match_query(Matcher::UrlEncoded("currency".into(), "BTC".into()))
+        .with_body(r#"{"jsonrpc": "2.0", "result": []}"#)
+        .create();
+
+    let client = client_for(&server);
+    assert_eq!(client.get_server_time().unwrap(), 1700000000000);
+    assert_eq!(client.clone().get_server_time().unwrap(), 1700000000000);
+    assert!(
+        client
+            .get_book_summary_by_currency("BTC", None)
+            .unwrap()
+            .is_empty()
+    );
+    time.assert();
+    summaries.assert();
+}
+
+#[test]
+fn test_private_endpoints_authenticate_and_report_errors() {
+    let mut server = mockito::Server::new();
+    let auth = server
+        .mock("GET", "/api/v2/public/auth")
+        .match_query(Matcher::Any)
+        .with_body(AUTH_BODY)
+        .expect(1)
+        .create();
+    let cancel = server
+        .mock("POST", "/api/v2/private/cancel_all")
+        .match_header("authorization", "bearer t")
+        .with_body(r#"{"jsonrpc": "2.0", "result": 3}"#)
+        .create();
+    server
+        .mock("POST", "/api/v2/private/get_positions")
+        .with_body(r#"{"jsonrpc": "2.0", "error": {"code": 13009, "message": "unauthorized"}}"#)
+        .create();
+
+    let client = client_for(&server);
+    assert_eq!(client.cancel_all().unwrap(), 3);
+    assert!(matches!(
+        client
+            .get_positions(Some("BTC"), None, None)
+            .map_err(HttpError::into_kind),
+        Err(HttpErrorKind::Api { code: 13009, .. })
+    ));
+    auth.assert();
+    cancel.assert();
+}
+
+#[test]
+fn test_block_on_and_call_reach_the_rest_of_the_api() {
+    let mut server = mockito::Server::new();
+    server
+        .mock("POST", "/api/v2/public/get_time")
+        .with_body(r#"{"jsonrpc": "2.0", "result": 1700000000000}"#)
+        .expect(2)
+        .create();
+
+    let client = client_for(&server);
+    let time: u64 = client.call("public/get_time", ()).unwrap();
+    assert_eq!(time, 1700000000000);
+    let raw = client
+        .block_on(client.async_client().call_raw("public/get_time", ()))
+        .unwrap();
+    assert_eq!(raw, serde_json::json!(1700000000000u64));
+}
+
+#[test]
+fn test_health_check_blocks_until_both_calls_answer() {
+    let mut server = mockito::Server::new();
+    server
+        .mock("GET", "/api/v2/public/test")
+        .with_body(r#"{"jsonrpc": "2.0", "result": {"version": "1.2.26"}}"#)
+        .create();
+    server
+        .mock("GET", "/api/v2/public/status")
+        .with_body(r#"{"jsonrpc": "2.0", "result": {"locked": "false", "locked_indices": []}}"#)
+        .create();
+
+    let client = client_for(&server);
+    let health = client.check_health();
+    assert!(health.is_healthy());
+    assert_eq!(health.version.as_deref(), Some("1.2.26"));
+    assert!(client.async_client().health().is_some());
+}
@@ -1,5 +1,6 @@
 //! Unit tests for book model
 
+use deribit_http::model::TimestampMs;
 use deribit_http::model::book::{BookSummaries, BookSummary, OrderBook, OrderBookEntry};
 use serde_json;
 
@@ -13,7 +14,7 @@ mod book_summary_tests {
             "BTC".to_string(),
             "USD".to_string(),
             50000.0,
-            1640995200000,
+            TimestampMs::from_millis(1640995200000),
         )
     }
 
@@ -25,7 +26,7 @@ mod book_summary_tests {
         assert_eq!(summary.base_currency, "BTC");
         assert_eq!(summary.quote_currency, "USD");
         assert_eq!(summary.mark_price, 50000.0);
-        assert_eq!(summary.creation_timestamp, 1640995200000);
+        assert_eq!(summary.creation_timestamp.as_millis(), 1640995200000);
         assert_eq!(summary.volume, 0.0);
         assert_eq!(summary.volume_usd, 0.0);
         assert_eq!(summary.open_interest, 0.0);
@@ -140,7 +141,7 @@ mod book_summary_tests {
             "BTC".to_string(),
             "USD".to_string(),
             50000.0,
-            1640995200000,
+            TimestampMs::from_millis(1640995200000),
         );
         assert!(perpetual.is_perpetual());
 
@@ -149,7 +150,7 @@ mod book_summary_tests {
             "BTC".to_string(),
             "USD".to_string(),
             50000.0,
-            1640995200000,
+            TimestampMs::from_millis(1640995200000),
         );
         assert!(!future.is_perpetual());
     }
@@ -161,7 +162,7 @@ mod book_summary_tests {
             "BTC".to_string(),
             "USD".to_string(),
             50000.0,
-            1640995200000,
+            TimestampMs::from_millis(1640995200000),
         );
         assert!(call_option.is_option());
 
@@ -170,7 +171,7 @@ mod book_summary_tests {
             "BTC".to_string(),
             "USD".to_string(),
             50000.0,
-            1640995200000,
+            TimestampMs::from_millis(1640995200000),
         );
         assert!(put_option.is_option());
 
@@ -179,7 +180,7 @@ mod book_summary_tests {
             "BTC".to_string(),
             "USD".to_string(),
             50000.0,
-            1640995200000,
+            TimestampMs::from_millis(1640995200000),
         );
         assert!(!perpetual.is_option());
     }
@@ -191,7 +192,7 @@ mod book_summary_tests {
             "BTC".to_string(),
             "USD".to_string(),
             50000.0,
-            1640995200000,
+            TimestampMs::from_millis(1640995200000),
         );
         assert!(future.is_future());
 
@@ -200,7 +201,7 @@ mod book_summary_tests {
             "BTC".to_string(),
             "USD".to_string(),
             50000.0,
-            1640995200000,
+            TimestampMs::from_millis(1640995200000),
         );
         assert!(!perpetual.is_future());
 
@@ -209,7 +210,7 @@ mod book_summary_tests {
             "BTC".to_string(),
             "USD".to_string(),
             50000.0,
-            1640995200000,
+            TimestampMs::from_millis(1640995200000),
         );
         assert!(!option.is_future());
     }
@@ -262,7 +263,7 @@ mod book_summaries_tests {
                 "BTC".to_string(),
                 "USD".to_string(),
                 50000.0,
-                1640995200000,
+                TimestampMs::from_millis(1640995200000),
             )
             .with_volume(1000.0, 50000000.0),
         );
@@ -273,7 +274,7 @@ mod book_summaries_tests {
                 "ETH".to_string(),
                 "USD".to_string(),
                 3000.0,
-                1640995200000,
+                TimestampMs::from_millis(1640995200000),
             )
             .with_volume(500.0, 1500000.0),
         );
@@ -284,7 +285,7 @@ mod book_summaries_tests {
                 "BTC".to_string(),
                 "USD".to_string(),
                 2000.0,
-                1640995200000,
+                TimestampMs::from_millis(1640995200000),
             )
             .with_open_interest(100.0),
         );
@@ -312,7 +313,7 @@ mod book_summaries_tests {
             "BTC".to_string(),
             "USD".to_string(),
             50000.0,
-            1640995200000,
+            TimestampMs::from_millis(1640995200000),
         );
 
         summaries.add(summary);
@@ -419,7 +420,11 @@ mod order_book_tests {
     use super::*;
 
     fn create_mock_order_book() -> OrderBook {
-        let mut book = OrderBook::new("BTC-PERPETUAL".to_string(), 1640995200000, 12345);
+        let mut book = OrderBook::new(
+            "BTC-PERPETUAL".to_string(),
+            TimestampMs::from_millis(1640995200000),
+            12345,
+        );
 
         book.bids = vec![
             OrderBookEntry::new(49950.0, 1.0),
@@ -438,10 +443,14 @@ mod order_book_tests {
 
     #[test]
     fn test_order_book_new() {
-        let book = OrderBook::new("BTC-PERPETUAL".to_string(), 1640995200000, 12345);
+        let book = OrderBook::new(
+            "BTC-PERPETUAL".to_string(),
+            TimestampMs::from_millis(1640995200000),
+            12345,
+        );
 
         assert_eq!(book.instrument_name, "BTC-PERPETUAL");
-        assert_eq!(book.timestamp, 1640995200000);
+        assert_eq!(book.timestamp.as_millis(), 1640995200000);
         assert_eq!(book.change_id, 12345);
         assert_eq!(book.prev_change_id, None);
         assert!(book.bids.is_empty());
@@ -495,7 +504,11 @@ mod order_book_tests {
 
     #[test]
     fn test_order_book_empty_best_prices() {
-        let book = OrderBook::new("BTC-PERPETUAL".to_string(), 1640995200000, 12345);
+        let book = OrderBook::new(
+            "BTC-PERPETUAL".to_string(),
+            TimestampMs::from_millis(1640995200000),
+            12345,
+        );
 
         assert_eq!(book.best_bid(), None);
         assert_eq!(book.best_ask(), None);
@@ -0,0 +1,105 @@
+use deribit_http::testing::{ChaosConfig, ChaosFault};
+use deribit_http::{DeribitHttpClient, HttpConfig, HttpError, HttpErrorKind};
+use mockito::Matcher;
+use serde_json::json;
+use url::Url;
+
+fn create_public_client(server: &mockito::ServerGuard, chaos: ChaosConfig) -> DeribitHttpClient {
+    let config = HttpConfig {
+        base_url: Url::parse(&server.url()).unwrap(),
+        ..Default::default()
+    };
+    DeribitHttpClient::with_config(config).with_chaos(chaos)
+}
+
+async fn create_time_mock(server: &mut mockito::Server, hits: usize) -> mockito::Mock {
+    server
+        .mock("GET", "//public/get_time")
+        .match_query(Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({"jsonrpc": "2.0", "id": 1, "result": 1700000000000u64}).to_string())
+        .expect(hits)
+        .create_async()
+        .await
+}
+
+#[cfg(test)]
+mod chaos_mode_tests {
+    use super::*;
+
+    #[test]
+    fn test_fault_bodies_match_deribit_errors() {
+        let body: serde_json::Value =
+            serde_json::from_str(&ChaosFault::NotEnoughFunds.body()).unwrap();
+        assert_eq!(body["error"]["code"], 10009);
+        assert_eq!(body["error"]["message"], "not_enough_funds");
+        assert_eq!(ChaosFault::SystemMaintenance.http_status(), 503);
+        assert_eq!(ChaosFault::PriceTooHigh.http_status(), 400);
+    }
+
+    #[test]
+    fn test_rates_are_clamped() {
+        let config = ChaosConfig::new(2.0).with_endpoint("/private/buy", -1.0, []);
+        assert_eq!(config.default_rate, 1.0);
+        assert_eq!(config.endpoints["/private/buy"].rate, 0.0);
+    }
+
+    #[tokio::test]
+    async fn test_full_rate_fails_before_network() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = create_time_mock(&mut server, 0).await;
+        let client = create_public_client(
+            &server,
+            ChaosConfig::new(1.0).with_faults([ChaosFault::MatchingEngineQueueFull]),
+        );
+
+        let result = client.get_server_time().await;
+
+        match result.map_err(HttpError::into_kind) {
+            Err(HttpErrorKind::Api { code, message, .. }) => {
+                assert_eq!(code, 10047);
+                assert_eq!(message, "matching_engine_queue_full");
+            }
+            other => panic!("expected injected error, got {:?}", other),
+        }
+        assert_eq!(client.chaos_injected(), 1);
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_endpoint_rate_overrides_default() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = create_time_mock(&mut server, 3).await;
+        let client = create_public_client(
+            &server,
+            ChaosConfig::new(1.0).with_endpoint("/public/get_time", 0.0, []),
+        );
+
+        for _ in 0..3 {
+            assert_eq!(client.get_server_time().await.unwrap(), 1700000000000);
+        }
+        assert_eq!(client.chaos_injected(), 0);
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_seed_makes_runs_reproducible() {
+        let mut server = mockito::Server::new_async().await;
+        let _mock = create_time_mock(&mut server, 40).await;
+        let config = ChaosConfig::new(0.5).with_seed(42);
+
+        let mut runs = Vec::new();
+        for _ in 0..2 {
+            let client = create_public_client(&server, config.clone());
+            let mut outcomes = Vec::new();
+            for _ in 0..20 {
+                outcomes.push(client.get_server_time().await.is_ok());
+            }
+            runs.push(outcomes);
+        }
+
+        assert_eq!(runs[0], runs[1]);
+        assert!(runs[0].contains(&true) && runs[0].contains(&false));
+    }
+}
@@ -7,6 +7,7 @@
 //! Unit tests for DeribitHttpClient
 
 use deribit_http::client::DeribitHttpClient;
+use deribit_http::model::instrument::InstrumentKind;
 
 #[tokio::test]
 async fn test_client_new_default_testnet() {
@@ -151,3 +152,2067 @@ mod mock_tests {
         assert!(result.is_err());
     }
 }
+
+#[cfg(test)]
+mod scope_downgrade_tests {
+    use super::*;
+    use deribit_http::config::HttpConfig;
+    use deribit_http::model::request::order::OrderRequest;
+    use deribit_http::{HttpError, HttpErrorKind};
+    use url::Url;
+
+    fn create_test_client(server: &mockito::ServerGuard) -> DeribitHttpClient {
+        let config = HttpConfig {
+            base_url: Url::parse(&format!("{}/api/v2", server.url())).unwrap(),
+            ..HttpConfig::testnet()
+        }
+        .with_oauth2(
+            "test_client_id".to_string(),
+            "test_client_secret".to_string(),
+        );
+        DeribitHttpClient::with_config(config)
+    }
+
+    fn token_body(scope: &str) -> String {
+        token_body_expiring(scope, 3600)
+    }
+
+    fn token_body_expiring(scope: &str, expires_in: u64) -> String {
+        format!(
+            r#"{{
+                "jsonrpc": "2.0",
+                "id": 1,
+                "result": {{
+                    "access_token": "test_access_token",
+                    "expires_in": {},
+                    "refresh_token": "test_refresh_token",
+                    "scope": "{}",
+                    "token_type": "bearer"
+                }}
+            }}"#,
+            expires_in, scope
+        )
+    }
+
+    async fn mock_cancel_all(server: &mut mockito::ServerGuard, hits: usize) -> mockito::Mock {
+        server
+            .mock("POST", "/api/v2/private/cancel_all")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"jsonrpc": "2.0", "id": 1, "result": 0}"#)
+            .expect(hits)
+            .create_async()
+            .await
+    }
+
+    #[tokio::test]
+    async fn test_scope_downgrade_halts_order_entry_until_acknowledged() {
+        let mut server = mockito::Server::new_async().await;
+        let client = create_test_client(&server);
+
+        // Expires within the renewal margin, so the next request refreshes it
+        let _auth_mock = server
+            .mock("GET", "/api/v2/public/auth?grant_type=client_credentials&client_id=test_client_id&client_secret=test_client_secret")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(token_body_expiring("account:read trade:read_write", 30))
+            .create_async()
+            .await;
+
+        let _refresh_mock = server
+            .mock(
+                "GET",
+                "/api/v2/public/auth?grant_type=refresh_token&refresh_token=test_refresh_token",
+            )
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(token_body("account:read trade:read"))
+            .create_async()
+            .await;
+
+        let cancel_mock = mock_cancel_all(&mut server, 3).await;
+        let buy_mock = server
+            .mock("POST", "/api/v2/private/buy")
+            .with_status(400)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"jsonrpc": "2.0", "id": 1, "error": {"code": 10004, "message": "order_not_found"}}"#)
+            .expect(1)
+            .create_async()
+            .await;
+        let order = || {
+            OrderRequest::builder()
+                .instrument_name("BTC-PERPETUAL")
+                .amount(10.0)
+                .limit(50_000.0)
+                .build()
+                .unwrap()
+        };
+
+        assert!(client.cancel_all().await.is_ok());
+        assert!(client.scope_downgrade().is_none());
+
+        let downgrade = match client
+            .buy_order(order())
+            .await
+            .map_err(HttpError::into_kind)
+        {
+            Err(HttpErrorKind::ScopeDowngraded(d)) => d,
+            other => panic!("expected ScopeDowngraded, got {:?}", other),
+        };
+        assert!(downgrade.affects("trade"));
+        assert_eq!(downgrade.reduced, vec!["trade:read_write".to_string()]);
+        assert_eq!(client.scope_downgrade(), Some(downgrade.clone()));
+
+        // Cancels are never held back by a downgrade
+        assert!(client.cancel_all().await.is_ok());
+
+        assert_eq!(client.acknowledge_scope_downgrade(), Some(downgrade));
+        assert!(!matches!(
+            client
+                .buy_order(order())
+                .await
+                .map_err(HttpError::into_kind),
+            Err(HttpErrorKind::ScopeDowngraded(_))
+        ));
+        assert!(client.cancel_all().await.is_ok());
+        cancel_mock.assert_async().await;
+        buy_mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_scope_downgrade_only_blocks_reduced_scope() {
+        let mut server = mockito::Server::new_async().await;
+        let client = create_test_client(&server);
+
+        let _auth_mock = server
+            .mock("GET", "/api/v2/public/auth?grant_type=client_credentials&client_id=test_client_id&client_secret=test_client_secret")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(token_body_expiring("trade:read_write wallet:read_write", 30))
+            .create_async()
+            .await;
+
+        let _refresh_mock = server
+            .mock(
+                "GET",
+                "/api/v2/public/auth?grant_type=refresh_token&refresh_token=test_refresh_token",
+            )
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(token_body("trade:read_write wallet:read"))
+            .create_async()
+            .await;
+
+        let cancel_mock = mock_cancel_all(&mut server, 2).await;
+
+        assert!(client.cancel_all().await.is_ok());
+        assert!(client.cancel_all().await.is_ok());
+        assert!(
+            client
+                .scope_downgrade()
+                .is_some_and(|d| d.affects("wallet"))
+        );
+        assert!(matches!(
+            client
+                .withdraw("BTC", "tb1qaddress", 0.1, None)
+                .await
+                .map_err(HttpError::into_kind),
+            Err(HttpErrorKind::ScopeDowngraded(_))
+        ));
+        cancel_mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_emergency_flatten_closes_positions_despite_downgrade() {
+        let mut server = mockito::Server::new_async().await;
+        let client = create_test_client(&server);
+
+        let _auth_mock = server
+            .mock("GET", "/api/v2/public/auth?grant_type=client_credentials&client_id=test_client_id&client_secret=test_client_secret")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(token_body_expiring("trade:read_write", 30))
+            .create_async()
+            .await;
+        let _refresh_mock = server
+            .mock(
+                "GET",
+                "/api/v2/public/auth?grant_type=refresh_token&refresh_token=test_refresh_token",
+            )
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(token_body("trade:read"))
+            .create_async()
+            .await;
+        let cancel_mock = mock_cancel_all(&mut server, 1).await;
+        let _cancel_by_currency = server
+            .mock("POST", "/api/v2/private/cancel_all_by_currency")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"jsonrpc": "2.0", "id": 1, "result": 0}"#)
+            .create_async()
+            .await;
+        let _positions = server
+            .mock("POST", "/api/v2/private/get_positions")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"{"jsonrpc": "2.0", "id": 1, "result": [{"instrument_name": "BTC-PERPETUAL", "size": 10.0}]}"#,
+            )
+            .create_async()
+            .await;
+        let close_mock = server
+            .mock("POST", "/api/v2/private/close_position")
+            .with_status(400)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"jsonrpc": "2.0", "id": 1, "error": {"code": 10041, "message": "settlement_in_progress"}}"#)
+            .expect(1)
+            .create_async()
+            .await;
+
+        assert!(client.cancel_all().await.is_ok());
+        let report = client.emergency_flatten(&["BTC"]).await;
+        assert!(client.scope_downgrade().is_some());
+
+        close_mock.assert_async().await;
+        cancel_mock.assert_async().await;
+        assert_eq!(report.closes.len(), 1);
+        assert!(matches!(
+            report.closes[0].1.as_ref().map_err(HttpError::kind),
+            Err(HttpErrorKind::Api { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_narrower_forked_or_exchanged_scope_is_not_a_downgrade() {
+        let mut server = mockito::Server::new_async().await;
+        let client = create_test_client(&server);
+
+        let _auth_mock = server
+            .mock("GET", "/api/v2/public/auth?grant_type=client_credentials&client_id=test_client_id&client_secret=test_client_secret")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(token_body("account:read trade:read_write"))
+            .create_async()
+            .await;
+
+        let _fork_mock = server
+            .mock("GET", "/api/v2/public/fork_token")
+            .match_query(mockito::Matcher::Any)
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(token_body("account:read trade:read session:bot"))
+            .create_async()
+            .await;
+
+        let _exchange_mock = server
+            .mock(
+                "GET",
+                "/api/v2/public/exchange_token?refresh_token=test_refresh_token&subject_id=10",
+            )
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(token_body("account:read"))
+            .create_async()
+            .await;
+
+        let cancel_mock = mock_cancel_all(&mut server, 3).await;
+
+        assert!(client.cancel_all().await.is_ok());
+
+        client
+            .fork_token("test_refresh_token", "bot", Some("account:read trade:read"))
+            .await
+            .unwrap();
+        assert!(client.scope_downgrade().is_none());
+        assert!(client.cancel_all().await.is_ok());
+
+        client
+            .exchange_token("test_refresh_token", 10, None)
+            .await
+            .unwrap();
+        assert!(client.scope_downgrade().is_none());
+        assert!(client.cancel_all().await.is_ok());
+        cancel_mock.assert_async().await;
+    }
+}
+
+#[cfg(test)]
+mod connection_reuse_tests {
+    use super::*;
+    use deribit_http::config::HttpConfig;
+    use deribit_http::{HttpError, HttpErrorKind};
+    use url::Url;
+
+    fn config_for(url: &str) -> HttpConfig {
+        HttpConfig {
+            base_url: Url::parse(url).unwrap(),
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_warm_up_calls_get_time() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "//public/get_time")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"jsonrpc": "2.0", "id": 1, "result": 1700000000000}"#)
+            .expect(1)
+            .create_async()
+            .await;
+        let client = DeribitHttpClient::with_config(config_for(&server.url()));
+
+        client.warm_up().await.unwrap();
+
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_warm_up_reports_unreachable_api() {
+        let client = DeribitHttpClient::with_config(config_for("http://127.0.0.1:1"));
+
+        let result = client.warm_up().await.map_err(HttpError::into_kind);
+
+        assert!(matches!(result, Err(HttpErrorKind::NetworkError(_))));
+    }
+
+    #[tokio::test]
+    async fn test_with_http_client_uses_given_client() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "//public/get_time")
+            .match_header("user-agent", "shared-pool/1.0")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"jsonrpc": "2.0", "id": 1, "result": 1700000000000}"#)
+            .expect(2)
+            .create_async()
+            .await;
+        let http = reqwest::Client::builder()
+            .user_agent("shared-pool/1.0")
+            .build()
+            .unwrap();
+
+        let first = DeribitHttpClient::with_http_client(config_for(&server.url()), http.clone());
+        let second = DeribitHttpClient::with_http_client(config_for(&server.url()), http);
+
+        assert!(first.get_server_time().await.is_ok());
+        assert!(second.clone().get_server_time().await.is_ok());
+        mock.assert_async().await;
+    }
+}
+
+#[cfg(test)]
+mod interceptor_tests {
+    use super::*;
+    use deribit_http::config::HttpConfig;
+    use deribit_http::interceptor::{HookFuture, RequestInterceptor};
+    use deribit_http::{HttpError, HttpErrorKind};
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicU16, AtomicUsize, Ordering};
+    use url::Url;
+
+    #[derive(Default)]
+    struct Audit {
+        sent: AtomicUsize,
+        last_status: AtomicU16,
+    }
+
+    impl RequestInterceptor for Audit {
+        fn before_request<'a>(
+            &'a self,
+            request: &'a mut reqwest::Request,
+        ) -> HookFuture<'a, Result<(), HttpError>> {
+            Box::pin(async move {
+                self.sent.fetch_add(1, Ordering::SeqCst);
+                request.headers_mut().insert(
+                    "x-audit-id",
+                    reqwest::header::HeaderValue::from_static("desk-7"),
+                );
+                Ok(())
+            })
+        }
+
+        fn after_response<'a>(&'a self, response: &'a reqwest::Response) -> HookFuture<'a, ()> {
+            Box::pin(async move {
+                self.last_status
+                    .store(response.status().as_u16(), Ordering::SeqCst);
+            })
+        }
+    }
+
+    struct Veto;
+
+    impl RequestInterceptor for Veto {
+        fn before_request<'a>(
+            &'a self,
+            _request: &'a mut reqwest::Request,
+        ) -> HookFuture<'a, Result<(), HttpError>> {
+            Box::pin(async {
+                Err(HttpErrorKind::ConfigError("blocked by policy".to_string()).into())
+            })
+        }
+    }
+
+    fn config_for(url: &str) -> HttpConfig {
+        HttpConfig {
+            base_url: Url::parse(url).unwrap(),
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_interceptor_adds_header_and_sees_response() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "//public/get_time")
+            .match_header("x-audit-id", "desk-7")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"jsonrpc": "2.0", "id": 1, "result": 1700000000000}"#)
+            .expect(1)
+            .create_async()
+            .await;
+        let audit = Arc::new(Audit::default());
+        let client = DeribitHttpClient::with_config(config_for(&server.url()))
+            .with_interceptor(audit.clone());
+
+        client.get_server_time().await.unwrap();
+
+        mock.assert_async().await;
+        assert_eq!(audit.sent.load(Ordering::SeqCst), 1);
+        assert_eq!(audit.last_status.load(Ordering::SeqCst), 200);
+    }
+
+    #[tokio::test]
+    async fn test_interceptor_error_aborts_request() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "//public/get_time")
+            .expect(0)
+            .create_async()
+            .await;
+        let client =
+            DeribitHttpClient::with_config(config_for(&server.url())).with_interceptor(Veto);
+
+        let result = client.get_server_time().await;
+
+        mock.assert_async().await;
+        assert!(
+            matches!(result.map_err(HttpError::into_kind), Err(HttpErrorKind::ConfigError(message)) if message == "blocked by policy")
+        );
+    }
+}
+
+#[cfg(test)]
+mod raw_response_tests {
+    use super::*;
+    use deribit_http::config::HttpConfig;
+    use deribit_http::{HttpError, HttpErrorKind};
+    use url::Url;
+
+    async fn malformed_time_error(debug_raw_responses: bool) -> String {
+        let mut server = mockito::Server::new_async().await;
+        let _mock = server
+            .mock("GET", "//public/get_time")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"jsonrpc": "2.0", "id": 1, "note": "body-marker", "result": 17"#)
+            .create_async()
+            .await;
+        let config = HttpConfig {
+            base_url: Url::parse(&server.url()).unwrap(),
+            ..Default::default()
+        }
+        .with_debug_raw_responses(debug_raw_responses);
+        let client = DeribitHttpClient::with_config(config);
+
+        match client.get_server_time().await.map_err(HttpError::into_kind) {
+            Err(HttpErrorKind::InvalidResponse(message)) => message,
+            other => panic!("expected InvalidResponse, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_parse_error_omits_body_by_default() {
+        let message = malformed_time_error(false).await;
+
+        assert!(message.starts_with("error decoding response body"));
+        assert!(!message.contains("body-marker"), "{}", message);
+    }
+
+    async fn time_server(body: &str) -> (mockito::ServerGuard, DeribitHttpClient) {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", "//public/get_time")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(body)
+            .create_async()
+            .await;
+        let config = HttpConfig {
+            base_url: Url::parse(&server.url()).unwrap(),
+            ..Default::default()
+        };
+        (server, DeribitHttpClient::with_config(config))
+    }
+
+    #[tokio::test]
+    async fn test_with_raw_returns_result_and_untouched_body() {
+        let body = r#"{"jsonrpc": "2.0", "id": 1, "new_field": "body-marker", "result": 17}"#;
+        let (_server, client) = time_server(body).await;
+
+        let time = client
+            .with_raw(|client| async move { client.get_server_time().await })
+            .await;
+
+        assert_eq!(time.raw.as_deref(), Some(body));
+        let (time, raw) = time.into_parts().unwrap();
+        assert_eq!(time, 17);
+        assert!(raw.unwrap().contains("body-marker"));
+
+        // A call that sends no request has no body
+        let plain = client
+            .with_raw(|_| async move { Ok::<_, HttpError>(()) })
+            .await;
+        assert_eq!(plain.raw, None);
+    }
+
+    #[tokio::test]
+    async fn test_with_raw_keeps_body_when_model_fails() {
+        let body = r#"{"jsonrpc": "2.0", "id": 1, "result": "not-a-number"}"#;
+        let (_server, client) = time_server(body).await;
+
+        let time = client
+            .with_raw(|client| async move { client.get_server_time().await })
+            .await;
+
+        assert!(matches!(
+            time.result.map_err(HttpError::into_kind),
+            Err(HttpErrorKind::InvalidResponse(_))
+        ));
+        assert_eq!(time.raw.as_deref(), Some(body));
+    }
+
+    #[tokio::test]
+    async fn test_with_meta_returns_envelope_metadata() {
+        let body = r#"{"jsonrpc": "2.0", "id": 7, "result": 17, "usIn": 1700000000000100, "usOut": 1700000000000350, "usDiff": 250, "testnet": true}"#;
+        let (_server, client) = time_server(body).await;
+
+        let time = client
+            .with_meta(|client| async move { client.get_server_time().await })
+            .await
+            .unwrap();
+
+        assert_eq!(time.result, 17);
+        assert_eq!(time.meta.id, Some(7));
+        assert_eq!(time.meta.us_in, Some(1_700_000_000_000_100));
+        assert_eq!(time.meta.us_out, Some(1_700_000_000_000_350));
+        assert_eq!(time.meta.testnet, Some(true));
+        assert_eq!(
+            time.meta.server_time(),
+            Some(std::time::Duration::from_micros(250))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_parse_error_quotes_body_when_capturing() {
+        let message = malformed_time_error(true).await;
+
+        assert!(message.contains("Raw (first 500 chars)"), "{}", message);
+        assert!(message.contains("body-marker"), "{}", message);
+    }
+}
+
+#[cfg(test)]
+mod span_tests {
+    use super::*;
+    use deribit_http::config::HttpConfig;
+    use std::collections::HashMap;
+    use std::sync::{Arc, Mutex};
+    use tracing::field::{Field, Visit};
+    use tracing::span::{Attributes, Id, Record};
+    use tracing_subscriber::Layer;
+    use tracing_subscriber::layer::{Context, SubscriberExt};
+    use url::Url;
+
+    type Fields = Arc<Mutex<HashMap<String, String>>>;
+
+    /// Collects the fields of `deribit_request` spans
+    struct SpanFields(Fields);
+
+    impl Visit for SpanFields {
+        fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+            self.0
+                .lock()
+                .unwrap()
+                .insert(field.name().to_string(), format!("{:?}", value));
+        }
+
+        fn record_str(&mut self, field: &Field, value: &str) {
+            self.0
+                .lock()
+                .unwrap()
+                .insert(field.name().to_string(), value.to_string());
+        }
+    }
+
+    struct RequestSpanLayer(Fields);
+
+    impl<S: tracing::Subscriber> Layer<S> for RequestSpanLayer {
+        fn on_new_span(&self, attrs: &Attributes<'_>, _id: &Id, _ctx: Context<'_, S>) {
+            if attrs.metadata().name() == "deribit_request" {
+                attrs.record(&mut SpanFields(self.0.clone()));
+            }
+        }
+
+        fn on_record(&self, _id: &Id, values: &Record<'_>, _ctx: Context<'_, S>) {
+            values.record(&mut SpanFields(self.0.clone()));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_endpoint_call_records_request_span() {
+        let mut server = mockito::Server::new_async().await;
+        let _mock = server
+            .mock("GET", "//public/get_time")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"jsonrpc": "2.0", "id": 1, "result": 17}"#)
+            .create_async()
+            .await;
+        let config = HttpConfig {
+            base_url: Url::parse(&server.url()).unwrap(),
+            ..Default::default()
+        };
+        let client = DeribitHttpClient::with_config(config);
+
+        let fields = Fields::default();
+        let subscriber = tracing_subscriber::registry().with(RequestSpanLayer(fields.clone()));
+        let _guard = tracing::subscriber::set_default(subscriber);
+
+        assert_eq!(client.get_server_time().await.unwrap(), 17);
+
+        let fields = fields.lock().unwrap();
+        assert_eq!(
+            fields.get("method").map(String::as_str),
+            Some("public/get_time")
+        );
+        assert_eq!(fields.get("params").map(String::as_str), Some("{}"));
+        assert_eq!(fields.get("status").map(String::as_str), Some("200"));
+        assert!(fields.contains_key("elapsed_ms"));
+        assert!(!fields.contains_key("error"));
+    }
+}
+
+#[cfg(test)]
+mod json_rpc_transport_tests {
+    use super::*;
+    use deribit_http::config::HttpConfig;
+    use mockito::Matcher;
+    use serde_json::json;
+    use url::Url;
+
+    #[tokio::test]
+    async fn test_private_request_is_sent_as_json_rpc_post() {
+        let mut server = mockito::Server::new_async().await;
+        let config = HttpConfig {
+            base_url: Url::parse(&format!("{}/api/v2", server.url())).unwrap(),
+            ..HttpConfig::testnet()
+        }
+        .with_oauth2(
+            "test_client_id".to_string(),
+            "test_client_secret".to_string(),
+        );
+        let client = DeribitHttpClient::with_config(config);
+
+        let _auth_mock = server
+            .mock("GET", "/api/v2/public/auth")
+            .match_query(Matcher::Any)
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"{"jsonrpc": "2.0", "id": 1, "result": {"access_token": "t", "expires_in": 3600,
+                "refresh_token": "r", "scope": "trade:read_write", "token_type": "bearer"}}"#,
+            )
+            .create_async()
+            .await;
+
+        let positions_mock = server
+            .mock("POST", "/api/v2/private/get_positions")
+            .match_query(Matcher::Missing)
+            .match_header("authorization", "bearer t")
+            .match_header("content-type", "application/json")
+            .match_body(Matcher::PartialJson(json!({
+                "jsonrpc": "2.0",
+                "method": "private/get_positions",
+                "params": { "currency": "BTC", "kind": "future" }
+            })))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"jsonrpc": "2.0", "id": 1, "result": []}"#)
+            .create_async()
+            .await;
+
+        let positions = client
+            .get_positions(Some("BTC"), Some(InstrumentKind::Future), None)
+            .await
+            .unwrap();
+
+        positions_mock.assert_async().await;
+        assert!(positions.is_empty());
+    }
+}
+
+#[cfg(test)]
+mod call_tests {
+    use super::*;
+    use deribit_http::config::HttpConfig;
+    use deribit_http::{HttpError, HttpErrorKind};
+    use mockito::Matcher;
+    use serde_json::{Value, json};
+    use url::Url;
+
+    fn create_test_client(server: &mockito::ServerGuard) -> DeribitHttpClient {
+        let config = HttpConfig {
+            base_url: Url::parse(&format!("{}/api/v2", server.url())).unwrap(),
+            ..HttpConfig::testnet()
+        }
+        .with_oauth2(
+            "test_client_id".to_string(),
+            "test_client_secret".to_string(),
+        );
+        DeribitHttpClient::with_config(config)
+    }
+
+    #[tokio::test]
+    async fn test_call_public_method_without_auth() {
+        let mut server = mockito::Server::new_async().await;
+        let client = create_test_client(&server);
+        let auth_mock = server
+            .mock("GET", "/api/v2/public/auth")
+            .match_query(Matcher::Any)
+            .expect(0)
+            .create_async()
+            .await;
+        let index_mock = server
+            .mock("POST", "/api/v2/public/get_index_price")
+            .match_body(Matcher::PartialJson(json!({
+                "method": "public/get_index_price",
+                "params": { "index_name": "btc_usd" }
+            })))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"jsonrpc": "2.0", "id": 1, "result": {"index_price": 65000.5}}"#)
+            .create_async()
+            .await;
+
+        let result: Value = client
+            .call(
+                "/public/get_index_price",
+                json!({ "index_name": "btc_usd" }),
+            )
+            .await
+            .unwrap();
+
+        index_mock.assert_async().await;
+        auth_mock.assert_async().await;
+        assert_eq!(result["index_price"], json!(65000.5));
+    }
+
+    #[tokio::test]
+    async fn test_call_private_method_with_typed_result() {
+        let mut server = mockito::Server::new_async().await;
+        let client = create_test_client(&server);
+        let _auth_mock = server
+            .mock("GET", "/api/v2/public/auth")
+            .match_query(Matcher::Any)
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"{"jsonrpc": "2.0", "id": 1, "result": {"access_token": "t", "expires_in": 3600,
+                "refresh_token": "r", "scope": "trade:read_write", "token_type": "bearer"}}"#,
+            )
+            .create_async()
+            .await;
+        let cancel_mock = server
+            .mock("POST", "/api/v2/private/cancel_all_by_kind_or_type")
+            .match_header("authorization", "bearer t")
+            .match_body(Matcher::PartialJson(json!({
+                "method": "private/cancel_all_by_kind_or_type",
+                "params": { "currency": ["BTC", "ETH"] }
+            })))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"jsonrpc": "2.0", "id": 1, "result": 3}"#)
+            .create_async()
+            .await;
+
+        let cancelled: u32 = client
+            .call(
+                "private/cancel_all_by_kind_or_type",
+                json!({ "currency": ["BTC", "ETH"] }),
+            )
+            .await
+            .unwrap();
+
+        cancel_mock.assert_async().await;
+        assert_eq!(cancelled, 3);
+    }
+
+    #[tokio::test]
+    async fn test_call_raw_returns_result_and_api_errors() {
+        let mut server = mockito::Server::new_async().await;
+        let client = create_test_client(&server);
+        let _time_mock = server
+            .mock("POST", "/api/v2/public/get_time")
+            .match_body(Matcher::PartialJson(json!({ "params": {} })))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"jsonrpc": "2.0", "id": 1, "result": 1700000000000}"#)
+            .create_async()
+            .await;
+        let _unknown_mock = server
+            .mock("POST", "/api/v2/public/no_such_method")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"{"jsonrpc": "2.0", "id": 1, "error": {"code": -32601, "message": "Method not found"}}"#,
+            )
+            .create_async()
+            .await;
+
+        let time = client.call_raw("public/get_time", ()).await.unwrap();
+        assert_eq!(time, json!(1_700_000_000_000_u64));
+
+        match client
+            .call_raw("public/no_such_method", ())
+            .await
+            .map_err(HttpError::into_kind)
+        {
+            Err(HttpErrorKind::Api { code, .. }) => assert_eq!(code, -32601),
+            other => panic!("expected Api error, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_call_rejects_non_object_params() {
+        let client = DeribitHttpClient::new();
+
+        let result = client.call_raw("public/get_time", vec![1, 2]).await;
+
+        assert!(matches!(
+            result.map_err(HttpError::into_kind),
+            Err(HttpErrorKind::ConfigError(_))
+        ));
+    }
+}
+
+#[cfg(test)]
+mod token_refresh_tests {
+    use super::*;
+    use deribit_http::config::HttpConfig;
+    use mockito::Matcher;
+    use url::Url;
+
+    #[tokio::test]
+    async fn test_concurrent_private_calls_share_one_authentication() {
+        let mut server = mockito::Server::new_async().await;
+        let config = HttpConfig {
+            base_url: Url::parse(&format!("{}/api/v2", server.url())).unwrap(),
+            ..HttpConfig::testnet()
+        }
+        .with_oauth2(
+            "test_client_id".to_string(),
+            "test_client_secret".to_string(),
+        );
+        let client = DeribitHttpClient::with_config(config);
+
+        let auth_mock = server
+            .mock("GET", "/api/v2/public/auth")
+            .match_query(Matcher::Any)
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"{"jsonrpc": "2.0", "id": 1, "result": {"access_token": "t", "expires_in": 3600,
+                "refresh_token": "r", "scope": "trade:read_write", "token_type": "bearer"}}"#,
+            )
+            .expect(1)
+            .create_async()
+            .await;
+        let cancel_mock = server
+            .mock("POST", "/api/v2/private/cancel_all")
+            .match_header("authorization", "bearer t")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"jsonrpc": "2.0", "id": 1, "result": 0}"#)
+            .expect(8)
+            .create_async()
+            .await;
+
+        let calls = (0..8).map(|_| {
+            let client = client.clone();
+            tokio::spawn(async move { client.cancel_all().await })
+        });
+        for result in futures_util::future::join_all(calls).await {
+            assert!(result.unwrap().is_ok());
+        }
+
+        auth_mock.assert_async().await;
+        cancel_mock.assert_async().await;
+    }
+}
+
+#[cfg(test)]
+mod token_grant_tests {
+    use super::*;
+    use deribit_http::config::HttpConfig;
+    use deribit_http::{
+        AuthManager, ExchangeTokenRequest, ForkTokenRequest, HttpError, HttpErrorKind,
+    };
+    use mockito::Matcher;
+    use url::Url;
+
+    fn token_body(access_token: &str) -> String {
+        format!(
+            r#"{{"jsonrpc": "2.0", "id": 1, "result": {{"access_token": "{}", "expires_in": 3600,
+            "refresh_token": "r", "scope": "session:bot trade:read_write", "token_type": "bearer"}}}}"#,
+            access_token
+        )
+    }
+
+    #[tokio::test]
+    async fn test_fork_token_replaces_session_token() {
+        let mut server = mockito::Server::new_async().await;
+        let config = HttpConfig {
+            base_url: Url::parse(&format!("{}/api/v2", server.url())).unwrap(),
+            ..HttpConfig::testnet()
+        };
+        let client = DeribitHttpClient::with_config(config);
+
+        let fork_mock = server
+            .mock("GET", "/api/v2/public/fork_token")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("refresh_token".into(), "r0".into()),
+                Matcher::UrlEncoded("session_name".into(), "bot".into()),
+                Matcher::UrlEncoded("scope".into(), "trade:read_write".into()),
+            ]))
+            .with_status(200)
+            .with_body(token_body("forked"))
+            .create_async()
+            .await;
+        let cancel_mock = server
+            .mock("POST", "/api/v2/private/cancel_all")
+            .match_header("authorization", "bearer forked")
+            .with_status(200)
+            .with_body(r#"{"jsonrpc": "2.0", "id": 1, "result": 0}"#)
+            .create_async()
+            .await;
+
+        let token = client
+            .fork_token("r0", "bot", Some("trade:read_write"))
+            .await
+            .unwrap();
+        assert_eq!(token.access_token, "forked");
+        assert!(client.cancel_all().await.is_ok());
+
+        fork_mock.assert_async().await;
+        cancel_mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_auth_manager_exchange_token() {
+        let mut server = mockito::Server::new_async().await;
+        let config = HttpConfig {
+            base_url: Url::parse(&format!("{}/api/v2", server.url())).unwrap(),
+            ..HttpConfig::testnet()
+        };
+        let manager = AuthManager::new(reqwest::Client::new(), config);
+
+        let _exchange_mock = server
+            .mock("GET", "/api/v2/public/exchange_token")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("refresh_token".into(), "r0".into()),
+                Matcher::UrlEncoded("subject_id".into(), "42".into()),
+            ]))
+            .with_status(200)
+            .with_body(token_body("sub"))
+            .create_async()
+            .await;
+        let _fork_error = server
+            .mock("GET", "/api/v2/public/fork_token")
+            .match_query(Matcher::Any)
+            .with_status(200)
+            .with_body(r#"{"jsonrpc": "2.0", "id": 1, "error": {"code": 13009, "message": "unauthorized"}}"#)
+            .create_async()
+            .await;
+
+        let token = manager
+            .exchange_token(&ExchangeTokenRequest::new("r0", 42))
+            .await
+            .unwrap();
+        assert_eq!(token.access_token, "sub");
+        assert_eq!(manager.get_token().unwrap().access_token, "sub");
+
+        let result = manager
+            .fork_token(&ForkTokenRequest::new("r0", "bot").with_scope("trade:read"))
+            .await;
+        assert!(matches!(
+            result.map_err(HttpError::into_kind),
+            Err(HttpErrorKind::AuthenticationFailed(_))
+        ));
+        assert_eq!(manager.get_token().unwrap().access_token, "sub");
+    }
+}
+
+#[cfg(test)]
+mod logout_tests {
+    use super::*;
+    use deribit_http::HttpErrorKind;
+    use deribit_http::config::HttpConfig;
+    use mockito::Matcher;
+    use serde_json::json;
+    use url::Url;
+
+    async fn setup() -> (mockito::ServerGuard, DeribitHttpClient, mockito::Mock) {
+        let mut server = mockito::Server::new_async().await;
+        let config = HttpConfig {
+            base_url: Url::parse(&format!("{}/api/v2", server.url())).unwrap(),
+            ..HttpConfig::testnet()
+        }
+        .with_oauth2(
+            "test_client_id".to_string(),
+            "test_client_secret".to_string(),
+        );
+        let client = DeribitHttpClient::with_config(config);
+        let auth_mock = server
+            .mock("GET", "/api/v2/public/auth")
+            .match_query(Matcher::Any)
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"{"jsonrpc": "2.0", "id": 1, "result": {"access_token": "t", "expires_in": 3600,
+                "refresh_token": "r", "scope": "trade:read_write", "token_type": "bearer"}}"#,
+            )
+            .expect(2)
+            .create_async()
+            .await;
+        (server, client, auth_mock)
+    }
+
+    #[tokio::test]
+    async fn test_logout_clears_token_and_reauthenticates() {
+        let (mut server, client, auth_mock) = setup().await;
+        let logout_mock = server
+            .mock("POST", "/api/v2/private/logout")
+            .match_body(Matcher::PartialJson(json!({
+                "params": { "invalidate_token": true }
+            })))
+            .with_status(200)
+            .with_body(r#"{"jsonrpc": "2.0", "id": 1, "result": "ok"}"#)
+            .create_async()
+            .await;
+        let cancel_mock = server
+            .mock("POST", "/api/v2/private/cancel_all")
+            .with_status(200)
+            .with_body(r#"{"jsonrpc": "2.0", "id": 1, "result": 0}"#)
+            .create_async()
+            .await;
+
+        client.logout(true).await.unwrap();
+        assert!(client.cancel_all().await.is_ok());
+
+        logout_mock.assert_async().await;
+        cancel_mock.assert_async().await;
+        auth_mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_rejected_logout_still_clears_token() {
+        let (mut server, client, auth_mock) = setup().await;
+        let _logout_mock = server
+            .mock("POST", "/api/v2/private/logout")
+            .with_status(200)
+            .with_body(
+                r#"{"jsonrpc": "2.0", "id": 1, "error": {"code": 10030, "message": "must_be_websocket_request"}}"#,
+            )
+            .create_async()
+            .await;
+        let cancel_mock = server
+            .mock("POST", "/api/v2/private/cancel_all")
+            .with_status(200)
+            .with_body(r#"{"jsonrpc": "2.0", "id": 1, "result": 0}"#)
+            .create_async()
+            .await;
+
+        let error = client.logout(false).await.unwrap_err();
+        assert!(matches!(
+            error.kind(),
+            HttpErrorKind::Api { code: 10030, .. }
+        ));
+        assert!(client.cancel_all().await.is_ok());
+
+        cancel_mock.assert_async().await;
+        auth_mock.assert_async().await;
+    }
+}
+
+#[cfg(test)]
+mod transport_tests {
+    use super::*;
+    use deribit_http::config::HttpConfig;
+    use deribit_http::interceptor::HookFuture;
+    use deribit_http::transport::{HttpTransport, TransportError, json_response};
+    use std::collections::VecDeque;
+    use std::sync::{Arc, Mutex};
+
+    /// Answers requests from a script and records what was sent
+    #[derive(Default)]
+    struct ScriptedTransport {
+        outcomes: Mutex<VecDeque<Result<reqwest::Response, TransportError>>>,
+        sent: Mutex<Vec<(String, Option<String>)>>,
+    }
+
+    impl ScriptedTransport {
+        fn new(outcomes: Vec<Result<reqwest::Response, TransportError>>) -> Arc<Self> {
+            Arc::new(Self {
+                outcomes: Mutex::new(outcomes.into()),
+                sent: Mutex::default(),
+            })
+        }
+
+        fn sent(&self) -> Vec<(String, Option<String>)> {
+            self.sent.lock().unwrap().clone()
+        }
+    }
+
+    impl HttpTransport for ScriptedTransport {
+        fn send(
+            &self,
+            request: reqwest::Request,
+        ) -> HookFuture<'_, Result<reqwest::Response, TransportError>> {
+            let authorization = request
+                .headers()
+                .get("authorization")
+                .map(|value| value.to_str().unwrap().to_string());
+            self.sent
+                .lock()
+                .unwrap()
+                .push((request.url().path().to_string(), authorization));
+            let outcome = self
+                .outcomes
+                .lock()
+                .unwrap()
+                .pop_front()
+                .expect("unexpected request");
+            Box::pin(async move { outcome })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_connect_errors_from_transport_are_retried() {
+        let transport = ScriptedTransport::new(vec![
+            Err(TransportError::Connect("connection refused".to_string())),
+            Ok(json_response(
+                200,
+                r#"{"jsonrpc": "2.0", "id": 1, "result": 17}"#,
+            )),
+        ]);
+        let config = HttpConfig::testnet().with_transport(transport.clone());
+        let client = DeribitHttpClient::with_config(config);
+
+        assert_eq!(client.get_server_time().await.unwrap(), 17);
+        let paths: Vec<String> = transport.sent().into_iter().map(|(path, _)| path).collect();
+        assert_eq!(
+            paths,
+            ["/api/v2/public/get_time", "/api/v2/public/get_time"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_token_requests_go_through_transport() {
+        let transport = ScriptedTransport::new(vec![
+            Ok(json_response(
+                200,
+                r#"{"jsonrpc": "2.0", "id": 1, "result": {"access_token": "t", "expires_in": 3600,
+                "refresh_token": "r", "scope": "trade:read_write", "token_type": "bearer"}}"#,
+            )),
+            Ok(json_response(
+                200,
+                r#"{"jsonrpc": "2.0", "id": 2, "result": 3}"#,
+            )),
+        ]);
+        let config = HttpConfig::testnet()
+            .with_oauth2("id".to_string(), "secret".to_string())
+            .with_transport(transport.clone());
+        let client = DeribitHttpClient::with_config(config);
+
+        assert_eq!(client.cancel_all().await.unwrap(), 3);
+        assert_eq!(
+            transport.sent(),
+            [
+                ("/api/v2/public/auth".to_string(), None),
+                (
+                    "/api/v2/private/cancel_all".to_string(),
+                    Some("bearer t".to_string())
+                ),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_transport_timeout_is_not_retried_for_private_calls() {
+        let transport = ScriptedTransport::new(vec![
+            Ok(json_response(
+                200,
+                r#"{"jsonrpc": "2.0", "id": 1, "result": {"access_token": "t", "expires_in": 3600,
+                "refresh_token": "r", "scope": "trade:read_write", "token_type": "bearer"}}"#,
+            )),
+            Err(TransportError::Timeout("operation timed out".to_string())),
+        ]);
+        let config = HttpConfig::testnet()
+            .with_oauth2("id".to_string(), "secret".to_string())
+            .with_transport(transport.clone());
+        let client = DeribitHttpClient::with_config(config);
+
+        match client
+            .cancel_all()
+            .await
+            .map_err(deribit_http::HttpError::into_kind)
+        {
+            Err(deribit_http::HttpErrorKind::NetworkError(message)) => {
+                assert_eq!(message, "operation timed out")
+            }
+            other => panic!("expected NetworkError, got {:?}", other),
+        }
+        assert_eq!(transport.sent().len(), 2);
+    }
+}
+
+#[cfg(test)]
+mod builder_api_tests {
+    use super::*;
+    use deribit_http::config::HttpConfig;
+    use deribit_http::interceptor::{HookFuture, RequestInterceptor};
+    use deribit_http::rate_limit::{CreditLimits, RateLimitCategory, RateLimiter};
+    use deribit_http::transport::{HttpTransport, TransportError, json_response};
+    use deribit_http::{HttpError, HttpErrorKind};
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
+
+    #[derive(Default)]
+    struct Counter(AtomicUsize);
+
+    impl RequestInterceptor for Counter {
+        fn before_request<'a>(
+            &'a self,
+            _request: &'a mut reqwest::Request,
+        ) -> HookFuture<'a, Result<(), HttpError>> {
+            self.0.fetch_add(1, Ordering::SeqCst);
+            Box::pin(async { Ok(()) })
+        }
+    }
+
+    struct FixedTime;
+
+    impl HttpTransport for FixedTime {
+        fn send(
+            &self,
+            _request: reqwest::Request,
+        ) -> HookFuture<'_, Result<reqwest::Response, TransportError>> {
+            Box::pin(async { Ok(json_response(200, r#"{"jsonrpc":"2.0","result":17}"#)) })
+        }
+    }
+
+    #[test]
+    fn test_builder_defaults_ignore_the_environment() {
+        unsafe {
+            std::env::set_var("DERIBIT_HTTP_USER_AGENT", "from-env");
+        }
+        let client = DeribitHttpClient::builder().build().unwrap();
+        unsafe {
+            std::env::remove_var("DERIBIT_HTTP_USER_AGENT");
+        }
+
+        assert!(client.base_url().contains("test.deribit.com"));
+        assert!(client.config().testnet);
+        assert!(!client.config().has_credentials());
+        assert_ne!(client.config().user_agent, "from-env");
+    }
+
+    #[test]
+    fn test_builder_sets_config() {
+        let client = DeribitHttpClient::builder()
+            .production()
+            .credentials("id", "secret")
+            .timeout(Duration::from_secs(3))
+            .max_retries(0)
+            .user_agent("desk/1.0")
+            .build()
+            .unwrap();
+        let config = client.config();
+
+        assert!(!config.testnet);
+        assert!(client.base_url().contains("www.deribit.com"));
+        assert_eq!(
+            config.credentials().unwrap().client_id.as_deref(),
+            Some("id")
+        );
+        assert_eq!(config.timeout, Duration::from_secs(3));
+        assert_eq!(config.max_retries, 0);
+        assert_eq!(config.user_agent, "desk/1.0");
+
+        let from_config = DeribitHttpClient::builder()
+            .config(HttpConfig::production().with_max_retries(4))
+            .testnet()
+            .build()
+            .unwrap();
+        assert!(from_config.config().testnet);
+        assert_eq!(from_config.config().max_retries, 4);
+    }
+
+    #[test]
+    fn test_builder_rejects_invalid_user_agent() {
+        let result = DeribitHttpClient::builder()
+            .user_agent("bad\nagent")
+            .build();
+        assert!(matches!(
+            result.map_err(HttpError::into_kind),
+            Err(HttpErrorKind::ConfigError(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_builder_wires_transport_interceptors_and_rate_limiter() {
+        let counter = Arc::new(Counter::default());
+        let limits = CreditLimits {
+            max_credits: 500,
+            refill_per_sec: 50,
+            cost: 10,
+        };
+        let shared = RateLimiter::with_limits(limits, limits);
+        let client = DeribitHttpClient::builder()
+            .transport(FixedTime)
+            .interceptor(counter.clone())
+            .interceptor(counter.clone())
+            .rate_limiter(shared.clone())
+            .build()
+            .unwrap();
+
+        assert_eq!(client.get_server_time().await.unwrap(), 17);
+        assert_eq!(counter.0.load(Ordering::SeqCst), 2);
+        assert!(
+            shared
+                .remaining_credits(RateLimitCategory::NonMatchingEngine)
+                .await
+                < 500
+        );
+    }
+
+    #[tokio::test]
+    async fn test_clients_sharing_a_rate_limiter_draw_from_one_pool() {
+        let limits = CreditLimits {
+            max_credits: 500,
+            refill_per_sec: 0,
+            cost: 10,
+        };
+        let shared = RateLimiter::with_limits(limits, limits);
+        let first = DeribitHttpClient::builder()
+            .transport(FixedTime)
+            .rate_limiter(shared.clone())
+            .build()
+            .unwrap();
+        let second = DeribitHttpClient::builder()
+            .transport(FixedTime)
+            .build()
+            .unwrap()
+            .with_rate_limiter(shared.clone());
+
+        assert!(
+            first
+                .rate_limiter()
+                .shares_pools_with(second.rate_limiter())
+        );
+        assert!(!first.rate_limiter().shares_pools_with(&RateLimiter::new()));
+        first.get_server_time().await.unwrap();
+        second.get_server_time().await.unwrap();
+        second.get_server_time().await.unwrap();
+
+        for client in [&first, &second] {
+            assert_eq!(
+                client
+                    .remaining_credits(RateLimitCategory::NonMatchingEngine)
+                    .await,
+                470
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod proxy_tests {
+    use super::*;
+    use deribit_http::config::ProxyConfig;
+    use url::Url;
+
+    const TIME: &str = r#"{"jsonrpc": "2.0", "id": 1, "result": 1700000000000}"#;
+
+    #[tokio::test]
+    async fn test_requests_go_through_the_proxy_with_credentials() {
+        let mut proxy = mockito::Server::new_async().await;
+        // Basic credentials for user:secret
+        let forwarded = proxy
+            .mock("GET", "/api/v2/public/get_time")
+            .match_header("proxy-authorization", "Basic dXNlcjpzZWNyZXQ=")
+            .with_body(TIME)
+            .expect(1)
+            .create_async()
+            .await;
+        let proxy_url = proxy.url().replace("http://", "http://user:secret@");
+
+        let client = DeribitHttpClient::builder()
+            .base_url(Url::parse("http://deribit.invalid/api/v2").unwrap())
+            .proxy(ProxyConfig::new(proxy_url.parse().unwrap()))
+            .max_retries(0)
+            .build()
+            .unwrap();
+
+        assert_eq!(client.get_server_time().await.unwrap(), 1700000000000);
+        forwarded.assert_async().await;
+        assert!(!format!("{:?}", client.config()).contains("secret"));
+    }
+
+    #[tokio::test]
+    async fn test_no_proxy_hosts_are_reached_directly() {
+        let mut server = mockito::Server::new_async().await;
+        let direct = server
+            .mock("GET", "/api/v2/public/get_time")
+            .with_body(TIME)
+            .expect(1)
+            .create_async()
+            .await;
+        let unreachable = ProxyConfig::new("http://127.0.0.1:9".parse().unwrap());
+
+        let client = DeribitHttpClient::builder()
+            .base_url(Url::parse(&format!("{}/api/v2", server.url())).unwrap())
+            .proxy(unreachable.with_no_proxy(["127.0.0.1"]))
+            .max_retries(0)
+            .build()
+            .unwrap();
+
+        assert!(client.get_server_time().await.is_ok());
+        direct.assert_async().await;
+    }
+}
+
+#[cfg(test)]
+mod default_header_tests {
+    use super::*;
+    use deribit_http::{HttpError, HttpErrorKind};
+    use mockito::Matcher;
+    use url::Url;
+
+    #[tokio::test]
+    async fn test_default_headers_reach_every_request() {
+        let mut server = mockito::Server::new_async().await;
+        let auth = server
+            .mock("GET", "/api/v2/public/auth")
+            .match_query(Matcher::Any)
+            .match_header("x-desk-id", "fx-7")
+            .with_body(
+                r#"{"jsonrpc": "2.0", "result": {"access_token": "t", "expires_in": 3600,
+                "refresh_token": "r", "scope": "trade:read_write", "token_type": "bearer"}}"#,
+            )
+            .expect(1)
+            .create_async()
+            .await;
+        let cancel = server
+            .mock("POST", "/api/v2/private/cancel_all")
+            .match_header("x-desk-id", "fx-7")
+            .match_header("authorization", "bearer t")
+            .with_body(r#"{"jsonrpc": "2.0", "result": 0}"#)
+            .expect(1)
+            .create_async()
+            .await;
+
+        let client = DeribitHttpClient::builder()
+            .base_url(Url::parse(&format!("{}/api/v2", server.url())).unwrap())
+            .credentials("id", "secret")
+            .header("X-Desk-Id", "fx-7")
+            .header("Authorization", "ignored")
+            .build()
+            .unwrap();
+
+        assert_eq!(client.cancel_all().await.unwrap(), 0);
+        auth.assert_async().await;
+        cancel.assert_async().await;
+    }
+
+    #[test]
+    fn test_invalid_default_header_fails_construction() {
+        let result = DeribitHttpClient::builder()
+            .header("x-desk id", "fx-7")
+            .build();
+        assert!(matches!(
+            result.map_err(HttpError::into_kind),
+            Err(HttpErrorKind::ConfigError(message)) if message.contains("x-desk id")
+        ));
+
+        let result = DeribitHttpClient::builder()
+            .header("x-desk-id", "fx\n7")
+            .build();
+        assert!(matches!(
+            result.map_err(HttpError::into_kind),
+            Err(HttpErrorKind::ConfigError(_))
+        ));
+    }
+
+    #[test]
+    fn test_debug_redacts_secret_headers() {
+        let client = DeribitHttpClient::builder()
+            .header("X-Gateway-Token", "gw-secret")
+            .build()
+            .unwrap();
+        let text = format!("{:?}", client.config());
+        assert!(text.contains("x-gateway-token"));
+        assert!(!text.contains("gw-secret"));
+    }
+}
+
+#[cfg(test)]
+mod pool_tests {
+    use super::*;
+    use deribit_http::config::PoolConfig;
+    use std::time::{Duration, Instant};
+    use url::Url;
+
+    #[tokio::test]
+    async fn test_connect_timeout_bounds_unreachable_hosts() {
+        // Non-routable address: connecting hangs until the connect timeout
+        let client = DeribitHttpClient::builder()
+            .base_url(Url::parse("http://10.255.255.1/api/v2").unwrap())
+            .timeout(Duration::from_secs(30))
+            .max_retries(0)
+            .pool(
+                PoolConfig::new()
+                    .with_connect_timeout(Duration::from_millis(200))
+                    .with_idle_timeout(Duration::from_secs(300))
+                    .with_max_idle_per_host(2),
+            )
+            .build()
+            .unwrap();
+
+        let started = Instant::now();
+        assert!(client.get_server_time().await.is_err());
+        assert!(started.elapsed() < Duration::from_secs(10));
+    }
+}
+
+#[cfg(test)]
+mod backend_tests {
+    use super::*;
+    use deribit_http::interceptor::HookFuture;
+    use deribit_http::transport::{HttpBackend, TransportError};
+    use std::sync::{Arc, Mutex};
+    use std::time::{Duration, Instant};
+
+    /// Records the requests it gets and answers with `get_time`'s result
+    #[derive(Default)]
+    struct Recording {
+        requests: Mutex<Vec<http::Request<Vec<u8>>>>,
+    }
+
+    impl HttpBackend for Recording {
+        fn execute(
+            &self,
+            request: http::Request<Vec<u8>>,
+        ) -> HookFuture<'_, Result<http::Response<Vec<u8>>, TransportError>> {
+            self.requests.lock().unwrap().push(request);
+            let body = br#"{"jsonrpc":"2.0","result":1700000000000}"#.to_vec();
+            Box::pin(async { Ok(http::Response::new(body)) })
+        }
+    }
+
+    /// Never answers
+    struct Silent;
+
+    impl HttpBackend for Silent {
+        fn execute(
+            &self,
+            _request: http::Request<Vec<u8>>,
+        ) -> HookFuture<'_, Result<http::Response<Vec<u8>>, TransportError>> {
+            Box::pin(std::future::pending())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_backend_gets_headers_and_user_agent() {
+        let backend = Arc::new(Recording::default());
+        let client = DeribitHttpClient::builder()
+            .user_agent("desk-bot/2.1")
+            .header("x-desk-id", "fx-7")
+            .backend(backend.clone())
+            .build()
+            .unwrap();
+
+        assert_eq!(client.get_server_time().await.unwrap(), 1700000000000);
+        let requests = backend.requests.lock().unwrap();
+        assert_eq!(requests.len(), 1);
+        assert!(requests[0].uri().path().ends_with("/public/get_time"));
+        assert_eq!(requests[0].headers()["user-agent"], "desk-bot/2.1");
+        assert_eq!(requests[0].headers()["x-desk-id"], "fx-7");
+    }
+
+    #[tokio::test]
+    async fn test_backend_requests_time_out() {
+        let client = DeribitHttpClient::builder()
+            .timeout(Duration::from_millis(100))
+            .max_retries(0)
+            .backend(Silent)
+            .build()
+            .unwrap();
+
+        let started = Instant::now();
+        assert!(client.get_server_time().await.is_err());
+        assert!(started.elapsed() < Duration::from_secs(5));
+    }
+}
+
+#[cfg(all(test, feature = "hyper-backend"))]
+mod hyper_backend_tests {
+    use super::*;
+    use deribit_http::transport::HyperBackend;
+    use mockito::Matcher;
+    use url::Url;
+
+    #[tokio::test]
+    async fn test_hyper_backend_sends_public_and_private_requests() {
+        let mut server = mockito::Server::new_async().await;
+        let auth = server
+            .mock("GET", "/api/v2/public/auth")
+            .match_query(Matcher::Any)
+            .with_body(
+                r#"{"jsonrpc": "2.0", "result": {"access_token": "t", "expires_in": 3600,
+                "refresh_token": "r", "scope": "trade:read_write", "token_type": "bearer"}}"#,
+            )
+            .expect(1)
+            .create_async()
+            .await;
+        let cancel = server
+            .mock("POST", "/api/v2/private/cancel_all")
+            .match_header("authorization", "bearer t")
+            .with_body(r#"{"jsonrpc": "2.0", "result": 2}"#)
+            .expect(1)
+            .create_async()
+            .await;
+        server
+            .mock("GET", "/api/v2/public/get_time")
+            .with_status(503)
+            .with_body("maintenance")
+            .create_async()
+            .await;
+
+        let client = DeribitHttpClient::builder()
+            .base_url(Url::parse(&format!("{}/api/v2", server.url())).unwrap())
+            .credentials("id", "secret")
+            .max_retries(0)
+            .backend(HyperBackend::new().unwrap())
+            .build()
+            .unwrap();
+
+        assert_eq!(client.cancel_all().await.unwrap(), 2);
+        assert!(matches!(
+            client
+                .get_server_time()
+                .await
+                .map_err(deribit_http::HttpError::into_kind),
+            Err(deribit_http::HttpErrorKind::ServerError { status: 503, .. })
+        ));
+        auth.assert_async().await;
+        cancel.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_hyper_backend_reports_connect_failures() {
+        let client = DeribitHttpClient::builder()
+            .base_url(Url::parse("http://127.0.0.1:9/api/v2").unwrap())
+            .max_retries(0)
+            .backend(HyperBackend::new().unwrap())
+            .build()
+            .unwrap();
+
+        assert!(matches!(
+            client
+                .get_server_time()
+                .await
+                .map_err(deribit_http::HttpError::into_kind),
+            Err(deribit_http::HttpErrorKind::NetworkError(_))
+        ));
+    }
+}
+
+#[cfg(test)]
+mod clone_tests {
+    use super::*;
+    use deribit_http::rate_limit::{CreditLimits, RateLimitCategory};
+    use url::Url;
+
+    const LIMITS: CreditLimits = CreditLimits {
+        max_credits: 100,
+        refill_per_sec: 1,
+        cost: 10,
+    };
+
+    #[tokio::test]
+    async fn test_clones_share_credit_pools() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", "/api/v2/public/get_time")
+            .with_body(r#"{"jsonrpc": "2.0", "result": 1700000000000}"#)
+            .expect(3)
+            .create_async()
+            .await;
+        let client = DeribitHttpClient::builder()
+            .base_url(Url::parse(&format!("{}/api/v2", server.url())).unwrap())
+            .credit_limits(LIMITS, LIMITS)
+            .build()
+            .unwrap();
+
+        // One at a time, so identical requests are not coalesced
+        for _ in 0..3 {
+            let client = client.clone();
+            let task = tokio::spawn(async move { client.get_server_time().await });
+            assert!(task.await.unwrap().is_ok());
+        }
+
+        let remaining = client
+            .remaining_credits(RateLimitCategory::NonMatchingEngine)
+            .await;
+        assert!(remaining < 80, "remaining credits: {}", remaining);
+    }
+
+    #[tokio::test]
+    async fn test_new_credit_limits_leave_earlier_clones_alone() {
+        let client = DeribitHttpClient::builder().build().unwrap();
+        let earlier = client.clone();
+        let limited = client.with_credit_limits(LIMITS, LIMITS);
+
+        assert_eq!(
+            limited
+                .credit_refill_rate(RateLimitCategory::MatchingEngine)
+                .await,
+            1
+        );
+        assert_ne!(
+            earlier
+                .credit_refill_rate(RateLimitCategory::MatchingEngine)
+                .await,
+            1
+        );
+        assert_eq!(limited.base_url(), earlier.base_url());
+    }
+}
+
+#[cfg(test)]
+mod coalescing_tests {
+    use super::*;
+    use deribit_http::config::RequestOptions;
+    use deribit_http::interceptor::{HookFuture, RequestInterceptor};
+    use deribit_http::{HttpError, HttpErrorKind};
+    use futures_util::future::join_all;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
+    use url::Url;
+
+    const TICKER: &str = r#"{"jsonrpc": "2.0", "result": {"instrument_name": "BTC-PERPETUAL",
+        "best_bid_amount": 1.0, "best_ask_amount": 1.0, "mark_price": 45000.0,
+        "timestamp": 1700000000000, "state": "open", "stats": {"volume": 1000.0}}}"#;
+
+    fn client_for(server: &mockito::Server) -> DeribitHttpClient {
+        DeribitHttpClient::builder()
+            .base_url(Url::parse(&format!("{}/api/v2", server.url())).unwrap())
+            .build()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_identical_requests_share_one_response() {
+        let mut server = mockito::Server::new_async().await;
+        let ticker = server
+            .mock("GET", "/api/v2/public/ticker?instrument_name=BTC-PERPETUAL")
+            .with_header("content-type", "application/json")
+            .with_body_from_request(|_| {
+                std::thread::sleep(Duration::from_millis(100));
+                TICKER.into()
+            })
+            .expect(1)
+            .create_async()
+            .await;
+        let client = client_for(&server);
+
+        let tickers = join_all((0..10).map(|_| client.get_ticker("BTC-PERPETUAL"))).await;
+
+        for ticker in tickers {
+            assert_eq!(ticker.unwrap().mark_price, 45000.0);
+        }
+        ticker.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_requests_after_completion_are_sent_again() {
+        let mut server = mockito::Server::new_async().await;
+        let ticker = server
+            .mock("GET", "/api/v2/public/ticker?instrument_name=BTC-PERPETUAL")
+            .with_header("content-type", "application/json")
+            .with_body(TICKER)
+            .expect(2)
+            .create_async()
+            .await;
+        let client = client_for(&server);
+
+        client.get_ticker("BTC-PERPETUAL").await.unwrap();
+        client.get_ticker("BTC-PERPETUAL").await.unwrap();
+
+        ticker.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_errors_are_shared_by_coalesced_requests() {
+        let mut server = mockito::Server::new_async().await;
+        let ticker = server
+            .mock("GET", "/api/v2/public/ticker?instrument_name=BTC-INVALID")
+            .with_status(400)
+            .with_header("content-type", "application/json")
+            .with_body_from_request(|_| {
+                std::thread::sleep(Duration::from_millis(100));
+                r#"{"jsonrpc": "2.0", "error": {"code": 10009, "message": "instrument_not_found"}}"#
+                    .into()
+            })
+            .expect(1)
+            .create_async()
+            .await;
+        let client = client_for(&server);
+
+        let results = join_all((0..3).map(|_| client.get_ticker("BTC-INVALID"))).await;
+
+        for result in results {
+            let error = result.unwrap_err();
+            assert!(matches!(
+                error.kind(),
+                HttpErrorKind::Api { code: 10009, .. }
+            ));
+            assert_eq!(error.status(), Some(400));
+        }
+        ticker.assert_async().await;
+    }
+
+    /// Counts the attempts it sees
+    #[derive(Default)]
+    struct Counter(AtomicUsize);
+
+    impl RequestInterceptor for Counter {
+        fn before_request<'a>(
+            &'a self,
+            request: &'a mut reqwest::Request,
+        ) -> HookFuture<'a, Result<(), HttpError>> {
+            let _ = request;
+            self.0.fetch_add(1, Ordering::SeqCst);
+            Box::pin(async { Ok(()) })
+        }
+    }
+
+    fn slow_ticker_mock(server: &mut mockito::Server, hits: usize) -> mockito::Mock {
+        server
+            .mock("GET", "/api/v2/public/ticker?instrument_name=BTC-PERPETUAL")
+            .with_header("content-type", "application/json")
+            .with_body_from_request(|_| {
+                std::thread::sleep(Duration::from_millis(100));
+                TICKER.into()
+            })
+            .expect(hits)
+    }
+
+    #[tokio::test]
+    async fn test_clients_with_different_interceptors_are_not_coalesced() {
+        let mut server = mockito::Server::new_async().await;
+        let ticker = slow_ticker_mock(&mut server, 2).create_async().await;
+        let client = client_for(&server);
+        let first = Arc::new(Counter::default());
+        let second = Arc::new(Counter::default());
+        let tagged = client.clone().with_interceptor(first.clone());
+        let other = client.clone().with_interceptor(second.clone());
+
+        let (a, b) = tokio::join!(
+            tagged.get_ticker("BTC-PERPETUAL"),
+            other.get_ticker("BTC-PERPETUAL")
+        );
+
+        a.unwrap();
+        b.unwrap();
+        assert_eq!(first.0.load(Ordering::SeqCst), 1);
+        assert_eq!(second.0.load(Ordering::SeqCst), 1);
+        ticker.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_clients_with_different_options_are_not_coalesced() {
+        let mut server = mockito::Server::new_async().await;
+        let ticker = slow_ticker_mock(&mut server, 2).create_async().await;
+        let client = client_for(&server);
+        let quoting = client.with_options(RequestOptions::new().with_max_retries(0));
+
+        let (a, b) = tokio::join!(
+            client.get_ticker("BTC-PERPETUAL"),
+            quoting.get_ticker("BTC-PERPETUAL")
+        );
+
+        a.unwrap();
+        b.unwrap();
+        ticker.assert_async().await;
+    }
+}
+
+#[cfg(test)]
+mod circuit_breaker_tests {
+    use super::*;
+    use deribit_http::HttpErrorKind;
+    use deribit_http::connection::{CircuitBreaker, CircuitBreakerConfig, CircuitState};
+    use std::time::Duration;
+    use url::Url;
+
+    fn client_for(server: &mockito::Server, breaker: &CircuitBreaker) -> DeribitHttpClient {
+        DeribitHttpClient::builder()
+            .base_url(Url::parse(&format!("{}/api/v2", server.url())).unwrap())
+            .max_retries(0)
+            .circuit_breaker(breaker.clone())
+            .build()
+            .unwrap()
+    }
+
+    fn breaker() -> CircuitBreaker {
+        CircuitBreaker::new(
+            CircuitBreakerConfig::new()
+                .with_window_size(2)
+                .with_minimum_requests(2)
+                .with_open_duration(Duration::from_secs(60)),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_open_circuit_fails_fast_without_sending() {
+        let mut server = mockito::Server::new_async().await;
+        let time = server
+            .mock("GET", "/api/v2/public/get_time")
+            .with_status(503)
+            .with_body("unavailable")
+            .expect(2)
+            .create_async()
+            .await;
+        let breaker = breaker();
+        let client = client_for(&server, &breaker);
+
+        for _ in 0..2 {
+            let error = client.get_server_time().await.unwrap_err();
+            assert!(matches!(
+                error.kind(),
+                HttpErrorKind::ServerError { status: 503, .. }
+            ));
+        }
+        assert_eq!(breaker.state(), CircuitState::Open);
+
+        let error = client.get_server_time().await.unwrap_err();
+        assert!(matches!(error.kind(), HttpErrorKind::CircuitOpen { .. }));
+        assert_eq!(error.endpoint(), Some("public/get_time"));
+        time.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_emergency_flatten_bypasses_open_circuit() {
+        let mut server = mockito::Server::new_async().await;
+        let _time = server
+            .mock("GET", "/api/v2/public/get_time")
+            .with_status(503)
+            .with_body("unavailable")
+            .create_async()
+            .await;
+        let _auth = server
+            .mock("GET", "/api/v2/public/auth?grant_type=client_credentials&client_id=test_client_id&client_secret=test_client_secret")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"{"jsonrpc": "2.0", "id": 1, "result": {"access_token": "test_access_token", "expires_in": 3600, "refresh_token": "test_refresh_token", "scope": "trade:read_write", "token_type": "bearer"}}"#,
+            )
+            .create_async()
+            .await;
+        let cancel = server
+            .mock("POST", "/api/v2/private/cancel_all_by_currency")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"jsonrpc": "2.0", "id": 1, "result": 2}"#)
+            .expect(1)
+            .create_async()
+            .await;
+        let positions = server
+            .mock("POST", "/api/v2/private/get_positions")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"jsonrpc": "2.0", "id": 1, "result": []}"#)
+            .expect(1)
+            .create_async()
+            .await;
+        let breaker = breaker();
+        let client = DeribitHttpClient::builder()
+            .base_url(Url::parse(&format!("{}/api/v2", server.url())).unwrap())
+            .max_retries(0)
+            .credentials("test_client_id", "test_client_secret")
+            .circuit_breaker(breaker.clone())
+            .build()
+            .unwrap();
+
+        for _ in 0..2 {
+            assert!(client.get_server_time().await.is_err());
+        }
+        assert_eq!(breaker.state(), CircuitState::Open);
+
+        let report = client.emergency_flatten(&["BTC"]).await;
+        assert!(report.is_complete());
+        assert_eq!(report.cancelled_orders(), 2);
+        cancel.assert_async().await;
+        positions.assert_async().await;
+
+        let error = client.cancel_all_by_currency("BTC").await.unwrap_err();
+        assert!(matches!(error.kind(), HttpErrorKind::CircuitOpen { .. }));
+    }
+
+    #[tokio::test]
+    async fn test_client_errors_do_not_open_circuit() {
+        let mut server = mockito::Server::new_async().await;
+        let _ticker = server
+            .mock("GET", "/api/v2/public/ticker?instrument_name=BTC-INVALID")
+            .with_status(400)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"{"jsonrpc": "2.0", "error": {"code": 10009, "message": "instrument_not_found"}}"#,
+            )
+            .create_async()
+            .await;
+        let breaker = breaker();
+        let client = client_for(&server, &breaker);
+
+        for _ in 0..3 {
+            let error = client.get_ticker("BTC-INVALID").await.unwrap_err();
+            assert!(matches!(error.kind(), HttpErrorKind::Api { .. }));
+        }
+        assert_eq!(breaker.state(), CircuitState::Closed);
+        assert!(
+            client
+                .circuit_breaker()
+                .unwrap()
+                .shares_state_with(&breaker)
+        );
+    }
+}
@@ -300,21 +300,18 @@ async fn test_create_combo_success() {
     });
 
     let mock = server
-        .mock(
-            "GET",
-            mockito::Matcher::Regex(r"/api/v2/private/create_combo\?trades=.*".to_string()),
-        )
+        .mock("POST", "/api/v2/private/create_combo")
+        .match_body(mockito::Matcher::Regex(r#""trades":\["#.to_string()))
         .with_status(200)
         .with_header("content-type", "application/json")
         .with_body(mock_response.to_string())
         .create_async()
         .await;
 
-    let trades = vec![
-        deribit_http::model::ComboTrade::buy("BTC-29APR22-37500-C", Some(1.0)),
-        deribit_http::model::ComboTrade::sell("BTC-29APR22-37500-P", Some(1.0)),
-    ];
-    let result = client.create_combo(&trades).await;
+    let request = deribit_http::model::CreateComboRequest::default()
+        .buy("BTC-29APR22-37500-C", Some(1.0))
+        .sell("BTC-29APR22-37500-P", Some(1.0));
+    let result = client.create_combo(&request).await;
 
     mock.assert_async().await;
     assert!(result.is_ok());
@@ -333,10 +330,8 @@ async fn test_create_combo_error() {
     let _auth_mock = create_auth_mock(&mut server).await;
 
     let mock = server
-        .mock(
-            "GET",
-            mockito::Matcher::Regex(r"/api/v2/private/create_combo\?trades=.*".to_string()),
-        )
+        .mock("POST", "/api/v2/private/create_combo")
+        .match_body(mockito::Matcher::Regex(r#""trades":\["#.to_string()))
         .with_status(400)
         .with_header("content-type", "application/json")
         .with_body(r#"{"jsonrpc": "2.0", "error": {"code": 11051, "message": "invalid_combo_structure"}, "id": 1}"#)
@@ -347,12 +342,34 @@ async fn test_create_combo_error() {
         "INVALID-INSTRUMENT",
         Some(1.0),
     )];
-    let result = client.create_combo(&trades).await;
+    let result = client.create_combo(&trades.into()).await;
 
     mock.assert_async().await;
     assert!(result.is_err());
 }
 
+#[tokio::test]
+async fn test_create_combo_without_legs_is_rejected() {
+    let mut server = mockito::Server::new_async().await;
+    let client = create_auth_test_client(&server);
+
+    let mock = server
+        .mock("POST", "/api/v2/private/create_combo")
+        .expect(0)
+        .create_async()
+        .await;
+
+    let result = client
+        .create_combo(&deribit_http::model::CreateComboRequest::default())
+        .await;
+
+    mock.assert_async().await;
+    assert!(matches!(
+        result.map_err(deribit_http::HttpError::into_kind),
+        Err(deribit_http::HttpErrorKind::ConfigError(_))
+    ));
+}
+
 #[tokio::test]
 async fn test_get_leg_prices_success() {
     let mut server = mockito::Server::new_async().await;
@@ -373,12 +390,10 @@ async fn test_get_leg_prices_success() {
     });
 
     let mock = server
-        .mock(
-            "GET",
-            mockito::Matcher::Regex(
-                r"/api/v2/private/get_leg_prices\?legs=.*&price=.*".to_string(),
-            ),
-        )
+        .mock("POST", "/api/v2/private/get_leg_prices")
+        .match_body(mockito::Matcher::Regex(
+            r#""legs":\[.*"price":"#.to_string(),
+        ))
         .with_status(200)
         .with_header("content-type", "application/json")
         .with_body(mock_response.to_string())
@@ -410,10 +425,8 @@ async fn test_get_leg_prices_error() {
     let _auth_mock = create_auth_mock(&mut server).await;
 
     let mock = server
-        .mock(
-            "GET",
-            mockito::Matcher::Regex(r"/api/v2/private/get_leg_prices\?legs=.*&price=.*".to_string()),
-        )
+        .mock("POST", "/api/v2/private/get_leg_prices")
+        .match_body(mockito::Matcher::Regex(r#""legs":\[.*"price":"#.to_string()))
         .with_status(400)
         .with_header("content-type", "application/json")
         .with_body(r#"{"jsonrpc": "2.0", "error": {"code": 11052, "message": "invalid_leg_structure"}, "id": 1}"#)
@@ -47,6 +47,14 @@ fn test_http_config_with_timeout() {
     assert_eq!(config.timeout, Duration::from_secs(60));
 }
 
+#[test]
+fn test_http_config_with_debug_raw_responses() {
+    let config = HttpConfig::testnet().with_debug_raw_responses(true);
+
+    assert!(config.debug_raw_responses);
+    assert!(!config.with_debug_raw_responses(false).debug_raw_responses);
+}
+
 #[test]
 fn test_http_config_with_user_agent() {
     let config = HttpConfig::testnet().with_user_agent("MyBot/1.0".to_string());
@@ -112,3 +120,59 @@ fn test_http_config_base_url_production() {
     let config = HttpConfig::production();
     assert!(config.base_url.as_str().contains("www.deribit.com"));
 }
+
+#[test]
+fn test_http_config_debug_and_display_redact_the_secret() {
+    let config = HttpConfig::testnet().with_oauth2("my_id".to_string(), "my_secret".to_string());
+
+    for text in [format!("{:?}", config), config.to_string()] {
+        assert!(text.contains("my_id"));
+        assert!(text.contains("[REDACTED]"));
+        assert!(!text.contains("my_secret"));
+    }
+    let credentials = config.credentials().unwrap();
+    assert!(!format!("{:?}", credentials).contains("my_secret"));
+    assert!(!credentials.to_string().contains("my_secret"));
+}
+
+#[test]
+fn test_http_config_zeroize_clears_credentials() {
+    use zeroize::Zeroize;
+
+    let mut config =
+        HttpConfig::testnet().with_oauth2("my_id".to_string(), "my_secret".to_string());
+    config.zeroize();
+
+    assert!(config.credentials.is_none());
+    assert!(!config.has_credentials());
+
+    let mut credentials = ApiCredentials {
+        client_id: Some("my_id".to_string()),
+        client_secret: Some("my_secret".to_string()),
+    };
+    credentials.zeroize();
+    assert!(!credentials.is_valid());
+}
+
+#[test]
+fn test_http_config_with_pool() {
+    use deribit_http::config::PoolConfig;
+
+    let pool = PoolConfig::new()
+        .with_max_idle_per_host(4)
+        .with_idle_timeout(Duration::from_secs(600))
+        .with_tcp_keepalive(Duration::from_secs(30))
+        .with_connect_timeout(Duration::from_secs(2));
+    let config = HttpConfig::testnet().with_pool(pool);
+
+    assert_eq!(config.pool.max_idle_per_host, Some(4));
+    assert_eq!(config.pool.idle_timeout, Some(Duration::from_secs(600)));
+    assert_eq!(config.pool.tcp_keepalive, Some(Duration::from_secs(30)));
+    assert_eq!(config.pool.connect_timeout, Some(Duration::from_secs(2)));
+    assert_eq!(HttpConfig::testnet().pool, PoolConfig::default());
+
+    let mut json = serde_json::to_value(&config).unwrap();
+    json.as_object_mut().unwrap().remove("pool");
+    let parsed: HttpConfig = serde_json::from_value(json).unwrap();
+    assert_eq!(parsed.pool, PoolConfig::default());
+}
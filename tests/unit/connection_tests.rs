@@ -20,6 +20,13 @@ async fn test_http_connection_new() {
         max_retries: 3,
         testnet: true,
         credentials: None,
+        credentials_provider: None,
+        debug_raw_responses: false,
+        price_normalization: None,
+        transport: None,
+        proxy: None,
+        default_headers: Default::default(),
+        pool: Default::default(),
     };
 
     let connection = HttpConnection::new(config.clone()).unwrap();
@@ -39,6 +46,13 @@ async fn test_http_connection_config_access() {
         max_retries: 5,
         testnet: false,
         credentials: None,
+        credentials_provider: None,
+        debug_raw_responses: false,
+        price_normalization: None,
+        transport: None,
+        proxy: None,
+        default_headers: Default::default(),
+        pool: Default::default(),
     };
 
     let connection = HttpConnection::new(config.clone()).unwrap();
@@ -62,6 +76,13 @@ async fn test_http_connection_client_access() {
         max_retries: 3,
         testnet: true,
         credentials: None,
+        credentials_provider: None,
+        debug_raw_responses: false,
+        price_normalization: None,
+        transport: None,
+        proxy: None,
+        default_headers: Default::default(),
+        pool: Default::default(),
     };
 
     let connection = HttpConnection::new(config).unwrap();
@@ -94,6 +115,13 @@ mod mock_tests {
             max_retries: 3,
             testnet: false,
             credentials: None,
+            credentials_provider: None,
+            debug_raw_responses: false,
+            price_normalization: None,
+            transport: None,
+            proxy: None,
+            default_headers: Default::default(),
+            pool: Default::default(),
         };
 
         let connection = HttpConnection::new(config).unwrap();
@@ -133,6 +161,13 @@ mod mock_tests {
             max_retries: 3,
             testnet: false,
             credentials: None,
+            credentials_provider: None,
+            debug_raw_responses: false,
+            price_normalization: None,
+            transport: None,
+            proxy: None,
+            default_headers: Default::default(),
+            pool: Default::default(),
         };
 
         let connection = HttpConnection::new(config).unwrap();
@@ -172,6 +207,13 @@ mod mock_tests {
             max_retries: 3,
             testnet: false,
             credentials: None,
+            credentials_provider: None,
+            debug_raw_responses: false,
+            price_normalization: None,
+            transport: None,
+            proxy: None,
+            default_headers: Default::default(),
+            pool: Default::default(),
         };
 
         let connection = HttpConnection::new(config).unwrap();
@@ -209,6 +251,13 @@ mod mock_tests {
             max_retries: 3,
             testnet: false,
             credentials: None,
+            credentials_provider: None,
+            debug_raw_responses: false,
+            price_normalization: None,
+            transport: None,
+            proxy: None,
+            default_headers: Default::default(),
+            pool: Default::default(),
         };
 
         let connection = HttpConnection::new(config).unwrap();
@@ -249,6 +298,13 @@ mod mock_tests {
             max_retries: 3,
             testnet: false,
             credentials: None,
+            credentials_provider: None,
+            debug_raw_responses: false,
+            price_normalization: None,
+            transport: None,
+            proxy: None,
+            default_headers: Default::default(),
+            pool: Default::default(),
         };
 
         let connection = HttpConnection::new(config).unwrap();
@@ -283,6 +339,13 @@ mod mock_tests {
             max_retries: 3,
             testnet: false,
             credentials: None,
+            credentials_provider: None,
+            debug_raw_responses: false,
+            price_normalization: None,
+            transport: None,
+            proxy: None,
+            default_headers: Default::default(),
+            pool: Default::default(),
         };
 
         let connection = HttpConnection::new(config).unwrap();
@@ -297,8 +360,8 @@ mod mock_tests {
         let result = connection.send_request(&request).await;
         assert!(result.is_err());
 
-        match result.unwrap_err() {
-            HttpError::RequestFailed(msg) => {
+        match result.unwrap_err().into_kind() {
+            HttpErrorKind::RequestFailed(msg) => {
                 assert!(msg.contains("Unsupported method: PATCH"));
             }
             _ => panic!("Expected RequestFailed error"),
@@ -314,6 +377,13 @@ mod mock_tests {
             max_retries: 3,
             testnet: false,
             credentials: None,
+            credentials_provider: None,
+            debug_raw_responses: false,
+            price_normalization: None,
+            transport: None,
+            proxy: None,
+            default_headers: Default::default(),
+            pool: Default::default(),
         };
 
         let connection = HttpConnection::new(config).unwrap();
@@ -328,8 +398,8 @@ mod mock_tests {
         let result = connection.send_request(&request).await;
         assert!(result.is_err());
 
-        match result.unwrap_err() {
-            HttpError::NetworkError(_) => {
+        match result.unwrap_err().into_kind() {
+            HttpErrorKind::NetworkError(_) => {
                 // Expected network error
             }
             _ => panic!("Expected NetworkError"),
@@ -356,6 +426,13 @@ mod mock_tests {
             max_retries: 3,
             testnet: false,
             credentials: None,
+            credentials_provider: None,
+            debug_raw_responses: false,
+            price_normalization: None,
+            transport: None,
+            proxy: None,
+            default_headers: Default::default(),
+            pool: Default::default(),
         };
 
         let connection = HttpConnection::new(config).unwrap();
@@ -379,3 +456,123 @@ mod mock_tests {
         mock.assert_async().await;
     }
 }
+
+#[cfg(test)]
+mod circuit_breaker_tests {
+    use super::*;
+
+    fn breaker(open_duration: Duration) -> CircuitBreaker {
+        CircuitBreaker::new(
+            CircuitBreakerConfig::new()
+                .with_failure_rate_threshold(0.5)
+                .with_window_size(4)
+                .with_minimum_requests(4)
+                .with_open_duration(open_duration),
+        )
+    }
+
+    fn record(breaker: &CircuitBreaker, failed: bool) {
+        let permit = breaker.try_acquire().unwrap();
+        if failed {
+            permit.failure();
+        } else {
+            permit.success();
+        }
+    }
+
+    #[test]
+    fn test_circuit_stays_closed_below_minimum_requests() {
+        let breaker = breaker(Duration::from_secs(60));
+        for _ in 0..3 {
+            record(&breaker, true);
+        }
+        assert_eq!(breaker.state(), CircuitState::Closed);
+        assert_eq!(breaker.failure_rate(), 1.0);
+    }
+
+    #[test]
+    fn test_circuit_opens_at_failure_rate_threshold() {
+        let breaker = breaker(Duration::from_secs(60));
+        record(&breaker, false);
+        record(&breaker, true);
+        record(&breaker, false);
+        record(&breaker, true);
+
+        assert_eq!(breaker.state(), CircuitState::Open);
+        let error = breaker.try_acquire().unwrap_err();
+        assert!(matches!(
+            error.kind(),
+            HttpErrorKind::CircuitOpen { retry_after } if *retry_after > Duration::from_secs(59)
+        ));
+    }
+
+    #[test]
+    fn test_window_forgets_old_outcomes() {
+        let breaker = breaker(Duration::from_secs(60));
+        record(&breaker, true);
+        for _ in 0..4 {
+            record(&breaker, false);
+        }
+        record(&breaker, true);
+        assert_eq!(breaker.state(), CircuitState::Closed);
+        assert_eq!(breaker.failure_rate(), 0.25);
+    }
+
+    #[test]
+    fn test_half_open_circuit_admits_one_probe_and_closes_on_success() {
+        let breaker = breaker(Duration::ZERO);
+        for _ in 0..4 {
+            record(&breaker, true);
+        }
+        assert_eq!(breaker.state(), CircuitState::HalfOpen);
+
+        let probe = breaker.try_acquire().unwrap();
+        assert!(probe.is_probe());
+        assert!(matches!(
+            breaker.try_acquire().map_err(HttpError::into_kind),
+            Err(HttpErrorKind::CircuitOpen { .. })
+        ));
+
+        probe.success();
+        assert_eq!(breaker.state(), CircuitState::Closed);
+        assert!(!breaker.try_acquire().unwrap().is_probe());
+    }
+
+    #[test]
+    fn test_failed_probe_reopens_circuit() {
+        let breaker = breaker(Duration::from_millis(50));
+        for _ in 0..4 {
+            record(&breaker, true);
+        }
+        std::thread::sleep(Duration::from_millis(60));
+
+        breaker.try_acquire().unwrap().failure();
+        assert_eq!(breaker.state(), CircuitState::Open);
+    }
+
+    #[test]
+    fn test_dropped_probe_frees_the_probe_slot() {
+        let breaker = breaker(Duration::ZERO);
+        for _ in 0..4 {
+            record(&breaker, true);
+        }
+
+        drop(breaker.try_acquire().unwrap());
+        assert!(breaker.try_acquire().unwrap().is_probe());
+    }
+
+    #[test]
+    fn test_reset_closes_circuit_for_every_clone() {
+        let breaker = breaker(Duration::from_secs(60));
+        let clone = breaker.clone();
+        for _ in 0..4 {
+            record(&breaker, true);
+        }
+        assert_eq!(clone.state(), CircuitState::Open);
+
+        clone.reset();
+        assert_eq!(breaker.state(), CircuitState::Closed);
+        assert!(breaker.shares_state_with(&clone));
+        assert!(!breaker.shares_state_with(&CircuitBreaker::default()));
+    }
+}
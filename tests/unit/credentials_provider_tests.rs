@@ -0,0 +1,242 @@
+#[cfg(feature = "aws-secrets-manager")]
+use deribit_http::config::AwsSecretsManagerCredentials;
+#[cfg(feature = "vault")]
+use deribit_http::config::VaultCredentials;
+use deribit_http::config::{ApiCredentials, CredentialsProvider, EnvCredentials, FileCredentials};
+use deribit_http::interceptor::HookFuture;
+use deribit_http::{AuthManager, HttpConfig, HttpError, HttpErrorKind};
+use mockito::Matcher;
+use std::sync::{Arc, Mutex};
+
+fn credentials(client_id: &str, client_secret: &str) -> ApiCredentials {
+    ApiCredentials {
+        client_id: Some(client_id.to_string()),
+        client_secret: Some(client_secret.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod provider_tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_env_credentials_are_read_on_every_call() {
+        let provider = EnvCredentials::with_vars("PROVIDER_TEST_ID", "PROVIDER_TEST_SECRET");
+        assert!(matches!(
+            provider.credentials().await.map_err(HttpError::into_kind),
+            Err(HttpErrorKind::ConfigError(message)) if message == "PROVIDER_TEST_ID is not set"
+        ));
+
+        unsafe {
+            std::env::set_var("PROVIDER_TEST_ID", "id_1");
+            std::env::set_var("PROVIDER_TEST_SECRET", "secret_1");
+        }
+        let read = provider.credentials().await.unwrap();
+        assert_eq!(read.client_id.as_deref(), Some("id_1"));
+        assert_eq!(read.client_secret.as_deref(), Some("secret_1"));
+    }
+
+    #[tokio::test]
+    async fn test_file_credentials_follow_the_file() {
+        let path =
+            std::env::temp_dir().join(format!("deribit_credentials_{}.json", std::process::id()));
+        let provider = FileCredentials::new(&path);
+
+        std::fs::write(
+            &path,
+            r#"{"client_id": "id_1", "client_secret": "secret_1"}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            provider.credentials().await.unwrap().client_id.as_deref(),
+            Some("id_1")
+        );
+
+        std::fs::write(
+            &path,
+            r#"{"client_id": "id_2", "client_secret": "secret_2"}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            provider.credentials().await.unwrap().client_id.as_deref(),
+            Some("id_2")
+        );
+
+        std::fs::write(&path, "client_id=id_3").unwrap();
+        assert!(matches!(
+            provider.credentials().await.map_err(HttpError::into_kind),
+            Err(HttpErrorKind::ConfigError(_))
+        ));
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[cfg(feature = "vault")]
+    #[tokio::test]
+    async fn test_vault_credentials() {
+        let mut server = mockito::Server::new_async().await;
+        let secret = server
+            .mock("GET", "/v1/kv/data/trading/deribit")
+            .match_header("x-vault-token", "hvs.test")
+            .with_status(200)
+            .with_body(
+                r#"{"data": {"data": {"client_id": "vault_id", "client_secret": "vault_secret"},
+                "metadata": {"version": 3}}}"#,
+            )
+            .create_async()
+            .await;
+        let provider =
+            VaultCredentials::new(server.url().parse().unwrap(), "hvs.test", "trading/deribit")
+                .with_mount("kv");
+
+        let read = provider.credentials().await.unwrap();
+        assert_eq!(read.client_id.as_deref(), Some("vault_id"));
+        assert_eq!(read.client_secret.as_deref(), Some("vault_secret"));
+        secret.assert_async().await;
+        assert!(!format!("{:?}", provider).contains("hvs.test"));
+
+        let denied = VaultCredentials::new(server.url().parse().unwrap(), "hvs.test", "other");
+        assert!(matches!(
+            denied.credentials().await.map_err(HttpError::into_kind),
+            Err(HttpErrorKind::RequestFailed(_))
+        ));
+    }
+
+    #[cfg(feature = "aws-secrets-manager")]
+    #[tokio::test]
+    async fn test_aws_secrets_manager_credentials() {
+        let mut server = mockito::Server::new_async().await;
+        let secret = server
+            .mock("POST", "/")
+            .match_header("x-amz-target", "secretsmanager.GetSecretValue")
+            .match_header("x-amz-security-token", "session")
+            .match_header(
+                "authorization",
+                Matcher::Regex(
+                    r"^AWS4-HMAC-SHA256 Credential=AKID/\d{8}/eu-west-1/secretsmanager/aws4_request, SignedHeaders=content-type;host;x-amz-date;x-amz-security-token;x-amz-target, Signature=[0-9a-f]{64}$"
+                        .to_string(),
+                ),
+            )
+            .match_body(Matcher::Json(serde_json::json!({"SecretId": "prod/deribit"})))
+            .with_status(200)
+            .with_body(
+                r#"{"Name": "prod/deribit",
+                "SecretString": "{\"client_id\": \"aws_id\", \"client_secret\": \"aws_secret\"}"}"#,
+            )
+            .create_async()
+            .await;
+        let provider =
+            AwsSecretsManagerCredentials::new("eu-west-1", "prod/deribit", "AKID", "key")
+                .unwrap()
+                .with_session_token("session")
+                .with_endpoint(format!("{}/", server.url()).parse().unwrap());
+
+        let read = provider.credentials().await.unwrap();
+        assert_eq!(read.client_id.as_deref(), Some("aws_id"));
+        secret.assert_async().await;
+    }
+}
+
+#[cfg(test)]
+mod rotation_tests {
+    use super::*;
+
+    /// Provider whose credentials the test swaps
+    struct Rotating(Mutex<ApiCredentials>);
+
+    impl CredentialsProvider for Rotating {
+        fn credentials(&self) -> HookFuture<'_, Result<ApiCredentials, HttpError>> {
+            let current = self.0.lock().unwrap().clone();
+            Box::pin(async move { Ok(current) })
+        }
+    }
+
+    /// Token expiring within the refresh margin, so every call renews it
+    fn token_body(access_token: &str, refresh_token: &str) -> String {
+        format!(
+            r#"{{"jsonrpc": "2.0", "result": {{"access_token": "{}", "expires_in": 60,
+            "refresh_token": "{}", "scope": "trade:read_write", "token_type": "bearer"}}}}"#,
+            access_token, refresh_token
+        )
+    }
+
+    #[tokio::test]
+    async fn test_rotated_credentials_replace_the_session() {
+        let mut server = mockito::Server::new_async().await;
+        let grant = |client_id: &str| {
+            Matcher::AllOf(vec![
+                Matcher::UrlEncoded("grant_type".into(), "client_credentials".into()),
+                Matcher::UrlEncoded("client_id".into(), client_id.into()),
+            ])
+        };
+        let first = server
+            .mock("GET", "/api/v2/public/auth")
+            .match_query(grant("id_1"))
+            .with_body(token_body("t1", "r1"))
+            .expect(1)
+            .create_async()
+            .await;
+        let refresh = server
+            .mock("GET", "/api/v2/public/auth")
+            .match_query(Matcher::UrlEncoded("refresh_token".into(), "r1".into()))
+            .with_body(token_body("t2", "r2"))
+            .expect(1)
+            .create_async()
+            .await;
+        let rotated = server
+            .mock("GET", "/api/v2/public/auth")
+            .match_query(grant("id_2"))
+            .with_body(token_body("t3", "r3"))
+            .expect(1)
+            .create_async()
+            .await;
+
+        let provider = Arc::new(Rotating(Mutex::new(credentials("id_1", "secret_1"))));
+        let config = HttpConfig {
+            base_url: format!("{}/api/v2", server.url()).parse().unwrap(),
+            credentials: None,
+            ..HttpConfig::testnet()
+        }
+        .with_credentials_provider(provider.clone());
+        let manager = AuthManager::new(reqwest::Client::new(), config);
+
+        assert_eq!(
+            manager.get_authorization_header().await.as_deref(),
+            Some("bearer t1")
+        );
+        assert_eq!(
+            manager.get_authorization_header().await.as_deref(),
+            Some("bearer t2")
+        );
+
+        *provider.0.lock().unwrap() = credentials("id_2", "secret_2");
+        assert_eq!(
+            manager.get_authorization_header().await.as_deref(),
+            Some("bearer t3")
+        );
+
+        first.assert_async().await;
+        refresh.assert_async().await;
+        rotated.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_provider_takes_precedence_over_static_credentials() {
+        let mut server = mockito::Server::new_async().await;
+        let auth = server
+            .mock("GET", "/api/v2/public/auth")
+            .match_query(Matcher::UrlEncoded("client_id".into(), "provided".into()))
+            .with_body(token_body("t1", "r1"))
+            .create_async()
+            .await;
+        let config = HttpConfig {
+            base_url: format!("{}/api/v2", server.url()).parse().unwrap(),
+            ..HttpConfig::testnet()
+        }
+        .with_oauth2("static".to_string(), "secret".to_string())
+        .with_credentials_provider(credentials("provided", "secret"));
+        let manager = AuthManager::new(reqwest::Client::new(), config);
+
+        manager.authenticate_oauth2().await.unwrap();
+        auth.assert_async().await;
+    }
+}
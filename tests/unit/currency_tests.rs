@@ -3,6 +3,7 @@
 use deribit_http::model::currency::{
     Currency, CurrencyExpirations, CurrencyInfo, CurrencyInfoCollection, CurrencyStruct,
 };
+use deribit_http::model::portfolio_simulation::SimulatePortfolioRequest;
 use deribit_http::model::withdrawal::WithdrawalPriority;
 use serde_json;
 
@@ -35,6 +36,22 @@ mod tests {
         assert_eq!(Currency::Eurr.to_string(), "EURR");
     }
 
+    #[test]
+    fn test_currency_as_str() {
+        assert_eq!(Currency::Btc.as_str(), "BTC");
+        assert_eq!(Currency::Eurr.as_ref(), "EURR");
+        for currency in [Currency::Eth, Currency::Usdc, Currency::Usdt] {
+            assert_eq!(currency.as_str(), currency.to_string());
+        }
+    }
+
+    #[test]
+    fn test_currency_into_string() {
+        assert_eq!(String::from(Currency::Usdc), "USDC");
+        let request = SimulatePortfolioRequest::new(Currency::Eth);
+        assert_eq!(request.currency, "ETH");
+    }
+
     #[test]
     fn test_currency_serialization() {
         let btc = Currency::Btc;
@@ -57,12 +74,12 @@ mod tests {
     }
 
     #[test]
-    fn test_currency_clone() {
+    fn test_currency_copy() {
         let btc = Currency::Btc;
-        let btc_clone = btc.clone();
+        let btc_copy = btc;
 
-        // Test that clone works by comparing display format
-        assert_eq!(format!("{}", btc), format!("{}", btc_clone));
+        assert_eq!(btc, btc_copy);
+        assert_eq!(format!("{}", btc), format!("{}", btc_copy));
     }
 }
 
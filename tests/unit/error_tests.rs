@@ -0,0 +1,263 @@
+use deribit_http::{
+    DeribitErrorCode, DeribitHttpClient, ErrorContext, HttpConfig, HttpError, HttpErrorKind,
+};
+use mockito::Matcher;
+use std::env;
+use url::Url;
+
+fn create_test_client(server: &mockito::ServerGuard) -> DeribitHttpClient {
+    unsafe {
+        env::set_var("DERIBIT_CLIENT_ID", "test_client_id");
+        env::set_var("DERIBIT_CLIENT_SECRET", "test_client_secret");
+    }
+    let config = HttpConfig {
+        base_url: Url::parse(&format!("{}/api/v2", server.url())).unwrap(),
+        ..Default::default()
+    };
+    DeribitHttpClient::with_config(config)
+}
+
+#[cfg(test)]
+mod error_code_tests {
+    use super::*;
+
+    #[test]
+    fn test_error_codes_round_trip() {
+        for code in [10009, 10021, 10007, 10028, 13009, -32602] {
+            let known = DeribitErrorCode::from_code(code).unwrap();
+            assert_eq!(known.code(), code);
+        }
+        assert_eq!(
+            DeribitErrorCode::from_code(10009),
+            Some(DeribitErrorCode::NotEnoughFunds)
+        );
+        assert_eq!(DeribitErrorCode::InvalidAmount.as_str(), "invalid_amount");
+        assert_eq!(
+            DeribitErrorCode::PriceTooHigh.to_string(),
+            "price_too_high (10007)"
+        );
+        assert_eq!(DeribitErrorCode::from_code(99999), None);
+    }
+
+    #[test]
+    fn test_error_code_only_for_api_errors() {
+        let api = HttpError::from(HttpErrorKind::Api {
+            code: 10021,
+            message: "invalid_amount".to_string(),
+            data: None,
+        });
+        assert_eq!(api.error_code(), Some(DeribitErrorCode::InvalidAmount));
+
+        let unknown = HttpError::from(HttpErrorKind::Api {
+            code: 42,
+            message: "something".to_string(),
+            data: None,
+        });
+        assert_eq!(unknown.error_code(), None);
+        assert_eq!(
+            HttpError::from(HttpErrorKind::RequestFailed("x".to_string())).error_code(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_rate_limit_error_keeps_api_code() {
+        let error = HttpError::from(deribit_http::prelude::ApiError {
+            code: 10028,
+            message: "too_many_requests".to_string(),
+            data: None,
+        });
+        assert!(matches!(
+            error.kind(),
+            HttpErrorKind::RateLimitExceeded { retry_after: None }
+        ));
+        assert_eq!(error.error_code(), Some(DeribitErrorCode::TooManyRequests));
+        assert_eq!(error.to_string(), "Rate limit exceeded");
+    }
+
+    #[tokio::test]
+    async fn test_endpoint_error_object_is_structured() {
+        let mut server = mockito::Server::new_async().await;
+        let _auth = server
+            .mock("GET", "/api/v2/public/auth")
+            .match_query(Matcher::Any)
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"{"jsonrpc": "2.0", "id": 1, "result": {"access_token": "t", "expires_in": 3600,
+                "refresh_token": "r", "scope": "trade:read_write", "token_type": "bearer"}}"#,
+            )
+            .create_async()
+            .await;
+        let _subaccounts = server
+            .mock("POST", "/api/v2/private/get_subaccounts")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"{"jsonrpc": "2.0", "id": 1, "error": {"code": 11042, "message": "permission_denied", "data": {"reason": "scope"}}}"#,
+            )
+            .create_async()
+            .await;
+        let client = create_test_client(&server);
+
+        let error = client.get_subaccounts(None).await.unwrap_err();
+
+        assert_eq!(error.error_code(), Some(DeribitErrorCode::PermissionDenied));
+        match error.into_kind() {
+            HttpErrorKind::Api {
+                code,
+                message,
+                data,
+            } => {
+                assert_eq!(code, 11042);
+                assert_eq!(message, "permission_denied");
+                assert_eq!(data, Some(serde_json::json!({"reason": "scope"})));
+            }
+            other => panic!("expected Api error, got {:?}", other),
+        }
+    }
+}
+
+#[cfg(test)]
+mod context_tests {
+    use super::*;
+
+    #[test]
+    fn test_context_is_displayed_and_looked_through() {
+        let error = HttpError::from(HttpErrorKind::Api {
+            code: 10009,
+            message: "not_enough_funds".to_string(),
+            data: None,
+        })
+        .with_context(
+            ErrorContext::for_endpoint("/private/buy")
+                .with_status(400)
+                .with_request_id(Some(7)),
+        );
+
+        assert_eq!(
+            error.to_string(),
+            "API error: 10009 - not_enough_funds (private/buy, HTTP 400, request id 7)"
+        );
+        assert_eq!(error.endpoint(), Some("private/buy"));
+        assert_eq!(error.status(), Some(400));
+        assert_eq!(error.request_id(), Some(7));
+        assert_eq!(error.error_code(), Some(DeribitErrorCode::NotEnoughFunds));
+        assert!(matches!(
+            error.kind(),
+            HttpErrorKind::Api { code: 10009, .. }
+        ));
+    }
+
+    #[test]
+    fn test_outer_context_only_fills_gaps() {
+        let error = HttpError::from(HttpErrorKind::InvalidResponse("bad body".to_string()))
+            .with_context(ErrorContext::default().with_status(200))
+            .with_context(ErrorContext::for_endpoint("public/ticker").with_status(500));
+
+        assert_eq!(
+            error.context(),
+            &ErrorContext {
+                endpoint: Some("public/ticker".to_string()),
+                status: Some(200),
+                ..ErrorContext::default()
+            }
+        );
+        assert!(matches!(
+            error.into_kind(),
+            HttpErrorKind::InvalidResponse(_)
+        ));
+    }
+
+    #[test]
+    fn test_errors_without_context() {
+        let error = HttpError::from(HttpErrorKind::NetworkError("reset".to_string()))
+            .with_context(ErrorContext::default());
+
+        assert!(error.context().is_empty());
+        assert_eq!(error.endpoint(), None);
+        assert_eq!(error.to_string(), "Network error: reset");
+
+        let server_error = HttpError::from(HttpErrorKind::ServerError {
+            status: 503,
+            message: "unavailable".to_string(),
+        });
+        assert_eq!(server_error.status(), Some(503));
+    }
+
+    #[tokio::test]
+    async fn test_parse_errors_carry_endpoint_status_and_id() {
+        let mut server = mockito::Server::new_async().await;
+        let _ticker = server
+            .mock("GET", "/api/v2/public/ticker")
+            .match_query(Matcher::Any)
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"jsonrpc": "2.0", "id": 42, "result": {"unexpected": true}}"#)
+            .create_async()
+            .await;
+        let client = create_test_client(&server);
+
+        let error = client.get_ticker("BTC-PERPETUAL").await.unwrap_err();
+
+        assert!(matches!(error.kind(), HttpErrorKind::InvalidResponse(_)));
+        assert_eq!(error.endpoint(), Some("public/ticker"));
+        assert_eq!(error.status(), Some(200));
+        assert_eq!(error.request_id(), Some(42));
+        assert!(
+            error
+                .to_string()
+                .ends_with("(public/ticker, HTTP 200, request id 42)")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_http_errors_carry_endpoint_status_and_id() {
+        let mut server = mockito::Server::new_async().await;
+        let _auth = server
+            .mock("GET", "/api/v2/public/auth")
+            .match_query(Matcher::Any)
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"{"jsonrpc": "2.0", "id": 1, "result": {"access_token": "t", "expires_in": 3600,
+                "refresh_token": "r", "scope": "trade:read_write", "token_type": "bearer"}}"#,
+            )
+            .create_async()
+            .await;
+        let _subaccounts = server
+            .mock("POST", "/api/v2/private/get_subaccounts")
+            .with_status(400)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"{"jsonrpc": "2.0", "id": 9, "error": {"code": 10004, "message": "order_not_found"}}"#,
+            )
+            .create_async()
+            .await;
+        let client = create_test_client(&server);
+
+        let error = client.get_subaccounts(None).await.unwrap_err();
+
+        assert_eq!(error.error_code(), Some(DeribitErrorCode::OrderNotFound));
+        assert_eq!(error.endpoint(), Some("private/get_subaccounts"));
+        assert_eq!(error.status(), Some(400));
+        assert_eq!(error.request_id(), Some(9));
+    }
+
+    #[tokio::test]
+    async fn test_network_errors_carry_endpoint_and_sent_id() {
+        let config = HttpConfig {
+            base_url: Url::parse("http://127.0.0.1:1/api/v2").unwrap(),
+            ..Default::default()
+        }
+        .with_max_retries(0);
+        let client = DeribitHttpClient::with_config(config);
+
+        let error = client.call_raw("public/get_time", ()).await.unwrap_err();
+
+        assert!(matches!(error.kind(), HttpErrorKind::NetworkError(_)));
+        assert_eq!(error.endpoint(), Some("public/get_time"));
+        assert_eq!(error.status(), None);
+        assert!(error.request_id().is_some());
+    }
+}
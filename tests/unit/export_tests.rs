@@ -0,0 +1,229 @@
+use deribit_http::export::{
+    Column, Table, ToTable, export_csv, export_parquet, write_csv, write_parquet,
+};
+use deribit_http::model::TimestampMs;
+use deribit_http::model::order::OrderSide;
+use deribit_http::model::trade::{LastTrade, Liquidity, Trade};
+use deribit_http::model::tradingview::TradingViewChartData;
+use deribit_http::model::transaction::{TransactionLogEntry, TransactionSide};
+use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+
+fn create_chart_data() -> TradingViewChartData {
+    let mut chart_data = TradingViewChartData::new();
+    chart_data.add_candle(
+        TimestampMs::from_millis(1640995200000),
+        50000.0,
+        51000.0,
+        49500.0,
+        50500.0,
+        10.0,
+        505000.0,
+    );
+    chart_data.add_candle(
+        TimestampMs::from_millis(1640995260000),
+        50500.0,
+        50800.0,
+        50200.0,
+        50300.0,
+        8.0,
+        402400.0,
+    );
+    chart_data
+}
+
+fn create_trade() -> Trade {
+    Trade {
+        trade_id: "trade_789".to_string(),
+        instrument_name: "BTC-PERPETUAL".to_string(),
+        order_id: "order_456".to_string(),
+        direction: OrderSide::Sell,
+        amount: 1.5,
+        price: 50000.0,
+        timestamp: TimestampMs(1640995200000),
+        fee: 0.0005,
+        fee_currency: "BTC".to_string(),
+        liquidity: Liquidity::Taker,
+        mark_price: 50000.0,
+        index_price: 49900.0,
+        instrument_kind: None,
+        trade_seq: None,
+        user_role: None,
+        block_trade: None,
+        underlying_price: None,
+        iv: None,
+        label: None,
+        profit_loss: None,
+        tick_direction: None,
+        self_trade: None,
+    }
+}
+
+fn create_last_trade(trade_seq: u64) -> LastTrade {
+    LastTrade {
+        amount: 1.5,
+        direction: "buy".to_string(),
+        index_price: 49900.0,
+        instrument_name: "BTC-PERPETUAL".to_string(),
+        iv: None,
+        liquid: Some("M".to_string()),
+        price: 50000.0,
+        tick_direction: 1,
+        timestamp: TimestampMs(1640995200000 + trade_seq),
+        trade_id: format!("trade_{trade_seq}"),
+        trade_seq,
+    }
+}
+
+fn create_transaction_log_entry() -> TransactionLogEntry {
+    TransactionLogEntry {
+        id: 12345,
+        currency: "BTC".to_string(),
+        amount: Some(1.0),
+        balance: 1.5,
+        timestamp: TimestampMs(1640995200000),
+        transaction_type: "trade".to_string(),
+        info: None,
+        change: -0.0001,
+        cashflow: -0.0001,
+        user_id: 1,
+        trade_id: Some("trade_456".to_string()),
+        order_id: None,
+        position: Some(1.0),
+        side: Some(TransactionSide::OpenBuy),
+        contracts: None,
+        interest_pl: None,
+        user_role: None,
+        fee_role: None,
+        index_price: None,
+        price: Some(45000.0),
+        user_seq: 1001,
+        settlement_price: None,
+        price_currency: None,
+        equity: 1.5,
+        total_interest_pl: None,
+        session_upl: None,
+        profit_as_cashflow: None,
+        commission: Some(0.0001),
+        session_rpl: None,
+        mark_price: None,
+        block_rfq_id: None,
+        ip: None,
+        username: "user".to_string(),
+        instrument_name: Some("BTC-PERPETUAL".to_string()),
+    }
+}
+
+#[test]
+fn test_chart_data_csv() {
+    let mut output = Vec::new();
+    write_csv(&create_chart_data(), &mut output).unwrap();
+
+    let csv = String::from_utf8(output).unwrap();
+    let lines: Vec<&str> = csv.lines().collect();
+    assert_eq!(lines[0], "timestamp,open,high,low,close,volume,cost");
+    assert_eq!(lines[1], "1640995200000,50000,51000,49500,50500,10,505000");
+    assert_eq!(lines.len(), 3);
+}
+
+#[test]
+fn test_chart_data_with_short_column_pads_missing_values() {
+    let mut chart_data = create_chart_data();
+    chart_data.cost.pop();
+
+    let table = chart_data.to_table();
+    assert_eq!(table.num_rows(), 2);
+    assert_eq!(
+        table.column("cost"),
+        Some(&Column::Float64(vec![Some(505000.0), None]))
+    );
+}
+
+#[test]
+fn test_trades_csv_uses_serialized_names() {
+    let mut output = Vec::new();
+    write_csv(&vec![create_trade()], &mut output).unwrap();
+
+    let csv = String::from_utf8(output).unwrap();
+    let mut lines = csv.lines();
+    let header: Vec<&str> = lines.next().unwrap().split(',').collect();
+    let row: Vec<&str> = lines.next().unwrap().split(',').collect();
+    let field = |name: &str| row[header.iter().position(|h| *h == name).unwrap()];
+    assert_eq!(field("direction"), "Sell");
+    assert_eq!(field("liquidity"), "T");
+    assert_eq!(field("trade_seq"), "");
+}
+
+#[test]
+fn test_transaction_log_table() {
+    let table = [create_transaction_log_entry()].to_table();
+
+    assert_eq!(table.num_rows(), 1);
+    assert_eq!(
+        table.column("side"),
+        Some(&Column::Utf8(vec![Some("open buy".to_string())]))
+    );
+    assert_eq!(table.column("order_id"), Some(&Column::Utf8(vec![None])));
+}
+
+#[test]
+fn test_last_trades_parquet_round_trip() {
+    let trades: Vec<LastTrade> = (1..=3).map(create_last_trade).collect();
+    let path = std::env::temp_dir().join(format!(
+        "deribit-http-last-trades-{}.parquet",
+        std::process::id()
+    ));
+    write_parquet(&trades, std::fs::File::create(&path).unwrap()).unwrap();
+
+    let reader = ParquetRecordBatchReaderBuilder::try_new(std::fs::File::open(&path).unwrap())
+        .unwrap()
+        .build()
+        .unwrap();
+    let batches: Vec<_> = reader.map(|batch| batch.unwrap()).collect();
+    let rows: usize = batches.iter().map(|batch| batch.num_rows()).sum();
+    assert_eq!(rows, 3);
+    let schema = batches[0].schema();
+    assert_eq!(schema.field(0).name(), "timestamp");
+    assert_eq!(
+        schema.fields().len(),
+        trades.to_table().column_names().len()
+    );
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn test_export_to_files() {
+    let dir = std::env::temp_dir().join(format!("deribit-http-export-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let csv_path = dir.join("candles.csv");
+    let parquet_path = dir.join("candles.parquet");
+
+    export_csv(&create_chart_data(), &csv_path).unwrap();
+    export_parquet(&create_chart_data(), &parquet_path).unwrap();
+
+    let csv = std::fs::read_to_string(&csv_path).unwrap();
+    assert!(csv.starts_with("timestamp,open,"));
+    assert!(std::fs::read(&parquet_path).unwrap().starts_with(b"PAR1"));
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_empty_table() {
+    let table = Table::new();
+    assert_eq!(table.num_rows(), 0);
+
+    let mut output = Vec::new();
+    write_csv(&Vec::<Trade>::new(), &mut output).unwrap();
+    assert!(
+        String::from_utf8(output)
+            .unwrap()
+            .starts_with("timestamp,trade_id")
+    );
+}
+
+#[test]
+#[should_panic(expected = "column b has 1 values")]
+fn test_table_rejects_uneven_columns() {
+    let _ = Table::new()
+        .with_column("a", Column::UInt64(vec![Some(1), Some(2)]))
+        .with_column("b", Column::UInt64(vec![Some(1)]));
+}
@@ -1,4 +1,7 @@
-use deribit_http::model::funding::{FundingChartData, FundingDataPoint, FundingRateData};
+use deribit_http::model::TimestampMs;
+use deribit_http::model::funding::{
+    FundingChartData, FundingChartLength, FundingDataPoint, FundingRateData,
+};
 use serde_json;
 
 #[cfg(test)]
@@ -29,7 +32,8 @@ mod funding_chart_data_tests {
         chart_data.current_interest = 0.0001;
         chart_data.interest_8h = 0.0008;
 
-        let data_point = FundingDataPoint::new(50000.0, 0.0001, 1640995200000);
+        let data_point =
+            FundingDataPoint::new(50000.0, 0.0001, TimestampMs::from_millis(1640995200000));
         chart_data.data.push(data_point);
 
         assert_eq!(chart_data.current_interest, 0.0001);
@@ -43,7 +47,8 @@ mod funding_chart_data_tests {
         chart_data.current_interest = 0.0001;
         chart_data.interest_8h = 0.0008;
 
-        let data_point = FundingDataPoint::new(50000.0, 0.0001, 1640995200000);
+        let data_point =
+            FundingDataPoint::new(50000.0, 0.0001, TimestampMs::from_millis(1640995200000));
         chart_data.data.push(data_point);
 
         let json = serde_json::to_string(&chart_data).unwrap();
@@ -82,6 +87,15 @@ mod funding_chart_data_tests {
         assert_eq!(chart_data.current_interest, -0.0001);
         assert_eq!(chart_data.interest_8h, -0.0008);
     }
+
+    #[test]
+    fn test_funding_chart_length_as_str() {
+        assert_eq!(FundingChartLength::Hours8.as_str(), "8h");
+        assert_eq!(FundingChartLength::Hours24.as_str(), "24h");
+        assert_eq!(FundingChartLength::Month1.as_str(), "1m");
+        let length: FundingChartLength = serde_json::from_str("\"24h\"").unwrap();
+        assert_eq!(length, FundingChartLength::Hours24);
+    }
 }
 
 #[cfg(test)]
@@ -90,16 +104,18 @@ mod funding_data_point_tests {
 
     #[test]
     fn test_funding_data_point_new() {
-        let data_point = FundingDataPoint::new(50000.0, 0.0001, 1640995200000);
+        let data_point =
+            FundingDataPoint::new(50000.0, 0.0001, TimestampMs::from_millis(1640995200000));
 
         assert_eq!(data_point.index_price, 50000.0);
         assert_eq!(data_point.interest_8h, 0.0001);
-        assert_eq!(data_point.timestamp, 1640995200000);
+        assert_eq!(data_point.timestamp.as_millis(), 1640995200000);
     }
 
     #[test]
     fn test_funding_data_point_serialization() {
-        let data_point = FundingDataPoint::new(50000.0, 0.0001, 1640995200000);
+        let data_point =
+            FundingDataPoint::new(50000.0, 0.0001, TimestampMs::from_millis(1640995200000));
 
         let json = serde_json::to_string(&data_point).unwrap();
         let deserialized: FundingDataPoint = serde_json::from_str(&json).unwrap();
@@ -111,7 +127,8 @@ mod funding_data_point_tests {
 
     #[test]
     fn test_funding_data_point_clone() {
-        let data_point = FundingDataPoint::new(50000.0, 0.0001, 1640995200000);
+        let data_point =
+            FundingDataPoint::new(50000.0, 0.0001, TimestampMs::from_millis(1640995200000));
         let cloned = data_point.clone();
 
         assert_eq!(data_point.index_price, cloned.index_price);
@@ -122,26 +139,29 @@ mod funding_data_point_tests {
     #[test]
     fn test_funding_data_point_edge_cases() {
         // Test with zero values
-        let zero_point = FundingDataPoint::new(0.0, 0.0, 0);
+        let zero_point = FundingDataPoint::new(0.0, 0.0, TimestampMs::EPOCH);
         assert_eq!(zero_point.index_price, 0.0);
         assert_eq!(zero_point.interest_8h, 0.0);
-        assert_eq!(zero_point.timestamp, 0);
+        assert_eq!(zero_point.timestamp.as_millis(), 0);
 
         // Test with negative values
-        let negative_point = FundingDataPoint::new(-1000.0, -0.001, 1640995200000);
+        let negative_point =
+            FundingDataPoint::new(-1000.0, -0.001, TimestampMs::from_millis(1640995200000));
         assert_eq!(negative_point.index_price, -1000.0);
         assert_eq!(negative_point.interest_8h, -0.001);
 
         // Test with extreme values
-        let extreme_point = FundingDataPoint::new(f64::MAX, f64::MIN, u64::MAX);
+        let extreme_point =
+            FundingDataPoint::new(f64::MAX, f64::MIN, TimestampMs::from_millis(u64::MAX));
         assert_eq!(extreme_point.index_price, f64::MAX);
         assert_eq!(extreme_point.interest_8h, f64::MIN);
-        assert_eq!(extreme_point.timestamp, u64::MAX);
+        assert_eq!(extreme_point.timestamp.as_millis(), u64::MAX);
     }
 
     #[test]
     fn test_funding_data_point_debug_display() {
-        let data_point = FundingDataPoint::new(50000.0, 0.0001, 1640995200000);
+        let data_point =
+            FundingDataPoint::new(50000.0, 0.0001, TimestampMs::from_millis(1640995200000));
 
         // Test Debug trait (uses DebugPretty)
         let debug_str = format!("{:?}", data_point);
@@ -160,9 +180,15 @@ mod funding_rate_data_tests {
 
     #[test]
     fn test_funding_rate_data_new() {
-        let rate_data = FundingRateData::new(1640995200000, 50000.0, 0.0001, 0.000012, 49950.0);
+        let rate_data = FundingRateData::new(
+            TimestampMs::from_millis(1640995200000),
+            50000.0,
+            0.0001,
+            0.000012,
+            49950.0,
+        );
 
-        assert_eq!(rate_data.timestamp, 1640995200000);
+        assert_eq!(rate_data.timestamp.as_millis(), 1640995200000);
         assert_eq!(rate_data.index_price, 50000.0);
         assert_eq!(rate_data.interest_8h, 0.0001);
         assert_eq!(rate_data.interest_1h, 0.000012);
@@ -171,7 +197,13 @@ mod funding_rate_data_tests {
 
     #[test]
     fn test_funding_rate_data_serialization() {
-        let rate_data = FundingRateData::new(1640995200000, 50000.0, 0.0001, 0.000012, 49950.0);
+        let rate_data = FundingRateData::new(
+            TimestampMs::from_millis(1640995200000),
+            50000.0,
+            0.0001,
+            0.000012,
+            49950.0,
+        );
 
         let json = serde_json::to_string(&rate_data).unwrap();
         let deserialized: FundingRateData = serde_json::from_str(&json).unwrap();
@@ -185,7 +217,13 @@ mod funding_rate_data_tests {
 
     #[test]
     fn test_funding_rate_data_clone() {
-        let rate_data = FundingRateData::new(1640995200000, 50000.0, 0.0001, 0.000012, 49950.0);
+        let rate_data = FundingRateData::new(
+            TimestampMs::from_millis(1640995200000),
+            50000.0,
+            0.0001,
+            0.000012,
+            49950.0,
+        );
 
         let cloned = rate_data.clone();
 
@@ -199,25 +237,36 @@ mod funding_rate_data_tests {
     #[test]
     fn test_funding_rate_data_edge_cases() {
         // Test with extreme values
-        let extreme_data = FundingRateData::new(u64::MAX, f64::MAX, f64::MIN, 0.0, f64::INFINITY);
+        let extreme_data = FundingRateData::new(
+            TimestampMs::from_millis(u64::MAX),
+            f64::MAX,
+            f64::MIN,
+            0.0,
+            f64::INFINITY,
+        );
 
-        assert_eq!(extreme_data.timestamp, u64::MAX);
+        assert_eq!(extreme_data.timestamp.as_millis(), u64::MAX);
         assert_eq!(extreme_data.index_price, f64::MAX);
         assert_eq!(extreme_data.interest_8h, f64::MIN);
         assert_eq!(extreme_data.interest_1h, 0.0);
         assert_eq!(extreme_data.prev_index_price, f64::INFINITY);
 
         // Test with zero values
-        let zero_data = FundingRateData::new(0, 0.0, 0.0, 0.0, 0.0);
-        assert_eq!(zero_data.timestamp, 0);
+        let zero_data = FundingRateData::new(TimestampMs::EPOCH, 0.0, 0.0, 0.0, 0.0);
+        assert_eq!(zero_data.timestamp.as_millis(), 0);
         assert_eq!(zero_data.index_price, 0.0);
         assert_eq!(zero_data.interest_8h, 0.0);
         assert_eq!(zero_data.interest_1h, 0.0);
         assert_eq!(zero_data.prev_index_price, 0.0);
 
         // Test with negative values
-        let negative_data =
-            FundingRateData::new(1640995200000, -50000.0, -0.0001, -0.000012, -49950.0);
+        let negative_data = FundingRateData::new(
+            TimestampMs::from_millis(1640995200000),
+            -50000.0,
+            -0.0001,
+            -0.000012,
+            -49950.0,
+        );
 
         assert_eq!(negative_data.index_price, -50000.0);
         assert_eq!(negative_data.interest_8h, -0.0001);
@@ -227,7 +276,13 @@ mod funding_rate_data_tests {
 
     #[test]
     fn test_funding_rate_data_debug_display() {
-        let rate_data = FundingRateData::new(1640995200000, 50000.0, 0.0001, 0.000012, 49950.0);
+        let rate_data = FundingRateData::new(
+            TimestampMs::from_millis(1640995200000),
+            50000.0,
+            0.0001,
+            0.000012,
+            49950.0,
+        );
 
         // Test Debug trait (uses DebugPretty)
         let debug_str = format!("{:?}", rate_data);
@@ -242,7 +297,7 @@ mod funding_rate_data_tests {
     #[test]
     fn test_funding_rate_data_interest_comparison() {
         let rate_data = FundingRateData::new(
-            1640995200000,
+            TimestampMs::from_millis(1640995200000),
             50000.0,
             0.0008, // 8h rate
             0.0001, // 1h rate
@@ -261,11 +316,11 @@ mod funding_rate_data_tests {
     fn test_funding_rate_data_realistic_values() {
         // Test with realistic funding rate values
         let realistic_data = FundingRateData::new(
-            1640995200000, // Jan 1, 2022
-            45000.0,       // BTC price
-            0.0001,        // 0.01% 8h funding
-            0.0000125,     // ~0.001% 1h funding
-            44950.0,       // Previous price
+            TimestampMs::from_millis(1640995200000), // Jan 1, 2022
+            45000.0,                                 // BTC price
+            0.0001,                                  // 0.01% 8h funding
+            0.0000125,                               // ~0.001% 1h funding
+            44950.0,                                 // Previous price
         );
 
         assert!(realistic_data.interest_8h > 0.0);
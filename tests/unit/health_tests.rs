@@ -0,0 +1,162 @@
+//! Unit tests for health checks and the health monitor
+
+use deribit_http::DeribitHttpClient;
+use deribit_http::health::{HealthEvent, HealthStatus};
+use deribit_http::{HttpError, HttpErrorKind};
+use std::time::Duration;
+use url::Url;
+
+const TEST_BODY: &str = r#"{"jsonrpc": "2.0", "id": 1, "result": {"version": "1.2.26"}}"#;
+
+fn client_for(server: &mockito::Server) -> DeribitHttpClient {
+    DeribitHttpClient::builder()
+        .base_url(Url::parse(&format!("{}/api/v2", server.url())).unwrap())
+        .max_retries(0)
+        .build()
+        .unwrap()
+}
+
+async fn mock_test(server: &mut mockito::Server, status: usize) -> mockito::Mock {
+    server
+        .mock("GET", "/api/v2/public/test")
+        .with_status(status)
+        .with_header("content-type", "application/json")
+        .with_body(TEST_BODY)
+        .create_async()
+        .await
+}
+
+async fn mock_status(server: &mut mockito::Server, locked: &str) -> mockito::Mock {
+    server
+        .mock("GET", "/api/v2/public/status")
+        .with_header("content-type", "application/json")
+        .with_body(format!(
+            r#"{{"jsonrpc": "2.0", "id": 2, "result": {{"locked": "{}", "locked_indices": ["btc_usd"]}}}}"#,
+            locked
+        ))
+        .create_async()
+        .await
+}
+
+#[tokio::test]
+async fn test_healthy_check_reports_latency_and_lock_state() {
+    let mut server = mockito::Server::new_async().await;
+    let _test = mock_test(&mut server, 200).await;
+    let _status = mock_status(&mut server, "partial").await;
+    let client = client_for(&server);
+    assert!(client.health().is_none());
+
+    let health = client.check_health().await;
+
+    assert!(health.is_healthy());
+    assert!(health.latency.is_some());
+    assert_eq!(health.version.as_deref(), Some("1.2.26"));
+    assert_eq!(health.locked, Some(false));
+    assert_eq!(health.locked_indices, vec!["btc_usd".to_string()]);
+    assert!(health.error.is_none());
+    let last = client.clone().health().unwrap();
+    assert_eq!(last.status, HealthStatus::Healthy);
+}
+
+#[tokio::test]
+async fn test_locked_platform_is_reported() {
+    let mut server = mockito::Server::new_async().await;
+    let _test = mock_test(&mut server, 200).await;
+    let _status = mock_status(&mut server, "true").await;
+    let client = client_for(&server);
+
+    let health = client.check_health().await;
+
+    assert_eq!(health.status, HealthStatus::Locked);
+    assert_eq!(health.locked, Some(true));
+    assert!(health.latency.is_some());
+}
+
+#[tokio::test]
+async fn test_failed_test_call_is_unreachable() {
+    let mut server = mockito::Server::new_async().await;
+    let _test = mock_test(&mut server, 503).await;
+    let status = server
+        .mock("GET", "/api/v2/public/status")
+        .expect(0)
+        .create_async()
+        .await;
+    let client = client_for(&server);
+
+    let health = client.check_health().await;
+
+    assert_eq!(health.status, HealthStatus::Unreachable);
+    assert!(health.latency.is_none());
+    assert!(matches!(
+        health.error.as_ref().map(HttpError::kind),
+        Some(HttpErrorKind::ServerError { status: 503, .. })
+    ));
+    status.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_probe_is_sent_once_without_retries() {
+    let mut server = mockito::Server::new_async().await;
+    let test = server
+        .mock("GET", "/api/v2/public/test")
+        .with_status(503)
+        .expect(1)
+        .create_async()
+        .await;
+    let client = DeribitHttpClient::builder()
+        .base_url(Url::parse(&format!("{}/api/v2", server.url())).unwrap())
+        .max_retries(3)
+        .build()
+        .unwrap();
+
+    let health = client.check_health().await;
+
+    assert_eq!(health.status, HealthStatus::Unreachable);
+    assert_eq!(
+        health.error.as_ref().and_then(HttpError::endpoint),
+        Some("public/test")
+    );
+    test.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_monitor_emits_degraded_and_recovered() {
+    let mut server = mockito::Server::new_async().await;
+    let failing = mock_test(&mut server, 503).await;
+    let _status = mock_status(&mut server, "false").await;
+    let client = client_for(&server);
+
+    let mut monitor = client.spawn_health_monitor(Duration::from_millis(100));
+
+    let degraded = tokio::time::timeout(Duration::from_secs(5), monitor.recv())
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(matches!(&degraded, HealthEvent::Degraded(_)));
+    assert_eq!(degraded.health().status, HealthStatus::Unreachable);
+
+    failing.remove_async().await;
+    let _healthy = mock_test(&mut server, 200).await;
+
+    let recovered = tokio::time::timeout(Duration::from_secs(5), monitor.recv())
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(matches!(&recovered, HealthEvent::Recovered(_)));
+    assert!(recovered.health().latency.is_some());
+    assert!(client.health().unwrap().is_healthy());
+}
+
+#[tokio::test]
+async fn test_monitor_is_quiet_while_healthy() {
+    let mut server = mockito::Server::new_async().await;
+    let _test = mock_test(&mut server, 200).await;
+    let _status = mock_status(&mut server, "false").await;
+    let client = client_for(&server);
+
+    let mut monitor = client.spawn_health_monitor(Duration::from_millis(100));
+    let event = tokio::time::timeout(Duration::from_millis(350), monitor.recv()).await;
+
+    assert!(event.is_err());
+    assert!(client.health().unwrap().is_healthy());
+}
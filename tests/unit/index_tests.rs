@@ -1,3 +1,4 @@
+use deribit_http::model::TimestampMs;
 use deribit_http::model::index::{IndexChartDataPoint, IndexData, IndexPriceData};
 use serde_json;
 
@@ -7,15 +8,15 @@ mod index_chart_data_point_tests {
 
     #[test]
     fn test_index_chart_data_point_new() {
-        let point = IndexChartDataPoint::new(1573228800000, 8751.7138636);
+        let point = IndexChartDataPoint::new(TimestampMs::from_millis(1573228800000), 8751.7138636);
 
-        assert_eq!(point.timestamp, 1573228800000);
+        assert_eq!(point.timestamp.as_millis(), 1573228800000);
         assert!((point.price - 8751.7138636).abs() < f64::EPSILON);
     }
 
     #[test]
     fn test_index_chart_data_point_serialization() {
-        let point = IndexChartDataPoint::new(1573228800000, 8751.7138636);
+        let point = IndexChartDataPoint::new(TimestampMs::from_millis(1573228800000), 8751.7138636);
 
         let json = serde_json::to_string(&point).expect("serialization should succeed");
         // Should serialize as tuple [timestamp, price]
@@ -30,13 +31,14 @@ mod index_chart_data_point_tests {
         let point: IndexChartDataPoint =
             serde_json::from_str(json).expect("deserialization should succeed");
 
-        assert_eq!(point.timestamp, 1573228800000);
+        assert_eq!(point.timestamp.as_millis(), 1573228800000);
         assert!((point.price - 8751.7138636).abs() < f64::EPSILON);
     }
 
     #[test]
     fn test_index_chart_data_point_roundtrip() {
-        let original = IndexChartDataPoint::new(1573228800000, 8751.7138636);
+        let original =
+            IndexChartDataPoint::new(TimestampMs::from_millis(1573228800000), 8751.7138636);
 
         let json = serde_json::to_string(&original).expect("serialization should succeed");
         let deserialized: IndexChartDataPoint =
@@ -48,7 +50,7 @@ mod index_chart_data_point_tests {
 
     #[test]
     fn test_index_chart_data_point_clone() {
-        let point = IndexChartDataPoint::new(1573228800000, 8751.7138636);
+        let point = IndexChartDataPoint::new(TimestampMs::from_millis(1573228800000), 8751.7138636);
         let cloned = point;
 
         assert_eq!(point.timestamp, cloned.timestamp);
@@ -57,7 +59,7 @@ mod index_chart_data_point_tests {
 
     #[test]
     fn test_index_chart_data_point_copy() {
-        let point = IndexChartDataPoint::new(1573228800000, 8751.7138636);
+        let point = IndexChartDataPoint::new(TimestampMs::from_millis(1573228800000), 8751.7138636);
         let copied = point;
 
         // Both should have same values (Copy trait)
@@ -67,9 +69,12 @@ mod index_chart_data_point_tests {
 
     #[test]
     fn test_index_chart_data_point_partial_eq() {
-        let point1 = IndexChartDataPoint::new(1573228800000, 8751.7138636);
-        let point2 = IndexChartDataPoint::new(1573228800000, 8751.7138636);
-        let point3 = IndexChartDataPoint::new(1573232400000, 8751.7138636);
+        let point1 =
+            IndexChartDataPoint::new(TimestampMs::from_millis(1573228800000), 8751.7138636);
+        let point2 =
+            IndexChartDataPoint::new(TimestampMs::from_millis(1573228800000), 8751.7138636);
+        let point3 =
+            IndexChartDataPoint::new(TimestampMs::from_millis(1573232400000), 8751.7138636);
 
         assert_eq!(point1, point2);
         assert_ne!(point1, point3);
@@ -78,27 +83,30 @@ mod index_chart_data_point_tests {
     #[test]
     fn test_index_chart_data_point_edge_cases() {
         // Test with zero values
-        let zero_point = IndexChartDataPoint::new(0, 0.0);
-        assert_eq!(zero_point.timestamp, 0);
+        let zero_point = IndexChartDataPoint::new(TimestampMs::EPOCH, 0.0);
+        assert_eq!(zero_point.timestamp.as_millis(), 0);
         assert!((zero_point.price - 0.0).abs() < f64::EPSILON);
 
         // Test with large timestamp (far future)
-        let future_point = IndexChartDataPoint::new(4102444800000, 100000.0);
-        assert_eq!(future_point.timestamp, 4102444800000);
+        let future_point =
+            IndexChartDataPoint::new(TimestampMs::from_millis(4102444800000), 100000.0);
+        assert_eq!(future_point.timestamp.as_millis(), 4102444800000);
         assert!((future_point.price - 100000.0).abs() < f64::EPSILON);
 
         // Test with very small price
-        let small_price = IndexChartDataPoint::new(1573228800000, 0.00000001);
+        let small_price =
+            IndexChartDataPoint::new(TimestampMs::from_millis(1573228800000), 0.00000001);
         assert!((small_price.price - 0.00000001).abs() < f64::EPSILON);
 
         // Test with very large price
-        let large_price = IndexChartDataPoint::new(1573228800000, 1000000000.0);
+        let large_price =
+            IndexChartDataPoint::new(TimestampMs::from_millis(1573228800000), 1000000000.0);
         assert!((large_price.price - 1000000000.0).abs() < f64::EPSILON);
     }
 
     #[test]
     fn test_index_chart_data_point_debug() {
-        let point = IndexChartDataPoint::new(1573228800000, 8751.7138636);
+        let point = IndexChartDataPoint::new(TimestampMs::from_millis(1573228800000), 8751.7138636);
 
         let debug_str = format!("{:?}", point);
         assert!(debug_str.contains("IndexChartDataPoint"));
@@ -118,11 +126,11 @@ mod index_chart_data_point_tests {
             serde_json::from_str(json).expect("deserialization should succeed");
 
         assert_eq!(points.len(), 3);
-        assert_eq!(points[0].timestamp, 1573228800000);
+        assert_eq!(points[0].timestamp.as_millis(), 1573228800000);
         assert!((points[0].price - 8751.7138636).abs() < f64::EPSILON);
-        assert_eq!(points[1].timestamp, 1573232400000);
+        assert_eq!(points[1].timestamp.as_millis(), 1573232400000);
         assert!((points[1].price - 8752.0).abs() < f64::EPSILON);
-        assert_eq!(points[2].timestamp, 1573236000000);
+        assert_eq!(points[2].timestamp.as_millis(), 1573236000000);
         assert!((points[2].price - 8753.5).abs() < f64::EPSILON);
     }
 
@@ -138,14 +146,14 @@ mod index_chart_data_point_tests {
     #[test]
     fn test_index_chart_data_realistic_btc_prices() {
         // Test with realistic BTC price data
-        let point = IndexChartDataPoint::new(1709683200000, 67234.56);
+        let point = IndexChartDataPoint::new(TimestampMs::from_millis(1709683200000), 67234.56);
 
-        assert_eq!(point.timestamp, 1709683200000);
+        assert_eq!(point.timestamp.as_millis(), 1709683200000);
         assert!((point.price - 67234.56).abs() < f64::EPSILON);
 
         // Verify the timestamp is reasonable (March 2024)
-        assert!(point.timestamp > 1700000000000);
-        assert!(point.timestamp < 2000000000000);
+        assert!(point.timestamp.as_millis() > 1700000000000);
+        assert!(point.timestamp.as_millis() < 2000000000000);
     }
 }
 
@@ -1,3 +1,4 @@
+use deribit_http::model::TimestampMs;
 use deribit_http::model::instrument::{Instrument, InstrumentKind, InstrumentType, OptionType};
 use serde_json;
 
@@ -49,6 +50,18 @@ mod instrument_kind_tests {
         assert_eq!(format!("{}", InstrumentKind::FutureCombo), "future_combo");
         assert_eq!(format!("{}", InstrumentKind::OptionCombo), "option_combo");
     }
+
+    #[test]
+    fn test_instrument_kind_filter_variants() {
+        assert_eq!(InstrumentKind::Combo.as_str(), "combo");
+        assert_eq!(InstrumentKind::Any.as_str(), "any");
+        assert_eq!(
+            serde_json::to_string(&InstrumentKind::Combo).unwrap(),
+            "\"combo\""
+        );
+        let any: InstrumentKind = serde_json::from_str("\"any\"").unwrap();
+        assert_eq!(any, InstrumentKind::Any);
+    }
 }
 
 #[cfg(test)]
@@ -112,13 +125,14 @@ mod tests {
             strike: None,
             option_type: None,
             tick_size: Some(0.5),
+            tick_size_steps: None,
             min_trade_amount: Some(10.0),
             contract_size: Some(1.0),
             settlement_period: Some("perpetual".to_string()),
             instrument_type: Some(InstrumentType::Linear),
             quote_currency: Some("USD".to_string()),
             settlement_currency: Some("BTC".to_string()),
-            creation_timestamp: Some(1609459200000),
+            creation_timestamp: Some(TimestampMs(1609459200000)),
             max_leverage: Some(100.0),
             maker_commission: Some(0.0001),
             taker_commission: Some(0.0005),
@@ -135,17 +149,18 @@ mod tests {
             kind: Some(InstrumentKind::Option),
             currency: Some("BTC".to_string()),
             is_active: Some(true),
-            expiration_timestamp: Some(1640419200000),
+            expiration_timestamp: Some(TimestampMs(1640419200000)),
             strike: Some(50000.0),
             option_type: Some(OptionType::Call),
             tick_size: Some(0.0005),
+            tick_size_steps: None,
             min_trade_amount: Some(0.1),
             contract_size: Some(1.0),
             settlement_period: Some("month".to_string()),
             instrument_type: Some(InstrumentType::Reversed),
             quote_currency: Some("USD".to_string()),
             settlement_currency: Some("BTC".to_string()),
-            creation_timestamp: Some(1609459200000),
+            creation_timestamp: Some(TimestampMs(1609459200000)),
             max_leverage: Some(1.0),
             maker_commission: Some(0.0003),
             taker_commission: Some(0.0003),
@@ -180,7 +195,7 @@ mod tests {
 
         // Test with expiration timestamp
         let mut with_expiration = create_mock_perpetual_instrument();
-        with_expiration.expiration_timestamp = Some(1640419200000);
+        with_expiration.expiration_timestamp = Some(TimestampMs(1640419200000));
         assert!(!with_expiration.is_perpetual());
     }
 
@@ -287,4 +302,32 @@ mod tests {
         assert!(debug_str.contains("BTC-PERPETUAL"));
         assert!(debug_str.contains("perpetual"));
     }
+
+    #[test]
+    fn test_tick_size_steps_apply_above_threshold() {
+        let instrument: Instrument = serde_json::from_str(
+            r#"{
+                "instrument_name": "BTC-27DEC24-60000-C",
+                "kind": "option",
+                "tick_size": 0.0001,
+                "tick_size_steps": [{"above_price": 0.005, "tick_size": 0.0005}]
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(instrument.tick_size_at(0.004), Some(0.0001));
+        assert_eq!(instrument.tick_size_at(0.02), Some(0.0005));
+        assert_eq!(instrument.round_to_tick(0.00437), Some(0.0044));
+        assert_eq!(instrument.round_to_tick(0.0213), Some(0.0215));
+    }
+
+    #[test]
+    fn test_round_to_tick_without_tick_size() {
+        let mut instrument = create_mock_perpetual_instrument();
+        assert_eq!(instrument.round_to_tick(50_000.3), Some(50_000.5));
+        assert_eq!(instrument.round_to_tick(50_000.2), Some(50_000.0));
+
+        instrument.tick_size = None;
+        assert_eq!(instrument.round_to_tick(50_000.3), None);
+    }
 }
@@ -0,0 +1,126 @@
+use deribit_http::model::book::OrderBook;
+use deribit_http::model::request::order::OrderRequest;
+use deribit_http::model::response::order::OrderResponse;
+use deribit_http::model::response::other::AccountSummaryResponse;
+use deribit_http::model::ticker::TickerData;
+use deribit_http::testing::{MOCK_INSTRUMENT, MockDeribitClient, fixtures};
+use deribit_http::{DeribitErrorCode, DeribitHttpClient, HttpError, HttpErrorKind};
+use serde_json::json;
+
+#[cfg(test)]
+mod fixture_tests {
+    use super::*;
+
+    #[test]
+    fn test_fixtures_parse_into_models() {
+        let ticker: TickerData = fixtures::ticker("BTC-PERPETUAL").parse().unwrap();
+        assert_eq!(ticker.best_bid_price, Some(50_010.0));
+
+        let book: OrderBook = fixtures::order_book("BTC-PERPETUAL").parse().unwrap();
+        assert_eq!(book.bids.len(), 5);
+        assert_eq!(book.spread(), Some(0.5));
+
+        let response: OrderResponse = fixtures::order_response("ETH-PERPETUAL", "sell")
+            .parse()
+            .unwrap();
+        assert_eq!(response.order.order_id, "ETH-1873252521");
+        assert_eq!(response.order.direction, "sell");
+
+        let summary: AccountSummaryResponse = fixtures::account_summary("ETH").parse().unwrap();
+        assert_eq!(summary.username, "trader");
+    }
+
+    #[test]
+    fn test_fixture_overrides() {
+        let order = fixtures::order("BTC-PERPETUAL", "buy")
+            .set("order_state", "filled")
+            .remove("max_show")
+            .into_value();
+        assert_eq!(order["order_state"], "filled");
+        assert!(order.get("max_show").is_none());
+    }
+}
+
+#[cfg(test)]
+mod offline_client_tests {
+    use super::*;
+
+    /// Trading logic under test: buy when the ticker trades below `limit`
+    async fn buy_below(client: &DeribitHttpClient, limit: f64) -> Result<bool, HttpError> {
+        let ticker = client.get_ticker(MOCK_INSTRUMENT).await?;
+        if ticker.mark_price >= limit {
+            return Ok(false);
+        }
+        let request = OrderRequest::builder()
+            .instrument_name(MOCK_INSTRUMENT)
+            .amount(10.0)
+            .limit(ticker.mark_price)
+            .build()?;
+        client.buy_order(request).await?;
+        Ok(true)
+    }
+
+    #[tokio::test]
+    async fn test_preloaded_fixtures_serve_trading_logic() {
+        let mock = MockDeribitClient::new();
+
+        assert!(buy_below(&mock, 60_000.0).await.unwrap());
+        assert!(!buy_below(&mock, 40_000.0).await.unwrap());
+
+        let orders = mock.requests_for("private/buy");
+        assert_eq!(orders.len(), 1);
+        assert_eq!(orders[0]["instrument_name"], MOCK_INSTRUMENT);
+        assert_eq!(orders[0]["price"], json!(50_010.32));
+        assert_eq!(mock.requests_for("public/auth").len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_preloaded_private_endpoints() {
+        let mock = MockDeribitClient::new();
+
+        let summary = mock.get_account_summary("BTC", None).await.unwrap();
+        assert_eq!(summary.id, 41_226);
+        let order = mock.get_order_state("BTC-1873252521").await.unwrap();
+        assert_eq!(order.order_state, "open");
+        let cancelled = mock.cancel_order("BTC-1873252521").await.unwrap();
+        assert_eq!(cancelled.order_state, "cancelled");
+        assert_eq!(mock.get_open_orders(None, None).await.unwrap().len(), 1);
+        assert_eq!(mock.cancel_all().await.unwrap(), 1);
+
+        let book = mock.get_order_book(MOCK_INSTRUMENT, Some(5)).await.unwrap();
+        assert_eq!(book.best_ask(), Some(50_010.5));
+        assert_eq!(
+            mock.requests_for("public/get_order_book"),
+            [json!({"instrument_name": MOCK_INSTRUMENT, "depth": "5"})]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_registered_errors_and_unknown_methods() {
+        let mock = MockDeribitClient::new().with_error("private/sell", 10009, "not_enough_funds");
+        let request = OrderRequest::builder()
+            .instrument_name(MOCK_INSTRUMENT)
+            .amount(10.0)
+            .market()
+            .build()
+            .unwrap();
+
+        let error = mock.sell_order(request).await.unwrap_err();
+        assert_eq!(error.error_code(), Some(DeribitErrorCode::NotEnoughFunds));
+
+        match mock.get_currencies().await.map_err(HttpError::into_kind) {
+            Err(HttpErrorKind::Api { code, .. }) => assert_eq!(code, -32601),
+            other => panic!("expected Api error, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_empty_mock_and_clones_share_responses() {
+        let mock = MockDeribitClient::empty();
+        let clone = mock.clone().with_result("public/get_time", 42);
+
+        assert_eq!(mock.get_server_time().await.unwrap(), 42);
+        assert_eq!(clone.requests().len(), 1);
+        assert!(mock.get_ticker(MOCK_INSTRUMENT).await.is_err());
+    }
+}
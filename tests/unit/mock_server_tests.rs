@@ -0,0 +1,167 @@
+use deribit_http::model::request::order::OrderRequest;
+use deribit_http::testing::{MOCK_INSTRUMENT, MockServer, Scenario};
+use deribit_http::{DeribitErrorCode, HttpError, HttpErrorKind};
+
+fn limit_order(amount: f64, price: f64) -> OrderRequest {
+    OrderRequest::builder()
+        .instrument_name(MOCK_INSTRUMENT)
+        .amount(amount)
+        .limit(price)
+        .label("mock")
+        .build()
+        .unwrap()
+}
+
+#[cfg(test)]
+mod order_flow_tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_resting_order_lifecycle() {
+        let server = MockServer::start().await;
+        let client = server.client();
+
+        let placed = client
+            .buy_order(limit_order(100.0, 49_000.0))
+            .await
+            .unwrap();
+        assert_eq!(placed.order.order_state, "open");
+        assert_eq!(placed.order.label, "mock");
+        assert!(placed.trades.is_empty());
+
+        let order_id = placed.order.order_id.clone();
+        assert_eq!(client.get_open_orders(None, None).await.unwrap().len(), 1);
+        let cancelled = client.cancel_order(&order_id).await.unwrap();
+        assert_eq!(cancelled.order_state, "cancelled");
+        assert_eq!(
+            client.get_order_state(&order_id).await.unwrap().order_state,
+            "cancelled"
+        );
+
+        let error = client.cancel_order(&order_id).await.unwrap_err();
+        assert_eq!(error.error_code(), Some(DeribitErrorCode::NotOpenOrder));
+        let error = client.get_order_state("BTC-999").await.unwrap_err();
+        assert_eq!(error.error_code(), Some(DeribitErrorCode::OrderNotFound));
+    }
+
+    #[tokio::test]
+    async fn test_marketable_orders_fill_and_update_positions() {
+        let server = MockServer::start().await;
+        let client = server.client();
+
+        let bought = client
+            .buy_order(limit_order(100.0, 51_000.0))
+            .await
+            .unwrap();
+        assert_eq!(bought.order.order_state, "filled");
+        assert_eq!(bought.trades.len(), 1);
+        assert_eq!(bought.trades[0].price, 51_000.0);
+
+        let market_sell = OrderRequest::builder()
+            .instrument_name(MOCK_INSTRUMENT)
+            .amount(40.0)
+            .market()
+            .build()
+            .unwrap();
+        let sold = client.sell_order(market_sell).await.unwrap();
+        assert_eq!(sold.trades[0].price, 50_010.0);
+
+        let positions = client
+            .get_positions(None::<&str>, None, None)
+            .await
+            .unwrap();
+        assert_eq!(positions.len(), 1);
+        assert_eq!(positions[0].instrument_name, MOCK_INSTRUMENT);
+        assert_eq!(positions[0].size, 60.0);
+        assert_eq!(positions[0].average_price, 51_000.0);
+    }
+
+    #[tokio::test]
+    async fn test_market_data_and_request_log() {
+        let server = MockServer::start().await;
+        let client = server.client();
+
+        let ticker = client.get_ticker("ETH-PERPETUAL").await.unwrap();
+        assert_eq!(ticker.instrument_name, "ETH-PERPETUAL");
+        let book = client
+            .get_order_book(MOCK_INSTRUMENT, Some(5))
+            .await
+            .unwrap();
+        assert_eq!(book.spread(), Some(0.5));
+        assert!(client.get_server_time().await.unwrap() > 0);
+
+        assert_eq!(
+            server.requests_for("public/ticker")[0]["instrument_name"],
+            "ETH-PERPETUAL"
+        );
+        match client.get_currencies().await.map_err(HttpError::into_kind) {
+            Err(HttpErrorKind::Api { code, .. }) => assert_eq!(code, -32601),
+            other => panic!("expected Api error, got {:?}", other),
+        }
+    }
+}
+
+#[cfg(test)]
+mod scenario_tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_auth_failure() {
+        let server = MockServer::start().await;
+        server.set_scenario(Scenario::auth_failure());
+
+        let error = server.client().cancel_all().await.unwrap_err();
+
+        assert!(
+            matches!(error.kind(), HttpErrorKind::AuthenticationFailed(_)),
+            "{:?}",
+            error
+        );
+        assert!(server.requests_for("private/cancel_all").is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_rate_limited_requests_are_retried() {
+        let server = MockServer::start().await;
+        server.set_scenario(Scenario::rate_limited(1));
+
+        assert!(server.client().get_server_time().await.is_ok());
+        assert_eq!(server.requests_for("public/get_time").len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_partial_fills() {
+        let server = MockServer::start().await;
+        server.set_scenario(Scenario::partial_fills(0.25));
+        let client = server.client();
+
+        let placed = client
+            .buy_order(limit_order(100.0, 49_000.0))
+            .await
+            .unwrap();
+
+        assert_eq!(placed.order.order_state, "open");
+        assert_eq!(placed.trades.len(), 1);
+        assert_eq!(placed.trades[0].amount, 25.0);
+        assert_eq!(client.cancel_all().await.unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_unknown_token_is_unauthorized() {
+        let server = MockServer::start().await;
+        let url = format!("{}/private/cancel_all", server.url());
+
+        let response = reqwest::Client::new()
+            .post(url)
+            .bearer_auth("forged")
+            .json(&serde_json::json!({"jsonrpc": "2.0", "id": 7, "method": "private/cancel_all"}))
+            .send()
+            .await
+            .unwrap();
+
+        assert_eq!(response.status().as_u16(), 400);
+        let body: serde_json::Value = response.json().await.unwrap();
+        assert_eq!(body["id"], 7);
+        assert_eq!(body["error"]["code"], 13009);
+    }
+}
@@ -8,30 +8,55 @@ pub mod account_tests;
 pub mod api_key_tests;
 pub mod beneficiary_tests;
 pub mod block_trade_tests;
+#[cfg(feature = "blocking")]
+pub mod blocking_tests;
 pub mod book_tests;
 pub mod builder_tests;
+#[cfg(feature = "testing")]
+pub mod chaos_tests;
 pub mod client_tests;
 pub mod combo_tests;
 pub mod config_tests;
 pub mod connection_tests;
+pub mod credentials_provider_tests;
 pub mod currency_tests;
 pub mod email_settings_tests;
+pub mod error_tests;
+#[cfg(feature = "export")]
+pub mod export_tests;
 pub mod funding_tests;
+pub mod health_tests;
 pub mod index_tests;
 pub mod instrument_tests;
 pub mod margin_model_tests;
 pub mod message_tests;
+#[cfg(feature = "testing")]
+pub mod mock_client_tests;
+#[cfg(feature = "mock-server")]
+pub mod mock_server_tests;
+pub mod option_chain_tests;
 pub mod option_tests;
+#[cfg(feature = "testing")]
+pub mod order_guard_tests;
 pub mod order_tests;
 pub mod other_model_tests;
 pub mod other_tests;
+#[cfg(feature = "testing")]
+pub mod paper_trading_tests;
+pub mod position_tests;
 pub mod private_endpoints_tests;
 pub mod public_endpoints_tests;
+#[cfg(feature = "testing")]
+pub mod rate_limit_simulation_tests;
 pub mod response_other_tests;
 pub mod response_tests;
+pub mod retry_tests;
 pub mod self_trading_tests;
 pub mod session_tests;
+pub mod strategy_tests;
+pub mod stream_tests;
 pub mod ticker_tests;
+pub mod time_range_tests;
 pub mod trade_tests;
 pub mod trading_products_tests;
 pub mod tradingview_tests;
@@ -0,0 +1,75 @@
+//! Unit tests for option chain models
+
+use deribit_http::model::other::{OptionInstrument, OptionInstrumentPair};
+use deribit_http::model::{OptionChain, TimestampMs};
+use serde_json::json;
+
+fn option(strike: f64, suffix: &str, mark_iv: f64, delta: f64) -> OptionInstrument {
+    serde_json::from_value(json!({
+        "instrument": {
+            "instrument_name": format!("BTC-27DEC24-{}-{}", strike, suffix),
+            "strike": strike,
+            "expiration_timestamp": 1735286400000u64
+        },
+        "ticker": {
+            "instrument_name": format!("BTC-27DEC24-{}-{}", strike, suffix),
+            "best_bid_price": 0.01,
+            "best_ask_price": 0.02,
+            "best_bid_amount": 1.0,
+            "best_ask_amount": 1.0,
+            "mark_price": 0.015,
+            "mark_iv": mark_iv,
+            "underlying_price": 51000.0,
+            "timestamp": 1700000000000u64,
+            "state": "open",
+            "stats": { "volume": 3.0 },
+            "greeks": { "delta": delta, "gamma": 0.0001, "vega": 20.0, "theta": -15.0, "rho": 1.0 }
+        }
+    }))
+    .unwrap()
+}
+
+fn pair(strike: f64) -> OptionInstrumentPair {
+    OptionInstrumentPair {
+        call: Some(option(strike, "C", 50.0, 0.5)),
+        put: Some(option(strike, "P", 52.0, -0.5)),
+    }
+}
+
+#[test]
+fn test_from_pairs_sorts_strikes_and_reads_greeks() {
+    let chain = OptionChain::from_pairs(
+        "BTC",
+        "27DEC24",
+        vec![pair(60000.0), pair(40000.0), pair(50000.0)],
+    );
+
+    let strikes: Vec<f64> = chain.strikes.iter().map(|row| row.strike).collect();
+    assert_eq!(strikes, vec![40000.0, 50000.0, 60000.0]);
+    assert_eq!(chain.underlying_price, Some(51000.0));
+    assert_eq!(chain.expiration_timestamp, Some(TimestampMs(1735286400000)));
+
+    let put = chain.strike(50000.0).unwrap().put.as_ref().unwrap();
+    assert_eq!(put.instrument_name, "BTC-27DEC24-50000-P");
+    assert_eq!(put.bid, Some(0.01));
+    assert_eq!(put.ask, Some(0.02));
+    assert_eq!(put.mark_iv, Some(52.0));
+    assert_eq!(put.delta, Some(-0.5));
+    assert_eq!(put.vega, Some(20.0));
+    assert_eq!(put.theta, Some(-15.0));
+    assert_eq!(put.volume, 3.0);
+}
+
+#[test]
+fn test_strike_lookup_and_at_the_money() {
+    let chain = OptionChain::from_pairs("BTC", "27DEC24", vec![pair(40000.0), pair(50000.0)]);
+
+    assert!(chain.strike(45000.0).is_none());
+    assert_eq!(chain.at_the_money().unwrap().strike, 50000.0);
+    assert_eq!(chain.len(), 2);
+    assert!(
+        OptionChain::from_pairs("BTC", "27DEC24", Vec::new())
+            .at_the_money()
+            .is_none()
+    );
+}
@@ -0,0 +1,216 @@
+use deribit_http::testing::{TestOrderGuard, cancel_stale_test_orders};
+use deribit_http::{DeribitHttpClient, HttpConfig, HttpError, HttpErrorKind};
+use mockito::Matcher;
+use serde_json::{Value, json};
+use std::env;
+use std::panic::{AssertUnwindSafe, catch_unwind};
+use url::Url;
+
+fn create_test_client(server: &mockito::ServerGuard) -> DeribitHttpClient {
+    unsafe {
+        env::set_var("DERIBIT_CLIENT_ID", "test_client_id");
+        env::set_var("DERIBIT_CLIENT_SECRET", "test_client_secret");
+        env::set_var("DERIBIT_TESTNET", "true");
+    }
+
+    let config = HttpConfig {
+        base_url: Url::parse(&format!("{}/api/v2", server.url())).unwrap(),
+        ..Default::default()
+    };
+
+    DeribitHttpClient::with_config(config)
+}
+
+async fn create_auth_mock(server: &mut mockito::Server) -> mockito::Mock {
+    server
+        .mock("GET", "/api/v2/public/auth")
+        .match_query(Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "result": {
+                    "access_token": "test_access_token",
+                    "expires_in": 3600,
+                    "refresh_token": "test_refresh_token",
+                    "scope": "trade:read_write",
+                    "state": "",
+                    "token_type": "bearer"
+                }
+            })
+            .to_string(),
+        )
+        .create_async()
+        .await
+}
+
+fn order_json(order_id: &str, label: &str, state: &str) -> Value {
+    json!({
+        "amount": 10.0,
+        "api": true,
+        "average_price": 0.0,
+        "creation_timestamp": 1609459200000u64,
+        "direction": "buy",
+        "filled_amount": 0.0,
+        "instrument_name": "BTC-PERPETUAL",
+        "is_liquidation": false,
+        "label": label,
+        "last_update_timestamp": 1609459200000u64,
+        "order_id": order_id,
+        "order_state": state,
+        "order_type": "limit",
+        "post_only": true,
+        "price": 10000.0,
+        "reduce_only": false,
+        "replaced": false,
+        "risk_reducing": false,
+        "time_in_force": "good_til_cancelled",
+        "web": false
+    })
+}
+
+async fn create_cancel_mock(
+    server: &mut mockito::Server,
+    order_id: &str,
+    result: Value,
+) -> mockito::Mock {
+    server
+        .mock("POST", "/api/v2/private/cancel")
+        .match_body(Matcher::PartialJson(
+            json!({ "params": { "order_id": order_id } }),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(result.to_string())
+        .expect(1)
+        .create_async()
+        .await
+}
+
+fn cancelled(order_id: &str) -> Value {
+    json!({"jsonrpc": "2.0", "id": 1, "result": order_json(order_id, "", "cancelled")})
+}
+
+#[cfg(test)]
+mod test_order_guard_tests {
+    use super::*;
+
+    #[test]
+    fn test_track_deduplicates_and_untracks() {
+        let guard = TestOrderGuard::new(DeribitHttpClient::new());
+        guard.track("ETH-1");
+        guard.track("ETH-1");
+        guard.track("ETH-2");
+        assert_eq!(guard.tracked(), vec!["ETH-1", "ETH-2"]);
+
+        assert!(guard.untrack("ETH-1"));
+        assert!(!guard.untrack("ETH-1"));
+        assert_eq!(guard.tracked(), vec!["ETH-2"]);
+
+        guard.untrack("ETH-2");
+    }
+
+    #[tokio::test]
+    async fn test_cleanup_skips_closed_orders() {
+        let mut server = mockito::Server::new_async().await;
+        let client = create_test_client(&server);
+        let _auth_mock = create_auth_mock(&mut server).await;
+        let open = create_cancel_mock(&mut server, "ETH-1", cancelled("ETH-1")).await;
+        let filled = create_cancel_mock(
+            &mut server,
+            "ETH-2",
+            json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "error": {"code": 11044, "message": "not_open_order"}
+            }),
+        )
+        .await;
+
+        let guard = TestOrderGuard::new(client);
+        guard.track("ETH-1");
+        guard.track("ETH-2");
+
+        assert_eq!(guard.cleanup().await.unwrap(), 1);
+        assert!(guard.tracked().is_empty());
+        open.assert_async().await;
+        filled.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_drop_cancels_remaining_orders() {
+        let mut server = mockito::Server::new_async().await;
+        let client = create_test_client(&server);
+        let _auth_mock = create_auth_mock(&mut server).await;
+        let cancel_mock = create_cancel_mock(&mut server, "ETH-1", cancelled("ETH-1")).await;
+
+        {
+            let guard = TestOrderGuard::new(client);
+            guard.track("ETH-1");
+        }
+
+        cancel_mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_drop_cancels_on_panic() {
+        let mut server = mockito::Server::new_async().await;
+        let client = create_test_client(&server);
+        let _auth_mock = create_auth_mock(&mut server).await;
+        let cancel_mock = create_cancel_mock(&mut server, "ETH-1", cancelled("ETH-1")).await;
+
+        let result = catch_unwind(AssertUnwindSafe(move || {
+            let guard = TestOrderGuard::new(client);
+            guard.track("ETH-1");
+            panic!("test failed mid-way");
+        }));
+
+        assert!(result.is_err());
+        cancel_mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_cancel_stale_test_orders_filters_by_label() {
+        let mut server = mockito::Server::new_async().await;
+        let client = create_test_client(&server);
+        let _auth_mock = create_auth_mock(&mut server).await;
+        let _open_orders = server
+            .mock("POST", "/api/v2/private/get_open_orders")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({
+                    "jsonrpc": "2.0",
+                    "id": 1,
+                    "result": [
+                        order_json("ETH-1", "it-test-1", "open"),
+                        order_json("ETH-2", "manual", "open"),
+                        order_json("ETH-3", "it-test-2", "open")
+                    ]
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+        let first = create_cancel_mock(&mut server, "ETH-1", cancelled("ETH-1")).await;
+        let third = create_cancel_mock(&mut server, "ETH-3", cancelled("ETH-3")).await;
+
+        let cancelled = cancel_stale_test_orders(&client, "it-test-").await.unwrap();
+
+        assert_eq!(cancelled, 2);
+        first.assert_async().await;
+        third.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_cancel_stale_test_orders_rejects_empty_prefix() {
+        let client = DeribitHttpClient::new();
+        let result = cancel_stale_test_orders(&client, "").await;
+        assert!(matches!(
+            result.map_err(HttpError::into_kind),
+            Err(HttpErrorKind::ConfigError(_))
+        ));
+    }
+}
@@ -389,3 +389,232 @@ mod integration_tests {
         assert_eq!(type_str, "limit");
     }
 }
+
+#[cfg(test)]
+mod order_request_builder_tests {
+    use deribit_http::model::order::{OrderSide, OrderType};
+    use deribit_http::model::request::linked_order::{LinkedOrder, LinkedOrders};
+    use deribit_http::model::request::order::{OrderRequest, OrderValidationError};
+    use deribit_http::model::response::order::LinkedOrderType;
+    use deribit_http::model::trigger::{Trigger, TriggerFillCondition};
+    use deribit_http::{HttpError, HttpErrorKind};
+    use serde_json::json;
+
+    #[test]
+    fn test_builder_sets_fields() {
+        let request = OrderRequest::builder()
+            .instrument_name("BTC-PERPETUAL")
+            .amount(10.0)
+            .limit(50_000.0)
+            .label("ladder-1")
+            .post_only(true)
+            .build()
+            .unwrap();
+
+        assert_eq!(request.instrument_name, "BTC-PERPETUAL");
+        assert_eq!(request.amount, Some(10.0));
+        assert_eq!(request.contracts, None);
+        assert_eq!(request.type_, Some(OrderType::Limit));
+        assert_eq!(request.price, Some(50_000.0));
+        assert_eq!(request.label.as_deref(), Some("ladder-1"));
+        assert_eq!(request.post_only, Some(true));
+        assert_eq!(request.trigger_price, None);
+    }
+
+    #[test]
+    fn test_builder_accepts_stop_market_with_trigger() {
+        let request = OrderRequest::builder()
+            .instrument_name("BTC-PERPETUAL")
+            .contracts(1.0)
+            .order_type(OrderType::StopMarket)
+            .trigger_price(45_000.0)
+            .trigger(Trigger::MarkPrice)
+            .reduce_only(true)
+            .build()
+            .unwrap();
+
+        assert_eq!(request.contracts, Some(1.0));
+        assert_eq!(request.trigger_price, Some(45_000.0));
+        assert_eq!(request.trigger, Some(Trigger::MarkPrice));
+    }
+
+    #[test]
+    fn test_builder_requires_exactly_one_size() {
+        let neither = OrderRequest::builder()
+            .instrument_name("BTC-PERPETUAL")
+            .market()
+            .build();
+        let both = OrderRequest::builder()
+            .instrument_name("BTC-PERPETUAL")
+            .amount(10.0)
+            .contracts(1.0)
+            .market()
+            .build();
+        let zero = OrderRequest::builder()
+            .instrument_name("BTC-PERPETUAL")
+            .amount(0.0)
+            .market()
+            .build();
+
+        assert_eq!(neither.unwrap_err(), OrderValidationError::MissingAmount);
+        assert_eq!(both.unwrap_err(), OrderValidationError::AmountAndContracts);
+        assert_eq!(zero.unwrap_err(), OrderValidationError::InvalidAmount(0.0));
+    }
+
+    #[test]
+    fn test_builder_requires_price_and_triggers_by_type() {
+        let base = || {
+            OrderRequest::builder()
+                .instrument_name("BTC-PERPETUAL")
+                .amount(10.0)
+        };
+
+        assert_eq!(
+            base().order_type(OrderType::Limit).build().unwrap_err(),
+            OrderValidationError::MissingPrice(OrderType::Limit)
+        );
+        assert_eq!(
+            base()
+                .order_type(OrderType::StopLimit)
+                .price(44_000.0)
+                .build()
+                .unwrap_err(),
+            OrderValidationError::MissingTriggerPrice(OrderType::StopLimit)
+        );
+        assert_eq!(
+            base()
+                .order_type(OrderType::TakeMarket)
+                .build()
+                .unwrap_err(),
+            OrderValidationError::MissingTriggerPrice(OrderType::TakeMarket)
+        );
+        assert_eq!(
+            base()
+                .order_type(OrderType::TrailingStop)
+                .build()
+                .unwrap_err(),
+            OrderValidationError::MissingTriggerOffset
+        );
+        assert_eq!(
+            OrderRequest::builder().amount(10.0).build().unwrap_err(),
+            OrderValidationError::MissingInstrument
+        );
+    }
+
+    #[test]
+    fn test_validation_error_converts_to_config_error() {
+        let error: HttpError = OrderValidationError::MissingPrice(OrderType::Limit).into();
+
+        match error.into_kind() {
+            HttpErrorKind::ConfigError(message) => {
+                assert!(
+                    message.contains("price is required for limit orders"),
+                    "{}",
+                    message
+                )
+            }
+            other => panic!("expected ConfigError, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_linked_orders_fill_request_fields() {
+        let request = OrderRequest::builder()
+            .instrument_name("BTC-PERPETUAL")
+            .amount(10.0)
+            .limit(50_000.0)
+            .linked_orders(
+                LinkedOrders::one_triggers_one_cancels_other()
+                    .trigger_fill_condition(TriggerFillCondition::FirstHit)
+                    .order(LinkedOrder::limit(OrderSide::Sell, 10.0, 55_000.0).reduce_only(true))
+                    .order(LinkedOrder::stop_market(
+                        OrderSide::Sell,
+                        10.0,
+                        45_000.0,
+                        Trigger::MarkPrice,
+                    )),
+            )
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            request.linked_order_type,
+            Some(LinkedOrderType::OneTriggersOneCancelsOther)
+        );
+        assert_eq!(
+            request.trigger_fill_condition,
+            Some(TriggerFillCondition::FirstHit)
+        );
+        assert_eq!(
+            serde_json::to_value(request.otoco_config).unwrap(),
+            json!([
+                {
+                    "amount": 10.0,
+                    "direction": "sell",
+                    "type": "limit",
+                    "price": 55000.0,
+                    "reduce_only": true
+                },
+                {
+                    "amount": 10.0,
+                    "direction": "sell",
+                    "type": "stop_market",
+                    "trigger_price": 45000.0,
+                    "trigger": "mark_price"
+                }
+            ])
+        );
+    }
+
+    #[test]
+    fn test_linked_orders_are_validated() {
+        let base = || {
+            OrderRequest::builder()
+                .instrument_name("BTC-PERPETUAL")
+                .amount(10.0)
+                .limit(50_000.0)
+        };
+
+        assert_eq!(
+            base()
+                .linked_orders(LinkedOrders::one_cancels_other())
+                .build()
+                .unwrap_err(),
+            OrderValidationError::MissingLinkedOrders
+        );
+        assert_eq!(
+            base()
+                .linked_orders(
+                    LinkedOrders::one_triggers_other()
+                        .order(LinkedOrder::limit(OrderSide::Sell, 10.0, 55_000.0))
+                        .order(LinkedOrder::new(
+                            OrderSide::Sell,
+                            10.0,
+                            OrderType::StopMarket
+                        )),
+                )
+                .build()
+                .unwrap_err(),
+            OrderValidationError::InvalidLinkedOrder(
+                1,
+                Box::new(OrderValidationError::MissingTriggerPrice(
+                    OrderType::StopMarket
+                ))
+            )
+        );
+        assert_eq!(
+            base()
+                .linked_orders(LinkedOrders::one_triggers_other().order(LinkedOrder::limit(
+                    OrderSide::Sell,
+                    0.0,
+                    55_000.0
+                )),)
+                .build()
+                .unwrap_err(),
+            OrderValidationError::InvalidLinkedOrder(
+                0,
+                Box::new(OrderValidationError::InvalidAmount(0.0))
+            )
+        );
+    }
+}
@@ -1,4 +1,5 @@
 use deribit_http::model::OptionType;
+use deribit_http::model::TimestampMs;
 use deribit_http::model::other::{
     DeliveryPriceData, Greeks, OptionInstrument, OptionInstrumentPair, ParsedOptionWithTicker,
     SortDirection,
@@ -15,17 +16,18 @@ fn create_mock_instrument() -> deribit_http::model::instrument::Instrument {
         kind: Some(InstrumentKind::Option),
         currency: Some("BTC".to_string()),
         is_active: Some(true),
-        expiration_timestamp: Some(1640390400000),
+        expiration_timestamp: Some(TimestampMs(1640390400000)),
         strike: Some(50000.0),
         option_type: Some(OptionType::Call),
         tick_size: Some(0.5),
+        tick_size_steps: None,
         min_trade_amount: Some(10.0),
         contract_size: Some(1.0),
         settlement_period: Some("month".to_string()),
         instrument_type: Some(InstrumentType::Linear),
         quote_currency: Some("USD".to_string()),
         settlement_currency: Some("BTC".to_string()),
-        creation_timestamp: Some(1234567890),
+        creation_timestamp: Some(TimestampMs(1234567890)),
         max_leverage: Some(1.0),
         maker_commission: Some(0.0001),
         taker_commission: Some(0.0005),
@@ -56,7 +58,7 @@ fn create_mock_ticker() -> deribit_http::model::ticker::TickerData {
         bid_iv: Some(0.24),
         ask_iv: Some(0.25),
         mark_iv: Some(0.245),
-        timestamp: 1640995200000,
+        timestamp: TimestampMs(1640995200000),
         state: "open".to_string(),
         settlement_price: Some(50000.0),
         stats: TickerStats {
@@ -330,10 +332,11 @@ mod tests {
     }
 
     #[test]
-    fn test_sort_direction_clone() {
+    fn test_sort_direction_copy() {
         let sort = SortDirection::Desc;
-        let cloned = sort.clone();
-        assert!(matches!(cloned, SortDirection::Desc));
+        let copied = sort;
+        assert_eq!(copied, sort);
+        assert!(matches!(copied, SortDirection::Desc));
     }
 
     #[test]
@@ -1,4 +1,5 @@
 use deribit_http::model::OptionType;
+use deribit_http::model::TimestampMs;
 use deribit_http::model::instrument::{Instrument, InstrumentKind, InstrumentType};
 use deribit_http::model::other::*;
 use deribit_http::model::ticker::{TickerData, TickerStats};
@@ -25,7 +26,7 @@ fn create_mock_ticker_data() -> TickerData {
         bid_iv: Some(0.75),
         ask_iv: Some(0.8),
         mark_iv: Some(0.77),
-        timestamp: 1640995200000,
+        timestamp: TimestampMs(1640995200000),
         state: "open".to_string(),
         settlement_price: None,
         stats: TickerStats {
@@ -58,13 +59,14 @@ fn create_mock_instrument() -> Instrument {
         strike: None,
         option_type: None,
         tick_size: Some(0.5),
+        tick_size_steps: None,
         min_trade_amount: Some(1.0),
         contract_size: Some(1.0),
         settlement_period: Some("perpetual".to_string()),
         instrument_type: Some(InstrumentType::Linear),
         quote_currency: Some("USD".to_string()),
         settlement_currency: Some("BTC".to_string()),
-        creation_timestamp: Some(1640995200000),
+        creation_timestamp: Some(TimestampMs(1640995200000)),
         max_leverage: Some(100.0),
         maker_commission: Some(0.0001),
         taker_commission: Some(0.0005),
@@ -537,18 +539,16 @@ mod sort_direction_tests {
     }
 
     #[test]
-    fn test_sort_direction_clone() {
-        let asc = SortDirection::Asc;
-        let _cloned = asc.clone();
-        // Clone test - just verify it compiles and runs
-
-        let desc = SortDirection::Desc;
-        let _cloned = desc.clone();
-        // Clone test - just verify it compiles and runs
-
-        let default = SortDirection::Default;
-        let _cloned = default.clone();
-        // Clone test - just verify it compiles and runs
+    fn test_sort_direction_copy() {
+        for direction in [
+            SortDirection::Asc,
+            SortDirection::Desc,
+            SortDirection::Default,
+        ] {
+            let copied = direction;
+            assert_eq!(copied, direction);
+            assert_eq!(copied.as_str(), direction.as_str());
+        }
     }
 
     #[test]
@@ -0,0 +1,179 @@
+use deribit_http::model::request::order::OrderRequest;
+use deribit_http::paper::PaperTradingConfig;
+use deribit_http::testing::{MOCK_INSTRUMENT, MockDeribitClient, fixtures};
+use deribit_http::{DeribitErrorCode, DeribitHttpClient, HttpError, HttpErrorKind};
+use serde_json::json;
+
+#[cfg(test)]
+mod paper_client_tests {
+    use super::*;
+
+    /// Mock serving the fixture book to a paper-trading client without fees
+    fn paper_client() -> (MockDeribitClient, DeribitHttpClient) {
+        let mock = MockDeribitClient::empty().with_result(
+            "public/get_order_book",
+            fixtures::order_book(MOCK_INSTRUMENT),
+        );
+        let client = DeribitHttpClient::with_config(mock.config().clone())
+            .with_paper_trading(PaperTradingConfig::default().with_fees(0.0, 0.0));
+        (mock, client)
+    }
+
+    fn order(amount: f64) -> deribit_http::model::request::order::OrderRequestBuilder {
+        OrderRequest::builder()
+            .instrument_name(MOCK_INSTRUMENT)
+            .amount(amount)
+    }
+
+    #[tokio::test]
+    async fn test_market_order_walks_the_live_book() {
+        let (mock, client) = paper_client();
+
+        let response = client
+            .buy_order(order(10_000.0).market().build().unwrap())
+            .await
+            .unwrap();
+        assert_eq!(response.order.order_state, "filled");
+        assert_eq!(response.trades.len(), 2);
+        assert_eq!(response.trades[0].price, 50_010.5);
+        assert_eq!(response.trades[0].amount, 4_120.0);
+        assert_eq!(response.trades[1].price, 50_011.0);
+        assert_eq!(response.trades[1].amount, 5_880.0);
+
+        let position = client.get_position(MOCK_INSTRUMENT).await.unwrap();
+        assert_eq!(position.size, 10_000.0);
+
+        let methods: Vec<String> = mock
+            .requests()
+            .into_iter()
+            .map(|request| request.method)
+            .collect();
+        assert!(
+            methods
+                .iter()
+                .all(|method| method == "public/get_order_book")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_resting_order_fills_when_the_book_moves() {
+        let (mock, client) = paper_client();
+
+        let placed = client
+            .buy_order(order(100.0).limit(50_000.0).label("dip").build().unwrap())
+            .await
+            .unwrap();
+        assert_eq!(placed.order.order_state, "open");
+        assert!(placed.trades.is_empty());
+        assert_eq!(client.get_open_orders(None, None).await.unwrap().len(), 1);
+
+        let _ = mock.clone().with_result(
+            "public/get_order_book",
+            fixtures::order_book(MOCK_INSTRUMENT).set("asks", json!([[49_990.0, 1_000.0]])),
+        );
+        let order_id = placed.order.order_id.as_str();
+        let filled = client.get_order_state(order_id).await.unwrap();
+        assert_eq!(filled.order_state, "filled");
+        assert_eq!(filled.average_price, Some(50_000.0));
+        assert!(client.get_open_orders(None, None).await.unwrap().is_empty());
+
+        let error = client.cancel_order(order_id).await.unwrap_err();
+        assert_eq!(error.error_code(), Some(DeribitErrorCode::NotOpenOrder));
+    }
+
+    #[tokio::test]
+    async fn test_round_trip_updates_balance() {
+        let mock = MockDeribitClient::empty().with_result(
+            "public/get_order_book",
+            fixtures::order_book(MOCK_INSTRUMENT),
+        );
+        let client = DeribitHttpClient::with_config(mock.config().clone())
+            .with_paper_trading(PaperTradingConfig::default().with_balance("BTC", 2.0));
+
+        client
+            .buy_order(order(1_000.0).market().build().unwrap())
+            .await
+            .unwrap();
+        let _ = mock.clone().with_result(
+            "public/get_order_book",
+            fixtures::order_book(MOCK_INSTRUMENT).set("bids", json!([[51_000.0, 5_000.0]])),
+        );
+        let sold = client
+            .sell_order(order(1_000.0).market().build().unwrap())
+            .await
+            .unwrap();
+        assert_eq!(sold.trades[0].price, 51_000.0);
+
+        let fees = 1_000.0 / 50_010.5 * 0.0005 + 1_000.0 / 51_000.0 * 0.0005;
+        let expected = 2.0 + 1_000.0 * (1.0 / 50_010.5 - 1.0 / 51_000.0) - fees;
+        let summary = client.get_account_summary("BTC", None).await.unwrap();
+        let btc = &summary.summaries[0];
+        assert!((btc.balance - expected).abs() < 1e-12);
+        assert_eq!(btc.equity, btc.balance);
+        assert_eq!(
+            client.get_position(MOCK_INSTRUMENT).await.unwrap().size,
+            0.0
+        );
+    }
+
+    #[tokio::test]
+    async fn test_time_in_force_and_post_only() {
+        let (_mock, client) = paper_client();
+
+        let killed = client
+            .buy_order(
+                order(1_000_000.0)
+                    .limit(50_020.0)
+                    .time_in_force(deribit_http::model::types::TimeInForce::FillOrKill)
+                    .build()
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(killed.order.order_state, "cancelled");
+        assert!(killed.trades.is_empty());
+
+        let repriced = client
+            .buy_order(order(10.0).limit(50_020.0).post_only(true).build().unwrap())
+            .await
+            .unwrap();
+        assert_eq!(repriced.order.order_state, "open");
+        assert_eq!(repriced.order.price, 50_010.0);
+
+        let error = client
+            .buy_order(
+                order(10.0)
+                    .limit(50_020.0)
+                    .post_only(true)
+                    .reject_post_only(true)
+                    .build()
+                    .unwrap(),
+            )
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            error.kind(),
+            HttpErrorKind::Api { code: 11054, .. }
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_unsimulated_and_unknown_requests() {
+        let (_mock, client) = paper_client();
+        assert!(client.is_paper_trading());
+
+        let error = client.cancel_order("BTC-404").await.unwrap_err();
+        assert_eq!(error.error_code(), Some(DeribitErrorCode::OrderNotFound));
+
+        match client
+            .get_subaccounts(None)
+            .await
+            .map_err(HttpError::into_kind)
+        {
+            Err(HttpErrorKind::ConfigError(message)) => {
+                assert!(message.contains("private/get_subaccounts"))
+            }
+            other => panic!("expected ConfigError, got {:?}", other),
+        }
+    }
+}
@@ -0,0 +1,63 @@
+use deribit_http::model::position::{Position, PositionChange};
+use serde_json::json;
+
+fn position(instrument_name: &str, size: f64) -> Position {
+    serde_json::from_value(json!({ "instrument_name": instrument_name, "size": size })).unwrap()
+}
+
+#[test]
+fn test_is_option_by_kind_or_name() {
+    assert!(position("BTC-27DEC24-50000-C", 1.0).is_option());
+    assert!(!position("BTC-PERPETUAL", 1.0).is_option());
+}
+
+#[test]
+fn test_position_change_between_identical_snapshots_is_empty() {
+    let snapshot = vec![position("BTC-PERPETUAL", 100.0)];
+    assert!(PositionChange::between(&snapshot, &snapshot).is_empty());
+}
+
+#[test]
+fn test_position_change_between_detects_open_close_and_resize() {
+    let previous = vec![
+        position("BTC-PERPETUAL", 100.0),
+        position("BTC-27DEC24", -50.0),
+        position("BTC-27DEC24-50000-C", 1.0),
+    ];
+    let current = vec![
+        position("BTC-PERPETUAL", 150.0),
+        position("BTC-27DEC24-50000-C", 1.0),
+        position("BTC-27DEC24-60000-P", 2.0),
+    ];
+
+    let changes = PositionChange::between(&previous, &current);
+
+    assert_eq!(changes.len(), 3);
+    match &changes[0] {
+        PositionChange::SizeChanged {
+            previous_size,
+            position,
+        } => {
+            assert_eq!(*previous_size, 100.0);
+            assert_eq!(position.instrument_name, "BTC-PERPETUAL");
+        }
+        other => panic!("expected SizeChanged, got {}", other),
+    }
+    assert!(
+        matches!(&changes[1], PositionChange::Opened(p) if p.instrument_name == "BTC-27DEC24-60000-P")
+    );
+    assert!(matches!(&changes[2], PositionChange::Closed(p) if p.instrument_name == "BTC-27DEC24"));
+}
+
+#[test]
+fn test_position_change_treats_zero_size_as_closed() {
+    let previous = vec![position("BTC-PERPETUAL", 100.0)];
+    let current = vec![position("BTC-PERPETUAL", 0.0)];
+
+    let changes = PositionChange::between(&previous, &current);
+
+    assert_eq!(changes.len(), 1);
+    assert!(matches!(&changes[0], PositionChange::Closed(_)));
+    assert_eq!(changes[0].position().instrument_name, "BTC-PERPETUAL");
+    assert!(PositionChange::between(&current, &current).is_empty());
+}
@@ -1,10 +1,20 @@
 //! Unit tests for private endpoints
 
-use deribit_http::DeribitHttpClient;
 use deribit_http::config::HttpConfig;
+use deribit_http::model::TimestampMs;
+use deribit_http::model::instrument::InstrumentKind;
+use deribit_http::model::order::{OrderSide, OrderType};
+use deribit_http::model::other::SortDirection;
+use deribit_http::model::request::linked_order::{LinkedOrder, LinkedOrders};
+use deribit_http::model::request::order::OrderRequest;
 use deribit_http::model::transaction::TransactionLogRequest;
+use deribit_http::model::trigger::{Trigger, TriggerFillCondition};
+use deribit_http::{DeribitErrorCode, DeribitHttpClient, HttpError, HttpErrorKind};
 use serde_json::json;
 use std::env;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
 use url::Url;
 
 // Helper function to create a test client
@@ -23,6 +33,11 @@ fn create_test_client(server: &mockito::ServerGuard) -> DeribitHttpClient {
     DeribitHttpClient::with_config(config)
 }
 
+// Helper function to match the JSON-RPC params of a private request
+fn rpc_params(params: serde_json::Value) -> mockito::Matcher {
+    mockito::Matcher::PartialJson(json!({ "params": params }))
+}
+
 // Helper function to create OAuth2 authentication mock
 async fn create_auth_mock(server: &mut mockito::Server) -> mockito::Mock {
     server
@@ -54,7 +69,8 @@ async fn test_get_subaccounts_success() {
     let _auth_mock = create_auth_mock(&mut server).await;
 
     let mock = server
-        .mock("GET", "/api/v2/private/get_subaccounts?with_portfolio=true")
+        .mock("POST", "/api/v2/private/get_subaccounts")
+        .match_body(rpc_params(json!({ "with_portfolio": true })))
         .with_status(200)
         .with_header("content-type", "application/json")
         .with_body(
@@ -98,7 +114,7 @@ async fn test_get_subaccounts_error() {
     let _auth_mock = create_auth_mock(&mut server).await;
 
     let mock = server
-        .mock("GET", "/api/v2/private/get_subaccounts")
+        .mock("POST", "/api/v2/private/get_subaccounts")
         .with_status(400)
         .with_header("content-type", "application/json")
         .with_body(
@@ -153,7 +169,8 @@ async fn test_get_transaction_log_success() {
     });
 
     let mock = server
-        .mock("GET", "/api/v2/private/get_transaction_log?currency=BTC&start_timestamp=1609459200000&end_timestamp=1609459300000")
+        .mock("POST", "/api/v2/private/get_transaction_log")
+        .match_body(rpc_params(json!({ "currency": "BTC", "start_timestamp": 1_609_459_200_000_u64, "end_timestamp": 1_609_459_300_000_u64 })))
         .with_status(200)
         .with_header("content-type", "application/json")
         .with_body(mock_response.to_string())
@@ -162,8 +179,8 @@ async fn test_get_transaction_log_success() {
 
     let request = TransactionLogRequest {
         currency: "BTC".to_string(),
-        start_timestamp: 1609459200000,
-        end_timestamp: 1609459300000,
+        start_timestamp: TimestampMs(1609459200000),
+        end_timestamp: TimestampMs(1609459300000),
         query: None,
         count: None,
         subaccount_id: None,
@@ -190,7 +207,8 @@ async fn test_get_transaction_log_error() {
     let _auth_mock = create_auth_mock(&mut server).await;
 
     let mock = server
-        .mock("GET", "/api/v2/private/get_transaction_log?currency=BTC&start_timestamp=1609459200000&end_timestamp=1609459300000")
+        .mock("POST", "/api/v2/private/get_transaction_log")
+        .match_body(rpc_params(json!({ "currency": "BTC", "start_timestamp": 1_609_459_200_000_u64, "end_timestamp": 1_609_459_300_000_u64 })))
         .with_status(400)
         .with_header("content-type", "application/json")
         .with_body(r#"{
@@ -206,8 +224,8 @@ async fn test_get_transaction_log_error() {
 
     let request = TransactionLogRequest {
         currency: "BTC".to_string(),
-        start_timestamp: 1609459200000,
-        end_timestamp: 1609459300000,
+        start_timestamp: TimestampMs(1609459200000),
+        end_timestamp: TimestampMs(1609459300000),
         query: None,
         count: None,
         subaccount_id: None,
@@ -219,6 +237,113 @@ async fn test_get_transaction_log_error() {
     assert!(result.is_err());
 }
 
+async fn create_transaction_log_pages(
+    server: &mut mockito::Server,
+    second_page_hits: usize,
+) -> (mockito::Mock, mockito::Mock) {
+    let entry = |id: u64| {
+        json!({
+            "id": id,
+            "currency": "BTC",
+            "amount": 0.001,
+            "balance": 1.5,
+            "timestamp": 1609459200000u64,
+            "type": "trade",
+            "change": 0.001,
+            "cashflow": 0.001,
+            "user_id": 1,
+            "user_seq": id,
+            "equity": 1.5,
+            "username": "test_user"
+        })
+    };
+    let first_page = server
+        .mock("POST", "/api/v2/private/get_transaction_log")
+        .match_body(rpc_params(json!({ "currency": "BTC", "count": 2 })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "result": { "logs": [entry(3), entry(2)], "continuation": 2 }
+            })
+            .to_string(),
+        )
+        .expect(1)
+        .create_async()
+        .await;
+    let second_page = server
+        .mock("POST", "/api/v2/private/get_transaction_log")
+        .match_body(rpc_params(
+            json!({ "currency": "BTC", "count": 2, "continuation": 2 }),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "result": { "logs": [entry(1)], "continuation": null }
+            })
+            .to_string(),
+        )
+        .expect(second_page_hits)
+        .create_async()
+        .await;
+    (first_page, second_page)
+}
+
+fn transaction_log_request() -> TransactionLogRequest {
+    TransactionLogRequest {
+        count: Some(2),
+        ..TransactionLogRequest::new(
+            "BTC",
+            (TimestampMs(1609459200000), TimestampMs(1609459300000)),
+        )
+    }
+}
+
+#[tokio::test]
+async fn test_stream_transaction_log_follows_continuation() {
+    use futures_util::TryStreamExt;
+
+    let mut server = mockito::Server::new_async().await;
+    let client = create_test_client(&server);
+
+    let _auth_mock = create_auth_mock(&mut server).await;
+    let (first_page, second_page) = create_transaction_log_pages(&mut server, 1).await;
+
+    let entries: Vec<_> = client
+        .stream_transaction_log(transaction_log_request())
+        .try_collect()
+        .await
+        .unwrap();
+
+    first_page.assert_async().await;
+    second_page.assert_async().await;
+    let ids: Vec<u64> = entries.iter().map(|entry| entry.id).collect();
+    assert_eq!(ids, vec![3, 2, 1]);
+}
+
+#[tokio::test]
+async fn test_get_transaction_log_all_stops_at_max_entries() {
+    let mut server = mockito::Server::new_async().await;
+    let client = create_test_client(&server);
+
+    let _auth_mock = create_auth_mock(&mut server).await;
+    let (first_page, second_page) = create_transaction_log_pages(&mut server, 0).await;
+
+    let entries = client
+        .get_transaction_log_all(transaction_log_request(), Some(2))
+        .await
+        .unwrap();
+
+    first_page.assert_async().await;
+    second_page.assert_async().await;
+    assert_eq!(entries.len(), 2);
+}
+
 #[tokio::test]
 async fn test_get_deposits_success() {
     let mut server = mockito::Server::new_async().await;
@@ -247,7 +372,8 @@ async fn test_get_deposits_success() {
     });
 
     let mock = server
-        .mock("GET", "/api/v2/private/get_deposits?currency=BTC")
+        .mock("POST", "/api/v2/private/get_deposits")
+        .match_body(rpc_params(json!({ "currency": "BTC" })))
         .with_status(200)
         .with_header("content-type", "application/json")
         .with_body(mock_response.to_string())
@@ -289,7 +415,8 @@ async fn test_get_withdrawals_success() {
     });
 
     let mock = server
-        .mock("GET", "/api/v2/private/get_withdrawals?currency=BTC")
+        .mock("POST", "/api/v2/private/get_withdrawals")
+        .match_body(rpc_params(json!({ "currency": "BTC" })))
         .with_status(200)
         .with_header("content-type", "application/json")
         .with_body(mock_response.to_string())
@@ -305,6 +432,121 @@ async fn test_get_withdrawals_success() {
     assert!(result.is_ok());
 }
 
+#[tokio::test]
+async fn test_stream_deposits_walks_offsets() {
+    use futures_util::TryStreamExt;
+
+    let mut server = mockito::Server::new_async().await;
+    let client = create_test_client(&server);
+
+    let _auth_mock = create_auth_mock(&mut server).await;
+
+    let deposit = |transaction_id: &str| {
+        json!({
+            "address": "1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa",
+            "amount": 0.001,
+            "currency": "BTC",
+            "state": "completed",
+            "received_timestamp": 1609459200000u64,
+            "transaction_id": transaction_id,
+            "updated_timestamp": 1609459200000u64
+        })
+    };
+    let first_page = server
+        .mock("POST", "/api/v2/private/get_deposits")
+        .match_body(rpc_params(json!({ "currency": "BTC", "count": 1000 })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "result": { "count": 3, "data": [deposit("tx3"), deposit("tx2")] }
+            })
+            .to_string(),
+        )
+        .expect(1)
+        .create_async()
+        .await;
+    let second_page = server
+        .mock("POST", "/api/v2/private/get_deposits")
+        .match_body(rpc_params(
+            json!({ "currency": "BTC", "count": 1000, "offset": 2 }),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "result": { "count": 3, "data": [deposit("tx1")] }
+            })
+            .to_string(),
+        )
+        .expect(1)
+        .create_async()
+        .await;
+
+    let deposits: Vec<_> = client.stream_deposits("BTC").try_collect().await.unwrap();
+
+    first_page.assert_async().await;
+    second_page.assert_async().await;
+    let ids: Vec<_> = deposits
+        .iter()
+        .filter_map(|deposit| deposit.transaction_id.as_deref())
+        .collect();
+    assert_eq!(ids, vec!["tx3", "tx2", "tx1"]);
+}
+
+#[tokio::test]
+async fn test_stream_withdrawals_stops_at_total() {
+    use futures_util::TryStreamExt;
+
+    let mut server = mockito::Server::new_async().await;
+    let client = create_test_client(&server);
+
+    let _auth_mock = create_auth_mock(&mut server).await;
+
+    let mock = server
+        .mock("POST", "/api/v2/private/get_withdrawals")
+        .match_body(rpc_params(json!({ "currency": "BTC", "count": 1000 })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "result": {
+                    "count": 1,
+                    "data": [{
+                        "id": 123,
+                        "currency": "BTC",
+                        "amount": 0.001,
+                        "address": "bc1qxy2kgdygjrsqtzq2n0yrf2493p83kkfjhx0wlh",
+                        "state": "completed",
+                        "created_timestamp": 1609459200000u64,
+                        "fee": 0.0001,
+                        "priority": "normal"
+                    }]
+                }
+            })
+            .to_string(),
+        )
+        .expect(1)
+        .create_async()
+        .await;
+
+    let withdrawals: Vec<_> = client
+        .stream_withdrawals("BTC")
+        .try_collect()
+        .await
+        .unwrap();
+
+    mock.assert_async().await;
+    assert_eq!(withdrawals.len(), 1);
+    assert_eq!(withdrawals[0].id, 123);
+}
+
 #[tokio::test]
 async fn test_submit_transfer_to_subaccount_success() {
     let mut server = mockito::Server::new_async().await;
@@ -323,7 +565,10 @@ async fn test_submit_transfer_to_subaccount_success() {
     });
 
     let mock = server
-        .mock("GET", "/api/v2/private/submit_transfer_to_subaccount?currency=BTC&amount=0.001&destination=123")
+        .mock("POST", "/api/v2/private/submit_transfer_to_subaccount")
+        .match_body(rpc_params(
+            json!({ "currency": "BTC", "amount": 0.001, "destination": 123 }),
+        ))
         .with_status(200)
         .with_header("content-type", "application/json")
         .with_body(mock_response.to_string())
@@ -362,7 +607,10 @@ async fn test_submit_transfer_to_user_success() {
     });
 
     let mock = server
-        .mock("GET", "/api/v2/private/submit_transfer_to_user?currency=BTC&amount=0.001&destination=test_user")
+        .mock("POST", "/api/v2/private/submit_transfer_to_user")
+        .match_body(rpc_params(
+            json!({ "currency": "BTC", "amount": 0.001, "destination": "test_user" }),
+        ))
         .with_status(200)
         .with_header("content-type", "application/json")
         .with_body(mock_response.to_string())
@@ -380,6 +628,55 @@ async fn test_submit_transfer_to_user_success() {
     assert!(result.is_ok());
 }
 
+// =========================================================================
+// Get Position Tests
+// =========================================================================
+
+#[tokio::test]
+async fn test_get_position_returns_single_position() {
+    let mut server = mockito::Server::new_async().await;
+    let client = create_test_client(&server);
+
+    let _auth_mock = create_auth_mock(&mut server).await;
+
+    let mock = server
+        .mock("POST", "/api/v2/private/get_position")
+        .match_body(rpc_params(json!({ "instrument_name": "BTC-PERPETUAL" })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "result": {
+                    "average_price": 50000.0,
+                    "delta": 0.02,
+                    "direction": "buy",
+                    "floating_profit_loss": 0.0001,
+                    "initial_margin": 0.0002,
+                    "instrument_name": "BTC-PERPETUAL",
+                    "kind": "future",
+                    "leverage": 50,
+                    "maintenance_margin": 0.0001,
+                    "mark_price": 50500.0,
+                    "size": 1000.0,
+                    "size_currency": 0.02,
+                    "total_profit_loss": 0.0001
+                }
+            })
+            .to_string(),
+        )
+        .create_async()
+        .await;
+
+    let position = client.get_position("BTC-PERPETUAL").await.unwrap();
+
+    mock.assert_async().await;
+    assert_eq!(position.instrument_name, "BTC-PERPETUAL");
+    assert_eq!(position.size, 1000.0);
+    assert_eq!(position.leverage, Some(50));
+}
+
 // =========================================================================
 // Close Position Tests (Issue #13)
 // =========================================================================
@@ -444,17 +741,19 @@ async fn test_close_position_market_order_success() {
     });
 
     let mock = server
-        .mock(
-            "GET",
-            "/api/v2/private/close_position?instrument_name=BTC-PERPETUAL&type=market",
-        )
+        .mock("POST", "/api/v2/private/close_position")
+        .match_body(rpc_params(
+            json!({ "instrument_name": "BTC-PERPETUAL", "type": "market" }),
+        ))
         .with_status(200)
         .with_header("content-type", "application/json")
         .with_body(mock_response.to_string())
         .create_async()
         .await;
 
-    let result = client.close_position("BTC-PERPETUAL", "market", None).await;
+    let result = client
+        .close_position("BTC-PERPETUAL", OrderType::Market, None)
+        .await;
 
     mock.assert_async().await;
     if let Err(e) = &result {
@@ -505,10 +804,10 @@ async fn test_close_position_limit_order_success() {
     });
 
     let mock = server
-        .mock(
-            "GET",
-            "/api/v2/private/close_position?instrument_name=ETH-PERPETUAL&type=limit&price=2500",
-        )
+        .mock("POST", "/api/v2/private/close_position")
+        .match_body(rpc_params(
+            json!({ "instrument_name": "ETH-PERPETUAL", "type": "limit", "price": 2500.0 }),
+        ))
         .with_status(200)
         .with_header("content-type", "application/json")
         .with_body(mock_response.to_string())
@@ -516,7 +815,7 @@ async fn test_close_position_limit_order_success() {
         .await;
 
     let result = client
-        .close_position("ETH-PERPETUAL", "limit", Some(2500.0))
+        .close_position("ETH-PERPETUAL", OrderType::Limit, Some(2500.0))
         .await;
 
     mock.assert_async().await;
@@ -539,10 +838,10 @@ async fn test_close_position_no_position_error() {
     let _auth_mock = create_auth_mock(&mut server).await;
 
     let mock = server
-        .mock(
-            "GET",
-            "/api/v2/private/close_position?instrument_name=BTC-PERPETUAL&type=market",
-        )
+        .mock("POST", "/api/v2/private/close_position")
+        .match_body(rpc_params(
+            json!({ "instrument_name": "BTC-PERPETUAL", "type": "market" }),
+        ))
         .with_status(400)
         .with_header("content-type", "application/json")
         .with_body(
@@ -558,12 +857,114 @@ async fn test_close_position_no_position_error() {
         .create_async()
         .await;
 
-    let result = client.close_position("BTC-PERPETUAL", "market", None).await;
+    let result = client
+        .close_position("BTC-PERPETUAL", OrderType::Market, None)
+        .await;
 
     mock.assert_async().await;
     assert!(result.is_err());
 }
 
+#[tokio::test]
+async fn test_close_position_market_sends_market_type() {
+    let mut server = mockito::Server::new_async().await;
+    let client = create_test_client(&server);
+
+    let _auth_mock = create_auth_mock(&mut server).await;
+
+    let mock = server
+        .mock("POST", "/api/v2/private/close_position")
+        .match_body(rpc_params(
+            json!({ "instrument_name": "ETH-PERPETUAL", "type": "market" }),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "result": {
+                    "order": {
+                        "amount": 5.0,
+                        "api": true,
+                        "average_price": 2500.0,
+                        "creation_timestamp": 1609459200000u64,
+                        "direction": "buy",
+                        "filled_amount": 5.0,
+                        "instrument_name": "ETH-PERPETUAL",
+                        "is_liquidation": false,
+                        "label": "",
+                        "last_update_timestamp": 1609459200000u64,
+                        "order_id": "ETH-345678",
+                        "order_state": "filled",
+                        "order_type": "market",
+                        "post_only": false,
+                        "price": 2500.0,
+                        "reduce_only": true,
+                        "replaced": false,
+                        "risk_reducing": false,
+                        "time_in_force": "good_til_cancelled",
+                        "web": false
+                    },
+                    "trades": []
+                }
+            })
+            .to_string(),
+        )
+        .create_async()
+        .await;
+
+    let response = client.close_position_market("ETH-PERPETUAL").await.unwrap();
+
+    mock.assert_async().await;
+    assert_eq!(response.order.order_id, "ETH-345678");
+    assert!(response.order.reduce_only);
+}
+
+#[tokio::test]
+async fn test_close_position_limit_without_price_is_rejected() {
+    let mut server = mockito::Server::new_async().await;
+    let client = create_test_client(&server);
+
+    let mock = server
+        .mock("POST", "/api/v2/private/close_position")
+        .expect(0)
+        .create_async()
+        .await;
+
+    let result = client
+        .close_position("BTC-PERPETUAL", OrderType::Limit, None)
+        .await;
+
+    mock.assert_async().await;
+    assert!(matches!(
+        result.map_err(HttpError::into_kind),
+        Err(HttpErrorKind::ConfigError(_))
+    ));
+}
+
+#[tokio::test]
+async fn test_close_position_stop_order_is_rejected() {
+    let mut server = mockito::Server::new_async().await;
+    let client = create_test_client(&server);
+
+    let mock = server
+        .mock("POST", "/api/v2/private/close_position")
+        .expect(0)
+        .create_async()
+        .await;
+
+    let result = client
+        .close_position("BTC-PERPETUAL", OrderType::StopMarket, None)
+        .await;
+
+    mock.assert_async().await;
+    assert!(matches!(
+        result.map_err(HttpError::into_kind),
+        Err(HttpErrorKind::ConfigError(m)) if m.contains("stop_market")
+    ));
+}
+
 // =========================================================================
 // Edit Order By Label Tests (Issue #14)
 // =========================================================================
@@ -608,10 +1009,8 @@ async fn test_edit_order_by_label_success() {
     });
 
     let mock = server
-        .mock(
-            "GET",
-            "/api/v2/private/edit_by_label?label=i_love_deribit&instrument_name=BTC-PERPETUAL&amount=150&price=50111",
-        )
+        .mock("POST", "/api/v2/private/edit_by_label")
+        .match_body(rpc_params(json!({ "label": "i_love_deribit", "instrument_name": "BTC-PERPETUAL", "amount": 150.0, "price": 50111.0 })))
         .with_status(200)
         .with_header("content-type", "application/json")
         .with_body(mock_response.to_string())
@@ -688,9 +1087,101 @@ async fn test_edit_order_by_label_missing_label_error() {
 
     assert!(result.is_err());
     let err = result.unwrap_err();
+    assert!(matches!(err.kind(), HttpErrorKind::ConfigError(_)));
     assert!(err.to_string().contains("label is required"));
 }
 
+#[tokio::test]
+async fn test_edit_order_by_label_forwards_modifiers() {
+    use deribit_http::model::request::order::{AdvancedOrderType, OrderRequest};
+
+    let mut server = mockito::Server::new_async().await;
+    let client = create_test_client(&server);
+
+    let _auth_mock = create_auth_mock(&mut server).await;
+
+    let mock = server
+        .mock("POST", "/api/v2/private/edit_by_label")
+        .match_body(rpc_params(json!({
+            "label": "iv_quote",
+            "instrument_name": "BTC-27DEC24-60000-C",
+            "contracts": 2.0,
+            "price": 55.5,
+            "post_only": true,
+            "reject_post_only": true,
+            "reduce_only": false,
+            "advanced": "implv",
+            "mmp": true,
+            "valid_until": 1_700_000_060_000_i64
+        })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "result": {
+                    "order": {
+                        "amount": 0.2,
+                        "api": true,
+                        "average_price": 0.0,
+                        "creation_timestamp": 1_700_000_000_000_u64,
+                        "direction": "sell",
+                        "filled_amount": 0.0,
+                        "instrument_name": "BTC-27DEC24-60000-C",
+                        "is_liquidation": false,
+                        "label": "iv_quote",
+                        "last_update_timestamp": 1_700_000_001_000_u64,
+                        "order_id": "ETH-1",
+                        "order_state": "open",
+                        "order_type": "limit",
+                        "post_only": true,
+                        "price": 0.035,
+                        "reduce_only": false,
+                        "replaced": true,
+                        "risk_reducing": false,
+                        "time_in_force": "good_til_cancelled",
+                        "web": false
+                    },
+                    "trades": []
+                }
+            })
+            .to_string(),
+        )
+        .create_async()
+        .await;
+
+    let request = OrderRequest {
+        order_id: None,
+        instrument_name: "BTC-27DEC24-60000-C".to_string(),
+        amount: None,
+        contracts: Some(2.0),
+        type_: None,
+        label: Some("iv_quote".to_string()),
+        price: Some(55.5),
+        time_in_force: None,
+        display_amount: None,
+        post_only: Some(true),
+        reject_post_only: Some(true),
+        reduce_only: Some(false),
+        trigger_price: None,
+        trigger_offset: None,
+        trigger: None,
+        advanced: Some(AdvancedOrderType::Implv),
+        mmp: Some(true),
+        valid_until: Some(1_700_000_060_000),
+        linked_order_type: None,
+        trigger_fill_condition: None,
+        otoco_config: None,
+    };
+
+    let response = client.edit_order_by_label(request).await.unwrap();
+
+    mock.assert_async().await;
+    assert_eq!(response.order.label, "iv_quote");
+    assert!(response.order.replaced);
+}
+
 #[tokio::test]
 async fn test_edit_order_by_label_no_order_error() {
     let mut server = mockito::Server::new_async().await;
@@ -700,10 +1191,8 @@ async fn test_edit_order_by_label_no_order_error() {
     let _auth_mock = create_auth_mock(&mut server).await;
 
     let mock = server
-        .mock(
-            "GET",
-            "/api/v2/private/edit_by_label?label=nonexistent_label&instrument_name=BTC-PERPETUAL&amount=150",
-        )
+        .mock("POST", "/api/v2/private/edit_by_label")
+        .match_body(rpc_params(json!({ "label": "nonexistent_label", "instrument_name": "BTC-PERPETUAL", "amount": 150.0 })))
         .with_status(400)
         .with_header("content-type", "application/json")
         .with_body(
@@ -773,10 +1262,10 @@ async fn test_get_margins_success() {
     });
 
     let mock = server
-        .mock(
-            "GET",
-            "/api/v2/private/get_margins?instrument_name=BTC-PERPETUAL&amount=10000&price=3725",
-        )
+        .mock("POST", "/api/v2/private/get_margins")
+        .match_body(rpc_params(
+            json!({ "instrument_name": "BTC-PERPETUAL", "amount": 10000.0, "price": 3725.0 }),
+        ))
         .with_status(200)
         .with_header("content-type", "application/json")
         .with_body(mock_response.to_string())
@@ -806,10 +1295,10 @@ async fn test_get_margins_error() {
     let _auth_mock = create_auth_mock(&mut server).await;
 
     let mock = server
-        .mock(
-            "GET",
-            "/api/v2/private/get_margins?instrument_name=INVALID&amount=10000&price=3725",
-        )
+        .mock("POST", "/api/v2/private/get_margins")
+        .match_body(rpc_params(
+            json!({ "instrument_name": "INVALID", "amount": 10000.0, "price": 3725.0 }),
+        ))
         .with_status(400)
         .with_header("content-type", "application/json")
         .with_body(
@@ -860,7 +1349,8 @@ async fn test_get_mmp_config_success() {
     });
 
     let mock = server
-        .mock("GET", "/api/v2/private/get_mmp_config?index_name=btc_usd")
+        .mock("POST", "/api/v2/private/get_mmp_config")
+        .match_body(rpc_params(json!({ "index_name": "btc_usd" })))
         .with_status(200)
         .with_header("content-type", "application/json")
         .with_body(mock_response.to_string())
@@ -897,7 +1387,8 @@ async fn test_get_mmp_status_success() {
     });
 
     let mock = server
-        .mock("GET", "/api/v2/private/get_mmp_status?index_name=btc_usd")
+        .mock("POST", "/api/v2/private/get_mmp_status")
+        .match_body(rpc_params(json!({ "index_name": "btc_usd" })))
         .with_status(200)
         .with_header("content-type", "application/json")
         .with_body(mock_response.to_string())
@@ -935,12 +1426,8 @@ async fn test_set_mmp_config_success() {
     });
 
     let mock = server
-        .mock(
-            "GET",
-            mockito::Matcher::Regex(
-                r"/api/v2/private/set_mmp_config\?.*index_name=btc_usd.*".to_string(),
-            ),
-        )
+        .mock("POST", "/api/v2/private/set_mmp_config")
+        .match_body(rpc_params(json!({ "index_name": "btc_usd" })))
         .with_status(200)
         .with_header("content-type", "application/json")
         .with_body(mock_response.to_string())
@@ -982,7 +1469,8 @@ async fn test_reset_mmp_success() {
     });
 
     let mock = server
-        .mock("GET", "/api/v2/private/reset_mmp?index_name=btc_usd")
+        .mock("POST", "/api/v2/private/reset_mmp")
+        .match_body(rpc_params(json!({ "index_name": "btc_usd" })))
         .with_status(200)
         .with_header("content-type", "application/json")
         .with_body(mock_response.to_string())
@@ -1025,10 +1513,7 @@ async fn test_get_order_margin_by_ids_success() {
     });
 
     let mock = server
-        .mock(
-            "GET",
-            mockito::Matcher::Regex(r"/api/v2/private/get_order_margin_by_ids\?ids=.*".to_string()),
-        )
+        .mock("POST", "/api/v2/private/get_order_margin_by_ids")
         .with_status(200)
         .with_header("content-type", "application/json")
         .with_body(mock_response.to_string())
@@ -1057,6 +1542,7 @@ async fn test_get_order_margin_by_ids_empty_ids_error() {
 
     assert!(result.is_err());
     let err = result.unwrap_err();
+    assert!(matches!(err.kind(), HttpErrorKind::ConfigError(_)));
     assert!(err.to_string().contains("ids array cannot be empty"));
 }
 
@@ -1102,10 +1588,8 @@ async fn test_get_order_state_by_label_success() {
     });
 
     let mock = server
-        .mock(
-            "GET",
-            "/api/v2/private/get_order_state_by_label?currency=ETH&label=fooBar",
-        )
+        .mock("POST", "/api/v2/private/get_order_state_by_label")
+        .match_body(rpc_params(json!({ "currency": "ETH", "label": "fooBar" })))
         .with_status(200)
         .with_header("content-type", "application/json")
         .with_body(mock_response.to_string())
@@ -1137,10 +1621,10 @@ async fn test_get_order_state_by_label_empty_result() {
     });
 
     let mock = server
-        .mock(
-            "GET",
-            "/api/v2/private/get_order_state_by_label?currency=BTC&label=nonexistent",
-        )
+        .mock("POST", "/api/v2/private/get_order_state_by_label")
+        .match_body(rpc_params(
+            json!({ "currency": "BTC", "label": "nonexistent" }),
+        ))
         .with_status(200)
         .with_header("content-type", "application/json")
         .with_body(mock_response.to_string())
@@ -1187,12 +1671,8 @@ async fn test_get_settlement_history_by_currency_success() {
     });
 
     let mock = server
-        .mock(
-            "GET",
-            mockito::Matcher::Regex(
-                r"/api/v2/private/get_settlement_history_by_currency\?currency=BTC.*".to_string(),
-            ),
-        )
+        .mock("POST", "/api/v2/private/get_settlement_history_by_currency")
+        .match_body(rpc_params(json!({ "currency": "BTC" })))
         .with_status(200)
         .with_header("content-type", "application/json")
         .with_body(mock_response.to_string())
@@ -1243,11 +1723,8 @@ async fn test_get_settlement_history_by_instrument_success() {
 
     let mock = server
         .mock(
-            "GET",
-            mockito::Matcher::Regex(
-                r"/api/v2/private/get_settlement_history_by_instrument\?instrument_name=.*"
-                    .to_string(),
-            ),
+            "POST",
+            "/api/v2/private/get_settlement_history_by_instrument",
         )
         .with_status(200)
         .with_header("content-type", "application/json")
@@ -1273,30 +1750,95 @@ async fn test_get_settlement_history_by_instrument_success() {
 }
 
 // =========================================================================
-// Get Trigger Order History Tests (Issue #20)
+// Get Stop Order History Tests
 // =========================================================================
 
 #[tokio::test]
-async fn test_get_trigger_order_history_success() {
+async fn test_get_stop_order_history_success() {
     let mut server = mockito::Server::new_async().await;
     let client = create_test_client(&server);
 
     let _auth_mock = create_auth_mock(&mut server).await;
 
-    let mock_response = json!({
-        "jsonrpc": "2.0",
-        "result": {
-            "entries": [
-                {
-                    "timestamp": 1555918941451i64,
-                    "trigger": "index_price",
-                    "trigger_price": 5285.0,
-                    "trigger_order_id": "SLIS-103",
-                    "order_id": "671473",
-                    "order_state": "triggered",
-                    "instrument_name": "BTC-PERPETUAL",
-                    "request": "trigger:order",
-                    "direction": "buy",
+    let mock = server
+        .mock("POST", "/api/v2/private/get_stop_order_history")
+        .match_body(rpc_params(json!({
+            "currency": "ETH",
+            "instrument_name": "ETH-PERPETUAL",
+            "count": 5,
+            "continuation": "1555918941451.SLIS-104"
+        })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "result": {
+                    "entries": [
+                        {
+                            "timestamp": 1555918941451i64,
+                            "trigger": "mark_price",
+                            "stop_price": 150.0,
+                            "stop_id": "SLTS-17",
+                            "order_state": "cancelled",
+                            "instrument_name": "ETH-PERPETUAL",
+                            "request": "cancel",
+                            "direction": "sell",
+                            "amount": 3.0
+                        }
+                    ],
+                    "continuation": null
+                }
+            })
+            .to_string(),
+        )
+        .create_async()
+        .await;
+
+    let response = client
+        .get_stop_order_history(
+            "ETH",
+            Some("ETH-PERPETUAL"),
+            Some(5),
+            Some("1555918941451.SLIS-104"),
+        )
+        .await
+        .unwrap();
+
+    mock.assert_async().await;
+    assert!(!response.has_more());
+    assert_eq!(response.entries.len(), 1);
+    assert_eq!(response.entries[0].stop_id, "SLTS-17");
+    assert_eq!(response.entries[0].stop_price, Some(150.0));
+    assert!(response.entries[0].order_id.is_none());
+}
+
+// =========================================================================
+// Get Trigger Order History Tests (Issue #20)
+// =========================================================================
+
+#[tokio::test]
+async fn test_get_trigger_order_history_success() {
+    let mut server = mockito::Server::new_async().await;
+    let client = create_test_client(&server);
+
+    let _auth_mock = create_auth_mock(&mut server).await;
+
+    let mock_response = json!({
+        "jsonrpc": "2.0",
+        "result": {
+            "entries": [
+                {
+                    "timestamp": 1555918941451i64,
+                    "trigger": "index_price",
+                    "trigger_price": 5285.0,
+                    "trigger_order_id": "SLIS-103",
+                    "order_id": "671473",
+                    "order_state": "triggered",
+                    "instrument_name": "BTC-PERPETUAL",
+                    "request": "trigger:order",
+                    "direction": "buy",
                     "price": 5179.28,
                     "amount": 10.0
                 }
@@ -1307,12 +1849,8 @@ async fn test_get_trigger_order_history_success() {
     });
 
     let mock = server
-        .mock(
-            "GET",
-            mockito::Matcher::Regex(
-                r"/api/v2/private/get_trigger_order_history\?currency=BTC.*".to_string(),
-            ),
-        )
+        .mock("POST", "/api/v2/private/get_trigger_order_history")
+        .match_body(rpc_params(json!({ "currency": "BTC" })))
         .with_status(200)
         .with_header("content-type", "application/json")
         .with_body(mock_response.to_string())
@@ -1348,12 +1886,8 @@ async fn test_get_trigger_order_history_empty() {
     });
 
     let mock = server
-        .mock(
-            "GET",
-            mockito::Matcher::Regex(
-                r"/api/v2/private/get_trigger_order_history\?currency=ETH.*".to_string(),
-            ),
-        )
+        .mock("POST", "/api/v2/private/get_trigger_order_history")
+        .match_body(rpc_params(json!({ "currency": "ETH" })))
         .with_status(200)
         .with_header("content-type", "application/json")
         .with_body(mock_response.to_string())
@@ -1371,6 +1905,178 @@ async fn test_get_trigger_order_history_empty() {
     assert!(response.continuation.is_none());
 }
 
+#[tokio::test]
+async fn test_stream_trigger_order_history_follows_continuation() {
+    use futures_util::TryStreamExt;
+
+    let mut server = mockito::Server::new_async().await;
+    let client = create_test_client(&server);
+
+    let _auth_mock = create_auth_mock(&mut server).await;
+
+    let entry = |id: &str, timestamp: u64| {
+        json!({
+            "timestamp": timestamp,
+            "trigger_order_id": id,
+            "order_state": "triggered",
+            "instrument_name": "BTC-PERPETUAL",
+            "direction": "sell",
+            "amount": 10.0
+        })
+    };
+    let first_page = server
+        .mock("POST", "/api/v2/private/get_trigger_order_history")
+        .match_body(rpc_params(json!({ "currency": "BTC", "count": 1000 })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "result": {
+                    "entries": [entry("SLIS-3", 3000), entry("SLIS-2", 2000)],
+                    "continuation": "2000.SLIS-2"
+                }
+            })
+            .to_string(),
+        )
+        .create_async()
+        .await;
+    let second_page = server
+        .mock("POST", "/api/v2/private/get_trigger_order_history")
+        .match_body(rpc_params(
+            json!({ "currency": "BTC", "continuation": "2000.SLIS-2" }),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "result": {
+                    "entries": [entry("SLIS-1", 1000)],
+                    "continuation": null
+                }
+            })
+            .to_string(),
+        )
+        .create_async()
+        .await;
+
+    let entries: Vec<_> = client
+        .stream_trigger_order_history("BTC", None)
+        .try_collect()
+        .await
+        .unwrap();
+
+    first_page.assert_async().await;
+    second_page.assert_async().await;
+    let ids: Vec<&str> = entries
+        .iter()
+        .map(|e| e.trigger_order_id.as_str())
+        .collect();
+    assert_eq!(ids, vec!["SLIS-3", "SLIS-2", "SLIS-1"]);
+}
+
+#[tokio::test]
+async fn test_stream_order_history_by_currency_walks_offsets() {
+    use futures_util::TryStreamExt;
+
+    let mut server = mockito::Server::new_async().await;
+    let client = create_test_client(&server);
+
+    let _auth_mock = create_auth_mock(&mut server).await;
+
+    let order = |id: usize| {
+        json!({
+            "order_id": format!("BTC-{}", id),
+            "instrument_name": "BTC-PERPETUAL",
+            "direction": "buy",
+            "amount": 10.0,
+            "order_state": "filled",
+            "order_type": "limit",
+            "label": "",
+            "price": 50000.0,
+            "time_in_force": "good_til_cancelled",
+            "creation_timestamp": 1_700_000_000_000_u64,
+            "last_update_timestamp": 1_700_000_000_000_u64,
+            "api": true,
+            "is_liquidation": false,
+            "post_only": false,
+            "reduce_only": false,
+            "replaced": false,
+            "risk_reducing": false,
+            "web": false
+        })
+    };
+    let full_page: Vec<_> = (0..1000).map(order).collect();
+    let first_page = server
+        .mock("POST", "/api/v2/private/get_order_history_by_currency")
+        .match_body(rpc_params(
+            json!({ "currency": "BTC", "kind": "future", "count": 1000 }),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({ "jsonrpc": "2.0", "id": 1, "result": full_page }).to_string())
+        .expect(1)
+        .create_async()
+        .await;
+    let second_page = server
+        .mock("POST", "/api/v2/private/get_order_history_by_currency")
+        .match_body(rpc_params(
+            json!({ "currency": "BTC", "count": 1000, "offset": 1000 }),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({ "jsonrpc": "2.0", "id": 1, "result": [order(1000)] }).to_string())
+        .expect(1)
+        .create_async()
+        .await;
+
+    let orders: Vec<_> = client
+        .stream_order_history_by_currency("BTC", Some(InstrumentKind::Future))
+        .try_collect()
+        .await
+        .unwrap();
+
+    first_page.assert_async().await;
+    second_page.assert_async().await;
+    assert_eq!(orders.len(), 1001);
+    assert_eq!(orders[0].order_id, "BTC-0");
+    assert_eq!(orders[1000].order_id, "BTC-1000");
+}
+
+#[tokio::test]
+async fn test_stream_order_history_by_instrument_stops_on_empty_page() {
+    use futures_util::TryStreamExt;
+
+    let mut server = mockito::Server::new_async().await;
+    let client = create_test_client(&server);
+
+    let _auth_mock = create_auth_mock(&mut server).await;
+
+    let page = server
+        .mock("POST", "/api/v2/private/get_order_history_by_instrument")
+        .match_body(rpc_params(
+            json!({ "instrument_name": "BTC-PERPETUAL", "count": 1000 }),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"jsonrpc": "2.0", "id": 1, "result": []}"#)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let orders: Vec<_> = client
+        .stream_order_history_by_instrument("BTC-PERPETUAL")
+        .try_collect()
+        .await
+        .unwrap();
+
+    page.assert_async().await;
+    assert!(orders.is_empty());
+}
+
 // =========================================================================
 // Move Positions Tests (Issue #21)
 // =========================================================================
@@ -1386,32 +2092,40 @@ async fn test_move_positions_success() {
 
     let mock_response = json!({
         "jsonrpc": "2.0",
-        "result": [
-            {
-                "target_uid": 23,
-                "source_uid": 3,
-                "price": 35800.0,
-                "instrument_name": "BTC-PERPETUAL",
-                "direction": "buy",
-                "amount": 110.0
-            },
-            {
-                "target_uid": 23,
-                "source_uid": 3,
-                "price": 0.1223,
-                "instrument_name": "BTC-28JAN22-32500-C",
-                "direction": "sell",
-                "amount": 0.1
-            }
-        ],
+        "result": {
+            "trades": [
+                {
+                    "target_uid": 23,
+                    "source_uid": 3,
+                    "price": 35800.0,
+                    "instrument_name": "BTC-PERPETUAL",
+                    "direction": "buy",
+                    "amount": 110.0
+                },
+                {
+                    "target_uid": 23,
+                    "source_uid": 3,
+                    "price": 0.1223,
+                    "instrument_name": "BTC-28JAN22-32500-C",
+                    "direction": "sell",
+                    "amount": 0.1
+                }
+            ]
+        },
         "id": 1
     });
 
     let mock = server
-        .mock(
-            "GET",
-            mockito::Matcher::Regex(r"/api/v2/private/move_positions\?currency=BTC.*".to_string()),
-        )
+        .mock("POST", "/api/v2/private/move_positions")
+        .match_body(rpc_params(json!({
+            "currency": "BTC",
+            "source_uid": 3,
+            "target_uid": 23,
+            "trades": [
+                { "instrument_name": "BTC-PERPETUAL", "amount": 110.0, "price": 35800.0 },
+                { "instrument_name": "BTC-28JAN22-32500-C", "amount": 0.1 }
+            ]
+        })))
         .with_status(200)
         .with_header("content-type", "application/json")
         .with_body(mock_response.to_string())
@@ -1452,10 +2166,8 @@ async fn test_move_positions_empty() {
     });
 
     let mock = server
-        .mock(
-            "GET",
-            mockito::Matcher::Regex(r"/api/v2/private/move_positions\?currency=ETH.*".to_string()),
-        )
+        .mock("POST", "/api/v2/private/move_positions")
+        .match_body(rpc_params(json!({ "currency": "ETH" })))
         .with_status(200)
         .with_header("content-type", "application/json")
         .with_body(mock_response.to_string())
@@ -1527,10 +2239,7 @@ async fn test_get_account_summaries_success() {
     });
 
     let mock = server
-        .mock(
-            "GET",
-            mockito::Matcher::Regex(r"/api/v2/private/get_account_summaries.*".to_string()),
-        )
+        .mock("POST", "/api/v2/private/get_account_summaries")
         .with_status(200)
         .with_header("content-type", "application/json")
         .with_body(mock_response.to_string())
@@ -1545,6 +2254,9 @@ async fn test_get_account_summaries_success() {
     assert_eq!(response.summaries.len(), 2);
     assert_eq!(response.account.email, "user@example.com");
     assert_eq!(response.account.username, Some("user".to_string()));
+    assert_eq!(response.currencies(), vec!["BTC", "ETH"]);
+    assert_eq!(response.summary("eth").unwrap().currency, "ETH");
+    assert!(response.summary("SOL").is_none());
 }
 
 #[tokio::test]
@@ -1588,12 +2300,8 @@ async fn test_get_account_summaries_with_subaccount() {
     });
 
     let mock = server
-        .mock(
-            "GET",
-            mockito::Matcher::Regex(
-                r"/api/v2/private/get_account_summaries\?subaccount_id=20.*".to_string(),
-            ),
-        )
+        .mock("POST", "/api/v2/private/get_account_summaries")
+        .match_body(rpc_params(json!({ "subaccount_id": 20 })))
         .with_status(200)
         .with_header("content-type", "application/json")
         .with_body(mock_response.to_string())
@@ -1682,12 +2390,8 @@ async fn test_get_subaccounts_details_success() {
     });
 
     let mock = server
-        .mock(
-            "GET",
-            mockito::Matcher::Regex(
-                r"/api/v2/private/get_subaccounts_details\?currency=BTC.*".to_string(),
-            ),
-        )
+        .mock("POST", "/api/v2/private/get_subaccounts_details")
+        .match_body(rpc_params(json!({ "currency": "BTC" })))
         .with_status(200)
         .with_header("content-type", "application/json")
         .with_body(mock_response.to_string())
@@ -1720,12 +2424,8 @@ async fn test_get_subaccounts_details_empty() {
     });
 
     let mock = server
-        .mock(
-            "GET",
-            mockito::Matcher::Regex(
-                r"/api/v2/private/get_subaccounts_details\?currency=ETH.*".to_string(),
-            ),
-        )
+        .mock("POST", "/api/v2/private/get_subaccounts_details")
+        .match_body(rpc_params(json!({ "currency": "ETH" })))
         .with_status(200)
         .with_header("content-type", "application/json")
         .with_body(mock_response.to_string())
@@ -1768,7 +2468,7 @@ async fn test_create_subaccount_success() {
     });
 
     let mock = server
-        .mock("GET", "/api/v2/private/create_subaccount")
+        .mock("POST", "/api/v2/private/create_subaccount")
         .with_status(200)
         .with_header("content-type", "application/json")
         .with_body(mock_response.to_string())
@@ -1793,7 +2493,7 @@ async fn test_create_subaccount_error() {
     let _auth_mock = create_auth_mock(&mut server).await;
 
     let mock = server
-        .mock("GET", "/api/v2/private/create_subaccount")
+        .mock("POST", "/api/v2/private/create_subaccount")
         .with_status(403)
         .with_header("content-type", "application/json")
         .with_body(r#"{"jsonrpc": "2.0", "error": {"code": 13009, "message": "not_main_account"}, "id": 1}"#)
@@ -1820,7 +2520,8 @@ async fn test_remove_subaccount_success() {
     });
 
     let mock = server
-        .mock("GET", "/api/v2/private/remove_subaccount?subaccount_id=123")
+        .mock("POST", "/api/v2/private/remove_subaccount")
+        .match_body(rpc_params(json!({ "subaccount_id": 123 })))
         .with_status(200)
         .with_header("content-type", "application/json")
         .with_body(mock_response.to_string())
@@ -1842,7 +2543,8 @@ async fn test_remove_subaccount_error() {
     let _auth_mock = create_auth_mock(&mut server).await;
 
     let mock = server
-        .mock("GET", "/api/v2/private/remove_subaccount?subaccount_id=999")
+        .mock("POST", "/api/v2/private/remove_subaccount")
+        .match_body(rpc_params(json!({ "subaccount_id": 999 })))
         .with_status(400)
         .with_header("content-type", "application/json")
         .with_body(r#"{"jsonrpc": "2.0", "error": {"code": 13004, "message": "subaccount_not_found"}, "id": 1}"#)
@@ -1869,10 +2571,8 @@ async fn test_change_subaccount_name_success() {
     });
 
     let mock = server
-        .mock(
-            "GET",
-            "/api/v2/private/change_subaccount_name?sid=7&name=new_user_name",
-        )
+        .mock("POST", "/api/v2/private/change_subaccount_name")
+        .match_body(rpc_params(json!({ "sid": 7, "name": "new_user_name" })))
         .with_status(200)
         .with_header("content-type", "application/json")
         .with_body(mock_response.to_string())
@@ -1894,7 +2594,8 @@ async fn test_change_subaccount_name_error() {
     let _auth_mock = create_auth_mock(&mut server).await;
 
     let mock = server
-        .mock("GET", "/api/v2/private/change_subaccount_name?sid=999&name=invalid")
+        .mock("POST", "/api/v2/private/change_subaccount_name")
+        .match_body(rpc_params(json!({ "sid": 999, "name": "invalid" })))
         .with_status(400)
         .with_header("content-type", "application/json")
         .with_body(r#"{"jsonrpc": "2.0", "error": {"code": 13004, "message": "subaccount_not_found"}, "id": 1}"#)
@@ -1921,10 +2622,8 @@ async fn test_toggle_subaccount_login_success() {
     });
 
     let mock = server
-        .mock(
-            "GET",
-            "/api/v2/private/toggle_subaccount_login?sid=7&state=enable",
-        )
+        .mock("POST", "/api/v2/private/toggle_subaccount_login")
+        .match_body(rpc_params(json!({ "sid": 7, "state": "enable" })))
         .with_status(200)
         .with_header("content-type", "application/json")
         .with_body(mock_response.to_string())
@@ -1946,7 +2645,8 @@ async fn test_toggle_subaccount_login_error() {
     let _auth_mock = create_auth_mock(&mut server).await;
 
     let mock = server
-        .mock("GET", "/api/v2/private/toggle_subaccount_login?sid=999&state=enable")
+        .mock("POST", "/api/v2/private/toggle_subaccount_login")
+        .match_body(rpc_params(json!({ "sid": 999, "state": "enable" })))
         .with_status(400)
         .with_header("content-type", "application/json")
         .with_body(r#"{"jsonrpc": "2.0", "error": {"code": 13004, "message": "subaccount_not_found"}, "id": 1}"#)
@@ -1973,12 +2673,8 @@ async fn test_set_email_for_subaccount_success() {
     });
 
     let mock = server
-        .mock(
-            "GET",
-            mockito::Matcher::Regex(
-                r"/api/v2/private/set_email_for_subaccount\?sid=7&email=.*".to_string(),
-            ),
-        )
+        .mock("POST", "/api/v2/private/set_email_for_subaccount")
+        .match_body(rpc_params(json!({ "sid": 7 })))
         .with_status(200)
         .with_header("content-type", "application/json")
         .with_body(mock_response.to_string())
@@ -2000,12 +2696,8 @@ async fn test_set_email_for_subaccount_error() {
     let _auth_mock = create_auth_mock(&mut server).await;
 
     let mock = server
-        .mock(
-            "GET",
-            mockito::Matcher::Regex(
-                r"/api/v2/private/set_email_for_subaccount\?sid=999&email=.*".to_string(),
-            ),
-        )
+        .mock("POST", "/api/v2/private/set_email_for_subaccount")
+        .match_body(rpc_params(json!({ "sid": 999 })))
         .with_status(400)
         .with_header("content-type", "application/json")
         .with_body(r#"{"jsonrpc": "2.0", "error": {"code": 13004, "message": "subaccount_not_found"}, "id": 1}"#)
@@ -2035,9 +2727,10 @@ async fn test_toggle_notifications_from_subaccount_success() {
 
     let mock = server
         .mock(
-            "GET",
-            "/api/v2/private/toggle_notifications_from_subaccount?sid=7&state=true",
+            "POST",
+            "/api/v2/private/toggle_notifications_from_subaccount",
         )
+        .match_body(rpc_params(json!({ "sid": 7, "state": true })))
         .with_status(200)
         .with_header("content-type", "application/json")
         .with_body(mock_response.to_string())
@@ -2059,7 +2752,8 @@ async fn test_toggle_notifications_from_subaccount_error() {
     let _auth_mock = create_auth_mock(&mut server).await;
 
     let mock = server
-        .mock("GET", "/api/v2/private/toggle_notifications_from_subaccount?sid=999&state=false")
+        .mock("POST", "/api/v2/private/toggle_notifications_from_subaccount")
+        .match_body(rpc_params(json!({ "sid": 999, "state": false })))
         .with_status(400)
         .with_header("content-type", "application/json")
         .with_body(r#"{"jsonrpc": "2.0", "error": {"code": 13004, "message": "subaccount_not_found"}, "id": 1}"#)
@@ -2105,7 +2799,8 @@ async fn test_get_transfers_success() {
     });
 
     let mock = server
-        .mock("GET", "/api/v2/private/get_transfers?currency=BTC")
+        .mock("POST", "/api/v2/private/get_transfers")
+        .match_body(rpc_params(json!({ "currency": "BTC" })))
         .with_status(200)
         .with_header("content-type", "application/json")
         .with_body(mock_response.to_string())
@@ -2143,10 +2838,10 @@ async fn test_get_transfers_with_pagination() {
     });
 
     let mock = server
-        .mock(
-            "GET",
-            "/api/v2/private/get_transfers?currency=ETH&count=5&offset=10",
-        )
+        .mock("POST", "/api/v2/private/get_transfers")
+        .match_body(rpc_params(
+            json!({ "currency": "ETH", "count": 5, "offset": 10 }),
+        ))
         .with_status(200)
         .with_header("content-type", "application/json")
         .with_body(mock_response.to_string())
@@ -2170,7 +2865,8 @@ async fn test_get_transfers_error() {
     let _auth_mock = create_auth_mock(&mut server).await;
 
     let mock = server
-        .mock("GET", "/api/v2/private/get_transfers?currency=INVALID")
+        .mock("POST", "/api/v2/private/get_transfers")
+        .match_body(rpc_params(json!({ "currency": "INVALID" })))
         .with_status(400)
         .with_header("content-type", "application/json")
         .with_body(
@@ -2185,6 +2881,71 @@ async fn test_get_transfers_error() {
     assert!(result.is_err());
 }
 
+#[tokio::test]
+async fn test_stream_transfers_walks_offsets() {
+    use futures_util::TryStreamExt;
+
+    let mut server = mockito::Server::new_async().await;
+    let client = create_test_client(&server);
+
+    let _auth_mock = create_auth_mock(&mut server).await;
+
+    let transfer = |id: i64| {
+        json!({
+            "id": id,
+            "created_timestamp": 1550579457727_i64,
+            "updated_timestamp": 1550579457727_i64,
+            "currency": "BTC",
+            "amount": 0.2,
+            "direction": "payment",
+            "other_side": "new_user_1_1",
+            "state": "confirmed",
+            "type": "subaccount"
+        })
+    };
+    let first_page = server
+        .mock("POST", "/api/v2/private/get_transfers")
+        .match_body(rpc_params(json!({ "currency": "BTC", "count": 1000 })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "result": { "count": 3, "data": [transfer(3), transfer(2)] }
+            })
+            .to_string(),
+        )
+        .expect(1)
+        .create_async()
+        .await;
+    let second_page = server
+        .mock("POST", "/api/v2/private/get_transfers")
+        .match_body(rpc_params(
+            json!({ "currency": "BTC", "count": 1000, "offset": 2 }),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "result": { "count": 3, "data": [transfer(1)] }
+            })
+            .to_string(),
+        )
+        .expect(1)
+        .create_async()
+        .await;
+
+    let transfers: Vec<_> = client.stream_transfers("BTC").try_collect().await.unwrap();
+
+    first_page.assert_async().await;
+    second_page.assert_async().await;
+    let ids: Vec<i64> = transfers.iter().map(|t| t.id).collect();
+    assert_eq!(ids, vec![3, 2, 1]);
+}
+
 #[tokio::test]
 async fn test_cancel_transfer_by_id_success() {
     let mut server = mockito::Server::new_async().await;
@@ -2209,10 +2970,8 @@ async fn test_cancel_transfer_by_id_success() {
     });
 
     let mock = server
-        .mock(
-            "GET",
-            "/api/v2/private/cancel_transfer_by_id?currency=BTC&id=123",
-        )
+        .mock("POST", "/api/v2/private/cancel_transfer_by_id")
+        .match_body(rpc_params(json!({ "currency": "BTC", "id": 123 })))
         .with_status(200)
         .with_header("content-type", "application/json")
         .with_body(mock_response.to_string())
@@ -2236,7 +2995,8 @@ async fn test_cancel_transfer_by_id_error() {
     let _auth_mock = create_auth_mock(&mut server).await;
 
     let mock = server
-        .mock("GET", "/api/v2/private/cancel_transfer_by_id?currency=BTC&id=999")
+        .mock("POST", "/api/v2/private/cancel_transfer_by_id")
+        .match_body(rpc_params(json!({ "currency": "BTC", "id": 999 })))
         .with_status(400)
         .with_header("content-type", "application/json")
         .with_body(
@@ -2276,9 +3036,12 @@ async fn test_submit_transfer_between_subaccounts_success() {
 
     let mock = server
         .mock(
-            "GET",
-            "/api/v2/private/submit_transfer_between_subaccounts?currency=ETH&amount=12.1234&destination=20",
+            "POST",
+            "/api/v2/private/submit_transfer_between_subaccounts",
         )
+        .match_body(rpc_params(
+            json!({ "currency": "ETH", "amount": 12.1234, "destination": 20 }),
+        ))
         .with_status(200)
         .with_header("content-type", "application/json")
         .with_body(mock_response.to_string())
@@ -2324,9 +3087,12 @@ async fn test_submit_transfer_between_subaccounts_with_source() {
 
     let mock = server
         .mock(
-            "GET",
-            "/api/v2/private/submit_transfer_between_subaccounts?currency=BTC&amount=1&destination=20&source=10",
+            "POST",
+            "/api/v2/private/submit_transfer_between_subaccounts",
         )
+        .match_body(rpc_params(
+            json!({ "currency": "BTC", "amount": 1.0, "destination": 20, "source": 10 }),
+        ))
         .with_status(200)
         .with_header("content-type", "application/json")
         .with_body(mock_response.to_string())
@@ -2343,6 +3109,38 @@ async fn test_submit_transfer_between_subaccounts_with_source() {
     assert_eq!(transfer.id, 789);
 }
 
+#[tokio::test]
+async fn test_submit_transfer_between_subaccounts_rejects_invalid_input() {
+    let mut server = mockito::Server::new_async().await;
+    let client = create_test_client(&server);
+
+    let mock = server
+        .mock(
+            "POST",
+            "/api/v2/private/submit_transfer_between_subaccounts",
+        )
+        .expect(0)
+        .create_async()
+        .await;
+
+    let same_account = client
+        .submit_transfer_between_subaccounts("BTC", 1.0, 20, Some(20))
+        .await;
+    let zero_amount = client
+        .submit_transfer_between_subaccounts("BTC", 0.0, 20, Some(10))
+        .await;
+
+    mock.assert_async().await;
+    assert!(matches!(
+        same_account.map_err(HttpError::into_kind),
+        Err(HttpErrorKind::ConfigError(_))
+    ));
+    assert!(matches!(
+        zero_amount.map_err(HttpError::into_kind),
+        Err(HttpErrorKind::ConfigError(_))
+    ));
+}
+
 #[tokio::test]
 async fn test_submit_transfer_between_subaccounts_error() {
     let mut server = mockito::Server::new_async().await;
@@ -2351,10 +3149,8 @@ async fn test_submit_transfer_between_subaccounts_error() {
     let _auth_mock = create_auth_mock(&mut server).await;
 
     let mock = server
-        .mock(
-            "GET",
-            "/api/v2/private/submit_transfer_between_subaccounts?currency=BTC&amount=1000000&destination=999",
-        )
+        .mock("POST", "/api/v2/private/submit_transfer_between_subaccounts")
+        .match_body(rpc_params(json!({ "currency": "BTC", "amount": 1000000.0, "destination": 999 })))
         .with_status(400)
         .with_header("content-type", "application/json")
         .with_body(
@@ -2410,10 +3206,10 @@ async fn test_get_block_rfqs_success() {
     });
 
     let mock = server
-        .mock(
-            "GET",
-            "/api/v2/private/get_block_rfqs?count=20&state=open&role=maker",
-        )
+        .mock("POST", "/api/v2/private/get_block_rfqs")
+        .match_body(rpc_params(
+            json!({ "count": 20, "state": "open", "role": "maker" }),
+        ))
         .with_status(200)
         .with_header("content-type", "application/json")
         .with_body(mock_response.to_string())
@@ -2427,7 +3223,7 @@ async fn test_get_block_rfqs_success() {
             Some(deribit_http::model::response::BlockRfqRole::Maker),
             None,
             None,
-            None,
+            None::<&str>,
         )
         .await;
 
@@ -2470,7 +3266,8 @@ async fn test_cancel_block_rfq_success() {
     });
 
     let mock = server
-        .mock("GET", "/api/v2/private/cancel_block_rfq?block_rfq_id=366")
+        .mock("POST", "/api/v2/private/cancel_block_rfq")
+        .match_body(rpc_params(json!({ "block_rfq_id": 366 })))
         .with_status(200)
         .with_header("content-type", "application/json")
         .with_body(mock_response.to_string())
@@ -2522,7 +3319,8 @@ async fn test_get_block_rfq_quotes_success() {
     });
 
     let mock = server
-        .mock("GET", "/api/v2/private/get_block_rfq_quotes?block_rfq_id=1")
+        .mock("POST", "/api/v2/private/get_block_rfq_quotes")
+        .match_body(rpc_params(json!({ "block_rfq_id": 1 })))
         .with_status(200)
         .with_header("content-type", "application/json")
         .with_body(mock_response.to_string())
@@ -2553,7 +3351,7 @@ async fn test_cancel_all_block_rfq_quotes_success() {
     });
 
     let mock = server
-        .mock("GET", "/api/v2/private/cancel_all_block_rfq_quotes")
+        .mock("POST", "/api/v2/private/cancel_all_block_rfq_quotes")
         .with_status(200)
         .with_header("content-type", "application/json")
         .with_body(mock_response.to_string())
@@ -2567,3 +3365,1638 @@ async fn test_cancel_all_block_rfq_quotes_success() {
     let quotes = result.unwrap();
     assert!(quotes.is_empty());
 }
+
+// =========================================================================
+// Subaccount Scoping Tests
+// =========================================================================
+
+#[tokio::test]
+async fn test_for_subaccount_get_positions() {
+    let mut server = mockito::Server::new_async().await;
+    let client = create_test_client(&server);
+
+    let _auth_mock = create_auth_mock(&mut server).await;
+
+    let mock = server
+        .mock("POST", "/api/v2/private/get_positions")
+        .match_body(rpc_params(
+            json!({ "currency": "BTC", "subaccount_id": 20 }),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"jsonrpc":"2.0","id":1,"result":[]}"#)
+        .create_async()
+        .await;
+
+    let sub = client.for_subaccount(20);
+    assert_eq!(sub.subaccount_id(), 20);
+    let result = sub.get_positions(Some("BTC"), None).await;
+
+    mock.assert_async().await;
+    assert!(result.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_for_subaccount_get_transaction_log_overrides_id() {
+    let mut server = mockito::Server::new_async().await;
+    let client = create_test_client(&server);
+
+    let _auth_mock = create_auth_mock(&mut server).await;
+
+    let mock = server
+        .mock("POST", "/api/v2/private/get_transaction_log")
+        .match_body(rpc_params(json!({ "subaccount_id": 20 })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"jsonrpc":"2.0","id":1,"result":{"continuation":null,"logs":[]}}"#)
+        .create_async()
+        .await;
+
+    let request = TransactionLogRequest {
+        currency: "BTC".to_string(),
+        start_timestamp: TimestampMs(1_700_000_000_000),
+        end_timestamp: TimestampMs(1_700_000_100_000),
+        subaccount_id: Some(99),
+        ..Default::default()
+    };
+    let result = client.for_subaccount(20).get_transaction_log(request).await;
+
+    mock.assert_async().await;
+    assert!(result.unwrap().logs.is_empty());
+}
+
+#[tokio::test]
+async fn test_for_subaccount_get_user_trades_by_currency() {
+    let mut server = mockito::Server::new_async().await;
+    let client = create_test_client(&server);
+
+    let _auth_mock = create_auth_mock(&mut server).await;
+
+    let mock = server
+        .mock("POST", "/api/v2/private/get_user_trades_by_currency")
+        .match_body(rpc_params(
+            json!({ "currency": "ETH", "subaccount_id": 20 }),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"jsonrpc":"2.0","id":1,"result":{"trades":[],"has_more":false}}"#)
+        .create_async()
+        .await;
+
+    let request = deribit_http::model::request::trade::TradesRequest {
+        currency: deribit_http::model::currency::Currency::Eth,
+        kind: None,
+        start_id: None,
+        end_id: None,
+        count: None,
+        start_timestamp: None,
+        end_timestamp: None,
+        sorting: None,
+        historical: None,
+        subaccount_id: None,
+    };
+    let result = client
+        .for_subaccount(20)
+        .get_user_trades_by_currency(request)
+        .await;
+
+    mock.assert_async().await;
+    assert!(!result.unwrap().has_more);
+}
+
+#[tokio::test]
+async fn test_for_subaccount_id_out_of_range() {
+    let server = mockito::Server::new_async().await;
+    let client = create_test_client(&server);
+
+    let result = client
+        .for_subaccount(u64::MAX)
+        .get_positions(Some("BTC"), None)
+        .await;
+
+    assert!(matches!(
+        result.map_err(HttpError::into_kind),
+        Err(deribit_http::HttpErrorKind::ConfigError(_))
+    ));
+}
+
+// =========================================================================
+// User Trades Streaming Tests
+// =========================================================================
+
+fn user_trade_json(trade_seq: u64, timestamp: u64) -> serde_json::Value {
+    json!({
+        "trade_id": format!("BTC-{}", trade_seq),
+        "instrument_name": "BTC-PERPETUAL",
+        "timestamp": timestamp,
+        "direction": "buy",
+        "price": 50000.0,
+        "amount": 10.0,
+        "fee": 0.0001,
+        "fee_currency": "BTC",
+        "order_id": "BTC-1",
+        "order_type": "limit",
+        "trade_seq": trade_seq,
+        "state": "filled",
+        "index_price": 50000.0,
+        "liquidity": "M",
+        "mark_price": 50000.0,
+        "tick_direction": 0,
+        "self_trade": false
+    })
+}
+
+fn user_trades_page(trades: Vec<serde_json::Value>, has_more: bool) -> String {
+    json!({
+        "jsonrpc": "2.0",
+        "id": 1,
+        "result": {"trades": trades, "has_more": has_more}
+    })
+    .to_string()
+}
+
+#[tokio::test]
+async fn test_stream_user_trades_by_instrument_advances_seq() {
+    use futures_util::TryStreamExt;
+
+    let mut server = mockito::Server::new_async().await;
+    let client = create_test_client(&server);
+
+    let _auth_mock = create_auth_mock(&mut server).await;
+
+    let first_page = server
+        .mock("POST", "/api/v2/private/get_user_trades_by_instrument")
+        .match_body(rpc_params(
+            json!({ "instrument_name": "BTC-PERPETUAL", "count": 2, "sorting": "asc" }),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(user_trades_page(
+            vec![user_trade_json(1, 1000), user_trade_json(2, 2000)],
+            true,
+        ))
+        .create_async()
+        .await;
+
+    let second_page = server
+        .mock("POST", "/api/v2/private/get_user_trades_by_instrument")
+        .match_body(rpc_params(json!({ "instrument_name": "BTC-PERPETUAL", "start_seq": 3, "count": 2, "sorting": "asc" })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(user_trades_page(vec![user_trade_json(3, 3000)], false))
+        .create_async()
+        .await;
+
+    let trades: Vec<_> = client
+        .stream_user_trades_by_instrument(
+            "BTC-PERPETUAL",
+            None,
+            None,
+            Some(2),
+            None,
+            Some(SortDirection::Asc),
+        )
+        .try_collect()
+        .await
+        .unwrap();
+
+    first_page.assert_async().await;
+    second_page.assert_async().await;
+    let seqs: Vec<u64> = trades.iter().map(|trade| trade.trade_seq).collect();
+    assert_eq!(seqs, vec![1, 2, 3]);
+}
+
+#[tokio::test]
+async fn test_stream_user_trades_by_currency_and_time_dedups_boundary() {
+    use deribit_http::model::currency::Currency;
+    use deribit_http::model::request::trade::TradesRequest;
+    use futures_util::TryStreamExt;
+
+    let mut server = mockito::Server::new_async().await;
+    let client = create_test_client(&server);
+
+    let _auth_mock = create_auth_mock(&mut server).await;
+
+    let first_page = server
+        .mock(
+            "POST",
+            "/api/v2/private/get_user_trades_by_currency_and_time",
+        )
+        .match_body(rpc_params(
+            json!({ "currency": "BTC", "start_timestamp": 0, "end_timestamp": 5000 }),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(user_trades_page(
+            vec![user_trade_json(4, 4000), user_trade_json(3, 3000)],
+            true,
+        ))
+        .create_async()
+        .await;
+
+    // The window end is inclusive, so trade 3 comes back again
+    let second_page = server
+        .mock(
+            "POST",
+            "/api/v2/private/get_user_trades_by_currency_and_time",
+        )
+        .match_body(rpc_params(
+            json!({ "currency": "BTC", "start_timestamp": 0, "end_timestamp": 3000 }),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(user_trades_page(
+            vec![user_trade_json(3, 3000), user_trade_json(2, 2000)],
+            false,
+        ))
+        .create_async()
+        .await;
+
+    let request = TradesRequest {
+        currency: Currency::Btc,
+        kind: None,
+        start_id: None,
+        end_id: None,
+        count: None,
+        start_timestamp: Some(TimestampMs(0)),
+        end_timestamp: Some(TimestampMs(5000)),
+        sorting: None,
+        historical: None,
+        subaccount_id: None,
+    };
+    let trades: Vec<_> = client
+        .stream_user_trades_by_currency_and_time(request)
+        .try_collect()
+        .await
+        .unwrap();
+
+    first_page.assert_async().await;
+    second_page.assert_async().await;
+    let seqs: Vec<u64> = trades.iter().map(|trade| trade.trade_seq).collect();
+    assert_eq!(seqs, vec![4, 3, 2]);
+}
+
+#[tokio::test]
+async fn test_stream_user_trades_by_currency_moves_end_id_to_last_trade() {
+    use deribit_http::model::currency::Currency;
+    use deribit_http::model::request::trade::TradesRequest;
+    use futures_util::TryStreamExt;
+
+    let mut server = mockito::Server::new_async().await;
+    let client = create_test_client(&server);
+
+    let _auth_mock = create_auth_mock(&mut server).await;
+
+    let first_page = server
+        .mock("POST", "/api/v2/private/get_user_trades_by_currency")
+        .match_body(rpc_params(
+            json!({ "currency": "BTC", "count": 2, "sorting": "desc" }),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(user_trades_page(
+            vec![user_trade_json(9, 4000), user_trade_json(8, 3000)],
+            true,
+        ))
+        .create_async()
+        .await;
+
+    // `end_id` is inclusive, so trade 8 comes back and is skipped
+    let second_page = server
+        .mock("POST", "/api/v2/private/get_user_trades_by_currency")
+        .match_body(rpc_params(json!({
+            "currency": "BTC",
+            "end_id": "BTC-8",
+            "count": 2,
+            "sorting": "desc"
+        })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(user_trades_page(
+            vec![user_trade_json(8, 3000), user_trade_json(7, 2000)],
+            false,
+        ))
+        .create_async()
+        .await;
+
+    let request = TradesRequest {
+        currency: Currency::Btc,
+        kind: None,
+        start_id: None,
+        end_id: None,
+        count: Some(2),
+        start_timestamp: None,
+        end_timestamp: None,
+        sorting: Some(SortDirection::Desc),
+        historical: None,
+        subaccount_id: None,
+    };
+    let trades: Vec<_> = client
+        .stream_user_trades_by_currency(request)
+        .try_collect()
+        .await
+        .unwrap();
+
+    first_page.assert_async().await;
+    second_page.assert_async().await;
+    let seqs: Vec<u64> = trades.iter().map(|trade| trade.trade_seq).collect();
+    assert_eq!(seqs, vec![9, 8, 7]);
+}
+
+#[tokio::test]
+async fn test_stream_user_trades_by_currency_fails_when_count_is_one() {
+    use deribit_http::model::currency::Currency;
+    use deribit_http::model::request::trade::TradesRequest;
+    use futures_util::TryStreamExt;
+
+    let mut server = mockito::Server::new_async().await;
+    let client = create_test_client(&server);
+
+    let _auth_mock = create_auth_mock(&mut server).await;
+
+    let first_page = server
+        .mock("POST", "/api/v2/private/get_user_trades_by_currency")
+        .match_body(rpc_params(
+            json!({ "currency": "BTC", "count": 1, "sorting": "desc" }),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(user_trades_page(vec![user_trade_json(9, 4000)], true))
+        .create_async()
+        .await;
+
+    // `end_id` is inclusive, so a single-trade page only repeats trade 9
+    let second_page = server
+        .mock("POST", "/api/v2/private/get_user_trades_by_currency")
+        .match_body(rpc_params(json!({
+            "currency": "BTC",
+            "end_id": "BTC-9",
+            "count": 1,
+            "sorting": "desc"
+        })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(user_trades_page(vec![user_trade_json(9, 4000)], true))
+        .expect(1)
+        .create_async()
+        .await;
+
+    let request = TradesRequest {
+        currency: Currency::Btc,
+        kind: None,
+        start_id: None,
+        end_id: None,
+        count: Some(1),
+        start_timestamp: None,
+        end_timestamp: None,
+        sorting: Some(SortDirection::Desc),
+        historical: None,
+        subaccount_id: None,
+    };
+    let result: Result<Vec<_>, _> = client
+        .stream_user_trades_by_currency(request)
+        .try_collect()
+        .await;
+
+    first_page.assert_async().await;
+    second_page.assert_async().await;
+    assert!(matches!(
+        result.map_err(HttpError::into_kind),
+        Err(HttpErrorKind::ConfigError(_))
+    ));
+}
+
+#[tokio::test]
+async fn test_cancel_on_disconnect_endpoints() {
+    use deribit_http::model::CancelOnDisconnectScope;
+
+    let mut server = mockito::Server::new_async().await;
+    let client = create_test_client(&server);
+    let _auth_mock = create_auth_mock(&mut server).await;
+
+    let get_mock = server
+        .mock("POST", "/api/v2/private/get_cancel_on_disconnect")
+        .match_body(rpc_params(json!({ "scope": "account" })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            r#"{"jsonrpc": "2.0", "id": 1, "result": {"scope": "account", "enabled": false}}"#,
+        )
+        .create_async()
+        .await;
+    let enable_mock = server
+        .mock("POST", "/api/v2/private/enable_cancel_on_disconnect")
+        .match_body(rpc_params(json!({ "scope": "account" })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"jsonrpc": "2.0", "id": 1, "result": "ok"}"#)
+        .create_async()
+        .await;
+    let disable_mock = server
+        .mock("POST", "/api/v2/private/disable_cancel_on_disconnect")
+        .match_body(rpc_params(json!({})))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"jsonrpc": "2.0", "id": 1, "result": "ok"}"#)
+        .create_async()
+        .await;
+
+    let cod = client
+        .get_cancel_on_disconnect(Some(CancelOnDisconnectScope::Account))
+        .await
+        .unwrap();
+    assert_eq!(cod.scope, CancelOnDisconnectScope::Account);
+    assert!(!cod.enabled);
+    assert!(
+        client
+            .enable_cancel_on_disconnect(Some(CancelOnDisconnectScope::Account))
+            .await
+            .unwrap()
+    );
+    assert!(client.disable_cancel_on_disconnect(None).await.unwrap());
+
+    get_mock.assert_async().await;
+    enable_mock.assert_async().await;
+    disable_mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_get_portfolio_margins_with_simulated_positions() {
+    let mut server = mockito::Server::new_async().await;
+    let client = create_test_client(&server);
+    let _auth_mock = create_auth_mock(&mut server).await;
+
+    let mock = server
+        .mock("POST", "/api/v2/private/get_portfolio_margins")
+        .match_body(rpc_params(json!({
+            "currency": "BTC",
+            "add_positions": true,
+            "simulated_positions": { "BTC-PERPETUAL": 1000.0 }
+        })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            r#"{"jsonrpc": "2.0", "id": 1, "result": {
+                "currency": "BTC",
+                "initial_margin": 0.2,
+                "maintenance_margin": 0.15,
+                "projected_initial_margin": 0.31,
+                "projected_maintenance_margin": 0.22
+            }}"#,
+        )
+        .create_async()
+        .await;
+
+    let positions = std::collections::HashMap::from([("BTC-PERPETUAL".to_string(), 1000.0)]);
+    let margins = client
+        .get_portfolio_margins("BTC", Some(positions))
+        .await
+        .unwrap();
+
+    mock.assert_async().await;
+    assert_eq!(margins.initial_margin, Some(0.2));
+    assert_eq!(margins.projected_initial_margin, Some(0.31));
+}
+
+// =========================================================================
+// Block Trade Simulation Tests
+// =========================================================================
+
+#[tokio::test]
+async fn test_simulate_block_trade_sends_role_and_legs() {
+    use deribit_http::model::block_trade::{
+        BlockTradeItem, BlockTradeRole, SimulateBlockTradeRequest, TradeDirection,
+    };
+
+    let mut server = mockito::Server::new_async().await;
+    let client = create_test_client(&server);
+
+    let _auth_mock = create_auth_mock(&mut server).await;
+
+    let mock = server
+        .mock("POST", "/api/v2/private/simulate_block_trade")
+        .match_body(rpc_params(json!({
+            "role": "maker",
+            "trades": [{
+                "instrument_name": "BTC-PERPETUAL",
+                "price": 50000.0,
+                "amount": 40.0,
+                "direction": "buy"
+            }]
+        })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({ "jsonrpc": "2.0", "id": 1, "result": true }).to_string())
+        .create_async()
+        .await;
+
+    let request = SimulateBlockTradeRequest::new(
+        Some(BlockTradeRole::Maker),
+        vec![BlockTradeItem::new(
+            "BTC-PERPETUAL",
+            50000.0,
+            Some(40.0),
+            TradeDirection::Buy,
+        )],
+    );
+    let executable = client.simulate_block_trade(&request).await.unwrap();
+
+    mock.assert_async().await;
+    assert!(executable);
+}
+
+#[tokio::test]
+async fn test_simulate_block_trade_missing_result_is_an_error() {
+    use deribit_http::model::block_trade::{
+        BlockTradeItem, SimulateBlockTradeRequest, TradeDirection,
+    };
+
+    let mut server = mockito::Server::new_async().await;
+    let client = create_test_client(&server);
+
+    let _auth_mock = create_auth_mock(&mut server).await;
+
+    let _mock = server
+        .mock("POST", "/api/v2/private/simulate_block_trade")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({ "jsonrpc": "2.0", "id": 1 }).to_string())
+        .create_async()
+        .await;
+
+    let request = SimulateBlockTradeRequest::new(
+        None,
+        vec![BlockTradeItem::new(
+            "BTC-PERPETUAL",
+            50000.0,
+            Some(40.0),
+            TradeDirection::Sell,
+        )],
+    );
+
+    assert!(client.simulate_block_trade(&request).await.is_err());
+}
+
+// =========================================================================
+// RFQ Tests
+// =========================================================================
+
+#[tokio::test]
+async fn test_send_rfq_sends_amount_and_side() {
+    let mut server = mockito::Server::new_async().await;
+    let client = create_test_client(&server);
+
+    let _auth_mock = create_auth_mock(&mut server).await;
+
+    let mock = server
+        .mock("POST", "/api/v2/private/send_rfq")
+        .match_body(rpc_params(json!({
+            "instrument_name": "BTC-27DEC24-60000-C",
+            "amount": 25.0,
+            "side": "buy"
+        })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({ "jsonrpc": "2.0", "id": 1, "result": "ok" }).to_string())
+        .create_async()
+        .await;
+
+    let sent = client
+        .send_rfq(
+            "BTC-27DEC24-60000-C",
+            Some(25.0),
+            Some(deribit_http::model::RfqSide::Buy),
+        )
+        .await
+        .unwrap();
+
+    mock.assert_async().await;
+    assert!(sent);
+}
+
+#[tokio::test]
+async fn test_cancel_rfq_success() {
+    let mut server = mockito::Server::new_async().await;
+    let client = create_test_client(&server);
+
+    let _auth_mock = create_auth_mock(&mut server).await;
+
+    let mock = server
+        .mock("POST", "/api/v2/private/cancel_rfq")
+        .match_body(rpc_params(
+            json!({ "instrument_name": "BTC-27DEC24-60000-C" }),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({ "jsonrpc": "2.0", "id": 1, "result": "ok" }).to_string())
+        .create_async()
+        .await;
+
+    let cancelled = client.cancel_rfq("BTC-27DEC24-60000-C").await.unwrap();
+
+    mock.assert_async().await;
+    assert!(cancelled);
+}
+
+// =========================================================================
+// Bulk Order Tests
+// =========================================================================
+
+fn limit_order(instrument_name: &str, price: f64) -> OrderRequest {
+    OrderRequest {
+        order_id: None,
+        instrument_name: instrument_name.to_string(),
+        amount: Some(10.0),
+        contracts: None,
+        type_: Some(OrderType::Limit),
+        label: Some("ladder".to_string()),
+        price: Some(price),
+        time_in_force: None,
+        display_amount: None,
+        post_only: Some(true),
+        reject_post_only: None,
+        reduce_only: None,
+        trigger_price: None,
+        trigger_offset: None,
+        trigger: None,
+        advanced: None,
+        mmp: None,
+        valid_until: None,
+        linked_order_type: None,
+        trigger_fill_condition: None,
+        otoco_config: None,
+    }
+}
+
+#[tokio::test]
+async fn test_submit_orders_reports_each_outcome() {
+    let mut server = mockito::Server::new_async().await;
+    let client = create_test_client(&server);
+
+    let _auth_mock = create_auth_mock(&mut server).await;
+
+    let buy_mock = server
+        .mock("POST", "/api/v2/private/buy")
+        .match_body(rpc_params(
+            json!({ "instrument_name": "BTC-PERPETUAL", "price": 49000.0 }),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "result": {
+                    "order": {
+                        "order_id": "bid-1",
+                        "instrument_name": "BTC-PERPETUAL",
+                        "direction": "buy",
+                        "amount": 10.0,
+                        "filled_amount": 0.0,
+                        "order_state": "open",
+                        "order_type": "limit",
+                        "label": "ladder",
+                        "price": 49000.0,
+                        "time_in_force": "good_til_cancelled",
+                        "creation_timestamp": 1_700_000_000_000_u64,
+                        "last_update_timestamp": 1_700_000_000_000_u64,
+                        "api": true,
+                        "is_liquidation": false,
+                        "post_only": true,
+                        "reduce_only": false,
+                        "replaced": false,
+                        "risk_reducing": false,
+                        "web": false,
+                        "average_price": 0.0
+                    },
+                    "trades": []
+                }
+            })
+            .to_string(),
+        )
+        .expect(1)
+        .create_async()
+        .await;
+    let sell_mock = server
+        .mock("POST", "/api/v2/private/sell")
+        .with_status(400)
+        .with_header("content-type", "application/json")
+        .with_body(
+            r#"{"jsonrpc": "2.0", "id": 1, "error": {"code": 10009, "message": "not_enough_funds"}}"#,
+        )
+        .expect(1)
+        .create_async()
+        .await;
+
+    let outcomes = client
+        .submit_orders(vec![
+            (OrderSide::Buy, limit_order("BTC-PERPETUAL", 49000.0)),
+            (OrderSide::Sell, limit_order("BTC-PERPETUAL", 51000.0)),
+        ])
+        .await;
+
+    buy_mock.assert_async().await;
+    sell_mock.assert_async().await;
+    assert_eq!(outcomes.len(), 2);
+    assert_eq!(outcomes[0].as_ref().unwrap().order.order_id, "bid-1");
+    let error = outcomes[1].as_ref().unwrap_err();
+    assert_eq!(error.error_code(), Some(DeribitErrorCode::NotEnoughFunds));
+}
+
+#[tokio::test]
+async fn test_submit_orders_empty_batch_sends_nothing() {
+    let server = mockito::Server::new_async().await;
+    let client = create_test_client(&server);
+
+    assert!(client.submit_orders(Vec::new()).await.is_empty());
+}
+
+#[tokio::test]
+async fn test_buy_order_with_linked_orders_returns_child_ids() {
+    let mut server = mockito::Server::new_async().await;
+    let client = create_test_client(&server);
+
+    let _auth_mock = create_auth_mock(&mut server).await;
+
+    let buy_mock = server
+        .mock("POST", "/api/v2/private/buy")
+        .match_body(rpc_params(json!({
+            "linked_order_type": "one_triggers_one_cancels_other",
+            "trigger_fill_condition": "first_hit",
+            "otoco_config": [
+                { "direction": "sell", "type": "limit", "price": 55000.0 },
+                { "direction": "sell", "type": "stop_market", "trigger_price": 45000.0 }
+            ]
+        })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "result": {
+                    "order": {
+                        "order_id": "primary-1",
+                        "instrument_name": "BTC-PERPETUAL",
+                        "direction": "buy",
+                        "amount": 10.0,
+                        "filled_amount": 0.0,
+                        "order_state": "open",
+                        "order_type": "limit",
+                        "label": "",
+                        "price": 50000.0,
+                        "time_in_force": "good_til_cancelled",
+                        "creation_timestamp": 1_700_000_000_000_u64,
+                        "last_update_timestamp": 1_700_000_000_000_u64,
+                        "api": true,
+                        "is_liquidation": false,
+                        "post_only": false,
+                        "reduce_only": false,
+                        "replaced": false,
+                        "risk_reducing": false,
+                        "web": false,
+                        "is_primary_otoco": true,
+                        "oto_order_ids": ["OTO-1", "OTO-2"]
+                    },
+                    "trades": []
+                }
+            })
+            .to_string(),
+        )
+        .expect(1)
+        .create_async()
+        .await;
+
+    let request = OrderRequest::builder()
+        .instrument_name("BTC-PERPETUAL")
+        .amount(10.0)
+        .limit(50_000.0)
+        .linked_orders(
+            LinkedOrders::one_triggers_one_cancels_other()
+                .trigger_fill_condition(TriggerFillCondition::FirstHit)
+                .order(LinkedOrder::limit(OrderSide::Sell, 10.0, 55_000.0))
+                .order(LinkedOrder::stop_market(
+                    OrderSide::Sell,
+                    10.0,
+                    45_000.0,
+                    Trigger::MarkPrice,
+                )),
+        )
+        .build()
+        .unwrap();
+
+    let response = client.buy_order(request).await.unwrap();
+
+    buy_mock.assert_async().await;
+    assert_eq!(response.order.is_primary_otoco, Some(true));
+    assert_eq!(response.linked_order_ids(), ["OTO-1", "OTO-2"]);
+}
+
+#[tokio::test]
+async fn test_order_without_linked_orders_has_no_child_ids() {
+    let mut server = mockito::Server::new_async().await;
+    let client = create_test_client(&server);
+
+    let _auth_mock = create_auth_mock(&mut server).await;
+
+    let _buy_mock = server
+        .mock("POST", "/api/v2/private/buy")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "result": {
+                    "order": {
+                        "order_id": "plain-1",
+                        "instrument_name": "BTC-PERPETUAL",
+                        "direction": "buy",
+                        "amount": 10.0,
+                        "order_state": "open",
+                        "order_type": "limit",
+                        "label": "",
+                        "price": 50000.0,
+                        "time_in_force": "good_til_cancelled",
+                        "creation_timestamp": 1_700_000_000_000_u64,
+                        "last_update_timestamp": 1_700_000_000_000_u64,
+                        "api": true,
+                        "is_liquidation": false,
+                        "post_only": false,
+                        "reduce_only": false,
+                        "replaced": false,
+                        "risk_reducing": false,
+                        "web": false
+                    },
+                    "trades": []
+                }
+            })
+            .to_string(),
+        )
+        .create_async()
+        .await;
+
+    let response = client
+        .buy_order(limit_order("BTC-PERPETUAL", 50000.0))
+        .await
+        .unwrap();
+
+    assert!(response.linked_order_ids().is_empty());
+}
+
+// =========================================================================
+// Price Normalization Tests
+// =========================================================================
+
+fn create_normalizing_client(server: &mockito::ServerGuard) -> DeribitHttpClient {
+    let config = HttpConfig {
+        base_url: Url::parse(&format!("{}/api/v2", server.url())).unwrap(),
+        ..Default::default()
+    }
+    .with_oauth2(
+        "test_client_id".to_string(),
+        "test_client_secret".to_string(),
+    )
+    .with_price_normalization(0.001);
+
+    DeribitHttpClient::with_config(config)
+}
+
+async fn create_perpetual_instrument_mock(server: &mut mockito::Server) -> mockito::Mock {
+    server
+        .mock("GET", "/api/v2/public/get_instrument")
+        .match_query(mockito::Matcher::UrlEncoded(
+            "instrument_name".into(),
+            "BTC-PERPETUAL".into(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "result": {
+                    "instrument_name": "BTC-PERPETUAL",
+                    "kind": "future",
+                    "tick_size": 0.5
+                }
+            })
+            .to_string(),
+        )
+        .expect(1)
+        .create_async()
+        .await
+}
+
+#[tokio::test]
+async fn test_price_normalization_rounds_to_tick() {
+    let mut server = mockito::Server::new_async().await;
+    let client = create_normalizing_client(&server);
+
+    let _auth_mock = create_auth_mock(&mut server).await;
+    let instrument_mock = create_perpetual_instrument_mock(&mut server).await;
+    let buy_mock = server
+        .mock("POST", "/api/v2/private/buy")
+        .match_body(rpc_params(
+            json!({ "instrument_name": "BTC-PERPETUAL", "price": 49000.5 }),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "result": {
+                    "order": {
+                        "order_id": "bid-1",
+                        "instrument_name": "BTC-PERPETUAL",
+                        "direction": "buy",
+                        "amount": 10.0,
+                        "filled_amount": 0.0,
+                        "order_state": "open",
+                        "order_type": "limit",
+                        "label": "ladder",
+                        "price": 49000.5,
+                        "time_in_force": "good_til_cancelled",
+                        "creation_timestamp": 1_700_000_000_000_u64,
+                        "last_update_timestamp": 1_700_000_000_000_u64,
+                        "api": true,
+                        "is_liquidation": false,
+                        "post_only": true,
+                        "reduce_only": false,
+                        "replaced": false,
+                        "risk_reducing": false,
+                        "web": false,
+                        "average_price": 0.0
+                    },
+                    "trades": []
+                }
+            })
+            .to_string(),
+        )
+        .expect(2)
+        .create_async()
+        .await;
+
+    client
+        .buy_order(limit_order("BTC-PERPETUAL", 49000.4))
+        .await
+        .unwrap();
+    client
+        .buy_order(limit_order("BTC-PERPETUAL", 49000.6))
+        .await
+        .unwrap();
+
+    instrument_mock.assert_async().await;
+    buy_mock.assert_async().await;
+    assert_eq!(client.instrument_cache().len().await, 1);
+}
+
+#[tokio::test]
+async fn test_price_normalization_rejects_large_moves() {
+    let mut server = mockito::Server::new_async().await;
+    let client = create_normalizing_client(&server);
+
+    let _auth_mock = create_auth_mock(&mut server).await;
+    let _instrument_mock = create_perpetual_instrument_mock(&mut server).await;
+    let sell_mock = server
+        .mock("POST", "/api/v2/private/sell")
+        .expect(0)
+        .create_async()
+        .await;
+
+    // 0.7 rounds to 0.5, a 28% move against a 0.1% tolerance
+    let result = client.sell_order(limit_order("BTC-PERPETUAL", 0.7)).await;
+
+    sell_mock.assert_async().await;
+    match result.map_err(HttpError::into_kind) {
+        Err(HttpErrorKind::ConfigError(message)) => {
+            assert!(message.contains("nearest tick is 0.5"), "{}", message)
+        }
+        other => panic!("expected ConfigError, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_price_normalization_rejects_amounts_below_the_minimum() {
+    let mut server = mockito::Server::new_async().await;
+    let client = create_normalizing_client(&server);
+
+    let _auth_mock = create_auth_mock(&mut server).await;
+    let _instrument_mock = server
+        .mock("GET", "/api/v2/public/get_instrument")
+        .match_query(mockito::Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "result": {
+                    "instrument_name": "BTC-PERPETUAL",
+                    "tick_size": 0.5,
+                    "min_trade_amount": 20.0
+                }
+            })
+            .to_string(),
+        )
+        .create_async()
+        .await;
+    let buy_mock = server
+        .mock("POST", "/api/v2/private/buy")
+        .expect(0)
+        .create_async()
+        .await;
+
+    let result = client
+        .buy_order(limit_order("BTC-PERPETUAL", 49000.5))
+        .await;
+
+    buy_mock.assert_async().await;
+    match result.map_err(HttpError::into_kind) {
+        Err(HttpErrorKind::ConfigError(message)) => {
+            assert!(message.contains("minimum trade amount 20"), "{}", message)
+        }
+        other => panic!("expected ConfigError, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_price_normalization_is_off_by_default() {
+    let mut server = mockito::Server::new_async().await;
+    let client = create_test_client(&server);
+
+    let _auth_mock = create_auth_mock(&mut server).await;
+    let instrument_mock = server
+        .mock("GET", "/api/v2/public/get_instrument")
+        .match_query(mockito::Matcher::Any)
+        .expect(0)
+        .create_async()
+        .await;
+    let sell_mock = server
+        .mock("POST", "/api/v2/private/sell")
+        .match_body(rpc_params(json!({ "price": 0.7 })))
+        .with_status(400)
+        .with_header("content-type", "application/json")
+        .with_body(
+            r#"{"jsonrpc": "2.0", "id": 1, "error": {"code": 10043, "message": "price_wrong_tick"}}"#,
+        )
+        .create_async()
+        .await;
+
+    let error = client
+        .sell_order(limit_order("BTC-PERPETUAL", 0.7))
+        .await
+        .unwrap_err();
+
+    instrument_mock.assert_async().await;
+    sell_mock.assert_async().await;
+    assert_eq!(error.error_code(), Some(DeribitErrorCode::PriceWrongTick));
+}
+
+fn account_result(currency: &str, equity: f64, margin: f64, initial: f64) -> serde_json::Value {
+    json!({
+        "currency": currency,
+        "balance": equity,
+        "equity": equity,
+        "available_funds": margin - initial,
+        "margin_balance": margin,
+        "initial_margin": initial,
+        "maintenance_margin": initial / 2.0
+    })
+}
+
+#[tokio::test]
+async fn test_get_portfolio_overview_values_every_currency_in_usd() {
+    let mut server = mockito::Server::new_async().await;
+    let client = create_test_client(&server);
+
+    let _auth_mock = create_auth_mock(&mut server).await;
+    server
+        .mock("POST", "/api/v2/private/get_account_summaries")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "result": {
+                    "id": 7,
+                    "email": "user@example.com",
+                    "summaries": [
+                        account_result("BTC", 2.0, 2.0, 0.5),
+                        account_result("USDC", 10000.0, 10000.0, 5000.0),
+                        account_result("EURR", 100.0, 100.0, 0.0)
+                    ]
+                }
+            })
+            .to_string(),
+        )
+        .create_async()
+        .await;
+    server
+        .mock("GET", "/api/v2/public/get_index_price_names")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({ "jsonrpc": "2.0", "id": 1, "result": ["btc_usd", "eth_usd"] }).to_string(),
+        )
+        .create_async()
+        .await;
+    let index_mock = server
+        .mock("GET", "/api/v2/public/get_index_price?index_name=btc_usd")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "result": { "index_price": 50000.0, "estimated_delivery_price": 50000.0 }
+            })
+            .to_string(),
+        )
+        .expect(1)
+        .create_async()
+        .await;
+    server
+        .mock("POST", "/api/v2/private/get_positions")
+        .match_body(rpc_params(json!({ "currency": "BTC" })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "result": [{
+                    "instrument_name": "BTC-PERPETUAL",
+                    "direction": "buy",
+                    "average_price": 49000.0,
+                    "size": 1000.0
+                }]
+            })
+            .to_string(),
+        )
+        .create_async()
+        .await;
+    server
+        .mock("POST", "/api/v2/private/get_positions")
+        .match_body(mockito::Matcher::Regex("USDC|EURR".into()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({ "jsonrpc": "2.0", "id": 1, "result": [] }).to_string())
+        .expect(2)
+        .create_async()
+        .await;
+
+    let overview = client.get_portfolio_overview().await.unwrap();
+
+    index_mock.assert_async().await;
+    assert_eq!(overview.currencies.len(), 3);
+    assert_eq!(overview.positions().count(), 1);
+    assert_eq!(
+        overview.currency("btc").unwrap().equity_usd(),
+        Some(100000.0)
+    );
+    assert_eq!(overview.total_equity_usd(), 110000.0);
+    assert_eq!(overview.total_initial_margin_usd(), 30000.0);
+    assert_eq!(overview.margin_usage(), Some(30000.0 / 110000.0));
+    assert_eq!(overview.unpriced_currencies(), vec!["EURR"]);
+}
+
+#[tokio::test]
+async fn test_get_exposure_fills_missing_option_greeks_from_tickers() {
+    let mut server = mockito::Server::new_async().await;
+    let client = create_test_client(&server);
+
+    let _auth_mock = create_auth_mock(&mut server).await;
+    server
+        .mock("POST", "/api/v2/private/get_positions")
+        .match_body(rpc_params(json!({ "currency": "BTC" })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "result": [
+                    {
+                        "instrument_name": "BTC-PERPETUAL",
+                        "kind": "future",
+                        "direction": "buy",
+                        "size": 25000.0,
+                        "delta": 0.5
+                    },
+                    {
+                        "instrument_name": "BTC-27DEC24-50000-C",
+                        "kind": "option",
+                        "direction": "sell",
+                        "size": -2.0
+                    },
+                    {
+                        "instrument_name": "BTC-27DEC24-60000-P",
+                        "kind": "option",
+                        "direction": "buy",
+                        "size": 1.0,
+                        "delta": -0.3,
+                        "gamma": 0.0002,
+                        "vega": 5.0,
+                        "theta": -2.0
+                    },
+                    {
+                        "instrument_name": "BTC-27DEC24",
+                        "kind": "future",
+                        "direction": "zero",
+                        "size": 0.0
+                    }
+                ]
+            })
+            .to_string(),
+        )
+        .create_async()
+        .await;
+    let ticker_mock = server
+        .mock(
+            "GET",
+            "/api/v2/public/ticker?instrument_name=BTC-27DEC24-50000-C",
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "result": {
+                    "instrument_name": "BTC-27DEC24-50000-C",
+                    "best_bid_amount": 1.0,
+                    "best_ask_amount": 1.0,
+                    "mark_price": 0.05,
+                    "timestamp": 1700000000000u64,
+                    "state": "open",
+                    "stats": { "volume": 0.0 },
+                    "greeks": { "delta": 0.4, "gamma": 0.0001, "vega": 10.0, "theta": -5.0 }
+                }
+            })
+            .to_string(),
+        )
+        .expect(1)
+        .create_async()
+        .await;
+
+    let exposure = client.get_exposure("BTC").await.unwrap();
+
+    ticker_mock.assert_async().await;
+    assert_eq!(exposure.underlyings.len(), 1);
+    let btc = exposure.underlying("BTC").unwrap();
+    assert_eq!(btc.positions, 3);
+    assert!((btc.delta - -0.6).abs() < 1e-12, "{}", btc.delta);
+    assert!(btc.gamma.abs() < 1e-12, "{}", btc.gamma);
+    assert_eq!(btc.vega, -15.0);
+    assert_eq!(btc.theta, 8.0);
+}
+
+fn funding_entry(
+    id: u64,
+    kind: &str,
+    instrument: &str,
+    interest_pl: Option<f64>,
+) -> serde_json::Value {
+    json!({
+        "id": id,
+        "currency": "BTC",
+        "balance": 1.0,
+        "timestamp": 1_700_000_000_000_u64 + id,
+        "type": kind,
+        "change": 0.0,
+        "cashflow": 0.0,
+        "user_id": 1,
+        "user_seq": id,
+        "equity": 1.0,
+        "username": "test_user",
+        "instrument_name": instrument,
+        "interest_pl": interest_pl
+    })
+}
+
+#[tokio::test]
+async fn test_get_funding_pnl_sums_funding_per_perpetual() {
+    let mut server = mockito::Server::new_async().await;
+    let client = create_test_client(&server);
+
+    let _auth_mock = create_auth_mock(&mut server).await;
+    server
+        .mock("POST", "/api/v2/private/get_transaction_log")
+        .match_body(rpc_params(json!({
+            "currency": "BTC",
+            "start_timestamp": 1_700_000_000_000_u64,
+            "end_timestamp": 1_700_100_000_000_u64
+        })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "result": {
+                    "logs": [
+                        funding_entry(1, "settlement", "BTC-PERPETUAL", Some(0.0002)),
+                        funding_entry(2, "trade", "BTC-PERPETUAL", Some(-0.0005)),
+                        funding_entry(3, "settlement", "BTC-27DEC24", Some(0.1)),
+                        funding_entry(4, "trade", "BTC-27DEC24-50000-C", None)
+                    ],
+                    "continuation": null
+                }
+            })
+            .to_string(),
+        )
+        .create_async()
+        .await;
+    let rates_mock = server
+        .mock("GET", "/api/v2/public/get_funding_rate_history")
+        .match_query(mockito::Matcher::AllOf(vec![
+            mockito::Matcher::UrlEncoded("instrument_name".into(), "BTC-PERPETUAL".into()),
+            mockito::Matcher::UrlEncoded("start_timestamp".into(), "1700000000000".into()),
+        ]))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "result": [
+                    { "timestamp": 1_700_000_000_000_u64, "index_price": 1.0, "prev_index_price": 1.0,
+                      "interest_8h": 0.0001, "interest_1h": 0.0 },
+                    { "timestamp": 1_700_003_600_000_u64, "index_price": 1.0, "prev_index_price": 1.0,
+                      "interest_8h": 0.0003, "interest_1h": 0.0 }
+                ]
+            })
+            .to_string(),
+        )
+        .expect(1)
+        .create_async()
+        .await;
+
+    let report = client
+        .get_funding_pnl(
+            "BTC",
+            (
+                TimestampMs::from_millis(1_700_000_000_000),
+                TimestampMs::from_millis(1_700_100_000_000),
+            ),
+        )
+        .await
+        .unwrap();
+
+    rates_mock.assert_async().await;
+    assert_eq!(report.instruments.len(), 1);
+    let perpetual = report.instrument("BTC-PERPETUAL").unwrap();
+    assert_eq!(perpetual.entries, 2);
+    assert_eq!(perpetual.received, 0.0002);
+    assert_eq!(perpetual.paid, 0.0005);
+    assert!((report.total() - -0.0003).abs() < 1e-12);
+    assert!((perpetual.average_rate_8h.unwrap() - 0.0002).abs() < 1e-12);
+}
+
+// =========================================================================
+// Wait For Order State Tests
+// =========================================================================
+
+fn order_state_response(order_state: &str) -> String {
+    json!({
+        "jsonrpc": "2.0",
+        "result": {
+            "time_in_force": "good_til_cancelled",
+            "reduce_only": false,
+            "price": 118.94,
+            "post_only": false,
+            "order_type": "limit",
+            "order_state": order_state,
+            "order_id": "ETH-331562",
+            "max_show": 37.0,
+            "last_update_timestamp": 1550219810944u64,
+            "label": "",
+            "is_liquidation": false,
+            "instrument_name": "ETH-PERPETUAL",
+            "filled_amount": 0.0,
+            "direction": "buy",
+            "creation_timestamp": 1550219749176u64,
+            "average_price": 0.0,
+            "api": false,
+            "amount": 37.0,
+            "replaced": false,
+            "risk_reducing": false,
+            "web": false
+        },
+        "id": 1
+    })
+    .to_string()
+}
+
+#[tokio::test]
+async fn test_wait_for_order_state_returns_once_target_reached() {
+    let mut server = mockito::Server::new_async().await;
+    let client = create_test_client(&server);
+    let _auth_mock = create_auth_mock(&mut server).await;
+
+    let polls = Arc::new(AtomicUsize::new(0));
+    let counter = polls.clone();
+    let mock = server
+        .mock("POST", "/api/v2/private/get_order_state")
+        .match_body(rpc_params(json!({ "order_id": "ETH-331562" })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body_from_request(move |_| {
+            let state = match counter.fetch_add(1, Ordering::SeqCst) {
+                0 | 1 => "open",
+                _ => "filled",
+            };
+            order_state_response(state).into_bytes()
+        })
+        .expect(3)
+        .create_async()
+        .await;
+
+    let order = client
+        .wait_for_order_state(
+            "ETH-331562",
+            &["filled", "cancelled"],
+            Duration::from_secs(5),
+            Duration::from_millis(10),
+        )
+        .await
+        .unwrap();
+
+    mock.assert_async().await;
+    assert_eq!(order.order_state, "filled");
+    assert_eq!(polls.load(Ordering::SeqCst), 3);
+}
+
+#[tokio::test]
+async fn test_wait_for_order_state_returns_last_state_on_timeout() {
+    let mut server = mockito::Server::new_async().await;
+    let client = create_test_client(&server);
+    let _auth_mock = create_auth_mock(&mut server).await;
+
+    server
+        .mock("POST", "/api/v2/private/get_order_state")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(order_state_response("open"))
+        .expect_at_least(2)
+        .create_async()
+        .await;
+
+    let started = std::time::Instant::now();
+    let order = client
+        .wait_for_order_state(
+            "ETH-331562",
+            &["filled"],
+            Duration::from_millis(100),
+            Duration::from_millis(20),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(order.order_state, "open");
+    assert!(started.elapsed() >= Duration::from_millis(100));
+    assert!(started.elapsed() < Duration::from_secs(2));
+}
+
+#[tokio::test]
+async fn test_wait_for_fill_stops_on_cancellation() {
+    let mut server = mockito::Server::new_async().await;
+    let client = create_test_client(&server);
+    let _auth_mock = create_auth_mock(&mut server).await;
+
+    let mock = server
+        .mock("POST", "/api/v2/private/get_order_state")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(order_state_response("cancelled"))
+        .expect(1)
+        .create_async()
+        .await;
+
+    let order = client
+        .wait_for_fill("ETH-331562", Duration::from_secs(5))
+        .await
+        .unwrap();
+
+    mock.assert_async().await;
+    assert_eq!(order.order_state, "cancelled");
+}
+
+// =========================================================================
+// Emergency Flatten Tests
+// =========================================================================
+
+#[tokio::test]
+async fn test_emergency_flatten_cancels_and_closes_positions() {
+    let mut server = mockito::Server::new_async().await;
+    let client = create_test_client(&server);
+    let _auth_mock = create_auth_mock(&mut server).await;
+
+    let btc_cancel = server
+        .mock("POST", "/api/v2/private/cancel_all_by_currency")
+        .match_body(rpc_params(json!({ "currency": "BTC" })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({ "jsonrpc": "2.0", "result": 3, "id": 1 }).to_string())
+        .create_async()
+        .await;
+    let eth_cancel = server
+        .mock("POST", "/api/v2/private/cancel_all_by_currency")
+        .match_body(rpc_params(json!({ "currency": "ETH" })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({ "jsonrpc": "2.0", "result": 0, "id": 1 }).to_string())
+        .create_async()
+        .await;
+    server
+        .mock("POST", "/api/v2/private/get_positions")
+        .match_body(rpc_params(json!({ "currency": "BTC" })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "jsonrpc": "2.0",
+                "result": [
+                    { "instrument_name": "BTC-PERPETUAL", "size": 100.0 },
+                    { "instrument_name": "BTC-27DEC24", "size": 0.0 }
+                ],
+                "id": 1
+            })
+            .to_string(),
+        )
+        .create_async()
+        .await;
+    server
+        .mock("POST", "/api/v2/private/get_positions")
+        .match_body(rpc_params(json!({ "currency": "ETH" })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "jsonrpc": "2.0",
+                "error": { "code": 13009, "message": "unauthorized" },
+                "id": 1
+            })
+            .to_string(),
+        )
+        .create_async()
+        .await;
+    let close = server
+        .mock("POST", "/api/v2/private/close_position")
+        .match_body(rpc_params(
+            json!({ "instrument_name": "BTC-PERPETUAL", "type": "market" }),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "jsonrpc": "2.0",
+                "result": {
+                    "order": {
+                        "amount": 100.0,
+                        "api": true,
+                        "average_price": 50000.0,
+                        "creation_timestamp": 1609459200000u64,
+                        "direction": "sell",
+                        "filled_amount": 100.0,
+                        "instrument_name": "BTC-PERPETUAL",
+                        "is_liquidation": false,
+                        "label": "",
+                        "last_update_timestamp": 1609459200000u64,
+                        "order_id": "BTC-123456",
+                        "order_state": "filled",
+                        "order_type": "market",
+                        "post_only": false,
+                        "price": 50000.0,
+                        "reduce_only": true,
+                        "replaced": false,
+                        "risk_reducing": false,
+                        "time_in_force": "good_til_cancelled",
+                        "web": false
+                    },
+                    "trades": []
+                },
+                "id": 1
+            })
+            .to_string(),
+        )
+        .expect(1)
+        .create_async()
+        .await;
+
+    let report = client.emergency_flatten(&["BTC", "ETH"]).await;
+
+    btc_cancel.assert_async().await;
+    eth_cancel.assert_async().await;
+    close.assert_async().await;
+    assert_eq!(report.cancelled_orders(), 3);
+    assert_eq!(report.closes.len(), 1);
+    assert_eq!(report.closes[0].0, "BTC-PERPETUAL");
+    assert!(report.closes[0].1.as_ref().unwrap().order.reduce_only);
+    assert!(!report.is_complete());
+    let failures: Vec<&str> = report.failures().map(|(target, _)| target).collect();
+    assert_eq!(failures, vec!["ETH"]);
+}
@@ -1,3 +1,9 @@
+use deribit_http::model::TimestampMs;
+use deribit_http::model::currency::Currency;
+use deribit_http::model::instrument::{InstrumentKind, OptionType};
+use deribit_http::model::other::OptionFilter;
+use deribit_http::model::response::other::VolatilityIndexResolution;
+use deribit_http::model::tradingview::Resolution;
 use deribit_http::{DeribitHttpClient, HttpConfig};
 use mockito;
 use serde_json::json;
@@ -547,6 +553,361 @@ async fn test_get_block_rfq_trades_success() {
     assert_eq!(response.block_rfqs[0].id, 6611);
 }
 
+#[tokio::test]
+async fn test_get_trade_volumes_basic() {
+    let mut server = mockito::Server::new_async().await;
+    let client = create_test_client(&server);
+
+    let mock = server
+        .mock("GET", "//public/get_trade_volumes")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "jsonrpc": "2.0",
+                "result": [
+                    {
+                        "currency": "BTC",
+                        "calls_volume": 145.0,
+                        "puts_volume": 48.0,
+                        "futures_volume": 6.25,
+                        "spot_volume": 11.1
+                    }
+                ],
+                "id": 1
+            })
+            .to_string(),
+        )
+        .create_async()
+        .await;
+
+    let volumes = client.get_trade_volumes(false).await.unwrap();
+
+    mock.assert_async().await;
+    assert_eq!(volumes.len(), 1);
+    assert_eq!(volumes[0].currency, "BTC");
+    assert_eq!(volumes[0].calls_volume, 145.0);
+    assert!(volumes[0].calls_volume_7d.is_none());
+}
+
+#[tokio::test]
+async fn test_get_trade_volumes_extended() {
+    let mut server = mockito::Server::new_async().await;
+    let client = create_test_client(&server);
+
+    let mock = server
+        .mock("GET", "//public/get_trade_volumes?extended=true")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "jsonrpc": "2.0",
+                "result": [
+                    {
+                        "currency": "ETH",
+                        "calls_volume": 37.4,
+                        "puts_volume": 11.2,
+                        "futures_volume": 10.5,
+                        "spot_volume": 2.5,
+                        "calls_volume_7d": 75.6,
+                        "puts_volume_7d": 356.9,
+                        "futures_volume_7d": 213.8841,
+                        "spot_volume_7d": 64.8,
+                        "calls_volume_30d": 547.3,
+                        "puts_volume_30d": 785.5,
+                        "futures_volume_30d": 998.2128,
+                        "spot_volume_30d": 310.5
+                    }
+                ],
+                "id": 1
+            })
+            .to_string(),
+        )
+        .create_async()
+        .await;
+
+    let volumes = client.get_trade_volumes(true).await.unwrap();
+
+    mock.assert_async().await;
+    assert_eq!(volumes[0].puts_volume_7d, Some(356.9));
+    assert_eq!(volumes[0].spot_volume_30d, Some(310.5));
+}
+
+#[tokio::test]
+async fn test_get_book_summary_by_currency_accepts_enum_and_string() {
+    let mut server = mockito::Server::new_async().await;
+    let client = create_test_client(&server);
+
+    let mock = server
+        .mock("GET", "//public/get_book_summary_by_currency?currency=ETH")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({"jsonrpc": "2.0", "result": [], "id": 1}).to_string())
+        .expect(2)
+        .create_async()
+        .await;
+
+    let by_enum = client
+        .get_book_summary_by_currency(Currency::Eth, None)
+        .await
+        .unwrap();
+    let by_string = client
+        .get_book_summary_by_currency(String::from("ETH"), None)
+        .await
+        .unwrap();
+
+    mock.assert_async().await;
+    assert!(by_enum.is_empty());
+    assert!(by_string.is_empty());
+}
+
+#[tokio::test]
+async fn test_get_instruments_sends_kind_filter() {
+    let mut server = mockito::Server::new_async().await;
+    let client = create_test_client(&server);
+
+    let mock = server
+        .mock(
+            "GET",
+            "//public/get_instruments?currency=BTC&kind=option_combo&expired=false",
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({"jsonrpc": "2.0", "result": [], "id": 1}).to_string())
+        .create_async()
+        .await;
+
+    let instruments = client
+        .get_instruments("BTC", Some(InstrumentKind::OptionCombo), Some(false))
+        .await
+        .unwrap();
+
+    mock.assert_async().await;
+    assert!(instruments.is_empty());
+}
+
+#[tokio::test]
+async fn test_get_volatility_index_data_parses_candles() {
+    let mut server = mockito::Server::new_async().await;
+    let client = create_test_client(&server);
+
+    let mock = server
+        .mock(
+            "GET",
+            "//public/get_volatility_index_data?currency=BTC&start_timestamp=1000&end_timestamp=5000&resolution=60",
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "jsonrpc": "2.0",
+                "result": {
+                    "data": [[1000, 50.1, 50.4, 49.9, 50.2], [2000, 50.2, 50.3, 50.0, 50.1]],
+                    "continuation": null
+                },
+                "id": 1
+            })
+            .to_string(),
+        )
+        .create_async()
+        .await;
+
+    let dvol = client
+        .get_volatility_index_data(
+            "BTC",
+            (
+                TimestampMs::from_millis(1000),
+                TimestampMs::from_millis(5000),
+            ),
+            VolatilityIndexResolution::Minute1,
+        )
+        .await
+        .unwrap();
+
+    mock.assert_async().await;
+    assert_eq!(dvol.data.len(), 2);
+    assert_eq!(dvol.data[1].timestamp.as_millis(), 2000);
+    assert_eq!(dvol.data[0].high, 50.4);
+}
+
+#[tokio::test]
+async fn test_get_all_volatility_index_data_follows_continuation() {
+    let mut server = mockito::Server::new_async().await;
+    let client = create_test_client(&server);
+
+    let newest = server
+        .mock(
+            "GET",
+            "//public/get_volatility_index_data?currency=BTC&start_timestamp=1000&end_timestamp=5000&resolution=60",
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "jsonrpc": "2.0",
+                "result": {
+                    "data": [[3000, 51.0, 51.0, 51.0, 51.0], [4000, 52.0, 52.0, 52.0, 52.0]],
+                    "continuation": 3000
+                },
+                "id": 1
+            })
+            .to_string(),
+        )
+        .create_async()
+        .await;
+    let oldest = server
+        .mock(
+            "GET",
+            "//public/get_volatility_index_data?currency=BTC&start_timestamp=1000&end_timestamp=3000&resolution=60",
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "jsonrpc": "2.0",
+                "result": {
+                    "data": [[2000, 50.0, 50.0, 50.0, 50.0], [3000, 51.0, 51.0, 51.0, 51.0]],
+                    "continuation": null
+                },
+                "id": 1
+            })
+            .to_string(),
+        )
+        .create_async()
+        .await;
+
+    let candles = client
+        .get_all_volatility_index_data(
+            "BTC",
+            (
+                TimestampMs::from_millis(1000),
+                TimestampMs::from_millis(5000),
+            ),
+            VolatilityIndexResolution::Minute1,
+        )
+        .await
+        .unwrap();
+
+    newest.assert_async().await;
+    oldest.assert_async().await;
+    let timestamps: Vec<u64> = candles.iter().map(|c| c.timestamp.as_millis()).collect();
+    assert_eq!(timestamps, vec![2000, 3000, 4000]);
+}
+
+#[tokio::test]
+async fn test_get_mark_price_history_returns_points() {
+    let mut server = mockito::Server::new_async().await;
+    let client = create_test_client(&server);
+
+    let mock = server
+        .mock(
+            "GET",
+            "//public/get_mark_price_history?instrument_name=BTC-25JUN21-50000-C&start_timestamp=1609376800000&end_timestamp=1609376810000",
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "jsonrpc": "2.0",
+                "result": [
+                    [1609376800000_u64, 0.5109],
+                    [1609376810000_u64, 0.5112]
+                ],
+                "id": 1
+            })
+            .to_string(),
+        )
+        .create_async()
+        .await;
+
+    let history = client
+        .get_mark_price_history(
+            "BTC-25JUN21-50000-C",
+            (
+                TimestampMs::from_millis(1609376800000),
+                TimestampMs::from_millis(1609376810000),
+            ),
+        )
+        .await
+        .unwrap();
+
+    mock.assert_async().await;
+    let points: Vec<(u64, f64)> = history.into_iter().map(Into::into).collect();
+    assert_eq!(
+        points,
+        vec![(1609376800000, 0.5109), (1609376810000, 0.5112)]
+    );
+}
+
+#[tokio::test]
+async fn test_get_mark_price_history_rejects_inverted_range() {
+    let mut server = mockito::Server::new_async().await;
+    let client = create_test_client(&server);
+
+    let mock = server
+        .mock("GET", mockito::Matcher::Any)
+        .expect(0)
+        .create_async()
+        .await;
+
+    let result = client
+        .get_mark_price_history(
+            "BTC-25JUN21-50000-C",
+            (
+                TimestampMs::from_millis(2000),
+                TimestampMs::from_millis(1000),
+            ),
+        )
+        .await;
+
+    mock.assert_async().await;
+    assert!(matches!(
+        result.map_err(deribit_http::HttpError::into_kind),
+        Err(deribit_http::HttpErrorKind::ConfigError(_))
+    ));
+}
+
+#[tokio::test]
+async fn test_get_rfqs_success() {
+    let mut server = mockito::Server::new_async().await;
+    let client = create_test_client(&server);
+
+    let mock = server
+        .mock("GET", "//public/get_rfqs?currency=BTC&kind=option")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "jsonrpc": "2.0",
+                "result": [
+                    {
+                        "state": true,
+                        "side": "sell",
+                        "last_update_timestamp": 1634816143836i64,
+                        "instrument_name": "BTC-27DEC24-60000-C",
+                        "amount": 25.0
+                    },
+                    { "instrument_name": "BTC-27DEC24-50000-P" }
+                ],
+                "id": 1
+            })
+            .to_string(),
+        )
+        .create_async()
+        .await;
+
+    let rfqs = client
+        .get_rfqs("BTC", Some(InstrumentKind::Option))
+        .await
+        .unwrap();
+
+    mock.assert_async().await;
+    assert_eq!(rfqs.len(), 2);
+    assert_eq!(rfqs[0].side, Some(deribit_http::model::RfqSide::Sell));
+    assert_eq!(rfqs[0].amount, Some(25.0));
+    assert!(rfqs[1].side.is_none());
+}
+
 #[tokio::test]
 async fn test_get_block_rfq_trades_empty() {
     let mut server = mockito::Server::new_async().await;
@@ -569,7 +930,7 @@ async fn test_get_block_rfq_trades_empty() {
         .create_async()
         .await;
 
-    let result = client.get_block_rfq_trades(None, None, None).await;
+    let result = client.get_block_rfq_trades(None::<&str>, None, None).await;
 
     mock.assert_async().await;
     assert!(result.is_ok());
@@ -577,3 +938,868 @@ async fn test_get_block_rfq_trades_empty() {
     assert!(!response.has_more());
     assert!(response.is_empty());
 }
+
+#[tokio::test]
+async fn test_get_block_rfq_trades_encodes_query() {
+    let mut server = mockito::Server::new_async().await;
+    let client = create_test_client(&server);
+
+    let mock = server
+        .mock(
+            "GET",
+            "//public/get_block_rfq_trades?currency=BTC&count=5&continuation=a%2Bb%3D",
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "jsonrpc": "2.0",
+                "result": { "continuation": null, "block_rfqs": [] },
+                "id": 1
+            })
+            .to_string(),
+        )
+        .create_async()
+        .await;
+
+    let result = client
+        .get_block_rfq_trades(Some(Currency::Btc), Some(5), Some("a+b="))
+        .await;
+
+    mock.assert_async().await;
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn test_get_apr_history_range_pages_backwards() {
+    let mut server = mockito::Server::new_async().await;
+    let client = create_test_client(&server);
+
+    let first_page = server
+        .mock(
+            "GET",
+            "//public/get_apr_history?currency=usde&limit=365&before=20011",
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "jsonrpc": "2.0",
+                "result": {
+                    "data": [
+                        {"day": 20010, "apr": 10.0},
+                        {"day": 20009, "apr": 9.0},
+                        {"day": 20008, "apr": 8.0}
+                    ],
+                    "continuation": "20008"
+                },
+                "id": 1
+            })
+            .to_string(),
+        )
+        .create_async()
+        .await;
+
+    let second_page = server
+        .mock(
+            "GET",
+            "//public/get_apr_history?currency=usde&limit=365&before=20008",
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "jsonrpc": "2.0",
+                "result": {
+                    "data": [
+                        {"day": 20007, "apr": 7.0},
+                        {"day": 20006, "apr": 6.0},
+                        {"day": 20005, "apr": 5.0}
+                    ],
+                    "continuation": "20005"
+                },
+                "id": 1
+            })
+            .to_string(),
+        )
+        .create_async()
+        .await;
+
+    let result = client
+        .get_apr_history_range("usde", 20006, 20010)
+        .await
+        .unwrap();
+
+    first_page.assert_async().await;
+    second_page.assert_async().await;
+    let days: Vec<i32> = result.iter().map(|point| point.day).collect();
+    assert_eq!(days, vec![20010, 20009, 20008, 20007, 20006]);
+}
+
+#[tokio::test]
+async fn test_get_apr_history_range_stops_on_empty_page() {
+    let mut server = mockito::Server::new_async().await;
+    let client = create_test_client(&server);
+
+    let mock = server
+        .mock(
+            "GET",
+            "//public/get_apr_history?currency=steth&limit=365&before=101",
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"jsonrpc":"2.0","result":{"data":[]},"id":1}"#)
+        .create_async()
+        .await;
+
+    let result = client.get_apr_history_range("steth", 1, 100).await.unwrap();
+
+    mock.assert_async().await;
+    assert!(result.is_empty());
+}
+
+#[tokio::test]
+async fn test_get_all_delivery_prices_walks_pages_and_dedups() {
+    let mut server = mockito::Server::new_async().await;
+    let client = create_test_client(&server);
+
+    let first_page = server
+        .mock(
+            "GET",
+            "//public/get_delivery_prices?index_name=btc_usd&count=1000&offset=0",
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "jsonrpc": "2.0",
+                "result": {
+                    "data": [
+                        {"date": "2024-03-03", "delivery_price": 3.0},
+                        {"date": "2024-03-02", "delivery_price": 2.0}
+                    ],
+                    "records_total": 5
+                },
+                "id": 1
+            })
+            .to_string(),
+        )
+        .create_async()
+        .await;
+
+    // A new delivery was published between pages, shifting the offsets by one
+    let second_page = server
+        .mock(
+            "GET",
+            "//public/get_delivery_prices?index_name=btc_usd&count=1000&offset=2",
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "jsonrpc": "2.0",
+                "result": {
+                    "data": [
+                        {"date": "2024-03-02", "delivery_price": 2.0},
+                        {"date": "2024-03-01", "delivery_price": 1.0},
+                        {"date": "2024-02-29", "delivery_price": 0.5}
+                    ],
+                    "records_total": 6
+                },
+                "id": 1
+            })
+            .to_string(),
+        )
+        .create_async()
+        .await;
+
+    let from = chrono::NaiveDate::from_ymd_opt(2024, 3, 1).unwrap();
+    let result = client
+        .get_all_delivery_prices("btc_usd", from..)
+        .await
+        .unwrap();
+
+    first_page.assert_async().await;
+    second_page.assert_async().await;
+    let dates: Vec<&str> = result.iter().map(|price| price.date.as_str()).collect();
+    assert_eq!(dates, vec!["2024-03-03", "2024-03-02", "2024-03-01"]);
+}
+
+#[tokio::test]
+async fn test_get_all_delivery_prices_stops_at_records_total() {
+    let mut server = mockito::Server::new_async().await;
+    let client = create_test_client(&server);
+
+    let mock = server
+        .mock(
+            "GET",
+            "//public/get_delivery_prices?index_name=eth_usd&count=1000&offset=0",
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "jsonrpc": "2.0",
+                "result": {
+                    "data": [
+                        {"date": "2024-03-02", "delivery_price": 2.0},
+                        {"date": "2024-03-01", "delivery_price": 1.0}
+                    ],
+                    "records_total": 2
+                },
+                "id": 1
+            })
+            .to_string(),
+        )
+        .expect(1)
+        .create_async()
+        .await;
+
+    let result = client.get_all_delivery_prices("eth_usd", ..).await.unwrap();
+
+    mock.assert_async().await;
+    assert_eq!(result.len(), 2);
+}
+
+#[tokio::test]
+async fn test_stream_last_settlements_by_currency_follows_continuation() {
+    use futures_util::TryStreamExt;
+
+    let mut server = mockito::Server::new_async().await;
+    let client = create_test_client(&server);
+
+    let first_page = server
+        .mock(
+            "GET",
+            "//public/get_last_settlements_by_currency?currency=BTC&count=1000",
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "jsonrpc": "2.0",
+                "result": {
+                    "settlements": [
+                        {"type": "settlement", "timestamp": 3000},
+                        {"type": "delivery", "timestamp": 2000}
+                    ],
+                    "continuation": "abc"
+                },
+                "id": 1
+            })
+            .to_string(),
+        )
+        .create_async()
+        .await;
+
+    let second_page = server
+        .mock(
+            "GET",
+            "//public/get_last_settlements_by_currency?currency=BTC&count=1000&continuation=abc",
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "jsonrpc": "2.0",
+                "result": {
+                    "settlements": [
+                        {"type": "settlement", "timestamp": 1000}
+                    ],
+                    "continuation": null
+                },
+                "id": 1
+            })
+            .to_string(),
+        )
+        .create_async()
+        .await;
+
+    let settlements: Vec<_> = client
+        .stream_last_settlements_by_currency("BTC", None, None, None)
+        .try_collect()
+        .await
+        .unwrap();
+
+    first_page.assert_async().await;
+    second_page.assert_async().await;
+    let timestamps: Vec<u64> = settlements
+        .iter()
+        .map(|s| s.timestamp.as_millis())
+        .collect();
+    assert_eq!(timestamps, vec![3000, 2000, 1000]);
+}
+
+#[tokio::test]
+async fn test_stream_last_settlements_by_instrument_stops_at_time_bound() {
+    use futures_util::TryStreamExt;
+
+    let mut server = mockito::Server::new_async().await;
+    let client = create_test_client(&server);
+
+    let mock = server
+        .mock(
+            "GET",
+            "//public/get_last_settlements_by_instrument?instrument_name=BTC-PERPETUAL&count=1000",
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "jsonrpc": "2.0",
+                "result": {
+                    "settlements": [
+                        {"type": "settlement", "timestamp": 3000},
+                        {"type": "settlement", "timestamp": 2000},
+                        {"type": "settlement", "timestamp": 1000}
+                    ],
+                    "continuation": "next"
+                },
+                "id": 1
+            })
+            .to_string(),
+        )
+        .expect(1)
+        .create_async()
+        .await;
+
+    let settlements: Vec<_> = client
+        .stream_last_settlements_by_instrument("BTC-PERPETUAL", None, None, Some(TimestampMs(2000)))
+        .try_collect()
+        .await
+        .unwrap();
+
+    mock.assert_async().await;
+    assert_eq!(settlements.len(), 2);
+}
+
+#[tokio::test]
+async fn test_stream_last_trades_by_instrument_and_time_pages_past_boundary() {
+    use futures_util::TryStreamExt;
+
+    let mut server = mockito::Server::new_async().await;
+    let client = create_test_client(&server);
+    let trade = |id: u64, timestamp: u64| {
+        json!({
+            "trade_id": id.to_string(),
+            "trade_seq": id,
+            "instrument_name": "BTC-PERPETUAL",
+            "timestamp": timestamp,
+            "direction": "buy",
+            "price": 50000.0,
+            "amount": 10.0,
+            "index_price": 50000.0,
+            "mark_price": 50000.0,
+            "tick_direction": 0
+        })
+    };
+    let page = |trades: Vec<serde_json::Value>, has_more: bool| {
+        json!({
+            "jsonrpc": "2.0",
+            "result": {"trades": trades, "has_more": has_more},
+            "id": 1
+        })
+        .to_string()
+    };
+
+    let first_page = server
+        .mock(
+            "GET",
+            "//public/get_last_trades_by_instrument_and_time?instrument_name=BTC-PERPETUAL&start_timestamp=0&end_timestamp=5000&count=2",
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(page(vec![trade(5, 4000), trade(4, 3000)], true))
+        .expect(1)
+        .create_async()
+        .await;
+    // The window end is inclusive, so trade 4 comes back and is skipped
+    let second_page = server
+        .mock(
+            "GET",
+            "//public/get_last_trades_by_instrument_and_time?instrument_name=BTC-PERPETUAL&start_timestamp=0&end_timestamp=3000&count=2",
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(page(vec![trade(4, 3000), trade(3, 3000)], false))
+        .expect(1)
+        .create_async()
+        .await;
+
+    let trades: Vec<_> = client
+        .stream_last_trades_by_instrument_and_time(
+            "BTC-PERPETUAL",
+            (TimestampMs::from_millis(0), TimestampMs::from_millis(5000)),
+            Some(2),
+            None,
+            None,
+        )
+        .try_collect()
+        .await
+        .unwrap();
+
+    first_page.assert_async().await;
+    second_page.assert_async().await;
+    let seqs: Vec<u64> = trades.iter().map(|trade| trade.trade_seq).collect();
+    assert_eq!(seqs, vec![5, 4, 3]);
+}
+
+#[tokio::test]
+async fn test_stream_last_trades_by_instrument_and_time_fails_on_crowded_timestamp() {
+    use futures_util::TryStreamExt;
+
+    let mut server = mockito::Server::new_async().await;
+    let client = create_test_client(&server);
+    let trade = |id: u64, timestamp: u64| {
+        json!({
+            "trade_id": id.to_string(),
+            "trade_seq": id,
+            "instrument_name": "BTC-PERPETUAL",
+            "timestamp": timestamp,
+            "direction": "buy",
+            "price": 50000.0,
+            "amount": 10.0,
+            "index_price": 50000.0,
+            "mark_price": 50000.0,
+            "tick_direction": 0
+        })
+    };
+    let page = |trades: Vec<serde_json::Value>, has_more: bool| {
+        json!({
+            "jsonrpc": "2.0",
+            "result": {"trades": trades, "has_more": has_more},
+            "id": 1
+        })
+        .to_string()
+    };
+
+    let first_page = server
+        .mock(
+            "GET",
+            "//public/get_last_trades_by_instrument_and_time?instrument_name=BTC-PERPETUAL&start_timestamp=0&end_timestamp=5000&count=2",
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(page(vec![trade(5, 4000), trade(4, 3000)], true))
+        .expect(1)
+        .create_async()
+        .await;
+    // Trades 4, 3 and more share 3000, so the window cannot end before it
+    let second_page = server
+        .mock(
+            "GET",
+            "//public/get_last_trades_by_instrument_and_time?instrument_name=BTC-PERPETUAL&start_timestamp=0&end_timestamp=3000&count=2",
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(page(vec![trade(4, 3000), trade(3, 3000)], true))
+        .expect(2)
+        .create_async()
+        .await;
+
+    let result: Result<Vec<_>, _> = client
+        .stream_last_trades_by_instrument_and_time(
+            "BTC-PERPETUAL",
+            (TimestampMs::from_millis(0), TimestampMs::from_millis(5000)),
+            Some(2),
+            None,
+            None,
+        )
+        .try_collect()
+        .await;
+
+    first_page.assert_async().await;
+    second_page.assert_async().await;
+    assert!(matches!(
+        result.map_err(deribit_http::HttpError::into_kind),
+        Err(deribit_http::HttpErrorKind::ConfigError(_))
+    ));
+}
+
+#[tokio::test]
+async fn test_refresh_instruments_fills_the_cached_lookups() {
+    let mut server = mockito::Server::new_async().await;
+    let client = create_test_client(&server);
+
+    let instruments_mock = server
+        .mock("GET", "//public/get_instruments")
+        .match_query(mockito::Matcher::UrlEncoded(
+            "currency".into(),
+            "BTC".into(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "jsonrpc": "2.0",
+                "result": [
+                    {
+                        "instrument_name": "BTC-PERPETUAL",
+                        "tick_size": 0.5,
+                        "contract_size": 10.0,
+                        "min_trade_amount": 10.0
+                    },
+                    {"instrument_name": "BTC-27DEC24", "tick_size": 2.5}
+                ],
+                "id": 1
+            })
+            .to_string(),
+        )
+        .expect(1)
+        .create_async()
+        .await;
+    let instrument_mock = server
+        .mock("GET", "//public/get_instrument")
+        .match_query(mockito::Matcher::Any)
+        .expect(0)
+        .create_async()
+        .await;
+
+    assert_eq!(client.refresh_instruments("BTC").await.unwrap(), 2);
+    assert_eq!(client.cached_tick_size("BTC-PERPETUAL").await.unwrap(), 0.5);
+    assert_eq!(
+        client.cached_contract_size("BTC-PERPETUAL").await.unwrap(),
+        10.0
+    );
+    assert_eq!(
+        client
+            .cached_min_trade_amount("BTC-PERPETUAL")
+            .await
+            .unwrap(),
+        10.0
+    );
+    assert!(matches!(
+        client
+            .cached_contract_size("BTC-27DEC24")
+            .await
+            .map_err(deribit_http::HttpError::into_kind),
+        Err(deribit_http::HttpErrorKind::InvalidResponse(_))
+    ));
+
+    instruments_mock.assert_async().await;
+    instrument_mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_get_options_fetches_every_ticker_in_instrument_order() {
+    let mut server = mockito::Server::new_async().await;
+    let client = create_test_client(&server);
+
+    let strikes: Vec<u64> = (1..=40).map(|i| i * 1000).collect();
+    let mut instruments: Vec<_> = strikes
+        .iter()
+        .map(|strike| json!({ "instrument_name": format!("BTC-27DEC24-{}-C", strike) }))
+        .collect();
+    instruments.push(json!({ "instrument_name": "BTC-28MAR25-50000-C" }));
+    server
+        .mock("GET", "//public/get_instruments")
+        .match_query(mockito::Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({ "jsonrpc": "2.0", "result": instruments, "id": 1 }).to_string())
+        .create_async()
+        .await;
+    let tickers = server
+        .mock("GET", "//public/ticker")
+        .match_query(mockito::Matcher::Regex("BTC-27DEC24".into()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body_from_request(|request| {
+            let name = request
+                .path_and_query()
+                .split("instrument_name=")
+                .nth(1)
+                .unwrap()
+                .to_string();
+            json!({
+                "jsonrpc": "2.0",
+                "result": {
+                    "instrument_name": name,
+                    "best_bid_amount": 1.0,
+                    "best_ask_amount": 1.0,
+                    "mark_price": 0.05,
+                    "timestamp": 1640995200000u64,
+                    "state": "open",
+                    "stats": { "volume": 0.0 }
+                },
+                "id": 1
+            })
+            .to_string()
+            .into()
+        })
+        .expect(strikes.len())
+        .create_async()
+        .await;
+
+    let options = client
+        .get_options("BTC", "27DEC24", OptionFilter::default())
+        .await
+        .unwrap();
+
+    tickers.assert_async().await;
+    assert_eq!(options.len(), strikes.len());
+    for (option, strike) in options.iter().zip(&strikes) {
+        let name = format!("BTC-27DEC24-{}-C", strike);
+        assert_eq!(option.instrument.instrument_name, name);
+        assert_eq!(option.ticker.instrument_name, name);
+    }
+}
+
+#[tokio::test]
+async fn test_get_options_only_fetches_tickers_that_pass_the_filter() {
+    let mut server = mockito::Server::new_async().await;
+    let client = create_test_client(&server);
+
+    let instruments: Vec<_> = [40000, 50000, 60000]
+        .iter()
+        .flat_map(|strike| {
+            ["call", "put"].map(|option_type| {
+                json!({
+                    "instrument_name": format!(
+                        "BTC-27DEC24-{}-{}",
+                        strike,
+                        &option_type[..1].to_uppercase()
+                    ),
+                    "strike": *strike as f64,
+                    "option_type": option_type
+                })
+            })
+        })
+        .collect();
+    server
+        .mock("GET", "//public/get_instruments")
+        .match_query(mockito::Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({ "jsonrpc": "2.0", "result": instruments, "id": 1 }).to_string())
+        .create_async()
+        .await;
+    let index_mock = server
+        .mock("GET", "//public/get_index_price?index_name=btc_usd")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "jsonrpc": "2.0",
+                "result": { "index_price": 50000.0, "estimated_delivery_price": 50000.0 },
+                "id": 1
+            })
+            .to_string(),
+        )
+        .expect(1)
+        .create_async()
+        .await;
+    let ticker_mock = server
+        .mock("GET", "//public/ticker?instrument_name=BTC-27DEC24-50000-C")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "jsonrpc": "2.0",
+                "result": {
+                    "instrument_name": "BTC-27DEC24-50000-C",
+                    "best_bid_amount": 1.0,
+                    "best_ask_amount": 1.0,
+                    "mark_price": 0.05,
+                    "timestamp": 1640995200000u64,
+                    "state": "open",
+                    "stats": { "volume": 0.0 }
+                },
+                "id": 1
+            })
+            .to_string(),
+        )
+        .expect(1)
+        .create_async()
+        .await;
+
+    let filter = OptionFilter::default()
+        .with_option_type(OptionType::Call)
+        .with_moneyness(0.9, 1.1);
+    let options = client.get_options("BTC", "27DEC24", filter).await.unwrap();
+
+    index_mock.assert_async().await;
+    ticker_mock.assert_async().await;
+    assert_eq!(options.len(), 1);
+    assert_eq!(options[0].instrument.strike, Some(50000.0));
+}
+
+fn chart_body(ticks: &[u64]) -> String {
+    let prices: Vec<f64> = ticks.iter().map(|tick| *tick as f64).collect();
+    json!({
+        "jsonrpc": "2.0",
+        "result": {
+            "status": "ok",
+            "ticks": ticks,
+            "open": prices,
+            "high": prices,
+            "low": prices,
+            "close": prices,
+            "volume": prices,
+            "cost": prices
+        },
+        "id": 1
+    })
+    .to_string()
+}
+
+#[tokio::test]
+async fn test_download_candles_splits_the_range_and_drops_boundary_duplicates() {
+    let mut server = mockito::Server::new_async().await;
+    let client = create_test_client(&server);
+
+    // 5000 one-minute candles per request
+    let window = 300_000_000u64;
+    let first = server
+        .mock("GET", "//public/get_tradingview_chart_data")
+        .match_query(mockito::Matcher::AllOf(vec![
+            mockito::Matcher::UrlEncoded("start_timestamp".into(), "0".into()),
+            mockito::Matcher::UrlEncoded("end_timestamp".into(), window.to_string()),
+        ]))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(chart_body(&[0, 60_000, window]))
+        .expect(1)
+        .create_async()
+        .await;
+    let second = server
+        .mock("GET", "//public/get_tradingview_chart_data")
+        .match_query(mockito::Matcher::AllOf(vec![
+            mockito::Matcher::UrlEncoded("start_timestamp".into(), window.to_string()),
+            mockito::Matcher::UrlEncoded("end_timestamp".into(), "400000000".into()),
+        ]))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(chart_body(&[window, window + 60_000]))
+        .expect(1)
+        .create_async()
+        .await;
+
+    let candles = client
+        .download_candles(
+            "BTC-PERPETUAL",
+            (
+                TimestampMs::from_millis(0),
+                TimestampMs::from_millis(400_000_000),
+            ),
+            Resolution::Minute1,
+        )
+        .await
+        .unwrap();
+
+    first.assert_async().await;
+    second.assert_async().await;
+    assert_eq!(candles.status, "ok");
+    assert_eq!(candles.len(), 4);
+    assert_eq!(
+        candles.ticks,
+        vec![
+            TimestampMs(0),
+            TimestampMs(60_000),
+            TimestampMs(window),
+            TimestampMs(window + 60_000)
+        ]
+    );
+    assert_eq!(candles.close[3], (window + 60_000) as f64);
+}
+
+#[tokio::test]
+async fn test_get_all_book_summaries_merges_currencies_and_reports_errors() {
+    let mut server = mockito::Server::new_async().await;
+    let client = create_test_client(&server);
+
+    let currency = |symbol: &str| {
+        json!({
+            "currency": symbol,
+            "currency_long": symbol,
+            "min_confirmations": 1,
+            "min_withdrawal_fee": 0.0001,
+            "disabled_deposit_address_creation": false,
+            "coin_type": "CRYPTO",
+            "fee_precision": 4,
+            "withdrawal_fee": 0.0005,
+            "withdrawal_priorities": []
+        })
+    };
+    server
+        .mock("GET", "//public/get_currencies")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "jsonrpc": "2.0",
+                "result": [currency("BTC"), currency("ETH"), currency("SOL")],
+                "id": 1
+            })
+            .to_string(),
+        )
+        .create_async()
+        .await;
+    let mut summary_mocks = Vec::new();
+    for (symbol, volume_usd) in [("BTC", 45000000.0), ("ETH", 9000000.0)] {
+        let mock = server
+            .mock(
+                "GET",
+                format!("//public/get_book_summary_by_currency?currency={symbol}&kind=future")
+                    .as_str(),
+            )
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({
+                    "jsonrpc": "2.0",
+                    "result": [{
+                        "instrument_name": format!("{symbol}-PERPETUAL"),
+                        "base_currency": symbol,
+                        "quote_currency": "USD",
+                        "volume": 1000.0,
+                        "volume_usd": volume_usd,
+                        "open_interest": 500.0,
+                        "mark_price": 45000.0,
+                        "creation_timestamp": 1640995200000u64
+                    }],
+                    "id": 1
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+        summary_mocks.push(mock);
+    }
+    server
+        .mock(
+            "GET",
+            "//public/get_book_summary_by_currency?currency=SOL&kind=future",
+        )
+        .with_status(400)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "jsonrpc": "2.0",
+                "error": { "code": -32602, "message": "Invalid params" },
+                "id": 1
+            })
+            .to_string(),
+        )
+        .create_async()
+        .await;
+
+    let scan = client
+        .get_all_book_summaries(Some(InstrumentKind::Future))
+        .await
+        .unwrap();
+
+    for mock in summary_mocks {
+        mock.assert_async().await;
+    }
+    let instruments: Vec<&str> = scan
+        .summaries
+        .summaries
+        .iter()
+        .map(|summary| summary.instrument_name.as_str())
+        .collect();
+    assert_eq!(instruments, vec!["BTC-PERPETUAL", "ETH-PERPETUAL"]);
+    assert!(!scan.is_complete());
+    assert_eq!(scan.errors.len(), 1);
+    assert_eq!(scan.errors[0].0, "SOL");
+}
@@ -0,0 +1,141 @@
+use deribit_http::rate_limit::RateLimitCategory;
+use deribit_http::testing::rate_limit::is_matching_engine;
+use deribit_http::testing::{CreditLimits, RateLimitSimulation};
+use deribit_http::{DeribitHttpClient, HttpConfig, HttpError, HttpErrorKind};
+use mockito::Matcher;
+use serde_json::json;
+use std::time::Duration;
+use url::Url;
+
+fn create_public_client(
+    server: &mockito::ServerGuard,
+    simulation: RateLimitSimulation,
+) -> DeribitHttpClient {
+    let config = HttpConfig {
+        base_url: Url::parse(&server.url()).unwrap(),
+        ..Default::default()
+    };
+    DeribitHttpClient::with_config(config).with_rate_limit_simulation(simulation)
+}
+
+async fn create_time_mock(server: &mut mockito::Server) -> mockito::Mock {
+    server
+        .mock("GET", "//public/get_time")
+        .match_query(Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({"jsonrpc": "2.0", "id": 1, "result": 1700000000000u64}).to_string())
+        .create_async()
+        .await
+}
+
+fn small_pool(refill_per_sec: u64) -> RateLimitSimulation {
+    RateLimitSimulation {
+        non_matching_engine: CreditLimits {
+            max_credits: 1_000,
+            refill_per_sec,
+            cost: 500,
+        },
+        ..Default::default()
+    }
+}
+
+#[cfg(test)]
+mod credit_simulation_tests {
+    use super::*;
+
+    #[test]
+    fn test_default_matches_deribit_limits() {
+        let simulation = RateLimitSimulation::default();
+        let burst =
+            simulation.non_matching_engine.max_credits / simulation.non_matching_engine.cost;
+        let rate =
+            simulation.non_matching_engine.refill_per_sec / simulation.non_matching_engine.cost;
+        assert_eq!((burst, rate), (100, 20));
+        assert_eq!(
+            simulation.matching_engine.max_credits / simulation.matching_engine.cost,
+            20
+        );
+    }
+
+    #[test]
+    fn test_matching_engine_classification() {
+        assert!(is_matching_engine(
+            "https://test.deribit.com/api/v2/private/buy?amount=10"
+        ));
+        assert!(is_matching_engine("/private/cancel_all_by_currency"));
+        assert!(!is_matching_engine("/private/get_open_orders"));
+        assert!(!is_matching_engine("/public/get_time"));
+    }
+
+    #[tokio::test]
+    async fn test_exhausted_pool_returns_too_many_requests() {
+        let mut server = mockito::Server::new_async().await;
+        let _mock = create_time_mock(&mut server).await;
+        let client = create_public_client(&server, small_pool(1));
+
+        assert!(client.get_server_time().await.is_ok());
+        assert!(client.get_server_time().await.is_ok());
+        match client.get_server_time().await.map_err(HttpError::into_kind) {
+            Err(HttpErrorKind::RateLimitExceeded { .. }) => {}
+            other => panic!("expected rate limit error, got {:?}", other),
+        }
+        assert_eq!(client.rate_limit_rejections(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_pool_refills_over_time() {
+        let mut server = mockito::Server::new_async().await;
+        let _mock = create_time_mock(&mut server).await;
+        let client = create_public_client(&server, small_pool(10_000));
+
+        assert!(client.get_server_time().await.is_ok());
+        assert!(client.get_server_time().await.is_ok());
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        assert!(client.get_server_time().await.is_ok());
+        assert_eq!(client.rate_limit_rejections(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_clones_share_credit_pools() {
+        let mut server = mockito::Server::new_async().await;
+        let _mock = create_time_mock(&mut server).await;
+        let client = create_public_client(&server, small_pool(1));
+        let clone = client.clone();
+
+        assert!(client.get_server_time().await.is_ok());
+        assert!(clone.get_server_time().await.is_ok());
+        assert!(client.get_server_time().await.is_err());
+        assert_eq!(clone.rate_limit_rejections(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_client_credit_limits_pace_requests_below_server_limit() {
+        let mut server = mockito::Server::new_async().await;
+        let _mock = create_time_mock(&mut server).await;
+        let simulation = small_pool(10_000);
+        let client = create_public_client(&server, simulation)
+            .with_credit_limits(simulation.non_matching_engine, simulation.matching_engine);
+
+        let started = std::time::Instant::now();
+        for _ in 0..4 {
+            assert!(client.get_server_time().await.is_ok());
+        }
+
+        // Two requests fit the pool; the other two wait 50ms each for refills
+        assert!(started.elapsed() >= Duration::from_millis(90));
+        assert_eq!(client.rate_limit_rejections(), 0);
+        assert_eq!(
+            client
+                .credit_refill_rate(RateLimitCategory::NonMatchingEngine)
+                .await,
+            10_000
+        );
+        assert!(
+            client
+                .remaining_credits(RateLimitCategory::NonMatchingEngine)
+                .await
+                < 500
+        );
+    }
+}
@@ -1,3 +1,4 @@
+use deribit_http::model::TimestampMs;
 use deribit_http::model::fee::FeeStructure;
 
 use deribit_http::model::response::other::*;
@@ -17,7 +18,7 @@ fn create_mock_last_trade() -> LastTrade {
         liquid: Some("T".to_string()),
         price: 50000.0,
         tick_direction: 1,
-        timestamp: 1234567890,
+        timestamp: TimestampMs(1234567890),
         trade_id: "trade_123".to_string(),
         trade_seq: 123456,
     }
@@ -27,7 +28,7 @@ fn create_mock_settlement() -> Settlement {
     use deribit_http::model::settlement::SettlementType;
     Settlement {
         settlement_type: SettlementType::Settlement,
-        timestamp: 1640995200000,
+        timestamp: TimestampMs(1640995200000),
         instrument_name: Some("BTC-PERPETUAL".to_string()),
         position_size: Some(1.5),
         mark_price: Some(50000.0),
@@ -49,7 +50,7 @@ fn create_mock_transaction_log_entry() -> TransactionLogEntry {
         currency: "BTC".to_string(),
         amount: Some(100.0),
         balance: 1095.0,
-        timestamp: 1640995200000,
+        timestamp: TimestampMs(1640995200000),
         transaction_type: "deposit".to_string(),
         info: Some(serde_json::json!({"test": "data"})),
         change: 100.0,
@@ -186,7 +187,7 @@ fn create_mock_account_info() -> AccountInfo {
         system_name: Some("user_12345".to_string()),
         username: Some("testuser".to_string()),
         block_rfq_self_match_prevention: Some(false),
-        creation_timestamp: Some(1640995200000),
+        creation_timestamp: Some(TimestampMs(1640995200000)),
         account_type: Some("main".to_string()),
         referrer_id: Some("ref_123".to_string()),
         login_enabled: Some(true),
@@ -205,7 +206,7 @@ fn create_mock_account_summary_response() -> AccountSummaryResponse {
         system_name: "user_12345".to_string(),
         username: "testuser".to_string(),
         block_rfq_self_match_prevention: false,
-        creation_timestamp: 1640995200000,
+        creation_timestamp: TimestampMs(1640995200000),
         account_type: "main".to_string(),
         referrer_id: Some("ref_123".to_string()),
         login_enabled: true,
@@ -300,7 +301,10 @@ fn test_settlements_response_with_continuation() {
     let continuation = "next_page_token".to_string();
     let response = SettlementsResponse::with_continuation(settlements, continuation.clone());
 
-    assert_eq!(response.continuation, Some(continuation));
+    assert_eq!(
+        response.continuation.as_deref(),
+        Some(continuation.as_str())
+    );
     assert_eq!(response.settlements.len(), 1);
     assert!(response.has_more());
 }
@@ -629,11 +633,11 @@ fn test_account_limits_serialization() {
 #[test]
 fn test_mark_price_history_point_creation() {
     let point = MarkPriceHistoryPoint {
-        timestamp: 1608142381229,
+        timestamp: TimestampMs(1608142381229),
         mark_price: 0.5165791606037885,
     };
 
-    assert_eq!(point.timestamp, 1608142381229);
+    assert_eq!(point.timestamp.as_millis(), 1608142381229);
     assert!((point.mark_price - 0.5165791606037885).abs() < f64::EPSILON);
 }
 
@@ -642,14 +646,14 @@ fn test_mark_price_history_point_from_tuple() {
     let tuple: (u64, f64) = (1608142381229, 0.5165791606037885);
     let point = MarkPriceHistoryPoint::from(tuple);
 
-    assert_eq!(point.timestamp, 1608142381229);
+    assert_eq!(point.timestamp.as_millis(), 1608142381229);
     assert!((point.mark_price - 0.5165791606037885).abs() < f64::EPSILON);
 }
 
 #[test]
 fn test_mark_price_history_point_into_tuple() {
     let point = MarkPriceHistoryPoint {
-        timestamp: 1608142381229,
+        timestamp: TimestampMs(1608142381229),
         mark_price: 0.5165791606037885,
     };
     let tuple: (u64, f64) = point.into();
@@ -663,14 +667,14 @@ fn test_mark_price_history_point_deserialization_from_array() {
     let json = "[1608142381229, 0.5165791606037885]";
     let point: MarkPriceHistoryPoint = serde_json::from_str(json).unwrap();
 
-    assert_eq!(point.timestamp, 1608142381229);
+    assert_eq!(point.timestamp.as_millis(), 1608142381229);
     assert!((point.mark_price - 0.5165791606037885).abs() < f64::EPSILON);
 }
 
 #[test]
 fn test_mark_price_history_point_serialization_to_array() {
     let point = MarkPriceHistoryPoint {
-        timestamp: 1608142381229,
+        timestamp: TimestampMs(1608142381229),
         mark_price: 0.5165791606037885,
     };
     let serialized = serde_json::to_string(&point).unwrap();
@@ -689,9 +693,9 @@ fn test_mark_price_history_point_vec_deserialization() {
     let points: Vec<MarkPriceHistoryPoint> = serde_json::from_str(json).unwrap();
 
     assert_eq!(points.len(), 3);
-    assert_eq!(points[0].timestamp, 1608142381229);
-    assert_eq!(points[1].timestamp, 1608142380231);
-    assert_eq!(points[2].timestamp, 1608142379227);
+    assert_eq!(points[0].timestamp.as_millis(), 1608142381229);
+    assert_eq!(points[1].timestamp.as_millis(), 1608142380231);
+    assert_eq!(points[2].timestamp.as_millis(), 1608142379227);
 }
 
 #[test]
@@ -705,7 +709,7 @@ fn test_mark_price_history_point_empty_vec_deserialization() {
 #[test]
 fn test_mark_price_history_point_clone() {
     let point = MarkPriceHistoryPoint {
-        timestamp: 1608142381229,
+        timestamp: TimestampMs(1608142381229),
         mark_price: 0.5165791606037885,
     };
     let cloned = point.clone();
@@ -717,11 +721,11 @@ fn test_mark_price_history_point_clone() {
 #[test]
 fn test_mark_price_history_point_equality() {
     let point1 = MarkPriceHistoryPoint {
-        timestamp: 1608142381229,
+        timestamp: TimestampMs(1608142381229),
         mark_price: 0.5165791606037885,
     };
     let point2 = MarkPriceHistoryPoint {
-        timestamp: 1608142381229,
+        timestamp: TimestampMs(1608142381229),
         mark_price: 0.5165791606037885,
     };
 
@@ -1071,14 +1075,14 @@ fn test_trade_volume_equality() {
 #[test]
 fn test_volatility_index_candle_creation() {
     let candle = VolatilityIndexCandle {
-        timestamp: 1598019300000,
+        timestamp: TimestampMs(1598019300000),
         open: 0.210084879,
         high: 0.212860821,
         low: 0.210084879,
         close: 0.212860821,
     };
 
-    assert_eq!(candle.timestamp, 1598019300000);
+    assert_eq!(candle.timestamp.as_millis(), 1598019300000);
     assert!((candle.open - 0.210084879).abs() < 1e-9);
     assert!((candle.close - 0.212860821).abs() < 1e-9);
 }
@@ -1095,7 +1099,7 @@ fn test_volatility_index_data_deserialization() {
 
     let data: VolatilityIndexData = serde_json::from_str(json).unwrap();
     assert_eq!(data.data.len(), 2);
-    assert_eq!(data.data[0].timestamp, 1598019300000);
+    assert_eq!(data.data[0].timestamp.as_millis(), 1598019300000);
     assert!((data.data[0].open - 0.210084879).abs() < 1e-9);
     assert!(data.continuation.is_none());
 }
@@ -1129,7 +1133,7 @@ fn test_volatility_index_data_empty() {
 #[test]
 fn test_volatility_index_candle_clone() {
     let candle = VolatilityIndexCandle {
-        timestamp: 1598019300000,
+        timestamp: TimestampMs(1598019300000),
         open: 0.21,
         high: 0.22,
         low: 0.20,
@@ -1145,7 +1149,7 @@ fn test_volatility_index_candle_clone() {
 fn test_volatility_index_data_clone() {
     let data = VolatilityIndexData {
         data: vec![VolatilityIndexCandle {
-            timestamp: 1598019300000,
+            timestamp: TimestampMs(1598019300000),
             open: 0.21,
             high: 0.22,
             low: 0.20,
@@ -1162,14 +1166,14 @@ fn test_volatility_index_data_clone() {
 #[test]
 fn test_volatility_index_candle_equality() {
     let candle1 = VolatilityIndexCandle {
-        timestamp: 1598019300000,
+        timestamp: TimestampMs(1598019300000),
         open: 0.21,
         high: 0.22,
         low: 0.20,
         close: 0.215,
     };
     let candle2 = VolatilityIndexCandle {
-        timestamp: 1598019300000,
+        timestamp: TimestampMs(1598019300000),
         open: 0.21,
         high: 0.22,
         low: 0.20,
@@ -1183,7 +1187,7 @@ fn test_volatility_index_candle_equality() {
 fn test_volatility_index_data_equality() {
     let data1 = VolatilityIndexData {
         data: vec![VolatilityIndexCandle {
-            timestamp: 1598019300000,
+            timestamp: TimestampMs(1598019300000),
             open: 0.21,
             high: 0.22,
             low: 0.20,
@@ -1193,7 +1197,7 @@ fn test_volatility_index_data_equality() {
     };
     let data2 = VolatilityIndexData {
         data: vec![VolatilityIndexCandle {
-            timestamp: 1598019300000,
+            timestamp: TimestampMs(1598019300000),
             open: 0.21,
             high: 0.22,
             low: 0.20,
@@ -1204,3 +1208,23 @@ fn test_volatility_index_data_equality() {
 
     assert_eq!(data1, data2);
 }
+
+#[test]
+fn test_status_response_lock_states() {
+    let locked: StatusResponse =
+        serde_json::from_str(r#"{"locked": "true", "locked_indices": []}"#).unwrap();
+    assert_eq!(locked.locked, Some(true));
+
+    let partial: StatusResponse =
+        serde_json::from_str(r#"{"locked": "partial", "locked_indices": ["btc_usd"]}"#).unwrap();
+    assert_eq!(partial.locked, Some(false));
+    assert_eq!(partial.locked_indices, Some(vec!["btc_usd".to_string()]));
+
+    let boolean: StatusResponse = serde_json::from_str(r#"{"locked": false}"#).unwrap();
+    assert_eq!(boolean.locked, Some(false));
+
+    let missing: StatusResponse = serde_json::from_str("{}").unwrap();
+    assert_eq!(missing.locked, None);
+
+    assert!(serde_json::from_str::<StatusResponse>(r#"{"locked": "maybe"}"#).is_err());
+}
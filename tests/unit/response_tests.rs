@@ -66,12 +66,11 @@ mod response_handler_tests {
 
         let result: Result<ApiResponse<TestData>, HttpError> = handler.parse_response(&response);
         assert!(result.is_err());
-        match result.unwrap_err() {
-            HttpError::RequestFailed(msg) => {
-                assert!(msg.contains("HTTP 404"));
+        match result.unwrap_err().into_kind() {
+            HttpErrorKind::NotFound(msg) => {
                 assert!(msg.contains("Not Found"));
             }
-            _ => panic!("Expected RequestFailed error"),
+            _ => panic!("Expected NotFound error"),
         }
     }
 
@@ -86,8 +85,8 @@ mod response_handler_tests {
 
         let result: Result<ApiResponse<TestData>, HttpError> = handler.parse_response(&response);
         assert!(result.is_err());
-        match result.unwrap_err() {
-            HttpError::InvalidResponse(_) => {}
+        match result.unwrap_err().into_kind() {
+            HttpErrorKind::InvalidResponse(_) => {}
             _ => panic!("Expected InvalidResponse error"),
         }
     }
@@ -259,8 +258,8 @@ mod response_handler_tests {
 
         let result = handler.handle_rate_limit(&response);
         assert!(result.is_err());
-        match result.unwrap_err() {
-            HttpError::RateLimitExceeded => {}
+        match result.unwrap_err().into_kind() {
+            HttpErrorKind::RateLimitExceeded { retry_after: None } => {}
             _ => panic!("Expected RateLimitExceeded error"),
         }
     }
@@ -289,8 +288,8 @@ mod response_handler_tests {
 
         let result = handler.handle_auth_error(&response);
         assert!(result.is_err());
-        match result.unwrap_err() {
-            HttpError::AuthenticationFailed(msg) => {
+        match result.unwrap_err().into_kind() {
+            HttpErrorKind::AuthenticationFailed(msg) => {
                 assert!(msg.contains("Authentication failed"));
             }
             _ => panic!("Expected AuthenticationFailed error"),
@@ -308,8 +307,8 @@ mod response_handler_tests {
 
         let result = handler.handle_auth_error(&response);
         assert!(result.is_err());
-        match result.unwrap_err() {
-            HttpError::AuthenticationFailed(msg) => {
+        match result.unwrap_err().into_kind() {
+            HttpErrorKind::AuthenticationFailed(msg) => {
                 assert!(msg.contains("Authentication failed"));
             }
             _ => panic!("Expected AuthenticationFailed error"),
@@ -328,3 +327,219 @@ mod response_handler_tests {
         // Test passes if clone and serialization complete without error
     }
 }
+
+#[cfg(test)]
+mod error_body_tests {
+    use deribit_http::prelude::*;
+    use deribit_http::{HttpConfig, HttpErrorKind};
+    use url::Url;
+
+    const ERROR_BODY: &str = r#"{"jsonrpc": "2.0", "id": 1, "error": {"code": 10004, "message": "order_not_found", "data": {"param": "order_id"}}}"#;
+
+    #[test]
+    fn test_error_for_status_parses_deribit_error() {
+        let error = HttpResponseHandler::new().error_for_status(400, None, ERROR_BODY);
+
+        match error.into_kind() {
+            HttpErrorKind::Api {
+                code,
+                message,
+                data,
+            } => {
+                assert_eq!(code, 10004);
+                assert_eq!(message, "order_not_found");
+                assert_eq!(data.unwrap()["param"], "order_id");
+            }
+            other => panic!("expected Api error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_error_for_status_falls_back_to_status_and_text() {
+        let error =
+            HttpResponseHandler::new().error_for_status(400, None, "<html>Bad Request</html>");
+
+        match error.into_kind() {
+            HttpErrorKind::RequestFailed(message) => {
+                assert_eq!(message, "HTTP 400 - <html>Bad Request</html>");
+            }
+            other => panic!("expected RequestFailed, got {:?}", other),
+        }
+    }
+
+    async fn ticker_error(status: usize) -> HttpErrorKind {
+        let mut server = mockito::Server::new_async().await;
+        let _mock = server
+            .mock("GET", "//public/ticker")
+            .match_query(mockito::Matcher::Any)
+            .with_status(status)
+            .with_header("content-type", "application/json")
+            .with_body(ERROR_BODY)
+            .create_async()
+            .await;
+        let config = HttpConfig {
+            base_url: Url::parse(&server.url()).unwrap(),
+            ..Default::default()
+        };
+        DeribitHttpClient::with_config(config)
+            .get_ticker("BTC-PERPETUAL")
+            .await
+            .unwrap_err()
+            .into_kind()
+    }
+
+    #[tokio::test]
+    async fn test_non_2xx_and_200_errors_are_identical() {
+        let from_400 = ticker_error(400).await;
+        let from_200 = ticker_error(200).await;
+
+        assert_eq!(from_400.to_string(), from_200.to_string());
+        assert!(matches!(from_400, HttpErrorKind::Api { code: 10004, .. }));
+    }
+}
+
+#[cfg(test)]
+mod status_mapping_tests {
+    use deribit_http::prelude::*;
+    use deribit_http::{HttpConfig, HttpError, HttpErrorKind};
+    use std::collections::HashMap;
+    use std::time::Duration;
+    use url::Url;
+
+    const AUTH_ERROR_BODY: &str =
+        r#"{"jsonrpc": "2.0", "error": {"code": 13009, "message": "unauthorized"}}"#;
+
+    fn map(status: u16, body: &str) -> HttpError {
+        HttpResponseHandler::new().error_for_status(status, None, body)
+    }
+
+    #[test]
+    fn test_401_and_403_map_to_authentication_failed() {
+        for status in [401, 403] {
+            match map(status, AUTH_ERROR_BODY).into_kind() {
+                HttpErrorKind::AuthenticationFailed(message) => {
+                    assert_eq!(message, "unauthorized (13009)");
+                }
+                other => panic!("{}: expected AuthenticationFailed, got {:?}", status, other),
+            }
+        }
+        assert!(
+            matches!(map(401, "Unauthorized").into_kind(), HttpErrorKind::AuthenticationFailed(m) if m == "Unauthorized")
+        );
+    }
+
+    #[test]
+    fn test_status_mapped_errors_keep_api_code() {
+        let error = map(401, AUTH_ERROR_BODY);
+        assert!(matches!(
+            error.kind(),
+            HttpErrorKind::AuthenticationFailed(_)
+        ));
+        assert_eq!(error.api_code(), Some(13009));
+        assert_eq!(error.error_code(), Some(DeribitErrorCode::Unauthorized));
+        let api_error = error.context().api_error.as_ref().unwrap();
+        assert_eq!(api_error.message, "unauthorized");
+
+        let body =
+            r#"{"jsonrpc": "2.0", "error": {"code": 10028, "message": "too_many_requests"}}"#;
+        let error = map(503, body);
+        assert!(matches!(error.kind(), HttpErrorKind::ServerError { .. }));
+        assert_eq!(error.error_code(), Some(DeribitErrorCode::TooManyRequests));
+
+        assert_eq!(map(401, "Unauthorized").api_code(), None);
+    }
+
+    #[test]
+    fn test_404_maps_to_not_found() {
+        assert!(
+            matches!(map(404, "Not Found").into_kind(), HttpErrorKind::NotFound(m) if m == "Not Found")
+        );
+    }
+
+    #[test]
+    fn test_429_maps_to_rate_limit_with_hint() {
+        let error = HttpResponseHandler::new().error_for_status(
+            429,
+            Some(Duration::from_secs(2)),
+            "Too Many Requests",
+        );
+        assert!(matches!(
+            error.kind(),
+            HttpErrorKind::RateLimitExceeded { retry_after: Some(d) } if *d == Duration::from_secs(2)
+        ));
+    }
+
+    #[test]
+    fn test_5xx_maps_to_server_error() {
+        for status in [500, 502, 503, 504] {
+            match map(status, "upstream unavailable").into_kind() {
+                HttpErrorKind::ServerError { status: s, message } => {
+                    assert_eq!(s, status);
+                    assert_eq!(message, "upstream unavailable");
+                }
+                other => panic!("{}: expected ServerError, got {:?}", status, other),
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_retry_after() {
+        assert_eq!(
+            HttpResponseHandler::parse_retry_after("3"),
+            Some(Duration::from_secs(3))
+        );
+        assert_eq!(
+            HttpResponseHandler::parse_retry_after(" 0.5 "),
+            Some(Duration::from_millis(500))
+        );
+        assert_eq!(HttpResponseHandler::parse_retry_after("-1"), None);
+        assert_eq!(
+            HttpResponseHandler::parse_retry_after("Wed, 21 Oct 2015 07:28:00 GMT"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_parse_response_reads_retry_after_header() {
+        let response = HttpResponse {
+            status: 429,
+            headers: HashMap::from([("Retry-After".to_string(), "7".to_string())]),
+            body: String::new(),
+        };
+
+        let result: Result<ApiResponse<serde_json::Value>, HttpError> =
+            HttpResponseHandler::new().parse_response(&response);
+
+        assert!(matches!(
+            result.map_err(HttpError::into_kind),
+            Err(HttpErrorKind::RateLimitExceeded { retry_after: Some(d) }) if d == Duration::from_secs(7)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_client_maps_429_with_retry_after() {
+        let mut server = mockito::Server::new_async().await;
+        let _mock = server
+            .mock("GET", "//public/get_time")
+            .with_status(429)
+            .with_header("retry-after", "1")
+            .with_body("Too Many Requests")
+            .create_async()
+            .await;
+        let config = HttpConfig {
+            base_url: Url::parse(&server.url()).unwrap(),
+            ..Default::default()
+        }
+        .with_max_retries(0);
+
+        let result = DeribitHttpClient::with_config(config)
+            .get_server_time()
+            .await
+            .map_err(HttpError::into_kind);
+
+        assert!(matches!(
+            result,
+            Err(HttpErrorKind::RateLimitExceeded { retry_after: Some(d) }) if d == Duration::from_secs(1)
+        ));
+    }
+}
@@ -0,0 +1,313 @@
+use deribit_http::{DeribitHttpClient, HttpConfig, HttpError, HttpErrorKind};
+use std::env;
+use std::fmt::Write;
+use std::sync::{Arc, Mutex};
+use tracing::field::{Field, Visit};
+use tracing::{Event, Subscriber};
+use tracing_subscriber::layer::{Context, Layer, SubscriberExt};
+use url::Url;
+
+const TIME_BODY: &str = r#"{"jsonrpc": "2.0", "id": 1, "result": 1700000000000}"#;
+
+/// Records the fields of retry events
+#[derive(Clone, Default)]
+struct RetryEvents(Arc<Mutex<Vec<String>>>);
+
+struct FieldWriter(String);
+
+impl Visit for FieldWriter {
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        let _ = write!(self.0, "{}={:?} ", field.name(), value);
+    }
+}
+
+impl<S: Subscriber> Layer<S> for RetryEvents {
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        if event.metadata().target() == "deribit_http::retry" {
+            let mut writer = FieldWriter(String::new());
+            event.record(&mut writer);
+            self.0.lock().unwrap().push(writer.0);
+        }
+    }
+}
+
+fn create_client(server: &mockito::ServerGuard, max_retries: u32) -> DeribitHttpClient {
+    unsafe {
+        env::set_var("DERIBIT_CLIENT_ID", "test_client_id");
+        env::set_var("DERIBIT_CLIENT_SECRET", "test_client_secret");
+    }
+    let config = HttpConfig {
+        base_url: Url::parse(&format!("{}/api/v2", server.url())).unwrap(),
+        ..Default::default()
+    }
+    .with_max_retries(max_retries);
+    DeribitHttpClient::with_config(config)
+}
+
+async fn create_status_mock(
+    server: &mut mockito::Server,
+    path: &str,
+    status: usize,
+    hits: usize,
+) -> mockito::Mock {
+    let method = if path.contains("/private/") {
+        "POST"
+    } else {
+        "GET"
+    };
+    server
+        .mock(method, path)
+        .match_query(mockito::Matcher::Any)
+        .with_status(status)
+        .with_header("content-type", "application/json")
+        .with_body(if status == 200 {
+            TIME_BODY
+        } else {
+            "Service Unavailable"
+        })
+        .expect(hits)
+        .create_async()
+        .await
+}
+
+async fn create_auth_mock(server: &mut mockito::Server) -> mockito::Mock {
+    server
+        .mock("GET", "/api/v2/public/auth")
+        .match_query(mockito::Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            r#"{"jsonrpc": "2.0", "id": 1, "result": {"access_token": "t", "expires_in": 3600,
+            "refresh_token": "r", "scope": "trade:read_write", "token_type": "bearer"}}"#,
+        )
+        .create_async()
+        .await
+}
+
+#[cfg(test)]
+mod retry_policy_tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_public_request_retries_server_errors() {
+        let mut server = mockito::Server::new_async().await;
+        let failing = create_status_mock(&mut server, "/api/v2/public/get_time", 503, 2).await;
+        let ok = create_status_mock(&mut server, "/api/v2/public/get_time", 200, 1).await;
+        let client = create_client(&server, 3);
+        let events = RetryEvents::default();
+        let _guard =
+            tracing::subscriber::set_default(tracing_subscriber::registry().with(events.clone()));
+
+        assert_eq!(client.get_server_time().await.unwrap(), 1700000000000);
+
+        failing.assert_async().await;
+        ok.assert_async().await;
+        let events = events.0.lock().unwrap();
+        assert_eq!(events.len(), 1);
+        assert!(events[0].contains("attempts=3"), "{}", events[0]);
+        assert!(events[0].contains("HTTP 503"), "{}", events[0]);
+    }
+
+    #[tokio::test]
+    async fn test_retries_are_bounded_by_max_retries() {
+        let mut server = mockito::Server::new_async().await;
+        let failing = create_status_mock(&mut server, "/api/v2/public/get_time", 500, 3).await;
+        let client = create_client(&server, 2);
+
+        let result = client.get_server_time().await.map_err(HttpError::into_kind);
+
+        assert!(matches!(
+            result,
+            Err(HttpErrorKind::ServerError { status: 500, .. })
+        ));
+        failing.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_zero_max_retries_disables_retry() {
+        let mut server = mockito::Server::new_async().await;
+        let failing = create_status_mock(&mut server, "/api/v2/public/get_time", 503, 1).await;
+        let client = create_client(&server, 0);
+
+        assert!(client.get_server_time().await.is_err());
+        failing.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_private_request_is_not_retried_after_reaching_server() {
+        let mut server = mockito::Server::new_async().await;
+        let _auth = create_auth_mock(&mut server).await;
+        let cancel = create_status_mock(&mut server, "/api/v2/private/cancel_all", 503, 1).await;
+        let client = create_client(&server, 3);
+
+        assert!(client.cancel_all().await.is_err());
+        cancel.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_rate_limited_private_request_waits_for_retry_after() {
+        let mut server = mockito::Server::new_async().await;
+        let _auth = create_auth_mock(&mut server).await;
+        let limited = server
+            .mock("POST", "/api/v2/private/cancel_all")
+            .with_status(429)
+            .with_header("retry-after", "0.2")
+            .with_body("Too Many Requests")
+            .expect(1)
+            .create_async()
+            .await;
+        let ok = server
+            .mock("POST", "/api/v2/private/cancel_all")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"jsonrpc": "2.0", "id": 1, "result": 3}"#)
+            .expect(1)
+            .create_async()
+            .await;
+        let client = create_client(&server, 3);
+
+        let started = std::time::Instant::now();
+        assert_eq!(client.cancel_all().await.unwrap(), 3);
+
+        assert!(started.elapsed() >= std::time::Duration::from_millis(200));
+        limited.assert_async().await;
+        ok.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_rate_limit_error_surfaces_retry_after() {
+        let mut server = mockito::Server::new_async().await;
+        let _limited = server
+            .mock("GET", "/api/v2/public/get_time")
+            .with_status(429)
+            .with_header("retry-after", "0.05")
+            .with_body("Too Many Requests")
+            .create_async()
+            .await;
+        let client = create_client(&server, 0);
+
+        match client.get_server_time().await.map_err(HttpError::into_kind) {
+            Err(HttpErrorKind::RateLimitExceeded { retry_after }) => {
+                assert_eq!(retry_after, Some(std::time::Duration::from_millis(50)))
+            }
+            other => panic!("expected rate limit error, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_too_many_requests_error_code_is_retried_after_cool_down() {
+        let mut server = mockito::Server::new_async().await;
+        let _auth = create_auth_mock(&mut server).await;
+        let limited = server
+            .mock("POST", "/api/v2/private/cancel_all")
+            .with_status(400)
+            .with_header("content-type", "application/json")
+            .with_header("retry-after", "0.2")
+            .with_body(
+                r#"{"jsonrpc": "2.0", "id": 1, "error": {"code": 10028, "message": "too_many_requests"}}"#,
+            )
+            .expect(1)
+            .create_async()
+            .await;
+        let ok = server
+            .mock("POST", "/api/v2/private/cancel_all")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"jsonrpc": "2.0", "id": 2, "result": 3}"#)
+            .expect(1)
+            .create_async()
+            .await;
+        let client = create_client(&server, 3);
+
+        let started = std::time::Instant::now();
+        assert_eq!(client.cancel_all().await.unwrap(), 3);
+
+        assert!(started.elapsed() >= std::time::Duration::from_millis(200));
+        limited.assert_async().await;
+        ok.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_too_many_requests_error_code_maps_to_rate_limit() {
+        let mut server = mockito::Server::new_async().await;
+        let _limited = server
+            .mock("GET", "/api/v2/public/get_time")
+            .with_status(400)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"{"jsonrpc": "2.0", "id": 1, "error": {"code": 10028, "message": "too_many_requests"}}"#,
+            )
+            .create_async()
+            .await;
+        let client = create_client(&server, 0);
+
+        assert!(matches!(
+            client.get_server_time().await.map_err(HttpError::into_kind),
+            Err(HttpErrorKind::RateLimitExceeded { retry_after: None })
+        ));
+    }
+}
+
+#[cfg(test)]
+mod request_options_tests {
+    use super::*;
+    use deribit_http::config::RequestOptions;
+    use deribit_http::interceptor::HookFuture;
+    use deribit_http::transport::{HttpTransport, TransportError, json_response};
+    use std::time::Duration;
+
+    /// Transport recording the timeout of every request it sends
+    #[derive(Default)]
+    struct Timeouts(Mutex<Vec<Option<Duration>>>);
+
+    impl HttpTransport for Timeouts {
+        fn send(
+            &self,
+            request: reqwest::Request,
+        ) -> HookFuture<'_, Result<reqwest::Response, TransportError>> {
+            self.0.lock().unwrap().push(request.timeout().copied());
+            Box::pin(async { Ok(json_response(200, TIME_BODY)) })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_options_override_max_retries_for_one_copy() {
+        let mut server = mockito::Server::new_async().await;
+        let client = create_client(&server, 3);
+
+        let once = create_status_mock(&mut server, "/api/v2/public/get_time", 503, 1).await;
+        let quoting = client.with_options(RequestOptions::new().with_max_retries(0));
+        assert!(quoting.get_server_time().await.is_err());
+        once.assert_async().await;
+        once.remove_async().await;
+
+        let retried = create_status_mock(&mut server, "/api/v2/public/get_time", 503, 4).await;
+        assert!(client.get_server_time().await.is_err());
+        retried.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_options_set_the_timeout_of_each_request() {
+        let timeouts = Arc::new(Timeouts::default());
+        let client = DeribitHttpClient::builder()
+            .transport(timeouts.clone())
+            .build()
+            .unwrap();
+
+        client.get_server_time().await.unwrap();
+        client
+            .with_options(RequestOptions::new().with_timeout(Duration::from_millis(250)))
+            .get_server_time()
+            .await
+            .unwrap();
+
+        assert_eq!(
+            *timeouts.0.lock().unwrap(),
+            vec![
+                Some(client.config().timeout),
+                Some(Duration::from_millis(250))
+            ]
+        );
+        assert_eq!(client.options(), &RequestOptions::default());
+    }
+}
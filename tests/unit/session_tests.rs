@@ -19,6 +19,13 @@ async fn test_http_session_new() {
         max_retries: 3,
         testnet: true,
         credentials: None,
+        credentials_provider: None,
+        debug_raw_responses: false,
+        price_normalization: None,
+        transport: None,
+        proxy: None,
+        default_headers: Default::default(),
+        pool: Default::default(),
     };
 
     let session = HttpSession::new(config.clone());
@@ -38,6 +45,13 @@ async fn test_session_config_access() {
         max_retries: 5,
         testnet: false,
         credentials: None,
+        credentials_provider: None,
+        debug_raw_responses: false,
+        price_normalization: None,
+        transport: None,
+        proxy: None,
+        default_headers: Default::default(),
+        pool: Default::default(),
     };
 
     let session = HttpSession::new(config.clone());
@@ -61,6 +75,13 @@ async fn test_session_initially_not_authenticated() {
         max_retries: 3,
         testnet: true,
         credentials: None,
+        credentials_provider: None,
+        debug_raw_responses: false,
+        price_normalization: None,
+        transport: None,
+        proxy: None,
+        default_headers: Default::default(),
+        pool: Default::default(),
     };
 
     let session = HttpSession::new(config);
@@ -78,6 +99,13 @@ async fn test_set_and_get_auth_token() {
         max_retries: 3,
         testnet: true,
         credentials: None,
+        credentials_provider: None,
+        debug_raw_responses: false,
+        price_normalization: None,
+        transport: None,
+        proxy: None,
+        default_headers: Default::default(),
+        pool: Default::default(),
     };
 
     let session = HttpSession::new(config);
@@ -116,6 +144,13 @@ async fn test_authorization_header() {
         max_retries: 3,
         testnet: true,
         credentials: None,
+        credentials_provider: None,
+        debug_raw_responses: false,
+        price_normalization: None,
+        transport: None,
+        proxy: None,
+        default_headers: Default::default(),
+        pool: Default::default(),
     };
 
     let session = HttpSession::new(config);
@@ -147,6 +182,13 @@ async fn test_authorization_header_different_token_types() {
         max_retries: 3,
         testnet: true,
         credentials: None,
+        credentials_provider: None,
+        debug_raw_responses: false,
+        price_normalization: None,
+        transport: None,
+        proxy: None,
+        default_headers: Default::default(),
+        pool: Default::default(),
     };
 
     let session = HttpSession::new(config);
@@ -176,6 +218,13 @@ async fn test_clear_auth_token() {
         max_retries: 3,
         testnet: true,
         credentials: None,
+        credentials_provider: None,
+        debug_raw_responses: false,
+        price_normalization: None,
+        transport: None,
+        proxy: None,
+        default_headers: Default::default(),
+        pool: Default::default(),
     };
 
     let session = HttpSession::new(config);
@@ -209,6 +258,13 @@ async fn test_is_token_expired() {
         max_retries: 3,
         testnet: true,
         credentials: None,
+        credentials_provider: None,
+        debug_raw_responses: false,
+        price_normalization: None,
+        transport: None,
+        proxy: None,
+        default_headers: Default::default(),
+        pool: Default::default(),
     };
 
     let session = HttpSession::new(config);
@@ -239,6 +295,13 @@ async fn test_session_clone() {
         max_retries: 3,
         testnet: true,
         credentials: None,
+        credentials_provider: None,
+        debug_raw_responses: false,
+        price_normalization: None,
+        transport: None,
+        proxy: None,
+        default_headers: Default::default(),
+        pool: Default::default(),
     };
 
     let session1 = HttpSession::new(config);
@@ -276,6 +339,13 @@ async fn test_concurrent_token_access() {
         max_retries: 3,
         testnet: true,
         credentials: None,
+        credentials_provider: None,
+        debug_raw_responses: false,
+        price_normalization: None,
+        transport: None,
+        proxy: None,
+        default_headers: Default::default(),
+        pool: Default::default(),
     };
 
     let session = HttpSession::new(config);
@@ -0,0 +1,347 @@
+use deribit_http::model::{
+    Instrument, InstrumentKind, OptionStrategy, OptionType, OrderSide, StrategyLeg, TimestampMs,
+};
+use deribit_http::{DeribitHttpClient, HttpConfig, HttpError, HttpErrorKind};
+use mockito::Matcher;
+use serde_json::json;
+use std::env;
+use url::Url;
+
+fn option(name: &str, strike: f64, option_type: OptionType, expiration: u64) -> Instrument {
+    Instrument {
+        instrument_name: name.to_string(),
+        kind: Some(InstrumentKind::Option),
+        strike: Some(strike),
+        option_type: Some(option_type),
+        expiration_timestamp: Some(TimestampMs::from_millis(expiration)),
+        min_trade_amount: Some(0.1),
+        ..Default::default()
+    }
+}
+
+fn chain() -> Vec<Instrument> {
+    let near = 1_735_286_400_000;
+    let far = 1_743_148_800_000;
+    vec![
+        option("BTC-27DEC24-60000-C", 60000.0, OptionType::Call, near),
+        option("BTC-27DEC24-60000-P", 60000.0, OptionType::Put, near),
+        option("BTC-27DEC24-70000-C", 70000.0, OptionType::Call, near),
+        option("BTC-27DEC24-50000-P", 50000.0, OptionType::Put, near),
+        option("BTC-28MAR25-60000-C", 60000.0, OptionType::Call, far),
+    ]
+}
+
+fn leg(name: &str, side: OrderSide) -> StrategyLeg {
+    StrategyLeg {
+        instrument_name: name.to_string(),
+        side,
+        amount: 1.0,
+    }
+}
+
+fn is_config_error(result: Result<Vec<StrategyLeg>, HttpError>) -> bool {
+    matches!(
+        result.map_err(HttpError::into_kind),
+        Err(HttpErrorKind::ConfigError(_))
+    )
+}
+
+fn create_test_client(server: &mockito::ServerGuard) -> DeribitHttpClient {
+    unsafe {
+        env::set_var("DERIBIT_CLIENT_ID", "test_client_id");
+        env::set_var("DERIBIT_CLIENT_SECRET", "test_client_secret");
+        env::set_var("DERIBIT_TESTNET", "true");
+    }
+
+    let config = HttpConfig {
+        base_url: Url::parse(&format!("{}/api/v2", server.url())).unwrap(),
+        ..Default::default()
+    };
+
+    DeribitHttpClient::with_config(config)
+}
+
+async fn create_auth_mock(server: &mut mockito::Server) -> mockito::Mock {
+    server
+        .mock("GET", "/api/v2/public/auth")
+        .match_query(Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "result": {
+                    "access_token": "test_access_token",
+                    "expires_in": 3600,
+                    "refresh_token": "test_refresh_token",
+                    "scope": "trade:read_write",
+                    "token_type": "bearer"
+                }
+            })
+            .to_string(),
+        )
+        .create_async()
+        .await
+}
+
+async fn create_instruments_mock(server: &mut mockito::Server) -> mockito::Mock {
+    server
+        .mock("GET", "/api/v2/public/get_instruments")
+        .match_query(Matcher::AllOf(vec![
+            Matcher::UrlEncoded("currency".into(), "BTC".into()),
+            Matcher::UrlEncoded("kind".into(), "option".into()),
+        ]))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({ "jsonrpc": "2.0", "id": 1, "result": chain() }).to_string())
+        .create_async()
+        .await
+}
+
+#[cfg(test)]
+mod option_strategy_tests {
+    use super::*;
+
+    #[test]
+    fn test_vertical_spread_resolves_long_and_short_legs() {
+        let spread = OptionStrategy::bull_call_spread("BTC", "27DEC24", 60000.0, 70000.0, 1.0);
+        let legs = spread.resolve(&chain()).unwrap();
+        assert_eq!(
+            legs,
+            vec![
+                leg("BTC-27DEC24-60000-C", OrderSide::Buy),
+                leg("BTC-27DEC24-70000-C", OrderSide::Sell),
+            ]
+        );
+
+        let reversed = spread.reversed().resolve(&chain()).unwrap();
+        assert_eq!(reversed[0], leg("BTC-27DEC24-70000-C", OrderSide::Buy));
+        assert_eq!(reversed[1], leg("BTC-27DEC24-60000-C", OrderSide::Sell));
+    }
+
+    #[test]
+    fn test_straddle_and_strangle_resolve_both_types() {
+        let straddle = OptionStrategy::long_straddle("btc", "27dec24", 60000.0, 1.0);
+        assert_eq!(
+            straddle.reversed().resolve(&chain()).unwrap(),
+            vec![
+                leg("BTC-27DEC24-60000-C", OrderSide::Sell),
+                leg("BTC-27DEC24-60000-P", OrderSide::Sell),
+            ]
+        );
+
+        let strangle = OptionStrategy::long_strangle("BTC", "27DEC24", 50000.0, 70000.0, 1.0);
+        assert_eq!(
+            strangle.resolve(&chain()).unwrap(),
+            vec![
+                leg("BTC-27DEC24-70000-C", OrderSide::Buy),
+                leg("BTC-27DEC24-50000-P", OrderSide::Buy),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_calendar_sells_near_and_buys_far() {
+        let calendar =
+            OptionStrategy::calendar("BTC", OptionType::Call, 60000.0, "27DEC24", "28MAR25", 1.0);
+        assert_eq!(
+            calendar.resolve(&chain()).unwrap(),
+            vec![
+                leg("BTC-27DEC24-60000-C", OrderSide::Sell),
+                leg("BTC-28MAR25-60000-C", OrderSide::Buy),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_invalid_structures_are_rejected() {
+        let chain = chain();
+        assert!(is_config_error(
+            OptionStrategy::bull_call_spread("BTC", "27DEC24", 60000.0, 60000.0, 1.0)
+                .resolve(&chain)
+        ));
+        assert!(is_config_error(
+            OptionStrategy::long_strangle("BTC", "27DEC24", 70000.0, 50000.0, 1.0).resolve(&chain)
+        ));
+        assert!(is_config_error(
+            OptionStrategy::calendar("BTC", OptionType::Call, 60000.0, "27DEC24", "27DEC24", 1.0)
+                .resolve(&chain)
+        ));
+        assert!(is_config_error(
+            OptionStrategy::long_straddle("BTC", "27DEC24", 60000.0, 0.0).resolve(&chain)
+        ));
+        assert!(is_config_error(
+            OptionStrategy::long_straddle("BTC", "27DEC24", 60000.0, 0.05).resolve(&chain)
+        ));
+    }
+
+    #[test]
+    fn test_missing_leg_names_the_instrument() {
+        let result =
+            OptionStrategy::long_straddle("BTC", "28MAR25", 60000.0, 1.0).resolve(&chain());
+        match result.map_err(HttpError::into_kind) {
+            Err(HttpErrorKind::ConfigError(message)) => {
+                assert!(message.contains("BTC-28MAR25-60000-P"), "{}", message)
+            }
+            other => panic!("expected ConfigError, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_leg_converts_to_combo_trade() {
+        let trade = leg("BTC-27DEC24-60000-P", OrderSide::Sell).to_combo_trade();
+        assert_eq!(trade.instrument_name, "BTC-27DEC24-60000-P");
+        assert_eq!(trade.direction, "sell");
+        assert_eq!(trade.amount, Some(1.0));
+    }
+
+    #[tokio::test]
+    async fn test_create_strategy_combo_sends_resolved_legs() {
+        let mut server = mockito::Server::new_async().await;
+        let client = create_test_client(&server);
+        let _auth = create_auth_mock(&mut server).await;
+        let _instruments = create_instruments_mock(&mut server).await;
+
+        let combo_mock = server
+            .mock("POST", "/api/v2/private/create_combo")
+            .match_body(Matcher::PartialJson(json!({
+                "params": {
+                    "trades": [
+                        {"instrument_name": "BTC-27DEC24-60000-C", "direction": "buy", "amount": 1.0},
+                        {"instrument_name": "BTC-27DEC24-60000-P", "direction": "buy", "amount": 1.0}
+                    ]
+                }
+            })))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({
+                    "jsonrpc": "2.0",
+                    "id": 1,
+                    "result": {
+                        "id": "BTC-STRD-27DEC24-60000",
+                        "instrument_id": 77,
+                        "state": "rfq",
+                        "state_timestamp": 1_700_000_000_000_u64,
+                        "creation_timestamp": 1_700_000_000_000_u64,
+                        "legs": [
+                            {"instrument_name": "BTC-27DEC24-60000-C", "amount": 1},
+                            {"instrument_name": "BTC-27DEC24-60000-P", "amount": 1}
+                        ]
+                    }
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let straddle = OptionStrategy::long_straddle("BTC", "27DEC24", 60000.0, 1.0);
+        let combo = client.create_strategy_combo(&straddle).await.unwrap();
+
+        combo_mock.assert_async().await;
+        assert_eq!(combo.id, "BTC-STRD-27DEC24-60000");
+        assert_eq!(combo.leg_count(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_place_strategy_orders_labels_each_leg() {
+        let mut server = mockito::Server::new_async().await;
+        let client = create_test_client(&server);
+        let _auth = create_auth_mock(&mut server).await;
+        let _instruments = create_instruments_mock(&mut server).await;
+
+        let order_body = |order_id: &str, instrument: &str, direction: &str| {
+            json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "result": {
+                    "order": {
+                        "order_id": order_id,
+                        "instrument_name": instrument,
+                        "direction": direction,
+                        "amount": 1.0,
+                        "filled_amount": 1.0,
+                        "order_state": "filled",
+                        "order_type": "market",
+                        "label": "spread-1",
+                        "price": 0.05,
+                        "time_in_force": "good_til_cancelled",
+                        "creation_timestamp": 1_700_000_000_000_u64,
+                        "last_update_timestamp": 1_700_000_000_000_u64,
+                        "api": true,
+                        "is_liquidation": false,
+                        "post_only": false,
+                        "reduce_only": false,
+                        "replaced": false,
+                        "risk_reducing": false,
+                        "web": false,
+                        "average_price": 0.05
+                    },
+                    "trades": []
+                }
+            })
+            .to_string()
+        };
+        let buy_mock = server
+            .mock("POST", "/api/v2/private/buy")
+            .match_body(Matcher::PartialJson(json!({
+                "params": {
+                    "instrument_name": "BTC-27DEC24-60000-C",
+                    "type": "market",
+                    "label": "spread-1"
+                }
+            })))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(order_body("buy-1", "BTC-27DEC24-60000-C", "buy"))
+            .create_async()
+            .await;
+        let sell_mock = server
+            .mock("POST", "/api/v2/private/sell")
+            .match_body(Matcher::PartialJson(json!({
+                "params": {
+                    "instrument_name": "BTC-27DEC24-70000-C",
+                    "label": "spread-1"
+                }
+            })))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(order_body("sell-1", "BTC-27DEC24-70000-C", "sell"))
+            .create_async()
+            .await;
+
+        let spread = OptionStrategy::bull_call_spread("BTC", "27DEC24", 60000.0, 70000.0, 1.0);
+        let orders = client
+            .place_strategy_orders(&spread, "spread-1")
+            .await
+            .unwrap();
+
+        buy_mock.assert_async().await;
+        sell_mock.assert_async().await;
+        let ids: Vec<&str> = orders.iter().map(|o| o.order.order_id.as_str()).collect();
+        assert_eq!(ids, vec!["buy-1", "sell-1"]);
+    }
+
+    #[tokio::test]
+    async fn test_missing_leg_sends_no_orders() {
+        let mut server = mockito::Server::new_async().await;
+        let client = create_test_client(&server);
+        let _auth = create_auth_mock(&mut server).await;
+        let _instruments = create_instruments_mock(&mut server).await;
+        let buy_mock = server
+            .mock("POST", "/api/v2/private/buy")
+            .expect(0)
+            .create_async()
+            .await;
+
+        let spread = OptionStrategy::bull_call_spread("BTC", "27DEC24", 60000.0, 80000.0, 1.0);
+        let result = client.place_strategy_orders(&spread, "spread-2").await;
+
+        assert!(matches!(
+            result.map_err(HttpError::into_kind),
+            Err(HttpErrorKind::ConfigError(_))
+        ));
+        buy_mock.assert_async().await;
+    }
+}
@@ -0,0 +1,277 @@
+use deribit_http::model::TimestampMs;
+use deribit_http::model::position::PositionChange;
+use deribit_http::prelude::StreamExt;
+use deribit_http::{DeribitHttpClient, HttpConfig};
+use serde_json::json;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+use url::Url;
+
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+fn create_test_client(server: &mockito::Server) -> DeribitHttpClient {
+    let config = HttpConfig {
+        base_url: Url::parse(server.url().trim_end_matches('/')).unwrap(),
+        ..Default::default()
+    };
+    DeribitHttpClient::with_config(config)
+}
+
+fn ticker_response(timestamp: u64) -> String {
+    json!({
+        "jsonrpc": "2.0",
+        "result": {
+            "instrument_name": "BTC-PERPETUAL",
+            "best_bid_amount": 1.0,
+            "best_ask_amount": 1.0,
+            "mark_price": 45000.0 + timestamp as f64,
+            "timestamp": timestamp,
+            "state": "open",
+            "stats": { "volume": 1000.0 }
+        },
+        "id": 1
+    })
+    .to_string()
+}
+
+fn order_book_response(change_id: u64) -> String {
+    json!({
+        "jsonrpc": "2.0",
+        "result": {
+            "instrument_name": "BTC-PERPETUAL",
+            "bids": [[44999.0, 1.0]],
+            "asks": [[45001.0, change_id as f64]],
+            "timestamp": 1640995200000u64 + change_id,
+            "change_id": change_id
+        },
+        "id": 1
+    })
+    .to_string()
+}
+
+#[tokio::test]
+async fn test_ticker_stream_skips_unchanged_timestamps() {
+    let mut server = mockito::Server::new_async().await;
+    let client = create_test_client(&server);
+    let mock = server
+        .mock("GET", "//public/ticker?instrument_name=BTC-PERPETUAL")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(ticker_response(1640995200000))
+        .expect_at_least(3)
+        .create_async()
+        .await;
+
+    let mut tickers = client.ticker_stream("BTC-PERPETUAL", POLL_INTERVAL);
+    let first = tickers.next().await.unwrap().unwrap();
+    assert_eq!(first.timestamp, TimestampMs(1640995200000));
+
+    let next = tokio::time::timeout(POLL_INTERVAL * 4, tickers.next()).await;
+    assert!(next.is_err(), "unchanged ticker was yielded again");
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_ticker_stream_yields_new_timestamps() {
+    let mut server = mockito::Server::new_async().await;
+    let client = create_test_client(&server);
+    let polls = Arc::new(AtomicU64::new(0));
+    let counter = polls.clone();
+    server
+        .mock("GET", "//public/ticker?instrument_name=BTC-PERPETUAL")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body_from_request(move |_| {
+            let poll = counter.fetch_add(1, Ordering::SeqCst);
+            ticker_response(1640995200000 + poll / 2).into_bytes()
+        })
+        .create_async()
+        .await;
+
+    let tickers: Vec<_> = client
+        .ticker_stream("BTC-PERPETUAL", POLL_INTERVAL)
+        .take(3)
+        .map(|ticker| ticker.unwrap().timestamp)
+        .collect()
+        .await;
+
+    assert_eq!(
+        tickers,
+        vec![
+            TimestampMs(1640995200000),
+            TimestampMs(1640995200001),
+            TimestampMs(1640995200002)
+        ]
+    );
+    assert!(polls.load(Ordering::SeqCst) >= 5);
+}
+
+#[tokio::test]
+async fn test_ticker_stream_yields_errors_and_keeps_polling() {
+    let mut server = mockito::Server::new_async().await;
+    let client = create_test_client(&server);
+    server
+        .mock("GET", "//public/ticker?instrument_name=BTC-INVALID")
+        .with_status(400)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "jsonrpc": "2.0",
+                "error": { "code": 10009, "message": "instrument_not_found" },
+                "id": 1
+            })
+            .to_string(),
+        )
+        .expect_at_least(2)
+        .create_async()
+        .await;
+
+    let mut tickers = client.ticker_stream("BTC-INVALID", POLL_INTERVAL);
+    assert!(tickers.next().await.unwrap().is_err());
+    assert!(tickers.next().await.unwrap().is_err());
+}
+
+#[tokio::test]
+async fn test_dropping_ticker_stream_stops_polling() {
+    let mut server = mockito::Server::new_async().await;
+    let client = create_test_client(&server);
+    let polls = Arc::new(AtomicU64::new(0));
+    let counter = polls.clone();
+    server
+        .mock("GET", "//public/ticker?instrument_name=BTC-PERPETUAL")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body_from_request(move |_| {
+            let poll = counter.fetch_add(1, Ordering::SeqCst);
+            ticker_response(1640995200000 + poll).into_bytes()
+        })
+        .create_async()
+        .await;
+
+    let mut tickers = client.ticker_stream("BTC-PERPETUAL", POLL_INTERVAL);
+    tickers.next().await.unwrap().unwrap();
+    drop(tickers);
+    let after_drop = polls.load(Ordering::SeqCst);
+
+    tokio::time::sleep(POLL_INTERVAL * 3).await;
+    assert!(polls.load(Ordering::SeqCst) <= after_drop + 1);
+}
+
+#[tokio::test]
+async fn test_order_book_stream_deduplicates_change_ids() {
+    let mut server = mockito::Server::new_async().await;
+    let client = create_test_client(&server);
+    // Polls see change ids 10, 10, 9 (stale), 11, 11, 12, ...
+    let change_ids = [10, 10, 9, 11, 11, 12];
+    let polls = Arc::new(AtomicU64::new(0));
+    let counter = polls.clone();
+    server
+        .mock(
+            "GET",
+            "//public/get_order_book?instrument_name=BTC-PERPETUAL&depth=5",
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body_from_request(move |_| {
+            let poll = counter.fetch_add(1, Ordering::SeqCst) as usize;
+            order_book_response(change_ids[poll.min(change_ids.len() - 1)]).into_bytes()
+        })
+        .create_async()
+        .await;
+
+    let books: Vec<_> = client
+        .order_book_stream("BTC-PERPETUAL", Some(5), POLL_INTERVAL)
+        .take(3)
+        .map(|book| book.unwrap().change_id)
+        .collect()
+        .await;
+
+    assert_eq!(books, vec![10, 11, 12]);
+    assert!(polls.load(Ordering::SeqCst) >= 6);
+}
+
+fn positions_response(sizes: &[(&str, f64)]) -> String {
+    let positions: Vec<_> = sizes
+        .iter()
+        .map(|(instrument_name, size)| json!({ "instrument_name": instrument_name, "size": size }))
+        .collect();
+    json!({ "jsonrpc": "2.0", "result": positions, "id": 1 }).to_string()
+}
+
+#[tokio::test]
+async fn test_positions_stream_emits_changes() {
+    let mut server = mockito::Server::new_async().await;
+    let config = HttpConfig {
+        base_url: Url::parse(&format!("{}/api/v2", server.url())).unwrap(),
+        ..Default::default()
+    }
+    .with_oauth2(
+        "test_client_id".to_string(),
+        "test_client_secret".to_string(),
+    );
+    let client = DeribitHttpClient::with_config(config);
+    server
+        .mock(
+            "GET",
+            mockito::Matcher::Regex("^/api/v2/public/auth".into()),
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "result": {
+                    "access_token": "test_access_token",
+                    "expires_in": 3600,
+                    "refresh_token": "test_refresh_token",
+                    "scope": "read",
+                    "state": "",
+                    "token_type": "bearer"
+                }
+            })
+            .to_string(),
+        )
+        .create_async()
+        .await;
+    let snapshots: [&[(&str, f64)]; 4] = [
+        &[("BTC-PERPETUAL", 100.0)],
+        &[("BTC-PERPETUAL", 100.0)],
+        &[("BTC-PERPETUAL", 200.0), ("BTC-27DEC24", 10.0)],
+        &[("BTC-27DEC24", 10.0)],
+    ];
+    let polls = Arc::new(AtomicU64::new(0));
+    let counter = polls.clone();
+    server
+        .mock("POST", "/api/v2/private/get_positions")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body_from_request(move |_| {
+            let poll = counter.fetch_add(1, Ordering::SeqCst) as usize;
+            positions_response(snapshots[poll.min(snapshots.len() - 1)]).into_bytes()
+        })
+        .create_async()
+        .await;
+
+    let updates: Vec<Vec<PositionChange>> = client
+        .positions_stream("BTC", POLL_INTERVAL)
+        .take(3)
+        .map(|changes| changes.unwrap())
+        .collect()
+        .await;
+
+    assert!(matches!(updates[0].as_slice(), [PositionChange::Opened(_)]));
+    assert!(matches!(
+        updates[1].as_slice(),
+        [
+            PositionChange::SizeChanged { previous_size, .. },
+            PositionChange::Opened(_)
+        ] if *previous_size == 100.0
+    ));
+    assert!(matches!(
+        updates[2].as_slice(),
+        [PositionChange::Closed(p)] if p.instrument_name == "BTC-PERPETUAL"
+    ));
+    assert!(polls.load(Ordering::SeqCst) >= 4);
+}
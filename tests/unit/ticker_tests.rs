@@ -1,3 +1,4 @@
+use deribit_http::model::TimestampMs;
 use deribit_http::model::instrument::InstrumentKind;
 use deribit_http::model::other::Greeks;
 use deribit_http::model::ticker::{Ticker, TickerData, TickerStats};
@@ -43,7 +44,7 @@ fn create_mock_ticker_data() -> TickerData {
         bid_iv: Some(0.8),
         ask_iv: Some(0.82),
         mark_iv: Some(0.81),
-        timestamp: 1640995200000,
+        timestamp: TimestampMs(1640995200000),
         state: "open".to_string(),
         settlement_price: None,
         stats: create_mock_ticker_stats(),
@@ -61,7 +62,7 @@ fn create_mock_ticker_data() -> TickerData {
 fn create_mock_ticker() -> Ticker {
     Ticker {
         instrument_name: "BTC-PERPETUAL".to_string(),
-        timestamp: 1640995200000,
+        timestamp: TimestampMs(1640995200000),
         best_bid_price: Some(49950.0),
         best_bid_amount: Some(1.5),
         best_ask_price: Some(50050.0),
@@ -192,7 +193,7 @@ fn test_ticker_data_clone() {
 fn test_ticker_creation() {
     let ticker = create_mock_ticker();
     assert_eq!(ticker.instrument_name, "BTC-PERPETUAL");
-    assert_eq!(ticker.timestamp, 1640995200000);
+    assert_eq!(ticker.timestamp.as_millis(), 1640995200000);
     assert_eq!(ticker.best_bid_price, Some(49950.0));
     assert_eq!(ticker.best_ask_price, Some(50050.0));
     assert_eq!(ticker.open_interest, 5000.0);
@@ -223,7 +224,7 @@ fn test_ticker_deserialization() {
 
     let deserialized: Ticker = serde_json::from_str(json).unwrap();
     assert_eq!(deserialized.instrument_name, "BTC-PERPETUAL");
-    assert_eq!(deserialized.timestamp, 1640995200000);
+    assert_eq!(deserialized.timestamp.as_millis(), 1640995200000);
     assert_eq!(deserialized.open_interest, 5000.0);
 }
 
@@ -314,7 +315,7 @@ fn test_ticker_has_valid_spread() {
 fn test_ticker_with_minimal_data() {
     let ticker = Ticker {
         instrument_name: "TEST".to_string(),
-        timestamp: 0,
+        timestamp: TimestampMs(0),
         best_bid_price: None,
         best_bid_amount: None,
         best_ask_price: None,
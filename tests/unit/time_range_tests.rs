@@ -0,0 +1,157 @@
+use chrono::{TimeZone, Utc};
+use deribit_http::model::time_range::TimeRange;
+use deribit_http::model::timestamp::TimestampMs;
+use deribit_http::{DeribitHttpClient, HttpConfig, HttpError, HttpErrorKind};
+use url::Url;
+
+fn ms(millis: u64) -> TimestampMs {
+    TimestampMs(millis)
+}
+
+#[cfg(test)]
+mod time_range_bounds_tests {
+    use super::*;
+
+    #[test]
+    fn test_bounds_at_resolves_open_ranges() {
+        let now = ms(10_000_000);
+        assert_eq!(TimeRange::From(ms(5)).bounds_at(now), (ms(5), now));
+        assert_eq!(TimeRange::Until(ms(7)).bounds_at(now), (ms(0), ms(7)));
+        assert_eq!(
+            TimeRange::Between(ms(1), ms(2)).bounds_at(now),
+            (ms(1), ms(2))
+        );
+        assert_eq!(
+            TimeRange::LastHours(2).bounds_at(now),
+            (ms(10_000_000 - 7_200_000), now)
+        );
+        assert_eq!(TimeRange::LastHours(10).bounds_at(now), (ms(0), now));
+    }
+
+    #[test]
+    fn test_from_chrono_ranges() {
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2024, 1, 2, 0, 0, 0).unwrap();
+
+        assert_eq!(
+            TimeRange::from(start..end),
+            TimeRange::Between(ms(1_704_067_200_000), ms(1_704_153_599_999))
+        );
+        assert_eq!(
+            TimeRange::between(start, end),
+            TimeRange::Between(ms(1_704_067_200_000), ms(1_704_153_600_000))
+        );
+        assert_eq!(
+            TimeRange::from(start..),
+            TimeRange::From(ms(1_704_067_200_000))
+        );
+        assert_eq!(
+            TimeRange::from(..=end),
+            TimeRange::Until(ms(1_704_153_600_000))
+        );
+    }
+
+    #[test]
+    fn test_resolve_rejects_inverted_range() {
+        assert!(matches!(
+            TimeRange::Between(ms(2), ms(1))
+                .resolve()
+                .map_err(HttpError::into_kind),
+            Err(HttpErrorKind::ConfigError(_))
+        ));
+        assert_eq!(
+            TimeRange::from((ms(1), ms(2))).resolve().unwrap(),
+            (ms(1), ms(2))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_endpoint_rejects_inverted_range_without_request() {
+        let server = mockito::Server::new_async().await;
+        let config = HttpConfig {
+            base_url: Url::parse(&server.url()).unwrap(),
+            ..Default::default()
+        };
+        let client = DeribitHttpClient::with_config(config);
+
+        let result = client
+            .get_funding_rate_history("BTC-PERPETUAL", (ms(2_000), ms(1_000)))
+            .await;
+
+        assert!(matches!(
+            result.map_err(HttpError::into_kind),
+            Err(HttpErrorKind::ConfigError(_))
+        ));
+    }
+}
+
+#[cfg(test)]
+mod timestamp_ms_tests {
+    use super::*;
+    use std::time::{Duration, UNIX_EPOCH};
+
+    #[test]
+    fn test_conversions_agree_on_milliseconds() {
+        let datetime = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let system_time = UNIX_EPOCH + Duration::from_secs(1_704_067_200);
+
+        assert_eq!(TimestampMs::from_secs(1_704_067_200), ms(1_704_067_200_000));
+        assert_eq!(TimestampMs::from(datetime), ms(1_704_067_200_000));
+        assert_eq!(TimestampMs::from(system_time), ms(1_704_067_200_000));
+        assert_eq!(ms(1_704_067_200_000).to_datetime(), Some(datetime));
+        assert_eq!(ms(1_704_067_200_999).as_secs(), 1_704_067_200);
+    }
+
+    #[test]
+    fn test_serializes_as_bare_integer() {
+        let json = serde_json::to_string(&ms(1_700_000_000_000)).unwrap();
+        assert_eq!(json, "1700000000000");
+        let parsed: TimestampMs = serde_json::from_str("1700000000000").unwrap();
+        assert_eq!(parsed.as_millis(), 1_700_000_000_000u64);
+        assert_eq!(parsed.to_string(), "1700000000000");
+    }
+
+    #[test]
+    fn test_saturating_arithmetic() {
+        assert_eq!(ms(1_000).saturating_sub(Duration::from_secs(2)), ms(0));
+        assert_eq!(
+            ms(1_000).saturating_add(Duration::from_millis(500)),
+            ms(1_500)
+        );
+    }
+}
+
+#[cfg(test)]
+mod datetime_millis_tests {
+    use super::*;
+    use chrono::DateTime;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Event {
+        #[serde(with = "deribit_http::model::timestamp::datetime_millis")]
+        timestamp: DateTime<Utc>,
+        #[serde(with = "deribit_http::model::timestamp::datetime_millis::option")]
+        expires: Option<DateTime<Utc>>,
+    }
+
+    #[test]
+    fn test_round_trips_through_milliseconds() {
+        let event: Event =
+            serde_json::from_str(r#"{"timestamp": 1704067200500, "expires": null}"#).unwrap();
+        assert_eq!(event.timestamp.timestamp_millis(), 1_704_067_200_500);
+        assert_eq!(event.expires, None);
+
+        let json = serde_json::to_string(&event).unwrap();
+        assert_eq!(json, r#"{"timestamp":1704067200500,"expires":null}"#);
+    }
+
+    #[test]
+    fn test_rejects_out_of_range_timestamp() {
+        let result: Result<Event, _> =
+            serde_json::from_str(r#"{"timestamp": 18446744073709551615, "expires": null}"#);
+        assert!(result.is_err());
+        assert!(DateTime::<Utc>::try_from(ms(u64::MAX)).is_err());
+        assert!(DateTime::<Utc>::try_from(ms(1_704_067_200_000)).is_ok());
+    }
+}
@@ -1,3 +1,4 @@
+use deribit_http::model::TimestampMs;
 use deribit_http::model::instrument::InstrumentKind;
 use deribit_http::model::order::OrderSide;
 use deribit_http::model::trade::{
@@ -26,7 +27,7 @@ fn create_mock_trade_execution() -> TradeExecution {
         self_trade: false,
         state: "filled".to_string(),
         tick_direction: 1,
-        timestamp: 1640995200000,
+        timestamp: TimestampMs(1640995200000),
         trade_id: "trade_789".to_string(),
         trade_seq: 123456,
         underlying_price: Some(49950.0),
@@ -60,7 +61,7 @@ fn create_mock_user_trade() -> UserTrade {
         self_trade: false,
         state: "filled".to_string(),
         tick_direction: 1,
-        timestamp: 1640995200000,
+        timestamp: TimestampMs(1640995200000),
         trade_id: "trade_789".to_string(),
         trade_seq: 123456,
         underlying_price: Some(49950.0),
@@ -78,7 +79,7 @@ fn create_mock_last_trade() -> LastTrade {
         liquid: Some("M".to_string()),
         price: 50000.0,
         tick_direction: 1,
-        timestamp: 1640995200000,
+        timestamp: TimestampMs(1640995200000),
         trade_id: "trade_789".to_string(),
         trade_seq: 123456,
     }
@@ -92,7 +93,7 @@ fn create_mock_trade() -> Trade {
         direction: OrderSide::Buy,
         amount: 1.5,
         price: 50000.0,
-        timestamp: 1640995200000,
+        timestamp: TimestampMs(1640995200000),
         fee: 0.0005,
         fee_currency: "BTC".to_string(),
         liquidity: Liquidity::Maker,
@@ -559,7 +560,7 @@ fn test_trade_with_minimal_data() {
         direction: OrderSide::Buy,
         amount: 0.0,
         price: 0.0,
-        timestamp: 0,
+        timestamp: TimestampMs(0),
         fee: 0.0,
         fee_currency: "USD".to_string(),
         liquidity: Liquidity::Taker,
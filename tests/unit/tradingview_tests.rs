@@ -1,26 +1,28 @@
-use deribit_http::model::tradingview::TradingViewChartData;
+use deribit_http::error::{HttpError, HttpErrorKind};
+use deribit_http::model::TimestampMs;
+use deribit_http::model::tradingview::{Candle, Resolution, TradingViewChartData};
 use serde_json;
 
 // Helper function to create mock data
 fn create_mock_chart_data() -> TradingViewChartData {
     let mut chart_data = TradingViewChartData::new();
     chart_data.add_candle(
-        1640995200000, // timestamp
-        50000.0,       // open
-        51000.0,       // high
-        49500.0,       // low
-        50500.0,       // close
-        1000.0,        // volume
-        50250000.0,    // cost
+        TimestampMs::from_millis(1640995200000), // timestamp
+        50000.0,                                 // open
+        51000.0,                                 // high
+        49500.0,                                 // low
+        50500.0,                                 // close
+        1000.0,                                  // volume
+        50250000.0,                              // cost
     );
     chart_data.add_candle(
-        1640995260000, // timestamp + 60s
-        50500.0,       // open
-        50800.0,       // high
-        50200.0,       // low
-        50300.0,       // close
-        800.0,         // volume
-        40240000.0,    // cost
+        TimestampMs::from_millis(1640995260000), // timestamp + 60s
+        50500.0,                                 // open
+        50800.0,                                 // high
+        50200.0,                                 // low
+        50300.0,                                 // close
+        800.0,                                   // volume
+        40240000.0,                              // cost
     );
     chart_data
 }
@@ -83,13 +85,13 @@ fn test_trading_view_chart_data_creation() {
 fn test_add_single_candle() {
     let mut chart_data = TradingViewChartData::new();
     chart_data.add_candle(
-        1640995200000, // timestamp
-        50000.0,       // open
-        51000.0,       // high
-        49500.0,       // low
-        50500.0,       // close
-        1000.0,        // volume
-        50250000.0,    // cost
+        TimestampMs::from_millis(1640995200000), // timestamp
+        50000.0,                                 // open
+        51000.0,                                 // high
+        49500.0,                                 // low
+        50500.0,                                 // close
+        1000.0,                                  // volume
+        50250000.0,                              // cost
     );
 
     assert_eq!(chart_data.ticks.len(), 1);
@@ -100,7 +102,7 @@ fn test_add_single_candle() {
     assert_eq!(chart_data.volume.len(), 1);
     assert_eq!(chart_data.cost.len(), 1);
 
-    assert_eq!(chart_data.ticks[0], 1640995200000);
+    assert_eq!(chart_data.ticks[0].as_millis(), 1640995200000);
     assert_eq!(chart_data.open[0], 50000.0);
     assert_eq!(chart_data.high[0], 51000.0);
     assert_eq!(chart_data.low[0], 49500.0);
@@ -122,7 +124,7 @@ fn test_add_multiple_candles() {
     assert_eq!(chart_data.cost.len(), 2);
 
     // First candle
-    assert_eq!(chart_data.ticks[0], 1640995200000);
+    assert_eq!(chart_data.ticks[0].as_millis(), 1640995200000);
     assert_eq!(chart_data.open[0], 50000.0);
     assert_eq!(chart_data.high[0], 51000.0);
     assert_eq!(chart_data.low[0], 49500.0);
@@ -131,7 +133,7 @@ fn test_add_multiple_candles() {
     assert_eq!(chart_data.cost[0], 50250000.0);
 
     // Second candle
-    assert_eq!(chart_data.ticks[1], 1640995260000);
+    assert_eq!(chart_data.ticks[1].as_millis(), 1640995260000);
     assert_eq!(chart_data.open[1], 50500.0);
     assert_eq!(chart_data.high[1], 50800.0);
     assert_eq!(chart_data.low[1], 50200.0);
@@ -143,10 +145,10 @@ fn test_add_multiple_candles() {
 #[test]
 fn test_add_candle_with_zero_values() {
     let mut chart_data = TradingViewChartData::new();
-    chart_data.add_candle(0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0);
+    chart_data.add_candle(TimestampMs::EPOCH, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0);
 
     assert_eq!(chart_data.ticks.len(), 1);
-    assert_eq!(chart_data.ticks[0], 0);
+    assert_eq!(chart_data.ticks[0].as_millis(), 0);
     assert_eq!(chart_data.open[0], 0.0);
     assert_eq!(chart_data.high[0], 0.0);
     assert_eq!(chart_data.low[0], 0.0);
@@ -159,7 +161,7 @@ fn test_add_candle_with_zero_values() {
 fn test_add_candle_with_negative_values() {
     let mut chart_data = TradingViewChartData::new();
     chart_data.add_candle(
-        1640995200000,
+        TimestampMs::from_millis(1640995200000),
         -100.0,  // negative open
         100.0,   // positive high
         -200.0,  // negative low
@@ -232,7 +234,7 @@ fn test_trading_view_chart_data_deserialization() {
     assert_eq!(deserialized.volume.len(), 2);
     assert_eq!(deserialized.cost.len(), 2);
 
-    assert_eq!(deserialized.ticks[0], 1640995200000);
+    assert_eq!(deserialized.ticks[0].as_millis(), 1640995200000);
     assert_eq!(deserialized.open[0], 50000.0);
     assert_eq!(deserialized.high[0], 51000.0);
     assert_eq!(deserialized.low[0], 49500.0);
@@ -319,7 +321,7 @@ fn test_trading_view_chart_data_clone_empty() {
 fn test_trading_view_chart_data_large_numbers() {
     let mut chart_data = TradingViewChartData::new();
     chart_data.add_candle(
-        u64::MAX,
+        TimestampMs::from_millis(u64::MAX),
         f64::MAX,
         f64::MAX,
         f64::MIN,
@@ -328,7 +330,7 @@ fn test_trading_view_chart_data_large_numbers() {
         f64::MAX,
     );
 
-    assert_eq!(chart_data.ticks[0], u64::MAX);
+    assert_eq!(chart_data.ticks[0].as_millis(), u64::MAX);
     assert_eq!(chart_data.open[0], f64::MAX);
     assert_eq!(chart_data.high[0], f64::MAX);
     assert_eq!(chart_data.low[0], f64::MIN);
@@ -344,13 +346,13 @@ fn test_trading_view_chart_data_consistency() {
     // Add multiple candles
     for i in 0..10 {
         chart_data.add_candle(
-            1640995200000 + (i * 60000), // timestamp increments
-            50000.0 + i as f64,          // open increments
-            51000.0 + i as f64,          // high increments
-            49000.0 + i as f64,          // low increments
-            50500.0 + i as f64,          // close increments
-            1000.0 + i as f64,           // volume increments
-            50000000.0 + i as f64,       // cost increments
+            TimestampMs::from_millis(1640995200000 + (i * 60000)), // timestamp increments
+            50000.0 + i as f64,                                    // open increments
+            51000.0 + i as f64,                                    // high increments
+            49000.0 + i as f64,                                    // low increments
+            50500.0 + i as f64,                                    // close increments
+            1000.0 + i as f64,                                     // volume increments
+            50000000.0 + i as f64,                                 // cost increments
         );
     }
 
@@ -365,7 +367,10 @@ fn test_trading_view_chart_data_consistency() {
 
     // Verify data consistency
     for i in 0..10 {
-        assert_eq!(chart_data.ticks[i], 1640995200000 + (i as u64 * 60000));
+        assert_eq!(
+            chart_data.ticks[i].as_millis(),
+            1640995200000 + (i as u64 * 60000)
+        );
         assert_eq!(chart_data.open[i], 50000.0 + i as f64);
         assert_eq!(chart_data.high[i], 51000.0 + i as f64);
         assert_eq!(chart_data.low[i], 49000.0 + i as f64);
@@ -402,3 +407,75 @@ fn test_trading_view_chart_data_round_trip_serialization() {
     assert_eq!(original.volume, deserialized.volume);
     assert_eq!(original.cost, deserialized.cost);
 }
+
+#[test]
+fn test_resolution_as_str_matches_serialization() {
+    let resolutions = [
+        (Resolution::Minute1, "1"),
+        (Resolution::Minute15, "15"),
+        (Resolution::Hour1, "60"),
+        (Resolution::Hour12, "720"),
+        (Resolution::Day1, "1D"),
+    ];
+    for (resolution, expected) in resolutions {
+        assert_eq!(resolution.as_str(), expected);
+        assert_eq!(resolution.to_string(), expected);
+        assert_eq!(
+            serde_json::to_string(&resolution).unwrap(),
+            format!("\"{expected}\"")
+        );
+    }
+}
+
+#[test]
+fn test_resolution_from_str() {
+    assert_eq!("60".parse::<Resolution>().unwrap(), Resolution::Hour1);
+    assert_eq!("1d".parse::<Resolution>().unwrap(), Resolution::Day1);
+    assert!(matches!(
+        "7".parse::<Resolution>().map_err(HttpError::into_kind),
+        Err(HttpErrorKind::ParseError(_))
+    ));
+}
+
+#[test]
+fn test_candles() {
+    let candles = create_mock_chart_data().candles().unwrap();
+
+    assert_eq!(candles.len(), 2);
+    assert_eq!(
+        candles[1],
+        Candle {
+            timestamp: TimestampMs(1640995260000),
+            open: 50500.0,
+            high: 50800.0,
+            low: 50200.0,
+            close: 50300.0,
+            volume: 800.0,
+            cost: 40240000.0,
+        }
+    );
+    assert!(create_empty_chart_data().candles().unwrap().is_empty());
+}
+
+#[test]
+fn test_candles_rejects_mismatched_lengths() {
+    let mut chart_data = create_mock_chart_data();
+    chart_data.volume.pop();
+
+    match chart_data.candles().map_err(HttpError::into_kind) {
+        Err(HttpErrorKind::InvalidResponse(message)) => {
+            assert!(message.contains("2 ticks but 1 volume values"))
+        }
+        other => panic!("expected InvalidResponse, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_chart_data_from_candles_round_trip() {
+    let original = create_mock_chart_data();
+    let rebuilt: TradingViewChartData = original.candles().unwrap().into_iter().collect();
+
+    assert_eq!(rebuilt.ticks, original.ticks);
+    assert_eq!(rebuilt.close, original.close);
+    assert_eq!(rebuilt.cost, original.cost);
+}
@@ -1,3 +1,4 @@
+use deribit_http::model::TimestampMs;
 use deribit_http::model::transfer::*;
 use serde_json;
 
@@ -9,7 +10,7 @@ fn create_mock_transfer() -> Transfer {
         1.5,
         0.0005,
         "bc1qxy2kgdygjrsqtzq2n0yrf2493p83kkfjhx0wlh".to_string(),
-        1640995200000, // 2022-01-01 00:00:00 UTC
+        TimestampMs::from_millis(1640995200000), // 2022-01-01 00:00:00 UTC
     )
 }
 
@@ -21,7 +22,7 @@ fn create_mock_subaccount_transfer() -> SubaccountTransfer {
         "ETH".to_string(),
         0,   // main account
         123, // subaccount
-        1640995200000,
+        TimestampMs::from_millis(1640995200000),
     )
 }
 
@@ -84,8 +85,8 @@ mod tests {
             "bc1qxy2kgdygjrsqtzq2n0yrf2493p83kkfjhx0wlh"
         );
         assert_eq!(transfer.state, TransferState::Prepared);
-        assert_eq!(transfer.created_timestamp, 1640995200000);
-        assert_eq!(transfer.updated_timestamp, 1640995200000);
+        assert_eq!(transfer.created_timestamp.as_millis(), 1640995200000);
+        assert_eq!(transfer.updated_timestamp.as_millis(), 1640995200000);
         assert!(transfer.transaction_id.is_none());
         assert!(transfer.confirmed_timestamp.is_none());
         assert!(transfer.transfer_type.is_none());
@@ -112,7 +113,7 @@ mod tests {
     #[test]
     fn test_transfer_confirm() {
         let mut transfer = create_mock_transfer();
-        let confirm_time = 1640995260000;
+        let confirm_time = TimestampMs::from_millis(1640995260000);
         transfer.confirm(confirm_time);
 
         assert_eq!(transfer.state, TransferState::Confirmed);
@@ -123,7 +124,7 @@ mod tests {
     #[test]
     fn test_transfer_cancel() {
         let mut transfer = create_mock_transfer();
-        let cancel_time = 1640995260000;
+        let cancel_time = TimestampMs::from_millis(1640995260000);
         transfer.cancel(cancel_time);
 
         assert_eq!(transfer.state, TransferState::Cancelled);
@@ -135,7 +136,7 @@ mod tests {
         let mut transfer = create_mock_transfer();
         assert!(!transfer.is_confirmed());
 
-        transfer.confirm(1640995260000);
+        transfer.confirm(TimestampMs::from_millis(1640995260000));
         assert!(transfer.is_confirmed());
     }
 
@@ -144,7 +145,7 @@ mod tests {
         let mut transfer = create_mock_transfer();
         assert!(!transfer.is_cancelled());
 
-        transfer.cancel(1640995260000);
+        transfer.cancel(TimestampMs::from_millis(1640995260000));
         assert!(transfer.is_cancelled());
     }
 
@@ -258,7 +259,7 @@ mod transfers_tests {
             10.0,
             0.01,
             "0x742d35Cc6634C0532925a3b8D4C9db96".to_string(),
-            1640995200000,
+            TimestampMs::from_millis(1640995200000),
         );
 
         transfers.add(btc_transfer.clone());
@@ -327,7 +328,7 @@ mod transfers_tests {
             2.5,
             0.001,
             "bc1qxy2kgdygjrsqtzq2n0yrf2493p83kkfjhx0wlh".to_string(),
-            1640995200000,
+            TimestampMs::from_millis(1640995200000),
         ); // 2.5 BTC
         let eth_transfer = Transfer::new(
             11111,
@@ -335,7 +336,7 @@ mod transfers_tests {
             10.0,
             0.01,
             "0x742d35Cc6634C0532925a3b8D4C9db96".to_string(),
-            1640995200000,
+            TimestampMs::from_millis(1640995200000),
         ); // 10.0 ETH
 
         transfers.add(btc_transfer1);
@@ -357,7 +358,7 @@ mod transfers_tests {
             2.5,
             0.001,
             "bc1qxy2kgdygjrsqtzq2n0yrf2493p83kkfjhx0wlh".to_string(),
-            1640995200000,
+            TimestampMs::from_millis(1640995200000),
         ); // 0.001 BTC fee
 
         transfers.add(btc_transfer1);
@@ -400,7 +401,7 @@ mod subaccount_transfer_tests {
         assert_eq!(transfer.source, 0);
         assert_eq!(transfer.destination, 123);
         assert_eq!(transfer.state, TransferState::Prepared);
-        assert_eq!(transfer.timestamp, 1640995200000);
+        assert_eq!(transfer.timestamp.as_millis(), 1640995200000);
         assert_eq!(transfer.transfer_type, "subaccount");
     }
 
@@ -427,7 +428,7 @@ mod subaccount_transfer_tests {
             "BTC".to_string(),
             123, // subaccount
             0,   // main account
-            1640995200000,
+            TimestampMs::from_millis(1640995200000),
         );
         assert!(sub_to_main.is_main_subaccount_transfer());
 
@@ -437,7 +438,7 @@ mod subaccount_transfer_tests {
             "BTC".to_string(),
             123, // subaccount
             456, // another subaccount
-            1640995200000,
+            TimestampMs::from_millis(1640995200000),
         );
         assert!(!sub_to_sub.is_main_subaccount_transfer());
     }
@@ -453,7 +454,7 @@ mod subaccount_transfer_tests {
             "BTC".to_string(),
             123, // subaccount
             456, // another subaccount
-            1640995200000,
+            TimestampMs::from_millis(1640995200000),
         );
         assert!(sub_to_sub.is_subaccount_to_subaccount());
     }
@@ -512,12 +513,25 @@ mod subaccount_transfer_tests {
     #[test]
     fn test_subaccount_transfer_edge_cases() {
         // Test with zero amounts
-        let zero_transfer = SubaccountTransfer::new(1, 0.0, "BTC".to_string(), 0, 1, 1640995200000);
+        let zero_transfer = SubaccountTransfer::new(
+            1,
+            0.0,
+            "BTC".to_string(),
+            0,
+            1,
+            TimestampMs::from_millis(1640995200000),
+        );
         assert_eq!(zero_transfer.amount, 0.0);
 
         // Test with very large amounts
-        let large_transfer =
-            SubaccountTransfer::new(2, 1000000.0, "USDT".to_string(), 1, 2, 1640995200000);
+        let large_transfer = SubaccountTransfer::new(
+            2,
+            1000000.0,
+            "USDT".to_string(),
+            1,
+            2,
+            TimestampMs::from_millis(1640995200000),
+        );
         assert_eq!(large_transfer.amount, 1000000.0);
     }
 }
@@ -117,6 +117,28 @@ fn test_auth_token_with_refresh() {
     assert_eq!(token.refresh_token, Some("refresh456".to_string()));
 }
 
+#[test]
+fn test_auth_token_debug_and_display_redact_tokens() {
+    let json = r#"{
+        "access_token": "abc123",
+        "token_type": "bearer",
+        "expires_in": 3600,
+        "refresh_token": "refresh456",
+        "scope": "trade:read_write"
+    }"#;
+    let token: AuthToken = serde_json::from_str(json).expect("Failed to parse");
+
+    for text in [format!("{:?}", token), token.to_string()] {
+        assert!(text.contains("trade:read_write"));
+        assert!(!text.contains("abc123"));
+        assert!(!text.contains("refresh456"));
+    }
+    assert_eq!(
+        serde_json::to_value(&token).unwrap()["access_token"],
+        "abc123"
+    );
+}
+
 #[test]
 fn test_request_params_new() {
     let params = RequestParams::new();
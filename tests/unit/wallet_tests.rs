@@ -5,6 +5,7 @@
 ******************************************************************************/
 //! Unit tests for wallet models and types
 
+use deribit_http::model::TimestampMs;
 use deribit_http::model::request::wallet::{
     AddToAddressBookRequest, UpdateInAddressBookRequest, WithdrawRequest,
 };
@@ -134,7 +135,7 @@ fn test_deposit_address_serialization() {
         address: "0xtest123".to_string(),
         currency: "ETH".to_string(),
         address_type: Some("deposit".to_string()),
-        creation_timestamp: Some(1234567890000),
+        creation_timestamp: Some(TimestampMs(1234567890000)),
         status: Some("active".to_string()),
     };
 
@@ -159,7 +160,7 @@ fn test_deposit_address_deserialization() {
     assert_eq!(addr.address, "bc1qtest");
     assert_eq!(addr.currency, "BTC");
     assert_eq!(addr.address_type, Some("deposit".to_string()));
-    assert_eq!(addr.creation_timestamp, Some(1234567890000));
+    assert_eq!(addr.creation_timestamp, Some(TimestampMs(1234567890000)));
     assert_eq!(addr.status, Some("active".to_string()));
 }
 
@@ -197,7 +198,7 @@ fn test_address_book_entry_serialization() {
         currency: "BTC".to_string(),
         entry_type: Some("withdrawal".to_string()),
         label: Some("Main wallet".to_string()),
-        creation_timestamp: Some(1234567890000),
+        creation_timestamp: Some(TimestampMs(1234567890000)),
         update_timestamp: None,
         agreed: Some(true),
         personal: Some(false),